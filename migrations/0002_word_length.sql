@@ -0,0 +1,5 @@
+-- 0002_word_length.sql
+
+
+-- games used to always be five letters, so existing games keep that
+ALTER TABLE games ADD COLUMN word_length TINYINT UNSIGNED NOT NULL DEFAULT 5;
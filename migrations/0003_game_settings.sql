@@ -0,0 +1,16 @@
+-- 0003_game_settings.sql
+
+
+-- rules for one game, which the owner may change until the game starts
+CREATE TABLE IF NOT EXISTS game_settings (
+    game_id INT NOT NULL PRIMARY KEY,
+    max_guesses TINYINT UNSIGNED NOT NULL DEFAULT 5,
+    turn_seconds SMALLINT UNSIGNED NOT NULL DEFAULT 90,
+    max_players TINYINT UNSIGNED NOT NULL DEFAULT 6,
+    FOREIGN KEY (game_id) REFERENCES games(id)
+);
+
+-- existing games were all played with the old fixed rules
+INSERT INTO game_settings (game_id)
+    SELECT id FROM games
+    WHERE id NOT IN (SELECT game_id FROM game_settings);
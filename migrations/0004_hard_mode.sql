@@ -0,0 +1,5 @@
+-- 0004_hard_mode.sql
+
+
+-- in hard mode every guess must use the hints from the player's earlier guesses
+ALTER TABLE game_settings ADD COLUMN hard_mode BOOL NOT NULL DEFAULT FALSE;
@@ -0,0 +1,18 @@
+-- 0005_daily_challenge.sql
+
+
+-- one row per player per day. The primary key allows only one attempt per day.
+CREATE TABLE IF NOT EXISTS daily_games (
+    challenge_date DATE NOT NULL,
+    user_id INT NOT NULL,
+    username VARCHAR(255) NOT NULL,
+    game_id INT NOT NULL UNIQUE,
+    solved BOOL NOT NULL DEFAULT FALSE,
+    guesses_used TINYINT UNSIGNED NOT NULL DEFAULT 0,
+    created_timestamp TIMESTAMP NOT NULL DEFAULT UTC_TIMESTAMP,
+    finished_timestamp TIMESTAMP NULL, -- null until the game is over
+    PRIMARY KEY (challenge_date, user_id),
+    FOREIGN KEY (game_id) REFERENCES games(id)
+);
+
+CREATE INDEX idx_daily_user_id ON daily_games(user_id);
@@ -0,0 +1,6 @@
+-- 0006_bots.sql
+
+
+-- bots are players run by the server, with a negative user_id (no real user behind them).
+-- bot_level is easy, medium or hard, and null for human players.
+ALTER TABLE game_users ADD COLUMN bot_level VARCHAR(10) NULL;
@@ -0,0 +1,22 @@
+-- 0007_ratings.sql
+
+
+-- every player starts at 1200 (see game_logic::DEFAULT_RATING). No row means no rated games yet.
+CREATE TABLE IF NOT EXISTS user_ratings (
+    user_id INT NOT NULL PRIMARY KEY,
+    rating INT NOT NULL DEFAULT 1200
+);
+
+
+-- one row per player per rated game, so we can show how ratings change over time
+CREATE TABLE IF NOT EXISTS rating_history (
+    id INT AUTO_INCREMENT NOT NULL PRIMARY KEY,
+    user_id INT NOT NULL,
+    game_id INT NOT NULL,
+    rating_before INT NOT NULL,
+    rating_after INT NOT NULL,
+    created_timestamp TIMESTAMP NOT NULL DEFAULT UTC_TIMESTAMP,
+    FOREIGN KEY (game_id) REFERENCES games(id)
+);
+
+CREATE INDEX idx_rating_history_user_id ON rating_history(user_id);
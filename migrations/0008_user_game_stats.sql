@@ -0,0 +1,89 @@
+-- 0008_user_game_stats.sql
+
+
+-- user_game_stats was created in 0001 but never used.
+-- It now holds each user's running totals, kept up to date whenever a game finishes or is cancelled.
+-- username is here so profile pages (which only know the username) can look stats up.
+ALTER TABLE user_game_stats
+    MODIFY wins INT NOT NULL DEFAULT 0,
+    MODIFY losses INT NOT NULL DEFAULT 0,
+    ADD COLUMN username VARCHAR(255) NOT NULL DEFAULT '',
+    ADD COLUMN cancelled INT NOT NULL DEFAULT 0,
+    ADD COLUMN current_streak INT NOT NULL DEFAULT 0, -- wins in a row, up to the latest finished game
+    ADD COLUMN best_streak INT NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_user_game_stats_username ON user_game_stats(username);
+
+
+-- how many games each user won on their 1st guess, 2nd guess, etc.
+CREATE TABLE IF NOT EXISTS user_guess_distribution (
+    user_id INT NOT NULL,
+    guess_number TINYINT UNSIGNED NOT NULL,
+    wins INT NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, guess_number)
+);
+
+
+-- BACKFILL from existing games (human players only: bots have negative ids)
+
+INSERT INTO user_game_stats (user_id, username, wins, losses, cancelled)
+    SELECT
+        gu.user_id,
+        MAX(gu.username),
+        SUM(g.game_status = 'finished' AND g.winner_id = gu.user_id),
+        SUM(g.game_status = 'finished' AND (g.winner_id IS NULL OR g.winner_id <> gu.user_id)),
+        SUM(g.game_status = 'cancelled')
+    FROM game_users gu
+    JOIN games g ON g.id = gu.game_id
+    WHERE gu.user_id > 0
+    GROUP BY gu.user_id
+ON DUPLICATE KEY UPDATE
+    username = VALUES(username),
+    wins = VALUES(wins),
+    losses = VALUES(losses),
+    cancelled = VALUES(cancelled);
+
+-- Streaks: number each user's finished games in order, and group the wins by
+-- how many losses came before them. Each group is one winning streak.
+-- The current streak is the group after the latest loss.
+UPDATE user_game_stats ugs
+JOIN (
+    SELECT
+        streak_runs.user_id,
+        MAX(streak_runs.run_length) AS best_streak,
+        SUBSTRING_INDEX(
+            GROUP_CONCAT(streak_runs.run_length ORDER BY streak_runs.losses_so_far DESC),
+            ',', 1
+        ) AS current_streak
+    FROM (
+        SELECT user_id, losses_so_far, SUM(won) AS run_length
+        FROM (
+            SELECT
+                gu.user_id,
+                COALESCE(g.winner_id = gu.user_id, 0) AS won,
+                SUM(1 - COALESCE(g.winner_id = gu.user_id, 0))
+                    OVER (PARTITION BY gu.user_id ORDER BY g.id) AS losses_so_far
+            FROM game_users gu
+            JOIN games g ON g.id = gu.game_id
+            WHERE gu.user_id > 0 AND g.game_status = 'finished'
+        ) finished_results
+        GROUP BY user_id, losses_so_far
+    ) streak_runs
+    GROUP BY streak_runs.user_id
+) streaks ON streaks.user_id = ugs.user_id
+SET ugs.best_streak = streaks.best_streak,
+    ugs.current_streak = streaks.current_streak;
+
+-- Guesses for finished games used to be deleted when the game ended,
+-- so the distribution can only be backfilled for winners whose guesses are still here.
+INSERT INTO user_guess_distribution (user_id, guess_number, wins)
+    SELECT winner_id, guess_count, COUNT(*)
+    FROM (
+        SELECT g.winner_id, COUNT(gs.id) AS guess_count
+        FROM games g
+        JOIN guesses gs ON gs.game_id = g.id AND gs.user_id = g.winner_id
+        WHERE g.game_status = 'finished' AND g.winner_id > 0
+        GROUP BY g.id, g.winner_id
+    ) won_games
+    GROUP BY winner_id, guess_count
+ON DUPLICATE KEY UPDATE wins = VALUES(wins);
@@ -0,0 +1,15 @@
+-- 0009_unique_guess_number.sql
+
+
+-- Each player's guesses in a game are numbered 1, 2, 3...
+-- Before guesses were saved in a locked transaction, a race could save
+-- two with the same number. Keep the first of any such pair.
+DELETE newer FROM guesses newer
+    JOIN guesses older
+        ON older.game_id = newer.game_id
+        AND older.user_id = newer.user_id
+        AND older.guess_number = newer.guess_number
+        AND older.id < newer.id;
+
+ALTER TABLE guesses
+    ADD CONSTRAINT uq_guesses_turn UNIQUE (game_id, user_id, guess_number);
@@ -0,0 +1,8 @@
+-- 0010_guess_scores.sql
+
+
+-- each guess's score, saved with the guess so refreshes don't rescore every guess.
+-- one character per letter: R (right spot), W (wrong spot), D (dud). ie: "RDWDR"
+-- NULL for guesses saved before this column existed (those are scored when read).
+ALTER TABLE guesses
+    ADD COLUMN score VARCHAR(10) NULL;
@@ -0,0 +1,66 @@
+-- 0011_backfill_guess_scores.sql
+
+
+-- Score every guess saved before 0010, the same way game_logic::check_guess does:
+--   R  the letter is in the right spot
+--   W  the letter is in the word, and the solution still has one of it unaccounted for
+--      (after the right-spot matches, and after earlier wrong-spot uses of the same letter)
+--   D  otherwise
+-- BINARY keeps comparisons case-sensitive, like Rust's char comparison.
+
+-- one row per letter of each unscored guess
+CREATE TABLE backfill_guess_letters AS
+    SELECT
+        gs.id,
+        p.pos,
+        SUBSTRING(gs.word, p.pos, 1) AS guess_letter,
+        SUBSTRING(g.word, p.pos, 1) AS answer_letter,
+        g.word AS answer
+    FROM guesses gs
+    JOIN games g ON g.id = gs.game_id
+    JOIN (
+        SELECT 1 AS pos UNION ALL SELECT 2 UNION ALL SELECT 3 UNION ALL SELECT 4
+        UNION ALL SELECT 5 UNION ALL SELECT 6 UNION ALL SELECT 7 UNION ALL SELECT 8
+        UNION ALL SELECT 9 UNION ALL SELECT 10
+    ) p ON p.pos <= CHAR_LENGTH(gs.word)
+    WHERE gs.score IS NULL;
+
+CREATE INDEX idx_backfill_guess_letters_id ON backfill_guess_letters(id);
+
+UPDATE guesses gs
+    JOIN (
+        SELECT
+            l.id,
+            GROUP_CONCAT(
+                CASE
+                    WHEN BINARY l.guess_letter = BINARY l.answer_letter THEN 'R'
+                    WHEN
+                        -- how many of this letter the solution has
+                        (CHAR_LENGTH(l.answer) - CHAR_LENGTH(REPLACE(l.answer, l.guess_letter, '')))
+                        -- minus the ones matched in the right spot
+                        - (SELECT COUNT(*) FROM backfill_guess_letters r
+                            WHERE r.id = l.id
+                            AND BINARY r.guess_letter = BINARY l.guess_letter
+                            AND BINARY r.answer_letter = BINARY l.guess_letter)
+                        -- minus the ones already used by earlier wrong-spot letters
+                        - (SELECT COUNT(*) FROM backfill_guess_letters e
+                            WHERE e.id = l.id
+                            AND e.pos < l.pos
+                            AND BINARY e.guess_letter = BINARY l.guess_letter
+                            AND BINARY e.answer_letter <> BINARY e.guess_letter)
+                        > 0 THEN 'W'
+                    ELSE 'D'
+                END
+                ORDER BY l.pos
+                SEPARATOR ''
+            ) AS score
+        FROM backfill_guess_letters l
+        GROUP BY l.id
+    ) scored ON scored.id = gs.id
+    SET gs.score = scored.score;
+
+DROP TABLE backfill_guess_letters;
+
+-- Every guess has a score from now on
+ALTER TABLE guesses
+    MODIFY score VARCHAR(10) NOT NULL;
@@ -0,0 +1,6 @@
+-- 0012_game_lang.sql
+
+
+-- the language a game is played in: which lists its solution and guesses come from.
+-- "en" or "fr" (SupportedLangs suffixes). Every game before this was English.
+ALTER TABLE games ADD COLUMN game_lang VARCHAR(2) NOT NULL DEFAULT 'en';
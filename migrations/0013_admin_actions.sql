@@ -0,0 +1,16 @@
+-- 0013_admin_actions.sql
+
+
+-- every look and every change made from the admin area, and who made it
+CREATE TABLE IF NOT EXISTS admin_actions (
+    id INT AUTO_INCREMENT NOT NULL PRIMARY KEY,
+    admin_id INT NOT NULL,
+    admin_username VARCHAR(255) NOT NULL,
+    action VARCHAR(30) NOT NULL, -- ie: search_games, view_game, cancel_game, finish_game, remove_player, view_user
+    game_id INT, -- nullable
+    target_username VARCHAR(255), -- nullable
+    details VARCHAR(255), -- nullable
+    created_timestamp TIMESTAMP NOT NULL DEFAULT UTC_TIMESTAMP
+);
+
+CREATE INDEX idx_admin_actions_created ON admin_actions(created_timestamp);
@@ -0,0 +1,17 @@
+-- 0014_bans.sql
+
+
+-- admin-managed suspensions. Unlike blocks, these apply everywhere.
+-- ban_scope is one of: no_open_games, no_invites, full (see bans.rs)
+CREATE TABLE IF NOT EXISTS bans (
+    id INT AUTO_INCREMENT NOT NULL PRIMARY KEY,
+    username VARCHAR(255) NOT NULL,
+    ban_scope VARCHAR(20) NOT NULL,
+    reason VARCHAR(255) NOT NULL,
+    banned_by_username VARCHAR(255) NOT NULL,
+    expires_timestamp TIMESTAMP NULL, -- NULL means it never runs out
+    lifted_timestamp TIMESTAMP NULL, -- set when an admin ends it early
+    created_timestamp TIMESTAMP NOT NULL DEFAULT UTC_TIMESTAMP
+);
+
+CREATE INDEX idx_bans_username ON bans(username);
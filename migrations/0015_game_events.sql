@@ -0,0 +1,19 @@
+-- 0015_game_events.sql
+
+
+-- append-only history of every game. Rows are only ever inserted,
+-- each in the same transaction as the change it records (see db::record_game_event).
+-- event_type: created, joined, left, booted, invited, uninvited, started,
+--             guessed, timed_out, turn_passed, quit, finished, cancelled
+CREATE TABLE IF NOT EXISTS game_events (
+    id BIGINT AUTO_INCREMENT NOT NULL PRIMARY KEY,
+    game_id INT NOT NULL,
+    event_type VARCHAR(20) NOT NULL,
+    actor_id INT, -- NULL when the server did it (timeouts, a game running out of guesses)
+    actor_username VARCHAR(255),
+    target_username VARCHAR(255), -- who it was done to (booted, invited...), if anybody
+    details VARCHAR(255), -- ie: word=CRANE score=RWDDW
+    created_timestamp TIMESTAMP NOT NULL DEFAULT UTC_TIMESTAMP
+);
+
+CREATE INDEX idx_game_events_game ON game_events(game_id, id);
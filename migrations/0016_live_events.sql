@@ -0,0 +1,15 @@
+-- 0016_live_events.sql
+
+
+-- live updates on their way to the other server instances (see live_updates::run_live_relay).
+-- Each instance writes what it publishes and reads what the others wrote.
+-- Rows are only needed for a few seconds. Old ones get deleted.
+CREATE TABLE IF NOT EXISTS live_events (
+    id BIGINT AUTO_INCREMENT NOT NULL PRIMARY KEY,
+    instance_id VARCHAR(64) NOT NULL, -- the instance that published it
+    channel VARCHAR(300) NOT NULL, -- ie: game:12 or user:alice
+    payload TEXT NOT NULL, -- the event's JSON, exactly as the browser gets it
+    created_timestamp TIMESTAMP NOT NULL DEFAULT UTC_TIMESTAMP
+);
+
+CREATE INDEX idx_live_events_created ON live_events(created_timestamp);
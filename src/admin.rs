@@ -0,0 +1,635 @@
+use actix_web::{ get, post, web, HttpRequest, HttpResponse };
+use askama::Template;
+use hash_ids::HashIds;
+use sqlx::{ MySqlPool };
+use time::OffsetDateTime;
+
+use crate::{
+    api_error::ApiError,
+    auth,
+    bans::{ self, BanScope },
+    db::{ self, AdminAction, AdminGameItem, AdminGameSearch, GameAndPlayers, PlayerInfo },
+    game_logic::{ self, GameEventKind, GameStatus },
+    live_updates::{ LiveEvent, LiveHub },
+    resource_mgr::AdminTexts,
+    resources::get_translation,
+    routes::redirect_to_err,
+    routes_utils::{
+        create_age_string,
+        decode_game_id,
+        publish_turn_passed,
+        AdminActionLine,
+        AdminActionSuccess,
+        AdminEventLine,
+        AdminBanData,
+        AdminBanLine,
+        AdminFinishGameData,
+        AdminGameLink,
+        AdminGameQuery,
+        AdminGameTemplate,
+        AdminGuess,
+        AdminLiftBanData,
+        AdminRemovePlayerData,
+        AdminTemplate,
+        AdminUserTemplate,
+        HashedGameId
+    },
+    utils::SupportedLangs
+};
+
+
+/*
+ *
+ *
+ *
+ *
+ * AAAAAAAAAAAAAAAAAAAAAAAAAA
+ * AAAAAAAAAAAAAAAAAAAAAAAAAA
+ * AAAAA                AAAAA
+ * AAAAA     ADMIN      AAAAA
+ * AAAAA                AAAAA
+ * AAAAAAAAAAAAAAAAAAAAAAAAAA
+ * AAAAAAAAAAAAAAAAAAAAAAAAAA
+ *
+ * Moderation pages under the /admin scope.
+ * middleware::admin_only_middleware keeps everybody but admins out,
+ * so these routes don't check the role again.
+ *
+ * Every search, look and change (bans too) is written to admin_actions
+ * (see log_action) and shown on the admin home page.
+ *
+ *
+ *
+ */
+
+
+// How many log entries the admin home page shows
+const RECENT_ACTIONS_LIMIT: u32 = 50;
+
+
+
+/*
+ *
+ *
+ * PAGES
+ *
+ *
+*/
+
+
+/**
+ * Search games by status, owner and age.
+ * Also shows the latest admin actions.
+ */
+#[get("")]
+pub async fn admin_home(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest,
+    query: web::Query<AdminGameQuery>
+) -> HttpResponse {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let search_query: AdminGameQuery = query.into_inner();
+
+    let owner: &str = search_query.owner.trim();
+    let search: AdminGameSearch = AdminGameSearch {
+        game_status: parse_game_status(&search_query.status),
+        owner_username: if owner.is_empty() { None } else { Some(owner.to_string()) },
+        created_after: days_ago(&search_query.newer_than_days),
+        created_before: days_ago(&search_query.older_than_days)
+    };
+
+    let games: Vec<AdminGameItem> = match db::search_games(&pool, &search).await {
+        Ok(games) => games,
+        Err(e) => {
+            eprintln!("Admin game search failed: {}", e);
+            return redirect_to_err("500");
+        }
+    };
+
+    let details: String = format!(
+        "status={} owner={} older_than_days={} newer_than_days={}",
+        search_query.status,
+        owner,
+        search_query.older_than_days,
+        search_query.newer_than_days
+    );
+    log_action(&pool, &user_req_data, "search_games", None, None, Some(&details)).await;
+
+    // Read the log after writing to it, so this search shows up too
+    let actions: Vec<AdminAction> =
+        match db::get_admin_actions(&pool, RECENT_ACTIONS_LIMIT).await {
+            Ok(actions) => actions,
+            Err(_e) => return redirect_to_err("500")
+        };
+
+    let template: AdminTemplate = AdminTemplate {
+        texts: AdminTexts::new(&user_req_data),
+        user: user_req_data,
+        search: search_query,
+        games: get_admin_game_links(&hash_ids, &games),
+        actions: actions
+            .iter()
+            .map(|action: &AdminAction| AdminActionLine {
+                admin_username: action.admin_username.to_owned(),
+                action: action.action.to_owned(),
+                hashed_game_id: action.game_id
+                    .map(|game_id: i32| hash_ids.encode(&[game_id as u64])),
+                target_username: action.target_username.to_owned(),
+                details: action.details.to_owned().unwrap_or_default(),
+                age_string: create_age_string(&action.created_timestamp)
+            })
+            .collect()
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/html")
+        .body(template.render().unwrap())
+}
+
+
+/**
+ * Any game in any state, with the solution and every guess.
+ */
+#[get("/game/{hashed_game_id}")]
+pub async fn admin_game(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest,
+    path: web::Path<String>
+) -> HttpResponse {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let hashed_game_id: String = path.into_inner();
+
+    let game_id: i32 = match decode_game_id(&hash_ids, &hashed_game_id) {
+        Ok(game_id) => game_id,
+        Err(_e) => return redirect_to_err("404")
+    };
+
+    let game: GameAndPlayers = match db::get_game_and_players(&pool, game_id).await {
+        Ok(game) => game,
+        Err(_e) => return redirect_to_err("404")
+    };
+
+    let settings: db::GameSettings = match db::get_game_settings(&pool, game_id).await {
+        Ok(settings) => settings,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    let raw_guesses: Vec<db::Guess> = match db::get_all_guesses(&pool, game_id).await {
+        Ok(guesses) => guesses,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    let guesses: Vec<AdminGuess> = raw_guesses
+        .iter()
+        .map(|guess: &db::Guess| AdminGuess {
+            guess_number: guess.guess_number,
+            username: game.players
+                .iter()
+                .find(|player: &&PlayerInfo| player.user_id == guess.user_id)
+                .map(|player: &PlayerInfo| player.username.to_owned())
+                .unwrap_or_else(|| guess.user_id.to_string()),
+            word: guess.word.to_owned(),
+            score: game_logic::decode_score(&guess.score)
+                .iter()
+                .map(|letter_score: &game_logic::LetterScore| letter_score.to_emoji())
+                .collect(),
+            age_string: create_age_string(&guess.created_timestamp)
+        })
+        .collect();
+
+    let raw_events: Vec<db::GameEvent> = match db::get_game_events(&pool, game_id).await {
+        Ok(events) => events,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    // No actor means the server did it (ie: a turn timed out)
+    let events: Vec<AdminEventLine> = raw_events
+        .iter()
+        .map(|event: &db::GameEvent| AdminEventLine {
+            event_type: event.event_type.to_owned(),
+            actor_username: event.actor_username.to_owned().unwrap_or_else(|| "-".to_string()),
+            target_username: event.target_username.to_owned().unwrap_or_default(),
+            details: event.details.to_owned().unwrap_or_default(),
+            age_string: create_age_string(&event.created_timestamp)
+        })
+        .collect();
+
+    log_action(&pool, &user_req_data, "view_game", Some(game_id), None, None).await;
+
+    let template: AdminGameTemplate = AdminGameTemplate {
+        texts: AdminTexts::new(&user_req_data),
+        user: user_req_data,
+        age_string: create_age_string(&game.game.created_timestamp),
+        game,
+        hashed_game_id,
+        settings,
+        guesses,
+        events
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/html")
+        .body(template.render().unwrap())
+}
+
+
+/**
+ * Every game a user has played in, everybody they've blocked (or been blocked by),
+ * and every ban they've had.
+ */
+#[get("/user/{username}")]
+pub async fn admin_user(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest,
+    path: web::Path<String>
+) -> HttpResponse {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let username: String = path.into_inner();
+
+    let games: Vec<AdminGameItem> = match db::get_games_by_username(&pool, &username).await {
+        Ok(games) => games,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    let blocks: Vec<db::Block> = match db::get_blocks_involving(&pool, &username).await {
+        Ok(blocks) => blocks,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    let bans: Vec<db::Ban> = match db::get_bans_by_username(&pool, &username).await {
+        Ok(bans) => bans,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    log_action(&pool, &user_req_data, "view_user", None, Some(&username), None).await;
+
+    let template: AdminUserTemplate = AdminUserTemplate {
+        texts: AdminTexts::new(&user_req_data),
+        user: user_req_data,
+        games: get_admin_game_links(&hash_ids, &games),
+        username,
+        blocks,
+        bans: bans.iter().map(get_admin_ban_line).collect(),
+        ban_scopes: BanScope::ALL.iter().map(|scope: &BanScope| scope.to_string()).collect()
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/html")
+        .body(template.render().unwrap())
+}
+
+
+
+/*
+ *
+ *
+ * ACTIONS
+ *
+ *
+*/
+
+
+/**
+ * Cancel any pre-game or in-progress game, whoever owns it.
+ */
+#[post("/cancel_game")]
+pub async fn cancel_game(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    hashed_game_id: web::Json<HashedGameId>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id.hashed_game_id)?;
+    db::get_game_by_id(&pool, game_id).await.map_err(|_e| ApiError::NotFound)?;
+
+    if !db::cancel_game(&pool, game_id, &PlayerInfo::from_user(&user_req_data)?).await? {
+        return Err(ApiError::GameOver);
+    }
+
+    hub.publish_to_game(game_id, &LiveEvent::GameCancelled);
+    log_action(&pool, &user_req_data, "cancel_game", Some(game_id), None, None).await;
+
+    Ok(HttpResponse::Ok().json(AdminActionSuccess {
+        success: true,
+        message: get_translation("msg.game_cancelled", &user_req_data.lang, None)
+    }))
+}
+
+
+/**
+ * End an in-progress game now. Without a winner, everybody loses.
+ * Stats and ratings are recorded like any other finished game.
+ */
+#[post("/finish_game")]
+pub async fn finish_game_now(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    finish_data: web::Json<AdminFinishGameData>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let game_id: i32 = decode_game_id(&hash_ids, &finish_data.hashed_game_id)?;
+    let game: GameAndPlayers = db::get_game_and_players(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
+
+    match game.game.game_status {
+        GameStatus::PreGame => return Err(ApiError::GameNotStarted),
+        GameStatus::Finished | GameStatus::Cancelled => return Err(ApiError::GameOver),
+        GameStatus::InProgress => {}
+    }
+
+    let winner_username_option: Option<&str> = finish_data.winner_username
+        .as_deref()
+        .map(str::trim)
+        .filter(|winner_username: &&str| !winner_username.is_empty());
+
+    let winner_id_option: Option<i32> = match winner_username_option {
+        Some(winner_username) => Some(
+            game.players
+                .iter()
+                .find(|player: &&PlayerInfo| player.username == winner_username)
+                .map(|player: &PlayerInfo| player.user_id)
+                .ok_or(ApiError::NotInGame)?
+        ),
+        None => None
+    };
+
+    // Somebody else finished it first
+    let admin_info: PlayerInfo = PlayerInfo::from_user(&user_req_data)?;
+    if db::finish_game(&pool, game_id, winner_id_option, &admin_info).await? == 0 {
+        return Err(ApiError::GameOver);
+    }
+
+    hub.publish_to_game(game_id, &LiveEvent::GameOver { winner_id: winner_id_option });
+    log_action(
+        &pool,
+        &user_req_data,
+        "finish_game",
+        Some(game_id),
+        winner_username_option,
+        None
+    ).await;
+
+    Ok(HttpResponse::Ok().json(AdminActionSuccess {
+        success: true,
+        message: get_translation("msg.game_finished", &user_req_data.lang, None)
+    }))
+}
+
+
+/**
+ * Take a player (and their guesses) out of a pre-game or in-progress game.
+ * The owner can't be removed. Cancel the game instead.
+ */
+#[post("/remove_player")]
+pub async fn remove_player(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    remove_data: web::Json<AdminRemovePlayerData>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let game_id: i32 = decode_game_id(&hash_ids, &remove_data.hashed_game_id)?;
+    let game: GameAndPlayers = db::get_game_and_players(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
+
+    if game.game.game_status == GameStatus::Finished ||
+        game.game.game_status == GameStatus::Cancelled
+    { return Err(ApiError::GameOver); }
+
+    let player: &PlayerInfo = game.players
+        .iter()
+        .find(|player: &&PlayerInfo| player.username == remove_data.username)
+        .ok_or(ApiError::NotInGame)?;
+
+    if player.user_id == game.game.owner_id {
+        return Err(ApiError::CannotBootOwner);
+    }
+
+    // If it was their turn, it passes on so the game doesn't get stuck.
+    // If nobody left can play, the game finishes.
+    let player_removed: db::PlayerRemoved = db::remove_player_from_game(
+        &pool,
+        game_id,
+        player.user_id,
+        GameEventKind::Booted,
+        &PlayerInfo::from_user(&user_req_data)?
+    ).await?;
+
+    if let db::PlayerRemoved::NotInGame = player_removed {
+        return Err(ApiError::NotInGame);
+    }
+
+    hub.publish_to_game(game_id, &LiveEvent::PlayerBooted {
+        username: player.username.to_owned()
+    });
+
+    // Nobody left could play, so the game finished with no winner
+    let game_over: bool =
+        matches!(player_removed, db::PlayerRemoved::TurnPassed(db::TurnPassed::GameOver));
+
+    if let db::PlayerRemoved::TurnPassed(turn_passed) = player_removed {
+        publish_turn_passed(&pool, &hub, game_id, &turn_passed).await?;
+    }
+
+    log_action(
+        &pool,
+        &user_req_data,
+        "remove_player",
+        Some(game_id),
+        Some(&player.username),
+        if game_over { Some("game over: nobody left could play") } else { None }
+    ).await;
+
+    Ok(HttpResponse::Ok().json(AdminActionSuccess {
+        success: true,
+        message: get_translation("msg.player_booted", &user_req_data.lang, None)
+    }))
+}
+
+
+
+/**
+ * Suspend a user (see bans.rs). Takes effect on their next game action.
+ */
+#[post("/ban_user")]
+pub async fn ban_user(
+    pool: web::Data<MySqlPool>,
+    req: HttpRequest,
+    ban_data: web::Json<AdminBanData>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+
+    let ban_scope: BanScope = BanScope::from_code(&ban_data.ban_scope).ok_or(ApiError::InvalidBan)?;
+    let reason: &str = ban_data.reason.trim();
+    let username: &str = ban_data.username.trim();
+
+    if reason.is_empty() || username.is_empty() || ban_data.days == Some(0) {
+        return Err(ApiError::InvalidBan);
+    }
+
+    // reason column holds 255 characters
+    let reason: String = reason.chars().take(255).collect();
+    let expires_option: Option<OffsetDateTime> = ban_data.days
+        .map(|days: u16| OffsetDateTime::now_utc() + time::Duration::days(days as i64));
+
+    let _ban_id: i32 = db::ban_user(
+        &pool,
+        &user_req_data,
+        username,
+        &ban_scope,
+        &reason,
+        expires_option
+    ).await?;
+
+    let details: String = format!(
+        "scope={} days={} reason={}",
+        ban_scope.to_string(),
+        ban_data.days.map(|days: u16| days.to_string()).unwrap_or_else(|| "forever".to_string()),
+        reason
+    );
+    log_action(&pool, &user_req_data, "ban_user", None, Some(username), Some(&details)).await;
+
+    Ok(HttpResponse::Ok().json(AdminActionSuccess {
+        success: true,
+        message: get_translation("msg.user_banned", &user_req_data.lang, None)
+    }))
+}
+
+
+/**
+ * End a ban early.
+ */
+#[post("/lift_ban")]
+pub async fn lift_ban(
+    pool: web::Data<MySqlPool>,
+    req: HttpRequest,
+    lift_data: web::Json<AdminLiftBanData>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+
+    let ban: db::Ban = db::get_ban_by_id(&pool, lift_data.ban_id).await?
+        .ok_or(ApiError::NotFound)?;
+
+    if !db::lift_ban(&pool, ban.id).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    let details: String = format!("ban_id={}", ban.id);
+    log_action(&pool, &user_req_data, "lift_ban", None, Some(&ban.username), Some(&details)).await;
+
+    Ok(HttpResponse::Ok().json(AdminActionSuccess {
+        success: true,
+        message: get_translation("msg.ban_lifted", &user_req_data.lang, None)
+    }))
+}
+
+
+
+/*
+ *
+ *
+ * HELPERS
+ *
+ *
+*/
+
+
+/**
+ * Write to the admin log. A failed write is reported but never undoes
+ * (or blocks) what the admin already did.
+ */
+async fn log_action(
+    pool: &MySqlPool,
+    admin: &auth::UserReqData,
+    action: &str,
+    game_id_option: Option<i32>,
+    target_username_option: Option<&str>,
+    details_option: Option<&str>
+) {
+    // details column holds 255 characters
+    let details_option: Option<String> = details_option
+        .map(|details: &str| details.chars().take(255).collect());
+
+    if let Err(e) = db::log_admin_action(
+        pool,
+        admin,
+        action,
+        game_id_option,
+        target_username_option,
+        details_option.as_deref()
+    ).await {
+        eprintln!("Failed to log admin action {}: {}", action, e);
+    }
+}
+
+
+/**
+ * A status filter only counts if it's a real status.
+ */
+fn parse_game_status(status: &str) -> Option<String> {
+    let game_status: GameStatus = GameStatus::from_string(status);
+
+    if game_status.to_string() == status {
+        Some(status.to_string())
+    } else {
+        None
+    }
+}
+
+
+/**
+ * "7" => the moment seven days ago. Empty or nonsense => no filter.
+ */
+fn days_ago(days_text: &str) -> Option<OffsetDateTime> {
+    let days: u16 = days_text.trim().parse::<u16>().ok()?;
+    Some(OffsetDateTime::now_utc() - time::Duration::days(days as i64))
+}
+
+
+fn get_admin_ban_line(ban: &db::Ban) -> AdminBanLine {
+    let is_expired: bool = ban.expires_timestamp
+        .is_some_and(|expires: OffsetDateTime| expires <= OffsetDateTime::now_utc());
+
+    AdminBanLine {
+        id: ban.id,
+        ban_scope: ban.ban_scope.to_owned(),
+        reason: ban.reason.to_owned(),
+        banned_by_username: ban.banned_by_username.to_owned(),
+        status: if ban.lifted_timestamp.is_some() {
+                "LIFTED"
+            } else if is_expired {
+                "EXPIRED"
+            } else {
+                "ACTIVE"
+            },
+        ends: ban.expires_timestamp
+            .as_ref()
+            .map(bans::format_ban_end)
+            .unwrap_or_else(|| "NEVER".to_string()),
+        age_string: create_age_string(&ban.created_timestamp)
+    }
+}
+
+
+fn get_admin_game_links(hash_ids: &HashIds, games: &[AdminGameItem]) -> Vec<AdminGameLink> {
+    games
+        .iter()
+        .map(|game: &AdminGameItem| AdminGameLink {
+            hashed_id: hash_ids.encode(&[game.id as u64]),
+            game_status: game.game_status.to_owned(),
+            owner_username: game.owner_username.to_owned(),
+            word_length: game.word_length,
+            game_lang: SupportedLangs::from(&game.game_lang).native_name(),
+            player_count: game.player_count,
+            age_string: create_age_string(&game.created_timestamp)
+        })
+        .collect()
+}
@@ -0,0 +1,426 @@
+use serde::{ Deserialize, Serialize };
+use actix_web::{
+    dev::ServiceRequest,
+    get, post, web,
+    Error,
+    HttpMessage,
+    HttpRequest,
+    HttpResponse
+};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use hash_ids::HashIds;
+use sqlx::{ MySqlPool };
+use time::OffsetDateTime;
+
+use crate::{
+    api_error::ApiError,
+    auth,
+    config::AppConfig,
+    db::{ self, GameAndPlayers, PlayerRefreshData },
+    game_actions,
+    game_logic::{ self, GameStatus },
+    live_updates::LiveHub,
+    routes_utils::{ decode_game_id, HashedGameId, RatingSummary }
+};
+
+
+/*
+ *
+ *
+ *
+ *
+ * VVVVVVVVVVVVVVVVVVVVVVVVVV
+ * VVVVVVVVVVVVVVVVVVVVVVVVVV
+ * VVVVV                VVVVV
+ * VVVVV    API  (v1)   VVVVV
+ * VVVVV                VVVVV
+ * VVVVVVVVVVVVVVVVVVVVVVVVVV
+ * VVVVVVVVVVVVVVVVVVVVVVVVVV
+ *
+ * JSON API for mobile and CLI clients, under the /api/v1 scope.
+ * Authenticated with an "Authorization: Bearer <jwt>" header instead of cookies.
+ * Game ids are hashed exactly like the browser URLs.
+ * Successes return the resource itself. Failures always return
+ * { "error": { "code", "message" } } (see api_error).
+ *
+ *
+ *
+ */
+
+
+/*
+ * REQUEST BODIES
+ */
+
+#[derive(Deserialize)]
+pub struct NewGameRequest {
+    #[serde(default)]
+    pub invite_only: bool,
+    pub word_length: Option<u8>,
+    // "en" or "fr". Defaults to English.
+    pub game_lang: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct InviteRequest {
+    pub username: String,
+}
+
+#[derive(Deserialize)]
+pub struct GuessRequest {
+    pub word: String,
+}
+
+
+/*
+ * RESPONSE BODIES
+ */
+
+#[derive(Serialize)]
+pub struct ApiGameSettings {
+    pub max_guesses: u8,
+    pub turn_seconds: u16,
+    pub max_players: u8,
+    pub hard_mode: bool,
+}
+
+/**
+ * Everything a client needs to draw a game.
+ * Other players' guesses are scores only. The word is only revealed once the game is over.
+ */
+#[derive(Serialize)]
+pub struct ApiGameState {
+    pub hashed_game_id: String,
+    pub game_status: GameStatus,
+    pub word_length: u8,
+    pub game_lang: &'static str,
+    pub open_game: bool,
+    pub owner_username: String,
+    pub turn_user_id: Option<i32>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub turn_timeout: OffsetDateTime,
+    pub winner_id: Option<i32>,
+    pub settings: ApiGameSettings,
+    pub players: Vec<PlayerRefreshData>,
+    pub my_guesses: Vec<game_logic::GuessAndScore>,
+    pub word: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ApiGameSummary {
+    pub hashed_game_id: String,
+    pub game_status: String,
+    pub word_length: u8,
+    pub game_lang: String,
+    pub won: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_timestamp: OffsetDateTime,
+}
+
+#[derive(Serialize)]
+pub struct ApiStats {
+    pub username: String,
+    pub rating: i32,
+    pub stats: db::PlayerStats,
+}
+
+
+/*
+ * AUTHENTICATION
+ */
+
+/**
+ * Validator for HttpAuthentication::bearer.
+ * A valid JWT becomes the request's UserReqData, just like the cookie middleware does.
+ * Expired tokens are rejected. API clients refresh them with the auth app themselves.
+ */
+pub async fn validate_bearer_token(
+    req: ServiceRequest,
+    credentials: BearerAuth
+) -> Result<ServiceRequest, (Error, ServiceRequest)> {
+    let app_config: web::Data<AppConfig> = match req.app_data::<web::Data<AppConfig>>() {
+        Some(app_config) => app_config.clone(),
+        None => return Err((ApiError::Internal.into(), req))
+    };
+
+    match auth::verify_jwt(credentials.token(), &app_config.auth).await {
+        auth::JwtVerification::Valid(claims) => {
+            let user_req_data: auth::UserReqData = auth::UserReqData::new(Some(claims))
+                .with_lang(auth::resolve_lang(req.request()));
+            req.extensions_mut().insert(user_req_data);
+            Ok(req)
+        },
+        auth::JwtVerification::Expired(_) |
+        auth::JwtVerification::Invalid => Err((ApiError::Unauthorized.into(), req))
+    }
+}
+
+
+// Every API route needs a real user
+fn get_api_user(req: &HttpRequest) -> Result<auth::UserReqData, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(req);
+    if user_req_data.id.is_none() || user_req_data.get_role() == "guest" {
+        return Err(ApiError::Unauthorized);
+    }
+
+    Ok(user_req_data)
+}
+
+
+/**
+ * Build the state of a game as the requesting user is allowed to see it.
+ * Pre-games: open games, players and invitees.
+ * In-progress and cancelled games: players only.
+ * Finished games: anybody (like the public result page).
+ */
+async fn get_game_state(
+    pool: &MySqlPool,
+    user_req_data: &auth::UserReqData,
+    hashed_game_id: &String,
+    game_id: i32
+) -> Result<ApiGameState, ApiError> {
+    let user_id: i32 = user_req_data.id.ok_or(ApiError::Unauthorized)?;
+
+    let game_and_players: GameAndPlayers = match db::get_game_and_players(pool, game_id).await {
+        Ok(game_and_players) => game_and_players,
+        Err(_e) => return Err(ApiError::NotFound)
+    };
+
+    let is_player: bool = game_and_players.user_id_is_player(user_id);
+
+    let is_allowed: bool = match game_and_players.game.game_status {
+        GameStatus::PreGame => {
+            if is_player || game_and_players.game.open_game {
+                true
+            } else {
+                let invitations: Vec<db::GameIdAndOwnerName> =
+                    db::get_invitations_by_username(pool, user_req_data.get_username()).await?;
+                invitations.iter().any(|invite| invite.game_id == game_id as i64)
+            }
+        },
+        GameStatus::InProgress | GameStatus::Cancelled => is_player,
+        GameStatus::Finished => true
+    };
+
+    if !is_allowed {
+        return Err(ApiError::Forbidden);
+    }
+
+    let the_game: db::Game = game_and_players.game;
+    let settings: db::GameSettings = db::get_game_settings(pool, game_id).await?;
+    let players: Vec<PlayerRefreshData> =
+        db::get_players_refresh_data_by_game_id(pool, &the_game).await?;
+
+    let my_guesses: Vec<game_logic::GuessAndScore> = if is_player {
+        db::get_guess_scores(pool, game_id, user_id).await?
+    } else {
+        Vec::new()
+    };
+
+    let word: Option<String> = if the_game.game_status == GameStatus::Finished {
+        Some(the_game.word.to_owned())
+    } else {
+        None
+    };
+
+    Ok(ApiGameState {
+        hashed_game_id: hashed_game_id.to_owned(),
+        word_length: the_game.word_length,
+        game_lang: the_game.game_lang.suffix(),
+        open_game: the_game.open_game,
+        owner_username: the_game.owner_username,
+        turn_user_id: the_game.turn_user_id,
+        turn_timeout: the_game.turn_timeout,
+        winner_id: the_game.winner_id,
+        game_status: the_game.game_status,
+        settings: ApiGameSettings {
+            max_guesses: settings.max_guesses,
+            turn_seconds: settings.turn_seconds,
+            max_players: settings.max_players,
+            hard_mode: settings.hard_mode
+        },
+        players,
+        my_guesses,
+        word
+    })
+}
+
+
+
+/*
+ * ROUTES
+ */
+
+#[post("/games")]
+pub async fn create_game(
+    pool: web::Data<MySqlPool>,
+    app_config: web::Data<AppConfig>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest,
+    new_game_request: web::Json<NewGameRequest>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = get_api_user(&req)?;
+
+    let game_id: i32 = game_actions::create_game(
+        &pool,
+        &app_config.limits,
+        &user_req_data,
+        new_game_request.invite_only,
+        new_game_request.word_length,
+        new_game_request.game_lang.as_deref()
+    ).await?;
+
+    Ok(HttpResponse::Created().json(HashedGameId {
+        hashed_game_id: hash_ids.encode(&[game_id as u64])
+    }))
+}
+
+
+#[get("/games/{hashed_game_id}")]
+pub async fn game_state(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest,
+    path: web::Path<String>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = get_api_user(&req)?;
+    let hashed_game_id: String = path.into_inner();
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id)?;
+
+    let state: ApiGameState = get_game_state(&pool, &user_req_data, &hashed_game_id, game_id).await?;
+    Ok(HttpResponse::Ok().json(state))
+}
+
+
+#[post("/games/{hashed_game_id}/join")]
+pub async fn join_game(
+    pool: web::Data<MySqlPool>,
+    app_config: web::Data<AppConfig>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    path: web::Path<String>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = get_api_user(&req)?;
+    let hashed_game_id: String = path.into_inner();
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id)?;
+
+    game_actions::join_game(&pool, &hub, &app_config.limits, &user_req_data, game_id).await?;
+
+    let state: ApiGameState = get_game_state(&pool, &user_req_data, &hashed_game_id, game_id).await?;
+    Ok(HttpResponse::Ok().json(state))
+}
+
+
+#[post("/games/{hashed_game_id}/invites")]
+pub async fn invite_player(
+    pool: web::Data<MySqlPool>,
+    app_config: web::Data<AppConfig>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    invite_request: web::Json<InviteRequest>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = get_api_user(&req)?;
+    let hashed_game_id: String = path.into_inner();
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id)?;
+
+    game_actions::invite_player(
+        &pool,
+        &hub,
+        &app_config.limits,
+        &user_req_data,
+        game_id,
+        &invite_request.username
+    ).await?;
+
+    let state: ApiGameState = get_game_state(&pool, &user_req_data, &hashed_game_id, game_id).await?;
+    Ok(HttpResponse::Created().json(state))
+}
+
+
+#[post("/games/{hashed_game_id}/start")]
+pub async fn start_game(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    path: web::Path<String>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = get_api_user(&req)?;
+    let hashed_game_id: String = path.into_inner();
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id)?;
+
+    game_actions::start_game(&pool, &hub, &user_req_data, game_id).await?;
+
+    let state: ApiGameState = get_game_state(&pool, &user_req_data, &hashed_game_id, game_id).await?;
+    Ok(HttpResponse::Ok().json(state))
+}
+
+
+#[post("/games/{hashed_game_id}/guesses")]
+pub async fn guess_word(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    guess_request: web::Json<GuessRequest>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = get_api_user(&req)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &path.into_inner())?;
+
+    let guess_result: game_logic::CheckGuessResult =
+        game_actions::guess_word(&pool, &hub, &user_req_data, game_id, &guess_request.word).await?;
+
+    Ok(HttpResponse::Created().json(guess_result))
+}
+
+
+// The user's game history, newest first
+#[get("/me/games")]
+pub async fn my_games(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = get_api_user(&req)?;
+    let user_id: i32 = user_req_data.id.ok_or(ApiError::Unauthorized)?;
+
+    let mut games: Vec<db::GameItemData> = db::get_games_byid(&pool, user_id).await?;
+    games.sort_by_key(|game: &db::GameItemData| std::cmp::Reverse(game.created_timestamp));
+
+    let game_summaries: Vec<ApiGameSummary> = games
+        .into_iter()
+        .map(|game: db::GameItemData| ApiGameSummary {
+            hashed_game_id: hash_ids.encode(&[game.id as u64]),
+            won: game.winner_id == Some(user_id),
+            game_status: game.game_status,
+            word_length: game.word_length,
+            game_lang: game.game_lang,
+            created_timestamp: game.created_timestamp
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(game_summaries))
+}
+
+
+#[get("/me/stats")]
+pub async fn my_stats(
+    pool: web::Data<MySqlPool>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = get_api_user(&req)?;
+    let user_id: i32 = user_req_data.id.ok_or(ApiError::Unauthorized)?;
+
+    let stats: db::PlayerStats = db::get_player_stats(&pool, user_id).await?;
+    let rating: RatingSummary = RatingSummary::new(db::get_rating_history(&pool, user_id).await?);
+
+    Ok(HttpResponse::Ok().json(ApiStats {
+        username: user_req_data.get_username(),
+        rating: rating.rating,
+        stats
+    }))
+}
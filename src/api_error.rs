@@ -0,0 +1,397 @@
+use serde::{ Serialize };
+use actix_web::{
+    body::MessageBody,
+    dev::{ ServiceRequest, ServiceResponse },
+    middleware::Next,
+    Error,
+    HttpResponse,
+    ResponseError,
+    http::StatusCode
+};
+use std::fmt;
+use time::OffsetDateTime;
+
+use crate::{
+    auth,
+    bans,
+    game_logic::{ self, MissedHint, SettingsError },
+    resources::get_translation,
+    utils::SupportedLangs
+};
+
+
+/*
+ *
+ *
+ *
+ *
+ * EEEEEEEEEEEEEEEEEEEEEEEEEE
+ * EEEEEEEEEEEEEEEEEEEEEEEEEE
+ * EEEEE                EEEEE
+ * EEEEE   API ERRORS   EEEEE
+ * EEEEE                EEEEE
+ * EEEEEEEEEEEEEEEEEEEEEEEEEE
+ * EEEEEEEEEEEEEEEEEEEEEEEEEE
+ *
+ * Every way a JSON route (/game_in, /api/v1) can fail.
+ * Each one has a stable machine-readable code and an HTTP status,
+ * so clients can branch on the code instead of parsing the message.
+ * Messages come from resources ("api_err.<code>") in the user's language.
+ *
+ *
+ *
+ */
+
+
+#[derive(Debug)]
+pub enum ApiError {
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    NotOwner,
+    NotPlayer,
+    NotInGame,
+    Blocked,
+    // when the ban ends (None: it doesn't)
+    Banned(Option<OffsetDateTime>),
+    AlreadyBlocked,
+    CannotBlockSelf,
+    EmailNotVerified,
+    TooManyCurrentGames,
+    TooManyPlayers,
+    PlayersAlreadyJoined,
+    AlreadyInGame,
+    MaxInvites,
+    CannotBootOwner,
+    GameAlreadyStarted,
+    GameNotStarted,
+    GameOver,
+    GameNotOver,
+    QuitTooSoon,
+    DailyAlreadyPlayed,
+    InvalidWordLength,
+    UnknownGameLang,
+    InvalidSettings(SettingsError),
+    UnknownBotLevel,
+    InvalidBan,
+    WrongTurn,
+    MaxGuesses,
+    WrongLength(u8),
+    FakeWord,
+    HardModeViolation(MissedHint),
+    Internal,
+}
+
+
+// Body of every error response: { "error": { ... } }
+#[derive(Serialize)]
+pub struct ApiErrorResponse {
+    pub error: ApiErrorBody,
+}
+
+/**
+ * Guess rejections carry the details a client needs to explain them.
+ * position is 1-based, and only set when a hard mode letter must be in a certain spot.
+ */
+#[derive(Serialize)]
+pub struct ApiErrorBody {
+    pub code: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_length: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub letter: Option<char>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<usize>,
+}
+
+
+impl ApiError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::Unauthorized => "unauthorized",
+            ApiError::Forbidden => "forbidden",
+            ApiError::NotFound => "not_found",
+            ApiError::NotOwner => "not_owner",
+            ApiError::NotPlayer => "not_player",
+            ApiError::NotInGame => "not_in_game",
+            ApiError::Blocked => "blocked",
+            ApiError::Banned(_) => "banned",
+            ApiError::AlreadyBlocked => "already_blocked",
+            ApiError::CannotBlockSelf => "cannot_block_self",
+            ApiError::EmailNotVerified => "email_not_verified",
+            ApiError::TooManyCurrentGames => "too_many_current_games",
+            ApiError::TooManyPlayers => "too_many_players",
+            ApiError::PlayersAlreadyJoined => "players_already_joined",
+            ApiError::AlreadyInGame => "already_in_game",
+            ApiError::MaxInvites => "max_invites",
+            ApiError::CannotBootOwner => "cannot_boot_owner",
+            ApiError::GameAlreadyStarted => "game_already_started",
+            ApiError::GameNotStarted => "game_not_started",
+            ApiError::GameOver => "game_over",
+            ApiError::GameNotOver => "game_not_over",
+            ApiError::QuitTooSoon => "quit_too_soon",
+            ApiError::DailyAlreadyPlayed => "daily_already_played",
+            ApiError::InvalidWordLength => "invalid_word_length",
+            ApiError::UnknownGameLang => "unknown_game_lang",
+            ApiError::InvalidSettings(_) => "invalid_settings",
+            ApiError::UnknownBotLevel => "unknown_bot_level",
+            ApiError::InvalidBan => "invalid_ban",
+            ApiError::WrongTurn => "wrong_turn",
+            ApiError::MaxGuesses => "max_guesses",
+            ApiError::WrongLength(_) => "wrong_length",
+            ApiError::FakeWord => "fake_word",
+            ApiError::HardModeViolation(_) => "hard_mode_violation",
+            ApiError::Internal => "internal",
+        }
+    }
+
+    /**
+     * Most codes have one message. The ones with details pick
+     * a more specific translation and fill in its placeholders.
+     */
+    pub fn message(&self, lang: &SupportedLangs) -> String {
+        let (key, params): (String, Vec<String>) = match self {
+            ApiError::InvalidWordLength => (
+                "api_err.invalid_word_length".to_string(),
+                vec![
+                    game_logic::MIN_WORD_LENGTH.to_string(),
+                    game_logic::MAX_WORD_LENGTH.to_string()
+                ]
+            ),
+            ApiError::InvalidSettings(SettingsError::Guesses) => (
+                "api_err.invalid_guesses_setting".to_string(),
+                vec![game_logic::MIN_GUESSES.to_string(), game_logic::MAX_GUESSES.to_string()]
+            ),
+            ApiError::InvalidSettings(SettingsError::TurnSeconds) => (
+                "api_err.invalid_turn_seconds_setting".to_string(),
+                vec![
+                    game_logic::MIN_TURN_SECONDS.to_string(),
+                    game_logic::MAX_TURN_SECONDS.to_string()
+                ]
+            ),
+            ApiError::InvalidSettings(SettingsError::Players { max }) => (
+                "api_err.invalid_players_setting".to_string(),
+                vec![game_logic::MIN_PLAYERS.to_string(), max.to_string()]
+            ),
+            ApiError::WrongLength(word_length) =>
+                ("api_err.wrong_length".to_string(), vec![word_length.to_string()]),
+            ApiError::HardModeViolation(MissedHint::RightSpot { letter, position }) => (
+                "api_err.hard_mode_right_spot".to_string(),
+                vec![letter.to_string(), (position + 1).to_string()]
+            ),
+            ApiError::HardModeViolation(MissedHint::WrongSpot { letter }) =>
+                ("api_err.hard_mode_wrong_spot".to_string(), vec![letter.to_string()]),
+            ApiError::Banned(Some(expires)) =>
+                ("api_err.banned_until".to_string(), vec![bans::format_ban_end(expires)]),
+            _ => (format!("api_err.{}", self.code()), Vec::new())
+        };
+
+        let param_refs: Vec<&str> = params.iter().map(|param: &String| param.as_str()).collect();
+        get_translation(&key, lang, Some(&param_refs))
+    }
+
+    pub fn to_body(&self, lang: &SupportedLangs) -> ApiErrorBody {
+        let mut body: ApiErrorBody = ApiErrorBody {
+            code: self.code(),
+            message: self.message(lang),
+            word_length: None,
+            letter: None,
+            position: None
+        };
+
+        match self {
+            ApiError::WrongLength(word_length) => body.word_length = Some(*word_length),
+            ApiError::HardModeViolation(MissedHint::RightSpot { letter, position }) => {
+                body.letter = Some(*letter);
+                body.position = Some(position + 1);
+            },
+            ApiError::HardModeViolation(MissedHint::WrongSpot { letter }) =>
+                body.letter = Some(*letter),
+            _ => {}
+        }
+
+        body
+    }
+
+    pub fn to_response(&self, lang: &SupportedLangs) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .json(ApiErrorResponse { error: self.to_body(lang) })
+    }
+}
+
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message(&SupportedLangs::English))
+    }
+}
+
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ApiError::Forbidden |
+            ApiError::NotOwner |
+            ApiError::NotPlayer |
+            ApiError::Blocked |
+            ApiError::Banned(_) |
+            ApiError::EmailNotVerified => StatusCode::FORBIDDEN,
+            ApiError::NotFound |
+            ApiError::NotInGame => StatusCode::NOT_FOUND,
+            ApiError::AlreadyBlocked |
+            ApiError::TooManyCurrentGames |
+            ApiError::TooManyPlayers |
+            ApiError::PlayersAlreadyJoined |
+            ApiError::AlreadyInGame |
+            ApiError::MaxInvites |
+            ApiError::CannotBootOwner |
+            ApiError::GameAlreadyStarted |
+            ApiError::GameNotStarted |
+            ApiError::GameOver |
+            ApiError::GameNotOver |
+            ApiError::QuitTooSoon |
+            ApiError::DailyAlreadyPlayed |
+            ApiError::WrongTurn |
+            ApiError::MaxGuesses => StatusCode::CONFLICT,
+            ApiError::CannotBlockSelf |
+            ApiError::InvalidWordLength |
+            ApiError::UnknownGameLang |
+            ApiError::InvalidSettings(_) |
+            ApiError::UnknownBotLevel |
+            ApiError::InvalidBan |
+            ApiError::WrongLength(_) |
+            ApiError::FakeWord |
+            ApiError::HardModeViolation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    // No request here, so English. localize_api_errors swaps in the user's language.
+    fn error_response(&self) -> HttpResponse {
+        self.to_response(&SupportedLangs::English)
+    }
+}
+
+
+// Database and other unexpected failures are logged and hidden from the client
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> ApiError {
+        eprintln!("Internal error: {:?}", e);
+        ApiError::Internal
+    }
+}
+
+
+/**
+ * Middleware for the JSON scopes.
+ * If a route failed with an ApiError, re-render it in the user's language.
+ */
+pub async fn localize_api_errors(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let res: ServiceResponse<_> = next.call(req).await?;
+
+    let localized_option: Option<HttpResponse> = res.response()
+        .error()
+        .and_then(|error: &Error| error.as_error::<ApiError>())
+        .map(|api_error: &ApiError| {
+            let user_req_data: auth::UserReqData = auth::get_user_req_data(res.request());
+            api_error.to_response(&user_req_data.lang)
+        });
+
+    match localized_option {
+        Some(localized) => Ok(res.into_response(localized).map_into_boxed_body()),
+        None => Ok(res.map_into_boxed_body())
+    }
+}
+
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::missing_trans;
+
+    // Every variant, and every detail that picks its own message
+    fn every_api_error() -> Vec<ApiError> {
+        vec![
+            ApiError::Unauthorized,
+            ApiError::Forbidden,
+            ApiError::NotFound,
+            ApiError::NotOwner,
+            ApiError::NotPlayer,
+            ApiError::NotInGame,
+            ApiError::Blocked,
+            ApiError::Banned(None),
+            ApiError::Banned(Some(OffsetDateTime::now_utc())),
+            ApiError::AlreadyBlocked,
+            ApiError::CannotBlockSelf,
+            ApiError::EmailNotVerified,
+            ApiError::TooManyCurrentGames,
+            ApiError::TooManyPlayers,
+            ApiError::PlayersAlreadyJoined,
+            ApiError::AlreadyInGame,
+            ApiError::MaxInvites,
+            ApiError::CannotBootOwner,
+            ApiError::GameAlreadyStarted,
+            ApiError::GameNotStarted,
+            ApiError::GameOver,
+            ApiError::GameNotOver,
+            ApiError::QuitTooSoon,
+            ApiError::DailyAlreadyPlayed,
+            ApiError::InvalidWordLength,
+            ApiError::UnknownGameLang,
+            ApiError::InvalidSettings(SettingsError::Guesses),
+            ApiError::InvalidSettings(SettingsError::TurnSeconds),
+            ApiError::InvalidSettings(SettingsError::Players { max: 6 }),
+            ApiError::UnknownBotLevel,
+            ApiError::InvalidBan,
+            ApiError::WrongTurn,
+            ApiError::MaxGuesses,
+            ApiError::WrongLength(5),
+            ApiError::FakeWord,
+            ApiError::HardModeViolation(MissedHint::RightSpot { letter: 'A', position: 0 }),
+            ApiError::HardModeViolation(MissedHint::WrongSpot { letter: 'A' }),
+            ApiError::Internal,
+        ]
+    }
+
+    // A new variant won't compile until it's added here, and then to every_api_error
+    fn is_listed(api_error: &ApiError) -> bool {
+        match api_error {
+            ApiError::Unauthorized | ApiError::Forbidden | ApiError::NotFound |
+            ApiError::NotOwner | ApiError::NotPlayer | ApiError::NotInGame |
+            ApiError::Blocked | ApiError::Banned(_) | ApiError::AlreadyBlocked |
+            ApiError::CannotBlockSelf | ApiError::EmailNotVerified |
+            ApiError::TooManyCurrentGames | ApiError::TooManyPlayers |
+            ApiError::PlayersAlreadyJoined | ApiError::AlreadyInGame | ApiError::MaxInvites |
+            ApiError::CannotBootOwner | ApiError::GameAlreadyStarted |
+            ApiError::GameNotStarted | ApiError::GameOver | ApiError::GameNotOver |
+            ApiError::QuitTooSoon | ApiError::DailyAlreadyPlayed |
+            ApiError::InvalidWordLength | ApiError::UnknownGameLang |
+            ApiError::InvalidSettings(_) | ApiError::UnknownBotLevel | ApiError::InvalidBan |
+            ApiError::WrongTurn | ApiError::MaxGuesses | ApiError::WrongLength(_) |
+            ApiError::FakeWord | ApiError::HardModeViolation(_) | ApiError::Internal => true
+        }
+    }
+
+    #[test]
+    fn every_error_has_a_message_in_every_language() {
+        for api_error in every_api_error() {
+            assert!(is_listed(&api_error));
+
+            for lang in SupportedLangs::ALL.iter() {
+                let message: String = api_error.message(lang);
+                assert_ne!(message, missing_trans(lang), "{} in {}", api_error.code(), lang.suffix());
+                assert!(!message.contains('{'), "{} has an unfilled placeholder", api_error.code());
+            }
+        }
+    }
+}
@@ -6,7 +6,7 @@ use time::{ Duration, OffsetDateTime };
 use actix_web::{ HttpMessage, HttpRequest, cookie::{Cookie, SameSite}};
 use std::fmt;
 
-use crate::utils::{self, SupportedLangs};
+use crate::{ config, utils::{self, SupportedLangs} };
 
 /*
  * 
@@ -206,6 +206,12 @@ impl UserReqData {
         &self.role == "admin"
     }
 
+    // UserReqData::new starts everybody in English. The middleware sets the real language.
+    pub fn with_lang(mut self, lang: SupportedLangs) -> Self {
+        self.lang = lang;
+        self
+    }
+
     pub fn lang_suffix(&self) -> &'static str {
         self.lang.suffix()
     }
@@ -289,7 +295,7 @@ pub fn generate_jwt(
     username: String,
     role: String,
     email_verified: bool,
-    //secret: &[u8]
+    auth_config: &config::AuthConfig
 ) -> Result<String, AuthError> {
     // Set expiration for 1 hour from now
     let exp: usize = (OffsetDateTime::now_utc() + Duration::minutes(30))
@@ -300,17 +306,13 @@ pub fn generate_jwt(
         username, role, exp, email_verified
     };
 
-    // Get JWT secret from env. Return err if missing.
-    let jwt_secret: String = get_jwt_secret()
-        .map_err(|_| AuthError::MissingJwtSecret)?;
-
-    // secret exists in env variables. Encode and match the result.
+    // Encode and match the result.
     // Encoding includes the HS256 signature.
     let jwt_result: Result<String, Error> =
         encode(
             &Header::default(),
             &claims,
-            &EncodingKey::from_secret(jwt_secret.as_bytes())
+            &EncodingKey::from_secret(auth_config.jwt_secret.as_bytes())
         );
     
     match jwt_result {
@@ -325,11 +327,13 @@ pub fn generate_jwt(
  * Setting a cookie only works for browsing within the auth site
  * For external app authentication we will implement OAuth2
  */
-pub fn build_token_cookie(token: String, name: String) -> Cookie<'static> {
-    // WARNING: THIS MUST BE TRUE IN PROD. Change env variable
-    let secure: bool = std::env::var("COOKIE_SECURE")
-        .map(|value: String| value == "true")
-        .unwrap_or(false);
+pub fn build_token_cookie(
+    token: String,
+    name: String,
+    auth_config: &config::AuthConfig
+) -> Cookie<'static> {
+    // WARNING: THIS MUST BE TRUE IN PROD. Change COOKIE_SECURE in config
+    let secure: bool = auth_config.cookie_secure;
 
     let max_age: Duration = Duration::days(21);
 
@@ -343,6 +347,46 @@ pub fn build_token_cookie(token: String, name: String) -> Cookie<'static> {
 }
 
 
+/**
+ * Remembers the language the user picked in the nav (see routes::set_lang).
+ * Not secret, so it lasts a year.
+ */
+pub const LANG_COOKIE: &str = "lang";
+
+pub fn build_lang_cookie(lang: &SupportedLangs, auth_config: &config::AuthConfig) -> Cookie<'static> {
+    Cookie::build(LANG_COOKIE, lang.suffix())
+        .http_only(true)
+        .secure(auth_config.cookie_secure)
+        .same_site(SameSite::Lax)
+        .path("/")
+        .max_age(Duration::days(365))
+        .finish()
+}
+
+
+/**
+ * Which language to show this request in:
+ * 1. the language the user picked (lang cookie)
+ * 2. the browser's Accept-Language header
+ * 3. English
+ */
+pub fn resolve_lang(req: &HttpRequest) -> SupportedLangs {
+    let cookie_lang_option: Option<SupportedLangs> = req
+        .cookie(LANG_COOKIE)
+        .and_then(|cookie: Cookie<'_>| SupportedLangs::from_code(cookie.value()));
+
+    if let Some(lang) = cookie_lang_option {
+        return lang;
+    }
+
+    req.headers()
+        .get(actix_web::http::header::ACCEPT_LANGUAGE)
+        .and_then(|header_value| header_value.to_str().ok())
+        .and_then(SupportedLangs::from_accept_language)
+        .unwrap_or(SupportedLangs::English)
+}
+
+
 /**
  * Decode the jwt string, check it against the Claims struct.
  * If the JWT is expired, we will still return the Claims (using insecure_decode)
@@ -350,15 +394,9 @@ pub fn build_token_cookie(token: String, name: String) -> Cookie<'static> {
  * If all is well, return the Claims stuct in case we want to
  * use that data or check it against DB data.
  */
-pub async fn verify_jwt(token: &str) -> JwtVerification {
+pub async fn verify_jwt(token: &str, auth_config: &config::AuthConfig) -> JwtVerification {
     // get the jwt secret so we can decode the jwt string
-    let secret: String = match get_jwt_secret() {
-        Ok(s) => s,
-        Err(_e) => {
-            eprintln!("Failed to retrieve JWT SECRET");
-            return JwtVerification::Invalid;
-        }
-    };
+    let secret: &String = &auth_config.jwt_secret;
 
     // HS256 algorithm matches the header default I use to encode
     match decode::<Claims>(
@@ -385,17 +423,9 @@ pub async fn verify_jwt(token: &str) -> JwtVerification {
 }
 
 
-// Get the JWT secret from env variables
-pub fn get_jwt_secret() -> Result<String, std::env::VarError> {
-    std::env::var("JWT_SECRET")
-}
-
-
-pub fn get_client_data() -> Result<ClientData, std::env::VarError> {
-    let client_id: String = std::env::var("CLIENT_ID")?;
-    let client_secret: String = std::env::var("CLIENT_SECRET")?;
-
-    Ok(ClientData {
-        client_id, client_secret
-    })
+pub fn get_client_data(auth_config: &config::AuthConfig) -> ClientData {
+    ClientData {
+        client_id: auth_config.client_id.to_owned(),
+        client_secret: auth_config.client_secret.to_owned()
+    }
 }
@@ -0,0 +1,302 @@
+use serde::{ Serialize, Deserialize };
+use std::{ collections::HashMap, sync::Mutex };
+
+use crate::{
+    auth_code_shared::{
+        AuthCodeRequest,
+        AuthCodeSuccess,
+        RefreshCheckRequest,
+        RefreshCheckSuccess,
+        SendVerificationEmailRequest,
+        SendVerificationEmailResponse
+    },
+    config::{ AuthConfig, AuthProviderKind },
+    crankword_io::HttpAuthProvider,
+    utils
+};
+
+
+/*
+ *
+ *
+ *
+ *
+ * ===========================
+ * ===========================
+ * =====                 =====
+ * =====  AUTH PROVIDER  =====
+ * =====                 =====
+ * ===========================
+ * ===========================
+ *
+ *
+ * Crankword doesn't keep users or passwords. Logins, refresh checks and
+ * verification emails are handled by an auth provider:
+ *
+ *      HttpAuthProvider  -- an external auth_app (crankade) at AUTH_BASE_URL
+ *      LocalAuthProvider -- built in, for development. Users come from a JSON file
+ *                           and "logging in" is picking one of them from a list.
+ *
+ * Pick one with AUTH_PROVIDER=http|local (default http). See config.rs.
+ *
+ *
+ *
+*/
+
+
+pub trait AuthProvider {
+    /**
+     * Trade the code from the login redirect (/reception?code=...)
+     * for the user's data and a refresh token.
+     */
+    async fn check_auth_code(&self, client_auth_data: AuthCodeRequest)
+        -> anyhow::Result<AuthCodeSuccess>;
+
+    /**
+     * Is the refresh token still good? Called when the user's JWT has expired.
+     */
+    async fn check_refresh_code(&self, refresh_request: &RefreshCheckRequest)
+        -> anyhow::Result<RefreshCheckSuccess>;
+
+    async fn send_email_verification_request(&self, email_req: SendVerificationEmailRequest)
+        -> anyhow::Result<SendVerificationEmailResponse>;
+
+    // Where to send the user to log in or register (client_id gets added as a querystring)
+    fn login_url(&self) -> String;
+    fn register_url(&self) -> String;
+}
+
+
+/**
+ * The provider chosen at startup. Shared by every worker as app data,
+ * so codes issued by the local provider are valid on any worker.
+ */
+pub enum AuthBackend {
+    Http(HttpAuthProvider),
+    Local(LocalAuthProvider),
+}
+
+impl AuthBackend {
+    pub fn from_config(auth_config: &AuthConfig) -> anyhow::Result<Self> {
+        match auth_config.provider {
+            AuthProviderKind::Http =>
+                Ok(AuthBackend::Http(HttpAuthProvider::new(&auth_config.base_url))),
+            AuthProviderKind::Local =>
+                Ok(AuthBackend::Local(LocalAuthProvider::from_file(&auth_config.local_users)?))
+        }
+    }
+
+    pub fn local(&self) -> Option<&LocalAuthProvider> {
+        match self {
+            AuthBackend::Local(local) => Some(local),
+            AuthBackend::Http(_) => None
+        }
+    }
+}
+
+impl AuthProvider for AuthBackend {
+    async fn check_auth_code(&self, client_auth_data: AuthCodeRequest)
+        -> anyhow::Result<AuthCodeSuccess>
+    {
+        match self {
+            AuthBackend::Http(http) => http.check_auth_code(client_auth_data).await,
+            AuthBackend::Local(local) => local.check_auth_code(client_auth_data).await
+        }
+    }
+
+    async fn check_refresh_code(&self, refresh_request: &RefreshCheckRequest)
+        -> anyhow::Result<RefreshCheckSuccess>
+    {
+        match self {
+            AuthBackend::Http(http) => http.check_refresh_code(refresh_request).await,
+            AuthBackend::Local(local) => local.check_refresh_code(refresh_request).await
+        }
+    }
+
+    async fn send_email_verification_request(&self, email_req: SendVerificationEmailRequest)
+        -> anyhow::Result<SendVerificationEmailResponse>
+    {
+        match self {
+            AuthBackend::Http(http) => http.send_email_verification_request(email_req).await,
+            AuthBackend::Local(local) => local.send_email_verification_request(email_req).await
+        }
+    }
+
+    fn login_url(&self) -> String {
+        match self {
+            AuthBackend::Http(http) => http.login_url(),
+            AuthBackend::Local(local) => local.login_url()
+        }
+    }
+
+    fn register_url(&self) -> String {
+        match self {
+            AuthBackend::Http(http) => http.register_url(),
+            AuthBackend::Local(local) => local.register_url()
+        }
+    }
+}
+
+
+
+/*
+ *
+ *
+ *
+ * ============================
+ * ============================
+ * =====                  =====
+ * =====  LOCAL PROVIDER  =====
+ * =====                  =====
+ * ============================
+ * ============================
+ *
+ *
+ * Codes and refresh tokens only live in memory,
+ * so everybody has to log in again after a restart.
+ *
+ * Users file looks like:
+ *      { "users": [
+ *          { "user_id": 1, "username": "alice", "user_role": "player", "email_verified": true }
+ *      ] }
+ *
+ *
+*/
+
+
+pub const DEFAULT_LOCAL_USERS_PATH: &str = "local_auth_users.json";
+
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LocalAuthUser {
+    pub user_id: i32,
+    pub username: String,
+    #[serde(default = "default_user_role")]
+    pub user_role: String,
+    #[serde(default)]
+    pub email_verified: bool,
+}
+
+fn default_user_role() -> String { "player".to_string() }
+
+
+#[derive(Deserialize)]
+struct LocalAuthUsersFile {
+    users: Vec<LocalAuthUser>,
+}
+
+
+pub struct LocalAuthProvider {
+    users: Mutex<Vec<LocalAuthUser>>,
+    // code => user_id. Each code works once.
+    auth_codes: Mutex<HashMap<String, i32>>,
+    // refresh_token => user_id
+    refresh_tokens: Mutex<HashMap<String, i32>>,
+}
+
+impl LocalAuthProvider {
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        let contents: String = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Could not read local auth users file {}: {}", path, e))?;
+        let users_file: LocalAuthUsersFile = serde_json::from_str(&contents)?;
+
+        // Real user_ids are positive. Negative ones belong to bots.
+        if let Some(bad_user) = users_file.users.iter().find(|user| user.user_id <= 0) {
+            anyhow::bail!("Local auth user {} needs a positive user_id", bad_user.username);
+        }
+
+        Ok(LocalAuthProvider {
+            users: Mutex::new(users_file.users),
+            auth_codes: Mutex::new(HashMap::new()),
+            refresh_tokens: Mutex::new(HashMap::new())
+        })
+    }
+
+    pub fn get_users(&self) -> Vec<LocalAuthUser> {
+        self.users.lock().unwrap().clone()
+    }
+
+    fn get_user(&self, user_id: i32) -> Option<LocalAuthUser> {
+        self.users.lock().unwrap().iter().find(|user| user.user_id == user_id).cloned()
+    }
+
+    /**
+     * User picked who to log in as. Give them a code to bring to /reception.
+     */
+    pub fn issue_auth_code(&self, user_id: i32) -> Option<String> {
+        self.get_user(user_id)?;
+
+        let code: String = utils::generate_client_secret();
+        self.auth_codes.lock().unwrap().insert(code.to_owned(), user_id);
+        Some(code)
+    }
+}
+
+impl AuthProvider for LocalAuthProvider {
+    async fn check_auth_code(&self, client_auth_data: AuthCodeRequest)
+        -> anyhow::Result<AuthCodeSuccess>
+    {
+        let user_id: i32 = match self.auth_codes.lock().unwrap().remove(&client_auth_data.code) {
+            Some(user_id) => user_id,
+            None => anyhow::bail!("Invalid auth code")
+        };
+
+        let user: LocalAuthUser = match self.get_user(user_id) {
+            Some(user) => user,
+            None => anyhow::bail!("User not found")
+        };
+
+        let refresh_token: String = utils::generate_client_secret();
+        self.refresh_tokens.lock().unwrap().insert(refresh_token.to_owned(), user_id);
+
+        Ok(AuthCodeSuccess {
+            user_id: user.user_id,
+            username: user.username,
+            user_role: user.user_role,
+            refresh_token,
+            email_verified: user.email_verified
+        })
+    }
+
+    async fn check_refresh_code(&self, refresh_request: &RefreshCheckRequest)
+        -> anyhow::Result<RefreshCheckSuccess>
+    {
+        let is_valid: bool = self.refresh_tokens.lock().unwrap()
+            .get(&refresh_request.token)
+            .is_some_and(|user_id: &i32| *user_id == refresh_request.user_id);
+
+        Ok(RefreshCheckSuccess::new(is_valid))
+    }
+
+    /**
+     * No email to send. Just mark them verified (it shows up on their next login).
+     */
+    async fn send_email_verification_request(&self, email_req: SendVerificationEmailRequest)
+        -> anyhow::Result<SendVerificationEmailResponse>
+    {
+        let mut users: std::sync::MutexGuard<'_, Vec<LocalAuthUser>> = self.users.lock().unwrap();
+        let user: &mut LocalAuthUser =
+            match users.iter_mut().find(|user| user.user_id == email_req.user_id) {
+                Some(user) => user,
+                None => anyhow::bail!("User not found")
+            };
+
+        user.email_verified = true;
+        println!("Local auth: verified email for {}", user.username);
+
+        Ok(SendVerificationEmailResponse {
+            success: true,
+            message: "Email verified. Log in again to refresh.".to_string(),
+            user_id: email_req.user_id
+        })
+    }
+
+    fn login_url(&self) -> String {
+        "/local_auth/login".to_string()
+    }
+
+    // Nobody registers locally. Add users to the file instead.
+    fn register_url(&self) -> String {
+        "/local_auth/login".to_string()
+    }
+}
@@ -0,0 +1,178 @@
+use sqlx::{ MySqlPool };
+use time::OffsetDateTime;
+
+use crate::{
+    api_error::ApiError,
+    auth,
+    db
+};
+
+
+/*
+ *
+ *
+ *
+ *
+ * ====================
+ * ====================
+ * =====          =====
+ * =====   BANS   =====
+ * =====          =====
+ * ====================
+ * ====================
+ *
+ *
+ * Admins suspend abusive players (see admin.rs). A ban has a scope:
+ *
+ *      no_open_games  -- can't create or join open games. Invite-only games are fine.
+ *      no_invites     -- can't invite anybody to their games.
+ *      full           -- can't create, join, invite or guess at all.
+ *
+ * game_actions checks before each of those, so the browser routes
+ * and the JSON API enforce the same bans.
+ *
+ *
+ *
+*/
+
+
+#[derive(Clone, PartialEq)]
+pub enum BanScope {
+    NoOpenGames,
+    NoInvites,
+    Full,
+}
+
+// The things a ban can stop a player from doing
+pub enum BannedAction {
+    CreateGame { open_game: bool },
+    JoinGame { open_game: bool },
+    Invite,
+    Guess,
+}
+
+
+impl BanScope {
+    pub const ALL: [BanScope; 3] = [BanScope::NoOpenGames, BanScope::NoInvites, BanScope::Full];
+
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            BanScope::NoOpenGames => "no_open_games",
+            BanScope::NoInvites => "no_invites",
+            BanScope::Full => "full",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<BanScope> {
+        BanScope::ALL.into_iter().find(|scope: &BanScope| scope.to_string() == code)
+    }
+
+    pub fn forbids(&self, action: &BannedAction) -> bool {
+        match self {
+            BanScope::Full => true,
+            BanScope::NoOpenGames => matches!(
+                action,
+                BannedAction::CreateGame { open_game: true } |
+                BannedAction::JoinGame { open_game: true }
+            ),
+            BanScope::NoInvites => matches!(action, BannedAction::Invite),
+        }
+    }
+}
+
+
+/**
+ * Fails with ApiError::Banned if any of the user's active bans forbids the action.
+ * The error carries when that ban ends (the longest one, if several apply).
+ */
+pub async fn check_not_banned(
+    pool: &MySqlPool,
+    user_req_data: &auth::UserReqData,
+    action: BannedAction
+) -> Result<(), ApiError> {
+    let bans: Vec<db::Ban> = db::get_active_bans(pool, &user_req_data.get_username()).await?;
+
+    // A scope we don't recognize is treated as the strictest one
+    let forbidding_ban_option: Option<&db::Ban> = bans
+        .iter()
+        .find(|ban: &&db::Ban|
+            BanScope::from_code(&ban.ban_scope).unwrap_or(BanScope::Full).forbids(&action));
+
+    match forbidding_ban_option {
+        Some(ban) => Err(ApiError::Banned(ban.expires_timestamp)),
+        None => Ok(())
+    }
+}
+
+
+/**
+ * When a ban runs out, ie: "2026-11-02 18:30 UTC"
+ */
+pub fn format_ban_end(expires: &OffsetDateTime) -> String {
+    format!("{} {:02}:{:02} UTC", expires.date(), expires.hour(), expires.minute())
+}
+
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ resources::TRANSLATIONS, utils::SupportedLangs };
+
+    fn every_action() -> [BannedAction; 6] {
+        [
+            BannedAction::CreateGame { open_game: true },
+            BannedAction::CreateGame { open_game: false },
+            BannedAction::JoinGame { open_game: true },
+            BannedAction::JoinGame { open_game: false },
+            BannedAction::Invite,
+            BannedAction::Guess,
+        ]
+    }
+
+    fn forbidden_count(ban_scope: &BanScope) -> usize {
+        every_action().iter().filter(|action: &&BannedAction| ban_scope.forbids(action)).count()
+    }
+
+    #[test]
+    fn full_ban_forbids_everything() {
+        assert_eq!(forbidden_count(&BanScope::Full), every_action().len());
+    }
+
+    #[test]
+    fn no_open_games_ban_only_stops_open_games() {
+        let ban_scope: BanScope = BanScope::NoOpenGames;
+
+        assert!(ban_scope.forbids(&BannedAction::CreateGame { open_game: true }));
+        assert!(ban_scope.forbids(&BannedAction::JoinGame { open_game: true }));
+        assert!(!ban_scope.forbids(&BannedAction::CreateGame { open_game: false }));
+        assert!(!ban_scope.forbids(&BannedAction::JoinGame { open_game: false }));
+        assert_eq!(forbidden_count(&ban_scope), 2);
+    }
+
+    #[test]
+    fn no_invites_ban_only_stops_invites() {
+        assert!(BanScope::NoInvites.forbids(&BannedAction::Invite));
+        assert_eq!(forbidden_count(&BanScope::NoInvites), 1);
+    }
+
+    #[test]
+    fn scope_codes_round_trip() {
+        for ban_scope in BanScope::ALL {
+            let code: &str = ban_scope.to_string();
+            assert!(BanScope::from_code(code).is_some_and(|scope: BanScope| scope.to_string() == code));
+
+            // the banned page describes every scope (see routes::banned)
+            for lang in SupportedLangs::ALL.iter() {
+                let key: String = format!("banned.scope.{}.{}", code, lang.suffix());
+                assert!(TRANSLATIONS.contains_key(key.as_str()), "{}", key);
+            }
+        }
+
+        assert!(BanScope::from_code("forever").is_none());
+    }
+}
@@ -0,0 +1,220 @@
+use rand::{ Rng, seq::IndexedRandom };
+
+use crate::{
+    game_logic::{ self, GuessAndScore, Word },
+    utils::{ self, SupportedLangs },
+    words_all
+};
+
+/*
+ *
+ *
+ *
+ *
+ * ==================
+ * ==================
+ * =====        =====
+ * =====  BOTS  =====
+ * =====        =====
+ * ==================
+ * ==================
+ *
+ *
+ * Computer players which the game owner can add in the pre-game lobby.
+ *
+ * A bot is a row in game_users with a negative user_id and a bot_level.
+ * The scheduler plays its turns through the same guess path as a human
+ * player's check_guess request.
+ *
+ * A bot never sees the solution. It only gets its own earlier guesses
+ * and their scores (exactly what a human sees on their own board),
+ * and picks a word from words_all which fits those scores.
+ * Weaker bots often ignore what they've learned and guess something random.
+ *
+ *
+ *
+*/
+
+
+pub enum BotLevel {
+    Easy,
+    Medium,
+    Hard,
+}
+
+
+impl BotLevel {
+    pub fn to_string(&self) -> &str {
+        match self {
+            BotLevel::Easy => "easy",
+            BotLevel::Medium => "medium",
+            BotLevel::Hard => "hard",
+        }
+    }
+
+    pub fn from_string(input: &str) -> Option<BotLevel> {
+        match input {
+            "easy" => Some(BotLevel::Easy),
+            "medium" => Some(BotLevel::Medium),
+            "hard" => Some(BotLevel::Hard),
+            &_ => None,
+        }
+    }
+
+    /**
+     * How often (out of 100) the bot actually uses its hints.
+     * The rest of the time it makes a deliberately poor guess.
+     */
+    fn smart_guess_percent(&self) -> u8 {
+        match self {
+            BotLevel::Easy => 25,
+            BotLevel::Medium => 60,
+            BotLevel::Hard => 100,
+        }
+    }
+}
+
+
+/**
+ * Bots get made-up usernames which real users can't register
+ * (usernames may not contain dots), ie: "BOT.2.HARD"
+ */
+pub fn get_bot_username(bot_id: i32, bot_level: &BotLevel) -> String {
+    format!("BOT.{}.{}", -bot_id, utils::all_caps(bot_level.to_string()))
+}
+
+
+/**
+ * Pick the bot's next guess.
+ * Returns None if there is no acceptable word of that length at all.
+ */
+pub fn choose_guess(
+    bot_level: &BotLevel,
+    word_length: u8,
+    game_lang: &SupportedLangs,
+    previous_guesses: &[GuessAndScore],
+    hard_mode: bool
+) -> Option<String> {
+    let mut rng: rand::prelude::ThreadRng = rand::rng();
+
+    // Never repeat a word, and in hard mode only consider guesses the server will accept
+    let allowed_words: Vec<Word> = words_all::get_words_of_length(word_length, game_lang)
+        .iter()
+        .filter_map(|word: &&str| Word::new(word).ok())
+        .filter(|word: &Word| {
+            !previous_guesses.iter().any(|guess| utils::all_caps(&guess.word) == word.to_string())
+        })
+        .filter(|word: &Word| {
+            !hard_mode || game_logic::find_missed_hint(word, previous_guesses).is_none()
+        })
+        .collect();
+
+    let plays_smart: bool = rng.random_range(0..100) < bot_level.smart_guess_percent();
+
+    if plays_smart {
+        let possible_solutions: Vec<&Word> = allowed_words
+            .iter()
+            .filter(|word: &&Word| fits_all_scores(word, previous_guesses))
+            .collect();
+
+        if let Some(word) = possible_solutions.choose(&mut rng) {
+            return Some(word.to_string());
+        }
+    }
+
+    allowed_words.choose(&mut rng).map(|word: &Word| word.to_string())
+}
+
+
+/**
+ * A word could still be the solution if scoring each earlier guess
+ * against it gives exactly the score that guess really got.
+ */
+fn fits_all_scores(word: &Word, previous_guesses: &[GuessAndScore]) -> bool {
+    previous_guesses.iter().all(|previous_guess: &GuessAndScore| {
+        // Timed-out turns are saved as dud words. They don't rule anything out.
+        let previous_word: Word = match Word::new(&previous_guess.word) {
+            Ok(previous_word) => previous_word,
+            Err(_e) => return true
+        };
+
+        match game_logic::check_guess(&previous_word, word) {
+            Ok(result) => result.score == previous_guess.score,
+            Err(_e) => false
+        }
+    })
+}
+
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // What the bot would see after guessing these words against the solution
+    fn scored_guesses(guesses: &[&str], solution: &str) -> Vec<GuessAndScore> {
+        let solution_word: Word = Word::new(solution).unwrap();
+
+        guesses
+            .iter()
+            .map(|guess: &&str| GuessAndScore {
+                word: guess.to_string(),
+                score: game_logic::check_guess(&Word::new(guess).unwrap(), &solution_word)
+                    .unwrap()
+                    .score
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hard_bot_guesses_fit_every_earlier_score() {
+        let previous_guesses: Vec<GuessAndScore> = scored_guesses(&["SLATE", "ROUND"], "CRANE");
+
+        for _ in 0..10 {
+            let guess: String = choose_guess(
+                &BotLevel::Hard,
+                5,
+                &SupportedLangs::English,
+                &previous_guesses,
+                false
+            ).unwrap();
+
+            assert!(fits_all_scores(&Word::new(&guess).unwrap(), &previous_guesses), "{}", guess);
+            assert!(guess != "SLATE" && guess != "ROUND");
+        }
+    }
+
+    #[test]
+    fn hard_mode_bot_guesses_use_every_hint() {
+        let previous_guesses: Vec<GuessAndScore> = scored_guesses(&["SLATE"], "CRANE");
+
+        for bot_level in [BotLevel::Easy, BotLevel::Medium, BotLevel::Hard] {
+            let guess: String =
+                choose_guess(&bot_level, 5, &SupportedLangs::English, &previous_guesses, true)
+                    .unwrap();
+            let guess_word: Word = Word::new(&guess).unwrap();
+
+            assert!(
+                game_logic::find_missed_hint(&guess_word, &previous_guesses).is_none(),
+                "{}",
+                guess
+            );
+        }
+    }
+
+    #[test]
+    fn dud_turns_rule_nothing_out() {
+        let mut previous_guesses: Vec<GuessAndScore> = scored_guesses(&["SLATE"], "CRANE");
+        previous_guesses.push(GuessAndScore {
+            word: "-----".to_string(),
+            score: game_logic::decode_score("DDDDD")
+        });
+
+        assert!(fits_all_scores(&Word::new("CRANE").unwrap(), &previous_guesses));
+        assert!(!fits_all_scores(&Word::new("SLATE").unwrap(), &previous_guesses));
+    }
+}
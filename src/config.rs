@@ -0,0 +1,447 @@
+use serde::{ Deserialize };
+use std::str::FromStr;
+
+use crate::{ crankword_io, auth_provider, game_logic };
+
+
+/*
+ *
+ *
+ *
+ *
+ * ====================
+ * ====================
+ * =====          =====
+ * =====  CONFIG  =====
+ * =====          =====
+ * ====================
+ * ====================
+ *
+ *
+ * Every setting the app needs, loaded once at startup and checked before
+ * the server binds. Anything missing or malformed stops the app with a
+ * message naming the setting.
+ *
+ * Settings come from an optional TOML file (CONFIG_FILE, default crankword.toml)
+ * and environment variables (and .env). Environment variables win.
+ *
+ *      TOML                        ENV
+ *      [server]
+ *      bind_address                BIND_ADDRESS            (default 127.0.0.1)
+ *      port                        PORT                    (default 8080)
+ *      workers                     WORKERS                 (default: one per CPU core)
+ *      [database]
+ *      url                         DATABASE_URL            (required)
+ *      max_connections             DB_MAX_CONNECTIONS      (default 10)
+ *      [auth]
+ *      provider                    AUTH_PROVIDER           (http | local, default http)
+ *      base_url                    AUTH_BASE_URL           (default https://crankade.com)
+ *      local_users                 LOCAL_AUTH_USERS        (default local_auth_users.json)
+ *      client_id                   CLIENT_ID               (required)
+ *      client_secret               CLIENT_SECRET           (required)
+ *      jwt_secret                  JWT_SECRET              (required)
+ *      hashid_secret               HASHID_SECRET           (required)
+ *      cookie_secure               COOKIE_SECURE           (default false. MUST be true in prod)
+ *      [limits]
+ *      max_current_games           MAX_CURRENT_GAMES       (default 3)
+ *      max_invites                 MAX_INVITES             (default 10)
+ *      max_players                 MAX_PLAYERS             (default 6)
+ *
+ *
+ *
+*/
+
+
+pub const DEFAULT_CONFIG_FILE: &str = "crankword.toml";
+
+
+#[derive(Clone)]
+pub struct AppConfig {
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub auth: AuthConfig,
+    pub limits: Limits,
+}
+
+#[derive(Clone)]
+pub struct ServerConfig {
+    pub bind_address: String,
+    pub port: u16,
+    pub workers: Option<usize>,
+}
+
+#[derive(Clone)]
+pub struct DatabaseConfig {
+    pub url: String,
+    pub max_connections: u32,
+}
+
+#[derive(Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthProviderKind {
+    Http,
+    Local,
+}
+
+#[derive(Clone)]
+pub struct AuthConfig {
+    pub provider: AuthProviderKind,
+    pub base_url: String,
+    pub local_users: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub jwt_secret: String,
+    pub hashid_secret: String,
+    pub cookie_secure: bool,
+}
+
+/**
+ * How much any one user or game may hold at once.
+ */
+#[derive(Clone)]
+pub struct Limits {
+    // pregame and in-progress games a user can be in
+    pub max_current_games: u8,
+    // pending invitations per game
+    pub max_invites: u8,
+    // the most players any game's settings may allow
+    pub max_players: u8,
+}
+
+
+impl FromStr for AuthProviderKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "http" => Ok(AuthProviderKind::Http),
+            "local" => Ok(AuthProviderKind::Local),
+            _ => Err("expected http or local".to_string())
+        }
+    }
+}
+
+
+
+/*
+ * The TOML file. Every field is optional,
+ * so a file can set just the things that differ from the env.
+ */
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct FileConfig {
+    server: FileServer,
+    database: FileDatabase,
+    auth: FileAuth,
+    limits: FileLimits,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct FileServer {
+    bind_address: Option<String>,
+    port: Option<u16>,
+    workers: Option<usize>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct FileDatabase {
+    url: Option<String>,
+    max_connections: Option<u32>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct FileAuth {
+    provider: Option<AuthProviderKind>,
+    base_url: Option<String>,
+    local_users: Option<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    jwt_secret: Option<String>,
+    hashid_secret: Option<String>,
+    cookie_secure: Option<bool>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct FileLimits {
+    max_current_games: Option<u8>,
+    max_invites: Option<u8>,
+    max_players: Option<u8>,
+}
+
+
+
+/*
+ *
+ *
+ * LOADING
+ *
+ *
+*/
+
+
+/**
+ * Collects every problem instead of stopping at the first,
+ * so one failed start shows everything that needs fixing.
+ * env looks up an environment variable (std::env in the app, a map in tests).
+ */
+struct ConfigLoader<'a> {
+    env: &'a dyn Fn(&str) -> Option<String>,
+    errors: Vec<String>,
+}
+
+impl ConfigLoader<'_> {
+    /**
+     * The env variable if it's set, otherwise the file's value.
+     * Records an error if the env variable doesn't parse.
+     */
+    fn optional<T: FromStr>(&mut self, env_name: &str, file_value: Option<T>) -> Option<T>
+    where T::Err: std::fmt::Display
+    {
+        match (self.env)(env_name) {
+            Some(value) => match value.trim().parse::<T>() {
+                Ok(parsed) => Some(parsed),
+                Err(e) => {
+                    self.errors.push(format!("{} is invalid ({}): {}", env_name, value, e));
+                    None
+                }
+            },
+            None => file_value
+        }
+    }
+
+    fn required<T: FromStr>(&mut self, env_name: &str, file_value: Option<T>) -> Option<T>
+    where T::Err: std::fmt::Display
+    {
+        let value_option: Option<T> = self.optional(env_name, file_value);
+        if value_option.is_none() && (self.env)(env_name).is_none() {
+            self.errors.push(format!("{} is not set", env_name));
+        }
+        value_option
+    }
+
+    fn check(&mut self, is_ok: bool, message: &str) {
+        if !is_ok {
+            self.errors.push(message.to_string());
+        }
+    }
+}
+
+
+impl AppConfig {
+    /**
+     * Read the TOML file (if there is one) and the env, and validate everything.
+     * Call after dotenvy has loaded .env.
+     */
+    pub fn load() -> anyhow::Result<AppConfig> {
+        let file_config: FileConfig = read_config_file()?;
+        AppConfig::from_sources(file_config, &|env_name: &str| std::env::var(env_name).ok())
+    }
+
+    // Environment variables win over the file
+    fn from_sources(
+        file_config: FileConfig,
+        env: &dyn Fn(&str) -> Option<String>
+    ) -> anyhow::Result<AppConfig> {
+        let mut loader: ConfigLoader = ConfigLoader { env, errors: Vec::new() };
+
+        let server: ServerConfig = ServerConfig {
+            bind_address: loader
+                .optional("BIND_ADDRESS", file_config.server.bind_address)
+                .unwrap_or_else(|| "127.0.0.1".to_string()),
+            port: loader.optional("PORT", file_config.server.port).unwrap_or(8080),
+            workers: loader.optional("WORKERS", file_config.server.workers),
+        };
+
+        let database_url: Option<String> =
+            loader.required("DATABASE_URL", file_config.database.url);
+        let max_connections: u32 = loader
+            .optional("DB_MAX_CONNECTIONS", file_config.database.max_connections)
+            .unwrap_or(10);
+
+        let provider: AuthProviderKind = loader
+            .optional("AUTH_PROVIDER", file_config.auth.provider)
+            .unwrap_or(AuthProviderKind::Http);
+        let base_url: String = loader
+            .optional("AUTH_BASE_URL", file_config.auth.base_url)
+            .unwrap_or_else(|| crankword_io::DEFAULT_AUTH_BASE_URL.to_string());
+        let local_users: String = loader
+            .optional("LOCAL_AUTH_USERS", file_config.auth.local_users)
+            .unwrap_or_else(|| auth_provider::DEFAULT_LOCAL_USERS_PATH.to_string());
+        let client_id: Option<String> = loader.required("CLIENT_ID", file_config.auth.client_id);
+        let client_secret: Option<String> =
+            loader.required("CLIENT_SECRET", file_config.auth.client_secret);
+        let jwt_secret: Option<String> =
+            loader.required("JWT_SECRET", file_config.auth.jwt_secret);
+        let hashid_secret: Option<String> =
+            loader.required("HASHID_SECRET", file_config.auth.hashid_secret);
+        let cookie_secure: bool = loader
+            .optional("COOKIE_SECURE", file_config.auth.cookie_secure)
+            .unwrap_or(false);
+
+        let limits: Limits = Limits {
+            max_current_games: loader
+                .optional("MAX_CURRENT_GAMES", file_config.limits.max_current_games)
+                .unwrap_or(3),
+            max_invites: loader
+                .optional("MAX_INVITES", file_config.limits.max_invites)
+                .unwrap_or(10),
+            max_players: loader
+                .optional("MAX_PLAYERS", file_config.limits.max_players)
+                .unwrap_or(6),
+        };
+
+        loader.check(server.workers != Some(0), "WORKERS must be at least 1");
+        loader.check(max_connections > 0, "DB_MAX_CONNECTIONS must be at least 1");
+        loader.check(limits.max_current_games > 0, "MAX_CURRENT_GAMES must be at least 1");
+        loader.check(
+            limits.max_players >= game_logic::MIN_PLAYERS,
+            &format!("MAX_PLAYERS must be at least {}", game_logic::MIN_PLAYERS)
+        );
+        loader.check(
+            jwt_secret.as_ref().is_none_or(|secret: &String| !secret.is_empty()),
+            "JWT_SECRET must not be empty"
+        );
+
+        if !loader.errors.is_empty() {
+            anyhow::bail!("Invalid configuration:\n  {}", loader.errors.join("\n  "));
+        }
+
+        // Everything required was checked above
+        Ok(AppConfig {
+            server,
+            database: DatabaseConfig {
+                url: database_url.unwrap_or_default(),
+                max_connections
+            },
+            auth: AuthConfig {
+                provider,
+                base_url,
+                local_users,
+                client_id: client_id.unwrap_or_default(),
+                client_secret: client_secret.unwrap_or_default(),
+                jwt_secret: jwt_secret.unwrap_or_default(),
+                hashid_secret: hashid_secret.unwrap_or_default(),
+                cookie_secure
+            },
+            limits
+        })
+    }
+}
+
+
+/**
+ * A missing default file is fine. A missing file that was asked for is not.
+ */
+fn read_config_file() -> anyhow::Result<FileConfig> {
+    let (path, must_exist): (String, bool) = match std::env::var("CONFIG_FILE") {
+        Ok(path) => (path, true),
+        Err(_e) => (DEFAULT_CONFIG_FILE.to_string(), false)
+    };
+
+    let contents: String = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(_e) if !must_exist => return Ok(FileConfig::default()),
+        Err(e) => anyhow::bail!("Could not read config file {}: {}", path, e)
+    };
+
+    toml::from_str::<FileConfig>(&contents)
+        .map_err(|e| anyhow::anyhow!("Could not parse config file {}: {}", path, e))
+}
+
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const REQUIRED_ENV: [(&str, &str); 5] = [
+        ("DATABASE_URL", "mysql://localhost/crankword"),
+        ("CLIENT_ID", "crankword"),
+        ("CLIENT_SECRET", "client-secret"),
+        ("JWT_SECRET", "jwt-secret"),
+        ("HASHID_SECRET", "hashid-secret"),
+    ];
+
+    // Every required setting, plus (or overridden by) extra_env
+    fn load_with(toml: &str, extra_env: &[(&str, &str)]) -> anyhow::Result<AppConfig> {
+        let env_map: HashMap<String, String> = REQUIRED_ENV
+            .iter()
+            .chain(extra_env.iter())
+            .map(|(name, value): &(&str, &str)| (name.to_string(), value.to_string()))
+            .collect();
+
+        let file_config: FileConfig = toml::from_str(toml).unwrap();
+        AppConfig::from_sources(file_config, &|env_name: &str| env_map.get(env_name).cloned())
+    }
+
+    fn load_error(extra_env: &[(&str, &str)]) -> String {
+        match load_with("", extra_env) {
+            Ok(_app_config) => panic!("config should not load"),
+            Err(e) => e.to_string()
+        }
+    }
+
+    #[test]
+    fn defaults_fill_in_optional_settings() {
+        let app_config: AppConfig = load_with("", &[]).unwrap();
+
+        assert_eq!(app_config.server.bind_address, "127.0.0.1");
+        assert_eq!(app_config.server.port, 8080);
+        assert_eq!(app_config.database.max_connections, 10);
+        assert!(app_config.auth.provider == AuthProviderKind::Http);
+        assert!(!app_config.auth.cookie_secure);
+        assert_eq!(app_config.limits.max_current_games, 3);
+        assert_eq!(app_config.limits.max_invites, 10);
+        assert_eq!(app_config.limits.max_players, 6);
+    }
+
+    #[test]
+    fn env_wins_over_the_file() {
+        let toml: &str = "[server]\nport = 9000\n[limits]\nmax_players = 4\n";
+
+        let file_only: AppConfig = load_with(toml, &[]).unwrap();
+        assert_eq!(file_only.server.port, 9000);
+        assert_eq!(file_only.limits.max_players, 4);
+
+        let env_too: AppConfig = load_with(toml, &[("PORT", "9100")]).unwrap();
+        assert_eq!(env_too.server.port, 9100);
+        assert_eq!(env_too.limits.max_players, 4);
+    }
+
+    #[test]
+    fn every_missing_setting_is_reported_at_once() {
+        let file_config: FileConfig = FileConfig::default();
+        let error: String = match AppConfig::from_sources(file_config, &|_env_name: &str| None) {
+            Ok(_app_config) => panic!("config should not load"),
+            Err(e) => e.to_string()
+        };
+
+        for (env_name, _value) in REQUIRED_ENV {
+            assert!(error.contains(&format!("{} is not set", env_name)), "{}", error);
+        }
+    }
+
+    #[test]
+    fn bad_values_are_rejected() {
+        assert!(load_error(&[("PORT", "eighty")]).contains("PORT is invalid"));
+        assert!(load_error(&[("AUTH_PROVIDER", "ldap")]).contains("AUTH_PROVIDER is invalid"));
+        assert!(load_error(&[("WORKERS", "0")]).contains("WORKERS must be at least 1"));
+        assert!(load_error(&[("MAX_PLAYERS", "1")]).contains("MAX_PLAYERS must be at least 2"));
+        assert!(load_error(&[("JWT_SECRET", "")]).contains("JWT_SECRET must not be empty"));
+    }
+
+    #[test]
+    fn unknown_file_settings_are_rejected() {
+        assert!(toml::from_str::<FileConfig>("[limits]\nmax_playerz = 4\n").is_err());
+    }
+}
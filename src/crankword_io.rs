@@ -1,8 +1,8 @@
-/* 
- * 
- * 
- * 
- * 
+/*
+ *
+ *
+ *
+ *
  * ====================================
  * ====================================
  * ===============      ===============
@@ -10,17 +10,17 @@
  * ===============      ===============
  * ====================================
  * ====================================
- * 
- * 
+ *
+ *
  * Functions for interacting with the auth_site
- * 
- * 
+ *
+ *
 */
 
 use reqwest::Client;
 
 use crate::{
-    auth_code_shared::{ 
+    auth_code_shared::{
         AuthCodeRequest,
         AuthCodeResponse,
         AuthCodeSuccess,
@@ -30,111 +30,107 @@ use crate::{
         SendVerificationEmailRequest,
         SendVerificationEmailResponse
     },
-    auth::{ UserReqData }
+    auth_provider::AuthProvider
 };
 
 
-pub async fn check_auth_code(
-    client_auth_data: AuthCodeRequest,
-) -> anyhow::Result<AuthCodeSuccess> {
-    // Use a reqwest Client for POST request
-    let client: Client = Client::new();
-    let response: reqwest::Response = client
-        //.post("http://auth.localhost.test:3000/ext_auth/verify_auth_code")
-        .post("https://crankade.com/ext_auth/verify_auth_code") // todo: put this in resources file
-        .json(&client_auth_data)
-        .send()
-        .await?;
-
-    let bytes: actix_web::web::Bytes = response.bytes().await?;
-
-    // Try to parse as JSON
-    let parsed: Result<AuthCodeResponse, _> = serde_json::from_slice(&bytes);
-
-    // If failed to parse, print bytes as string
-    if let Err(error) = parsed {
-        println!("Failed to parse: {:?}", error);
-        println!("Raw response: {}", String::from_utf8_lossy(&bytes));
-        anyhow::bail!("Failed to parse AuthCodeResponse: {:?}", error);
-    }
-    
-    match parsed.unwrap() {
-        AuthCodeResponse::Ok(success) => Ok(success),
-        AuthCodeResponse::Err(err) => anyhow::bail!(err.message),
-    }
-}
+// The real auth_app. Override with AUTH_BASE_URL in config (ie: http://auth.localhost.test:3000)
+pub const DEFAULT_AUTH_BASE_URL: &str = "https://crankade.com";
+
 
 /**
- * When the user's JWT runs out we get their refresh_token and send to the auth_app,
- * which checks that it's valid and non-expired.
+ * Talks to an external auth_app over HTTP.
+ * Every endpoint hangs off the same base URL.
  */
-pub async fn check_refresh_code(refresh_request: &RefreshCheckRequest)
-    -> anyhow::Result<RefreshCheckSuccess>
-{
-    // Use a reqwest Client for POST request
-    let client: Client = Client::new();
-    let response: RefreshCheckResponse = client
-        .post("https://crankade.com/ext_auth/check_refresh") // todo: put this in resources file
-        //.post("http://auth.localhost.test:3000/ext_auth/check_refresh") // put this in resources file
-        .json(&refresh_request)
-        .send()
-        .await?
-        .json::<RefreshCheckResponse>()
-        .await?;
-
-    match response {
-        RefreshCheckResponse::Ok(success) => { Ok(success) }
-        RefreshCheckResponse::Err(err) => anyhow::bail!(err.message)
+pub struct HttpAuthProvider {
+    base_url: String,
+    client: Client,
+}
+
+impl HttpAuthProvider {
+    pub fn new(base_url: &str) -> Self {
+        HttpAuthProvider {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client: Client::new()
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
     }
 }
 
 
-/**
- * This should only work when user is logged in
- */
-pub async fn send_email_verification_request(
-    user_req_data: &UserReqData
-) -> anyhow::Result<SendVerificationEmailResponse> {
-
-    let user_id: i32 = match (user_req_data.logged_in, user_req_data.id) {
-        (true, Some(id)) => id,
-        _ => anyhow::bail!("USER NOT LOGGED IN")
-    };
-
-    let client_id: String =
-        match std::env::var("CLIENT_ID") {
-            Ok(secret) => secret,
-            Err(_e) => {
-                eprintln!("ERROR: NO CLIENT ID.");
-                anyhow::bail!("NO CLIENT ID")
-            }
-        };
-
-    let client_secret: String =
-        match std::env::var("CLIENT_SECRET") {
-            Ok(secret) => secret,
-            Err(_e) => {
-                eprintln!("ERROR: NO CLIENT SECRET.");
-                anyhow::bail!("ERROR: NO CLIENT SECRET.")
-            }
-        };
-
-    let email_req: SendVerificationEmailRequest =
-        SendVerificationEmailRequest{
-            client_id, client_secret, user_id,
-            username: user_req_data.get_username()
-        };
-
-    // Use a reqwest Client for POST request
-    let client: Client = Client::new();
-    let response: SendVerificationEmailResponse = client
-        .post("https://crankade.com/ext_auth/req_ver_email") // todo: put this in resources file
-        //.post("http://auth.localhost.test:3000/ext_auth/check_refresh") // put this in resources file
-        .json(&email_req)
-        .send()
-        .await?
-        .json::<SendVerificationEmailResponse>()
-        .await?;
-
-    Ok(response)
+impl AuthProvider for HttpAuthProvider {
+    async fn check_auth_code(
+        &self,
+        client_auth_data: AuthCodeRequest,
+    ) -> anyhow::Result<AuthCodeSuccess> {
+        let response: reqwest::Response = self.client
+            .post(self.url("/ext_auth/verify_auth_code"))
+            .json(&client_auth_data)
+            .send()
+            .await?;
+
+        let bytes: actix_web::web::Bytes = response.bytes().await?;
+
+        // Try to parse as JSON
+        let parsed: Result<AuthCodeResponse, _> = serde_json::from_slice(&bytes);
+
+        // If failed to parse, print bytes as string
+        if let Err(error) = parsed {
+            println!("Failed to parse: {:?}", error);
+            println!("Raw response: {}", String::from_utf8_lossy(&bytes));
+            anyhow::bail!("Failed to parse AuthCodeResponse: {:?}", error);
+        }
+
+        match parsed.unwrap() {
+            AuthCodeResponse::Ok(success) => Ok(success),
+            AuthCodeResponse::Err(err) => anyhow::bail!(err.message),
+        }
+    }
+
+    /**
+     * When the user's JWT runs out we get their refresh_token and send to the auth_app,
+     * which checks that it's valid and non-expired.
+     */
+    async fn check_refresh_code(&self, refresh_request: &RefreshCheckRequest)
+        -> anyhow::Result<RefreshCheckSuccess>
+    {
+        let response: RefreshCheckResponse = self.client
+            .post(self.url("/ext_auth/check_refresh"))
+            .json(&refresh_request)
+            .send()
+            .await?
+            .json::<RefreshCheckResponse>()
+            .await?;
+
+        match response {
+            RefreshCheckResponse::Ok(success) => { Ok(success) }
+            RefreshCheckResponse::Err(err) => anyhow::bail!(err.message)
+        }
+    }
+
+    async fn send_email_verification_request(
+        &self,
+        email_req: SendVerificationEmailRequest
+    ) -> anyhow::Result<SendVerificationEmailResponse> {
+        let response: SendVerificationEmailResponse = self.client
+            .post(self.url("/ext_auth/req_ver_email"))
+            .json(&email_req)
+            .send()
+            .await?
+            .json::<SendVerificationEmailResponse>()
+            .await?;
+
+        Ok(response)
+    }
+
+    fn login_url(&self) -> String {
+        self.url("/auth/login")
+    }
+
+    fn register_url(&self) -> String {
+        self.url("/auth/register")
+    }
 }
@@ -2,18 +2,23 @@ extern crate rand;
 // import commonly used items from the prelude:
 use anyhow::{ Result, anyhow };
 use serde::Serialize;
-use sqlx::{ MySqlPool, MySql, Transaction };
+use sqlx::{ MySqlConnection, MySqlPool, MySql, Transaction };
 use rand::Rng;
-use time::{ OffsetDateTime };
+use time::{ Date, OffsetDateTime };
 
 use crate::{
     auth,
+    bans::BanScope,
+    bots::{ self, BotLevel },
+    utils::{ self, SupportedLangs },
     words_solutions,
     game_logic::{
         self,
+        GameEventKind,
         GameStatus,
         GuessAndScore,
         LetterScore,
+        PlayerRating,
         WordlessScore
     }
 };
@@ -79,12 +84,16 @@ pub struct GameItemData {
     pub id: i32,
     pub game_status: String,
     pub winner_id: Option<i32>,
+    pub word_length: u8,
+    pub game_lang: String,
     pub created_timestamp: OffsetDateTime,
 }
 
 pub struct GameLinkData {
     pub hashid: String,
     pub game_status: String,
+    pub word_length: u8,
+    pub game_lang: SupportedLangs,
     pub age_string: String,
 }
 
@@ -93,6 +102,8 @@ pub struct GameLinkData {
 pub struct RawOpenGame {
     pub id: i32,
     pub owner_username: String,
+    pub word_length: u8,
+    pub game_lang: String,
     pub created_timestamp: OffsetDateTime,
 }
 
@@ -103,12 +114,88 @@ struct Count {
 }
 
 
+// A player's attempt at one day's challenge
+pub struct DailyGame {
+    pub game_id: i32,
+    pub solved: i8,
+    pub finished_timestamp: Option<OffsetDateTime>,
+}
+
+// One finished daily attempt, for the leaderboard
+pub struct RawDailyResult {
+    pub username: String,
+    pub solved: i8,
+    pub guesses_used: u8,
+    pub created_timestamp: OffsetDateTime,
+    pub finished_timestamp: Option<OffsetDateTime>,
+}
+
+struct DailyDate {
+    challenge_date: Date,
+}
+
+
 
 #[derive(Serialize)]
 pub struct PlayerStats {
     pub wins: u32,
+    pub losses: u32,
     pub past_games: u32,
     pub cancelled_games: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub guess_distribution: Vec<GuessDistributionRow>,
+}
+
+// raw DB data from user_game_stats to populate PlayerStats
+struct RawUserGameStats {
+    wins: i32,
+    losses: i32,
+    cancelled: i32,
+    current_streak: i32,
+    best_streak: i32,
+}
+
+// How many games a user won on one guess number
+#[derive(Serialize)]
+pub struct GuessDistributionRow {
+    pub guess_number: u8,
+    pub wins: i32,
+}
+
+
+impl PlayerStats {
+    fn new(
+        raw_stats: &RawUserGameStats,
+        guess_distribution: Vec<GuessDistributionRow>
+    ) -> PlayerStats {
+        let wins: u32 = raw_stats.wins.max(0) as u32;
+        let losses: u32 = raw_stats.losses.max(0) as u32;
+        let cancelled_games: u32 = raw_stats.cancelled.max(0) as u32;
+
+        PlayerStats {
+            wins,
+            losses,
+            past_games: wins + losses + cancelled_games,
+            cancelled_games,
+            current_streak: raw_stats.current_streak.max(0) as u32,
+            best_streak: raw_stats.best_streak.max(0) as u32,
+            guess_distribution
+        }
+    }
+
+    // For a user who hasn't finished or cancelled any games yet
+    pub fn empty() -> PlayerStats {
+        PlayerStats {
+            wins: 0,
+            losses: 0,
+            past_games: 0,
+            cancelled_games: 0,
+            current_streak: 0,
+            best_streak: 0,
+            guess_distribution: Vec::new()
+        }
+    }
 }
 
 pub struct InviteeUsername {
@@ -120,6 +207,8 @@ pub struct InviteeUsername {
 pub struct RawGame {
     pub id: i32,
     pub word: String,
+    pub word_length: u8,
+    pub game_lang: String,
     pub game_status: String,
     pub owner_id: i32,
     pub owner_username: String,
@@ -134,6 +223,9 @@ pub struct RawGame {
 pub struct Game {
     pub id: i32,
     pub word: String,
+    pub word_length: u8,
+    // which word lists the solution and guesses come from
+    pub game_lang: SupportedLangs,
     pub game_status: GameStatus,
     pub owner_id: i32,
     pub owner_username: String,
@@ -144,9 +236,60 @@ pub struct Game {
     pub open_game: bool,
 }
 
+// An in-progress game whose current turn has run out of time
+pub struct TimedOutTurn {
+    pub id: i32,
+    pub word_length: u8,
+    pub max_guesses: u8,
+    pub turn_seconds: u16,
+    pub turn_user_id: Option<i32>,
+    pub turn_timeout: OffsetDateTime,
+}
+
+// An in-progress game where it's a bot's turn to play
+pub struct BotTurn {
+    pub game_id: i32,
+    pub bot_id: i32,
+    pub bot_level: Option<String>,
+}
+
+// A player's rating going into a game. No rating means they've never played a rated game.
+struct RawPlayerRating {
+    user_id: i32,
+    rating: Option<i32>,
+}
+
+// How one rated game changed a player's rating
+pub struct RatingHistoryItem {
+    pub rating_before: i32,
+    pub rating_after: i32,
+    pub created_timestamp: OffsetDateTime,
+}
+
+// raw DB data for one game's settings to populate GameSettings
+pub struct RawGameSettings {
+    pub game_id: i32,
+    pub max_guesses: u8,
+    pub turn_seconds: u16,
+    pub max_players: u8,
+    pub hard_mode: i8,
+}
+
+// Rules for one game. The owner may change them until the game starts.
+#[derive(Serialize)]
+pub struct GameSettings {
+    pub game_id: i32,
+    pub max_guesses: u8,
+    pub turn_seconds: u16,
+    pub max_players: u8,
+    pub hard_mode: bool,
+}
+
 pub struct Guess {
     pub id: i64,
     pub word: String,
+    // encoded (see game_logic::encode_score)
+    pub score: String,
     pub game_id: i32,
     pub user_id: i32,
     pub guess_number: i8,
@@ -154,6 +297,20 @@ pub struct Guess {
 }
 
 
+// One guess's score (no word), for building everybody's scores from one query
+pub struct GuessScoreRow {
+    pub user_id: i32,
+    pub score: String,
+}
+
+
+// A player who left a game, named by whatever they did in it (see get_departed_players)
+struct DepartedPlayerRow {
+    user_id: i32,
+    username: Option<String>,
+}
+
+
 /* Simple identifiers for a Player  */
 #[derive(PartialEq, Serialize)]
 pub struct PlayerInfo {
@@ -176,6 +333,95 @@ pub struct GameAndPlayers {
 }
 
 
+/*
+ * ADMIN
+ */
+
+// One row of the admin area's game lists
+pub struct AdminGameItem {
+    pub id: i32,
+    pub game_status: String,
+    pub owner_username: String,
+    pub word_length: u8,
+    pub game_lang: String,
+    pub player_count: i64,
+    pub created_timestamp: OffsetDateTime,
+}
+
+/**
+ * What the admin area can search games by. Every filter is optional.
+ * created_after/created_before come from the age the admin asked for.
+ */
+pub struct AdminGameSearch {
+    pub game_status: Option<String>,
+    pub owner_username: Option<String>,
+    pub created_after: Option<OffsetDateTime>,
+    pub created_before: Option<OffsetDateTime>,
+}
+
+pub struct Block {
+    pub blocker_username: String,
+    pub blocked_username: String,
+}
+
+/**
+ * An admin's suspension of a user (see bans.rs).
+ * Active until it expires (never, if there's no expiry) or is lifted.
+ */
+pub struct Ban {
+    pub id: i32,
+    pub username: String,
+    pub ban_scope: String,
+    pub reason: String,
+    pub banned_by_username: String,
+    pub expires_timestamp: Option<OffsetDateTime>,
+    pub lifted_timestamp: Option<OffsetDateTime>,
+    pub created_timestamp: OffsetDateTime,
+}
+
+// One entry in a game's event log (see record_game_event)
+pub struct GameEvent {
+    pub event_type: String,
+    pub actor_id: Option<i32>,
+    pub actor_username: Option<String>,
+    pub target_username: Option<String>,
+    pub details: Option<String>,
+    pub created_timestamp: OffsetDateTime,
+}
+
+// A live update another server instance published (see live_updates::run_live_relay)
+pub struct RelayedLiveEvent {
+    pub id: i64,
+    pub channel: String,
+    pub payload: String,
+}
+
+// One entry in the admin log (see log_admin_action)
+pub struct AdminAction {
+    pub admin_username: String,
+    pub action: String,
+    pub game_id: Option<i32>,
+    pub target_username: Option<String>,
+    pub details: Option<String>,
+    pub created_timestamp: OffsetDateTime,
+}
+
+
+impl PlayerInfo {
+    // The logged-in user, as the actor in a game event.
+    // Fails for anybody who isn't logged in, so the log never gets a made-up actor.
+    pub fn from_user(user_req_data: &auth::UserReqData) -> Result<PlayerInfo> {
+        match (user_req_data.id, &user_req_data.username) {
+            (Some(user_id), Some(username)) => Ok(PlayerInfo {
+                user_id,
+                username: username.to_owned()
+            }),
+            _ => Err(anyhow!("Only a logged-in user can act in a game"))
+        }
+    }
+}
+
+
 impl GameAndPlayers {
 
     pub fn owner_name(&self) -> Option<&String> {
@@ -195,6 +441,8 @@ impl Game {
         Game {
             id: raw_game.id,
             word: raw_game.word.to_owned(),
+            word_length: raw_game.word_length,
+            game_lang: SupportedLangs::from(&raw_game.game_lang),
             game_status: GameStatus::from_string(&raw_game.game_status),
             owner_id: raw_game.owner_id,
             owner_username: raw_game.owner_username.to_owned(),
@@ -208,12 +456,27 @@ impl Game {
 }
 
 
+impl GameSettings {
+    pub fn new(raw_settings: &RawGameSettings) -> Self {
+        GameSettings {
+            game_id: raw_settings.game_id,
+            max_guesses: raw_settings.max_guesses,
+            turn_seconds: raw_settings.turn_seconds,
+            max_players: raw_settings.max_players,
+            hard_mode: raw_settings.hard_mode == 1
+        }
+    }
+}
+
+
 impl GameItemData {
     pub fn new_from(item: &GameItemData) -> GameItemData {
         GameItemData {
             id: item.id,
             game_status: item.game_status.to_owned(),
             winner_id: item.winner_id,
+            word_length: item.word_length,
+            game_lang: item.game_lang.to_owned(),
             created_timestamp: item.created_timestamp
         }
     }
@@ -308,13 +571,6 @@ pub async fn is_blocked(
 
 
 
-/**
- * Get winning word from given game_id
- */
-pub async fn get_winning_word(pool: &MySqlPool, game_id: i32) -> Result<String> {
-    let game: Game = get_game_by_id(pool, game_id).await?;
-    Ok(game.word)
-}
 
 
 /**
@@ -356,7 +612,7 @@ pub async fn get_invitations_by_username(
 pub async fn get_guesses(pool: &MySqlPool, game_id: i32, user_id: i32) -> Result<Vec<Guess>> {
     let guesses: Vec<Guess> = sqlx::query_as!(
         Guess,
-        "SELECT id, game_id, word, guess_number, user_id, created_timestamp FROM guesses
+        "SELECT id, game_id, word, score, guess_number, user_id, created_timestamp FROM guesses
             WHERE user_id = ? AND game_id = ?
             ORDER BY guess_number ASC",
         user_id, game_id
@@ -366,13 +622,31 @@ pub async fn get_guesses(pool: &MySqlPool, game_id: i32, user_id: i32) -> Result
 }
 
 
+/**
+ * Every guess in a game from every player, in the order they were played.
+ * Players who quit or were booted keep their guesses, so they're here too.
+ * Includes the words, so only use this once the game is over.
+ */
+pub async fn get_all_guesses(pool: &MySqlPool, game_id: i32) -> Result<Vec<Guess>> {
+    let guesses: Vec<Guess> = sqlx::query_as!(
+        Guess,
+        "SELECT id, game_id, word, score, guess_number, user_id, created_timestamp FROM guesses
+            WHERE game_id = ?
+            ORDER BY id ASC",
+        game_id
+    ).fetch_all(pool).await?;
+
+    Ok(guesses)
+}
+
+
 /**
  * Get all games which are marked "open".
  */
 pub async fn get_open_games(pool: &MySqlPool) -> Result<Vec<RawOpenGame>> {
     let games: Vec<RawOpenGame> = sqlx::query_as!(
         RawOpenGame,
-        "SELECT id, owner_username, created_timestamp FROM games
+        "SELECT id, owner_username, word_length, game_lang, created_timestamp FROM games
             WHERE open_game = ? AND game_status = ?
             ORDER BY created_timestamp DESC LIMIT 50",
             1, GameStatus::PreGame.to_string()
@@ -383,69 +657,279 @@ pub async fn get_open_games(pool: &MySqlPool) -> Result<Vec<RawOpenGame>> {
 
 
 /**
- * We're not directly calling the DB here.
- * Instead, we're calling other DB functions to collect some data and return it.
- * 
- * 1. get all words guessed by this user for this game
- * 2. get a vector of LetterScore structs for each guess
- * 3. return a vec of all those vecs
+ * Admin game search. Newest first, at most 100.
+ */
+pub async fn search_games(pool: &MySqlPool, search: &AdminGameSearch) -> Result<Vec<AdminGameItem>> {
+    let games: Vec<AdminGameItem> = sqlx::query_as!(
+        AdminGameItem,
+        r#"
+            SELECT g.id, g.game_status, g.owner_username, g.word_length, g.game_lang,
+                (SELECT COUNT(*) FROM game_users gu WHERE gu.game_id = g.id) AS "player_count!",
+                g.created_timestamp
+            FROM games g
+            WHERE (? IS NULL OR g.game_status = ?)
+                AND (? IS NULL OR g.owner_username LIKE CONCAT('%', ?, '%'))
+                AND (? IS NULL OR g.created_timestamp >= ?)
+                AND (? IS NULL OR g.created_timestamp <= ?)
+            ORDER BY g.created_timestamp DESC LIMIT 100
+        "#,
+        search.game_status, search.game_status,
+        search.owner_username, search.owner_username,
+        search.created_after, search.created_after,
+        search.created_before, search.created_before
+    ).fetch_all(pool).await?;
+
+    Ok(games)
+}
+
+
+/**
+ * Every game a user has been a player in, newest first.
+ */
+pub async fn get_games_by_username(pool: &MySqlPool, username: &str) -> Result<Vec<AdminGameItem>> {
+    let games: Vec<AdminGameItem> = sqlx::query_as!(
+        AdminGameItem,
+        r#"
+            SELECT g.id, g.game_status, g.owner_username, g.word_length, g.game_lang,
+                (SELECT COUNT(*) FROM game_users gu2 WHERE gu2.game_id = g.id) AS "player_count!",
+                g.created_timestamp
+            FROM games g
+            JOIN game_users gu ON gu.game_id = g.id
+            WHERE gu.username = ?
+            ORDER BY g.created_timestamp DESC
+        "#,
+        username
+    ).fetch_all(pool).await?;
+
+    Ok(games)
+}
+
+
+/**
+ * Blocks the user made, and blocks against the user.
+ */
+pub async fn get_blocks_involving(pool: &MySqlPool, username: &str) -> Result<Vec<Block>> {
+    let blocks: Vec<Block> = sqlx::query_as!(
+        Block,
+        "SELECT blocker_username, blocked_username FROM blocks
+            WHERE blocker_username = ? OR blocked_username = ?
+            ORDER BY blocker_username, blocked_username",
+        username,
+        username
+    ).fetch_all(pool).await?;
+
+    Ok(blocks)
+}
+
+
+/**
+ * Bans which haven't expired or been lifted. Longest-lasting first.
+ */
+pub async fn get_active_bans(pool: &MySqlPool, username: &str) -> Result<Vec<Ban>> {
+    let bans: Vec<Ban> = sqlx::query_as!(
+        Ban,
+        "SELECT id, username, ban_scope, reason, banned_by_username,
+            expires_timestamp, lifted_timestamp, created_timestamp
+            FROM bans
+            WHERE username = ? AND lifted_timestamp IS NULL
+                AND (expires_timestamp IS NULL OR expires_timestamp > UTC_TIMESTAMP())
+            ORDER BY expires_timestamp IS NULL DESC, expires_timestamp DESC",
+        username
+    ).fetch_all(pool).await?;
+
+    Ok(bans)
+}
+
+
+pub async fn get_ban_by_id(pool: &MySqlPool, ban_id: i32) -> Result<Option<Ban>> {
+    let ban_option: Option<Ban> = sqlx::query_as!(
+        Ban,
+        "SELECT id, username, ban_scope, reason, banned_by_username,
+            expires_timestamp, lifted_timestamp, created_timestamp
+            FROM bans
+            WHERE id = ?",
+        ban_id
+    ).fetch_optional(pool).await?;
+
+    Ok(ban_option)
+}
+
+
+/**
+ * Every ban the user has ever had, newest first.
+ */
+pub async fn get_bans_by_username(pool: &MySqlPool, username: &str) -> Result<Vec<Ban>> {
+    let bans: Vec<Ban> = sqlx::query_as!(
+        Ban,
+        "SELECT id, username, ban_scope, reason, banned_by_username,
+            expires_timestamp, lifted_timestamp, created_timestamp
+            FROM bans
+            WHERE username = ?
+            ORDER BY id DESC",
+        username
+    ).fetch_all(pool).await?;
+
+    Ok(bans)
+}
+
+
+/**
+ * A game's whole history, oldest first.
+ */
+pub async fn get_game_events(pool: &MySqlPool, game_id: i32) -> Result<Vec<GameEvent>> {
+    let events: Vec<GameEvent> = sqlx::query_as!(
+        GameEvent,
+        "SELECT event_type, actor_id, actor_username, target_username, details, created_timestamp
+            FROM game_events
+            WHERE game_id = ?
+            ORDER BY id ASC",
+        game_id
+    ).fetch_all(pool).await?;
+
+    Ok(events)
+}
+
+
+/**
+ * Live updates the other instances published in the last few seconds.
+ * Rows can commit a little out of id order, so we look back by time
+ * and the relay skips the ones it already delivered.
+ */
+pub async fn get_recent_live_events(
+    pool: &MySqlPool,
+    instance_id: &str,
+    seconds: u32
+) -> Result<Vec<RelayedLiveEvent>> {
+    let events: Vec<RelayedLiveEvent> = sqlx::query_as!(
+        RelayedLiveEvent,
+        "SELECT id, channel, payload FROM live_events
+            WHERE instance_id != ?
+            AND created_timestamp >= TIMESTAMPADD(SECOND, ?, UTC_TIMESTAMP())
+            ORDER BY id ASC",
+        instance_id,
+        -(seconds as i64)
+    ).fetch_all(pool).await?;
+
+    Ok(events)
+}
+
+
+pub async fn get_admin_actions(pool: &MySqlPool, limit: u32) -> Result<Vec<AdminAction>> {
+    let actions: Vec<AdminAction> = sqlx::query_as!(
+        AdminAction,
+        "SELECT admin_username, action, game_id, target_username, details, created_timestamp
+            FROM admin_actions
+            ORDER BY id DESC LIMIT ?",
+        limit
+    ).fetch_all(pool).await?;
+
+    Ok(actions)
+}
+
+
+/**
+ * The user's attempt at the given day's challenge, if they made one.
+ */
+pub async fn get_daily_game(
+    pool: &MySqlPool,
+    challenge_date: Date,
+    user_id: i32
+) -> Result<Option<DailyGame>> {
+    let daily_game: Option<DailyGame> = sqlx::query_as!(
+        DailyGame,
+        "SELECT game_id, solved, finished_timestamp FROM daily_games
+            WHERE challenge_date = ? AND user_id = ?",
+        challenge_date, user_id
+    ).fetch_optional(pool).await?;
+
+    Ok(daily_game)
+}
+
+
+/**
+ * Everybody who finished the given day's challenge.
+ * Solvers first, then fewest guesses, then fastest.
+ */
+pub async fn get_daily_results(
+    pool: &MySqlPool,
+    challenge_date: Date
+) -> Result<Vec<RawDailyResult>> {
+    let results: Vec<RawDailyResult> = sqlx::query_as!(
+        RawDailyResult,
+        "SELECT username, solved, guesses_used, created_timestamp, finished_timestamp
+            FROM daily_games
+            WHERE challenge_date = ? AND finished_timestamp IS NOT NULL
+            ORDER BY solved DESC, guesses_used ASC,
+                TIMESTAMPDIFF(SECOND, created_timestamp, finished_timestamp) ASC",
+        challenge_date
+    ).fetch_all(pool).await?;
+
+    Ok(results)
+}
+
+
+/**
+ * Every day the user solved the challenge, most recent first.
+ * For working out their streak.
+ */
+pub async fn get_daily_solved_dates(pool: &MySqlPool, user_id: i32) -> Result<Vec<Date>> {
+    let daily_dates: Vec<DailyDate> = sqlx::query_as!(
+        DailyDate,
+        "SELECT challenge_date FROM daily_games
+            WHERE user_id = ? AND solved = ?
+            ORDER BY challenge_date DESC",
+        user_id, 1
+    ).fetch_all(pool).await?;
+
+    Ok(daily_dates.iter().map(|daily_date| daily_date.challenge_date).collect())
+}
+
+
+/**
+ * All of this user's guesses in this game, with their scores.
+ * Scores were saved with the guesses, so the solution is never loaded.
  */
 pub async fn get_guess_scores(
     pool: &MySqlPool,
     game_id: i32,
     user_id: i32
 ) -> Result<Vec<game_logic::GuessAndScore>> {
-    let the_game: Game = get_game_by_id(pool, game_id).await?;
     let guesses: Vec<Guess> = get_guesses(pool, game_id, user_id).await?;
-    let all_scores: Vec<game_logic::GuessAndScore> = 
+    let all_scores: Vec<game_logic::GuessAndScore> =
         guesses
-        .iter()
-        .map(
-            |guess| {
-                let word: String = guess.word.to_string();
-                let score: Vec<LetterScore> =
-                    game_logic::check_guess(
-                        &guess.word, &the_game.word
-                    ).score;
-
-                GuessAndScore {word, score}
-            }
-        ).collect();
+        .into_iter()
+        .map(|guess: Guess| GuessAndScore {
+            score: game_logic::decode_score(&guess.score),
+            word: guess.word
+        })
+        .collect();
 
     Ok(all_scores)
 }
 
 /**
- * We're not directly calling the DB here.
- * Instead, we're calling other DB functions to collect some data and return it.
  * We want to ONLY deliver the score, because this is FOR DISPLAY for the other players.
- * 
- * 1. get all words guessed by this user for this game
- * 2. get a vector of LetterScore structs for each guess
- * 3. return a vec of all those vecs
  */
 pub async fn get_wordless_guess_scores(
     pool: &MySqlPool,
-    the_game: &Game,
+    game_id: i32,
     user_id: i32
 ) -> Result<Vec<game_logic::WordlessScore>> {
-    // Get the full guess so we can get the score
-    let guesses: Vec<Guess> = get_guesses(pool, the_game.id, user_id).await?;
-    // Deliver only the score, without the word
-    let all_scores: Vec<game_logic::WordlessScore> = 
-        guesses
+    let guess_scores: Vec<GuessScoreRow> = sqlx::query_as!(
+        GuessScoreRow,
+        "SELECT user_id, score FROM guesses
+            WHERE user_id = ? AND game_id = ?
+            ORDER BY guess_number ASC",
+        user_id, game_id
+    ).fetch_all(pool).await?;
+
+    let all_scores: Vec<game_logic::WordlessScore> =
+        guess_scores
         .iter()
-        .map(
-            |guess| {
-                let score: Vec<LetterScore> =
-                    game_logic::check_guess(
-                        &guess.word, &the_game.word
-                    ).score;
-
-                game_logic::WordlessScore { score }
-            }
-                
-        ).collect();
+        .map(|guess_score: &GuessScoreRow| game_logic::WordlessScore {
+            score: game_logic::decode_score(&guess_score.score)
+        })
+        .collect();
 
     Ok(all_scores)
 }
@@ -522,32 +1006,12 @@ pub async fn get_current_games_count(pool: &MySqlPool, user_id: i32) -> Result<u
 }
 
 
-
-pub async fn get_guess_count(pool: &MySqlPool, game_id: i32, user_id: i32) -> Result<u8> {
-    let count_option: Option<Count> = match sqlx::query_as!(
-        Count,
-        "SELECT COUNT(*) as count FROM guesses WHERE game_id = ? AND user_id = ?",
-        game_id,
-        user_id
-    ).fetch_optional(pool).await {
-        Ok(count) => count,
-        Err(e) => {
-            eprintln!("Failed to fetch guesses count from DB: {:?}", e);
-            return Err(anyhow!("Could not fetch guesses count: {e}"));
-        }
-    };
-
-    let count: u8 = count_option.unwrap_or(Count{count: 0}).count as u8;
-
-    Ok(count)
-}
-
 pub async fn get_game_by_id(pool: &MySqlPool, game_id: i32) -> Result<Game> {
     // RawGame gets the string from game_status, all to populate Game which takes an enum.
     let raw_game: RawGame = sqlx::query_as!(
         RawGame,
-        "SELECT id, word, game_status, owner_id, owner_username, winner_id, open_game,
-            turn_user_id, turn_timeout, created_timestamp FROM games
+        "SELECT id, word, word_length, game_lang, game_status, owner_id, owner_username,
+            winner_id, open_game, turn_user_id, turn_timeout, created_timestamp FROM games
             WHERE id = ?",
         game_id
     ).fetch_one(pool).await?;
@@ -555,6 +1019,17 @@ pub async fn get_game_by_id(pool: &MySqlPool, game_id: i32) -> Result<Game> {
     Ok(Game::new(&raw_game))
 }
 
+pub async fn get_game_settings(pool: &MySqlPool, game_id: i32) -> Result<GameSettings> {
+    let raw_settings: RawGameSettings = sqlx::query_as!(
+        RawGameSettings,
+        "SELECT game_id, max_guesses, turn_seconds, max_players, hard_mode FROM game_settings
+            WHERE game_id = ?",
+        game_id
+    ).fetch_one(pool).await?;
+
+    Ok(GameSettings::new(&raw_settings))
+}
+
 pub async fn get_owner_username(pool: &MySqlPool, game_id: i32) -> Result<String> {
     let owner_name_obj: OwnerUsername = sqlx::query_as!(
         OwnerUsername,
@@ -578,6 +1053,35 @@ pub async fn get_players_by_game_id(pool: &MySqlPool, game_id: i32) -> Result<Ve
 }
 
 
+/**
+ * Players who guessed in a game but aren't in it any more (they quit or were booted).
+ * game_users has forgotten them, so their usernames come from the game_events
+ * they were the actor of (everybody created or joined the game, at least).
+ */
+pub async fn get_departed_players(pool: &MySqlPool, game_id: i32) -> Result<Vec<PlayerInfo>> {
+    let departed_rows: Vec<DepartedPlayerRow> = sqlx::query_as!(
+        DepartedPlayerRow,
+        "SELECT DISTINCT g.user_id, e.actor_username AS username
+            FROM guesses g
+            JOIN game_events e ON e.game_id = g.game_id AND e.actor_id = g.user_id
+            WHERE g.game_id = ?
+                AND g.user_id NOT IN (SELECT user_id FROM game_users WHERE game_id = ?)",
+        game_id,
+        game_id
+    ).fetch_all(pool).await?;
+
+    let mut departed_players: Vec<PlayerInfo> = Vec::new();
+    for row in departed_rows {
+        let Some(username) = row.username else { continue };
+        if !departed_players.iter().any(|player: &PlayerInfo| player.user_id == row.user_id) {
+            departed_players.push(PlayerInfo { user_id: row.user_id, username });
+        }
+    }
+
+    Ok(departed_players)
+}
+
+
 /**
  * This gets the WORDLESS guess scores along with basic player info
  * for displaying OPPONENT info on player's page during in-progress games.
@@ -586,7 +1090,8 @@ pub async fn get_players_refresh_data_by_game_id(
     pool: &MySqlPool,
     game: &Game
 ) -> Result<Vec<PlayerRefreshData>> {
-    // First just get the PlayerInfo
+    // Two queries however many players there are: the players, then every guess in the game.
+    // Players who left keep their guesses, so only count the ones still in the game.
     let player_info_vec: Vec<PlayerInfo> = sqlx::query_as!(
         PlayerInfo,
         "SELECT user_id, username FROM game_users WHERE game_id = ?
@@ -594,20 +1099,33 @@ pub async fn get_players_refresh_data_by_game_id(
         game.id
     ).fetch_all(pool).await?;
 
-    let mut players_refresh_data: Vec<PlayerRefreshData> = Vec::new();
-
-    for player_info in player_info_vec {
-        let scores: Vec<WordlessScore> = match get_wordless_guess_scores(pool, &game, player_info.user_id).await {
-            Ok(scores) => scores,
-            Err(_) => Vec::new()
-        };
+    let guess_scores: Vec<GuessScoreRow> = sqlx::query_as!(
+        GuessScoreRow,
+        "SELECT g.user_id, g.score FROM guesses g
+            JOIN game_users gu ON gu.game_id = g.game_id AND gu.user_id = g.user_id
+            WHERE g.game_id = ?
+            ORDER BY g.guess_number ASC",
+        game.id
+    ).fetch_all(pool).await?;
 
-        players_refresh_data.push(PlayerRefreshData {
-            user_id: player_info.user_id,
-            username: player_info.username,
-            scores,
-        });
-    }
+    let players_refresh_data: Vec<PlayerRefreshData> = player_info_vec
+        .into_iter()
+        .map(|player_info: PlayerInfo| {
+            let scores: Vec<WordlessScore> = guess_scores
+                .iter()
+                .filter(|guess_score: &&GuessScoreRow| guess_score.user_id == player_info.user_id)
+                .map(|guess_score: &GuessScoreRow| WordlessScore {
+                    score: game_logic::decode_score(&guess_score.score)
+                })
+                .collect();
+
+            PlayerRefreshData {
+                user_id: player_info.user_id,
+                username: player_info.username,
+                scores,
+            }
+        })
+        .collect();
 
     Ok(players_refresh_data)
 }
@@ -629,7 +1147,7 @@ pub async fn get_games_byid(
     let games: Vec<GameItemData> = sqlx::query_as!(
         GameItemData,
         r#"
-            SELECT g.id, g.game_status, g.winner_id, g.created_timestamp
+            SELECT g.id, g.game_status, g.winner_id, g.word_length, g.game_lang, g.created_timestamp
             FROM games g
             JOIN game_users gu ON g.id = gu.game_id
             WHERE gu.user_id = ?
@@ -643,45 +1161,290 @@ pub async fn get_games_byid(
 }
 
 /**
- * Get basic data for all of user's current games.
+ * A user's stored stats (see record_game_stats).
  */
-pub async fn get_games_by_username(
+pub async fn get_player_stats(pool: &MySqlPool, user_id: i32) -> Result<PlayerStats> {
+    let raw_stats_option: Option<RawUserGameStats> = sqlx::query_as!(
+        RawUserGameStats,
+        "SELECT wins, losses, cancelled, current_streak, best_streak
+            FROM user_game_stats WHERE user_id = ?",
+        user_id
+    ).fetch_optional(pool).await?;
+
+    let raw_stats: RawUserGameStats = match raw_stats_option {
+        Some(raw_stats) => raw_stats,
+        None => return Ok(PlayerStats::empty())
+    };
+
+    let guess_distribution: Vec<GuessDistributionRow> = sqlx::query_as!(
+        GuessDistributionRow,
+        "SELECT guess_number, wins FROM user_guess_distribution
+            WHERE user_id = ? ORDER BY guess_number ASC",
+        user_id
+    ).fetch_all(pool).await?;
+
+    Ok(PlayerStats::new(&raw_stats, guess_distribution))
+}
+
+
+/**
+ * Same as get_player_stats, for the profile page which only knows the username.
+ * None if the user has never finished or cancelled a game.
+ */
+pub async fn get_player_stats_by_username(
     pool: &MySqlPool,
     username: &String
-) -> Result<Vec<GameItemData>> {
-    let games: Vec<GameItemData> = sqlx::query_as!(
-        GameItemData,
-        r#"
-            SELECT g.id, g.game_status, g.winner_id, g.created_timestamp
-            FROM games g
-            JOIN game_users gu ON g.id = gu.game_id
-            WHERE gu.username = ?
-        "#,
+) -> Result<Option<PlayerStats>> {
+    let raw_stats_option: Option<RawUserGameStats> = sqlx::query_as!(
+        RawUserGameStats,
+        "SELECT wins, losses, cancelled, current_streak, best_streak
+            FROM user_game_stats WHERE username = ?",
         username
-    )
-    .fetch_all(pool)
-    .await?;
+    ).fetch_optional(pool).await?;
 
-    Ok(games)
+    let raw_stats: RawUserGameStats = match raw_stats_option {
+        Some(raw_stats) => raw_stats,
+        None => return Ok(None)
+    };
+
+    let guess_distribution: Vec<GuessDistributionRow> = sqlx::query_as!(
+        GuessDistributionRow,
+        "SELECT ugd.guess_number, ugd.wins FROM user_guess_distribution ugd
+            JOIN user_game_stats ugs ON ugs.user_id = ugd.user_id
+            WHERE ugs.username = ? ORDER BY ugd.guess_number ASC",
+        username
+    ).fetch_all(pool).await?;
+
+    Ok(Some(PlayerStats::new(&raw_stats, guess_distribution)))
 }
 
 
+/**
+ * The human players in a game (bots don't have stats or ratings).
+ */
+async fn get_human_players(
+    conn: &mut MySqlConnection,
+    game_id: i32
+) -> Result<Vec<PlayerInfo>> {
+    let player_info_vec: Vec<PlayerInfo> = sqlx::query_as!(
+        PlayerInfo,
+        "SELECT user_id, username FROM game_users
+            WHERE game_id = ? AND bot_level IS NULL
+            ORDER BY turn_order ASC",
+        game_id
+    ).fetch_all(&mut *conn).await?;
+
+    Ok(player_info_vec)
+}
+
 
 /**
- * Check if any players still have turns left to play.
+ * Add a finished game to every human player's stats.
+ * The winner gets a win, a longer streak, and a count in their guess distribution.
+ * Everybody else gets a loss and their streak ends.
+ * Runs in the transaction that finishes the game (see record_finished_game).
  */
-pub async fn somebody_can_play(pool: &MySqlPool, game_id: i32) -> Result<bool> {
-    let players: Vec<PlayerInfo> = get_players_by_game_id(pool, game_id).await?;
+async fn record_game_stats(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    winner_id_option: Option<i32>
+) -> Result<()> {
+    let players: Vec<PlayerInfo> = get_human_players(&mut *conn, game_id).await?;
+    let winner_guess_count_option: Option<u8> = match winner_id_option {
+        Some(winner_id) if winner_id > 0 => Some(sqlx::query_as!(
+            Count,
+            "SELECT COUNT(*) as count FROM guesses WHERE game_id = ? AND user_id = ?",
+            game_id,
+            winner_id
+        ).fetch_one(&mut *conn).await?.count as u8),
+        _ => None
+    };
+
     for player in players {
-        let guess_count: u8 = get_guess_count(pool, game_id, player.user_id).await?;
-        if guess_count < game_logic::MAX_TURNS {
-            return Ok(true)
+        if Some(player.user_id) == winner_id_option {
+            // assignments run left to right, so best_streak sees the new current_streak
+            sqlx::query(
+                "INSERT INTO user_game_stats (user_id, username, wins, current_streak, best_streak)
+                    VALUES (?, ?, 1, 1, 1)
+                    ON DUPLICATE KEY UPDATE
+                        wins = wins + 1,
+                        current_streak = current_streak + 1,
+                        best_streak = GREATEST(best_streak, current_streak)")
+                .bind(player.user_id)
+                .bind(&player.username)
+                .execute(&mut *conn)
+                .await?;
+
+            if let Some(guess_count) = winner_guess_count_option {
+                sqlx::query(
+                    "INSERT INTO user_guess_distribution (user_id, guess_number, wins)
+                        VALUES (?, ?, 1)
+                        ON DUPLICATE KEY UPDATE wins = wins + 1")
+                    .bind(player.user_id)
+                    .bind(guess_count)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+        } else {
+            sqlx::query(
+                "INSERT INTO user_game_stats (user_id, username, losses)
+                    VALUES (?, ?, 1)
+                    ON DUPLICATE KEY UPDATE losses = losses + 1, current_streak = 0")
+                .bind(player.user_id)
+                .bind(&player.username)
+                .execute(&mut *conn)
+                .await?;
         }
     }
 
-    Ok(false)
+    Ok(())
+}
+
+
+
+/**
+ * Find every multi-player in-progress game whose turn timer has run out.
+ * Single-player games have no turn timer, so they are left out.
+ */
+pub async fn get_timed_out_turns(pool: &MySqlPool) -> Result<Vec<TimedOutTurn>> {
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let timed_out_turns: Vec<TimedOutTurn> = sqlx::query_as!(
+        TimedOutTurn,
+        "SELECT g.id, g.word_length, gs.max_guesses, gs.turn_seconds,
+            g.turn_user_id, g.turn_timeout FROM games g
+            JOIN game_settings gs ON gs.game_id = g.id
+            WHERE g.game_status = ? AND g.turn_timeout <= ?
+            AND g.turn_user_id IS NOT NULL
+            AND (SELECT COUNT(*) FROM game_users gu WHERE gu.game_id = g.id) > 1",
+        GameStatus::InProgress.to_string(),
+        now
+    ).fetch_all(pool).await?;
+
+    Ok(timed_out_turns)
+}
+
+
+/**
+ * Get in-progress games where it's a bot's turn,
+ * and the turn began before the given time (so bots don't answer instantly).
+ */
+pub async fn get_bot_turns(
+    pool: &MySqlPool,
+    turn_started_before: OffsetDateTime
+) -> Result<Vec<BotTurn>> {
+    let bot_turns: Vec<BotTurn> = sqlx::query_as!(
+        BotTurn,
+        "SELECT g.id AS game_id, gu.user_id AS bot_id, gu.bot_level FROM games g
+            JOIN game_users gu ON gu.game_id = g.id AND gu.user_id = g.turn_user_id
+            JOIN game_settings gs ON gs.game_id = g.id
+            WHERE g.game_status = ? AND gu.bot_level IS NOT NULL
+            AND TIMESTAMPADD(SECOND, -gs.turn_seconds, g.turn_timeout) <= ?",
+        GameStatus::InProgress.to_string(),
+        turn_started_before
+    ).fetch_all(pool).await?;
+
+    Ok(bot_turns)
+}
+
+
+/**
+ * Current ratings of the human players in a game.
+ * Bots are left out: they aren't rated and don't affect anybody's rating.
+ */
+async fn get_game_ratings(conn: &mut MySqlConnection, game_id: i32) -> Result<Vec<PlayerRating>> {
+    let raw_ratings: Vec<RawPlayerRating> = sqlx::query_as!(
+        RawPlayerRating,
+        r#"SELECT gu.user_id, ur.rating AS "rating?" FROM game_users gu
+            LEFT JOIN user_ratings ur ON ur.user_id = gu.user_id
+            WHERE gu.game_id = ? AND gu.bot_level IS NULL"#,
+        game_id
+    ).fetch_all(&mut *conn).await?;
+
+    let player_ratings: Vec<PlayerRating> = raw_ratings
+        .iter()
+        .map(|raw_rating: &RawPlayerRating| PlayerRating {
+            user_id: raw_rating.user_id,
+            rating: raw_rating.rating.unwrap_or(game_logic::DEFAULT_RATING)
+        })
+        .collect();
+
+    Ok(player_ratings)
+}
+
+
+/**
+ * Save everybody's new rating after a game, with a history row for each.
+ * old_ratings and new_ratings must be in the same order (see game_logic::get_new_ratings).
+ */
+async fn save_new_ratings(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    old_ratings: &[PlayerRating],
+    new_ratings: &[PlayerRating]
+) -> Result<()> {
+    for (old_rating, new_rating) in old_ratings.iter().zip(new_ratings) {
+        sqlx::query(
+            "INSERT INTO user_ratings (user_id, rating) VALUES (?, ?)
+                ON DUPLICATE KEY UPDATE rating = VALUES(rating)")
+            .bind(new_rating.user_id)
+            .bind(new_rating.rating)
+            .execute(&mut *conn)
+            .await?;
+
+        sqlx::query(
+            "INSERT INTO rating_history (user_id, game_id, rating_before, rating_after)
+                VALUES (?, ?, ?, ?)")
+            .bind(new_rating.user_id)
+            .bind(game_id)
+            .bind(old_rating.rating)
+            .bind(new_rating.rating)
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
+
+/**
+ * A user's most recent rating changes, newest first.
+ */
+pub async fn get_rating_history(pool: &MySqlPool, user_id: i32) -> Result<Vec<RatingHistoryItem>> {
+    let history: Vec<RatingHistoryItem> = sqlx::query_as!(
+        RatingHistoryItem,
+        "SELECT rating_before, rating_after, created_timestamp FROM rating_history
+            WHERE user_id = ?
+            ORDER BY id DESC LIMIT ?",
+        user_id,
+        utils::RATING_HISTORY_LENGTH
+    ).fetch_all(pool).await?;
+
+    Ok(history)
+}
+
+
+/**
+ * Same as get_rating_history, for the profile page which only knows the username.
+ */
+pub async fn get_rating_history_by_username(
+    pool: &MySqlPool,
+    username: &String
+) -> Result<Vec<RatingHistoryItem>> {
+    let history: Vec<RatingHistoryItem> = sqlx::query_as!(
+        RatingHistoryItem,
+        "SELECT rh.rating_before, rh.rating_after, rh.created_timestamp FROM rating_history rh
+            JOIN game_users gu ON gu.game_id = rh.game_id AND gu.user_id = rh.user_id
+            WHERE gu.username = ?
+            ORDER BY rh.id DESC LIMIT ?",
+        username,
+        utils::RATING_HISTORY_LENGTH
+    ).fetch_all(pool).await?;
+
+    Ok(history)
 }
 
+
+
 /* 
  * 
  * 
@@ -700,53 +1463,52 @@ pub async fn somebody_can_play(pool: &MySqlPool, game_id: i32) -> Result<bool> {
  * 
 */
 
-pub async fn new_guess(
-    pool: &MySqlPool,
-    user_id: i32,
-    game_id: i32,
-    guess_word: &str,
-    guess_number: u8
-) -> Result<i64, anyhow::Error> {
-    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-        "INSERT INTO guesses (
-            game_id, word, guess_number, user_id)
-            VALUES (?, ?, ?, ?)")
-        .bind(game_id)
-        .bind(guess_word)
-        .bind(guess_number)
-        .bind(user_id)
-        .execute(pool).await.map_err(|e| {
-            eprintln!("Failed to save GUESS to database: {:?}", e);
-            anyhow!("Could not save GUESS to database: {e}")
-    })?;
-
-    Ok(result.last_insert_id() as i64)
-}
 
 pub async fn new_game(
     pool: &MySqlPool,
     user_req_data: &auth::UserReqData,
-    open_game_bool: bool
+    open_game_bool: bool,
+    word_length: u8,
+    game_lang: &SupportedLangs,
+    max_players: u8
 ) -> Result<i32, anyhow::Error> {
     // get word
-    let word: String = words_solutions::get_random_word();
+    let word: String = words_solutions::get_random_word(word_length, game_lang);
     let open_game_int: i32 = if open_game_bool { 1 } else { 0 };
 
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
     let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
         "INSERT INTO games (
-            word, owner_id, owner_username, open_game)
-            VALUES (?, ?, ?, ?)")
+            word, word_length, game_lang, owner_id, owner_username, open_game)
+            VALUES (?, ?, ?, ?, ?, ?)")
         .bind(word)
+        .bind(word_length)
+        .bind(game_lang.suffix())
         .bind(user_req_data.id)
         .bind(user_req_data.get_username())
         .bind(open_game_int)
-        .execute(pool).await.map_err(|e| {
+        .execute(&mut *tx).await.map_err(|e| {
             eprintln!("Failed to save game to database: {:?}", e);
             anyhow!("Could not save game to database: {e}")
     })?;
 
     let game_id: i32 = result.last_insert_id() as i32;
 
+    // Every game starts with the default settings, and as many players as the config allows
+    let _settings_result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+        "INSERT INTO game_settings (
+            game_id, max_guesses, turn_seconds, max_players)
+            VALUES (?, ?, ?, ?)")
+        .bind(game_id)
+        .bind(game_logic::DEFAULT_MAX_GUESSES)
+        .bind(game_logic::DEFAULT_TURN_SECONDS)
+        .bind(max_players)
+        .execute(&mut *tx).await.map_err(|e| {
+            eprintln!("Failed to save game_settings to database: {:?}", e);
+            anyhow!("Could not save game_settings to database: {e}")
+    })?;
+
     // Now put owner_id in game_users table
     let game_users_result: sqlx::mysql::MySqlQueryResult = sqlx::query(
         "INSERT INTO game_users (
@@ -757,16 +1519,120 @@ pub async fn new_game(
         .bind(game_id)
         .bind(user_req_data.id)
         .bind(user_req_data.get_username())
-        .execute(pool).await.map_err(|e| {
+        .execute(&mut *tx).await.map_err(|e| {
             eprintln!("Failed to save game_user to database: {:?}", e);
             anyhow!("Could not save game_user to database: {e}")
     })?;
 
-    if game_users_result.rows_affected() > 0 {
-        Ok(game_id)
-    } else {
-        Err(anyhow!("Could not save game_user to database"))
+    if game_users_result.rows_affected() == 0 {
+        return Err(anyhow!("Could not save game_user to database"));
     }
+
+    let details: String = format!(
+        "open_game={} word_length={} game_lang={}",
+        open_game_bool,
+        word_length,
+        game_lang.suffix()
+    );
+    record_game_event(
+        &mut tx,
+        game_id,
+        GameEventKind::Created,
+        Some(&PlayerInfo::from_user(user_req_data)?),
+        None,
+        Some(&details)
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(game_id)
+}
+
+
+/**
+ * Start the user's attempt at the day's challenge.
+ * It's a solo game which skips the pre-game and goes straight to in-progress.
+ * Everything is one transaction: if the user already has an attempt for the day,
+ * the daily_games primary key rejects it and the new game is rolled back too.
+ */
+pub async fn new_daily_game(
+    pool: &MySqlPool,
+    user_req_data: &auth::UserReqData,
+    challenge_date: Date
+) -> Result<i32, anyhow::Error> {
+    let user_id: i32 = match user_req_data.id {
+        Some(id) => id,
+        None => return Err(anyhow!("No user id."))
+    };
+
+    let word: String = words_solutions::get_daily_word(challenge_date);
+    let turn_timeout: OffsetDateTime =
+        game_logic::get_turn_timeout(game_logic::DEFAULT_TURN_SECONDS);
+
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+        "INSERT INTO games (
+            word, word_length, game_status, owner_id, owner_username,
+            turn_user_id, turn_timeout, open_game)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)")
+        .bind(word)
+        .bind(game_logic::DEFAULT_WORD_LENGTH)
+        .bind(GameStatus::InProgress.to_string())
+        .bind(user_id)
+        .bind(user_req_data.get_username())
+        .bind(user_id)
+        .bind(turn_timeout)
+        .bind(0)
+        .execute(&mut *tx)
+        .await?;
+
+    let game_id: i32 = result.last_insert_id() as i32;
+
+    sqlx::query(
+        "INSERT INTO game_settings (
+            game_id, max_guesses, turn_seconds, max_players)
+            VALUES (?, ?, ?, ?)")
+        .bind(game_id)
+        .bind(game_logic::DEFAULT_MAX_GUESSES)
+        .bind(game_logic::DEFAULT_TURN_SECONDS)
+        .bind(1)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO game_users (game_id, user_id, username, turn_order)
+            VALUES (?, ?, ?, ?)")
+        .bind(game_id)
+        .bind(user_id)
+        .bind(user_req_data.get_username())
+        .bind(1)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO daily_games (challenge_date, user_id, username, game_id)
+            VALUES (?, ?, ?, ?)")
+        .bind(challenge_date)
+        .bind(user_id)
+        .bind(user_req_data.get_username())
+        .bind(game_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let details: String = format!("daily={}", challenge_date);
+    record_game_event(
+        &mut tx,
+        game_id,
+        GameEventKind::Created,
+        Some(&PlayerInfo::from_user(user_req_data)?),
+        None,
+        Some(&details)
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(game_id)
 }
 
 
@@ -778,164 +1644,838 @@ pub async fn user_join_game(
     user_req_data: &auth::UserReqData,
     game_id: i32
 ) -> Result<bool> {
-    if user_req_data.id.is_none() || user_req_data.get_role() == "guest" {
-        return Err(anyhow!("User is not valid"));
+    if user_req_data.id.is_none() || user_req_data.get_role() == "guest" {
+        return Err(anyhow!("User is not valid"));
+    }
+
+    // get the game and check that it's pregame
+    // make sure the user isn't already part of the game
+
+    let game: GameAndPlayers = get_game_and_players(pool, game_id).await?;
+    if game.game.game_status != GameStatus::PreGame {
+        return Err(anyhow!("Game already started"));
+    } else if game.user_id_is_player(user_req_data.id.unwrap()) {
+        return Err(anyhow!("User already joined game."));
+    }
+
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+        "INSERT INTO game_users (
+            game_id,
+            user_id,
+            username)
+            VALUES (?, ?, ?)")
+        .bind(game_id)
+        .bind(user_req_data.id.unwrap())
+        .bind(user_req_data.get_username())
+        .execute(&mut *tx).await.map_err(|e| {
+            eprintln!("Failed to save game_user to database: {:?}", e);
+            anyhow!("Could not save game_user to database: {e}")
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    record_game_event(
+        &mut tx,
+        game_id,
+        GameEventKind::Joined,
+        Some(&PlayerInfo::from_user(user_req_data)?),
+        None,
+        None
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+/**
+ * Owner adds a bot to a pre-game.
+ * Bots get the next unused negative user_id in the game,
+ * so they never clash with real users and never count toward anybody's current games.
+ */
+pub async fn add_bot(
+    pool: &MySqlPool,
+    owner: &auth::UserReqData,
+    game_id: i32,
+    bot_level: &BotLevel
+) -> Result<PlayerInfo> {
+    let game: GameAndPlayers = get_game_and_players(pool, game_id).await?;
+    if game.game.game_status != GameStatus::PreGame {
+        return Err(anyhow!("Game already started"));
+    }
+
+    let lowest_id: i32 = game.players
+        .iter()
+        .map(|player: &PlayerInfo| player.user_id)
+        .filter(|user_id: &i32| *user_id < 0)
+        .min()
+        .unwrap_or(0);
+
+    let bot_id: i32 = lowest_id - 1;
+    let username: String = bots::get_bot_username(bot_id, bot_level);
+
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
+    // If two requests race for the same id, the primary key rejects the second one
+    sqlx::query(
+        "INSERT INTO game_users (
+            game_id,
+            user_id,
+            username,
+            bot_level)
+            VALUES (?, ?, ?, ?)")
+        .bind(game_id)
+        .bind(bot_id)
+        .bind(&username)
+        .bind(bot_level.to_string())
+        .execute(&mut *tx).await.map_err(|e| {
+            eprintln!("Failed to save bot to database: {:?}", e);
+            anyhow!("Could not save bot to database: {e}")
+    })?;
+
+    // The owner added the bot, so the owner is the actor
+    let details: String = format!("bot_level={}", bot_level.to_string());
+    record_game_event(
+        &mut tx,
+        game_id,
+        GameEventKind::Joined,
+        Some(&PlayerInfo::from_user(owner)?),
+        Some(&username),
+        Some(&details)
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(PlayerInfo { user_id: bot_id, username })
+}
+
+
+/**
+ * Invite user to an existing game.
+ */
+pub async fn invite_user(
+    pool: &MySqlPool,
+    username: &String,
+    owner: &auth::UserReqData,
+    game_id: i32
+) -> Result<bool> {
+    // get the game and check that it's pregame
+    // make sure the user isn't already part of the game
+
+    let game: GameAndPlayers = get_game_and_players(pool, game_id).await?;
+    if game.game.game_status != GameStatus::PreGame {
+        return Err(anyhow!("Game already started"));
+    } else if game.username_is_player(username) {
+        return Err(anyhow!("User already joined game."));
+    }
+
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+        "INSERT INTO invites (
+            game_id,
+            username,
+            owner_name)
+            VALUES (?, ?, ?)")
+        .bind(game_id)
+        .bind(username)
+        .bind(owner.get_username())
+        .execute(&mut *tx).await.map_err(|e| {
+            eprintln!("Failed to save game_user to database: {:?}", e);
+            anyhow!("Could not save game_user to database: {e}")
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    record_game_event(
+        &mut tx,
+        game_id,
+        GameEventKind::Invited,
+        Some(&PlayerInfo::from_user(owner)?),
+        Some(username),
+        None
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+
+/**
+ * Returns false if the user was already blocked.
+ */
+pub async fn block_user(
+    pool: &MySqlPool,
+    blocker_username: &String,
+    blocked_username: &String
+) -> Result<bool, anyhow::Error> {
+    let insert_result: Result<sqlx::mysql::MySqlQueryResult, sqlx::Error> = sqlx::query(
+        "INSERT INTO blocks (
+            blocker_username, blocked_username)
+            VALUES (?, ?)")
+        .bind(blocker_username)
+        .bind(blocked_username)
+        .execute(pool).await;
+
+    match insert_result {
+        Ok(result) => Ok(result.rows_affected() > 0),
+        // duplicate key: already blocked
+        Err(sqlx::Error::Database(db_error)) if db_error.is_unique_violation() => Ok(false),
+        Err(e) => {
+            eprintln!("Failed to save block to database: {:?}", e);
+            Err(anyhow!("Could not save block to database: {e}"))
+        }
+    }
+}
+
+
+/**
+ * Append to a game's event log. Only ever called with the transaction
+ * that makes the change, so the log and the game can't disagree.
+ * No actor means the server did it (a turn timing out, a game running out of guesses).
+ */
+async fn record_game_event(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    event_kind: GameEventKind,
+    actor_option: Option<&PlayerInfo>,
+    target_username_option: Option<&str>,
+    details_option: Option<&str>
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO game_events (
+            game_id, event_type, actor_id, actor_username, target_username, details)
+            VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(game_id)
+        .bind(event_kind.to_string())
+        .bind(actor_option.map(|actor: &PlayerInfo| actor.user_id))
+        .bind(actor_option.map(|actor: &PlayerInfo| actor.username.as_str()))
+        .bind(target_username_option)
+        .bind(details_option)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+
+/**
+ * Suspend a user. No expiry means it lasts until an admin lifts it.
+ * Returns the new ban's id.
+ */
+pub async fn ban_user(
+    pool: &MySqlPool,
+    admin: &auth::UserReqData,
+    username: &str,
+    ban_scope: &BanScope,
+    reason: &str,
+    expires_option: Option<OffsetDateTime>
+) -> Result<i32> {
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+        "INSERT INTO bans (username, ban_scope, reason, banned_by_username, expires_timestamp)
+            VALUES (?, ?, ?, ?, ?)")
+        .bind(username)
+        .bind(ban_scope.to_string())
+        .bind(reason)
+        .bind(admin.get_username())
+        .bind(expires_option)
+        .execute(pool)
+        .await?;
+
+    Ok(result.last_insert_id() as i32)
+}
+
+
+/**
+ * End a ban early. False if it was already lifted (or doesn't exist).
+ */
+pub async fn lift_ban(pool: &MySqlPool, ban_id: i32) -> Result<bool> {
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+        "UPDATE bans SET lifted_timestamp = UTC_TIMESTAMP()
+            WHERE id = ? AND lifted_timestamp IS NULL")
+        .bind(ban_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+
+/**
+ * Share live updates with the other server instances.
+ * events are (channel, JSON) pairs, ie: ("game:12", "{\"event\":\"game_started\"}")
+ */
+pub async fn save_live_events(
+    pool: &MySqlPool,
+    instance_id: &str,
+    events: &[(String, String)]
+) -> Result<()> {
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
+    for (channel, payload) in events {
+        sqlx::query("INSERT INTO live_events (instance_id, channel, payload) VALUES (?, ?, ?)")
+            .bind(instance_id)
+            .bind(channel)
+            .bind(payload)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+
+/**
+ * Record something an admin did (see admin.rs).
+ */
+pub async fn log_admin_action(
+    pool: &MySqlPool,
+    admin: &auth::UserReqData,
+    action: &str,
+    game_id_option: Option<i32>,
+    target_username_option: Option<&str>,
+    details_option: Option<&str>
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO admin_actions (
+            admin_id, admin_username, action, game_id, target_username, details)
+            VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(admin.id)
+        .bind(admin.get_username())
+        .bind(action)
+        .bind(game_id_option)
+        .bind(target_username_option)
+        .bind(details_option)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+
+/* 
+ * 
+ * 
+ * 
+ * 
+ * ====================
+ * ====================
+ * =====          =====
+ * =====  UPDATE  =====
+ * =====          =====
+ * ====================
+ * ====================
+ * 
+ * 
+ * 
+ * 
+*/
+
+
+
+
+/**
+ * Pass the turn on from a player who can't take it (they're out of guesses).
+ * Returns None if it's no longer their turn (somebody else already moved it on).
+ */
+pub async fn next_turn(
+    pool: &MySqlPool,
+    game_id: i32,
+    current_user_id: i32
+) -> Result<Option<TurnPassed>> {
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+    let locked_game: LockedGame = lock_game(&mut tx, game_id).await?;
+
+    if locked_game.game_status != GameStatus::InProgress.to_string() ||
+        locked_game.turn_user_id != Some(current_user_id)
+    { return Ok(None); }
+
+    let current_player: PlayerInfo = get_player_in_game(&mut tx, game_id, current_user_id).await?
+        .ok_or_else(|| anyhow!("Player {} is not in game {}", current_user_id, game_id))?;
+    let turn_passed: TurnPassed =
+        advance_turn(&mut tx, game_id, current_user_id, &locked_game, true).await?;
+    record_turn_passed(&mut tx, game_id, None, &current_player, &turn_passed).await?;
+
+    tx.commit().await?;
+    Ok(Some(turn_passed))
+}
+
+
+
+/*
+ *
+ *
+ * TAKING A TURN
+ *
+ * A guess (or a timed-out player's dud) is saved, the turn passes on,
+ * and the game may finish, all in ONE transaction with the game row locked.
+ * So two quick submissions, a guess racing the timeout sweep, or two
+ * server instances can never save the same guess_number twice or skip a player.
+ * Whoever gets the lock second sees the turn has already moved on.
+ *
+ *
+*/
+
+
+// The game row (and its settings), read with FOR UPDATE
+struct LockedGame {
+    game_status: String,
+    turn_user_id: Option<i32>,
+    turn_timeout: OffsetDateTime,
+    max_guesses: u8,
+    turn_seconds: u16,
+}
+
+pub enum TurnKind {
+    // The current player guessed. is_winner if it's the solution.
+    Guess { is_winner: bool },
+    // The current player ran out of time. Only recorded if the turn is STILL timed out.
+    TimedOut,
+    // The current player gave up their turn (a bot with no word to play).
+    // Saved like a timeout, but right away and as the player's own doing.
+    Skipped,
+}
+
+pub enum TurnTaken {
+    NotInProgress,
+    WrongTurn,
+    MaxGuesses,
+    Recorded(RecordedTurn),
+}
+
+// Where the turn went when it was passed on
+pub enum TurnPassed {
+    To(i32),
+    // Nobody had guesses left, so the game finished with no winner
+    GameOver,
+}
+
+// What taking a player out of a game did to it
+pub enum PlayerRemoved {
+    NotInGame,
+    // It wasn't their turn, so nothing else changed
+    Removed,
+    // It was their turn, so it passed on (or the game ended)
+    TurnPassed(TurnPassed),
+}
+
+// A player and how many guesses they've used, in turn order
+struct TurnCandidate {
+    user_id: i32,
+    guess_count: i64,
+}
+
+pub struct RecordedTurn {
+    pub guess_number: u8,
+    // None when the game ended on this turn
+    pub next_turn_id: Option<i32>,
+    pub game_over: bool,
+    pub winner_id: Option<i32>,
+}
+
+
+async fn lock_game(conn: &mut MySqlConnection, game_id: i32) -> Result<LockedGame> {
+    let locked_game: LockedGame = sqlx::query_as!(
+        LockedGame,
+        "SELECT g.game_status, g.turn_user_id, g.turn_timeout, gs.max_guesses, gs.turn_seconds
+            FROM games g
+            JOIN game_settings gs ON gs.game_id = g.id
+            WHERE g.id = ?
+            FOR UPDATE",
+        game_id
+    ).fetch_one(&mut *conn).await?;
+
+    Ok(locked_game)
+}
+
+
+// The username that goes with a user_id in one game (bots' ids are only unique per game)
+async fn get_player_in_game(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    user_id: i32
+) -> Result<Option<PlayerInfo>> {
+    let player_option: Option<PlayerInfo> = sqlx::query_as!(
+        PlayerInfo,
+        "SELECT user_id, username FROM game_users WHERE game_id = ? AND user_id = ?",
+        game_id,
+        user_id
+    ).fetch_optional(&mut *conn).await?;
+
+    Ok(player_option)
+}
+
+
+/**
+ * Give the turn to the next player in turn order who still has guesses left,
+ * with a fresh turn timeout. The current player only gets it back if nobody
+ * else can play, and never if current_stays is false (they're leaving).
+ * If nobody can play at all, the game finishes with no winner.
+ */
+async fn advance_turn(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    current_user_id: i32,
+    locked_game: &LockedGame,
+    current_stays: bool
+) -> Result<TurnPassed> {
+    let candidates: Vec<TurnCandidate> = sqlx::query_as!(
+        TurnCandidate,
+        "SELECT gu.user_id,
+            (SELECT COUNT(*) FROM guesses g
+                WHERE g.game_id = gu.game_id AND g.user_id = gu.user_id) AS guess_count
+            FROM game_users gu WHERE gu.game_id = ?
+            ORDER BY gu.turn_order ASC",
+        game_id
+    ).fetch_all(&mut *conn).await?;
+
+    if candidates.is_empty() {
+        return Err(anyhow!("No players."));
+    }
+
+    // Look at everybody after the current player, wrapping around (so the current player comes last).
+    // If the current player isn't in the game, start from the first player.
+    let start_index: usize = candidates
+        .iter()
+        .position(|candidate: &TurnCandidate| candidate.user_id == current_user_id)
+        .map_or(0, |index: usize| index + 1);
+
+    let next_player_option: Option<&TurnCandidate> = (0..candidates.len())
+        .map(|offset: usize| &candidates[(start_index + offset) % candidates.len()])
+        .find(|candidate: &&TurnCandidate|
+            candidate.guess_count < locked_game.max_guesses as i64 &&
+            (current_stays || candidate.user_id != current_user_id));
+
+    let new_user_turn_id: i32 = match next_player_option {
+        Some(next_player) => next_player.user_id,
+        None => {
+            finish_in_progress_game(conn, game_id, None, None, "out of guesses").await?;
+            return Ok(TurnPassed::GameOver);
+        }
+    };
+
+    let turn_timeout: OffsetDateTime = game_logic::get_turn_timeout(locked_game.turn_seconds);
+
+    let _result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+    "UPDATE games SET turn_user_id = ?, turn_timeout = ? WHERE id = ?")
+        .bind(new_user_turn_id)
+        .bind(turn_timeout)
+        .bind(game_id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(TurnPassed::To(new_user_turn_id))
+}
+
+
+/**
+ * Log a turn that moved on without a guess (the player was out of guesses, or left).
+ * A game that ended instead already has its Finished event.
+ */
+async fn record_turn_passed(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    actor_option: Option<&PlayerInfo>,
+    from_player: &PlayerInfo,
+    turn_passed: &TurnPassed
+) -> Result<()> {
+    let next_turn_id: i32 = match turn_passed {
+        TurnPassed::To(next_turn_id) => *next_turn_id,
+        TurnPassed::GameOver => return Ok(())
+    };
+
+    let next_player: PlayerInfo = get_player_in_game(&mut *conn, game_id, next_turn_id).await?
+        .ok_or_else(|| anyhow!("Player {} is not in game {}", next_turn_id, game_id))?;
+
+    record_game_event(
+        conn,
+        game_id,
+        GameEventKind::TurnPassed,
+        actor_option,
+        Some(&next_player.username),
+        Some(&format!("from={}", from_player.username))
+    ).await
+}
+
+
+// Does anybody in the game besides user_id still have guesses left?
+async fn somebody_else_can_play(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    user_id: i32,
+    locked_game: &LockedGame
+) -> Result<bool> {
+    let players_with_turns: i64 = sqlx::query_as!(
+        Count,
+        "SELECT COUNT(*) as count FROM game_users gu
+            WHERE gu.game_id = ? AND gu.user_id != ?
+            AND (SELECT COUNT(*) FROM guesses g
+                WHERE g.game_id = gu.game_id AND g.user_id = gu.user_id) < ?",
+        game_id,
+        user_id,
+        locked_game.max_guesses
+    ).fetch_one(&mut *conn).await?.count;
+
+    Ok(players_with_turns > 0)
+}
+
+
+/**
+ * Finish an in-progress game and log it, inside the caller's transaction.
+ * Returns false if the game wasn't in progress (somebody else finished or cancelled it first),
+ * so a game is never finished twice.
+ * No actor means the game ended by itself (a winning guess, or nobody had guesses left).
+ */
+async fn finish_in_progress_game(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    winner_option: Option<&PlayerInfo>,
+    actor_option: Option<&PlayerInfo>,
+    details: &str
+) -> Result<bool> {
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+    "UPDATE games SET game_status = ?, winner_id = ? WHERE id = ? AND game_status = ?")
+        .bind(GameStatus::Finished.to_string())
+        .bind(winner_option.map(|winner: &PlayerInfo| winner.user_id))
+        .bind(game_id)
+        .bind(GameStatus::InProgress.to_string())
+        .execute(&mut *conn)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
     }
 
-    // get the game and check that it's pregame
-    // make sure the user isn't already part of the game
+    record_game_event(
+        conn,
+        game_id,
+        GameEventKind::Finished,
+        actor_option,
+        winner_option.map(|winner: &PlayerInfo| winner.username.as_str()),
+        Some(details)
+    ).await?;
+
+    record_finished_game(
+        conn,
+        game_id,
+        winner_option.map(|winner: &PlayerInfo| winner.user_id)
+    ).await?;
 
-    let game: GameAndPlayers = get_game_and_players(pool, game_id).await?;
-    if game.game.game_status != GameStatus::PreGame {
-        return Err(anyhow!("Game already started"));
-    } else if game.user_id_is_player(user_req_data.id.unwrap()) {
-        return Err(anyhow!("User already joined game."));
-    }
+    Ok(true)
+}
 
-    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-        "INSERT INTO game_users (
-            game_id,
-            user_id,
-            username)
-            VALUES (?, ?, ?)")
-        .bind(game_id)
-        .bind(user_req_data.id.unwrap())
-        .bind(user_req_data.get_username())
-        .execute(pool).await.map_err(|e| {
-            eprintln!("Failed to save game_user to database: {:?}", e);
-            anyhow!("Could not save game_user to database: {e}")
-    })?;
 
-    Ok(result.rows_affected() > 0 )
+/**
+ * Daily challenge results, player stats and ratings are all updated here.
+ * Only called by finish_in_progress_game, in the same transaction,
+ * so a game is finished with all of them or not at all.
+ */
+async fn record_finished_game(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    winner_id_option: Option<i32>
+) -> Result<()> {
+    let _daily_recorded: bool =
+        record_daily_result(&mut *conn, game_id, winner_id_option.is_some()).await?;
+    record_game_stats(&mut *conn, game_id, winner_id_option).await?;
+    update_ratings(&mut *conn, game_id, winner_id_option).await?;
+    Ok(())
 }
 
+
 /**
- * Invite user to an existing game.
+ * Rate everybody in a finished game against each other.
+ * Solo games don't count: that includes the daily challenge
+ * and games with only one human against bots (bots aren't rated).
  */
-pub async fn invite_user(
-    pool: &MySqlPool,
-    username: &String,
-    owner_name: &String,
-    game_id: i32
-) -> Result<bool> {
-    // get the game and check that it's pregame
-    // make sure the user isn't already part of the game
+async fn update_ratings(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    winner_id_option: Option<i32>
+) -> Result<()> {
+    let player_ratings: Vec<PlayerRating> = get_game_ratings(&mut *conn, game_id).await?;
 
-    let game: GameAndPlayers = get_game_and_players(pool, game_id).await?;
-    if game.game.game_status != GameStatus::PreGame {
-        return Err(anyhow!("Game already started"));
-    } else if game.username_is_player(username) {
-        return Err(anyhow!("User already joined game."));
+    if player_ratings.len() < 2 {
+        return Ok(());
     }
 
-    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-        "INSERT INTO invites (
-            game_id,
-            username,
-            owner_name)
-            VALUES (?, ?, ?)")
-        .bind(game_id)
-        .bind(username)
-        .bind(owner_name)
-        .execute(pool).await.map_err(|e| {
-            eprintln!("Failed to save game_user to database: {:?}", e);
-            anyhow!("Could not save game_user to database: {e}")
-    })?;
+    let new_ratings: Vec<PlayerRating> =
+        game_logic::get_new_ratings(&player_ratings, winner_id_option);
 
-    Ok(result.rows_affected() > 0 )
+    save_new_ratings(&mut *conn, game_id, &player_ratings, &new_ratings).await
 }
 
 
-pub async fn block_user(
+/**
+ * Save the current player's turn and move the game along:
+ * 1. lock the game and make sure it's still this player's turn
+ * 2. save the guess with the next guess_number
+ * 3. a winning guess finishes the game
+ * 4. otherwise pass the turn on to somebody with guesses left.
+ *    If nobody has any left, finish with no winner.
+ *
+ * A finished game's stats, ratings etc. are saved in the same transaction
+ * (see record_finished_game).
+ */
+pub async fn take_turn(
     pool: &MySqlPool,
-    blocker_username: &String,
-    blocked_username: &String
-) -> Result<bool, anyhow::Error> {
+    game_id: i32,
+    user_id: i32,
+    word: &str,
+    score: &[LetterScore],
+    turn_kind: TurnKind
+) -> Result<TurnTaken> {
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+    let locked_game: LockedGame = lock_game(&mut tx, game_id).await?;
+
+    // Returning early drops the transaction, which rolls it back and releases the lock.
+    if locked_game.game_status != GameStatus::InProgress.to_string() {
+        return Ok(TurnTaken::NotInProgress);
+    } else if locked_game.turn_user_id != Some(user_id) {
+        return Ok(TurnTaken::WrongTurn);
+    } else if matches!(turn_kind, TurnKind::TimedOut) &&
+        locked_game.turn_timeout > OffsetDateTime::now_utc()
+    {
+        // they guessed (or someone else timed them out) since the sweep looked
+        return Ok(TurnTaken::WrongTurn);
+    }
+
+    let guess_count: u8 = sqlx::query_as!(
+        Count,
+        "SELECT COUNT(*) as count FROM guesses WHERE game_id = ? AND user_id = ?",
+        game_id,
+        user_id
+    ).fetch_one(&mut *tx).await?.count as u8;
 
-    if blocked_username == blocker_username {
-        return Err(anyhow!("You cannot block yourself"))
+    if guess_count >= locked_game.max_guesses {
+        return Ok(TurnTaken::MaxGuesses);
     }
 
-    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-        "INSERT INTO blocks (
-            blocker_username, blocked_username)
-            VALUES (?, ?)")
-        .bind(blocker_username)
-        .bind(blocked_username)
-        .execute(pool).await.map_err(|e| {
+    let guess_number: u8 = guess_count + 1;
+
+    // uq_guesses_turn backs this up if anything ever gets past the lock.
+    // The score is saved too, so reading scores never needs the solution.
+    let _insert_result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+        "INSERT INTO guesses (
+            game_id, word, guess_number, user_id, score)
+            VALUES (?, ?, ?, ?, ?)")
+        .bind(game_id)
+        .bind(word)
+        .bind(guess_number)
+        .bind(user_id)
+        .bind(game_logic::encode_score(score))
+        .execute(&mut *tx).await.map_err(|e| {
             eprintln!("Failed to save GUESS to database: {:?}", e);
             anyhow!("Could not save GUESS to database: {e}")
     })?;
 
-    Ok(result.rows_affected() > 0)
-}
+    // A guess or a skip is the player's own doing. A timeout is the server's, done to the player.
+    let player: PlayerInfo = get_player_in_game(&mut tx, game_id, user_id).await?
+        .ok_or_else(|| anyhow!("Player {} is not in game {}", user_id, game_id))?;
+    let guess_details: String =
+        format!("word={} score={}", word, game_logic::encode_score(score));
 
+    match turn_kind {
+        TurnKind::Guess { .. } => record_game_event(
+            &mut tx,
+            game_id,
+            GameEventKind::Guessed,
+            Some(&player),
+            None,
+            Some(&guess_details)
+        ).await?,
+        TurnKind::TimedOut => record_game_event(
+            &mut tx,
+            game_id,
+            GameEventKind::TimedOut,
+            None,
+            Some(&player.username),
+            Some(&guess_details)
+        ).await?,
+        TurnKind::Skipped => record_game_event(
+            &mut tx,
+            game_id,
+            GameEventKind::TimedOut,
+            Some(&player),
+            Some(&player.username),
+            Some(&guess_details)
+        ).await?
+    }
 
-/* 
- * 
- * 
- * 
- * 
- * ====================
- * ====================
- * =====          =====
- * =====  UPDATE  =====
- * =====          =====
- * ====================
- * ====================
- * 
- * 
- * 
- * 
-*/
+    if let TurnKind::Guess { is_winner: true } = turn_kind {
+        finish_in_progress_game(&mut tx, game_id, Some(&player), None, "winner").await?;
+
+        tx.commit().await?;
+        return Ok(TurnTaken::Recorded(RecordedTurn {
+            guess_number,
+            next_turn_id: None,
+            game_over: true,
+            winner_id: Some(user_id)
+        }));
+    }
 
+    let turn_passed: TurnPassed =
+        advance_turn(&mut tx, game_id, user_id, &locked_game, true).await?;
 
+    tx.commit().await?;
 
+    Ok(TurnTaken::Recorded(RecordedTurn {
+        guess_number,
+        next_turn_id: match turn_passed {
+            TurnPassed::To(next_turn_id) => Some(next_turn_id),
+            TurnPassed::GameOver => None
+        },
+        game_over: matches!(turn_passed, TurnPassed::GameOver),
+        winner_id: None
+    }))
+}
 
 /**
- * Returns the id of the new current turn user.
+ * Owner changed the settings in the pre-game lobby.
+ * Only works while the game is still pre-game, so a game that
+ * started in the meantime keeps the settings it started with.
  */
-pub async fn next_turn(pool: &MySqlPool, game_id: i32) -> Result<i32> {
-    let players: Vec<PlayerInfo> = get_players_by_game_id(pool, game_id).await?;
-    let game: Game = get_game_by_id(pool, game_id).await?;
-    let current_user_id: i32 = match game.turn_user_id {
-        Some(id) => id,
-        None => return Err(anyhow!("No current turn."))
-    };
+pub async fn update_game_settings(pool: &MySqlPool, settings: &GameSettings) -> Result<bool> {
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+    "UPDATE game_settings gs JOIN games g ON g.id = gs.game_id
+        SET gs.max_guesses = ?, gs.turn_seconds = ?, gs.max_players = ?, gs.hard_mode = ?
+        WHERE gs.game_id = ? AND g.game_status = ?")
+        .bind(settings.max_guesses)
+        .bind(settings.turn_seconds)
+        .bind(settings.max_players)
+        .bind(settings.hard_mode)
+        .bind(settings.game_id)
+        .bind(GameStatus::PreGame.to_string())
+        .execute(pool)
+        .await?;
 
-    // get the vector index of the current turn
-    let mut index_count: usize = 0;
-    for player in &players {
-        if player.user_id == current_user_id {
-            break;
-        } else {
-            index_count += 1;
-        }
+    // MySQL reports zero rows when nothing changed, so check the game status instead
+    if result.rows_affected() > 0 {
+        return Ok(true);
     }
 
-    // increment that index and get THAT user_id
-    let vec_index_of_new_turn_id: usize =
-        if index_count >= (players.len() - 1) { 0 }
-        else { index_count + 1 };
-
-    let new_user_turn_id: i32 = players[vec_index_of_new_turn_id].user_id;
-    let turn_timeout: OffsetDateTime = game_logic::get_turn_timeout();
-
-    let _result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-    "UPDATE games SET turn_user_id = ?, turn_timeout = ? WHERE id = ?")
-        .bind(new_user_turn_id)
-        .bind(turn_timeout)
-        .bind(game_id)
-        .execute(pool)
-        .await?;            
-
-    Ok(new_user_turn_id)
+    let game: Game = get_game_by_id(pool, settings.game_id).await?;
+    Ok(game.game_status == GameStatus::PreGame)
 }
 
+
 /**
  * When transitioning a game from one stage to the next.
  */
-pub async fn start_game(pool: &MySqlPool, game_id: i32) -> Result<bool> {
+pub async fn start_game(
+    pool: &MySqlPool,
+    owner: &auth::UserReqData,
+    game_id: i32
+) -> Result<bool> {
     let mut turn_user_id: i32 = 0;
 
     // set turn orders. Get all players. Scramble their IDs. Scrambled index +1 becomes turn order.
@@ -950,6 +2490,11 @@ pub async fn start_game(pool: &MySqlPool, game_id: i32) -> Result<bool> {
         players.remove(index);
     }
 
+    let settings: GameSettings = get_game_settings(pool, game_id).await?;
+    let turn_timeout: OffsetDateTime = game_logic::get_turn_timeout(settings.turn_seconds);
+
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
     // Instead of using an index, just increment turn during the loop.
     let mut turn: i32 = 0;
 
@@ -961,104 +2506,212 @@ pub async fn start_game(pool: &MySqlPool, game_id: i32) -> Result<bool> {
             .bind(turn)
             .bind(game_id)
             .bind(user_id)
-            .execute(pool)
+            .execute(&mut *tx)
             .await?;
     }
 
-    let turn_timeout: OffsetDateTime = game_logic::get_turn_timeout();
-
     let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
     "UPDATE games SET game_status = ?, turn_user_id = ?, turn_timeout = ? WHERE id = ?")
         .bind(GameStatus::InProgress.to_string())
         .bind(turn_user_id)
         .bind(turn_timeout)
         .bind(game_id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?;
 
-    Ok(result.rows_affected() > 0)
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    // Keep the turn order, so a replay knows who went when
+    let turn_order: Vec<String> = scrambled_player_ids
+        .iter()
+        .map(|user_id: &i32| user_id.to_string())
+        .collect();
+    let details: String = format!("turn_order={}", turn_order.join(","));
+    record_game_event(
+        &mut tx,
+        game_id,
+        GameEventKind::Started,
+        Some(&PlayerInfo::from_user(owner)?),
+        None,
+        Some(&details)
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 
+/**
+ * If the finished game was a daily challenge, record how it went.
+ * Returns false if the game wasn't a daily challenge.
+ */
+async fn record_daily_result(
+    conn: &mut MySqlConnection,
+    game_id: i32,
+    solved: bool
+) -> Result<bool> {
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+    "UPDATE daily_games SET solved = ?, finished_timestamp = ?,
+        guesses_used = (SELECT COUNT(*) FROM guesses WHERE game_id = ?)
+        WHERE game_id = ? AND finished_timestamp IS NULL")
+        .bind(solved)
+        .bind(OffsetDateTime::now_utc())
+        .bind(game_id)
+        .bind(game_id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 /**
  * If we have a winner, send in Some(winner_id).
  * Else, everybody has lost.
+ * Stats, ratings and the daily result are saved in the same transaction.
  */
 pub async fn finish_game(
     pool: &MySqlPool,
     game_id: i32,
-    winner_id_option: Option<i32>
+    winner_id_option: Option<i32>,
+    actor: &PlayerInfo
 ) -> Result<u8> {
-    match winner_id_option {
-        Some(winner_id) => {
-            let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-            "UPDATE games SET game_status = ?, winner_id = ? WHERE id = ?")
-                .bind(GameStatus::Finished.to_string())
-                .bind(winner_id)
-                .bind(game_id)
-                .execute(pool)
-                .await?;
-            Ok(result.rows_affected() as u8)
-        }, None => {
-            let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-            "UPDATE games SET game_status = ? WHERE id = ?")
-                .bind(GameStatus::Finished.to_string())
-                .bind(game_id)
-                .execute(pool)
-                .await?;
-            Ok(result.rows_affected() as u8)
-        }
-    }
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
+    let winner_option: Option<PlayerInfo> = match winner_id_option {
+        Some(winner_id) => Some(
+            get_player_in_game(&mut tx, game_id, winner_id).await?
+                .ok_or_else(|| anyhow!("Winner {} is not in game {}", winner_id, game_id))?
+        ),
+        None => None
+    };
+
+    let details: &str = if winner_option.is_some() { "winner" } else { "no winner" };
+    let finished: bool = finish_in_progress_game(
+        &mut tx,
+        game_id,
+        winner_option.as_ref(),
+        Some(actor),
+        details
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(finished as u8)
 }
 
 /**
- * If we have a winner, send in Some(winner_id).
- * Else, everybody has lost.
+ * Cancel a pre-game or in-progress game.
+ * A cancelled daily challenge counts as that day's attempt (unsolved),
+ * so it can't be cancelled and started over.
+ * Returns false if the game was already over.
  */
 pub async fn cancel_game(
     pool: &MySqlPool,
-    game_id: i32
+    game_id: i32,
+    actor: &PlayerInfo
 ) -> Result<bool> {
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+    let players: Vec<PlayerInfo> = get_human_players(&mut tx, game_id).await?;
+
+    // A game which is already over can't be cancelled (or counted as cancelled) again
     let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-    "UPDATE games SET game_status = ? WHERE id = ?")
+    "UPDATE games SET game_status = ? WHERE id = ? AND game_status IN (?, ?)")
         .bind(GameStatus::Cancelled.to_string())
         .bind(game_id)
-        .execute(pool)
+        .bind(GameStatus::PreGame.to_string())
+        .bind(GameStatus::InProgress.to_string())
+        .execute(&mut *tx)
         .await?;
-    Ok(result.rows_affected() > 0)
+
+    if result.rows_affected() == 0 {
+        tx.rollback().await?;
+        return Ok(false);
+    }
+
+    // Cancelled games count toward past games, but don't touch wins, losses or streaks
+    for player in players {
+        sqlx::query(
+            "INSERT INTO user_game_stats (user_id, username, cancelled)
+                VALUES (?, ?, 1)
+                ON DUPLICATE KEY UPDATE cancelled = cancelled + 1")
+            .bind(player.user_id)
+            .bind(&player.username)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    record_daily_result(&mut tx, game_id, false).await?;
+    record_game_event(&mut tx, game_id, GameEventKind::Cancelled, Some(actor), None, None).await?;
+
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 /**
  * A user wants to quit, presumably because the game is dead and they need
  * to free up the space. So we will delete one game_users.
+ * Admins remove players this way too, so the event says which it was
+ * (Quit or Booted) and who did it. Their guesses stay (the replay still shows them),
+ * and reads of the live game only look at players still in game_users.
+ *
+ * If it was their turn, the turn passes on first (in the same transaction),
+ * so the game is never left waiting on somebody who isn't in it.
+ * If nobody else has guesses left (whoever's turn it was), the game finishes
+ * with no winner (and the leaving player still counts as having lost).
  * */
 pub async fn remove_player_from_game(
     pool: &MySqlPool,
     game_id: i32,
-    user_id: i32
-) -> Result<bool> {
+    user_id: i32,
+    event_kind: GameEventKind,
+    actor: &PlayerInfo
+) -> Result<PlayerRemoved> {
 
     let mut tx: Transaction<MySql> = pool.begin().await?;
+    let locked_game: LockedGame = lock_game(&mut tx, game_id).await?;
 
-    // Delete guesses for the player in the game
-    sqlx::query("DELETE FROM guesses WHERE game_id = ? AND user_id = ?")
-        .bind(game_id)
-        .bind(user_id)
-        .execute(&mut *tx)
+    let player: PlayerInfo = match get_player_in_game(&mut tx, game_id, user_id).await? {
+        Some(player) => player,
+        None => return Ok(PlayerRemoved::NotInGame)
+    };
+
+    record_game_event(&mut tx, game_id, event_kind, Some(actor), Some(&player.username), None)
         .await?;
 
+    let turn_passed_option: Option<TurnPassed> =
+        if locked_game.game_status != GameStatus::InProgress.to_string() {
+            None
+        } else if locked_game.turn_user_id == Some(user_id) {
+            let turn_passed: TurnPassed =
+                advance_turn(&mut tx, game_id, user_id, &locked_game, false).await?;
+            record_turn_passed(&mut tx, game_id, Some(actor), &player, &turn_passed).await?;
+            Some(turn_passed)
+        } else if !somebody_else_can_play(&mut tx, game_id, user_id, &locked_game).await? {
+            // It wasn't their turn, but they were the only one with guesses left
+            finish_in_progress_game(&mut tx, game_id, None, None, "out of guesses").await?;
+            Some(TurnPassed::GameOver)
+        } else {
+            None
+        };
+
     // Delete the user from the game_users table
-    let result: u64 = sqlx::query("DELETE FROM game_users WHERE game_id = ? AND user_id = ?")
+    sqlx::query("DELETE FROM game_users WHERE game_id = ? AND user_id = ?")
         .bind(game_id)
         .bind(user_id)
         .execute(&mut *tx)
-        .await?
-        .rows_affected();
+        .await?;
 
     // Commit the transaction
     tx.commit().await?;
 
-    Ok(result > 0)
+    Ok(match turn_passed_option {
+        Some(turn_passed) => PlayerRemoved::TurnPassed(turn_passed),
+        None => PlayerRemoved::Removed
+    })
 }
 
 
@@ -1095,26 +2748,48 @@ pub async fn delete_invite(
         Ok(result.rows_affected() > 0)
 }
 
-pub async fn delete_invites(
+/**
+ * Owner takes back an invitation.
+ * (A joining player's invitation is just deleted. Their Joined event covers it.)
+ */
+pub async fn uninvite_user(
     pool: &MySqlPool,
-    game_id: i32
-) -> Result<u8> {
+    owner: &auth::UserReqData,
+    game_id: i32,
+    username: &String
+) -> Result<bool> {
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
     let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-        "DELETE FROM invites WHERE game_id = ?")
+        "DELETE FROM invites WHERE game_id = ? AND username = ?")
         .bind(game_id)
-        .execute(pool)
+        .bind(username)
+        .execute(&mut *tx)
         .await?;
 
-        Ok(result.rows_affected() as u8)
-}
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    record_game_event(
+        &mut tx,
+        game_id,
+        GameEventKind::Uninvited,
+        Some(&PlayerInfo::from_user(owner)?),
+        Some(username),
+        None
+    ).await?;
+    tx.commit().await?;
 
+    Ok(true)
+}
 
-pub async fn delete_guesses(
+pub async fn delete_invites(
     pool: &MySqlPool,
     game_id: i32
 ) -> Result<u8> {
     let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
-        "DELETE FROM guesses WHERE game_id = ?")
+        "DELETE FROM invites WHERE game_id = ?")
         .bind(game_id)
         .execute(pool)
         .await?;
@@ -1123,19 +2798,33 @@ pub async fn delete_guesses(
 }
 
 
+/**
+ * Take a player out of a pre-game. event_kind says whether they Left or were Booted.
+ */
 pub async fn delete_user_from_game(
     pool: &MySqlPool,
     game_id: i32,
-    username: &String
+    username: &String,
+    event_kind: GameEventKind,
+    actor: &PlayerInfo
 ) -> Result<bool> {
+    let mut tx: Transaction<MySql> = pool.begin().await?;
+
     let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
         "DELETE FROM game_users WHERE game_id = ? AND username = ?")
         .bind(game_id)
         .bind(username)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?;
 
-        Ok(result.rows_affected() > 0)
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    record_game_event(&mut tx, game_id, event_kind, Some(actor), Some(username), None).await?;
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 
@@ -1152,4 +2841,18 @@ pub async fn delete_block(
         .await?;
 
         Ok(result.rows_affected() > 0)
-}
\ No newline at end of file
+}
+
+
+/**
+ * Live updates only need to live long enough for every instance to relay them.
+ */
+pub async fn delete_old_live_events(pool: &MySqlPool, seconds: u32) -> Result<u64> {
+    let result: sqlx::mysql::MySqlQueryResult = sqlx::query(
+        "DELETE FROM live_events WHERE created_timestamp < TIMESTAMPADD(SECOND, ?, UTC_TIMESTAMP())")
+        .bind(-(seconds as i64))
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
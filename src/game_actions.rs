@@ -0,0 +1,322 @@
+use sqlx::{ MySqlPool };
+
+use crate::{
+    api_error::ApiError,
+    auth,
+    bans::{ self, BannedAction },
+    config::Limits,
+    db::{ self, GameAndPlayers },
+    game_logic::{ self, GameStatus },
+    live_updates::{ LiveEvent, LiveHub },
+    routes_utils::{ self, GuessOutcome },
+    utils::SupportedLangs
+};
+
+
+/*
+ *
+ *
+ *
+ *
+ * AAAAAAAAAAAAAAAAAAAAAAAAAA
+ * AAAAAAAAAAAAAAAAAAAAAAAAAA
+ * AAAAA                AAAAA
+ * AAAAA  GAME ACTIONS  AAAAA
+ * AAAAA                AAAAA
+ * AAAAAAAAAAAAAAAAAAAAAAAAAA
+ * AAAAAAAAAAAAAAAAAAAAAAAAAA
+ *
+ * The rules for each thing a player can do to a game.
+ * Shared by the browser (/game_in) routes and the JSON API (/api/v1),
+ * so both enforce the same checks and publish the same live events.
+ *
+ *
+ *
+ */
+
+
+/**
+ * Create a new pre-game owned by the user.
+ * Returns the new game's (unhashed) id.
+ */
+pub async fn create_game(
+    pool: &MySqlPool,
+    limits: &Limits,
+    user_req_data: &auth::UserReqData,
+    invite_only: bool,
+    word_length_option: Option<u8>,
+    game_lang_option: Option<&str>
+) -> Result<i32, ApiError> {
+    let user_id: i32 = routes_utils::get_player_id(user_req_data)?;
+    bans::check_not_banned(
+        pool,
+        user_req_data,
+        BannedAction::CreateGame { open_game: !invite_only }
+    ).await?;
+
+    // Make sure they're not already in too many pregame or inprogress games.
+    let games_count: u8 = db::get_current_games_count(pool, user_id).await?;
+    if games_count >= limits.max_current_games {
+        return Err(ApiError::TooManyCurrentGames);
+    }
+
+    // only verified users can create OPEN games
+    if !invite_only && !user_req_data.email_verified {
+        return Err(ApiError::EmailNotVerified);
+    }
+
+    let word_length: u8 = word_length_option.unwrap_or(game_logic::DEFAULT_WORD_LENGTH);
+    if !game_logic::word_length_is_valid(word_length) {
+        return Err(ApiError::InvalidWordLength);
+    }
+
+    // older clients don't send a language, so they get English
+    let game_lang: SupportedLangs = match game_lang_option {
+        Some(lang_code) => SupportedLangs::from_code(lang_code).ok_or(ApiError::UnknownGameLang)?,
+        None => SupportedLangs::English
+    };
+
+    let game_id: i32 =
+        db::new_game(
+            pool,
+            user_req_data,
+            !invite_only,
+            word_length,
+            &game_lang,
+            limits.max_players
+        ).await?;
+    Ok(game_id)
+}
+
+
+/**
+ * User joins a pre-game.
+ * Open games can be joined by anybody. Invite-only games need an invitation.
+ */
+pub async fn join_game(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    limits: &Limits,
+    user_req_data: &auth::UserReqData,
+    game_id: i32
+) -> Result<(), ApiError> {
+    let user_id: i32 = routes_utils::get_player_id(user_req_data)?;
+    let username: String = user_req_data.get_username();
+
+    let game_and_players: GameAndPlayers = match db::get_game_and_players(pool, game_id).await {
+        Ok(game_and_players) => game_and_players,
+        Err(_e) => return Err(ApiError::NotFound)
+    };
+
+    if game_and_players.game.game_status != GameStatus::PreGame {
+        return Err(ApiError::GameAlreadyStarted);
+    } else if game_and_players.user_id_is_player(user_id) {
+        return Err(ApiError::AlreadyInGame);
+    }
+
+    bans::check_not_banned(
+        pool,
+        user_req_data,
+        BannedAction::JoinGame { open_game: game_and_players.game.open_game }
+    ).await?;
+
+    // find out if user is blocked by the owner
+    if db::is_blocked(pool, &game_and_players.game.owner_username, &username).await? {
+        return Err(ApiError::Blocked);
+    }
+
+    if !game_and_players.game.open_game {
+        let invitations: Vec<db::GameIdAndOwnerName> =
+            db::get_invitations_by_username(pool, username.to_owned()).await?;
+
+        if !invitations.iter().any(|invite| invite.game_id == game_id as i64) {
+            return Err(ApiError::Forbidden);
+        }
+    }
+
+    // Make sure they're not already in too many pregame or inprogress games.
+    let games_count: u8 = db::get_current_games_count(pool, user_id).await?;
+    if games_count >= limits.max_current_games {
+        return Err(ApiError::TooManyCurrentGames);
+    }
+
+    let settings: db::GameSettings = db::get_game_settings(pool, game_id).await?;
+    if game_and_players.players.len() >= settings.max_players as usize {
+        return Err(ApiError::TooManyPlayers);
+    }
+
+    // User may join
+    if !db::user_join_game(pool, user_req_data, game_id).await? {
+        return Err(ApiError::Internal);
+    }
+
+    hub.publish_to_game(game_id, &LiveEvent::PlayerJoined {
+        user_id,
+        username: username.to_owned()
+    });
+
+    // delete invitation. Don't worry about the result.
+    let invite_deleted: bool = match db::delete_invite(pool, game_id, &username).await {
+        Ok(deleted) => deleted,
+        Err(_e) => {
+            eprintln!("Error deleting invitation");
+            false
+        }
+    };
+
+    if invite_deleted {
+        hub.publish_to_user(&username, &LiveEvent::InvitesChanged);
+    }
+
+    Ok(())
+}
+
+
+/**
+ * Owner invites another user to a pre-game.
+ */
+pub async fn invite_player(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    limits: &Limits,
+    user_req_data: &auth::UserReqData,
+    game_id: i32,
+    invited_username: &String
+) -> Result<(), ApiError> {
+    let user_id: i32 = routes_utils::get_player_id(user_req_data)?;
+
+    // get game and make sure user is owner
+    let game_and_players: GameAndPlayers = match db::get_game_and_players(pool, game_id).await {
+        Ok(game_and_players) => game_and_players,
+        Err(_e) => return Err(ApiError::NotFound)
+    };
+
+    if game_and_players.game.owner_id != user_id {
+        return Err(ApiError::NotOwner);
+    } else if game_and_players.game.game_status != GameStatus::PreGame {
+        return Err(ApiError::GameAlreadyStarted);
+    } else if game_and_players.username_is_player(invited_username) {
+        return Err(ApiError::AlreadyInGame);
+    }
+
+    bans::check_not_banned(pool, user_req_data, BannedAction::Invite).await?;
+
+    // find out if user is blocked by invitee
+    if db::is_blocked(pool, invited_username, &user_req_data.get_username()).await? {
+        return Err(ApiError::Blocked);
+    }
+
+    // make sure we don't already have too many invites
+    let invites_count: u8 = db::get_invites_count(pool, game_id).await?;
+    if invites_count >= limits.max_invites {
+        return Err(ApiError::MaxInvites);
+    }
+
+    let invited: bool =
+        db::invite_user(pool, invited_username, user_req_data, game_id).await?;
+
+    if !invited {
+        return Err(ApiError::Internal);
+    }
+
+    hub.publish_to_game(game_id, &LiveEvent::PlayerInvited {
+        username: invited_username.to_owned()
+    });
+    hub.publish_to_user(invited_username, &LiveEvent::InvitesChanged);
+
+    Ok(())
+}
+
+
+/**
+ * Owner starts the game. Pending invitations are thrown away.
+ */
+pub async fn start_game(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    user_req_data: &auth::UserReqData,
+    game_id: i32
+) -> Result<(), ApiError> {
+    let user_id: i32 = routes_utils::get_player_id(user_req_data)?;
+
+    let the_game: db::Game = match db::get_game_by_id(pool, game_id).await {
+        Ok(the_game) => the_game,
+        Err(_e) => return Err(ApiError::NotFound)
+    };
+
+    if the_game.owner_id != user_id {
+        return Err(ApiError::NotOwner);
+    } else if the_game.game_status != GameStatus::PreGame {
+        return Err(ApiError::GameAlreadyStarted);
+    }
+
+    // Call the db to change the status of the game
+    if !db::start_game(pool, user_req_data, game_id).await? {
+        return Err(ApiError::Internal);
+    }
+
+    // remember who was invited so their dashboards can drop the invitation
+    let invitee_usernames: Vec<String> =
+        db::get_invitee_usernames(pool, game_id).await.unwrap_or_default();
+
+    // delete all invitations (some may be pending, so still extant)
+    let _deleted_invite_count_result: Result<u8, anyhow::Error> =
+        db::delete_invites(pool, game_id).await;
+
+    hub.publish_to_game(game_id, &LiveEvent::GameStarted);
+    for invitee_username in invitee_usernames {
+        hub.publish_to_user(&invitee_username, &LiveEvent::InvitesChanged);
+    }
+
+    Ok(())
+}
+
+
+/**
+ * A player guesses a word on their turn.
+ */
+pub async fn guess_word(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    user_req_data: &auth::UserReqData,
+    game_id: i32,
+    guess_word: &String
+) -> Result<game_logic::CheckGuessResult, ApiError> {
+    let user_id: i32 = routes_utils::get_player_id(user_req_data)?;
+
+    let game_and_players: GameAndPlayers = match db::get_game_and_players(pool, game_id).await {
+        Ok(game_and_players) => game_and_players,
+        Err(_e) => return Err(ApiError::NotFound)
+    };
+
+    if !game_and_players.user_id_is_player(user_id) {
+        return Err(ApiError::NotPlayer);
+    }
+
+    match game_and_players.game.game_status {
+        GameStatus::InProgress => {},
+        GameStatus::PreGame => return Err(ApiError::GameNotStarted),
+        GameStatus::Finished | GameStatus::Cancelled => return Err(ApiError::GameOver)
+    }
+
+    bans::check_not_banned(pool, user_req_data, BannedAction::Guess).await?;
+
+    let guess_outcome: GuessOutcome = routes_utils::submit_guess(
+        pool,
+        hub,
+        &game_and_players.game,
+        user_id,
+        guess_word
+    ).await?;
+
+    match guess_outcome {
+        GuessOutcome::Scored(guess_result) => Ok(guess_result),
+        GuessOutcome::WrongTurn => Err(ApiError::WrongTurn),
+        GuessOutcome::MaxGuesses => Err(ApiError::MaxGuesses),
+        GuessOutcome::WrongLength(word_length) => Err(ApiError::WrongLength(word_length)),
+        GuessOutcome::FakeWord => Err(ApiError::FakeWord),
+        GuessOutcome::HardModeViolation(missed_hint) =>
+            Err(ApiError::HardModeViolation(missed_hint)),
+        GuessOutcome::GameOver => Err(ApiError::GameOver),
+    }
+}
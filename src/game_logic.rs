@@ -2,13 +2,33 @@ use serde::{ Serialize };
 use std::collections::BTreeMap;
 use time::{ Duration, OffsetDateTime };
 
-pub const MAX_TURNS: u8 = 5;
+use crate::utils;
+
+// Defaults for a new game's settings (the owner may change them before starting)
+pub const DEFAULT_MAX_GUESSES: u8 = 5;
+pub const DEFAULT_TURN_SECONDS: u16 = 90;
+
+// What the owner may choose from
+pub const MIN_GUESSES: u8 = 3;
+pub const MAX_GUESSES: u8 = 8;
+pub const MIN_TURN_SECONDS: u16 = 30;
+pub const MAX_TURN_SECONDS: u16 = 600;
+pub const MIN_PLAYERS: u8 = 2;
+
+// Game owner picks the word length when creating a game
+pub const MIN_WORD_LENGTH: u8 = 4;
+pub const MAX_WORD_LENGTH: u8 = 8;
+pub const DEFAULT_WORD_LENGTH: u8 = 5;
+
+// Elo-style skill rating
+pub const DEFAULT_RATING: i32 = 1200;
+const RATING_K_FACTOR: f64 = 32.0;
 
 /**
  * the snake_case macro turns RightSpot into "right_spot" string
  * which matches what we see in the JS on the front-end.
  */
-#[derive(Serialize, PartialEq)]
+#[derive(Serialize, PartialEq, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum LetterScore {
     RightSpot,
@@ -25,6 +45,24 @@ pub enum GameStatus {
     Cancelled,
 }
 
+// Everything that can happen to a game (see db::record_game_event)
+pub enum GameEventKind {
+    Created,
+    Joined,
+    Left,
+    Booted,
+    Invited,
+    Uninvited,
+    Started,
+    Guessed,
+    TimedOut,
+    // The turn moved on without a guess (the player was out of guesses, or left)
+    TurnPassed,
+    Quit,
+    Finished,
+    Cancelled,
+}
+
 
 #[derive(Serialize)]
 pub struct GuessAndScore {
@@ -37,6 +75,35 @@ pub struct WordlessScore {
     pub score: Vec<LetterScore>,
 }
 
+/**
+ * Hard mode: the first hint a guess failed to use.
+ * position is 0-based.
+ */
+#[derive(Debug)]
+pub enum MissedHint {
+    RightSpot { letter: char, position: usize },
+    WrongSpot { letter: char },
+}
+
+/**
+ * A guess or solution that's ready to score.
+ * Built with Word::new, which trims it, drops accents, makes it all caps
+ * and only accepts the letters A to Z. Positions are letter positions
+ * (not byte offsets), so every language's words score the same way.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct Word {
+    letters: Vec<char>,
+}
+
+// Why some text can't be a Word (or can't be scored against another)
+#[derive(Debug, PartialEq)]
+pub enum WordError {
+    Empty,
+    BadLetter(char),
+    WrongLength(usize),
+}
+
 #[derive(Serialize)]
 pub struct CheckGuessResultBasic {
     pub score: Vec<LetterScore>,
@@ -52,6 +119,35 @@ pub struct CheckGuessResult {
 }
 
 
+impl LetterScore {
+    // One character per letter, for storing scores in guesses.score
+    pub fn to_code(&self) -> char {
+        match self {
+            LetterScore::RightSpot => 'R',
+            LetterScore::WrongSpot => 'W',
+            LetterScore::Dud => 'D',
+        }
+    }
+
+    pub fn from_code(code: char) -> LetterScore {
+        match code {
+            'R' => LetterScore::RightSpot,
+            'W' => LetterScore::WrongSpot,
+            _ => LetterScore::Dud,
+        }
+    }
+
+    // For the shareable result grid (blue matches the in-game wrong-spot color)
+    pub fn to_emoji(&self) -> char {
+        match self {
+            LetterScore::RightSpot => '🟩',
+            LetterScore::WrongSpot => '🟦',
+            LetterScore::Dud => '⬛',
+        }
+    }
+}
+
+
 impl GameStatus {
     pub fn to_string(&self) -> &str {
         match self {
@@ -74,6 +170,69 @@ impl GameStatus {
 }
 
 
+impl GameEventKind {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            GameEventKind::Created => "created",
+            GameEventKind::Joined => "joined",
+            GameEventKind::Left => "left",
+            GameEventKind::Booted => "booted",
+            GameEventKind::Invited => "invited",
+            GameEventKind::Uninvited => "uninvited",
+            GameEventKind::Started => "started",
+            GameEventKind::Guessed => "guessed",
+            GameEventKind::TimedOut => "timed_out",
+            GameEventKind::TurnPassed => "turn_passed",
+            GameEventKind::Quit => "quit",
+            GameEventKind::Finished => "finished",
+            GameEventKind::Cancelled => "cancelled",
+        }
+    }
+}
+
+
+impl Word {
+    pub fn new(input: &str) -> Result<Word, WordError> {
+        let letters: Vec<char> = utils::all_caps(input.trim()).chars().collect();
+
+        if letters.is_empty() {
+            return Err(WordError::Empty);
+        }
+
+        if let Some(bad_letter) = letters.iter().find(|letter: &&char| !letter.is_ascii_uppercase()) {
+            return Err(WordError::BadLetter(*bad_letter));
+        }
+
+        Ok(Word { letters })
+    }
+
+    // A Word that must have a certain number of letters (ie: a guess in a 6-letter game)
+    pub fn with_length(input: &str, word_length: u8) -> Result<Word, WordError> {
+        let word: Word = Word::new(input)?;
+
+        if word.letter_count() != word_length as usize {
+            return Err(WordError::WrongLength(word_length as usize));
+        }
+
+        Ok(word)
+    }
+
+    pub fn letter_count(&self) -> usize {
+        self.letters.len()
+    }
+
+    pub fn letters(&self) -> &[char] {
+        &self.letters
+    }
+}
+
+impl std::fmt::Display for Word {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.letters.iter().collect::<String>())
+    }
+}
+
+
 impl CheckGuessResult {
     pub fn new(
         basic_result: CheckGuessResultBasic,
@@ -89,130 +248,433 @@ impl CheckGuessResult {
     }
 }
 
+// One human player's skill rating, before or after a game
+pub struct PlayerRating {
+    pub user_id: i32,
+    pub rating: i32,
+}
+
+pub fn get_turn_timeout(turn_seconds: u16) -> OffsetDateTime {
+    OffsetDateTime::now_utc() + Duration::seconds(turn_seconds as i64)
+}
+
+
 /**
- * We need occurrences of each letter so we can highlight the correct
- * number of occurrences in the guess.
+ * We only have word lists for these lengths.
  */
-fn get_letter_counts(word: &String) -> BTreeMap<char, u8> {
-    let mut letter_counts: BTreeMap<char, u8> = BTreeMap::new();
-
-    for letter in word.chars() {
-        letter_counts.insert(letter, {
-            match letter_counts.get(&letter) {
-                None => 1,
-                Some(count) => count +1
-            }
-        });
+pub fn word_length_is_valid(word_length: u8) -> bool {
+    (MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&word_length)
+}
+
+
+// The first setting that is out of range (see get_settings_error)
+#[derive(Debug)]
+pub enum SettingsError {
+    Guesses,
+    TurnSeconds,
+    // carries the config's limit for the message
+    Players { max: u8 },
+}
+
+
+/**
+ * Check the settings the owner asked for.
+ * max_players_limit is the most the config allows.
+ * Returns the first bad setting, or None if all is well.
+ */
+pub fn get_settings_error(
+    max_guesses: u8,
+    turn_seconds: u16,
+    max_players: u8,
+    max_players_limit: u8
+) -> Option<SettingsError> {
+    if !(MIN_GUESSES..=MAX_GUESSES).contains(&max_guesses) {
+        return Some(SettingsError::Guesses);
+    }
+
+    if !(MIN_TURN_SECONDS..=MAX_TURN_SECONDS).contains(&turn_seconds) {
+        return Some(SettingsError::TurnSeconds);
+    }
+
+    if !(MIN_PLAYERS..=max_players_limit).contains(&max_players) {
+        return Some(SettingsError::Players { max: max_players_limit });
     }
 
-    letter_counts
+    None
 }
 
-pub fn get_turn_timeout() -> OffsetDateTime {
-    OffsetDateTime::now_utc() + Duration::seconds(90)
+
+// ie: [RightSpot, Dud, WrongSpot] <=> "RDW"
+pub fn encode_score(score: &[LetterScore]) -> String {
+    score.iter().map(|letter_score: &LetterScore| letter_score.to_code()).collect()
+}
+
+pub fn decode_score(encoded_score: &str) -> Vec<LetterScore> {
+    encoded_score.chars().map(LetterScore::from_code).collect()
 }
 
 
 /**
- * Checking for perfect placement.
- * Simple true/false return.
- * No side effects here.
+ * Score a guess against the solution, letter by letter.
+ * RightSpot letters are matched first, then WrongSpot letters use up
+ * whatever's left of the solution, so a letter is never highlighted
+ * more times than it appears in the solution.
+ * Both words must be the same length.
  */
-fn letter_is_right_spot(
-    letter: &char,
-    winning_word: &String,
-    input_position: u8,
-    letter_counts: &mut BTreeMap<char, u8>
-) -> bool {
-    
-    let letter_at_position_option: Option<char> =
-        winning_word.chars().nth(input_position as usize);
+pub fn check_guess(guess: &Word, solution: &Word) -> Result<CheckGuessResultBasic, WordError> {
+    if guess.letter_count() != solution.letter_count() {
+        return Err(WordError::WrongLength(solution.letter_count()));
+    }
+
+    let mut score: Vec<LetterScore> = vec![LetterScore::Dud; guess.letter_count()];
 
-    if letter_at_position_option.is_none() {
-        eprintln!("LETTER POSITION OUT OF RANGE");
-        return false;
+    // Solution letters not matched by a RightSpot
+    let mut unmatched_counts: BTreeMap<char, u8> = BTreeMap::new();
+
+    for (position, (guess_letter, solution_letter)) in
+        guess.letters().iter().zip(solution.letters()).enumerate()
+    {
+        if guess_letter == solution_letter {
+            score[position] = LetterScore::RightSpot;
+        } else {
+            *unmatched_counts.entry(*solution_letter).or_insert(0) += 1;
+        }
     }
 
-    if letter_at_position_option.unwrap() == *letter {
-        let count_option: Option<&u8> = letter_counts.get(letter);
-        if count_option.is_none() {
-            return false;
+    for (position, guess_letter) in guess.letters().iter().enumerate() {
+        if score[position] == LetterScore::RightSpot {
+            continue
         }
 
-        if *count_option.unwrap() > 0 {
-            return true;
+        if let Some(count) = unmatched_counts.get_mut(guess_letter) && *count > 0 {
+            *count -= 1;
+            score[position] = LetterScore::WrongSpot;
         }
     }
 
-    false
+    let is_winner: bool = score.iter().all(|letter_score: &LetterScore| *letter_score == LetterScore::RightSpot);
+
+    Ok(CheckGuessResultBasic {
+        score,
+        is_winner
+    })
 }
 
 
 /**
- * Send in the guess word and the winning word,
- * we do the comparison and build a map of results
- * about the status of each letter in the guess word.
+ * Hard mode check.
+ * Every RightSpot letter from an earlier guess must stay in its spot,
+ * and every WrongSpot letter must appear somewhere in the new guess
+ * (as many times as the earlier guess proved it's in the word).
+ * Returns the first hint the guess ignores, or None if it uses them all.
  */
-pub fn check_guess(
-    guess_word: &String,
-    winning_word: &String
-) -> CheckGuessResultBasic {
-    // Create default vector with size based on guess word size
-    let mut guess_map: Vec<LetterScore> = Vec::new();
-    for _ in guess_word.chars() {
-        guess_map.push(LetterScore::Dud);
-    }
-
-    // Assume winning conditions, but set to false if any letter breaks perfect placement
-    let mut is_winner: bool = true;
-    let mut letter_counts: BTreeMap<char, u8> = get_letter_counts(winning_word);
-
-    // Check each letter for perfect placement
-    for (input_position, letter) in guess_word.chars().enumerate() {
-        let letter_is_right_spot: bool = letter_is_right_spot(
-            &letter,
-            &winning_word,
-            input_position as u8,
-            &mut letter_counts
-        );
-
-        if letter_is_right_spot && input_position < guess_map.len() {
-            let count_option: Option<&u8>  = letter_counts.get(&letter);
-            if count_option.is_none() {
-                continue
+pub fn find_missed_hint(
+    guess: &Word,
+    previous_guesses: &[GuessAndScore]
+) -> Option<MissedHint> {
+    let guess_letters: &[char] = guess.letters();
+
+    for previous_guess in previous_guesses {
+        let previous_letters: Vec<char> =
+            utils::all_caps(&previous_guess.word).chars().collect();
+
+        // RightSpot letters must stay put
+        for (position, score) in previous_guess.score.iter().enumerate() {
+            if *score != LetterScore::RightSpot {
+                continue;
             }
 
-            let count: u8 = *count_option.unwrap();
-            guess_map[input_position] = LetterScore::RightSpot;
+            let letter: char = previous_letters[position];
+            if guess_letters.get(position) != Some(&letter) {
+                return Some(MissedHint::RightSpot { letter, position });
+            }
+        }
 
-            // decrement count
-            letter_counts.insert(letter.clone(), count - 1);
-        } else {
-            is_winner = false;
+        // How many of each letter this guess proved are in the word
+        let mut known_counts: BTreeMap<char, u8> = BTreeMap::new();
+        for (position, score) in previous_guess.score.iter().enumerate() {
+            if *score != LetterScore::Dud {
+                *known_counts.entry(previous_letters[position]).or_insert(0) += 1;
+            }
         }
-    }
 
-    // Check each letter for wrong position or else dud
-    for (input_position, letter) in guess_word.chars().enumerate() {
-        // Skip the ones that have already been found in the right spot
-        if guess_map[input_position] == LetterScore::RightSpot {
-            continue
+        // WrongSpot letters must be used (at least as many times as we know about)
+        for (position, score) in previous_guess.score.iter().enumerate() {
+            if *score != LetterScore::WrongSpot {
+                continue;
+            }
+
+            let letter: char = previous_letters[position];
+            let guess_count: usize =
+                guess_letters.iter().filter(|guess_letter| **guess_letter == letter).count();
+
+            if guess_count < known_counts[&letter] as usize {
+                return Some(MissedHint::WrongSpot { letter });
+            }
         }
+    }
+
+    None
+}
 
-        match letter_counts.get(&letter) {
-            None => continue, // DUD
-            Some(count) => {
-                if *count > 0 {
-                    // Good letter in the wrong spot
-                    letter_counts.insert(letter, count - 1);
-                    guess_map[input_position] = LetterScore::WrongSpot;
+
+/**
+ * Elo for games with more than two players.
+ * Every pair of players counts as one match: the winner beats everybody,
+ * and players who didn't solve the word tie with each other.
+ * The changes from all of a player's matches are averaged,
+ * so a big game moves ratings about as much as a two-player game.
+ * Points only move between players: the ratings add up to the same total after.
+ * Returns the new ratings in the same order.
+ */
+pub fn get_new_ratings(
+    player_ratings: &[PlayerRating],
+    winner_id_option: Option<i32>
+) -> Vec<PlayerRating> {
+    let opponents_count: f64 = (player_ratings.len() as f64 - 1.0).max(1.0);
+
+    let rating_changes: Vec<f64> = player_ratings
+        .iter()
+        .map(|player: &PlayerRating| {
+            let mut rating_change: f64 = 0.0;
+
+            for opponent in player_ratings {
+                if opponent.user_id == player.user_id {
+                    continue;
                 }
+
+                let actual_score: f64 = match winner_id_option {
+                    Some(winner_id) if winner_id == player.user_id => 1.0,
+                    Some(winner_id) if winner_id == opponent.user_id => 0.0,
+                    _ => 0.5
+                };
+
+                let rating_gap: f64 = (opponent.rating - player.rating) as f64;
+                let expected_score: f64 = 1.0 / (1.0 + 10f64.powf(rating_gap / 400.0));
+
+                rating_change += RATING_K_FACTOR * (actual_score - expected_score);
             }
+
+            rating_change / opponents_count
+        })
+        .collect();
+
+    let mut rounded_changes: Vec<i32> = rating_changes
+        .iter()
+        .map(|rating_change: &f64| rating_change.round() as i32)
+        .collect();
+
+    // Rounding each change can leave the total a point or two off.
+    // Take the extra points back from whoever the rounding helped most.
+    let mut drift: i32 = rounded_changes.iter().sum();
+    while drift != 0 {
+        let step: i32 = drift.signum();
+        let rounding_gain = |index: &usize| -> f64 {
+            (rounded_changes[*index] as f64 - rating_changes[*index]) * step as f64
+        };
+
+        let Some(index) = (0..rounded_changes.len())
+            .max_by(|a: &usize, b: &usize| rounding_gain(a).total_cmp(&rounding_gain(b)))
+        else { break };
+
+        rounded_changes[index] -= step;
+        drift -= step;
+    }
+
+    player_ratings
+        .iter()
+        .zip(rounded_changes)
+        .map(|(player, rating_change): (&PlayerRating, i32)| PlayerRating {
+            user_id: player.user_id,
+            rating: player.rating + rating_change
+        })
+        .collect()
+}
+
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(input: &str) -> Word {
+        Word::new(input).unwrap()
+    }
+
+    fn score_code(guess: &str, solution: &str) -> String {
+        let result: CheckGuessResultBasic = check_guess(&word(guess), &word(solution)).unwrap();
+        result.score.iter().map(|letter_score: &LetterScore| letter_score.to_code()).collect()
+    }
+
+
+    #[test]
+    fn extra_copies_of_a_letter_are_duds() {
+        // three Es in the guess, one in the solution (already matched)
+        assert_eq!(score_code("EERIE", "CRANE"), "DDWDR");
+        // two Es in the guess, one in the solution (not matched)
+        assert_eq!(score_code("SPEED", "ABIDE"), "DDWDW");
+        // two Es in each
+        assert_eq!(score_code("EERIE", "GEESE"), "WRDDR");
+    }
+
+    #[test]
+    fn winning_guess_is_all_right_spot() {
+        let result: CheckGuessResultBasic = check_guess(&word("crane"), &word("CRANE")).unwrap();
+        assert!(result.is_winner);
+        assert_eq!(score_code("crane", "CRANE"), "RRRRR");
+    }
+
+    #[test]
+    fn words_are_trimmed_and_capitalized() {
+        assert_eq!(word("  crane "), word("CRANE"));
+        assert_eq!(word("Crane").to_string(), "CRANE");
+        assert_eq!(word("crane").letter_count(), 5);
+    }
+
+    #[test]
+    fn french_accents_are_folded() {
+        assert_eq!(word("été"), word("ETE"));
+        assert_eq!(word(" cœur ").to_string(), "COEUR");
+        assert_eq!(word("Garçon").to_string(), "GARCON");
+
+        let result: CheckGuessResultBasic = check_guess(&word("élève"), &word("ELEVE")).unwrap();
+        assert!(result.is_winner);
+    }
+
+    #[test]
+    fn bad_letters_are_rejected() {
+        assert_eq!(Word::new("   "), Err(WordError::Empty));
+        assert_eq!(Word::new("CR4NE"), Err(WordError::BadLetter('4')));
+        assert_eq!(Word::new("CRÅNE"), Word::new("CRANE"));
+        assert_eq!(Word::new("CRΛNE"), Err(WordError::BadLetter('Λ')));
+    }
+
+    #[test]
+    fn wrong_length_guess_is_rejected() {
+        assert_eq!(Word::with_length("CRANES", 5), Err(WordError::WrongLength(5)));
+        assert_eq!(Word::with_length("CRAN", 5), Err(WordError::WrongLength(5)));
+        assert!(Word::with_length("CRANE", 5).is_ok());
+
+        assert!(matches!(
+            check_guess(&word("CRANES"), &word("CRANE")),
+            Err(WordError::WrongLength(5))
+        ));
+    }
+
+
+    fn previous_guess(guess: &str, score_code: &str) -> GuessAndScore {
+        GuessAndScore {
+            word: guess.to_string(),
+            score: score_code.chars().map(LetterScore::from_code).collect()
         }
     }
 
-    CheckGuessResultBasic {
-        score: guess_map,
-        is_winner
+    #[test]
+    fn hard_mode_counts_repeated_wrong_spot_letters() {
+        // The earlier guess proved there are two Es, neither in the first two spots
+        let previous_guesses: Vec<GuessAndScore> = vec![previous_guess("EEAST", "WWDDD")];
+
+        assert!(matches!(
+            find_missed_hint(&word("BREAD"), &previous_guesses),
+            Some(MissedHint::WrongSpot { letter: 'E' })
+        ));
+        assert!(find_missed_hint(&word("BRIEE"), &previous_guesses).is_none());
+    }
+
+    #[test]
+    fn hard_mode_keeps_right_spot_letters() {
+        let previous_guesses: Vec<GuessAndScore> = vec![previous_guess("CRANE", "RDDDD")];
+
+        assert!(matches!(
+            find_missed_hint(&word("BLOTS"), &previous_guesses),
+            Some(MissedHint::RightSpot { letter: 'C', position: 0 })
+        ));
+        assert!(find_missed_hint(&word("CLOTS"), &previous_guesses).is_none());
+    }
+
+    #[test]
+    fn hard_mode_checks_every_earlier_guess() {
+        let previous_guesses: Vec<GuessAndScore> = vec![
+            previous_guess("CRANE", "RDDDD"),
+            previous_guess("CLOTH", "RDWDD"),
+        ];
+
+        assert!(matches!(
+            find_missed_hint(&word("CURBS"), &previous_guesses),
+            Some(MissedHint::WrongSpot { letter: 'O' })
+        ));
+        assert!(find_missed_hint(&word("COMBS"), &previous_guesses).is_none());
+        assert!(find_missed_hint(&word("COMBS"), &[]).is_none());
+    }
+
+
+    fn ratings(ratings: &[i32]) -> Vec<PlayerRating> {
+        ratings
+            .iter()
+            .enumerate()
+            .map(|(index, rating): (usize, &i32)| PlayerRating {
+                user_id: index as i32 + 1,
+                rating: *rating
+            })
+            .collect()
+    }
+
+    fn total(player_ratings: &[PlayerRating]) -> i32 {
+        player_ratings.iter().map(|player: &PlayerRating| player.rating).sum()
+    }
+
+    #[test]
+    fn multiplayer_ratings_keep_their_total() {
+        let player_ratings: Vec<PlayerRating> = ratings(&[1200, 1350, 1100, 1500, 1275]);
+
+        for winner_id_option in [None, Some(1), Some(2), Some(3), Some(4), Some(5)] {
+            let new_ratings: Vec<PlayerRating> = get_new_ratings(&player_ratings, winner_id_option);
+            assert_eq!(total(&new_ratings), total(&player_ratings), "winner: {:?}", winner_id_option);
+
+            if let Some(winner_id) = winner_id_option {
+                let winner_index: usize = winner_id as usize - 1;
+                assert!(new_ratings[winner_index].rating > player_ratings[winner_index].rating);
+            }
+        }
+    }
+
+    #[test]
+    fn rounding_never_changes_the_total() {
+        // Ratings where rounding each change on its own used to gain or lose a point
+        let player_ratings: Vec<PlayerRating> = ratings(&[1200, 1350, 1100, 1500]);
+        let new_ratings: Vec<PlayerRating> = get_new_ratings(&player_ratings, Some(3));
+        assert_eq!(total(&new_ratings), total(&player_ratings));
+    }
+
+    #[test]
+    fn two_equal_players_split_the_k_factor() {
+        let new_ratings: Vec<PlayerRating> = get_new_ratings(&ratings(&[1200, 1200]), Some(1));
+        assert_eq!(new_ratings[0].rating, 1216);
+        assert_eq!(new_ratings[1].rating, 1184);
+
+        let drawn_ratings: Vec<PlayerRating> = get_new_ratings(&ratings(&[1200, 1200]), None);
+        assert_eq!(drawn_ratings[0].rating, 1200);
+        assert_eq!(drawn_ratings[1].rating, 1200);
+    }
+
+
+    #[test]
+    fn encoded_score_decodes_to_itself() {
+        let score: Vec<LetterScore> = vec![
+            LetterScore::RightSpot,
+            LetterScore::Dud,
+            LetterScore::WrongSpot,
+            LetterScore::WrongSpot,
+            LetterScore::RightSpot
+        ];
+
+        assert_eq!(encode_score(&score), "RDWWR");
+        assert!(decode_score(&encode_score(&score)) == score);
+        assert_eq!(encode_score(&decode_score("DDWRR")), "DDWRR");
+        assert!(decode_score("").is_empty());
     }
 }
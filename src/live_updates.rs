@@ -0,0 +1,361 @@
+use actix_web::web;
+use actix_ws::{ Message, MessageStream, Session };
+use anyhow::{ Result };
+use serde::Serialize;
+use sqlx::{ MySqlPool };
+use std::{ collections::HashMap, sync::Mutex, time::{ Duration, Instant } };
+use time::{ OffsetDateTime };
+use tokio::{
+    sync::broadcast::{ self, error::RecvError },
+    time::{ Interval, MissedTickBehavior }
+};
+
+use crate::{
+    db,
+    game_logic::LetterScore,
+    utils
+};
+
+/*
+ *
+ *
+ *
+ *
+ * ==========================
+ * ==========================
+ * =====                =====
+ * =====  LIVE UPDATES  =====
+ * =====                =====
+ * ==========================
+ * ==========================
+ *
+ *
+ * Push game events to the browser over WebSockets instead of
+ * having every open tab poll the database.
+ *
+ * The LiveHub keeps one broadcast channel per game (and one per user,
+ * for the dashboard). Routes publish events to a channel as things happen,
+ * and every socket subscribed to that channel forwards them to its browser.
+ *
+ * Channels only exist while somebody is listening.
+ * Events are JSON objects tagged with an "event" field, ie:
+ *      { "event": "turn_changed", "current_turn_id": 12, "turn_timeout": "..." }
+ *
+ * With several server instances, a guess (or timeout, or bot turn) handled on one
+ * instance must reach sockets held by the others. So every event is also written
+ * to the live_events table, and each instance's relay (run_live_relay) reads what
+ * the others wrote and passes it to its own sockets.
+ *
+ *
+ *
+*/
+
+
+// How many unread events a slow socket may fall behind before it must resync
+const CHANNEL_CAPACITY: usize = 32;
+
+// How often the relay passes events between instances
+const RELAY_MILLIS: u64 = 500;
+
+// How far back each relay pass looks (rows can commit a little out of order)
+const RELAY_LOOKBACK_SECONDS: u32 = 5;
+
+// Relayed events older than this get deleted
+const RELAY_KEEP_SECONDS: u32 = 60;
+
+
+/**
+ * Who is listening.
+ * Game channels are for the pre-game and in-progress pages.
+ * User channels are for the dashboard (invitations).
+ */
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum LiveChannel {
+    Game(i32),
+    User(String),
+}
+
+
+/**
+ * Everything we push to the browser.
+ * The snake_case tag matches what the front-end JS switches on.
+ */
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum LiveEvent {
+    TurnChanged {
+        current_turn_id: i32,
+        #[serde(with = "time::serde::rfc3339")]
+        turn_timeout: OffsetDateTime,
+    },
+    NewScore {
+        user_id: i32,
+        score: Vec<LetterScore>,
+    },
+    PlayerJoined {
+        user_id: i32,
+        username: String,
+    },
+    PlayerLeft {
+        username: String,
+    },
+    PlayerBooted {
+        username: String,
+    },
+    PlayerInvited {
+        username: String,
+    },
+    InviteDeleted {
+        username: String,
+    },
+    GameStarted,
+    GameCancelled,
+    SettingsChanged,
+    GameOver {
+        winner_id: Option<i32>,
+    },
+    InvitesChanged,
+    Resync,
+}
+
+
+pub struct LiveHub {
+    channels: Mutex<HashMap<LiveChannel, broadcast::Sender<String>>>,
+    // Published here but not yet shared with the other instances: (channel key, JSON)
+    outbox: Mutex<Vec<(String, String)>>,
+    // Tells this instance's rows in live_events apart from everybody else's
+    instance_id: String,
+}
+
+
+impl LiveChannel {
+    // How the channel is stored in live_events, ie: "game:12" or "user:alice"
+    fn to_key(&self) -> String {
+        match self {
+            LiveChannel::Game(game_id) => format!("game:{}", game_id),
+            LiveChannel::User(username) => format!("user:{}", username),
+        }
+    }
+
+    fn from_key(key: &str) -> Option<LiveChannel> {
+        match key.split_once(':') {
+            Some(("game", game_id)) => game_id.parse::<i32>().ok().map(LiveChannel::Game),
+            Some(("user", username)) => Some(LiveChannel::User(username.to_string())),
+            _ => None
+        }
+    }
+}
+
+
+impl LiveHub {
+    pub fn new() -> LiveHub {
+        LiveHub {
+            channels: Mutex::new(HashMap::new()),
+            outbox: Mutex::new(Vec::new()),
+            instance_id: utils::generate_client_secret()
+        }
+    }
+
+    /**
+     * Get a receiver for the channel, creating the channel if nobody
+     * else is listening yet.
+     */
+    pub fn subscribe(&self, channel: &LiveChannel) -> broadcast::Receiver<String> {
+        let mut channels = self.channels.lock().unwrap();
+        match channels.get(channel) {
+            Some(sender) => sender.subscribe(),
+            None => {
+                let (sender, receiver) = broadcast::channel(CHANNEL_CAPACITY);
+                channels.insert(channel.clone(), sender);
+                receiver
+            }
+        }
+    }
+
+    /**
+     * Drop the channel once the last listener has gone.
+     * Call this AFTER dropping the receiver.
+     */
+    pub fn release(&self, channel: &LiveChannel) {
+        let mut channels = self.channels.lock().unwrap();
+        let is_empty: bool = match channels.get(channel) {
+            Some(sender) => sender.receiver_count() == 0,
+            None => false
+        };
+
+        if is_empty {
+            channels.remove(channel);
+        }
+    }
+
+    /**
+     * Send an event to everybody listening on the channel,
+     * here right away and on the other instances through the relay.
+     */
+    pub fn publish(&self, channel: &LiveChannel, event: &LiveEvent) {
+        let json: String = match serde_json::to_string(event) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("Failed to serialize live event: {:?}", e);
+                return;
+            }
+        };
+
+        self.deliver(channel, &json);
+        self.outbox.lock().unwrap().push((channel.to_key(), json));
+    }
+
+    /**
+     * Send JSON to the sockets listening on the channel in THIS instance.
+     * If nobody is listening there is nothing to do.
+     */
+    fn deliver(&self, channel: &LiveChannel, json: &str) {
+        let channels = self.channels.lock().unwrap();
+        if let Some(sender) = channels.get(channel) {
+            let _ = sender.send(json.to_string());
+        }
+    }
+
+    pub fn publish_to_game(&self, game_id: i32, event: &LiveEvent) {
+        self.publish(&LiveChannel::Game(game_id), event);
+    }
+
+    pub fn publish_to_user(&self, username: &str, event: &LiveEvent) {
+        self.publish(&LiveChannel::User(username.to_string()), event);
+    }
+}
+
+
+/**
+ * Loop forever, passing events between server instances through the live_events table.
+ * What we published goes out, and what the others published comes in to our sockets.
+ * Errors are logged and the loop carries on with the next pass.
+ */
+pub async fn run_live_relay(pool: MySqlPool, hub: web::Data<LiveHub>) {
+    let mut interval: Interval = tokio::time::interval(Duration::from_millis(RELAY_MILLIS));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    // Rows we've already passed on, and when we first saw them
+    let mut delivered_ids: HashMap<i64, Instant> = HashMap::new();
+    let mut last_cleanup: Instant = Instant::now();
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = relay_live_events(&pool, &hub, &mut delivered_ids).await {
+            eprintln!("Live update relay failed: {:?}", e);
+        }
+
+        if last_cleanup.elapsed() >= Duration::from_secs(RELAY_KEEP_SECONDS as u64) {
+            last_cleanup = Instant::now();
+            if let Err(e) = db::delete_old_live_events(&pool, RELAY_KEEP_SECONDS).await {
+                eprintln!("Failed to delete old live events: {:?}", e);
+            }
+        }
+    }
+}
+
+
+/**
+ * One relay pass. Our outbox is written even if nobody else is running,
+ * since we can't know whether other instances are.
+ * If the write fails, those events only ever reached this instance's sockets.
+ */
+async fn relay_live_events(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    delivered_ids: &mut HashMap<i64, Instant>
+) -> Result<()> {
+    let outgoing: Vec<(String, String)> = std::mem::take(&mut *hub.outbox.lock().unwrap());
+
+    if !outgoing.is_empty() {
+        db::save_live_events(pool, &hub.instance_id, &outgoing).await?;
+    }
+
+    let incoming: Vec<db::RelayedLiveEvent> =
+        db::get_recent_live_events(pool, &hub.instance_id, RELAY_LOOKBACK_SECONDS).await?;
+
+    for relayed_event in incoming {
+        if delivered_ids.insert(relayed_event.id, Instant::now()).is_some() {
+            continue;
+        }
+
+        if let Some(channel) = LiveChannel::from_key(&relayed_event.channel) {
+            hub.deliver(&channel, &relayed_event.payload);
+        }
+    }
+
+    // Rows older than the lookback never come back, so we can forget them
+    let remember_for: Duration = Duration::from_secs(RELAY_LOOKBACK_SECONDS as u64 * 2);
+    delivered_ids.retain(|_id: &i64, first_seen: &mut Instant| first_seen.elapsed() < remember_for);
+
+    Ok(())
+}
+
+
+/**
+ * After the turn has moved on, tell everybody whose turn it is now
+ * and when that turn will time out.
+ */
+pub async fn publish_turn_changed(hub: &LiveHub, pool: &MySqlPool, game_id: i32) {
+    let the_game: db::Game = match db::get_game_by_id(pool, game_id).await {
+        Ok(g) => g,
+        Err(e) => {
+            eprintln!("Failed to load game for live update: {:?}", e);
+            return;
+        }
+    };
+
+    if let Some(current_turn_id) = the_game.turn_user_id {
+        hub.publish_to_game(game_id, &LiveEvent::TurnChanged {
+            current_turn_id,
+            turn_timeout: the_game.turn_timeout
+        });
+    }
+}
+
+
+/**
+ * Forward channel events to one browser until either side hangs up.
+ * We don't expect anything from the browser except pings and close frames.
+ */
+pub async fn run_socket(
+    mut session: Session,
+    mut msg_stream: MessageStream,
+    hub: actix_web::web::Data<LiveHub>,
+    channel: LiveChannel
+) {
+    let mut receiver: broadcast::Receiver<String> = hub.subscribe(&channel);
+
+    loop {
+        tokio::select! {
+            event = receiver.recv() => {
+                let sent = match event {
+                    Ok(json) => session.text(json).await,
+                    // We missed some events. Browser must reload its data.
+                    Err(RecvError::Lagged(_)) => {
+                        let json: String = serde_json::to_string(&LiveEvent::Resync)
+                            .unwrap_or_default();
+                        session.text(json).await
+                    },
+                    Err(RecvError::Closed) => break
+                };
+
+                if sent.is_err() { break; }
+            },
+            msg = msg_stream.recv() => {
+                match msg {
+                    Some(Ok(Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() { break; }
+                    },
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => continue
+                }
+            }
+        }
+    }
+
+    drop(receiver);
+    hub.release(&channel);
+    let _ = session.close(None).await;
+}
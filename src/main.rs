@@ -1,29 +1,40 @@
 #![allow(dead_code)] // dead code come on I'm just not using the fields yet.
 
 use actix_web::{ App, HttpServer, middleware::{ from_fn }, web };
+use actix_web_httpauth::middleware::HttpAuthentication;
 use actix_files::Files;
 use dotenvy;
 use std::io;
 use hash_ids::HashIds;
-use sqlx::{ MySqlPool };
+use sqlx::{ MySqlPool, mysql::MySqlPoolOptions };
+
+use crate::config::AppConfig;
 
 mod routes;
 mod routes_utils;
+mod api;
+mod admin;
+mod api_error;
+mod bans;
+mod game_actions;
 mod game_logic;
 mod db;
 mod auth_code_shared;
 mod auth;
+mod auth_provider;
 mod crankword_io;
 mod utils;
 mod middleware;
 mod resources;
 mod resource_mgr;
+mod live_updates;
+mod scheduler;
+mod bots;
 mod words_solutions;
 mod words_all;
-
-pub struct AppConfig {
-    pub client_id: String,
-}
+mod words_all_fr;
+mod words_solutions_fr;
+mod config;
 
 
 /* 
@@ -43,52 +54,99 @@ async fn main() -> std::io::Result<()> {
     // Prepare data for storage in app data and other universal utils
 
     /* dotenvy loads env variables for whole app
-     * after this, just call std::env::var(variable_name) */
+     * so the config can read them (along with crankword.toml) */
     dotenvy::dotenv().ok();
 
-    // Prepare the hash for hashing game_ids and user_ids
-    let hash_ids: HashIds = match get_hashids().await {
-        Ok(ids) => ids,
-        Err(_e) => return hashid_secret_err().await
+    // Every setting, checked up front. Stop here if anything's wrong.
+    let app_config: AppConfig = match AppConfig::load() {
+        Ok(app_config) => app_config,
+        Err(e) => return config_err(e).await
     };
 
+    // Every text needs every language
+    let missing_translations: Vec<String> = resources::find_missing_translations();
+    if !missing_translations.is_empty() {
+        return translations_err(&missing_translations).await
+    }
+
+    // Prepare the hash for hashing game_ids and user_ids
+    let hash_ids: HashIds = get_hashids(&app_config);
+
     // Create the database pool that every function will use
-    let pool: MySqlPool = match create_pool().await {
+    let pool: MySqlPool = match create_pool(&app_config).await {
         Ok(pool) => pool,
         Err(_e) => return database_pool_err().await
     };
 
-    HttpServer::new(move || {
+    // Login, refresh checks and verification emails go through the auth provider
+    let auth_backend: web::Data<auth_provider::AuthBackend> =
+        match auth_provider::AuthBackend::from_config(&app_config.auth) {
+            Ok(backend) => web::Data::new(backend),
+            Err(e) => return auth_provider_err(e).await
+        };
+
+    // One hub shared by every worker, so events reach every open socket.
+    // The relay passes events to and from any other instances of the server.
+    let live_hub: web::Data<live_updates::LiveHub> =
+        web::Data::new(live_updates::LiveHub::new());
+    tokio::spawn(live_updates::run_live_relay(pool.clone(), live_hub.clone()));
+
+    // Time out stalled turns in the background, whether or not anybody is watching
+    tokio::spawn(scheduler::run_turn_timeout_sweeper(pool.clone(), live_hub.clone()));
+    tokio::spawn(scheduler::run_bot_players(pool.clone(), live_hub.clone()));
+
+    let bind_address: (String, u16) =
+        (app_config.server.bind_address.to_owned(), app_config.server.port);
+    let workers_option: Option<usize> = app_config.server.workers;
+    let app_config_data: web::Data<AppConfig> = web::Data::new(app_config);
+
+    let mut server = HttpServer::new(move || {
         App::new()
+            .app_data(app_config_data.clone())
             .app_data(web::Data::new(hash_ids.clone()))
             .app_data(web::Data::new(pool.clone()))
+            .app_data(live_hub.clone())
+            .app_data(auth_backend.clone())
             .service(Files::new("/static", "./static"))
             .wrap(from_fn(middleware::login_status_middleware))
+            .wrap(from_fn(api_error::localize_api_errors))
             .service(routes::error_root)
             .service(routes::error_root_2)
             .service(routes::error_page)
+            .service(routes::banned)
             .service(routes::home)
             .service(routes::game)
             .service(routes::game_root)
+            .service(routes::game_result)
             .service(routes::new_game)
+            .service(routes::new_daily_game)
             .service(routes::login)
             .service(routes::register)
             .service(routes::logout)
+            .service(routes::set_lang)
             .service(routes::reception)
+            .service(routes::local_login)
+            .service(routes::local_authorize)
             .service(routes::dashboard)
             .service(routes::open_games)
+            .service(routes::daily)
             .service(routes::view_user)
             .service(routes::block_user)
             .service(routes::unblock_user)
             .service(routes::request_verification_email)
+            .service(routes::game_socket)
+            .service(routes::dashboard_socket)
             .service(
                 web::scope("/game_in")
                 .service(routes::check_guess)
                 .service(routes::join_game)
                 .service(routes::start_game)
+                .service(routes::update_settings)
+                .service(routes::add_bot)
                 .service(routes::refresh_pregame)
                 .service(routes::refresh_in_prog_players)
                 .service(routes::get_guess_scores)
+                .service(routes::get_replay)
                 .service(routes::invite_player)
                 .service(routes::delete_invite)
                 .service(routes::boot_player_pregame)
@@ -96,21 +154,60 @@ async fn main() -> std::io::Result<()> {
                 .service(routes::cancel_game)
                 .service(routes::leave_game)
             )
+            .service(
+                web::scope("/api/v1")
+                .wrap(HttpAuthentication::bearer(api::validate_bearer_token))
+                .service(api::create_game)
+                .service(api::game_state)
+                .service(api::join_game)
+                .service(api::invite_player)
+                .service(api::start_game)
+                .service(api::guess_word)
+                .service(api::my_games)
+                .service(api::my_stats)
+            )
+            .service(
+                web::scope("/admin")
+                .wrap(from_fn(middleware::admin_only_middleware))
+                .service(admin::admin_home)
+                .service(admin::admin_game)
+                .service(admin::admin_user)
+                .service(admin::cancel_game)
+                .service(admin::finish_game_now)
+                .service(admin::remove_player)
+                .service(admin::ban_user)
+                .service(admin::lift_ban)
+            )
             .default_service(web::get().to(routes::not_found)) // <- catch-all
             .wrap(from_fn(middleware::jwt_cookie_middleware))
-    })
-    .bind(("127.0.0.1", 8080))?
-    .run()
-    .await
+    });
+
+    if let Some(workers) = workers_option {
+        server = server.workers(workers);
+    }
+
+    server
+        .bind(bind_address)?
+        .run()
+        .await
 }
 
 
-async fn hashid_secret_err() -> std::io::Result<()> {
-    eprintln!("ERROR: NO HASH ID SECRET.");
-    return Err(
-        io::Error::new(
-            io::ErrorKind::NotFound, "HASHID_SECRET not set")
-    );
+async fn config_err(e: anyhow::Error) -> std::io::Result<()> {
+    eprintln!("ERROR: {}", e);
+    Err(io::Error::other("Invalid configuration."))
+}
+
+
+async fn translations_err(missing_keys: &[String]) -> std::io::Result<()> {
+    eprintln!("ERROR: MISSING TRANSLATIONS:\n  {}", missing_keys.join("\n  "));
+    Err(io::Error::other("Missing translations."))
+}
+
+
+async fn auth_provider_err(e: anyhow::Error) -> std::io::Result<()> {
+    eprintln!("ERROR: AUTH PROVIDER: {}", e);
+    Err(io::Error::other("Failed to set up the auth provider."))
 }
 
 
@@ -126,14 +223,19 @@ async fn database_pool_err() -> std::io::Result<()> {
  * ROUTES SCHEME:
  *      /game/{}            -- get user_id from JSON web token from cookie
  *      /game_in/           -- SCOPE for routes sending data TO the game (db) FROM the user/client ()POST
+ *      /ws/                -- WebSockets pushing live game events TO the user/client
+ *      /api/v1/            -- SCOPE for the JSON API (Authorization: Bearer <jwt> instead of cookies)
  *      /game_out/          -- SCOPE for routes sending data FROM the game (db) TO the user/client
  */
 
 
  fn check_words() {
-    let word: String = words_solutions::get_random_word();
+    let word: String = words_solutions::get_random_word(
+        game_logic::DEFAULT_WORD_LENGTH,
+        &utils::SupportedLangs::English
+    );
     println!("{}", word);
-    let word_exists: bool = words_all::is_real_word(&word);
+    let word_exists: bool = words_all::is_real_word(&word, &utils::SupportedLangs::English);
 
     if word_exists {
         println!("{} exists", word);
@@ -146,14 +248,11 @@ async fn database_pool_err() -> std::io::Result<()> {
  /**
   * Create the database thread pool that every function will use
   */
-async fn create_pool() -> Result<MySqlPool, String> {
-    let database_url: String = match std::env::var("DATABASE_URL") {
-        Ok(url) => url,
-        Err(_e) => return Err("Database Error".to_string())
-    };
-
+async fn create_pool(app_config: &AppConfig) -> Result<MySqlPool, String> {
     let pool: sqlx::Pool<sqlx::MySql> =
-        match MySqlPool::connect(database_url.as_str()).await {
+        match MySqlPoolOptions::new()
+            .max_connections(app_config.database.max_connections)
+            .connect(app_config.database.url.as_str()).await {
             Ok(pool) => pool,
             Err(_e) => return Err("Database Error".to_string())
         };
@@ -163,15 +262,8 @@ async fn create_pool() -> Result<MySqlPool, String> {
 
 
 // Prepare the hash for hashing game_ids and user_ids
-async fn get_hashids() -> Result<HashIds, String> {
-    let hashid_secret: String = match std::env::var("HASHID_SECRET") {
-        Ok(secret) => secret,
-        Err(_e) => return Err("HASHID_SECRET not set".to_string())
-    };
-
-    let hash_ids: HashIds = HashIds::builder()
-        .with_salt(&hashid_secret)
-        .finish();
-
-    Ok(hash_ids)
+fn get_hashids(app_config: &AppConfig) -> HashIds {
+    HashIds::builder()
+        .with_salt(&app_config.auth.hashid_secret)
+        .finish()
 }
\ No newline at end of file
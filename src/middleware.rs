@@ -16,12 +16,15 @@
  */
 
 use actix_web::{
-    error, Error, HttpMessage,
-    body::MessageBody, dev::{ServiceRequest, ServiceResponse},
+    error, web, Error, HttpMessage, HttpResponse,
+    body::{ EitherBody, MessageBody }, dev::{ServiceRequest, ServiceResponse},
+    http::header,
     middleware::{ Next }
 };
 
-use crate::{ auth, crankword_io,
+use crate::{ auth,
+    auth_provider::{ AuthBackend, AuthProvider },
+    config::AppConfig,
     auth_code_shared::{ 
             RefreshCheckSuccess,
             RefreshCheckRequest,
@@ -80,6 +83,10 @@ pub async fn login_status_middleware(
         guest_data
     ).await?;
 
+    // Every page and message after this is in the user's language
+    let user_req_data: auth::UserReqData =
+        user_req_data.with_lang(auth::resolve_lang(req.request()));
+
     // Put UserReqData into the request object to identify user to all routes.
     req.extensions_mut().insert(user_req_data);
     next.call(req).await
@@ -87,6 +94,32 @@ pub async fn login_status_middleware(
 
 
 
+/**
+ * Guards the /admin scope. Runs after login_status_middleware, so the
+ * UserReqData is already in the request.
+ * Anybody who isn't an admin gets the same 404 as a page that doesn't exist.
+ */
+pub async fn admin_only_middleware<B: MessageBody>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, Error> {
+    let is_admin: bool = req
+        .extensions()
+        .get::<auth::UserReqData>()
+        .is_some_and(|user_req_data: &auth::UserReqData| user_req_data.is_admin());
+
+    if !is_admin {
+        let not_found: HttpResponse = HttpResponse::Found()
+            .append_header((header::LOCATION, "/error/404"))
+            .finish();
+        return Ok(req.into_response(not_found).map_into_right_body());
+    }
+
+    next.call(req).await.map(ServiceResponse::map_into_left_body)
+}
+
+
+
 /**
  * Assists the login_status_middleware function by getting
  * user data for request (UserReqData).
@@ -101,8 +134,13 @@ async fn get_user_req_data_from_opt(
     if option.is_none() { return Ok(guest_data); }
     let jwt_cookie: actix_web::cookie::Cookie<'_> = option.unwrap();
 
+    let app_config: &web::Data<AppConfig> = match req.app_data::<web::Data<AppConfig>>() {
+        Some(app_config) => app_config,
+        None => return Err(error::ErrorInternalServerError("No app config"))
+    };
+
     // hold claims in var if jwt expired. Otherwise return within match.
-    let claims: auth::Claims = match auth::verify_jwt(jwt_cookie.value()).await {
+    let claims: auth::Claims = match auth::verify_jwt(jwt_cookie.value(), &app_config.auth).await {
         auth::JwtVerification::Invalid => {
             return Ok(guest_data);
         },
@@ -128,13 +166,7 @@ async fn get_user_req_data_from_opt(
     if r_token_optn.is_none() { return Ok(guest_data); }
     let r_tkn_ckie: actix_web::cookie::Cookie<'_> = r_token_optn.unwrap();
     let refresh_token: &str = r_tkn_ckie.value();
-    let client_data_result: Result<auth::ClientData, std::env::VarError> =
-        auth::get_client_data();
-
-    let client_data: auth::ClientData = match client_data_result {
-        Ok(data) => data,
-        Err(e) => return Err(error::ErrorInternalServerError(e.to_string()))
-    };
+    let client_data: auth::ClientData = auth::get_client_data(&app_config.auth);
 
     let refresh_check_data: RefreshCheckRequest = RefreshCheckRequest {
         token: refresh_token.to_string(),
@@ -143,8 +175,13 @@ async fn get_user_req_data_from_opt(
         client_secret: client_data.client_secret
     };
 
+    let auth_backend: &web::Data<AuthBackend> = match req.app_data::<web::Data<AuthBackend>>() {
+        Some(backend) => backend,
+        None => return Err(error::ErrorInternalServerError("No auth provider"))
+    };
+
     let refresh_check_result: Result<RefreshCheckSuccess, anyhow::Error> =
-        crankword_io::check_refresh_code(&refresh_check_data).await;
+        auth_backend.check_refresh_code(&refresh_check_data).await;
 
     // Get the OK from auth_app
     let r_tkn_valid: bool = match refresh_check_result {
@@ -160,7 +197,8 @@ async fn get_user_req_data_from_opt(
                 claims.get_sub(),
                 claims.get_username().to_owned(),
                 claims.get_role().to_owned(),
-                claims.email_verified
+                claims.email_verified,
+                &app_config.auth
             );
 
         if let Err(e) = new_jwt_rslt {
@@ -213,12 +251,18 @@ pub async fn jwt_cookie_middleware<B>(
         .get::<NewJwtObj>()
         .map(|obj| obj.get_token().to_owned());
 
+    let app_config_option: Option<web::Data<AppConfig>> = res
+        .request()
+        .app_data::<web::Data<AppConfig>>()
+        .cloned();
+
     // After handler, check for the NewJwt flag and add cookie if present
-    if let Some(token) = new_jwt {
+    if let (Some(token), Some(app_config)) = (new_jwt, app_config_option) {
         let cookie: actix_web::cookie::Cookie<'_> =
             auth::build_token_cookie(
                 token,
-                String::from("jwt")
+                String::from("jwt"),
+                &app_config.auth
             );
 
         res.response_mut().add_cookie(&cookie).ok();
@@ -117,6 +117,55 @@ impl OpenGameTexts {
 }
 
 
+pub struct DailyTexts {
+    pub title: String,
+    pub nav: NavTexts
+}
+
+impl DailyTexts {
+    pub fn new(user_req_data: &UserReqData) -> DailyTexts {
+        let lang: &SupportedLangs = &user_req_data.lang;
+        let title: String = get_translation("daily.title", lang, None);
+        let nav: NavTexts = NavTexts::new(lang);
+
+        DailyTexts { title, nav }
+    }
+}
+
+
+pub struct BannedTexts {
+    pub title: String,
+    pub none: String,
+    pub nav: NavTexts
+}
+
+impl BannedTexts {
+    pub fn new(user_req_data: &UserReqData) -> BannedTexts {
+        let lang: &SupportedLangs = &user_req_data.lang;
+        let title: String = get_translation("banned.title", lang, None);
+        let none: String = get_translation("banned.none", lang, None);
+        let nav: NavTexts = NavTexts::new(lang);
+
+        BannedTexts { title, none, nav }
+    }
+}
+
+
+pub struct AdminTexts {
+    pub title: String,
+    pub nav: NavTexts
+}
+
+impl AdminTexts {
+    pub fn new(user_req_data: &UserReqData) -> AdminTexts {
+        let lang: &SupportedLangs = &user_req_data.lang;
+        let title: String = get_translation("admin.title", lang, None);
+        let nav: NavTexts = NavTexts::new(lang);
+
+        AdminTexts { title, nav }
+    }
+}
+
 
 pub struct PreGameTexts {
     pub title: String,
@@ -184,7 +233,6 @@ pub struct HomeTexts {
     pub title: String,
     pub message_1: String,
     pub message_2: String,
-    pub message_3: String,
     pub nav: NavTexts
 }
 
@@ -202,18 +250,12 @@ impl HomeTexts {
             lang,
             Some(&[&user_req_data.get_role()]));
 
-        let message_3: String = get_translation(
-            "home.message.3",
-            lang,
-            Some(&[&user_req_data.get_role()]));
-
         let nav: NavTexts = NavTexts::new(lang);
 
         HomeTexts {
             title,
             message_1,
             message_2,
-            message_3,
             nav
         }
     }
@@ -297,6 +339,9 @@ pub struct NavTexts {
     pub login: &'static str,
     pub register: &'static str,
     pub logout: &'static str,
+    // label and route for switching to the other language
+    pub switch_lang: &'static str,
+    pub switch_lang_code: &'static str,
 }
 
 
@@ -315,6 +360,7 @@ impl NavTexts {
         let login_key: String = format!("{}.{}", "nav.login", lang_suffix);
         let register_key: String = format!("{}.{}", "nav.register", lang_suffix);
         let logout_key: String = format!("{}.{}", "nav.logout", lang_suffix);
+        let switch_lang_key: String = format!("{}.{}", "nav.switch_lang", lang_suffix);
 
         let home: &'static str = raw_trans_or_missing(home_key.as_str(), lang);
         let admin: &'static str = raw_trans_or_missing(admin_key.as_str(), lang);
@@ -322,6 +368,7 @@ impl NavTexts {
         let login: &'static str = raw_trans_or_missing(login_key.as_str(), lang);
         let register: &'static str = raw_trans_or_missing(register_key.as_str(), lang);
         let logout: &'static str = raw_trans_or_missing(logout_key.as_str(), lang);
+        let switch_lang: &'static str = raw_trans_or_missing(switch_lang_key.as_str(), lang);
 
         NavTexts {
             home,
@@ -330,6 +377,8 @@ impl NavTexts {
             login,
             register,
             logout,
+            switch_lang,
+            switch_lang_code: lang.other().suffix(),
         }
     }
 }
@@ -55,6 +55,30 @@ pub static TRANSLATIONS: phf::Map<&'static str, &'static str> = phf_map! {
     // DASH TEXTS
     "dash.title.en" => "DASHBOARD",
     "dash.title.fr" => "TABLEAU DE BORD",
+    "daily.title.en" => "DAILY CHALLENGE",
+    "daily.title.fr" => "DÉFI DU JOUR",
+
+    // BANNED PAGE
+    "banned.title.en" => "ACCOUNT SUSPENDED",
+    "banned.title.fr" => "COMPTE SUSPENDU",
+    "banned.none.en" => "Your account is not suspended.",
+    "banned.none.fr" => "Votre compte n'est pas suspendu.",
+    "banned.scope.no_open_games.en" => "You can't create or join open games. Invite-only games are still allowed.",
+    "banned.scope.no_open_games.fr" => "Vous ne pouvez pas créer ni rejoindre de parties ouvertes. Les parties sur invitation restent permises.",
+    "banned.scope.no_invites.en" => "You can't invite players to your games.",
+    "banned.scope.no_invites.fr" => "Vous ne pouvez pas inviter de joueurs à vos parties.",
+    "banned.scope.full.en" => "You can't create, join or play games.",
+    "banned.scope.full.fr" => "Vous ne pouvez pas créer, rejoindre ni jouer de parties.",
+    "banned.reason.en" => "Reason: {0}",
+    "banned.reason.fr" => "Raison : {0}",
+    "banned.ends.en" => "This suspension ends {0}.",
+    "banned.ends.fr" => "Cette suspension prend fin le {0}.",
+    "banned.never_ends.en" => "This suspension has no end date.",
+    "banned.never_ends.fr" => "Cette suspension n'a pas de date de fin.",
+
+    // ADMIN AREA
+    "admin.title.en" => "ADMIN",
+    "admin.title.fr" => "ADMINISTRATION",
     "dash.new_game.en" => "CREATE NEW GAME",
     "dash.new_game.fr" => "NOUVEAU JEU",
     "dash.current_games.en" => "CURRENT GAMES",
@@ -83,6 +107,8 @@ pub static TRANSLATIONS: phf::Map<&'static str, &'static str> = phf_map! {
     "nav.logout.fr" => "DÉCONNEXION",
     "nav.dashboard.en" => "DASHBOARD",
     "nav.dashboard.fr" => "TABLEAU DE BORD",
+    "nav.switch_lang.en" => "FRANÇAIS",
+    "nav.switch_lang.fr" => "ENGLISH",
 
     // PRE-GAME PAGE TEXTS
     "pregame.players.label.en" => "PLAYERS",
@@ -107,6 +133,8 @@ pub static TRANSLATIONS: phf::Map<&'static str, &'static str> = phf_map! {
     "postgame.nowinner.message.fr" => "Fin du jeu ! Il n'y avait pas de gagnant !",
     "postgame.cancelled.message.en" => "Game was cancelled!",
     "postgame.cancelled.message.fr" => "Le jeu a été annulé !",
+    "postgame.share.headline.en" => "CRANKWORD {0}: {1} guesses",
+    "postgame.share.headline.fr" => "CRANKWORD {0} : {1} essais",
     
 
     // ERROR CODES AND TITLES FOR ERROR PAGE
@@ -172,22 +200,112 @@ pub static TRANSLATIONS: phf::Map<&'static str, &'static str> = phf_map! {
     "err.504.body.fr" => "Le serveur n'a pas répondu à temps.",
 
     // AD-HOC ERRORS FOR JSON
-    "err.empty_creds.en" => "Invalid Credentials: Empty Field.",
-    "err.empty_creds.fr" => "Identifiants invalides : champ vide.",
     "err.invalid_creds.en" => "Invalid Credentials.",
     "err.invalid_creds.fr" => "Identifiants invalides.",
     "err.user_not_found.en" => "User not found.",
     "err.user_not_found.fr" => "Utilisateur non trouvé.",
 
-    // LINKS & URLS
-    "links.login.en" => "https://crankade.com/auth/login",
-    "links.login.fr" => "https://crankade.com/auth/login",
-    "links.register.en" => "https://crankade.com/auth/register",
-    "links.register.fr" => "https://crankade.com/auth/register",
-    // "links.login.en" => "http://auth.localhost.test:3000/auth/login",
-    // "links.login.fr" => "http://auth.localhost.test:3000/auth/login",
-    // "links.register.en" => "http://auth.localhost.test:3000/auth/register",
-    // "links.register.fr" => "http://auth.localhost.test:3000/auth/register",
+    // API ERRORS (see api_error::ApiError, keyed by its code)
+    "api_err.unauthorized.en" => "Authentication required.",
+    "api_err.unauthorized.fr" => "Authentification requise.",
+    "api_err.forbidden.en" => "You do not have access to this game.",
+    "api_err.forbidden.fr" => "Vous n'avez pas accès à cette partie.",
+    "api_err.not_found.en" => "Not found.",
+    "api_err.not_found.fr" => "Introuvable.",
+    "api_err.not_owner.en" => "Only the game owner can do that.",
+    "api_err.not_owner.fr" => "Seul le propriétaire de la partie peut faire cela.",
+    "api_err.not_player.en" => "You are not a player in this game.",
+    "api_err.not_player.fr" => "Vous ne jouez pas dans cette partie.",
+    "api_err.not_in_game.en" => "That player is not in this game.",
+    "api_err.not_in_game.fr" => "Ce joueur n'est pas dans cette partie.",
+    "api_err.blocked.en" => "User has blocked you.",
+    "api_err.blocked.fr" => "L'utilisateur vous a bloqué.",
+    "api_err.banned.en" => "Your account is suspended, so you can't do that.",
+    "api_err.banned.fr" => "Votre compte est suspendu, vous ne pouvez donc pas faire cela.",
+    "api_err.banned_until.en" => "Your account is suspended until {0}, so you can't do that.",
+    "api_err.banned_until.fr" => "Votre compte est suspendu jusqu'au {0}, vous ne pouvez donc pas faire cela.",
+    "api_err.already_blocked.en" => "User is already blocked.",
+    "api_err.already_blocked.fr" => "L'utilisateur est déjà bloqué.",
+    "api_err.cannot_block_self.en" => "You cannot block yourself.",
+    "api_err.cannot_block_self.fr" => "Vous ne pouvez pas vous bloquer vous-même.",
+    "api_err.email_not_verified.en" => "Verify your email to create open games.",
+    "api_err.email_not_verified.fr" => "Vérifiez votre courriel pour créer des parties ouvertes.",
+    "api_err.too_many_current_games.en" => "You're in too many current games.",
+    "api_err.too_many_current_games.fr" => "Vous êtes dans trop de parties en cours.",
+    "api_err.too_many_players.en" => "Too many current players.",
+    "api_err.too_many_players.fr" => "Trop de joueurs.",
+    "api_err.players_already_joined.en" => "More players have already joined.",
+    "api_err.players_already_joined.fr" => "Plus de joueurs ont déjà rejoint la partie.",
+    "api_err.already_in_game.en" => "User already joined game.",
+    "api_err.already_in_game.fr" => "L'utilisateur a déjà rejoint la partie.",
+    "api_err.max_invites.en" => "Max invites reached.",
+    "api_err.max_invites.fr" => "Nombre maximal d'invitations atteint.",
+    "api_err.cannot_boot_owner.en" => "Cannot boot game owner.",
+    "api_err.cannot_boot_owner.fr" => "Impossible d'expulser le propriétaire de la partie.",
+    "api_err.game_already_started.en" => "Game has already started.",
+    "api_err.game_already_started.fr" => "La partie a déjà commencé.",
+    "api_err.game_not_started.en" => "Game is not in progress.",
+    "api_err.game_not_started.fr" => "La partie n'est pas en cours.",
+    "api_err.game_over.en" => "Game is already over.",
+    "api_err.game_over.fr" => "La partie est déjà terminée.",
+    "api_err.game_not_over.en" => "Only available after the game is over.",
+    "api_err.game_not_over.fr" => "Disponible seulement après la fin de la partie.",
+    "api_err.quit_too_soon.en" => "Game must be five minutes old.",
+    "api_err.quit_too_soon.fr" => "La partie doit avoir cinq minutes.",
+    "api_err.daily_already_played.en" => "You already played today's challenge.",
+    "api_err.daily_already_played.fr" => "Vous avez déjà joué le défi du jour.",
+    "api_err.invalid_word_length.en" => "Word length must be {0} to {1} letters.",
+    "api_err.invalid_word_length.fr" => "Le mot doit avoir de {0} à {1} lettres.",
+    "api_err.unknown_game_lang.en" => "Games can be played in English (en) or French (fr).",
+    "api_err.unknown_game_lang.fr" => "On peut jouer en anglais (en) ou en français (fr).",
+    "api_err.invalid_guesses_setting.en" => "Guesses must be {0} to {1}.",
+    "api_err.invalid_guesses_setting.fr" => "Le nombre d'essais doit être de {0} à {1}.",
+    "api_err.invalid_turn_seconds_setting.en" => "Turn timer must be {0} to {1} seconds.",
+    "api_err.invalid_turn_seconds_setting.fr" => "Le chrono doit être de {0} à {1} secondes.",
+    "api_err.invalid_players_setting.en" => "Players must be {0} to {1}.",
+    "api_err.invalid_players_setting.fr" => "Le nombre de joueurs doit être de {0} à {1}.",
+    "api_err.unknown_bot_level.en" => "Unknown bot level.",
+    "api_err.unknown_bot_level.fr" => "Niveau de robot inconnu.",
+    "api_err.invalid_ban.en" => "A ban needs a scope, a reason and (if it runs out) at least one day.",
+    "api_err.invalid_ban.fr" => "Une suspension exige une portée, une raison et (si elle expire) au moins un jour.",
+    "api_err.wrong_turn.en" => "NOT YOUR TURN",
+    "api_err.wrong_turn.fr" => "CE N'EST PAS VOTRE TOUR",
+    "api_err.max_guesses.en" => "NO MORE GUESSES",
+    "api_err.max_guesses.fr" => "PLUS D'ESSAIS",
+    "api_err.wrong_length.en" => "WORD MUST BE {0} LETTERS",
+    "api_err.wrong_length.fr" => "LE MOT DOIT AVOIR {0} LETTRES",
+    "api_err.fake_word.en" => "NOT IN WORD LIST",
+    "api_err.fake_word.fr" => "PAS DANS LA LISTE DE MOTS",
+    "api_err.hard_mode_right_spot.en" => "HARD MODE: LETTER {1} MUST BE {0}",
+    "api_err.hard_mode_right_spot.fr" => "MODE DIFFICILE : LA LETTRE {1} DOIT ÊTRE {0}",
+    "api_err.hard_mode_wrong_spot.en" => "HARD MODE: GUESS MUST CONTAIN {0}",
+    "api_err.hard_mode_wrong_spot.fr" => "MODE DIFFICILE : LE MOT DOIT CONTENIR {0}",
+    "api_err.internal.en" => "Internal server error.",
+    "api_err.internal.fr" => "Erreur interne du serveur.",
+
+    // ROUTE MESSAGES (successful JSON actions)
+    "msg.welcome.en" => "Welcome to Crankword!",
+    "msg.welcome.fr" => "Bienvenue chez Crankword !",
+    "msg.player_booted.en" => "Player was removed from game",
+    "msg.player_booted.fr" => "Le joueur a été retiré de la partie",
+    "msg.invite_removed.en" => "Invitation removed",
+    "msg.invite_removed.fr" => "Invitation supprimée",
+    "msg.user_blocked.en" => "User has been blocked",
+    "msg.user_blocked.fr" => "L'utilisateur a été bloqué",
+    "msg.user_unblocked.en" => "User has been unblocked",
+    "msg.user_unblocked.fr" => "L'utilisateur a été débloqué",
+    "msg.game_cancelled.en" => "Game cancelled",
+    "msg.game_cancelled.fr" => "Partie annulée",
+    "msg.quit_game.en" => "You have quit the game",
+    "msg.quit_game.fr" => "Vous avez quitté la partie",
+    "msg.user_invited.en" => "User invited",
+    "msg.user_invited.fr" => "Utilisateur invité",
+    "msg.game_finished.en" => "Game finished",
+    "msg.game_finished.fr" => "Partie terminée",
+    "msg.user_banned.en" => "User has been suspended",
+    "msg.user_banned.fr" => "L'utilisateur a été suspendu",
+    "msg.ban_lifted.en" => "Suspension lifted",
+    "msg.ban_lifted.fr" => "Suspension levée",
 
     // EXPLAINER / RULES
     "rules.title.en" => "HOW IT WORKS",
@@ -211,13 +329,16 @@ pub static TRANSLATIONS: phf::Map<&'static str, &'static str> = phf_map! {
         invitations, you can decline an invitation in the IN-PROGRESS page by pressing the (x) 
         next to your username in the PENDING INVITATIONS list.</p>
         <h3>PLAYING THE GAME</h3>
-        <p>For each game there is a secret five-letter word. Each player will try to guess 
-        that word. It's turn-based, so you have to wait until everybody else guesses before 
-        you can guess again. Everybody gets five guesses.</p>
+        <p>For each game there is a secret word, four to eight letters long (the OWNER picks 
+        the length). Each player will try to guess that word. It's turn-based, so you have to 
+        wait until everybody else guesses before you can guess again. Everybody gets five 
+        guesses, unless the OWNER changes the SETTINGS in the PRE-GAME dashboard. The OWNER 
+        can also change the turn timer and the number of players, or turn on HARD MODE. 
+        In HARD MODE every guess must keep the letters you found in the right spot, and use 
+        the letters you found in the wrong spot.</p>
         <p>There is a timer so nobody takes too long. If the timer runs out, the game skips 
-        that turn and it goes to the next player. However, this only works if the game's OWNER 
-        is online. If you find that people are taking too long, and the OWNER is absent, you 
-        can QUIT a game where nobody has made a guess in five minutes. But if the game is active 
+        that turn and it goes to the next player, even if nobody is online. If you find that 
+        people are taking too long, you can QUIT a game where nobody has made a guess in five minutes. But if the game is active 
         then you cannot QUIT an IN-PROGRESS game.</p>",
 
     "rules.body.fr" => "<h2>INSTRUCTIONS FRANÇAISES EN COURS DE CONSTRUCTION !</h2>
@@ -238,21 +359,58 @@ pub static TRANSLATIONS: phf::Map<&'static str, &'static str> = phf_map! {
         invitations, you can decline an invitation in the IN-PROGRESS page by pressing the (x) 
         next to your username in the PENDING INVITATIONS list.</p>
         <h3>PLAYING THE GAME</h3>
-        <p>For each game there is a secret five-letter word. Each player will try to guess 
-        that word. It's turn-based, so you have to wait until everybody else guesses before 
-        you can guess again. Everybody gets five guesses.</p>
+        <p>For each game there is a secret word, four to eight letters long (the OWNER picks 
+        the length). Each player will try to guess that word. It's turn-based, so you have to 
+        wait until everybody else guesses before you can guess again. Everybody gets five 
+        guesses, unless the OWNER changes the SETTINGS in the PRE-GAME dashboard. The OWNER 
+        can also change the turn timer and the number of players, or turn on HARD MODE. 
+        In HARD MODE every guess must keep the letters you found in the right spot, and use 
+        the letters you found in the wrong spot.</p>
         <p>There is a timer so nobody takes too long. If the timer runs out, the game skips 
-        that turn and it goes to the next player. However, this only works if the game's OWNER 
-        is online. If you find that people are taking too long, and the OWNER is absent, you 
-        can QUIT a game where nobody has made a guess in five minutes. But if the game is active 
+        that turn and it goes to the next player, even if nobody is online. If you find that 
+        people are taking too long, you can QUIT a game where nobody has made a guess in five minutes. But if the game is active 
         then you cannot QUIT an IN-PROGRESS game.</p>",
 };
 
 
+/**
+ * Every text must exist in every language.
+ * Returns the full keys that are missing (ie: "dash.title.fr"),
+ * plus any key that doesn't end in a supported language.
+ * Checked at startup, so a missing translation stops the app
+ * instead of showing "[ translation missing ]" to somebody.
+ */
+pub fn find_missing_translations() -> Vec<String> {
+    let mut missing: Vec<String> = Vec::new();
+
+    for key in TRANSLATIONS.keys() {
+        let base_key: &str = match key.rsplit_once('.') {
+            Some((base_key, suffix))
+                if SupportedLangs::ALL.iter().any(|lang: &SupportedLangs| lang.suffix() == suffix) =>
+                    base_key,
+            _ => {
+                missing.push(format!("{} (no language suffix)", key));
+                continue
+            }
+        };
+
+        for lang in SupportedLangs::ALL.iter() {
+            let full_key: String = format!("{}.{}", base_key, lang.suffix());
+            if !TRANSLATIONS.contains_key(full_key.as_str()) && !missing.contains(&full_key) {
+                missing.push(full_key);
+            }
+        }
+    }
+
+    missing.sort();
+    missing
+}
+
+
 /**
  * For missing translations, or mis-typed keys.
  */
-fn missing_trans(lang: &SupportedLangs) -> &'static str {
+pub(crate) fn missing_trans(lang: &SupportedLangs) -> &'static str {
     match lang {
         SupportedLangs::English => "[ translation missing ]",
         SupportedLangs::French => "[ traduction manquante ]"
@@ -308,3 +466,66 @@ pub fn raw_trans_or_missing(
         None => missing_trans(lang)
     }
 }
+
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every literal key passed to get_translation, and every "msg." key, in one source file
+    fn find_used_keys(source: &str) -> Vec<String> {
+        let mut keys: Vec<String> = Vec::new();
+
+        for (index, _) in source.match_indices("get_translation(") {
+            let rest: &str = source[index + "get_translation(".len()..].trim_start();
+            if let Some(literal) = rest.strip_prefix('"') && let Some(end) = literal.find('"') {
+                keys.push(literal[..end].to_string());
+            }
+        }
+
+        for (index, _) in source.match_indices("\"msg.") {
+            let literal: &str = &source[index + 1..];
+            if let Some(end) = literal.find('"') {
+                keys.push(literal[..end].to_string());
+            }
+        }
+
+        keys
+    }
+
+    #[test]
+    fn every_translation_has_every_language() {
+        assert_eq!(find_missing_translations(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn every_key_used_in_the_code_is_translated() {
+        let mut missing: Vec<String> = Vec::new();
+
+        for entry in std::fs::read_dir("src").unwrap() {
+            let path: std::path::PathBuf = entry.unwrap().path();
+            if path.extension().is_none_or(|extension| extension != "rs") ||
+                path.ends_with("resources.rs")
+            {
+                continue;
+            }
+
+            let source: String = std::fs::read_to_string(&path).unwrap();
+            for key in find_used_keys(&source) {
+                for lang in SupportedLangs::ALL.iter() {
+                    let full_key: String = format!("{}.{}", key, lang.suffix());
+                    if !TRANSLATIONS.contains_key(full_key.as_str()) {
+                        missing.push(format!("{} ({})", full_key, path.display()));
+                    }
+                }
+            }
+        }
+
+        assert_eq!(missing, Vec::<String>::new());
+    }
+}
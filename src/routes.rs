@@ -6,12 +6,14 @@ use actix_web::{
 use askama::Template;
 use hash_ids::HashIds;
 use sqlx::{ MySqlPool };
-use time::OffsetDateTime;
+use time::{ Date, OffsetDateTime };
 
 use crate::{
-    auth, auth_code_shared::{ 
+    api_error::ApiError, auth, config::AppConfig, auth_code_shared::{ 
         self, AuthCodeRequest, AuthCodeSuccess
-    }, crankword_io, db::{self, GameAndPlayers, PlayerStats}, game_logic::{ self, GameStatus }, resource_mgr::{self, *}, resources::get_translation, routes_utils::*, utils::{ self, SupportedLangs }, words_all
+    }, auth_provider::{ AuthBackend, AuthProvider, LocalAuthProvider }, bans::{ self, BanScope, BannedAction }, bots::BotLevel, db::{self, GameAndPlayers, PlayerStats}, game_actions, game_logic::{ self, GameEventKind, GameStatus },
+    live_updates::{ self, LiveChannel, LiveEvent, LiveHub },
+    resource_mgr::{self, *}, resources::get_translation, routes_utils::*, utils::SupportedLangs
 };
 
 /* 
@@ -56,26 +58,23 @@ use crate::{
 
 
 /**
-  * Redirect user to auth_app for login
+  * Redirect user to the auth provider for login
   */
  #[get("/login")]
- async fn login() -> HttpResponse {
-    let lang: SupportedLangs = SupportedLangs::English;
-    let mut login_url: String = get_translation(
-        "links.login",
-        &lang,
-        None
-    );
+ async fn login(
+    req: HttpRequest,
+    app_config: web::Data<AppConfig>,
+    auth_backend: web::Data<AuthBackend>
+) -> HttpResponse {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let mut login_url: String = auth_backend.login_url();
 
-    let querystring: String = match std::env::var("CLIENT_ID") {
-        Ok(client_id) => {
-            "?client_id=".to_string() + &client_id
-        },
-        Err(e) => {
-            eprintln!("ERROR: {}", e);
-            return redirect_to_err("500")
-        }
-    };
+    // pass the language along so the auth pages can match it
+    let querystring: String = format!(
+        "?client_id={}&lang={}",
+        app_config.auth.client_id,
+        user_req_data.lang_suffix()
+    );
 
     login_url.push_str(&querystring);
 
@@ -86,26 +85,22 @@ use crate::{
 
 
 /**
-  * Redirect user to auth_app for registration
+  * Redirect user to the auth provider for registration
   */
  #[get("/register")]
- async fn register() -> HttpResponse {
-    let lang: SupportedLangs = SupportedLangs::English;
-    let mut register_url: String = get_translation(
-        "links.register",
-        &lang,
-        None
-    );
+ async fn register(
+    req: HttpRequest,
+    app_config: web::Data<AppConfig>,
+    auth_backend: web::Data<AuthBackend>
+) -> HttpResponse {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let mut register_url: String = auth_backend.register_url();
 
-    let querystring: String = match std::env::var("CLIENT_ID") {
-        Ok(client_id) => {
-            "?client_id=".to_string() + &client_id
-        },
-        Err(e) => {
-            eprintln!("ERROR: {}", e);
-            return redirect_to_err("500")
-        }
-    };
+    let querystring: String = format!(
+        "?client_id={}&lang={}",
+        app_config.auth.client_id,
+        user_req_data.lang_suffix()
+    );
 
     register_url.push_str(&querystring);
 
@@ -116,6 +111,49 @@ use crate::{
 
 
 
+/**
+ * Nav language switch. Remember the choice in a cookie
+ * and send the user back to the page they were on.
+ */
+#[get("/lang/{lang_code}")]
+async fn set_lang(
+    req: HttpRequest,
+    app_config: web::Data<AppConfig>,
+    path: web::Path<String>
+) -> HttpResponse {
+    let lang: SupportedLangs = match SupportedLangs::from_code(&path.into_inner()) {
+        Some(lang) => lang,
+        None => return redirect_to_err("404")
+    };
+
+    // Only go back to a page on this site
+    let return_path: String = req.headers()
+        .get(header::REFERER)
+        .and_then(|referer| referer.to_str().ok())
+        .and_then(|referer: &str| get_local_path(&req, referer))
+        .unwrap_or_else(|| "/".to_string());
+
+    HttpResponse::Found()
+        .append_header((header::LOCATION, return_path))
+        .cookie(auth::build_lang_cookie(&lang, &app_config.auth))
+        .finish()
+}
+
+
+// The path (and query) of a URL on this host. None for anywhere else.
+fn get_local_path(req: &HttpRequest, url: &str) -> Option<String> {
+    let connection_info: actix_web::dev::ConnectionInfo = req.connection_info().clone();
+    let origin: String = format!("{}://{}", connection_info.scheme(), connection_info.host());
+    let path: &str = url.strip_prefix(&origin)?;
+
+    if path.starts_with('/') && !path.starts_with("//") {
+        Some(path.to_string())
+    } else {
+        None
+    }
+}
+
+
 #[get("/logout")]
 pub async fn logout() -> HttpResponse {
 
@@ -151,7 +189,7 @@ async fn home(req: HttpRequest) -> HttpResponse {
 
     let home_template: HomeTemplate = HomeTemplate {
         title: "CRANKWORD".to_string(),
-        message: "Welcome to Crankword!".to_string(),
+        message: get_translation("msg.welcome", &user_req_data.lang, None),
         user: user_req_data,
         texts
     };
@@ -178,6 +216,7 @@ async fn game_root(req: HttpRequest) -> HttpResponse {
  #[get("/game/{hashed_game_id}")]
  async fn game(
     pool: web::Data<MySqlPool>,
+    app_config: web::Data<AppConfig>,
     hash_ids: web::Data<HashIds>,
     req: HttpRequest,
     path: web::Path<String>
@@ -210,11 +249,13 @@ async fn game_root(req: HttpRequest) -> HttpResponse {
     // Each option is in a function
     match game.game.game_status {
         game_logic::GameStatus::PreGame =>
-            go_to_pregame(&hashed_game_id, game, user_req_data, &pool).await,
+            go_to_pregame(&hashed_game_id, game, user_req_data, &pool, &app_config).await,
         game_logic::GameStatus::InProgress =>
-            go_to_inprogress_game(&hashed_game_id, game, user_req_data).await,
-        game_logic::GameStatus::Finished =>
-            go_to_finished_game(game, user_req_data).await,
+            go_to_inprogress_game(&hashed_game_id, game, user_req_data, &pool).await,
+        game_logic::GameStatus::Finished => {
+            let result_url: String = get_result_url(&req, &hashed_game_id);
+            go_to_finished_game(&hashed_game_id, result_url, game, user_req_data, &pool).await
+        },
         game_logic::GameStatus::Cancelled =>
             go_to_cancelled_game(game, user_req_data).await
     }
@@ -227,6 +268,7 @@ async fn go_to_pregame(
     the_game: db::GameAndPlayers,
     user_req_data: auth::UserReqData,
     pool: &web::Data<MySqlPool>,
+    app_config: &AppConfig,
 ) -> HttpResponse {
 
     // make sure it's an open game OR the user has an invite OR user is player
@@ -252,12 +294,19 @@ async fn go_to_pregame(
             return redirect_to_err("403")
     }
 
+    let settings: db::GameSettings = match db::get_game_settings(pool, the_game.game.id).await {
+        Ok(settings) => settings,
+        Err(_) => return redirect_to_err("500")
+    };
+
     let pre_game_template: PreGameTemplate = PreGameTemplate {
         age_string: create_age_string(&the_game.game.created_timestamp),
         texts: resource_mgr::PreGameTexts::new(&user_req_data),
         game: the_game,
         user: user_req_data,
-        hashed_game_id: hashed_game_id.to_owned()
+        hashed_game_id: hashed_game_id.to_owned(),
+        settings,
+        max_players_limit: app_config.limits.max_players
     };
 
     HttpResponse::Ok()
@@ -269,13 +318,19 @@ async fn go_to_pregame(
 async fn go_to_inprogress_game(
     hashed_game_id: &String,
     the_game: db::GameAndPlayers,
-    user_req_data: auth::UserReqData
+    user_req_data: auth::UserReqData,
+    pool: &web::Data<MySqlPool>,
 ) -> HttpResponse {
     // Only let players in
     if !the_game.user_id_is_player(user_req_data.to_owned().id.unwrap()) {
         return redirect_to_err("403")
     }
 
+    let settings: db::GameSettings = match db::get_game_settings(pool, the_game.game.id).await {
+        Ok(settings) => settings,
+        Err(_) => return redirect_to_err("500")
+    };
+
     let is_owner: bool = the_game.game.owner_id == user_req_data.to_owned().id.unwrap();
     let texts: GameTexts = GameTexts::new(&user_req_data, is_owner);
 
@@ -284,7 +339,8 @@ async fn go_to_inprogress_game(
         user: user_req_data,
         game: the_game,
         texts,
-        hashed_game_id: hashed_game_id.to_owned()
+        hashed_game_id: hashed_game_id.to_owned(),
+        max_turns: settings.max_guesses
     };
 
     HttpResponse::Ok()
@@ -300,9 +356,23 @@ async fn go_to_inprogress_game(
  * already finished/completed.
  */
 async fn go_to_finished_game(
+    hashed_game_id: &String,
+    result_url: String,
     the_game: db::GameAndPlayers,
-    user_req_data: auth::UserReqData
+    user_req_data: auth::UserReqData,
+    pool: &web::Data<MySqlPool>,
 ) -> HttpResponse {
+    let share_text: String = match get_share_text(
+        pool,
+        &the_game,
+        hashed_game_id,
+        &result_url,
+        &user_req_data.lang
+    ).await {
+        Ok(text) => text,
+        Err(_e) => return redirect_to_err("500")
+    };
+
     let post_game_texts: PostGameTexts = resource_mgr::PostGameTexts::new(
         &user_req_data,
         None,
@@ -335,7 +405,10 @@ async fn go_to_finished_game(
         texts: post_game_texts,
         user: user_req_data,
         game: the_game,
-        winner_name
+        winner_name,
+        hashed_game_id: hashed_game_id.to_owned(),
+        share_text,
+        result_url
     };
 
     return HttpResponse::Ok()
@@ -344,6 +417,113 @@ async fn go_to_finished_game(
 }
 
 
+/**
+ * NOT A ROUTE
+ * Gather each player's wordless scores and build the shareable text.
+ */
+async fn get_share_text(
+    pool: &MySqlPool,
+    the_game: &db::GameAndPlayers,
+    hashed_game_id: &String,
+    result_url: &String,
+    lang: &SupportedLangs
+) -> anyhow::Result<String> {
+    let player_results: Vec<PlayerResult> = get_player_results(pool, &the_game.game).await?;
+    let settings: db::GameSettings = db::get_game_settings(pool, the_game.game.id).await?;
+
+    Ok(build_share_text(hashed_game_id, &player_results, settings.max_guesses, result_url, lang))
+}
+
+
+/**
+ * NOT A ROUTE
+ * Every player's spoiler-free result for a finished game.
+ */
+async fn get_player_results(
+    pool: &MySqlPool,
+    the_game: &db::Game
+) -> anyhow::Result<Vec<PlayerResult>> {
+    let players_refresh_data: Vec<db::PlayerRefreshData> =
+        db::get_players_refresh_data_by_game_id(pool, the_game).await?;
+
+    Ok(players_refresh_data
+        .iter()
+        .map(|player: &db::PlayerRefreshData| PlayerResult::new(player, the_game.winner_id))
+        .collect())
+}
+
+
+/**
+ * Public result page for a finished game.
+ * Anybody with the link can see it, logged in or not,
+ * so it only shows the emoji grid: no words, no answer.
+ */
+#[get("/result/{hashed_game_id}")]
+async fn game_result(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest,
+    path: web::Path<String>
+) -> HttpResponse {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let hashed_game_id: String = path.into_inner();
+
+    let game_id: i32 = match hash_ids.decode(&hashed_game_id) {
+        Ok(hash_ids) => {
+            if !hash_ids.is_empty() { hash_ids[0] as i32 }
+            else { return redirect_to_err("404") }
+        },
+        Err(_e) => return redirect_to_err("404")
+    };
+
+    let the_game: db::GameAndPlayers = match db::get_game_and_players(&pool, game_id).await {
+        Ok(g) => g,
+        Err(_e) => return redirect_to_err("404")
+    };
+
+    // Games still being played (or cancelled) have no result to show
+    if the_game.game.game_status != GameStatus::Finished {
+        return redirect_to_err("404");
+    }
+
+    let player_results: Vec<PlayerResult> =
+        match get_player_results(&pool, &the_game.game).await {
+            Ok(results) => results,
+            Err(_e) => return redirect_to_err("500")
+        };
+
+    let settings: db::GameSettings = match db::get_game_settings(&pool, game_id).await {
+        Ok(settings) => settings,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    let winner_name: Option<String> = player_results
+        .iter()
+        .find(|player_result: &&PlayerResult| player_result.solved)
+        .map(|player_result: &PlayerResult| player_result.username.to_owned());
+
+    let share_text: String = build_share_text(
+        &hashed_game_id,
+        &player_results,
+        settings.max_guesses,
+        &get_result_url(&req, &hashed_game_id),
+        &user_req_data.lang
+    );
+
+    let result_template: ResultTemplate = ResultTemplate {
+        texts: resource_mgr::PostGameTexts::new(&user_req_data, winner_name, false),
+        user: user_req_data,
+        hashed_game_id,
+        players: player_results,
+        share_text
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/html")
+        .body(result_template.render().unwrap())
+}
+
+
 /**
  * NOT A ROUTE
  * This provides an HttpResponse for a user
@@ -388,7 +568,6 @@ async fn view_user(
         user_req_data.username.is_none()
     { return redirect_to_login() }
 
-    let user_id: i32 = user_req_data.id.unwrap();
     let username_to_view: String = match path.into_inner().parse::<String>() {
         Ok(username_to_view) => username_to_view,
         Err(_) => "400".to_string()
@@ -398,36 +577,14 @@ async fn view_user(
         return redirect_to_dash()
     }
 
-    // Get user's games... but BY USERNAME
-    let all_user_games: Vec<db::GameItemData> = match db::get_games_by_username(
-        &pool, &username_to_view).await {
-        Ok(games) => games,
-        Err(_e) => return redirect_to_err("500")
-    };
-
-    let has_stats: bool = all_user_games.len() > 0;
-
-    // Create stats object
-    let mut wins: u32 = 0;
-    let mut past_games: u32 = 0;
-    let mut cancelled_games: u32 = 0;
-    for user_game in all_user_games {
+    // Get user's stats... but BY USERNAME
+    let stats_option: Option<PlayerStats> =
+        match db::get_player_stats_by_username(&pool, &username_to_view).await {
+            Ok(stats) => stats,
+            Err(_e) => return redirect_to_err("500")
+        };
 
-        // get current games (in progress and pre-game)
-        if user_game.game_status == game_logic::GameStatus::InProgress.to_string() ||
-            user_game.game_status == game_logic::GameStatus::PreGame.to_string()
-        { continue; }
-
-        past_games += 1;
-        
-        if user_game.game_status == game_logic::GameStatus::Cancelled.to_string() {
-            cancelled_games += 1;
-        } else if user_game.winner_id.is_some() {
-            if user_game.winner_id.unwrap() == user_id {
-                wins += 1;
-            }
-        }
-    }
+    let has_stats: bool = stats_option.is_some();
 
     let is_blocked: bool = match db::is_blocked(
         &pool,
@@ -438,13 +595,20 @@ async fn view_user(
         Err(_e) => return redirect_to_err("500")
     };
 
+    let rating_history: Vec<db::RatingHistoryItem> =
+        match db::get_rating_history_by_username(&pool, &username_to_view).await {
+            Ok(history) => history,
+            Err(_e) => return redirect_to_err("500")
+        };
+
     let view_user_template: ViewUserTemplate = ViewUserTemplate {
         texts: ViewUserTexts::new(&user_req_data),
         user: user_req_data,
-        stats: PlayerStats { wins, past_games, cancelled_games },
+        stats: stats_option.unwrap_or(PlayerStats::empty()),
         username: username_to_view.to_owned(),
         has_stats,
-        is_blocked
+        is_blocked,
+        rating: RatingSummary::new(rating_history)
     };
 
     HttpResponse::Ok()
@@ -477,10 +641,6 @@ async fn dashboard(
             Err(_e) => return redirect_to_err("500")
         };
 
-    // Create stats object
-    let mut wins: u32 = 0;
-    let mut past_games: u32 = 0;
-    let mut cancelled_games: u32 = 0;
     let mut current_games: Vec<db::GameLinkData> = Vec::new();
 
     for user_game in all_user_games {
@@ -492,35 +652,43 @@ async fn dashboard(
             current_games.push(db::GameLinkData {
                 hashid: hash_ids.encode(&[user_game.id as u64]),
                 game_status: user_game.game_status,
+                word_length: user_game.word_length,
+                game_lang: SupportedLangs::from(&user_game.game_lang),
                 age_string: create_age_string(&user_game.created_timestamp)
             });
-
-            continue;
-        }
-
-        past_games += 1;
-        
-        if user_game.game_status == game_logic::GameStatus::Cancelled.to_string() {
-            cancelled_games += 1;
-        } else if user_game.winner_id.is_some() {
-            if user_game.winner_id.unwrap() == user_id {
-                wins += 1;
-            }
         }
     }
 
+    let stats: PlayerStats = match db::get_player_stats(&pool, user_id).await {
+        Ok(stats) => stats,
+        Err(_e) => return redirect_to_err("500")
+    };
+
     let raw_invitations: Vec<db::GameIdAndOwnerName> =
         match db::get_invitations_by_username(&pool, username).await {
             Ok(invites) => invites,
             Err(_e) => return redirect_to_err("500")
         };
 
+    let solved_dates: Vec<Date> = match db::get_daily_solved_dates(&pool, user_id).await {
+        Ok(dates) => dates,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    let rating_history: Vec<db::RatingHistoryItem> =
+        match db::get_rating_history(&pool, user_id).await {
+            Ok(history) => history,
+            Err(_e) => return redirect_to_err("500")
+        };
+
     let dash_template: DashboardTemplate = DashboardTemplate {
         texts: DashTexts::new(&user_req_data),
         user: user_req_data,
         current_games,
-        stats: PlayerStats { wins, past_games, cancelled_games },
-        invited_games: get_hashes_from_game_ids(&hash_ids, raw_invitations)
+        stats,
+        invited_games: get_hashes_from_game_ids(&hash_ids, raw_invitations),
+        daily_streak: get_daily_streak(&solved_dates, OffsetDateTime::now_utc().date()),
+        rating: RatingSummary::new(rating_history)
     };
 
     HttpResponse::Ok()
@@ -556,7 +724,9 @@ async fn open_games(
             let hashed_id: String = hash_ids.encode(&[raw_game.id as u64]);
             let age_string: String = create_age_string(&raw_game.created_timestamp);
             let owner_username: String = raw_game.owner_username.to_owned();
-            return OpenGame { hashed_id, age_string, owner_username }
+            let word_length: u8 = raw_game.word_length;
+            let game_lang: &'static str = SupportedLangs::from(&raw_game.game_lang).native_name();
+            return OpenGame { hashed_id, age_string, owner_username, word_length, game_lang }
         })
         .collect();
 
@@ -572,6 +742,56 @@ async fn open_games(
 }
 
 
+/* DAILY CHALLENGE ROUTE */
+#[get("/daily")]
+async fn daily(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest
+) -> HttpResponse {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+
+    if user_req_data.role == "guest" ||
+        user_req_data.id.is_none() ||
+        user_req_data.username.is_none()
+    { return redirect_to_login() }
+
+    let user_id: i32 = user_req_data.id.unwrap();
+    let today: Date = OffsetDateTime::now_utc().date();
+
+    let daily_game_option: Option<db::DailyGame> =
+        match db::get_daily_game(&pool, today, user_id).await {
+            Ok(daily_game) => daily_game,
+            Err(_e) => return redirect_to_err("500")
+        };
+
+    let raw_results: Vec<db::RawDailyResult> = match db::get_daily_results(&pool, today).await {
+        Ok(results) => results,
+        Err(_e) => return redirect_to_err("500")
+    };
+
+    // Only link back to today's game if it isn't finished yet
+    let in_progress_hashid: Option<String> = match &daily_game_option {
+        Some(daily_game) if daily_game.finished_timestamp.is_none() =>
+            Some(hash_ids.encode(&[daily_game.game_id as u64])),
+        _ => None
+    };
+
+    let template: DailyTemplate = DailyTemplate {
+        texts: DailyTexts::new(&user_req_data),
+        user: user_req_data,
+        challenge_date: today.to_string(),
+        entries: build_daily_leaderboard(raw_results),
+        played_today: daily_game_option.is_some(),
+        in_progress_hashid
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/html")
+        .body(template.render().unwrap())
+}
+
+
 fn redirect_to_game() -> HttpResponse {
     HttpResponse::Found() // 302 redirect
         .append_header((header::LOCATION, "/game"))
@@ -585,35 +805,23 @@ fn redirect_to_game() -> HttpResponse {
   * they are redirected here.
   */
 #[get("/reception")]
-async fn reception(query: web::Query<AuthCodeQuery>) -> HttpResponse {
+async fn reception(
+    app_config: web::Data<AppConfig>,
+    auth_backend: web::Data<AuthBackend>,
+    query: web::Query<AuthCodeQuery>
+) -> HttpResponse {
     let auth_code: String = query.code.to_owned();
 
     // IN THIS FUNCTION we will CALL the AUTH APP and RECEIVE the REFRESH_TOKEN
 
-    let client_id: String = match std::env::var("CLIENT_ID") {
-        Ok(secret) => secret,
-        Err(_e) => {
-            eprintln!("ERROR: NO CLIENT ID.");
-            return redirect_to_err("404");
-        }
-    };
-
-    let client_secret: String = match std::env::var("CLIENT_SECRET") {
-        Ok(secret) => secret,
-        Err(_e) => {
-            eprintln!("ERROR: NO CLIENT SECRET.");
-            return redirect_to_err("400");
-        }
-    };
-
     let client_auth_data: AuthCodeRequest = AuthCodeRequest {
-        client_id,
-        client_secret,
+        client_id: app_config.auth.client_id.to_owned(),
+        client_secret: app_config.auth.client_secret.to_owned(),
         code: auth_code,
     };
 
     let auth_code_response: Result<AuthCodeSuccess, anyhow::Error> = 
-        crankword_io::check_auth_code(client_auth_data).await;
+        auth_backend.check_auth_code(client_auth_data).await;
 
     match auth_code_response {
         Ok(success) => {
@@ -622,7 +830,8 @@ async fn reception(query: web::Query<AuthCodeQuery>) -> HttpResponse {
                 success.user_id,
                 success.username,
                 success.user_role,
-                success.email_verified
+                success.email_verified,
+                &app_config.auth
             ) {
                 Ok(token) => token,
                 Err(e) => {
@@ -634,11 +843,13 @@ async fn reception(query: web::Query<AuthCodeQuery>) -> HttpResponse {
             // Now make the cookies and set them in the response
             let jwt_cookie: Cookie<'_> = auth::build_token_cookie(
                 jwt,
-                String::from("jwt"));
+                String::from("jwt"),
+                &app_config.auth);
             
             let refresh_token_cookie: Cookie<'_> = auth::build_token_cookie(
                 success.refresh_token,
-                String::from("refresh_token"));
+                String::from("refresh_token"),
+                &app_config.auth);
 
             HttpResponse::Found() // 302 redirect
                 .append_header((header::LOCATION, "/game"))
@@ -655,6 +866,51 @@ async fn reception(query: web::Query<AuthCodeQuery>) -> HttpResponse {
 }
 
 
+/**
+ * LOCAL AUTH
+ * Stands in for the auth_app's login page when AUTH_PROVIDER=local.
+ * Lists the users from the config file. Picking one logs in as them.
+ */
+#[get("/local_auth/login")]
+async fn local_login(auth_backend: web::Data<AuthBackend>) -> HttpResponse {
+    let local_provider: &LocalAuthProvider = match auth_backend.local() {
+        Some(local_provider) => local_provider,
+        None => return redirect_to_err("404")
+    };
+
+    let template: LocalLoginTemplate = LocalLoginTemplate {
+        users: local_provider.get_users()
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/html")
+        .body(template.render().unwrap())
+}
+
+
+/**
+ * Issue a code for the chosen user and send them to reception,
+ * just like the auth_app would.
+ */
+#[get("/local_auth/authorize/{user_id}")]
+async fn local_authorize(
+    auth_backend: web::Data<AuthBackend>,
+    path: web::Path<i32>
+) -> HttpResponse {
+    let local_provider: &LocalAuthProvider = match auth_backend.local() {
+        Some(local_provider) => local_provider,
+        None => return redirect_to_err("404")
+    };
+
+    match local_provider.issue_auth_code(path.into_inner()) {
+        Some(code) => HttpResponse::Found()
+            .append_header((header::LOCATION, format!("/reception?code={}", code)))
+            .finish(),
+        None => redirect_to_err("404")
+    }
+}
+
+
 // Function for the catch-all "not found" route
 pub async fn not_found() -> impl Responder {
     Redirect::to("/error/404")
@@ -687,6 +943,58 @@ async fn error_page(req: HttpRequest, path: web::Path<String>) -> HttpResponse {
 }
 
 
+/**
+ * Where a suspended player lands (the JS sends them here on a "banned" error).
+ * Explains each active ban in the user's language, and when it ends.
+ */
+#[get("/banned")]
+async fn banned(pool: web::Data<MySqlPool>, req: HttpRequest) -> HttpResponse {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+
+    if !user_req_data.logged_in {
+        return redirect_to_login();
+    }
+
+    let bans: Vec<db::Ban> =
+        match db::get_active_bans(&pool, &user_req_data.get_username()).await {
+            Ok(bans) => bans,
+            Err(_e) => return redirect_to_err("500")
+        };
+
+    let lang: &SupportedLangs = &user_req_data.lang;
+    let notices: Vec<BanNotice> = bans
+        .iter()
+        .map(|ban: &db::Ban| {
+            let scope: BanScope = BanScope::from_code(&ban.ban_scope).unwrap_or(BanScope::Full);
+            let ends: String = match &ban.expires_timestamp {
+                Some(expires) => get_translation(
+                    "banned.ends",
+                    lang,
+                    Some(&[&bans::format_ban_end(expires)])
+                ),
+                None => get_translation("banned.never_ends", lang, None)
+            };
+
+            BanNotice {
+                scope: get_translation(&format!("banned.scope.{}", scope.to_string()), lang, None),
+                reason: get_translation("banned.reason", lang, Some(&[&ban.reason])),
+                ends
+            }
+        })
+        .collect();
+
+    let template: BannedTemplate = BannedTemplate {
+        texts: BannedTexts::new(&user_req_data),
+        user: user_req_data,
+        notices
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/html")
+        .body(template.render().unwrap())
+}
+
+
 #[get("/error")]
 async fn error_root() -> HttpResponse {
     HttpResponse::Found()
@@ -733,85 +1041,161 @@ pub fn redirect_to_dash() -> HttpResponse {
  * 
  * 
  * 
- * =========================
- * =========================
- * =====               =====
- * =====  POST ROUTES  =====
- * =====               =====
- * =========================
- * =========================
+ * ==============================
+ * ==============================
+ * =====                    =====
+ * =====  WEBSOCKET ROUTES  =====
+ * =====                    =====
+ * ==============================
+ * ==============================
  * 
  * 
+ * The browser opens a socket and we push live game events down it.
+ * Same JWT cookie as every other route (checked by the middleware).
  * 
  * 
 */
 
-#[post("/boot_player_pregame")]
-pub async fn boot_player_pregame(
+
+/**
+ * Live events for one game.
+ * Anybody who may view the pre-game page may listen to a pre-game game.
+ * Only players may listen once the game is in progress.
+ */
+#[get("/ws/game/{hashed_game_id}")]
+pub async fn game_socket(
     pool: web::Data<MySqlPool>,
     hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
     req: HttpRequest,
-    boot_player_data: web::Json<BootPlayerData>
-) -> HttpResponse {
-    // Make sure it's a real user
+    body: web::Payload,
+    path: web::Path<String>
+) -> Result<HttpResponse, ApiError> {
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    let player_id: i32 = match user_req_data.id {
-        Some(id) => id,
-        None => return return_unauthorized_err_json(&user_req_data)
-    };
+    let user_id: i32 = get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &path.into_inner())?;
 
-    let username: String = match user_req_data.to_owned().username {
-        Some(name) => name,
-        None => return return_unauthorized_err_json(&user_req_data)
-    };
+    let the_game: db::GameAndPlayers = db::get_game_and_players(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
-    let game_id: i32 = match hash_ids.decode(&boot_player_data.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 { ids[0] as i32 }
-            else { return return_internal_err_json() }
-        },
-        Err(_e) => return return_internal_err_json()
+    let may_listen: bool = if the_game.user_id_is_player(user_id) {
+        true
+    } else if the_game.game.game_status == GameStatus::PreGame {
+        let invitee_usernames: Vec<String> = db::get_invitee_usernames(&pool, game_id).await?;
+        the_game.game.open_game || invitee_usernames.contains(&user_req_data.get_username())
+    } else {
+        false
     };
 
-    // get the game
-    let the_game: db::Game = match db::get_game_by_id(&pool, game_id).await {
-        Ok(g) => g,
-        Err(_) => return return_unauthorized_err_json(&user_req_data)
+    if !may_listen {
+        return Err(ApiError::Forbidden);
+    }
+
+    let (response, session, msg_stream) = actix_ws::handle(&req, body)
+        .map_err(|_e| ApiError::Internal)?;
+
+    actix_web::rt::spawn(live_updates::run_socket(
+        session,
+        msg_stream,
+        hub.clone(),
+        LiveChannel::Game(game_id)
+    ));
+
+    Ok(response)
+}
+
+
+/**
+ * Live events for the user's dashboard (invitations coming and going).
+ */
+#[get("/ws/dashboard")]
+pub async fn dashboard_socket(
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    body: web::Payload
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let username: String = match user_req_data.to_owned().username {
+        Some(name) => name,
+        None => return Err(ApiError::Unauthorized)
     };
 
+    let (response, session, msg_stream) = actix_ws::handle(&req, body)
+        .map_err(|_e| ApiError::Internal)?;
+
+    actix_web::rt::spawn(live_updates::run_socket(
+        session,
+        msg_stream,
+        hub.clone(),
+        LiveChannel::User(username)
+    ));
+
+    Ok(response)
+}
+
+
+/* 
+ * 
+ * 
+ * 
+ * 
+ * =========================
+ * =========================
+ * =====               =====
+ * =====  POST ROUTES  =====
+ * =====               =====
+ * =========================
+ * =========================
+ * 
+ * 
+ * 
+ * 
+*/
+
+#[post("/boot_player_pregame")]
+pub async fn boot_player_pregame(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    boot_player_data: web::Json<BootPlayerData>
+) -> Result<HttpResponse, ApiError> {
+    // Make sure it's a real user
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let player_id: i32 = get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &boot_player_data.hashed_game_id)?;
+
+    // get the game
+    let the_game: db::Game = db::get_game_by_id(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
+
     // user must be game owner
     if the_game.owner_id != player_id {
-        return HttpResponse::Ok().json(BootPlayerSuccessObject {
-            success: false,
-            message: "Only game owner can boot players".to_string()
-        })
-    } else if username == boot_player_data.username {
-        return HttpResponse::Ok().json(BootPlayerSuccessObject {
-            success: false,
-            message: "Cannot boot game owner".to_string()
-        })
+        return Err(ApiError::NotOwner);
+    } else if user_req_data.get_username() == boot_player_data.username {
+        return Err(ApiError::CannotBootOwner);
     }
 
-    let player_removed: bool =
-        match db::delete_user_from_game(
-            &pool,
-            game_id,
-            &boot_player_data.username
-        ).await {
-            Ok(removed) => removed,
-            Err(_e) => return return_internal_err_json()
-        };
+    let player_removed: bool = db::delete_user_from_game(
+        &pool,
+        game_id,
+        &boot_player_data.username,
+        GameEventKind::Booted,
+        &db::PlayerInfo::from_user(&user_req_data)?
+    ).await?;
 
-    let message: String = if player_removed {
-        "Player was removed from game".to_string()
-    } else {
-        "Player was not removed".to_string()
-    };
+    if !player_removed {
+        return Err(ApiError::NotInGame);
+    }
 
-    HttpResponse::Ok().json(BootPlayerSuccessObject {
-        success: player_removed,
-        message
-    })
+    hub.publish_to_game(game_id, &LiveEvent::PlayerBooted {
+        username: boot_player_data.username.to_owned()
+    });
+
+    Ok(HttpResponse::Ok().json(BootPlayerSuccessObject {
+        success: true,
+        message: get_translation("msg.player_booted", &user_req_data.lang, None)
+    }))
 }
 
 
@@ -819,62 +1203,43 @@ pub async fn boot_player_pregame(
 pub async fn delete_invite(
     pool: web::Data<MySqlPool>,
     hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
     req: HttpRequest,
     delete_invite_data: web::Json<DeleteInviteData>
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    let player_id: i32 = match user_req_data.id {
-        Some(id) => id,
-        None => return return_unauthorized_err_json(&user_req_data)
-    };
-
-    let username: String = user_req_data.get_username();
-    let game_id: i32 = match hash_ids.decode(&delete_invite_data.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 { ids[0] as i32 }
-            else { return return_internal_err_json() }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
+    let player_id: i32 = get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &delete_invite_data.hashed_game_id)?;
 
     // get the game
-    let the_game: db::Game = match db::get_game_by_id(&pool, game_id).await {
-        Ok(g) => g,
-        Err(_) => return return_unauthorized_err_json(&user_req_data)
-    };
+    let the_game: db::Game = db::get_game_by_id(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
     let player_can_delete: bool = the_game.owner_id == player_id ||
-        username == delete_invite_data.username;
+        user_req_data.get_username() == delete_invite_data.username;
 
     // user must be game owner OR invitee
     if !player_can_delete {
-        return HttpResponse::Ok().json(UninviteSuccessObject {
-            success: false,
-            message: "Only game owner OR invited player can delete invitations".to_string()
-        })
+        return Err(ApiError::Forbidden);
     }
 
     let invite_deleted: bool =
-        match db::delete_invite(
-            &pool, game_id,
-            &delete_invite_data.username
-        ).await {
-            Ok(deleted) => deleted,
-            Err(_e) => return return_internal_err_json()
-        };
-    
-    let message: String = if invite_deleted {
-        "Invitation removed".to_string()
-    } else {
-        "Invitation was not removed".to_string()
-    };
+        db::uninvite_user(&pool, &user_req_data, game_id, &delete_invite_data.username).await?;
 
-    let uninvite_success_object: UninviteSuccessObject = UninviteSuccessObject {
-        success: invite_deleted, message
-    };
+    if !invite_deleted {
+        return Err(ApiError::NotFound);
+    }
 
-    HttpResponse::Ok().json(uninvite_success_object)
+    hub.publish_to_game(game_id, &LiveEvent::InviteDeleted {
+        username: delete_invite_data.username.to_owned()
+    });
+    hub.publish_to_user(&delete_invite_data.username, &LiveEvent::InvitesChanged);
+
+    Ok(HttpResponse::Ok().json(UninviteSuccessObject {
+        success: true,
+        message: get_translation("msg.invite_removed", &user_req_data.lang, None)
+    }))
 }
 
 
@@ -886,27 +1251,24 @@ pub async fn refresh_dashboard(
     pool: web::Data<MySqlPool>,
     hash_ids: web::Data<HashIds>,
     req: HttpRequest
-) -> HttpResponse {
-        // Make sure it's a real user
+) -> Result<HttpResponse, ApiError> {
+    // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
     let username: String = match user_req_data.username {
         Some(name) => name,
-        None => return return_unauthorized_err_json(&user_req_data)
+        None => return Err(ApiError::Unauthorized)
     };
 
     // get a list of invitations
     let raw_invitations: Vec<db::GameIdAndOwnerName> =
-        match db::get_invitations_by_username(&pool, username).await {
-            Ok(invites) => invites,
-            Err(_e) => return redirect_to_err("500")
-        };
+        db::get_invitations_by_username(&pool, username).await?;
 
     // hash each id into a new vector
     let dashboard_refresh_data: DashboardRefreshData = DashboardRefreshData {
         invited_games: get_hashes_from_game_ids(&hash_ids, raw_invitations)
     };
 
-    HttpResponse::Ok().json(dashboard_refresh_data)
+    Ok(HttpResponse::Ok().json(dashboard_refresh_data))
 }
 
 /**
@@ -917,45 +1279,27 @@ pub async fn block_user(
     pool: web::Data<MySqlPool>,
     block_this_obj: web::Json<UsernameToBlock>,
     req: HttpRequest
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.id.is_none() {
-        return return_unauthorized_err_json(&user_req_data)
-    }
+    get_player_id(&user_req_data)?;
 
     let username: String = user_req_data.get_username();
     let username_to_block: String = block_this_obj.username.to_owned();
 
-    let success: bool =
-        match db::block_user(&pool, &username, &username_to_block).await {
-            Ok(success) => success,
-            Err(e) => {
-
-                let error_string: String = e.to_string();
-                let message: String = if error_string.contains("1062") {
-                    "User is already blocked".to_string()
-                } else {
-                    "Error blocking user".to_string()
-                };
-
-                let block_success_obj: BlockSuccessful = BlockSuccessful {
-                    success: false,
-                    message
-                };
-
-                return HttpResponse::Ok().json(block_success_obj)
-            }
-        };
-
-    let message: String = if success { "User has been blocked".to_string() }
-        else { "Something went wrong. Could not block user.".to_string() };
+    if username_to_block == username {
+        return Err(ApiError::CannotBlockSelf);
+    }
 
-        let block_success_obj: BlockSuccessful = BlockSuccessful {
-            success, message
-        };
+    let success: bool = db::block_user(&pool, &username, &username_to_block).await?;
+    if !success {
+        return Err(ApiError::AlreadyBlocked);
+    }
 
-    HttpResponse::Ok().json(block_success_obj)
+    Ok(HttpResponse::Ok().json(BlockSuccessful {
+        success,
+        message: get_translation("msg.user_blocked", &user_req_data.lang, None)
+    }))
 }
 
 
@@ -967,38 +1311,25 @@ pub async fn unblock_user(
     pool: web::Data<MySqlPool>,
     unblock_this_obj: web::Json<UsernameToBlock>,
     req: HttpRequest
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.id.is_none() {
-        return return_unauthorized_err_json(&user_req_data)
-    }
+    get_player_id(&user_req_data)?;
 
     let username: String = user_req_data.get_username();
     let username_to_unblock: String = unblock_this_obj.username.to_owned();
 
     let unblock_successful: bool =
-        match db::delete_block(&pool, &username, &username_to_unblock).await {
-            Ok(success) => success,
-            Err(_e) => {
-                let block_success_obj: BlockSuccessful = BlockSuccessful {
-                    success: false,
-                    message: "Error unblocking user".to_string()
-                };
-
-                return HttpResponse::Ok().json(block_success_obj)
-            }
-        };
-
-    let message: String = if unblock_successful { "User has been unblocked".to_string() }
-        else { "Something went wrong. Could not unblock user.".to_string() };
+        db::delete_block(&pool, &username, &username_to_unblock).await?;
 
-        let block_success_obj: BlockSuccessful = BlockSuccessful {
-            success: unblock_successful,
-            message
-        };
+    if !unblock_successful {
+        return Err(ApiError::NotFound);
+    }
 
-    HttpResponse::Ok().json(block_success_obj)
+    Ok(HttpResponse::Ok().json(BlockSuccessful {
+        success: unblock_successful,
+        message: get_translation("msg.user_unblocked", &user_req_data.lang, None)
+    }))
 }
 
 
@@ -1009,53 +1340,36 @@ pub async fn unblock_user(
 pub async fn cancel_game(
     pool: web::Data<MySqlPool>,
     hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
     req: HttpRequest,
     hashed_game_id: web::Json<HashedGameId>
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.id.is_none() {
-        return return_unauthorized_err_json(&user_req_data)
-    }
-    let user_id: i32 = user_req_data.to_owned().id.unwrap();
+    let user_id: i32 = get_player_id(&user_req_data)?;
 
     // get the game to cancel
-    let game_id: i32 = match hash_ids.decode(&hashed_game_id.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 { ids[0] as i32 }
-            else { return return_internal_err_json() }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
-
-    let the_game: db::Game = match db::get_game_by_id(&pool, game_id).await {
-        Ok(g) => g,
-        Err(_) => return return_unauthorized_err_json(&user_req_data)
-    };
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id.hashed_game_id)?;
+    let the_game: db::Game = db::get_game_by_id(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
     // make sure user is owner
     if the_game.owner_id != user_id {
         // QUIT game instead.
-        return quit_game(pool, user_req_data, the_game).await;
+        return quit_game(pool, hub, user_req_data, the_game).await;
     }
 
-    // cancel the actual game
-    let success: bool = match db::cancel_game(&pool, game_id).await {
-        Ok(success) => success,
-        Err(_e) => return return_internal_err_json()
-    };
-
-    let message = if success {
-        "Game cancelled".to_string()
-    } else {
-        "Game was not cancelled".to_string()
-    };
+    // cancel the actual game. Only pre-games and in-progress games can be cancelled.
+    if !db::cancel_game(&pool, game_id, &db::PlayerInfo::from_user(&user_req_data)?).await? {
+        return Err(ApiError::GameOver);
+    }
 
-    let game_cancelled: GameCancelled = GameCancelled {
-        success, message
-    };
+    hub.publish_to_game(game_id, &LiveEvent::GameCancelled);
 
-    HttpResponse::Ok().json(game_cancelled)
+    Ok(HttpResponse::Ok().json(GameCancelled {
+        success: true,
+        message: get_translation("msg.game_cancelled", &user_req_data.lang, None)
+    }))
 }
 
 
@@ -1064,9 +1378,10 @@ pub async fn cancel_game(
  */
 pub async fn quit_game(
     pool: web::Data<MySqlPool>,
+    hub: web::Data<LiveHub>,
     user_req_data: auth::UserReqData,
     the_game: db::Game
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     // 1. get game's turns
     // 2. get the latest turn
     // 3. see if it has timed out
@@ -1074,12 +1389,9 @@ pub async fn quit_game(
     // 5. if it HAS timed out, remove game_users entry AND their turn
     // 6. send a notification so user reloads
     // 7. others must also notice and reload
-    let user_id: i32 = user_req_data.id.unwrap();
+    let user_id: i32 = get_player_id(&user_req_data)?;
     let game_id: i32 = the_game.id;    
-    let guesses: Vec<db::Guess> = match db::get_guesses(&pool, game_id, user_id).await {
-        Ok(g) => g,
-        Err(_e) => return return_internal_err_json()
-    };
+    let guesses: Vec<db::Guess> = db::get_guesses(&pool, game_id, user_id).await?;
 
     let max_wait: time::Duration = time::Duration::minutes(5);
     let mut game_is_fresh: bool = false;
@@ -1096,39 +1408,34 @@ pub async fn quit_game(
     }
 
     if game_is_fresh {
-        return HttpResponse::Ok().json(QuitGameSuccess {
-            success: false,
-            message: "Game must be five minutes old".to_string()
-        })
+        return Err(ApiError::QuitTooSoon);
     }
 
-    // Make sure the game doesn't get stuck on somebody else's turn
-    if the_game.turn_user_id.is_some() && the_game.turn_user_id.unwrap() == user_id {
-        // Actually switch the turn
-        let _next_turn_result: i32 = match db::next_turn(&pool, game_id).await {
-            Ok(new_user_turn_id) => new_user_turn_id,
-            Err(_) => return return_internal_err_json()
-        };
-    }
+    // Game is old. Can delete. If it was their turn, it passes on.
+    let player_removed: db::PlayerRemoved = db::remove_player_from_game(
+        &pool,
+        game_id,
+        user_id,
+        GameEventKind::Quit,
+        &db::PlayerInfo::from_user(&user_req_data)?
+    ).await?;
 
-    // Game is old. Can delete
-    let deleted: bool = match db::remove_player_from_game(&pool, game_id, user_id).await {
-        Ok(deleted) => deleted,
-        Err(_e) => return return_internal_err_json()
-    };
+    if let db::PlayerRemoved::NotInGame = player_removed {
+        return Err(ApiError::NotInGame);
+    }
 
-    let message: String = if deleted {
-        "You have quit the game".to_string()
-    } else {
-        "Something went wrong and you did not quit the game".to_string()
-    };
+    hub.publish_to_game(game_id, &LiveEvent::PlayerLeft {
+        username: user_req_data.get_username()
+    });
 
-    let quit_game_success: QuitGameSuccess = QuitGameSuccess {
-        success: deleted,
-        message
-    };
+    if let db::PlayerRemoved::TurnPassed(turn_passed) = player_removed {
+        publish_turn_passed(&pool, &hub, game_id, &turn_passed).await?;
+    }
 
-    HttpResponse::Ok().json(quit_game_success)
+    Ok(HttpResponse::Ok().json(QuitGameSuccess {
+        success: true,
+        message: get_translation("msg.quit_game", &user_req_data.lang, None)
+    }))
 }
 
 
@@ -1140,21 +1447,29 @@ pub async fn quit_game(
  */
 #[post("request_verification_email")]
 pub async fn request_verification_email(
-    req: HttpRequest
-) -> HttpResponse {
+    req: HttpRequest,
+    app_config: web::Data<AppConfig>,
+    auth_backend: web::Data<AuthBackend>
+) -> Result<HttpResponse, ApiError> {
     // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.id.is_none() {
-        return return_unauthorized_err_json(&user_req_data)
-    }
+    let user_id: i32 = match (user_req_data.logged_in, user_req_data.id) {
+        (true, Some(id)) => id,
+        _ => return Err(ApiError::Unauthorized)
+    };
 
-    let response: auth_code_shared::SendVerificationEmailResponse =
-        match crankword_io::send_email_verification_request(&user_req_data).await {
-            Ok(res) => res,
-            Err(_e) => return return_internal_err_json()
+    let email_req: auth_code_shared::SendVerificationEmailRequest =
+        auth_code_shared::SendVerificationEmailRequest {
+            client_id: app_config.auth.client_id.to_owned(),
+            client_secret: app_config.auth.client_secret.to_owned(),
+            user_id,
+            username: user_req_data.get_username()
         };
 
-    HttpResponse::Ok().json(response)
+    let response: auth_code_shared::SendVerificationEmailResponse =
+        auth_backend.send_email_verification_request(email_req).await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
 
@@ -1178,132 +1493,43 @@ pub async fn refresh_in_prog_players(
     hash_ids: web::Data<HashIds>,
     req: HttpRequest,
     hashed_game_id: web::Json<HashedGameId>
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    let player_id: i32 = match user_req_data.id {
-        Some(id) => id,
-        None => return return_unauthorized_err_json(&user_req_data)
-    };
-
-    let game_id: i32 = match hash_ids.decode(&hashed_game_id.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 { ids[0] as i32 }
-            else { return return_internal_err_json() }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
+    let player_id: i32 = get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id.hashed_game_id)?;
 
     // get the game
-    let mut the_game: db::Game = match db::get_game_by_id(&pool, game_id).await {
-        Ok(g) => g,
-        Err(_) => return return_unauthorized_err_json(&user_req_data)
-    };
+    let the_game: db::Game = db::get_game_by_id(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
     // Get the players with their scores, but no words in the scores
     let players: Vec<db::PlayerRefreshData> =
-        match db::get_players_refresh_data_by_game_id(&pool, &the_game).await {
-            Ok(p) => p,
-            Err(_) => return return_unauthorized_err_json(&user_req_data)
-        };
+        db::get_players_refresh_data_by_game_id(&pool, &the_game).await?;
 
     // make sure player is actually in the game
     if !player_id_is_in_players_refresh_data(player_id, &players) {
-        return return_unauthorized_err_json(&user_req_data)
-    }
-
-    // CHECK FOR TIMEOUT AND SWITCH TURN
-    // Only GAME OWNER checks and initiates switch_turn
-    // and only for multi-player games
-    if the_game.owner_id == player_id && players.len() > 1 {
-        let now: OffsetDateTime = OffsetDateTime::now_utc();
-        if now >= the_game.turn_timeout && the_game.turn_user_id.is_some() {
-            let current_turn_user_id: i32 = the_game.turn_user_id.unwrap();
-
-            // PUT DUDS into player who missed a turn
-            // get the NUMBER of player guesses.
-            let turn_player_guess_count: u8 =
-                match db::get_guess_count(
-                    &pool,
-                    game_id,
-                    current_turn_user_id
-                ).await {
-                    Ok(count) => count,
-                    Err(_e) => return return_internal_err_json()
-                };
-            
-            // Insert dud guess
-            let dud_word: &str = "-----";
-            let _insert_dud_result: i64 = match db::new_guess(
-                &pool,
-                current_turn_user_id,
-                game_id,
-                dud_word,
-                turn_player_guess_count + 1
-            ).await {
-                Ok(new_id) => new_id,
-                Err(_) => return return_internal_err_json()
-            };
-
-            // Actually switch the turn
-            let _next_turn_result: i32 = match db::next_turn(&pool, game_id).await {
-                Ok(new_user_turn_id) => new_user_turn_id,
-                Err(_) => return return_unauthorized_err_json(&user_req_data)
-            };
-
-            // Turn has been switched. Refresh game object.
-            the_game = match db::get_game_by_id(&pool, game_id).await {
-                Ok(g) => g,
-                Err(_) => return return_unauthorized_err_json(&user_req_data)
-            };
-
-            // check if game is over
-            // 1. check if this was player's final turn
-            // 2. if so, check if anybody else has remaining turns
-            // 3. if nobody else can play, game over (no winner)
-
-            if turn_player_guess_count + 1 >= game_logic::MAX_TURNS {
-                /*
-                * This was the final turn, and NOT the correct guess.
-                * So it's game over for this player.
-                * So check if anybody else still has a turn.
-                */
-
-                let turns_still_exist_result: Result<bool, anyhow::Error> =
-                    db::somebody_can_play(&pool, game_id).await;
-
-                if turns_still_exist_result.is_err() {
-                    return return_internal_err_json();
-                }
-                
-                let turns_still_exist: bool = turns_still_exist_result.unwrap();
-
-                if !turns_still_exist {
-                    // game is over.
-                    let _finish_game_result: Result<u8, anyhow::Error> =
-                        finish_game(&pool, game_id, None).await;
-                }
-            }
-        }
+        return Err(ApiError::NotPlayer);
     }
 
-
     // Client must know whose turn it is
     let current_turn_id: i32 = match the_game.turn_user_id {
         Some(id) => id,
-        None => return return_unauthorized_err_json(&user_req_data)
+        None => return Err(ApiError::GameNotStarted)
     };
 
     let turn_timeout: time::OffsetDateTime = the_game.turn_timeout;
+    let settings: db::GameSettings = db::get_game_settings(&pool, game_id).await?;
 
     let in_prog_refresh: InProgRefresh = InProgRefresh {
         current_turn_id,
         players,
         game_status: the_game.game_status,
         turn_timeout,
+        settings,
     };
 
-    HttpResponse::Ok().json(in_prog_refresh)  
+    Ok(HttpResponse::Ok().json(in_prog_refresh))
 }
 
 
@@ -1317,39 +1543,26 @@ pub async fn refresh_pregame(
     hash_ids: web::Data<HashIds>,
     req: HttpRequest,
     hashed_game_id: web::Json<HashedGameId>
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.get_role() == "guest" {
-        return return_unauthorized_err_json(&user_req_data);
-    }
-
-    let game_id: i32 = match hash_ids.decode(&hashed_game_id.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 { ids[0] as i32 }
-            else { return return_internal_err_json() }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
+    get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id.hashed_game_id)?;
 
-    let the_game: db::GameAndPlayers = match db::get_game_and_players(&pool, game_id).await {
-        Ok(gap) => gap,
-        Err(_e) => return return_internal_err_json()
-    };
+    let the_game: db::GameAndPlayers = db::get_game_and_players(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
-    let invitee_usernames: Vec<String> =
-        match db::get_invitee_usernames(&pool, game_id).await {
-            Ok(usernames) => usernames,
-            Err(_e) => return return_internal_err_json()
-        };
+    let invitee_usernames: Vec<String> = db::get_invitee_usernames(&pool, game_id).await?;
+    let settings: db::GameSettings = db::get_game_settings(&pool, game_id).await?;
 
     let refresh_data: PreGameRefresh = PreGameRefresh {
         game_status: the_game.game.game_status,
         players: the_game.players,
-        invitee_usernames
+        invitee_usernames,
+        settings
     };
 
-    HttpResponse::Ok().json(refresh_data)
+    Ok(HttpResponse::Ok().json(refresh_data))
 }
 
 
@@ -1357,552 +1570,349 @@ pub async fn refresh_pregame(
 #[post("/join_game")]
 pub async fn join_game(
     pool: web::Data<MySqlPool>,
+    app_config: web::Data<AppConfig>,
     hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
     req: HttpRequest,
     game_join_hash_id: web::Json<HashedGameId>
-) -> HttpResponse {
-    // Make sure it's a real user
+) -> Result<HttpResponse, ApiError> {
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.get_role() == "guest" {
-        return return_unauthorized_err_json(&user_req_data);
-    }
-
-    let game_id: i32 = match hash_ids.decode(&game_join_hash_id.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 { ids[0] as i32 }
-            else { return return_internal_err_json() }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
+    let game_id: i32 = decode_game_id(&hash_ids, &game_join_hash_id.hashed_game_id)?;
 
-    let owner_username: String = match db::get_owner_username(&pool, game_id).await {
-        Ok(name) => name,
-        Err(_e) => return return_internal_err_json()
-    };
-
-    // find out if user is blocked by invitee
-    let is_blocked: bool =
-        match db::is_blocked(
-            &pool,
-            &owner_username,
-            &user_req_data.get_username()
-        ).await {
-            Ok(blocked) => blocked,
-            Err(_e) => return return_internal_err_json()
-        };
+    game_actions::join_game(&pool, &hub, &app_config.limits, &user_req_data, game_id).await?;
+    Ok(HttpResponse::Ok().json(JoinGameSuccess { success: true }))
+}
 
-    if is_blocked {
-        return HttpResponse::Ok().json(InviteSuccessObject {
-            invite_success: false,
-            message: "User has blocked you".to_string()
-        })
-    }
 
-    // Make sure they're not already in too many pregame or inprogress games.
-    let games_count: u8 = 
-        match db::get_current_games_count(&pool, user_req_data.id.unwrap()).await {
-            Ok(count) => count,
-            Err(_e) => return return_internal_err_json()
-        };
 
-    if games_count >= utils::MAX_CURRENT_GAMES {
-        return HttpResponse::Ok().json(JoinGameFailure {
-            success: false,
-            error: "You're in too many current games".to_string()
-        });
-    }
+#[post("/leave_game")]
+pub async fn leave_game(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    game_join_hash_id: web::Json<HashedGameId>
+) -> Result<HttpResponse, ApiError> {
+    // Make sure it's a real user
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &game_join_hash_id.hashed_game_id)?;
 
-    let other_players_count: u8 =
-        match db:: get_game_players_count(&pool, game_id).await {
-            Ok(count) => count,
-            Err(_e) => return return_internal_err_json()
-        };
+    // get game to make sure it's pre_game
+    let the_game: db::Game = db::get_game_by_id(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
-    if other_players_count >= utils::MAX_PLAYERS {
-        return HttpResponse::Ok().json(JoinGameFailure {
-            success: false,
-            error: "Too many current players".to_string()
-        });
+    if the_game.game_status != GameStatus::PreGame {
+        return Err(ApiError::GameAlreadyStarted);
     }
 
-    // Use may join
-    let user_joined_game: bool = match db::user_join_game(
+    // User may leave
+    let user_left_game: bool = db::delete_user_from_game(
         &pool,
-        &user_req_data,
-        game_id
-    ).await {
-        Ok(joined) => joined,
-        Err(e) => return HttpResponse::Ok().json(JoinGameFailure::new(e.to_string()))
-    };
+        game_id,
+        &user_req_data.get_username(),
+        GameEventKind::Left,
+        &db::PlayerInfo::from_user(&user_req_data)?
+    ).await?;
 
-    if user_joined_game {
-        // delete invitation. Don't worry about the result.
-        let _invite_deleted: bool =
-            match db::delete_invite(
-                &pool,
-                game_id,
-                &user_req_data.get_username()
-            ).await {
-                Ok(deleted) => deleted,
-                Err(_e) => {
-                    eprintln!("Error deleting invitation");
-                    false
-                }
-            };
+    if !user_left_game {
+        return Err(ApiError::NotInGame);
     }
 
-    HttpResponse::Ok().json(JoinGameSuccess { success: user_joined_game })
+    hub.publish_to_game(game_id, &LiveEvent::PlayerLeft {
+        username: user_req_data.get_username()
+    });
+
+    Ok(HttpResponse::Ok().json(LeaveGameSuccess { success: true }))
 }
 
 
+#[post("/start_game")]
+pub async fn start_game(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
+    req: HttpRequest,
+    game_start_id: web::Json<HashedGameId>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let game_id: i32 = decode_game_id(&hash_ids, &game_start_id.hashed_game_id)?;
 
-#[post("/leave_game")]
-pub async fn leave_game(
+    game_actions::start_game(&pool, &hub, &user_req_data, game_id).await?;
+    Ok(HttpResponse::Ok().json(StartGameSuccess { success: true }))
+}
+
+
+/**
+ * Owner changes the rules (guesses, turn timer, player cap) in the pre-game lobby.
+ * Once the game has started the settings are locked.
+ */
+#[post("/update_settings")]
+pub async fn update_settings(
     pool: web::Data<MySqlPool>,
+    app_config: web::Data<AppConfig>,
     hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
     req: HttpRequest,
-    game_join_hash_id: web::Json<HashedGameId>
-) -> HttpResponse {
+    settings_data: web::Json<GameSettingsData>
+) -> Result<HttpResponse, ApiError> {
     // Make sure it's a real user
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.get_role() == "guest" {
-        return return_unauthorized_err_json(&user_req_data);
-    }
+    let user_id: i32 = get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &settings_data.hashed_game_id)?;
 
-    let game_id: i32 = match hash_ids.decode(&game_join_hash_id.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 { ids[0] as i32 }
-            else { return return_internal_err_json() }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
+    let the_game: db::GameAndPlayers = db::get_game_and_players(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
-    let leave_game_failure_resp: HttpResponse =
-        HttpResponse::Ok().json(LeaveGameFailure {
-            error: "Failed to leave game".to_string(),
-            success: false
-        });
+    if the_game.game.owner_id != user_id {
+        return Err(ApiError::NotOwner);
+    } else if the_game.game.game_status != GameStatus::PreGame {
+        return Err(ApiError::GameAlreadyStarted);
+    }
 
-    // get game to make sure it's pre_game
-    let the_game: db::Game = match db::get_game_by_id(&pool, game_id).await {
-        Ok(g) => g,
-        Err(_e) => return return_internal_err_json()
-    };
+    if let Some(settings_error) = game_logic::get_settings_error(
+        settings_data.max_guesses,
+        settings_data.turn_seconds,
+        settings_data.max_players,
+        app_config.limits.max_players
+    ) {
+        return Err(ApiError::InvalidSettings(settings_error));
+    }
 
-    if the_game.game_status != GameStatus::PreGame {
-        return leave_game_failure_resp
+    // Don't lock out players who have already joined
+    if (settings_data.max_players as usize) < the_game.players.len() {
+        return Err(ApiError::PlayersAlreadyJoined);
     }
 
-    // Use may leave
-    let user_left_game: bool = match db::delete_user_from_game(
-        &pool,
+    let settings: db::GameSettings = db::GameSettings {
         game_id,
-        &user_req_data.get_username()
-    ).await {
-        Ok(quit) => quit,
-        Err(_e) => return leave_game_failure_resp
+        max_guesses: settings_data.max_guesses,
+        turn_seconds: settings_data.turn_seconds,
+        max_players: settings_data.max_players,
+        hard_mode: settings_data.hard_mode
     };
 
-    HttpResponse::Ok().json(LeaveGameSuccess { success: user_left_game })
+    // update_game_settings only touches pre-games, so the game may have started meanwhile
+    if !db::update_game_settings(&pool, &settings).await? {
+        return Err(ApiError::GameAlreadyStarted);
+    }
+
+    hub.publish_to_game(game_id, &LiveEvent::SettingsChanged);
+    Ok(HttpResponse::Ok().json(UpdateSettingsSuccess { success: true }))
 }
 
 
-#[post("/start_game")]
-pub async fn start_game(
+/**
+ * Game owner adds a computer player to their pre-game.
+ * Bots take up a player slot like anybody else,
+ * and the owner can boot them the same way too.
+ */
+#[post("/add_bot")]
+pub async fn add_bot(
     pool: web::Data<MySqlPool>,
     hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
     req: HttpRequest,
-    game_start_id: web::Json<HashedGameId>
-) -> HttpResponse {
-    // Make sure it's a real user
+    add_bot_data: web::Json<AddBotData>
+) -> Result<HttpResponse, ApiError> {
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.get_role() == "guest" {
-        return return_unauthorized_err_json(&user_req_data);
-    }
+    let user_id: i32 = get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &add_bot_data.hashed_game_id)?;
 
-    let game_id: i32 = match hash_ids.decode(&game_start_id.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 {
-                ids[0] as i32
-            } else {
-                return return_internal_err_json()
-            }
-        },
-        Err(_e) => return return_internal_err_json()
+    let bot_level: BotLevel = match BotLevel::from_string(&add_bot_data.bot_level) {
+        Some(level) => level,
+        None => return Err(ApiError::UnknownBotLevel)
     };
 
-    let the_game: db::Game = match db::get_game_by_id(&pool, game_id).await {
-        Ok(the_game) => the_game,
-        Err(_e) => return return_internal_err_json()
-    };
+    let the_game: db::Game = db::get_game_by_id(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
-    if the_game.owner_id != user_req_data.id.unwrap() {
-        // TODO: put the error message into the resources file
-        return HttpResponse::Ok().json(StartGameFailure {
-            error: "Only the game owner can start a game.".to_string(),
-            success: false
-        });
+    if the_game.owner_id != user_id {
+        return Err(ApiError::NotOwner);
     } else if the_game.game_status != GameStatus::PreGame {
-        // TODO: put the error message into the resources file
-        return HttpResponse::Ok().json(StartGameFailure {
-            error: "Game has already started.".to_string(),
-            success: false
-        });
+        return Err(ApiError::GameAlreadyStarted);
     }
 
-    // Call the db to change the status of the game
-    let game_started: StartGameSuccess =
-        match db::start_game(&pool, the_game.id).await {
-            Ok(success) => StartGameSuccess {success},
-            Err(_e) => return return_internal_err_json()
-        };
+    let players_count: u8 = db::get_game_players_count(&pool, game_id).await?;
+    let settings: db::GameSettings = db::get_game_settings(&pool, game_id).await?;
 
-    // delete all invitations (some may be pending, so still extant)
-    if game_started.success {
-        let _deleted_invite_count_result: Result<u8, anyhow::Error> =
-            db::delete_invites(&pool, game_id).await;
+    if players_count >= settings.max_players {
+        return Err(ApiError::TooManyPlayers);
     }
 
-    HttpResponse::Ok().json(game_started)
+    let bot_info: db::PlayerInfo = db::add_bot(&pool, &user_req_data, game_id, &bot_level).await?;
+
+    hub.publish_to_game(game_id, &LiveEvent::PlayerJoined {
+        user_id: bot_info.user_id,
+        username: bot_info.username.to_owned()
+    });
+
+    Ok(HttpResponse::Ok().json(BotAdded {
+        success: true,
+        username: bot_info.username
+    }))
 }
 
 
 #[post("/new_game")]
 pub async fn new_game(
     pool: web::Data<MySqlPool>,
+    app_config: web::Data<AppConfig>,
     hash_ids: web::Data<HashIds>,
     req: HttpRequest,
     invite_only_data: web::Json<InviteOnlyData>
-) -> HttpResponse {
-    // Make sure it's a real user
+) -> Result<HttpResponse, ApiError> {
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    if user_req_data.get_role() == "guest" {
-        return return_unauthorized_err_json(&user_req_data);
-    }
 
     // make the game and get the id
-    let user_id: i32 = user_req_data.id.unwrap();
+    let game_id: i32 = game_actions::create_game(
+        &pool,
+        &app_config.limits,
+        &user_req_data,
+        invite_only_data.invite_only,
+        invite_only_data.word_length,
+        invite_only_data.game_lang.as_deref()
+    ).await?;
 
-    // Make sure they're not already in a pregame or inprogress game.
-    let games_count: u8 = 
-        match db::get_current_games_count(&pool, user_id).await {
-            Ok(count) => count,
-            Err(_e) => return return_internal_err_json()
-        };
+    // send back the game_id so the front-end can redirect.
+    Ok(HttpResponse::Ok().json(HashedGameId { 
+        hashed_game_id: hash_ids.encode(&[game_id as u64])
+    }))
+}
 
-    if games_count >= utils::MAX_CURRENT_GAMES {
-        return HttpResponse::Ok().json(JoinGameFailure {
-            success: false,
-            error: "Too many current games".to_string()
-        });
-    }
+/**
+ * Start today's daily challenge.
+ * Everybody gets the same word, and each user gets one attempt per day.
+ * If the user's attempt is still going we just send them back to it.
+ */
+#[post("/new_daily_game")]
+pub async fn new_daily_game(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    let user_id: i32 = get_player_id(&user_req_data)?;
+    let today: Date = OffsetDateTime::now_utc().date();
+    bans::check_not_banned(&pool, &user_req_data, BannedAction::CreateGame { open_game: false })
+        .await?;
+
+    let daily_game_option: Option<db::DailyGame> =
+        db::get_daily_game(&pool, today, user_id).await?;
+
+    if let Some(daily_game) = daily_game_option {
+        if daily_game.finished_timestamp.is_some() {
+            return Err(ApiError::DailyAlreadyPlayed);
+        }
 
-    // only verified users can create OPEN games
-    if !invite_only_data.invite_only && !user_req_data.email_verified {
-        let success: bool = false;
-        let error: String = "Verify your email to create open games.".to_string();            
-        return HttpResponse::Ok().json(JoinGameFailure { success, error });
+        return Ok(HttpResponse::Ok().json(HashedGameId {
+            hashed_game_id: hash_ids.encode(&[daily_game.game_id as u64])
+        }));
     }
 
-    let game_id: i32 = match db::new_game(
-        &pool,
-        &user_req_data,
-        !invite_only_data.invite_only
-    ).await {
-        Ok(id) => id,
-        Err(e) => {
-            return HttpResponse::Unauthorized().json(
-                ErrorResponse {
-                error: e.to_string(),
-                code: 404
-    })}};
+    // Most likely to fail on a double-click racing the first request
+    let game_id: i32 = db::new_daily_game(&pool, &user_req_data, today).await?;
 
-    // send back the game_id so the front-end can redirect.
-    HttpResponse::Ok().json(HashedGameId { 
+    Ok(HttpResponse::Ok().json(HashedGameId {
         hashed_game_id: hash_ids.encode(&[game_id as u64])
-     })
+    }))
 }
 
+
 /**
  * One of the most important functions.
  * User's guesses must be checked in multiple ways:
  * 1. get the GAME -- NEW STRUCT which includes PLAYER IDs
- * 2. make sure user belongs in game AND it is user's turn
- * 3. make sure word is REAL WORD
- * 4. add guess to DB table
- * 5. check word against winning word and return vector of LetterScores
+ * 2. make sure user belongs in game
+ * 3. everything else (turn, real word, saving and scoring) happens in submit_guess,
+ *    which bots go through too
+ * Steps 1 and 2 live in game_actions::guess_word, shared with the JSON API.
  */
 #[post("/check_guess")]
 pub async fn check_guess(
     pool: web::Data<MySqlPool>,
     hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
     req: HttpRequest,
     word_json: web::Json<WordToCheck>
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    let user_id: i32 = match user_req_data.id {
-        Some(id) => id,
-        None => {
-            // user is guest
-            let error: String = get_translation("err.403.body", &user_req_data.lang, None);
-            return HttpResponse::Unauthorized().json(
-                ErrorResponse {
-                error,
-                code: 403
-            });
-    }};
-
-    let game_id: i32 = match hash_ids.decode(&word_json.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 {
-                ids[0] as i32
-            } else {
-                return return_internal_err_json()
-            }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
-
-    // User is logged in
-    // Get the game
-    let game_and_players: GameAndPlayers =
-        match db::get_game_and_players(&pool, game_id).await {
-            Ok(data) => data,
-            Err(_e) => return return_internal_err_json()
-        };
+    let game_id: i32 = decode_game_id(&hash_ids, &word_json.hashed_game_id)?;
 
-    // Make sure user is a player
-    if !game_and_players.user_is_player(
-        db::PlayerInfo {
-            user_id,
-            username: user_req_data.get_username()
-        }
-    ) {
-        // User is NOT player for this game.
-        let error: String = get_translation("err.403.body", &user_req_data.lang, None);
-        return HttpResponse::Unauthorized().json(
-            ErrorResponse {
-            error,
-            code: 403
-        });
-    }
-
-    // Make sure it is player's turn
-    // If it's not the user's turn, return a json object which indicates that.
-    let player_turn: bool = game_and_players.game.turn_user_id.is_some() &&
-        game_and_players.game.turn_user_id.unwrap() == user_id;
-
-    if !player_turn {
-        return HttpResponse::Ok().json(WrongTurn::new());
-    }
-
-    // get the NUMBER of player guesses.
-    let player_guess_count: u8 =
-        match db::get_guess_count(&pool, game_id, user_id).await {
-            Ok(count) => count,
-            Err(_e) => return return_internal_err_json()
-        };
-
-    if player_guess_count >= game_logic::MAX_TURNS {
-        return HttpResponse::Ok().json(MaxGuesses::new());
-    }
-
-    // make sure guess word is REAL WORD
-    if !words_all::is_real_word(&word_json.guess_word) {
-        return HttpResponse::Ok().json(FakeWord::new());
-    }
-
-    // add guess to the DB
-    let add_guess_result: Result<i64, anyhow::Error> = db::new_guess(
+    let guess_result: game_logic::CheckGuessResult = game_actions::guess_word(
         &pool,
-        user_id,
-        game_and_players.game.id,
-        &word_json.guess_word,
-        player_guess_count + 1
-    ).await;
-
-    if add_guess_result.is_err() {
-        eprintln!("Error adding guess result");
-        return return_internal_err_json();
-    }
-    
-    // If it's not the user's turn, return a json object which indicates that.
-    let winning_word: String = match db::get_winning_word(&pool, game_id).await {
-        Ok(word) => word,
-        Err(_e) => {
-            return HttpResponse::Unauthorized().json(ErrorResponse{
-                error: String::from("Not Found"),
-                code: 406
-            })
-        }
-    };
-
-    let guess_result_basic: game_logic::CheckGuessResultBasic =
-        game_logic::check_guess(&word_json.guess_word, &winning_word);
-    
-    let mut guess_result: game_logic::CheckGuessResult = 
-        game_logic::CheckGuessResult::new(
-            guess_result_basic,
-            false,
-            user_id
-        );
-
-    // Do we have a winner?
-    if guess_result.is_winner {
-        let finish_game_result: Result<u8, anyhow::Error> =
-            finish_game(&pool, game_id, Some(user_id)).await;
-        
-        if finish_game_result.is_err() {
-            return return_internal_err_json();
-        }
-
-        guess_result.game_over = true;
-    } else {
-
-        // make it the next player's turn:
-        let next_turn_id: i32 =
-            match db::next_turn(&pool, game_id).await {
-                Ok(new_id) => new_id,
-                Err(_) => {
-                    eprintln!("Error switching turns.");
-                    return return_internal_err_json();
-                }
-            };
-
-        // Make sure the user knows whose turn is next.
-        guess_result.next_turn_id = next_turn_id;
-
-        // check if game is over
-        // 1. check if this was player's final turn
-        // 2. if so, check if anybody else has remaining turns
-        // 3. if nobody else can play, game over (no winner)
-
-        if player_guess_count + 1 >= game_logic::MAX_TURNS {
-            /*
-             * This was the final turn, and NOT the correct guess.
-             * So it's game over for this player.
-             * So check if anybody else still has a turn.
-             */
-
-            let turns_still_exist_result: Result<bool, anyhow::Error> =
-                db::somebody_can_play(&pool, game_id).await;
-
-            if turns_still_exist_result.is_err() {
-                return return_internal_err_json();
-            }
-            
-            let turns_still_exist: bool = turns_still_exist_result.unwrap();
-
-            if !turns_still_exist {
-                // game is over.
-                let _finish_game_result: Result<u8, anyhow::Error> =
-                    finish_game(&pool, game_id, None).await;
-                guess_result.game_over = true;
-            }
-        }
-    }
+        &hub,
+        &user_req_data,
+        game_id,
+        &word_json.guess_word
+    ).await?;
 
-    HttpResponse::Ok().json(guess_result)
+    Ok(HttpResponse::Ok().json(guess_result))
 }
 
 
 /**
- * TODO:
- * All errors should send back THE SAME OBJECT.
- * inviet_success can be FALSE
- * and include a MESSAGE explaining what went wrong.
+ * Owner invites another user to the pre-game.
+ * Failures (blocked, max invites, not owner...) come back as an ApiError.
  */
 #[post("/invite_player")]
 pub async fn invite_player(
     pool: web::Data<MySqlPool>,
+    app_config: web::Data<AppConfig>,
     hash_ids: web::Data<HashIds>,
+    hub: web::Data<LiveHub>,
     req: HttpRequest,
     invite_data: web::Json<InviteData>
- ) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    let user_id: i32 = match user_req_data.id {
-        Some(id) => id,
-        None => { return return_unauthorized_err_json(&user_req_data); }
-    };
-
-    // find out if user is blocked by invitee
-    let is_blocked: bool =
-        match db::is_blocked(
-            &pool,
-            &invite_data.invited_player_username,
-            &user_req_data.get_username()
-        ).await {
-            Ok(blocked) => blocked,
-            Err(_e) => return return_internal_err_json()
-        };
-
-    if is_blocked {
-        return HttpResponse::Ok().json(InviteSuccessObject {
-            invite_success: false,
-            message: "User has blocked you".to_string()
-        })
-    }
 
     // The game id is hashed. Decode it.
-    let game_id: i32 = match hash_ids.decode(&invite_data.hashed_game_id) {
-        Ok(hash_ids) => {
-            if hash_ids.len() > 0 { hash_ids[0] as i32 }
-            else { return return_internal_err_json() }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
+    let game_id: i32 = decode_game_id(&hash_ids, &invite_data.hashed_game_id)?;
 
-    // get game and make sure user is owner
-    let the_game: db::Game = match db::get_game_by_id(&pool, game_id).await {
-        Ok(g) => g,
-        Err(_e) => return redirect_to_err("404")
-    };
-
-    if the_game.owner_id != user_id {
-        return return_internal_err_json()
-    }
+    game_actions::invite_player(
+        &pool,
+        &hub,
+        &app_config.limits,
+        &user_req_data,
+        game_id,
+        &invite_data.invited_player_username
+    ).await?;
 
-    // make sure we don't already have too many invites
-    let invites_count: u8 =
-        match db:: get_invites_count(&pool, game_id).await {
-            Ok(count) => count,
-            Err(_e) => return return_internal_err_json()
-        };
+    Ok(HttpResponse::Ok().json(InviteSuccessObject {
+        invite_success: true,
+        message: get_translation("msg.user_invited", &user_req_data.lang, None)
+    }))
+}
 
-    if invites_count >= utils::MAX_INVITES {
-        let success_object: InviteSuccessObject = InviteSuccessObject {
-            invite_success: false,
-            message: "Max invites reached".to_string()
-        };
-        
-        return HttpResponse::Ok().json(success_object);
-    }
 
-    // user is owner. Make the invite
-    let invite_success: bool =
-        match db::invite_user(
-            &pool,
-            &invite_data.invited_player_username,
-            &user_req_data.get_username(),
-            game_id
-        ).await {
-            Ok(invited) => invited,
-            Err(_e) => return return_internal_err_json()
-        };
+/**
+ * The full turn-by-turn timeline of a finished game, words included.
+ * Words are only revealed once the game is over. Until then players
+ * only get each other's scores (see get_wordless_guess_scores).
+ */
+#[post("/get_replay")]
+pub async fn get_replay(
+    pool: web::Data<MySqlPool>,
+    hash_ids: web::Data<HashIds>,
+    req: HttpRequest,
+    hashed_game_id: web::Json<HashedGameId>
+) -> Result<HttpResponse, ApiError> {
+    let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
+    get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id.hashed_game_id)?;
 
-    let message: String = if invite_success {
-        "User invited".to_string()
-    } else {
-        "User not invited".to_string()
-    };
+    let game_and_players: GameAndPlayers = db::get_game_and_players(&pool, game_id).await
+        .map_err(|_e| ApiError::NotFound)?;
 
-    let success_object: InviteSuccessObject = InviteSuccessObject {
-        invite_success,
-        message
-    };
+    if game_and_players.game.game_status != GameStatus::Finished {
+        return Err(ApiError::GameNotOver);
+    }
 
-    HttpResponse::Ok().json(success_object)
- }
+    // Everybody's guesses, including players who quit or were booted
+    let guesses: Vec<db::Guess> = db::get_all_guesses(&pool, game_id).await?;
+    let departed_players: Vec<db::PlayerInfo> = db::get_departed_players(&pool, game_id).await?;
+    Ok(HttpResponse::Ok().json(build_game_replay(game_and_players, departed_players, guesses)))
+}
 
 
 /**
@@ -1915,34 +1925,19 @@ pub async fn get_guess_scores(
     hash_ids: web::Data<HashIds>,
     req: HttpRequest,
     hashed_game_id: web::Json<HashedGameId>
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     let user_req_data: auth::UserReqData = auth::get_user_req_data(&req);
-    let user_id: i32 = match user_req_data.id {
-        Some(id) => id,
-        None => { return return_unauthorized_err_json(&user_req_data); }
-    };
-
-    let game_id: i32 = match hash_ids.decode(&hashed_game_id.hashed_game_id) {
-        Ok(ids) => {
-            if ids.len() > 0 {
-                ids[0] as i32
-            } else {
-                return return_internal_err_json()
-            }
-        },
-        Err(_e) => return return_internal_err_json()
-    };
+    let user_id: i32 = get_player_id(&user_req_data)?;
+    let game_id: i32 = decode_game_id(&hash_ids, &hashed_game_id.hashed_game_id)?;
 
     let all_scores: Vec<game_logic::GuessAndScore> =
-        match db::get_guess_scores(&pool, game_id, user_id).await {
-            Ok(scores) => scores,
-            Err(_e) => return return_unauthorized_err_json(&user_req_data)
-        };
+        db::get_guess_scores(&pool, game_id, user_id).await
+            .map_err(|_e| ApiError::NotFound)?;
     
     // now I have all the scores. I need to serialize them and deliver them.
     let scores_obj: AllPlayerScores = AllPlayerScores {
         scores: all_scores
     };
 
-    HttpResponse::Ok().json(scores_obj)
+    Ok(HttpResponse::Ok().json(scores_obj))
 }
@@ -3,18 +3,23 @@ use askama::Template;
 use actix_web::{
     web,
     cookie::{ Cookie },
-    HttpResponse,
-    http::StatusCode
+    dev::ConnectionInfo,
+    HttpRequest
 };
 use anyhow::{ Result, };
 use sqlx::{ MySqlPool };
-use time::{ OffsetDateTime };
+use time::{ Date, OffsetDateTime };
 
 use crate::{
-    game_logic::{ self,GameStatus },
-    db::{ self, PlayerInfo,PlayerRefreshData },
-    auth, resource_mgr::{*},
-    resources::get_translation
+    api_error::ApiError,
+    game_logic::{ self, GameStatus, Word, WordError },
+    db::{ self, GameAndPlayers, PlayerInfo,PlayerRefreshData },
+    auth, auth_provider::LocalAuthUser, resource_mgr::{*},
+    live_updates::{ self, LiveEvent, LiveHub },
+    resources::get_translation,
+    scheduler,
+    utils::SupportedLangs,
+    words_all
 };
 use hash_ids::HashIds;
 
@@ -57,6 +62,219 @@ pub struct OpenGame {
     pub hashed_id: String,
     pub age_string: String,
     pub owner_username: String,
+    pub word_length: u8,
+    // the game language's name (ie: "FRANÇAIS")
+    pub game_lang: &'static str,
+}
+
+
+// One active ban, explained in the user's language
+pub struct BanNotice {
+    pub scope: String,
+    pub reason: String,
+    pub ends: String,
+}
+
+
+/*
+ * ADMIN AREA
+ */
+
+/**
+ * The admin game search form. Everything comes in as text so
+ * an empty box just means "don't filter by this".
+ * Ages are in days.
+ */
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct AdminGameQuery {
+    pub status: String,
+    pub owner: String,
+    pub older_than_days: String,
+    pub newer_than_days: String,
+}
+
+pub struct AdminGameLink {
+    pub hashed_id: String,
+    pub game_status: String,
+    pub owner_username: String,
+    pub word_length: u8,
+    pub game_lang: &'static str,
+    pub player_count: i64,
+    pub age_string: String,
+}
+
+// Every guess in a game, words and all
+pub struct AdminGuess {
+    pub guess_number: i8,
+    pub username: String,
+    pub word: String,
+    pub score: String,
+    pub age_string: String,
+}
+
+pub struct AdminEventLine {
+    pub event_type: String,
+    pub actor_username: String,
+    pub target_username: String,
+    pub details: String,
+    pub age_string: String,
+}
+
+pub struct AdminActionLine {
+    pub admin_username: String,
+    pub action: String,
+    pub hashed_game_id: Option<String>,
+    pub target_username: Option<String>,
+    pub details: String,
+    pub age_string: String,
+}
+
+#[derive(Deserialize)]
+pub struct AdminFinishGameData {
+    pub hashed_game_id: String,
+    // Nobody wins if this is missing
+    pub winner_username: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct AdminRemovePlayerData {
+    pub hashed_game_id: String,
+    pub username: String,
+}
+
+// A ban as the admin user page lists it
+pub struct AdminBanLine {
+    pub id: i32,
+    pub ban_scope: String,
+    pub reason: String,
+    pub banned_by_username: String,
+    // ACTIVE, EXPIRED or LIFTED
+    pub status: &'static str,
+    pub ends: String,
+    pub age_string: String,
+}
+
+#[derive(Deserialize)]
+pub struct AdminBanData {
+    pub username: String,
+    pub ban_scope: String,
+    pub reason: String,
+    // No days means the ban lasts until it's lifted
+    pub days: Option<u16>,
+}
+
+#[derive(Deserialize)]
+pub struct AdminLiftBanData {
+    pub ban_id: i32,
+}
+
+#[derive(Serialize)]
+pub struct AdminActionSuccess {
+    pub success: bool,
+    pub message: String,
+}
+
+
+// One rated game's change, ready for display
+pub struct RatingHistoryEntry {
+    pub rating_after: i32,
+    pub change: String,
+    pub date: String,
+}
+
+// A player's current rating and how it got there
+pub struct RatingSummary {
+    pub rating: i32,
+    pub history: Vec<RatingHistoryEntry>,
+}
+
+impl RatingSummary {
+    /**
+     * history must be newest first, so the current rating is the first rating_after.
+     */
+    pub fn new(history_items: Vec<db::RatingHistoryItem>) -> RatingSummary {
+        let rating: i32 = match history_items.first() {
+            Some(item) => item.rating_after,
+            None => game_logic::DEFAULT_RATING
+        };
+
+        let history: Vec<RatingHistoryEntry> = history_items
+            .iter()
+            .map(|item: &db::RatingHistoryItem| RatingHistoryEntry {
+                rating_after: item.rating_after,
+                change: format!("{:+}", item.rating_after - item.rating_before),
+                date: item.created_timestamp.date().to_string()
+            })
+            .collect();
+
+        RatingSummary { rating, history }
+    }
+}
+
+
+// One player's spoiler-free result: a row of emoji squares per guess
+pub struct PlayerResult {
+    pub username: String,
+    pub solved: bool,
+    pub guesses_used: usize,
+    pub emoji_rows: Vec<String>,
+}
+
+
+impl PlayerResult {
+    pub fn new(player: &PlayerRefreshData, winner_id_option: Option<i32>) -> PlayerResult {
+        PlayerResult {
+            username: player.username.to_owned(),
+            solved: winner_id_option == Some(player.user_id),
+            guesses_used: player.scores.len(),
+            emoji_rows: player.scores
+                .iter()
+                .map(|wordless_score: &game_logic::WordlessScore|
+                    wordless_score.score.iter().map(|score| score.to_emoji()).collect())
+                .collect()
+        }
+    }
+}
+
+
+/**
+ * One turn of a finished game, for the replay.
+ * Specifying time::serde::rfc3339 to make the serialized
+ * timestamp JavaScript-readable.
+ */
+#[derive(Serialize)]
+pub struct ReplayTurn {
+    pub turn_number: usize,
+    pub user_id: i32,
+    pub username: String,
+    pub guess_number: i8,
+    // None when the player ran out of time and the turn timeout recorded a dud
+    pub word: Option<String>,
+    pub score: Vec<game_logic::LetterScore>,
+    pub timed_out: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_timestamp: OffsetDateTime,
+}
+
+// The whole timeline of a finished game
+#[derive(Serialize)]
+pub struct GameReplay {
+    pub word: String,
+    pub word_length: u8,
+    pub winner_id: Option<i32>,
+    pub players: Vec<PlayerInfo>,
+    pub turns: Vec<ReplayTurn>,
+}
+
+
+// One row of the daily challenge leaderboard
+pub struct DailyLeaderboardEntry {
+    pub rank: usize,
+    pub username: String,
+    pub solved: bool,
+    pub guesses_used: u8,
+    pub solve_time: String,
 }
 
 
@@ -119,12 +337,6 @@ pub struct QuitGameSuccess {
 }
 
 
-#[derive(Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
-    pub code: u16,
-}
-
 #[derive(Serialize)]
 pub struct GameCancelled {
     pub success: bool,
@@ -144,12 +356,17 @@ pub struct PreGameRefresh {
     pub game_status: GameStatus,
     pub players: Vec<PlayerInfo>,
     pub invitee_usernames: Vec<String>,
+    pub settings: db::GameSettings,
 }
 
 
 #[derive(Deserialize)]
 pub struct InviteOnlyData {
     pub invite_only: bool,
+    // older clients don't send a length, so they get five letters
+    pub word_length: Option<u8>,
+    // "en" or "fr"
+    pub game_lang: Option<String>,
 }
 
 
@@ -185,6 +402,7 @@ pub struct InProgRefresh {
     pub game_status: GameStatus,
     #[serde(with = "time::serde::rfc3339")]
     pub turn_timeout: OffsetDateTime,
+    pub settings: db::GameSettings,
 }
 
 #[derive(Serialize)]
@@ -193,50 +411,26 @@ pub struct DashboardRefreshData {
 }
 
 #[derive(Serialize)]
-pub struct JoinGameFailure {
-    pub error: String,
-    pub success: bool,
-}
-
-
-impl JoinGameFailure {
-    pub fn new(message: String) -> JoinGameFailure {
-        JoinGameFailure {
-            success: false,
-            error: message
-        }
-    }
-}
-
-#[derive(Serialize)]
-pub struct LeaveGameFailure {
-    pub error: String,
+pub struct JoinGameSuccess {
     pub success: bool,
 }
 
 
 #[derive(Serialize)]
-pub struct StartGameFailure {
-    pub error: String,
+pub struct LeaveGameSuccess {
     pub success: bool,
 }
 
 
-#[derive(Serialize)]
-pub struct JoinGameSuccess {
-    pub success: bool,
-}
-
 
 #[derive(Serialize)]
-pub struct LeaveGameSuccess {
+pub struct StartGameSuccess {
     pub success: bool,
 }
 
 
-
 #[derive(Serialize)]
-pub struct StartGameSuccess {
+pub struct UpdateSettingsSuccess {
     pub success: bool,
 }
 
@@ -255,6 +449,18 @@ pub struct HashedGameId {
 
 
 
+// Owner changing the game's settings in the pre-game lobby
+#[derive(Deserialize)]
+pub struct GameSettingsData {
+    pub hashed_game_id: String,
+    pub max_guesses: u8,
+    pub turn_seconds: u16,
+    pub max_players: u8,
+    #[serde(default)]
+    pub hard_mode: bool,
+}
+
+
 #[derive(Serialize, Deserialize)]
 pub struct InviteData {
     pub hashed_game_id: String,
@@ -262,44 +468,35 @@ pub struct InviteData {
 }
 
 
-#[derive(Serialize)]
-pub struct FakeWord {
-    pub fake_word: bool,
-}
-
-#[derive(Serialize)]
-pub struct MaxGuesses {
-    pub max_guesses: bool,
+// Owner adding a bot player in the pre-game lobby
+#[derive(Deserialize)]
+pub struct AddBotData {
+    pub hashed_game_id: String,
+    pub bot_level: String,
 }
 
 #[derive(Serialize)]
-pub struct WrongTurn {
-    pub wrong_turn: bool,
+pub struct BotAdded {
+    pub success: bool,
+    pub username: String,
 }
 
-impl FakeWord {
-    pub fn new() -> FakeWord {
-        FakeWord {
-            fake_word: true
-        }
-    }
-}
 
-impl MaxGuesses {
-    pub fn new() -> MaxGuesses {
-        MaxGuesses {
-            max_guesses: true
-        }
-    }
+/**
+ * What happened when a player (human or bot) submitted a guess.
+ * Only Scored means the guess was saved and used up a turn.
+ */
+pub enum GuessOutcome {
+    Scored(game_logic::CheckGuessResult),
+    WrongTurn,
+    MaxGuesses,
+    WrongLength(u8),
+    FakeWord,
+    HardModeViolation(game_logic::MissedHint),
+    // finished (or cancelled) before the guess could be saved
+    GameOver,
 }
 
-impl WrongTurn {
-    pub fn new() -> WrongTurn {
-        WrongTurn {
-            wrong_turn: true
-        }
-    }
-}
 
 impl InProgRefresh {
 
@@ -360,6 +557,7 @@ pub struct GameTemplate {
     pub game: db::GameAndPlayers,
     pub texts: GameTexts,
     pub hashed_game_id: String,
+    pub max_turns: u8,
 }
 
 
@@ -371,6 +569,8 @@ pub struct PreGameTemplate {
     pub game: db::GameAndPlayers,
     pub hashed_game_id: String,
     pub age_string: String,
+    pub settings: db::GameSettings,
+    pub max_players_limit: u8,
 }
 
 #[derive(Template)]
@@ -388,6 +588,63 @@ pub struct FinishedGameTemplate {
     pub user: auth::UserReqData,
     pub game: db::GameAndPlayers,
     pub winner_name: Option<String>,
+    pub hashed_game_id: String,
+    pub share_text: String,
+    pub result_url: String,
+}
+
+
+/**
+ * Public, read-only result of a finished game.
+ * Guests can see it too, so it never shows the words (or the answer).
+ */
+#[derive(Template)]
+#[template(path="result.html")]
+pub struct ResultTemplate {
+    pub texts: PostGameTexts,
+    pub user: auth::UserReqData,
+    pub hashed_game_id: String,
+    pub players: Vec<PlayerResult>,
+    pub share_text: String,
+}
+
+
+#[derive(Template)]
+#[template(path="admin.html")]
+pub struct AdminTemplate {
+    pub texts: AdminTexts,
+    pub user: auth::UserReqData,
+    pub search: AdminGameQuery,
+    pub games: Vec<AdminGameLink>,
+    pub actions: Vec<AdminActionLine>,
+}
+
+/**
+ * Admins see everything: the solution, and every guess with its word.
+ */
+#[derive(Template)]
+#[template(path="admin_game.html")]
+pub struct AdminGameTemplate {
+    pub texts: AdminTexts,
+    pub user: auth::UserReqData,
+    pub game: db::GameAndPlayers,
+    pub hashed_game_id: String,
+    pub settings: db::GameSettings,
+    pub guesses: Vec<AdminGuess>,
+    pub events: Vec<AdminEventLine>,
+    pub age_string: String,
+}
+
+#[derive(Template)]
+#[template(path="admin_user.html")]
+pub struct AdminUserTemplate {
+    pub texts: AdminTexts,
+    pub user: auth::UserReqData,
+    pub username: String,
+    pub games: Vec<AdminGameLink>,
+    pub blocks: Vec<db::Block>,
+    pub bans: Vec<AdminBanLine>,
+    pub ban_scopes: Vec<&'static str>,
 }
 
 // GameItemData should have list of player names
@@ -401,6 +658,21 @@ pub struct DashboardTemplate {
     pub current_games: Vec<db::GameLinkData>,
     pub stats: db::PlayerStats,
     pub invited_games: Vec<InviteInfo>,
+    pub daily_streak: u32,
+    pub rating: RatingSummary,
+}
+
+
+#[derive(Template)]
+#[template(path="daily.html")]
+pub struct DailyTemplate {
+    pub texts: DailyTexts,
+    pub user: auth::UserReqData,
+    pub challenge_date: String,
+    pub entries: Vec<DailyLeaderboardEntry>,
+    pub played_today: bool,
+    // hashid of today's game if the user is still playing it
+    pub in_progress_hashid: Option<String>,
 }
 
 
@@ -413,6 +685,15 @@ pub struct ViewUserTemplate {
     pub username: String,
     pub has_stats: bool,
     pub is_blocked: bool,
+    pub rating: RatingSummary,
+}
+
+
+// Only used by the built-in local auth provider
+#[derive(Template)]
+#[template(path ="local_login.html")]
+pub struct LocalLoginTemplate {
+    pub users: Vec<LocalAuthUser>,
 }
 
 
@@ -424,6 +705,14 @@ pub struct ErrorTemplate {
     pub texts: ErrorTexts,
 }
 
+#[derive(Template)]
+#[template(path ="banned.html")]
+pub struct BannedTemplate {
+    pub texts: BannedTexts,
+    pub user: auth::UserReqData,
+    pub notices: Vec<BanNotice>,
+}
+
 
 
 /* 
@@ -467,50 +756,25 @@ struct TwoAuthCookies {
 
 
 /**
- * Sometimes we don't know what went wrong and we need to return a JSON
- * object which says so.
+ * Game ids arrive hashed from the client.
+ * Anything that doesn't decode can't be one of our games.
  */
-pub fn return_internal_err_json() -> HttpResponse {
-    HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
-        .json(ErrorResponse{
-            error: String::from("Internal server error"),
-            code: 500
-        })
-}
-
-// If authentication failed and user must log back in
-pub fn return_authentication_err_json() -> HttpResponse {
-    HttpResponse::Unauthorized().json(ErrorResponse{
-        error: String::from("Authentication required"),
-        code: 401
-    })
-}
-
-
-// If something is not found
-pub fn return_not_found_err_json() -> HttpResponse {
-    HttpResponse::Unauthorized().json(ErrorResponse{
-        error: String::from("Not Found"),
-        code: 406
-    })
+pub fn decode_game_id(hash_ids: &HashIds, hashed_game_id: &str) -> Result<i32, ApiError> {
+    match hash_ids.decode(hashed_game_id) {
+        Ok(ids) if !ids.is_empty() => Ok(ids[0] as i32),
+        _ => Err(ApiError::NotFound)
+    }
 }
 
-pub fn return_unauthorized_err_json(user_req_data: &auth::UserReqData) -> HttpResponse {
-    let error: String = get_translation(
-        "err.empty_creds",
-        &user_req_data.lang,
-        None
-    );
-
-    return HttpResponse::Unauthorized().json(
-        ErrorResponse {
-        error,
-        code: 401
-    });
+// Every game action needs a real, logged-in user
+pub fn get_player_id(user_req_data: &auth::UserReqData) -> Result<i32, ApiError> {
+    match user_req_data.id {
+        Some(id) if user_req_data.get_role() != "guest" => Ok(id),
+        _ => Err(ApiError::Unauthorized)
+    }
 }
 
 
-
 /* 
  * 
  * 
@@ -530,25 +794,130 @@ pub fn return_unauthorized_err_json(user_req_data: &auth::UserReqData) -> HttpRe
 */
 
 /**
- * Wrapping the DB's "finish game" function with a call to
- * "delete guesses" so the routes module can just
- * call this one function instead of both.
+ * The one path every guess goes through, whether it came from
+ * a player's check_guess request or from a bot's turn.
+ * Caller must already know the user is a player in this game.
+ * 1. make sure the word is the right length, a real word, and (in hard mode) uses the hints
+ * 2. save it, pass the turn along and maybe finish the game, as one locked transaction
+ *    (which also makes sure it's still the player's turn and they have guesses left)
+ * 3. tell everybody the score, the new turn, and whether the game is over
+ */
+pub async fn submit_guess(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    game: &db::Game,
+    user_id: i32,
+    guess_word: &str
+) -> Result<GuessOutcome> {
+    let game_id: i32 = game.id;
+
+    // Quick check before doing any work. take_turn checks again with the game locked.
+    if game.turn_user_id != Some(user_id) {
+        return Ok(GuessOutcome::WrongTurn);
+    }
+
+    // Trimmed, all caps and without accents. It's saved and scored that way.
+    // Anything besides plain letters can't be on a word list.
+    let guess: Word = match Word::with_length(guess_word, game.word_length) {
+        Ok(guess) => guess,
+        Err(WordError::WrongLength(_)) => return Ok(GuessOutcome::WrongLength(game.word_length)),
+        Err(_e) => return Ok(GuessOutcome::FakeWord)
+    };
+    let guess_text: String = guess.to_string();
+
+    // make sure guess word is REAL WORD
+    if !words_all::is_real_word(&guess_text, &game.game_lang) {
+        return Ok(GuessOutcome::FakeWord);
+    }
+
+    // In hard mode the guess must use every hint from the player's earlier guesses.
+    // Rejecting it here means the guess is never saved, so it doesn't use a turn.
+    let settings: db::GameSettings = db::get_game_settings(pool, game_id).await?;
+    if settings.hard_mode {
+        let previous_guesses: Vec<game_logic::GuessAndScore> =
+            db::get_guess_scores(pool, game_id, user_id).await?;
+
+        if let Some(missed_hint) =
+            game_logic::find_missed_hint(&guess, &previous_guesses)
+        {
+            return Ok(GuessOutcome::HardModeViolation(missed_hint));
+        }
+    }
+
+    let solution: Word = Word::new(&game.word)
+        .map_err(|e| anyhow::anyhow!("Game {} has an unusable word: {:?}", game_id, e))?;
+
+    let guess_result_basic: game_logic::CheckGuessResultBasic =
+        game_logic::check_guess(&guess, &solution)
+            .map_err(|e| anyhow::anyhow!("Game {} can't score the guess: {:?}", game_id, e))?;
+
+    let turn_kind: db::TurnKind = db::TurnKind::Guess {
+        is_winner: guess_result_basic.is_winner
+    };
+
+    let recorded_turn: db::RecordedTurn =
+        match db::take_turn(
+            pool,
+            game_id,
+            user_id,
+            &guess_text,
+            &guess_result_basic.score,
+            turn_kind
+        ).await? {
+            db::TurnTaken::Recorded(recorded_turn) => recorded_turn,
+            db::TurnTaken::NotInProgress => return Ok(GuessOutcome::GameOver),
+            db::TurnTaken::WrongTurn => return Ok(GuessOutcome::WrongTurn),
+            db::TurnTaken::MaxGuesses => return Ok(GuessOutcome::MaxGuesses)
+        };
+
+    // Opponents only ever see the score, never the word
+    hub.publish_to_game(game_id, &LiveEvent::NewScore {
+        user_id,
+        score: guess_result_basic.score.clone()
+    });
+
+    let mut guess_result: game_logic::CheckGuessResult =
+        game_logic::CheckGuessResult::new(
+            guess_result_basic,
+            false,
+            user_id
+        );
+
+    if let Some(next_turn_id) = recorded_turn.next_turn_id {
+        guess_result.next_turn_id = next_turn_id;
+        live_updates::publish_turn_changed(hub, pool, game_id).await;
+    }
+
+    if recorded_turn.game_over {
+        hub.publish_to_game(game_id, &LiveEvent::GameOver { winner_id: recorded_turn.winner_id });
+        guess_result.game_over = true;
+    }
+
+    Ok(GuessOutcome::Scored(guess_result))
+}
+
+
+/**
+ * Tell everybody where the turn went after it was passed on.
+ * If nobody could play, the game is over with no winner.
  */
-pub async fn finish_game(
+pub async fn publish_turn_passed(
     pool: &MySqlPool,
+    hub: &LiveHub,
     game_id: i32,
-    winner_id_option: Option<i32>
-) -> Result<u8> {
-    let finish_game_affected_rows: u8 =
-        db::finish_game(&pool, game_id, winner_id_option).await?;
-    
-    if finish_game_affected_rows > 0 {
-        let _guesses_deleted: u8 = db::delete_guesses(pool, game_id).await?;
+    turn_passed: &db::TurnPassed
+) -> Result<()> {
+    match turn_passed {
+        db::TurnPassed::To(_next_turn_id) =>
+            live_updates::publish_turn_changed(hub, pool, game_id).await,
+        db::TurnPassed::GameOver =>
+            hub.publish_to_game(game_id, &LiveEvent::GameOver { winner_id: None })
     }
 
-    Ok(finish_game_affected_rows)
+    Ok(())
 }
 
+
 /**
  * Take a vector of GameId objects (each of which holds an i64 game id),
  * hash each one into a String, and return a vector of those Strings.
@@ -594,6 +963,175 @@ pub fn create_age_string(aged_date: &OffsetDateTime) -> String {
     format!("{} minutes old", age.whole_minutes())
 }
 
+/**
+ * The text players copy to share a finished game, ie:
+ *      CRANKWORD x7Yq2: 7 guesses
+ *      alice 3/5
+ *      🟦⬛⬛🟩⬛
+ *      ...
+ *      bob X/5
+ *      ...
+ *      https://.../result/x7Yq2
+ * Only scores, never words, so it can't spoil anything.
+ * The first line is in the sharer's language.
+ */
+pub fn build_share_text(
+    hashed_game_id: &String,
+    player_results: &[PlayerResult],
+    max_guesses: u8,
+    result_url: &String,
+    lang: &SupportedLangs
+) -> String {
+    let total_guesses: usize = player_results
+        .iter()
+        .map(|player_result: &PlayerResult| player_result.guesses_used)
+        .sum();
+
+    let mut lines: Vec<String> = vec![get_translation(
+        "postgame.share.headline",
+        lang,
+        Some(&[hashed_game_id, &total_guesses.to_string()])
+    )];
+
+    for player_result in player_results {
+        let guesses_label: String = if player_result.solved {
+            player_result.guesses_used.to_string()
+        } else {
+            "X".to_string()
+        };
+
+        lines.push(format!("{} {}/{}", player_result.username, guesses_label, max_guesses));
+        lines.extend(player_result.emoji_rows.iter().cloned());
+    }
+
+    lines.push(result_url.to_owned());
+    lines.join("\n")
+}
+
+
+/**
+ * Public address of a finished game's result page,
+ * built from the host this request came in on.
+ */
+pub fn get_result_url(req: &HttpRequest, hashed_game_id: &String) -> String {
+    let connection_info: ConnectionInfo = req.connection_info().clone();
+    format!(
+        "{}://{}/result/{}",
+        connection_info.scheme(),
+        connection_info.host(),
+        hashed_game_id
+    )
+}
+
+
+/**
+ * Score every guess of a finished game against the answer, in the order they were played.
+ * Players who left are named from departed_players (see db::get_departed_players).
+ * Callers must make sure the game is over: this reveals every word.
+ */
+pub fn build_game_replay(
+    game_and_players: GameAndPlayers,
+    departed_players: Vec<PlayerInfo>,
+    guesses: Vec<db::Guess>
+) -> GameReplay {
+    let turns: Vec<ReplayTurn> = guesses
+        .iter()
+        .enumerate()
+        .map(|(index, guess)| {
+            let username: String = match game_and_players.players
+                .iter()
+                .chain(departed_players.iter())
+                .find(|player: &&PlayerInfo| player.user_id == guess.user_id)
+            {
+                Some(player) => player.username.to_owned(),
+                None => "?".to_string()
+            };
+
+            let timed_out: bool = guess.word.chars().all(|letter| letter == scheduler::DUD_LETTER);
+
+            ReplayTurn {
+                turn_number: index + 1,
+                user_id: guess.user_id,
+                username,
+                guess_number: guess.guess_number,
+                word: if timed_out { None } else { Some(guess.word.to_owned()) },
+                score: game_logic::decode_score(&guess.score),
+                timed_out,
+                created_timestamp: guess.created_timestamp
+            }
+        })
+        .collect();
+
+    GameReplay {
+        word: game_and_players.game.word,
+        word_length: game_and_players.game.word_length,
+        winner_id: game_and_players.game.winner_id,
+        players: game_and_players.players,
+        turns
+    }
+}
+
+
+/**
+ * How many days in a row the user has solved the daily challenge.
+ * Today's puzzle may not be played yet, so a streak ending yesterday still counts.
+ * solved_dates must be most recent first.
+ */
+pub fn get_daily_streak(solved_dates: &[Date], today: Date) -> u32 {
+    let mut expected_date: Date = match solved_dates.first() {
+        Some(date) if *date == today => today,
+        Some(_) => match today.previous_day() {
+            Some(yesterday) => yesterday,
+            None => return 0
+        },
+        None => return 0
+    };
+
+    let mut streak: u32 = 0;
+    for solved_date in solved_dates {
+        if *solved_date != expected_date {
+            break;
+        }
+
+        streak += 1;
+        expected_date = match expected_date.previous_day() {
+            Some(day) => day,
+            None => break
+        };
+    }
+
+    streak
+}
+
+
+/**
+ * Turn the raw daily results (already sorted by the DB) into leaderboard rows.
+ */
+pub fn build_daily_leaderboard(raw_results: Vec<db::RawDailyResult>) -> Vec<DailyLeaderboardEntry> {
+    raw_results
+        .iter()
+        .enumerate()
+        .map(|(index, raw_result)| {
+            let solve_time: String = match raw_result.finished_timestamp {
+                Some(finished) => {
+                    let seconds: i64 = (finished - raw_result.created_timestamp).whole_seconds();
+                    format!("{}:{:02}", seconds / 60, seconds % 60)
+                },
+                None => "--".to_string()
+            };
+
+            DailyLeaderboardEntry {
+                rank: index + 1,
+                username: raw_result.username.to_owned(),
+                solved: raw_result.solved == 1,
+                guesses_used: raw_result.guesses_used,
+                solve_time
+            }
+        })
+        .collect()
+}
+
+
 /**
  * Make sure whoever is asking for refresh data for a game
  * is actually in the players list.
@@ -609,3 +1147,33 @@ pub fn player_id_is_in_players_refresh_data(
     }
     false
 }
+
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    fn day(month_day: u8) -> Date {
+        Date::from_calendar_date(2026, Month::March, month_day).unwrap()
+    }
+
+    #[test]
+    fn daily_streak_counts_days_in_a_row() {
+        assert_eq!(get_daily_streak(&[day(14), day(13), day(12), day(10)], day(14)), 3);
+        assert_eq!(get_daily_streak(&[], day(14)), 0);
+    }
+
+    #[test]
+    fn daily_streak_waits_for_today() {
+        // today's puzzle isn't played yet, so the streak up to yesterday still counts
+        assert_eq!(get_daily_streak(&[day(13), day(12)], day(14)), 2);
+        // but a missed day ends it
+        assert_eq!(get_daily_streak(&[day(12), day(11)], day(14)), 0);
+    }
+}
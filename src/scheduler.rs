@@ -0,0 +1,293 @@
+use actix_web::web;
+use anyhow::{ Result };
+use sqlx::{ MySqlPool };
+use ::time::OffsetDateTime;
+use tokio::time::{ self, Duration, MissedTickBehavior };
+
+use crate::{
+    bots::{ self, BotLevel },
+    db::{ self, BotTurn, TimedOutTurn },
+    game_logic::{ GuessAndScore, LetterScore },
+    live_updates::{ self, LiveEvent, LiveHub },
+    routes_utils::{ publish_turn_passed, submit_guess, GuessOutcome }
+};
+
+/*
+ *
+ *
+ *
+ *
+ * =======================
+ * =======================
+ * =====             =====
+ * =====  SCHEDULER  =====
+ * =====             =====
+ * =======================
+ * =======================
+ *
+ *
+ * Background tasks started in main which run on a timer,
+ * independent of any browser being open.
+ *
+ * The turn timeout sweep finds in-progress games where the current
+ * player ran out of time, inserts a dud guess for them, and passes the
+ * turn along (or finishes the game if nobody can play anymore).
+ *
+ * Several server instances may run this at once. db::take_turn locks the
+ * game row (SELECT ... FOR UPDATE) and checks the turn is still timed out,
+ * so only one instance handles each timed-out turn.
+ *
+ * The bot runner finds in-progress games where it's a bot's turn
+ * and plays that turn through the same submit_guess path as a player's request.
+ *
+ *
+ *
+*/
+
+
+// How often we look for timed-out turns
+const TURN_TIMEOUT_SWEEP_SECONDS: u64 = 2;
+
+// How often we look for bots whose turn it is
+const BOT_TURN_SWEEP_SECONDS: u64 = 2;
+
+// Bots wait this long into their turn before guessing, so humans can follow along
+const BOT_THINK_SECONDS: u64 = 3;
+
+// The "word" we record when a player misses their turn is made of these
+pub const DUD_LETTER: char = '-';
+
+
+/**
+ * Loop forever, sweeping for timed-out turns.
+ * Errors are logged and the loop carries on with the next sweep.
+ */
+pub async fn run_turn_timeout_sweeper(pool: MySqlPool, hub: web::Data<LiveHub>) {
+    let mut interval: time::Interval =
+        time::interval(Duration::from_secs(TURN_TIMEOUT_SWEEP_SECONDS));
+
+    // If a sweep runs long, don't fire a burst of catch-up sweeps after it
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = sweep_timed_out_turns(&pool, &hub).await {
+            eprintln!("Turn timeout sweep failed: {:?}", e);
+        }
+    }
+}
+
+
+/**
+ * One pass over every timed-out turn.
+ * A failure on one game should not stop the others from being handled.
+ */
+async fn sweep_timed_out_turns(pool: &MySqlPool, hub: &LiveHub) -> Result<()> {
+    let timed_out_turns: Vec<TimedOutTurn> = db::get_timed_out_turns(pool).await?;
+
+    for timed_out_turn in timed_out_turns {
+        if let Err(e) = time_out_turn(pool, hub, &timed_out_turn).await {
+            eprintln!("Failed to time out turn for game {}: {:?}", timed_out_turn.id, e);
+        }
+    }
+
+    Ok(())
+}
+
+
+/**
+ * Time out the current player's turn (see play_dud_turn).
+ */
+async fn time_out_turn(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    timed_out_turn: &TimedOutTurn
+) -> Result<()> {
+    let current_turn_user_id: i32 = match timed_out_turn.turn_user_id {
+        Some(id) => id,
+        None => return Ok(())
+    };
+
+    play_dud_turn(
+        pool,
+        hub,
+        timed_out_turn.id,
+        current_turn_user_id,
+        timed_out_turn.word_length,
+        db::TurnKind::TimedOut
+    ).await
+}
+
+
+/**
+ * Skip the current player's turn:
+ * a dud guess is saved for them and the turn passes to the next player,
+ * in one locked transaction (see db::take_turn). If they guessed in the meantime,
+ * or another instance got here first, nothing happens.
+ * If that was the player's final turn and nobody else can play, game over (no winner).
+ * A player who is already out of guesses gets no dud. The turn just passes on.
+ */
+async fn play_dud_turn(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    game_id: i32,
+    current_turn_user_id: i32,
+    word_length: u8,
+    turn_kind: db::TurnKind
+) -> Result<()> {
+    let dud_word: String = get_dud_word(word_length);
+    let dud_score: Vec<LetterScore> = dud_word.chars().map(|_| LetterScore::Dud).collect();
+
+    let recorded_turn: db::RecordedTurn = match db::take_turn(
+        pool,
+        game_id,
+        current_turn_user_id,
+        &dud_word,
+        &dud_score,
+        turn_kind
+    ).await? {
+        db::TurnTaken::Recorded(recorded_turn) => recorded_turn,
+        db::TurnTaken::MaxGuesses => {
+            if let Some(turn_passed) = db::next_turn(pool, game_id, current_turn_user_id).await? {
+                publish_turn_passed(pool, hub, game_id, &turn_passed).await?;
+            }
+            return Ok(());
+        },
+        db::TurnTaken::NotInProgress | db::TurnTaken::WrongTurn => return Ok(())
+    };
+
+    hub.publish_to_game(game_id, &LiveEvent::NewScore {
+        user_id: current_turn_user_id,
+        score: dud_score
+    });
+
+    if recorded_turn.next_turn_id.is_some() {
+        live_updates::publish_turn_changed(hub, pool, game_id).await;
+    }
+
+    if recorded_turn.game_over {
+        hub.publish_to_game(game_id, &LiveEvent::GameOver { winner_id: None });
+    }
+
+    Ok(())
+}
+
+
+/**
+ * Loop forever, playing any bot turns that are due.
+ * Errors are logged and the loop carries on with the next sweep.
+ */
+pub async fn run_bot_players(pool: MySqlPool, hub: web::Data<LiveHub>) {
+    let mut interval: time::Interval =
+        time::interval(Duration::from_secs(BOT_TURN_SWEEP_SECONDS));
+
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = play_bot_turns(&pool, &hub).await {
+            eprintln!("Bot turn sweep failed: {:?}", e);
+        }
+    }
+}
+
+
+/**
+ * One pass over every game waiting on a bot.
+ * A failure in one game should not stop the others from being played.
+ */
+async fn play_bot_turns(pool: &MySqlPool, hub: &LiveHub) -> Result<()> {
+    let turn_started_before: OffsetDateTime =
+        OffsetDateTime::now_utc() - Duration::from_secs(BOT_THINK_SECONDS);
+    let bot_turns: Vec<BotTurn> = db::get_bot_turns(pool, turn_started_before).await?;
+
+    for bot_turn in bot_turns {
+        if let Err(e) = play_bot_turn(pool, hub, &bot_turn).await {
+            eprintln!("Failed to play bot turn for game {}: {:?}", bot_turn.game_id, e);
+        }
+    }
+
+    Ok(())
+}
+
+
+/**
+ * The bot only gets its own earlier guesses and their scores
+ * (what a human player sees on their own board) to choose a word.
+ * The guess then goes through submit_guess like any player's guess.
+ * If the bot can't come up with a word (or its word is rejected), it skips
+ * the turn with a dud, so the next sweep doesn't pick the same turn up again.
+ */
+async fn play_bot_turn(pool: &MySqlPool, hub: &LiveHub, bot_turn: &BotTurn) -> Result<()> {
+    let bot_level: BotLevel = match bot_turn.bot_level
+        .as_deref()
+        .and_then(BotLevel::from_string)
+    {
+        Some(level) => level,
+        None => return Ok(())
+    };
+
+    let game: db::Game = db::get_game_by_id(pool, bot_turn.game_id).await?;
+    let settings: db::GameSettings = db::get_game_settings(pool, bot_turn.game_id).await?;
+    let previous_guesses: Vec<GuessAndScore> =
+        db::get_guess_scores(pool, bot_turn.game_id, bot_turn.bot_id).await?;
+
+    let guess_word: String = match bots::choose_guess(
+        &bot_level,
+        game.word_length,
+        &game.game_lang,
+        &previous_guesses,
+        settings.hard_mode
+    ) {
+        Some(word) => word,
+        None => {
+            eprintln!("Bot {} has no word to guess in game {}", bot_turn.bot_id, bot_turn.game_id);
+            return skip_bot_turn(pool, hub, &game, bot_turn).await;
+        }
+    };
+
+    let guess_outcome: GuessOutcome =
+        submit_guess(pool, hub, &game, bot_turn.bot_id, &guess_word).await?;
+
+    match guess_outcome {
+        GuessOutcome::Scored(_) => Ok(()),
+        // the turn moved on without the bot. Nothing to skip.
+        GuessOutcome::WrongTurn | GuessOutcome::GameOver => Ok(()),
+        GuessOutcome::MaxGuesses |
+        GuessOutcome::WrongLength(_) |
+        GuessOutcome::FakeWord |
+        GuessOutcome::HardModeViolation(_) => {
+            eprintln!("Bot {} guess was rejected in game {}", bot_turn.bot_id, bot_turn.game_id);
+            skip_bot_turn(pool, hub, &game, bot_turn).await
+        }
+    }
+}
+
+
+// A bot that can't play gives up its turn with a dud
+async fn skip_bot_turn(
+    pool: &MySqlPool,
+    hub: &LiveHub,
+    game: &db::Game,
+    bot_turn: &BotTurn
+) -> Result<()> {
+    play_dud_turn(
+        pool,
+        hub,
+        bot_turn.game_id,
+        bot_turn.bot_id,
+        game.word_length,
+        db::TurnKind::Skipped
+    ).await
+}
+
+
+/**
+ * A dud "word" the same length as the game's solution,
+ * so it scores as all Duds.
+ */
+fn get_dud_word(word_length: u8) -> String {
+    DUD_LETTER.to_string().repeat(word_length as usize)
+}
@@ -30,9 +30,8 @@ pub struct StringRange {
     pub max: usize,
 }
 
-pub const MAX_CURRENT_GAMES: u8 = 3;
-pub const MAX_PLAYERS: u8 = 6;
-pub const MAX_INVITES: u8 = 10;
+// How many recent rating changes the dashboard and profile pages show
+pub const RATING_HISTORY_LENGTH: u8 = 10;
 
 fn username_length_range() -> StringRange {
     StringRange{ min: 6, max: 20 }
@@ -139,6 +138,9 @@ pub enum SupportedLangs {
 }
 
 impl SupportedLangs {
+    // Every language that needs a version of every text
+    pub const ALL: [SupportedLangs; 2] = [SupportedLangs::English, SupportedLangs::French];
+
     pub fn suffix(&self) -> &'static str {
         match self {
             SupportedLangs::English => "en",
@@ -148,23 +150,78 @@ impl SupportedLangs {
 
     // When checking the header (accept-lang) or DB for lang
     pub fn from(input: &str) -> SupportedLangs {
-        if input.starts_with("en") {
-            return SupportedLangs::English;
-        } else if input.starts_with("fr") {
-            return SupportedLangs::French;
+        SupportedLangs::from_code(input).unwrap_or(SupportedLangs::English)
+    }
+
+    // None if we don't support it. "fr", "fr-CA", "FR" are all French.
+    pub fn from_code(input: &str) -> Option<SupportedLangs> {
+        let code: String = input.trim().to_lowercase();
+
+        if code.starts_with("en") {
+            Some(SupportedLangs::English)
+        } else if code.starts_with("fr") {
+            Some(SupportedLangs::French)
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Pick from an Accept-Language header, ie: "fr-CA,fr;q=0.9,en;q=0.8"
+     * The supported language with the highest q wins (ties go to whichever came first).
+     */
+    pub fn from_accept_language(header: &str) -> Option<SupportedLangs> {
+        let mut best_option: Option<(SupportedLangs, f32)> = None;
+
+        for entry in header.split(',') {
+            let mut parts: std::str::Split<'_, char> = entry.split(';');
+            let lang: SupportedLangs = match parts.next().and_then(SupportedLangs::from_code) {
+                Some(lang) => lang,
+                None => continue
+            };
+
+            let quality: f32 = parts
+                .find_map(|param: &str| param.trim().strip_prefix("q="))
+                .and_then(|q: &str| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            let is_better: bool = match &best_option {
+                Some((_, best_quality)) => quality > *best_quality,
+                None => quality > 0.0
+            };
+
+            if is_better {
+                best_option = Some((lang, quality));
+            }
+        }
+
+        best_option.map(|(lang, _quality)| lang)
+    }
+
+    // What a game's language is called, in that language
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            SupportedLangs::English => "ENGLISH",
+            SupportedLangs::French => "FRANÇAIS"
         }
+    }
 
-        // Default
-        return SupportedLangs::English;
+    // For the nav's language switch
+    pub fn other(&self) -> SupportedLangs {
+        match self {
+            SupportedLangs::English => SupportedLangs::French,
+            SupportedLangs::French => SupportedLangs::English
+        }
     }
 }
 
 /**
- * The words and guesses must always be all-caps
+ * The words and guesses must always be all-caps.
+ * Accents are dropped too (ie: "été" becomes "ETE").
  */
 pub fn all_caps(input: &str) -> String {
     input.split_whitespace()
-        .map(|word| word.to_uppercase())
+        .map(|word| fold_accents(word).to_uppercase())
         .collect::<Vec<_>>()
         .join(" ")
 }
@@ -172,10 +229,81 @@ pub fn all_caps(input: &str) -> String {
 
 /**
  * The words lists are lowercase, so we must make guesses lowercase before checking.
+ * Accents are dropped the same way as in all_caps.
  */
 pub fn no_caps(input: &str) -> String {
     input.split_whitespace()
-        .map(|word| word.to_lowercase())
+        .map(fold_accents)
         .collect::<Vec<_>>()
         .join(" ")
-}
\ No newline at end of file
+}
+
+
+/**
+ * French words are played with plain letters, so É, È, Ê and E are all one tile.
+ * Ligatures get both letters (ie: "cœur" becomes "coeur").
+ * Returns lowercase.
+ */
+fn fold_accents(word: &str) -> String {
+    word.to_lowercase()
+        .chars()
+        .map(|letter: char| match letter {
+            'à' | 'â' | 'ä' | 'á' | 'ã' | 'å' => "a".to_string(),
+            'ç' => "c".to_string(),
+            'é' | 'è' | 'ê' | 'ë' => "e".to_string(),
+            'î' | 'ï' | 'í' | 'ì' => "i".to_string(),
+            'ô' | 'ö' | 'ó' | 'ò' | 'õ' => "o".to_string(),
+            'ù' | 'û' | 'ü' | 'ú' => "u".to_string(),
+            'ÿ' | 'ý' => "y".to_string(),
+            'ñ' => "n".to_string(),
+            'œ' => "oe".to_string(),
+            'æ' => "ae".to_string(),
+            other => other.to_string()
+        })
+        .collect()
+}
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accept_language_code(header: &str) -> Option<&'static str> {
+        SupportedLangs::from_accept_language(header).map(|lang: SupportedLangs| lang.suffix())
+    }
+
+    #[test]
+    fn accept_language_picks_the_highest_quality() {
+        assert_eq!(accept_language_code("fr-CA,fr;q=0.9,en;q=0.8"), Some("fr"));
+        assert_eq!(accept_language_code("en;q=0.5, fr;q=0.9"), Some("fr"));
+        assert_eq!(accept_language_code("en-US,en;q=0.9"), Some("en"));
+    }
+
+    #[test]
+    fn accept_language_ties_go_to_the_first() {
+        assert_eq!(accept_language_code("en,fr"), Some("en"));
+        assert_eq!(accept_language_code("fr;q=0.7,en;q=0.7"), Some("fr"));
+    }
+
+    #[test]
+    fn accept_language_skips_unsupported_languages() {
+        assert_eq!(accept_language_code("de-DE,de;q=0.9,fr;q=0.3"), Some("fr"));
+        assert_eq!(accept_language_code("de,es;q=0.5"), None);
+        assert_eq!(accept_language_code("*"), None);
+        assert_eq!(accept_language_code(""), None);
+        // q=0 means "not this one"
+        assert_eq!(accept_language_code("fr;q=0"), None);
+    }
+
+    #[test]
+    fn french_accents_fold_to_plain_letters() {
+        assert_eq!(all_caps("élève"), "ELEVE");
+        assert_eq!(all_caps(" Garçon "), "GARCON");
+        assert_eq!(no_caps("CŒUR"), "coeur");
+        assert_eq!(no_caps("Noël"), "noel");
+    }
+}
@@ -1,5 +1,5 @@
 use phf::{phf_set, Set};
-use crate::utils;
+use crate::{ utils::{ self, SupportedLangs }, words_all_fr };
 
 /* 
  * 
@@ -21,20 +21,64 @@ use crate::utils;
  * (and actually compare it to the solution) if it's part
  * of this list.
  * 
- * This is a subset of the YAWL list (yet another word list).
- * I selected only the five-letter words.
+ * The five-letter list is a subset of the YAWL list (yet another word list).
+ * 
+ * The other lengths are smaller lists of common English words,
+ * plus plurals of their nouns. For four letters we also take
+ * the stems of the five-letter plurals (ie: "ducks" gives "duck").
+ * 
+ * French games use the lists in words_all_fr instead.
  * 
  * 
 */
 
 
+/**
+ * The master list for a word length in the game's language.
+ * None for lengths we don't play.
+ */
+fn get_word_list(word_length: usize, lang: &SupportedLangs) -> Option<&'static Set<&'static str>> {
+    let word_list: &'static Set<&'static str> = match (lang, word_length) {
+        (SupportedLangs::English, 4) => &FOUR_LETTER_WORDS,
+        (SupportedLangs::English, 5) => &ALL_WORDS,
+        (SupportedLangs::English, 6) => &SIX_LETTER_WORDS,
+        (SupportedLangs::English, 7) => &SEVEN_LETTER_WORDS,
+        (SupportedLangs::English, 8) => &EIGHT_LETTER_WORDS,
+        (SupportedLangs::French, 4) => &words_all_fr::FOUR_LETTER_WORDS,
+        (SupportedLangs::French, 5) => &words_all_fr::FIVE_LETTER_WORDS,
+        (SupportedLangs::French, 6) => &words_all_fr::SIX_LETTER_WORDS,
+        (SupportedLangs::French, 7) => &words_all_fr::SEVEN_LETTER_WORDS,
+        (SupportedLangs::French, 8) => &words_all_fr::EIGHT_LETTER_WORDS,
+        _ => return None
+    };
+
+    Some(word_list)
+}
+
+
 /**
  * Get a simple boolean indicating whether the given word
- * is on the master list of five-letter words.
+ * is on the master list of words of its length (in the game's language).
  */
-pub fn is_real_word(word: &str) -> bool {
+pub fn is_real_word(word: &str, lang: &SupportedLangs) -> bool {
     let no_caps_word: String = utils::no_caps(word);
-    ALL_WORDS.contains(&no_caps_word)
+
+    match get_word_list(no_caps_word.chars().count(), lang) {
+        Some(word_list) => word_list.contains(&no_caps_word),
+        None => false
+    }
+}
+
+
+/**
+ * Every acceptable word of the given length (lowercase).
+ * Bots choose their guesses from these.
+ */
+pub fn get_words_of_length(word_length: u8, lang: &SupportedLangs) -> Vec<&'static str> {
+    match get_word_list(word_length as usize, lang) {
+        Some(word_list) => word_list.iter().copied().collect(),
+        None => Vec::new()
+    }
 }
 
 
@@ -12075,4 +12119,25744 @@ static ALL_WORDS: Set<&'static str> = phf_set! {
     "zygon",
     "zymes",
     "zymic"
-};
\ No newline at end of file
+};
+
+
+static FOUR_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "abac",
+    "abba",
+    "abbe",
+    "abby",
+    "abel",
+    "abes",
+    "abet",
+    "abie",
+    "able",
+    "ably",
+    "abox",
+    "abri",
+    "abut",
+    "abye",
+    "acer",
+    "aces",
+    "ache",
+    "acid",
+    "acme",
+    "acne",
+    "acre",
+    "acyl",
+    "adah",
+    "adam",
+    "adan",
+    "adas",
+    "adaw",
+    "aday",
+    "aden",
+    "adio",
+    "adit",
+    "adze",
+    "aede",
+    "aegi",
+    "aeon",
+    "aero",
+    "afar",
+    "afro",
+    "agar",
+    "agee",
+    "ager",
+    "agha",
+    "agio",
+    "aglu",
+    "agly",
+    "agma",
+    "agon",
+    "ague",
+    "aida",
+    "aide",
+    "aido",
+    "aiko",
+    "aina",
+    "airn",
+    "airt",
+    "airy",
+    "aitu",
+    "ajas",
+    "akee",
+    "akin",
+    "akka",
+    "alan",
+    "alap",
+    "alay",
+    "alba",
+    "alda",
+    "aldo",
+    "alec",
+    "alef",
+    "alew",
+    "alex",
+    "alfa",
+    "alga",
+    "alia",
+    "alif",
+    "alis",
+    "alix",
+    "alla",
+    "alli",
+    "ally",
+    "alma",
+    "alme",
+    "alod",
+    "aloe",
+    "also",
+    "alta",
+    "alto",
+    "alum",
+    "alva",
+    "amah",
+    "amal",
+    "ambo",
+    "amee",
+    "amen",
+    "amia",
+    "amic",
+    "amid",
+    "amie",
+    "amin",
+    "amir",
+    "amis",
+    "amla",
+    "ammo",
+    "amok",
+    "amos",
+    "amya",
+    "amyl",
+    "amys",
+    "anas",
+    "andy",
+    "anew",
+    "anga",
+    "anhs",
+    "anil",
+    "anja",
+    "ankh",
+    "anku",
+    "anla",
+    "anna",
+    "anne",
+    "anns",
+    "anoa",
+    "anta",
+    "ante",
+    "anti",
+    "ants",
+    "anya",
+    "anyu",
+    "aona",
+    "apay",
+    "aper",
+    "apes",
+    "aphi",
+    "apio",
+    "apod",
+    "apre",
+    "apse",
+    "apsi",
+    "aqua",
+    "arak",
+    "arar",
+    "aras",
+    "arba",
+    "arch",
+    "arcu",
+    "area",
+    "ares",
+    "aret",
+    "argu",
+    "aria",
+    "arid",
+    "arie",
+    "aril",
+    "aris",
+    "arla",
+    "arle",
+    "arlo",
+    "army",
+    "arna",
+    "aron",
+    "arra",
+    "arri",
+    "arse",
+    "arsi",
+    "arts",
+    "arty",
+    "arum",
+    "arvo",
+    "arya",
+    "aryl",
+    "asas",
+    "ascu",
+    "asha",
+    "ashe",
+    "ashy",
+    "asia",
+    "asko",
+    "aspi",
+    "asps",
+    "asse",
+    "atap",
+    "atar",
+    "atla",
+    "atma",
+    "atoc",
+    "atok",
+    "atom",
+    "atry",
+    "auks",
+    "aula",
+    "auld",
+    "aulo",
+    "aune",
+    "aunt",
+    "aura",
+    "aure",
+    "auri",
+    "auto",
+    "avah",
+    "avas",
+    "aven",
+    "aver",
+    "avga",
+    "avid",
+    "avis",
+    "avow",
+    "away",
+    "awdl",
+    "awed",
+    "awol",
+    "axel",
+    "axil",
+    "axis",
+    "axle",
+    "axon",
+    "ayah",
+    "ayin",
+    "ayla",
+    "ayre",
+    "azan",
+    "azon",
+    "azym",
+    "baal",
+    "baba",
+    "babe",
+    "babu",
+    "baby",
+    "bach",
+    "back",
+    "bael",
+    "baff",
+    "baft",
+    "baht",
+    "bail",
+    "bait",
+    "baju",
+    "bake",
+    "bala",
+    "bald",
+    "bale",
+    "balk",
+    "ball",
+    "balm",
+    "balu",
+    "banc",
+    "band",
+    "bane",
+    "bang",
+    "bank",
+    "bann",
+    "bant",
+    "baos",
+    "bapu",
+    "barb",
+    "bard",
+    "bare",
+    "barf",
+    "bari",
+    "bark",
+    "barm",
+    "barn",
+    "barp",
+    "bart",
+    "base",
+    "basi",
+    "bask",
+    "bass",
+    "bast",
+    "bate",
+    "bath",
+    "bats",
+    "batt",
+    "baud",
+    "bauk",
+    "baur",
+    "bawd",
+    "bawl",
+    "bawn",
+    "bawr",
+    "baye",
+    "bayt",
+    "bead",
+    "beak",
+    "beam",
+    "bean",
+    "bear",
+    "beas",
+    "beat",
+    "beau",
+    "bebe",
+    "beck",
+    "bede",
+    "beef",
+    "beep",
+    "beer",
+    "bees",
+    "beet",
+    "beld",
+    "bell",
+    "belt",
+    "bema",
+    "bend",
+    "bene",
+    "beni",
+    "bens",
+    "bent",
+    "bere",
+    "berg",
+    "berk",
+    "berm",
+    "bert",
+    "bese",
+    "bess",
+    "best",
+    "beta",
+    "bete",
+    "beth",
+    "bevs",
+    "beze",
+    "bhel",
+    "bhut",
+    "bibb",
+    "bibi",
+    "bice",
+    "bide",
+    "bier",
+    "biff",
+    "bigg",
+    "bigo",
+    "bike",
+    "bile",
+    "bilk",
+    "bill",
+    "bima",
+    "bind",
+    "bine",
+    "bing",
+    "bink",
+    "bint",
+    "biog",
+    "bird",
+    "birk",
+    "birl",
+    "biro",
+    "birr",
+    "bise",
+    "bisk",
+    "bite",
+    "bito",
+    "bitt",
+    "bize",
+    "blab",
+    "blad",
+    "blae",
+    "blag",
+    "blah",
+    "blam",
+    "blas",
+    "blat",
+    "blaw",
+    "blay",
+    "bleb",
+    "blee",
+    "blet",
+    "bley",
+    "blin",
+    "blip",
+    "blob",
+    "bloc",
+    "blog",
+    "blot",
+    "blow",
+    "blub",
+    "blue",
+    "blur",
+    "boak",
+    "boar",
+    "boas",
+    "boat",
+    "boba",
+    "bobs",
+    "bock",
+    "bode",
+    "body",
+    "boff",
+    "bogu",
+    "boho",
+    "boil",
+    "boke",
+    "boko",
+    "boks",
+    "bola",
+    "bold",
+    "bole",
+    "boll",
+    "bolo",
+    "bolt",
+    "bolu",
+    "boma",
+    "bomb",
+    "bona",
+    "bond",
+    "bone",
+    "bong",
+    "bonk",
+    "bonu",
+    "bony",
+    "boob",
+    "booh",
+    "book",
+    "bool",
+    "boom",
+    "boon",
+    "boor",
+    "boot",
+    "bora",
+    "bord",
+    "bore",
+    "bork",
+    "bort",
+    "bosk",
+    "boss",
+    "bota",
+    "bott",
+    "bouk",
+    "boun",
+    "bout",
+    "bowe",
+    "bowl",
+    "bowr",
+    "boxe",
+    "boyd",
+    "boyg",
+    "boyo",
+    "bozo",
+    "brad",
+    "brae",
+    "brag",
+    "bran",
+    "brat",
+    "braw",
+    "bray",
+    "bree",
+    "brei",
+    "bren",
+    "brer",
+    "bret",
+    "brew",
+    "bria",
+    "brie",
+    "brig",
+    "brim",
+    "brin",
+    "brio",
+    "brit",
+    "brod",
+    "brog",
+    "broo",
+    "brow",
+    "brut",
+    "bryn",
+    "buat",
+    "buba",
+    "bubu",
+    "buck",
+    "budo",
+    "buds",
+    "buff",
+    "bufo",
+    "bugs",
+    "buhl",
+    "buhr",
+    "buik",
+    "buke",
+    "bula",
+    "bulb",
+    "bulk",
+    "bull",
+    "bumf",
+    "bump",
+    "buna",
+    "bund",
+    "bung",
+    "bunk",
+    "bunn",
+    "bunt",
+    "buoy",
+    "bura",
+    "burb",
+    "burd",
+    "burg",
+    "burk",
+    "burl",
+    "burn",
+    "burp",
+    "burr",
+    "burt",
+    "buse",
+    "busk",
+    "bust",
+    "busy",
+    "bute",
+    "butt",
+    "buzz",
+    "byke",
+    "byre",
+    "byrl",
+    "byte",
+    "caba",
+    "caca",
+    "cade",
+    "cadi",
+    "cafe",
+    "caff",
+    "cage",
+    "cagy",
+    "caid",
+    "cain",
+    "cake",
+    "calf",
+    "cali",
+    "calk",
+    "call",
+    "calm",
+    "calo",
+    "calp",
+    "cama",
+    "came",
+    "cami",
+    "camo",
+    "camp",
+    "camu",
+    "cane",
+    "cang",
+    "cann",
+    "cant",
+    "cany",
+    "capa",
+    "cape",
+    "caph",
+    "capo",
+    "cara",
+    "carb",
+    "card",
+    "care",
+    "cari",
+    "cark",
+    "carl",
+    "carn",
+    "carp",
+    "carr",
+    "cart",
+    "cary",
+    "casa",
+    "case",
+    "cash",
+    "cask",
+    "cast",
+    "casu",
+    "cate",
+    "cats",
+    "cauk",
+    "caul",
+    "caum",
+    "caup",
+    "cave",
+    "cawk",
+    "cede",
+    "cedi",
+    "ceil",
+    "cell",
+    "celt",
+    "cent",
+    "cepe",
+    "cere",
+    "cero",
+    "cert",
+    "cete",
+    "chad",
+    "chae",
+    "chai",
+    "chal",
+    "cham",
+    "chan",
+    "chao",
+    "chap",
+    "char",
+    "chas",
+    "chat",
+    "chau",
+    "chaw",
+    "chay",
+    "chef",
+    "cher",
+    "chet",
+    "chew",
+    "chia",
+    "chic",
+    "chik",
+    "chin",
+    "chip",
+    "chis",
+    "chit",
+    "chiv",
+    "choc",
+    "chon",
+    "chop",
+    "chow",
+    "chub",
+    "chug",
+    "chum",
+    "chun",
+    "chus",
+    "ciao",
+    "ciel",
+    "cill",
+    "cine",
+    "cion",
+    "cira",
+    "circ",
+    "cire",
+    "cirl",
+    "cist",
+    "cite",
+    "city",
+    "cive",
+    "clad",
+    "clae",
+    "clag",
+    "clam",
+    "clan",
+    "clap",
+    "clat",
+    "claw",
+    "clay",
+    "clef",
+    "cleg",
+    "clem",
+    "cleo",
+    "clew",
+    "clie",
+    "clip",
+    "clit",
+    "clod",
+    "clog",
+    "clon",
+    "clop",
+    "clot",
+    "clou",
+    "clow",
+    "cloy",
+    "club",
+    "clue",
+    "coal",
+    "coat",
+    "cobb",
+    "coca",
+    "cock",
+    "coco",
+    "coda",
+    "code",
+    "codi",
+    "cods",
+    "cody",
+    "coed",
+    "coff",
+    "coho",
+    "coif",
+    "coil",
+    "coin",
+    "coir",
+    "coit",
+    "coke",
+    "coky",
+    "cola",
+    "cold",
+    "cole",
+    "coll",
+    "colt",
+    "coly",
+    "coma",
+    "comb",
+    "come",
+    "comp",
+    "comu",
+    "cone",
+    "conk",
+    "conn",
+    "conu",
+    "cony",
+    "coof",
+    "cook",
+    "cool",
+    "coom",
+    "coon",
+    "coop",
+    "coot",
+    "cope",
+    "copy",
+    "cora",
+    "cord",
+    "core",
+    "cori",
+    "cork",
+    "corm",
+    "corn",
+    "corp",
+    "cory",
+    "cose",
+    "cost",
+    "cote",
+    "coth",
+    "cott",
+    "coua",
+    "coud",
+    "coup",
+    "cour",
+    "cove",
+    "cowl",
+    "cowp",
+    "cows",
+    "coxe",
+    "coys",
+    "coze",
+    "crab",
+    "crag",
+    "cram",
+    "cran",
+    "crap",
+    "craw",
+    "cred",
+    "cree",
+    "crew",
+    "crib",
+    "crie",
+    "crim",
+    "cris",
+    "crit",
+    "croc",
+    "crop",
+    "crow",
+    "crud",
+    "cruz",
+    "cube",
+    "cubs",
+    "cucs",
+    "cuff",
+    "cuif",
+    "cuit",
+    "cuke",
+    "cull",
+    "culm",
+    "cult",
+    "cunt",
+    "curb",
+    "curd",
+    "cure",
+    "curf",
+    "curl",
+    "curn",
+    "curr",
+    "curt",
+    "cusk",
+    "cusp",
+    "cute",
+    "cuti",
+    "cyan",
+    "cyca",
+    "cyma",
+    "cyme",
+    "cyst",
+    "czar",
+    "daal",
+    "dace",
+    "dada",
+    "dado",
+    "daff",
+    "daft",
+    "dago",
+    "dahl",
+    "dale",
+    "dali",
+    "dalt",
+    "dame",
+    "damn",
+    "damp",
+    "dana",
+    "dane",
+    "dang",
+    "dani",
+    "dank",
+    "dann",
+    "dans",
+    "dant",
+    "dara",
+    "darb",
+    "dare",
+    "darg",
+    "dari",
+    "dark",
+    "darl",
+    "darn",
+    "dart",
+    "dash",
+    "date",
+    "dato",
+    "daub",
+    "daud",
+    "daur",
+    "daut",
+    "dave",
+    "dawd",
+    "dawk",
+    "dawn",
+    "dawt",
+    "daxs",
+    "daze",
+    "dead",
+    "deal",
+    "dean",
+    "dear",
+    "deaw",
+    "debi",
+    "debs",
+    "debt",
+    "debu",
+    "deck",
+    "deco",
+    "dede",
+    "deed",
+    "deem",
+    "deen",
+    "deep",
+    "deer",
+    "dees",
+    "deet",
+    "deev",
+    "defi",
+    "deft",
+    "defy",
+    "dega",
+    "deid",
+    "deil",
+    "deja",
+    "deke",
+    "dele",
+    "delf",
+    "deli",
+    "dell",
+    "dels",
+    "delt",
+    "deme",
+    "demi",
+    "demo",
+    "dena",
+    "dene",
+    "dent",
+    "deny",
+    "deon",
+    "dere",
+    "derm",
+    "dern",
+    "derv",
+    "desk",
+    "deva",
+    "dewy",
+    "dexe",
+    "dgag",
+    "dhak",
+    "dhal",
+    "dhow",
+    "dial",
+    "dian",
+    "dias",
+    "dibb",
+    "dice",
+    "dick",
+    "dict",
+    "dido",
+    "dieb",
+    "diet",
+    "diff",
+    "dika",
+    "dike",
+    "dill",
+    "dime",
+    "dina",
+    "dine",
+    "ding",
+    "dink",
+    "dino",
+    "dint",
+    "diol",
+    "dion",
+    "dire",
+    "dirk",
+    "dirl",
+    "dirt",
+    "disa",
+    "disc",
+    "dish",
+    "disk",
+    "dita",
+    "dite",
+    "ditt",
+    "diva",
+    "dive",
+    "divi",
+    "djin",
+    "dmod",
+    "doab",
+    "doat",
+    "dock",
+    "dodo",
+    "doek",
+    "doer",
+    "does",
+    "doff",
+    "doge",
+    "dogs",
+    "doit",
+    "dojo",
+    "dole",
+    "doll",
+    "dolt",
+    "dome",
+    "dona",
+    "dong",
+    "donn",
+    "dons",
+    "doob",
+    "dook",
+    "dool",
+    "doom",
+    "door",
+    "dopa",
+    "dope",
+    "dopy",
+    "dora",
+    "dori",
+    "dork",
+    "dorm",
+    "dorp",
+    "dorr",
+    "dort",
+    "dory",
+    "dose",
+    "dote",
+    "dots",
+    "doty",
+    "douc",
+    "doug",
+    "doum",
+    "doup",
+    "dour",
+    "dout",
+    "doux",
+    "dove",
+    "dowd",
+    "dowf",
+    "dowl",
+    "down",
+    "dowp",
+    "dowt",
+    "dowy",
+    "doze",
+    "dozy",
+    "drab",
+    "drag",
+    "dram",
+    "drap",
+    "drat",
+    "draw",
+    "dray",
+    "dree",
+    "dreg",
+    "drek",
+    "drew",
+    "drey",
+    "drib",
+    "drie",
+    "drip",
+    "drop",
+    "drow",
+    "drub",
+    "drug",
+    "drum",
+    "drys",
+    "duad",
+    "dual",
+    "duan",
+    "duar",
+    "duce",
+    "duck",
+    "duct",
+    "dude",
+    "duel",
+    "duet",
+    "duff",
+    "dufu",
+    "duit",
+    "duke",
+    "dule",
+    "dull",
+    "duly",
+    "duma",
+    "dumb",
+    "dump",
+    "dune",
+    "dung",
+    "dunk",
+    "dunt",
+    "dupe",
+    "dura",
+    "dure",
+    "durn",
+    "duro",
+    "durr",
+    "dusk",
+    "dust",
+    "duty",
+    "duxe",
+    "dwam",
+    "dyad",
+    "dyan",
+    "dyer",
+    "dyke",
+    "dyne",
+    "dzho",
+    "eale",
+    "eans",
+    "eard",
+    "earl",
+    "earn",
+    "ease",
+    "east",
+    "easy",
+    "eave",
+    "ebon",
+    "ecad",
+    "eche",
+    "echo",
+    "echt",
+    "ecru",
+    "edas",
+    "edda",
+    "eddy",
+    "eden",
+    "edge",
+    "edgy",
+    "edie",
+    "edit",
+    "edna",
+    "edra",
+    "eels",
+    "eely",
+    "eery",
+    "eevn",
+    "efts",
+    "egad",
+    "egal",
+    "eger",
+    "egma",
+    "eido",
+    "eild",
+    "ekka",
+    "elan",
+    "elas",
+    "elba",
+    "elda",
+    "elfs",
+    "elhi",
+    "elia",
+    "elin",
+    "elis",
+    "eliz",
+    "elke",
+    "elks",
+    "ella",
+    "elle",
+    "elli",
+    "elly",
+    "elma",
+    "elmo",
+    "elmy",
+    "elna",
+    "elop",
+    "eloy",
+    "elsa",
+    "else",
+    "elsy",
+    "elva",
+    "elve",
+    "elza",
+    "emas",
+    "embu",
+    "emeu",
+    "emil",
+    "emir",
+    "emit",
+    "emma",
+    "emmy",
+    "emus",
+    "emyd",
+    "enas",
+    "enda",
+    "enew",
+    "enid",
+    "enol",
+    "enow",
+    "enzo",
+    "eorl",
+    "epee",
+    "epha",
+    "epic",
+    "epri",
+    "eras",
+    "eric",
+    "erik",
+    "erin",
+    "erma",
+    "erna",
+    "erne",
+    "erse",
+    "erub",
+    "eruv",
+    "eryn",
+    "esme",
+    "esne",
+    "esse",
+    "esta",
+    "etat",
+    "eten",
+    "etha",
+    "etho",
+    "etna",
+    "etta",
+    "etui",
+    "eugh",
+    "eula",
+    "euna",
+    "euns",
+    "eura",
+    "euro",
+    "evan",
+    "evas",
+    "even",
+    "eves",
+    "evet",
+    "evia",
+    "evie",
+    "evil",
+    "evon",
+    "ewas",
+    "ewer",
+    "ewes",
+    "exam",
+    "exec",
+    "exie",
+    "exit",
+    "exon",
+    "expo",
+    "exul",
+    "eyed",
+    "eyer",
+    "eyot",
+    "eyra",
+    "eyre",
+    "ezra",
+    "face",
+    "fack",
+    "fact",
+    "fade",
+    "fado",
+    "faes",
+    "faff",
+    "faik",
+    "fail",
+    "fain",
+    "fair",
+    "fake",
+    "fall",
+    "fame",
+    "fand",
+    "fane",
+    "fang",
+    "fank",
+    "fano",
+    "fard",
+    "fare",
+    "farl",
+    "farm",
+    "faro",
+    "fart",
+    "fast",
+    "fate",
+    "faun",
+    "fava",
+    "fave",
+    "favu",
+    "fawn",
+    "faxe",
+    "faye",
+    "fays",
+    "faze",
+    "feal",
+    "fear",
+    "feat",
+    "fece",
+    "feck",
+    "feed",
+    "feel",
+    "feer",
+    "fell",
+    "felt",
+    "feme",
+    "fend",
+    "feni",
+    "fenk",
+    "fent",
+    "feod",
+    "fere",
+    "ferm",
+    "fern",
+    "fest",
+    "feta",
+    "fete",
+    "fett",
+    "fetu",
+    "feud",
+    "feze",
+    "fiar",
+    "fiat",
+    "fice",
+    "fico",
+    "ficu",
+    "fido",
+    "fief",
+    "fife",
+    "figo",
+    "fike",
+    "file",
+    "fill",
+    "film",
+    "filo",
+    "find",
+    "fine",
+    "fini",
+    "fink",
+    "finn",
+    "fino",
+    "fire",
+    "firk",
+    "firm",
+    "firn",
+    "fisc",
+    "fish",
+    "fisk",
+    "fist",
+    "fitt",
+    "five",
+    "fixe",
+    "flab",
+    "flag",
+    "flak",
+    "flam",
+    "flan",
+    "flap",
+    "flat",
+    "flaw",
+    "flay",
+    "flea",
+    "flee",
+    "fleg",
+    "flew",
+    "fley",
+    "flic",
+    "flie",
+    "flip",
+    "flir",
+    "flit",
+    "floc",
+    "floe",
+    "flog",
+    "flop",
+    "flor",
+    "flos",
+    "flow",
+    "floy",
+    "flub",
+    "flue",
+    "flys",
+    "foal",
+    "foam",
+    "focu",
+    "fohn",
+    "foid",
+    "foil",
+    "foin",
+    "fold",
+    "folk",
+    "fome",
+    "fond",
+    "font",
+    "food",
+    "fool",
+    "foon",
+    "foot",
+    "forb",
+    "ford",
+    "fore",
+    "fork",
+    "form",
+    "fort",
+    "foud",
+    "foul",
+    "four",
+    "fowl",
+    "foxe",
+    "foxs",
+    "foxy",
+    "fozy",
+    "frab",
+    "frag",
+    "fran",
+    "frap",
+    "frat",
+    "frau",
+    "fray",
+    "fred",
+    "free",
+    "fret",
+    "frie",
+    "frig",
+    "frit",
+    "froe",
+    "frog",
+    "fron",
+    "frow",
+    "frug",
+    "fuck",
+    "fucu",
+    "fuel",
+    "fuff",
+    "fugu",
+    "fuji",
+    "full",
+    "fuma",
+    "fume",
+    "fumy",
+    "fund",
+    "fung",
+    "funk",
+    "furl",
+    "furo",
+    "furr",
+    "fury",
+    "fuse",
+    "fust",
+    "fuze",
+    "fyce",
+    "fyke",
+    "fyle",
+    "fyrd",
+    "gade",
+    "gadi",
+    "gael",
+    "gaff",
+    "gaga",
+    "gage",
+    "gaid",
+    "gail",
+    "gain",
+    "gair",
+    "gait",
+    "gajo",
+    "gala",
+    "gale",
+    "gall",
+    "gama",
+    "gamb",
+    "game",
+    "gamp",
+    "gamy",
+    "gang",
+    "gant",
+    "gaol",
+    "gape",
+    "gapo",
+    "gapy",
+    "garb",
+    "gars",
+    "gary",
+    "gase",
+    "gasp",
+    "gast",
+    "gate",
+    "gath",
+    "gaud",
+    "gaum",
+    "gaup",
+    "gaur",
+    "gawd",
+    "gawk",
+    "gawp",
+    "gaye",
+    "gaze",
+    "geal",
+    "gean",
+    "gear",
+    "geat",
+    "geck",
+    "geek",
+    "geep",
+    "geit",
+    "geld",
+    "gelt",
+    "gema",
+    "gena",
+    "gene",
+    "gent",
+    "genu",
+    "gere",
+    "geri",
+    "germ",
+    "gest",
+    "geta",
+    "geum",
+    "ghat",
+    "ghee",
+    "gias",
+    "gibe",
+    "gibu",
+    "gift",
+    "giga",
+    "gigi",
+    "gila",
+    "gild",
+    "gill",
+    "gils",
+    "gilt",
+    "gimp",
+    "gina",
+    "ging",
+    "gink",
+    "gino",
+    "gird",
+    "giri",
+    "girl",
+    "girn",
+    "giro",
+    "girr",
+    "girt",
+    "gism",
+    "gist",
+    "gita",
+    "gite",
+    "give",
+    "glad",
+    "glam",
+    "glan",
+    "gled",
+    "glee",
+    "gleg",
+    "glei",
+    "glen",
+    "gley",
+    "glia",
+    "glib",
+    "glim",
+    "glit",
+    "glob",
+    "glom",
+    "glop",
+    "glow",
+    "glue",
+    "glug",
+    "glum",
+    "glut",
+    "gnar",
+    "gnat",
+    "gnaw",
+    "gnus",
+    "goad",
+    "goaf",
+    "goal",
+    "goat",
+    "gobo",
+    "goby",
+    "goel",
+    "goer",
+    "goff",
+    "gogo",
+    "gold",
+    "gole",
+    "golf",
+    "golp",
+    "gong",
+    "gonk",
+    "gony",
+    "good",
+    "goof",
+    "gook",
+    "gool",
+    "goon",
+    "goop",
+    "goor",
+    "gore",
+    "gork",
+    "gorm",
+    "gorp",
+    "gory",
+    "goth",
+    "gouk",
+    "gout",
+    "gowd",
+    "gowf",
+    "gowk",
+    "gowl",
+    "gown",
+    "goxe",
+    "grab",
+    "grad",
+    "gram",
+    "gran",
+    "grav",
+    "gray",
+    "gree",
+    "greg",
+    "gren",
+    "grew",
+    "grey",
+    "grid",
+    "grig",
+    "grim",
+    "grin",
+    "grip",
+    "grit",
+    "grog",
+    "grok",
+    "grot",
+    "grow",
+    "grub",
+    "grue",
+    "grum",
+    "guan",
+    "guar",
+    "guck",
+    "gude",
+    "guff",
+    "guga",
+    "guid",
+    "gula",
+    "gule",
+    "gulf",
+    "gull",
+    "gulp",
+    "gump",
+    "gung",
+    "gunk",
+    "gurl",
+    "gurn",
+    "guru",
+    "gust",
+    "guys",
+    "gwen",
+    "gwyn",
+    "gyal",
+    "gybe",
+    "gymp",
+    "gyre",
+    "gyro",
+    "gyru",
+    "gyte",
+    "gyve",
+    "haaf",
+    "haar",
+    "habu",
+    "hack",
+    "hade",
+    "haem",
+    "haes",
+    "haet",
+    "haff",
+    "hafi",
+    "haft",
+    "hagg",
+    "haha",
+    "haik",
+    "hail",
+    "hain",
+    "hair",
+    "hais",
+    "haje",
+    "haji",
+    "haka",
+    "hake",
+    "haku",
+    "hale",
+    "half",
+    "hall",
+    "halm",
+    "halo",
+    "hals",
+    "halt",
+    "hame",
+    "hana",
+    "hand",
+    "hang",
+    "hanh",
+    "hank",
+    "hans",
+    "hant",
+    "hard",
+    "hare",
+    "hark",
+    "harl",
+    "harm",
+    "harn",
+    "haro",
+    "harp",
+    "hart",
+    "hask",
+    "hasp",
+    "hate",
+    "haud",
+    "haul",
+    "have",
+    "hawk",
+    "hawm",
+    "haze",
+    "hazy",
+    "head",
+    "heal",
+    "heap",
+    "hear",
+    "heat",
+    "hebe",
+    "heck",
+    "hedy",
+    "heed",
+    "heel",
+    "hees",
+    "heft",
+    "heid",
+    "heil",
+    "heir",
+    "hele",
+    "heli",
+    "hell",
+    "helm",
+    "helo",
+    "help",
+    "heme",
+    "hemp",
+    "hend",
+    "hens",
+    "hent",
+    "herb",
+    "herd",
+    "here",
+    "herl",
+    "herm",
+    "hern",
+    "hero",
+    "hesp",
+    "hest",
+    "hete",
+    "heth",
+    "hexe",
+    "hick",
+    "hide",
+    "hiem",
+    "hien",
+    "high",
+    "hike",
+    "hill",
+    "hilt",
+    "hilu",
+    "hind",
+    "hing",
+    "hint",
+    "hire",
+    "hist",
+    "hive",
+    "hoar",
+    "hoas",
+    "hobo",
+    "hock",
+    "hocu",
+    "hoer",
+    "hogg",
+    "hogh",
+    "hogs",
+    "hoik",
+    "hoke",
+    "hoki",
+    "hold",
+    "hole",
+    "holk",
+    "holm",
+    "holt",
+    "holy",
+    "home",
+    "homo",
+    "homy",
+    "hond",
+    "hone",
+    "hong",
+    "honk",
+    "hood",
+    "hoof",
+    "hook",
+    "hoon",
+    "hoop",
+    "hoot",
+    "hope",
+    "hora",
+    "horn",
+    "hose",
+    "host",
+    "houf",
+    "hour",
+    "hout",
+    "hove",
+    "howe",
+    "howf",
+    "howk",
+    "howl",
+    "hoxe",
+    "hoya",
+    "hoyt",
+    "hsiu",
+    "huck",
+    "hued",
+    "huer",
+    "hues",
+    "huey",
+    "huff",
+    "huge",
+    "hugh",
+    "hugo",
+    "huia",
+    "huis",
+    "hula",
+    "hule",
+    "hulk",
+    "hull",
+    "huma",
+    "humf",
+    "hump",
+    "humu",
+    "hung",
+    "hunk",
+    "hunt",
+    "hurd",
+    "hurl",
+    "hurt",
+    "husk",
+    "huso",
+    "hwas",
+    "hwyl",
+    "hyen",
+    "hyes",
+    "hyke",
+    "hyla",
+    "hyle",
+    "hymn",
+    "hyon",
+    "hyos",
+    "hype",
+    "hypo",
+    "hyte",
+    "hyun",
+    "iamb",
+    "ians",
+    "ibex",
+    "ibis",
+    "iced",
+    "icer",
+    "iche",
+    "icky",
+    "icon",
+    "ictu",
+    "idas",
+    "idea",
+    "idee",
+    "idle",
+    "idly",
+    "idol",
+    "idyl",
+    "iffy",
+    "iglu",
+    "iiwi",
+    "ikat",
+    "iker",
+    "ikes",
+    "ikon",
+    "ilas",
+    "ilda",
+    "ileu",
+    "illa",
+    "illy",
+    "ilse",
+    "imam",
+    "imas",
+    "imid",
+    "impi",
+    "imps",
+    "inas",
+    "inch",
+    "incu",
+    "ines",
+    "inez",
+    "info",
+    "inga",
+    "inge",
+    "inky",
+    "inly",
+    "inti",
+    "into",
+    "iola",
+    "iona",
+    "ione",
+    "iota",
+    "iras",
+    "irid",
+    "iris",
+    "irma",
+    "iron",
+    "isas",
+    "isba",
+    "ishe",
+    "isis",
+    "isla",
+    "isle",
+    "item",
+    "ivan",
+    "ivas",
+    "ivey",
+    "ivie",
+    "ivys",
+    "ixia",
+    "izar",
+    "izba",
+    "jace",
+    "jack",
+    "jada",
+    "jade",
+    "jaes",
+    "jagg",
+    "jail",
+    "jair",
+    "jake",
+    "jama",
+    "jamb",
+    "jame",
+    "jami",
+    "jana",
+    "jane",
+    "jani",
+    "jann",
+    "jans",
+    "jape",
+    "jari",
+    "jark",
+    "jarl",
+    "jase",
+    "jasp",
+    "jato",
+    "jauk",
+    "jaup",
+    "java",
+    "jaxs",
+    "jaye",
+    "jays",
+    "jazz",
+    "jean",
+    "jeat",
+    "jeds",
+    "jeel",
+    "jeep",
+    "jeer",
+    "jefe",
+    "jeff",
+    "jehu",
+    "jell",
+    "jena",
+    "jene",
+    "jeni",
+    "jens",
+    "jere",
+    "jeri",
+    "jerk",
+    "jess",
+    "jest",
+    "jesu",
+    "jete",
+    "jett",
+    "jiao",
+    "jibb",
+    "jibe",
+    "jiff",
+    "jill",
+    "jilt",
+    "jimp",
+    "jims",
+    "jina",
+    "jink",
+    "jinn",
+    "jins",
+    "jird",
+    "jism",
+    "jive",
+    "joan",
+    "jobe",
+    "jock",
+    "jodi",
+    "jody",
+    "joel",
+    "joes",
+    "joey",
+    "john",
+    "joie",
+    "join",
+    "jois",
+    "joke",
+    "jole",
+    "joll",
+    "jolt",
+    "jomo",
+    "jona",
+    "jone",
+    "jong",
+    "joni",
+    "jons",
+    "jook",
+    "jose",
+    "josh",
+    "jota",
+    "joug",
+    "jouk",
+    "jour",
+    "jowl",
+    "joya",
+    "joye",
+    "joys",
+    "juan",
+    "juba",
+    "jube",
+    "juda",
+    "jude",
+    "judi",
+    "judo",
+    "judy",
+    "juju",
+    "juke",
+    "jule",
+    "juli",
+    "jump",
+    "june",
+    "jung",
+    "junk",
+    "jupe",
+    "just",
+    "jute",
+    "juve",
+    "kaci",
+    "kacy",
+    "kade",
+    "kadi",
+    "kago",
+    "kagu",
+    "kaia",
+    "kaid",
+    "kaie",
+    "kaif",
+    "kail",
+    "kaim",
+    "kain",
+    "kais",
+    "kaka",
+    "kaki",
+    "kala",
+    "kale",
+    "kali",
+    "kama",
+    "kame",
+    "kami",
+    "kams",
+    "kana",
+    "kane",
+    "kang",
+    "kant",
+    "kaon",
+    "kapa",
+    "kaph",
+    "kara",
+    "kari",
+    "karl",
+    "karn",
+    "kart",
+    "kary",
+    "kase",
+    "kash",
+    "kasi",
+    "kata",
+    "kate",
+    "kati",
+    "katy",
+    "kava",
+    "kaya",
+    "kaye",
+    "kayo",
+    "kays",
+    "kazi",
+    "kbar",
+    "keas",
+    "keck",
+    "keef",
+    "keek",
+    "keel",
+    "keen",
+    "keep",
+    "keet",
+    "keir",
+    "keli",
+    "kell",
+    "kelp",
+    "kelt",
+    "kemb",
+    "kemp",
+    "kena",
+    "keno",
+    "kens",
+    "kent",
+    "kepi",
+    "kera",
+    "kerb",
+    "kerf",
+    "keri",
+    "kern",
+    "kest",
+    "keta",
+    "keto",
+    "keva",
+    "kexe",
+    "khaf",
+    "khan",
+    "khat",
+    "khet",
+    "khor",
+    "khud",
+    "kian",
+    "kias",
+    "kibe",
+    "kick",
+    "kids",
+    "kief",
+    "kier",
+    "kike",
+    "kill",
+    "kiln",
+    "kilo",
+    "kilp",
+    "kilt",
+    "kimi",
+    "kims",
+    "kina",
+    "kind",
+    "kine",
+    "king",
+    "kink",
+    "kino",
+    "kipe",
+    "kipp",
+    "kips",
+    "kira",
+    "kirk",
+    "kirn",
+    "kiss",
+    "kist",
+    "kite",
+    "kith",
+    "kits",
+    "kiva",
+    "kiwi",
+    "knag",
+    "knap",
+    "knar",
+    "knee",
+    "knit",
+    "knob",
+    "knop",
+    "knot",
+    "know",
+    "knox",
+    "knub",
+    "knur",
+    "knut",
+    "koan",
+    "kobe",
+    "kobo",
+    "kody",
+    "koel",
+    "koff",
+    "kohl",
+    "kois",
+    "koji",
+    "kola",
+    "kole",
+    "kolo",
+    "konk",
+    "kook",
+    "koph",
+    "kora",
+    "kore",
+    "kori",
+    "kory",
+    "kose",
+    "koto",
+    "krab",
+    "kram",
+    "kran",
+    "kris",
+    "ksar",
+    "kudo",
+    "kudu",
+    "kuku",
+    "kumy",
+    "kuna",
+    "kuri",
+    "kurt",
+    "kuru",
+    "kyak",
+    "kyar",
+    "kyat",
+    "kyla",
+    "kyle",
+    "kyms",
+    "kynd",
+    "kyra",
+    "kyte",
+    "labi",
+    "labs",
+    "lace",
+    "laci",
+    "lack",
+    "lacy",
+    "lade",
+    "lady",
+    "lael",
+    "laer",
+    "laff",
+    "laic",
+    "laid",
+    "laik",
+    "lair",
+    "lais",
+    "lake",
+    "lakh",
+    "laky",
+    "lala",
+    "lall",
+    "lama",
+    "lamb",
+    "lame",
+    "lamp",
+    "lana",
+    "land",
+    "lane",
+    "lang",
+    "lani",
+    "lank",
+    "lans",
+    "lant",
+    "lapi",
+    "lara",
+    "lard",
+    "lare",
+    "lari",
+    "lark",
+    "larn",
+    "lase",
+    "last",
+    "lath",
+    "laud",
+    "lauf",
+    "lava",
+    "lave",
+    "lawk",
+    "lawn",
+    "laxe",
+    "laze",
+    "lazo",
+    "lazy",
+    "lead",
+    "leaf",
+    "leah",
+    "leak",
+    "leal",
+    "leam",
+    "lean",
+    "leap",
+    "lear",
+    "leas",
+    "leat",
+    "lect",
+    "leda",
+    "leek",
+    "leep",
+    "leer",
+    "lees",
+    "leet",
+    "left",
+    "lege",
+    "lehr",
+    "leia",
+    "leif",
+    "leir",
+    "lela",
+    "leme",
+    "lena",
+    "lend",
+    "lene",
+    "leng",
+    "leni",
+    "leno",
+    "lens",
+    "leon",
+    "leos",
+    "lera",
+    "lere",
+    "lerp",
+    "lesa",
+    "lese",
+    "less",
+    "lest",
+    "leta",
+    "leud",
+    "levi",
+    "levo",
+    "lewd",
+    "lewi",
+    "lexe",
+    "lexi",
+    "leze",
+    "liam",
+    "liar",
+    "lias",
+    "lick",
+    "lida",
+    "lido",
+    "lief",
+    "lien",
+    "lier",
+    "lieu",
+    "life",
+    "lift",
+    "like",
+    "lila",
+    "lili",
+    "lill",
+    "lilo",
+    "lilt",
+    "lily",
+    "lima",
+    "limb",
+    "lime",
+    "limn",
+    "limo",
+    "limp",
+    "limy",
+    "lina",
+    "lind",
+    "line",
+    "ling",
+    "linh",
+    "link",
+    "linn",
+    "lino",
+    "lins",
+    "lint",
+    "liny",
+    "lion",
+    "lira",
+    "lirk",
+    "lisa",
+    "lise",
+    "lisk",
+    "lisp",
+    "list",
+    "lita",
+    "lite",
+    "lith",
+    "live",
+    "livs",
+    "liza",
+    "lizs",
+    "load",
+    "loaf",
+    "loam",
+    "loan",
+    "lobe",
+    "lobo",
+    "lobu",
+    "loch",
+    "lock",
+    "loco",
+    "locu",
+    "lode",
+    "loft",
+    "loge",
+    "logo",
+    "logy",
+    "loid",
+    "loin",
+    "loir",
+    "lois",
+    "loke",
+    "lola",
+    "loll",
+    "loma",
+    "lome",
+    "lona",
+    "lone",
+    "long",
+    "loni",
+    "lons",
+    "loof",
+    "look",
+    "loom",
+    "loon",
+    "loop",
+    "loot",
+    "lope",
+    "lora",
+    "lord",
+    "lore",
+    "lori",
+    "lorn",
+    "lory",
+    "lose",
+    "lota",
+    "lote",
+    "loth",
+    "loto",
+    "lots",
+    "lotu",
+    "loud",
+    "loui",
+    "loun",
+    "loup",
+    "lour",
+    "lous",
+    "lout",
+    "love",
+    "lowe",
+    "lown",
+    "lowp",
+    "lowt",
+    "loxe",
+    "loyd",
+    "luau",
+    "luba",
+    "lube",
+    "luca",
+    "luce",
+    "luci",
+    "luck",
+    "lucy",
+    "lude",
+    "ludo",
+    "lues",
+    "luff",
+    "luge",
+    "luis",
+    "luka",
+    "luke",
+    "lula",
+    "lull",
+    "lulu",
+    "lump",
+    "luna",
+    "lune",
+    "lung",
+    "lunk",
+    "lunt",
+    "luny",
+    "lupe",
+    "lupu",
+    "lura",
+    "lure",
+    "lurk",
+    "lush",
+    "lusk",
+    "lust",
+    "lusu",
+    "lute",
+    "luxe",
+    "luzs",
+    "lwei",
+    "lyam",
+    "lyda",
+    "lyla",
+    "lyle",
+    "lyme",
+    "lyne",
+    "lynn",
+    "lyns",
+    "lynx",
+    "lyre",
+    "lyse",
+    "lysi",
+    "lyte",
+    "maar",
+    "mabe",
+    "mace",
+    "mach",
+    "maci",
+    "mack",
+    "macs",
+    "macy",
+    "maes",
+    "mage",
+    "magg",
+    "magu",
+    "maia",
+    "maid",
+    "maik",
+    "mail",
+    "maim",
+    "main",
+    "mair",
+    "mais",
+    "make",
+    "mako",
+    "mala",
+    "male",
+    "mali",
+    "mall",
+    "malm",
+    "malt",
+    "mama",
+    "mana",
+    "mane",
+    "mang",
+    "mani",
+    "mano",
+    "mans",
+    "manu",
+    "many",
+    "maos",
+    "mara",
+    "marc",
+    "mare",
+    "marg",
+    "mari",
+    "mark",
+    "marl",
+    "marm",
+    "mart",
+    "marx",
+    "mary",
+    "masa",
+    "mase",
+    "mask",
+    "mass",
+    "mast",
+    "masu",
+    "mate",
+    "math",
+    "matt",
+    "maud",
+    "maul",
+    "maut",
+    "mavi",
+    "mawk",
+    "mawr",
+    "maxe",
+    "maxi",
+    "maxs",
+    "maya",
+    "maye",
+    "mayo",
+    "mays",
+    "maze",
+    "mazy",
+    "mead",
+    "meal",
+    "mean",
+    "meat",
+    "mebo",
+    "meda",
+    "meed",
+    "meek",
+    "meer",
+    "mees",
+    "meet",
+    "megs",
+    "mein",
+    "meis",
+    "mela",
+    "meld",
+    "mell",
+    "mels",
+    "melt",
+    "meme",
+    "memo",
+    "mend",
+    "mene",
+    "meng",
+    "menu",
+    "meou",
+    "meow",
+    "merc",
+    "mere",
+    "meri",
+    "merk",
+    "merl",
+    "mesa",
+    "mese",
+    "mesh",
+    "meta",
+    "mete",
+    "meth",
+    "meti",
+    "meve",
+    "mewl",
+    "meze",
+    "miah",
+    "mias",
+    "mica",
+    "mick",
+    "mico",
+    "midi",
+    "mien",
+    "miff",
+    "migg",
+    "mika",
+    "mike",
+    "miki",
+    "mila",
+    "mild",
+    "mile",
+    "milk",
+    "mill",
+    "milo",
+    "milt",
+    "mime",
+    "mimi",
+    "mina",
+    "mind",
+    "mine",
+    "ming",
+    "minh",
+    "mini",
+    "mink",
+    "mino",
+    "mins",
+    "mint",
+    "minu",
+    "mira",
+    "mire",
+    "mirk",
+    "mirv",
+    "miry",
+    "mise",
+    "miso",
+    "miss",
+    "mist",
+    "mite",
+    "miti",
+    "mitt",
+    "mixe",
+    "miya",
+    "moan",
+    "moat",
+    "moch",
+    "mock",
+    "mode",
+    "modu",
+    "mohr",
+    "moil",
+    "moit",
+    "mojo",
+    "moke",
+    "moki",
+    "moko",
+    "mola",
+    "mold",
+    "mole",
+    "moll",
+    "molt",
+    "moly",
+    "mome",
+    "momu",
+    "mona",
+    "mong",
+    "monk",
+    "mono",
+    "mood",
+    "mook",
+    "mool",
+    "moon",
+    "moop",
+    "moor",
+    "moot",
+    "mope",
+    "mopu",
+    "mopy",
+    "mora",
+    "more",
+    "morn",
+    "mort",
+    "mose",
+    "mosk",
+    "most",
+    "mote",
+    "moth",
+    "mott",
+    "motu",
+    "moue",
+    "moul",
+    "moup",
+    "move",
+    "mowa",
+    "moxa",
+    "moya",
+    "moyl",
+    "moze",
+    "mozo",
+    "much",
+    "muck",
+    "mucu",
+    "muff",
+    "mugg",
+    "muid",
+    "muil",
+    "muir",
+    "muis",
+    "mule",
+    "mull",
+    "mumm",
+    "mump",
+    "mumu",
+    "muni",
+    "munt",
+    "muoi",
+    "muon",
+    "mura",
+    "mure",
+    "murk",
+    "murl",
+    "murr",
+    "muse",
+    "musk",
+    "muso",
+    "must",
+    "mute",
+    "muti",
+    "mutt",
+    "muxe",
+    "myah",
+    "myas",
+    "myla",
+    "myna",
+    "myop",
+    "myra",
+    "myrl",
+    "myth",
+    "mzee",
+    "naam",
+    "naan",
+    "nabe",
+    "nabi",
+    "nabk",
+    "nada",
+    "naff",
+    "naga",
+    "naif",
+    "naik",
+    "nail",
+    "nala",
+    "name",
+    "nams",
+    "nana",
+    "nans",
+    "napa",
+    "nape",
+    "nara",
+    "narc",
+    "nard",
+    "nare",
+    "nari",
+    "nark",
+    "nash",
+    "nate",
+    "nave",
+    "naze",
+    "nazi",
+    "neal",
+    "neap",
+    "near",
+    "neat",
+    "neck",
+    "neda",
+    "neds",
+    "need",
+    "neem",
+    "neep",
+    "negu",
+    "neif",
+    "neil",
+    "neli",
+    "nell",
+    "nema",
+    "nemn",
+    "nena",
+    "nene",
+    "neon",
+    "nerd",
+    "nerk",
+    "nert",
+    "nery",
+    "nesh",
+    "nest",
+    "neta",
+    "nete",
+    "nett",
+    "neuk",
+    "neum",
+    "neva",
+    "neve",
+    "nevu",
+    "news",
+    "newt",
+    "next",
+    "nexu",
+    "ngan",
+    "ngas",
+    "ngoc",
+    "nias",
+    "nice",
+    "nick",
+    "nico",
+    "nida",
+    "nide",
+    "nidu",
+    "nief",
+    "nife",
+    "niff",
+    "nigh",
+    "niki",
+    "niko",
+    "nila",
+    "nill",
+    "nimb",
+    "nina",
+    "nine",
+    "nipa",
+    "nirl",
+    "nisi",
+    "nisu",
+    "nita",
+    "nite",
+    "nits",
+    "nixe",
+    "noah",
+    "nock",
+    "node",
+    "nodu",
+    "noel",
+    "noes",
+    "nogg",
+    "noil",
+    "noir",
+    "nola",
+    "nole",
+    "noll",
+    "nolo",
+    "noma",
+    "nome",
+    "nomo",
+    "nona",
+    "none",
+    "nong",
+    "nook",
+    "noon",
+    "noop",
+    "nora",
+    "nori",
+    "nork",
+    "norm",
+    "nose",
+    "nosy",
+    "note",
+    "noul",
+    "noun",
+    "noup",
+    "nova",
+    "nowl",
+    "nowt",
+    "noxe",
+    "noye",
+    "nude",
+    "nuff",
+    "nuke",
+    "null",
+    "numb",
+    "nurd",
+    "nurl",
+    "nurr",
+    "nyla",
+    "oase",
+    "oasi",
+    "oast",
+    "oath",
+    "oave",
+    "obey",
+    "obia",
+    "obit",
+    "oboe",
+    "obol",
+    "oche",
+    "ocie",
+    "octa",
+    "odah",
+    "odal",
+    "odas",
+    "odin",
+    "odis",
+    "odor",
+    "odso",
+    "odyl",
+    "ofay",
+    "ogam",
+    "ogee",
+    "ogle",
+    "ogre",
+    "ohia",
+    "oily",
+    "oink",
+    "oint",
+    "okay",
+    "okeh",
+    "okra",
+    "okta",
+    "olas",
+    "olen",
+    "oleo",
+    "olga",
+    "olid",
+    "olin",
+    "olio",
+    "olla",
+    "olpe",
+    "omar",
+    "omas",
+    "ombu",
+    "omen",
+    "omer",
+    "omit",
+    "onas",
+    "once",
+    "oncu",
+    "oner",
+    "onie",
+    "onku",
+    "only",
+    "oont",
+    "oory",
+    "oose",
+    "ooze",
+    "oozy",
+    "opah",
+    "opal",
+    "open",
+    "oral",
+    "oras",
+    "orca",
+    "ordo",
+    "oren",
+    "orfe",
+    "orle",
+    "orni",
+    "orri",
+    "oryx",
+    "orzo",
+    "otha",
+    "otic",
+    "otis",
+    "otto",
+    "oulk",
+    "ouph",
+    "oust",
+    "outr",
+    "ouzo",
+    "oval",
+    "oven",
+    "over",
+    "owen",
+    "owls",
+    "owre",
+    "oxer",
+    "oxid",
+    "oxim",
+    "oyer",
+    "ozie",
+    "paca",
+    "pace",
+    "pack",
+    "paco",
+    "pact",
+    "padi",
+    "page",
+    "paik",
+    "pail",
+    "pain",
+    "pair",
+    "pala",
+    "pale",
+    "pall",
+    "palm",
+    "palp",
+    "paly",
+    "pams",
+    "pana",
+    "pand",
+    "pane",
+    "pang",
+    "pant",
+    "papa",
+    "pape",
+    "para",
+    "pard",
+    "pare",
+    "pari",
+    "park",
+    "parp",
+    "parr",
+    "part",
+    "pase",
+    "pass",
+    "past",
+    "pata",
+    "pate",
+    "path",
+    "pats",
+    "patt",
+    "paty",
+    "paua",
+    "paul",
+    "pave",
+    "pavi",
+    "pawa",
+    "pawk",
+    "pawl",
+    "pawn",
+    "paxe",
+    "pazs",
+    "peag",
+    "peak",
+    "peal",
+    "pean",
+    "pear",
+    "peat",
+    "peba",
+    "pech",
+    "peck",
+    "pede",
+    "peek",
+    "peel",
+    "peen",
+    "peep",
+    "peer",
+    "pegh",
+    "pegs",
+    "pein",
+    "peis",
+    "peke",
+    "pela",
+    "pele",
+    "pelf",
+    "pell",
+    "pelt",
+    "pend",
+    "pene",
+    "peni",
+    "penk",
+    "pent",
+    "peon",
+    "pepo",
+    "pere",
+    "peri",
+    "perk",
+    "perm",
+    "pern",
+    "perp",
+    "pert",
+    "perv",
+    "peso",
+    "pest",
+    "pete",
+    "phil",
+    "phoh",
+    "phon",
+    "phot",
+    "phut",
+    "pial",
+    "pian",
+    "pias",
+    "pica",
+    "pick",
+    "pied",
+    "pier",
+    "piet",
+    "pigs",
+    "pika",
+    "pike",
+    "piki",
+    "pile",
+    "pili",
+    "pill",
+    "pilu",
+    "pily",
+    "pima",
+    "pimp",
+    "pina",
+    "pine",
+    "ping",
+    "pink",
+    "pint",
+    "piny",
+    "pion",
+    "piou",
+    "pioy",
+    "pipa",
+    "pipe",
+    "pipi",
+    "pipy",
+    "pirl",
+    "pirn",
+    "pise",
+    "piso",
+    "pita",
+    "pith",
+    "pium",
+    "pixe",
+    "pize",
+    "plan",
+    "plap",
+    "plat",
+    "play",
+    "plea",
+    "pleb",
+    "plew",
+    "plie",
+    "plim",
+    "plod",
+    "plop",
+    "plot",
+    "plow",
+    "ploy",
+    "plug",
+    "plum",
+    "pock",
+    "poem",
+    "poet",
+    "pogo",
+    "poil",
+    "poke",
+    "poks",
+    "pole",
+    "poli",
+    "polk",
+    "poll",
+    "polo",
+    "polt",
+    "poly",
+    "pome",
+    "pomo",
+    "pomp",
+    "pond",
+    "pone",
+    "pong",
+    "ponk",
+    "pont",
+    "pony",
+    "pood",
+    "poof",
+    "pooh",
+    "pook",
+    "pool",
+    "poon",
+    "poop",
+    "poor",
+    "poot",
+    "pope",
+    "pops",
+    "pore",
+    "pork",
+    "porn",
+    "port",
+    "pose",
+    "post",
+    "pote",
+    "pott",
+    "pouf",
+    "pouk",
+    "pour",
+    "pout",
+    "pown",
+    "poxe",
+    "prad",
+    "pram",
+    "prao",
+    "prat",
+    "prau",
+    "pray",
+    "pree",
+    "prep",
+    "prey",
+    "prie",
+    "prig",
+    "prim",
+    "proa",
+    "prob",
+    "prod",
+    "prof",
+    "prog",
+    "prom",
+    "prop",
+    "prow",
+    "psoa",
+    "pube",
+    "pubi",
+    "puce",
+    "puck",
+    "pudu",
+    "puer",
+    "puff",
+    "pugs",
+    "puir",
+    "puja",
+    "puke",
+    "puku",
+    "pula",
+    "pule",
+    "puli",
+    "pulk",
+    "pull",
+    "pulp",
+    "pulu",
+    "puma",
+    "pump",
+    "puna",
+    "pung",
+    "punk",
+    "punt",
+    "puny",
+    "pupa",
+    "pups",
+    "pura",
+    "pure",
+    "puri",
+    "purl",
+    "purr",
+    "puse",
+    "push",
+    "putt",
+    "pyat",
+    "pyet",
+    "pyic",
+    "pyin",
+    "pyne",
+    "pyot",
+    "pyre",
+    "pyro",
+    "pyxe",
+    "pyxi",
+    "qadi",
+    "qaid",
+    "qoph",
+    "quad",
+    "quag",
+    "quai",
+    "quat",
+    "quay",
+    "quey",
+    "quid",
+    "quim",
+    "quin",
+    "quip",
+    "quit",
+    "quiz",
+    "quod",
+    "quop",
+    "rabi",
+    "race",
+    "rack",
+    "racy",
+    "raes",
+    "raff",
+    "raft",
+    "raga",
+    "rage",
+    "ragg",
+    "ragi",
+    "raia",
+    "raid",
+    "raik",
+    "rail",
+    "rain",
+    "rait",
+    "raja",
+    "raje",
+    "rake",
+    "raki",
+    "raku",
+    "rale",
+    "rami",
+    "ramp",
+    "rams",
+    "ramu",
+    "rana",
+    "rand",
+    "rani",
+    "rank",
+    "rant",
+    "rape",
+    "rapt",
+    "rare",
+    "rase",
+    "rasp",
+    "rata",
+    "rate",
+    "rath",
+    "rato",
+    "rats",
+    "ratu",
+    "raul",
+    "raun",
+    "rave",
+    "rawn",
+    "raxe",
+    "raya",
+    "raye",
+    "rays",
+    "raze",
+    "read",
+    "reak",
+    "real",
+    "ream",
+    "rean",
+    "reap",
+    "rear",
+    "reas",
+    "reba",
+    "rebu",
+    "reck",
+    "reda",
+    "redd",
+    "rede",
+    "redo",
+    "reed",
+    "reef",
+    "reek",
+    "reel",
+    "reen",
+    "rege",
+    "rego",
+    "reid",
+    "reif",
+    "reik",
+    "rein",
+    "reke",
+    "rely",
+    "rema",
+    "remi",
+    "remy",
+    "rena",
+    "rend",
+    "rene",
+    "rent",
+    "repo",
+    "repp",
+    "rese",
+    "rest",
+    "reta",
+    "rete",
+    "reva",
+    "rexe",
+    "rexs",
+    "reys",
+    "rhea",
+    "rhie",
+    "rhys",
+    "rial",
+    "rias",
+    "ribe",
+    "rice",
+    "rich",
+    "rick",
+    "rico",
+    "ride",
+    "riel",
+    "riem",
+    "rife",
+    "riff",
+    "rift",
+    "rigg",
+    "rile",
+    "rill",
+    "rima",
+    "rime",
+    "rimu",
+    "rimy",
+    "rina",
+    "rind",
+    "rine",
+    "ring",
+    "rink",
+    "riot",
+    "ripe",
+    "ripp",
+    "risa",
+    "rise",
+    "risk",
+    "risp",
+    "risu",
+    "rita",
+    "rite",
+    "ritt",
+    "riva",
+    "rive",
+    "rivo",
+    "riza",
+    "road",
+    "roam",
+    "roan",
+    "roar",
+    "robe",
+    "robs",
+    "robt",
+    "rock",
+    "rode",
+    "rods",
+    "roil",
+    "roin",
+    "roji",
+    "roke",
+    "role",
+    "rolf",
+    "roll",
+    "roma",
+    "romp",
+    "rona",
+    "rone",
+    "roni",
+    "rons",
+    "ront",
+    "rood",
+    "roof",
+    "rook",
+    "room",
+    "roon",
+    "roop",
+    "root",
+    "rope",
+    "ropy",
+    "rore",
+    "rort",
+    "rory",
+    "rosa",
+    "rose",
+    "ross",
+    "rost",
+    "rosy",
+    "rota",
+    "rote",
+    "roti",
+    "rotl",
+    "roto",
+    "roue",
+    "roul",
+    "roum",
+    "roup",
+    "rout",
+    "rove",
+    "rowt",
+    "roxy",
+    "roys",
+    "rube",
+    "rubi",
+    "rubu",
+    "ruby",
+    "ruck",
+    "ruda",
+    "rudd",
+    "rude",
+    "rudy",
+    "ruer",
+    "ruff",
+    "ruin",
+    "rukh",
+    "rule",
+    "ruly",
+    "rume",
+    "rump",
+    "rund",
+    "rune",
+    "rung",
+    "runt",
+    "rurp",
+    "ruru",
+    "rusa",
+    "ruse",
+    "rusk",
+    "russ",
+    "rust",
+    "ruth",
+    "ryal",
+    "ryan",
+    "ryke",
+    "rynd",
+    "ryot",
+    "sabe",
+    "sack",
+    "sade",
+    "sadi",
+    "safe",
+    "saga",
+    "sage",
+    "sago",
+    "saic",
+    "said",
+    "sail",
+    "saim",
+    "sain",
+    "sair",
+    "sake",
+    "saki",
+    "sale",
+    "salp",
+    "sals",
+    "salt",
+    "sama",
+    "same",
+    "samp",
+    "sams",
+    "sana",
+    "sand",
+    "sane",
+    "sang",
+    "sant",
+    "sara",
+    "sard",
+    "sari",
+    "sark",
+    "saro",
+    "saru",
+    "sate",
+    "sati",
+    "saul",
+    "saus",
+    "saut",
+    "save",
+    "saxe",
+    "saze",
+    "scab",
+    "scad",
+    "scag",
+    "scam",
+    "scan",
+    "scar",
+    "scat",
+    "scaw",
+    "scoe",
+    "scog",
+    "scop",
+    "scot",
+    "scow",
+    "scud",
+    "scug",
+    "scul",
+    "scum",
+    "scup",
+    "scur",
+    "scut",
+    "scye",
+    "seal",
+    "seam",
+    "sean",
+    "sear",
+    "seat",
+    "sect",
+    "sede",
+    "seed",
+    "seek",
+    "seel",
+    "seem",
+    "seep",
+    "seer",
+    "sees",
+    "sego",
+    "seif",
+    "seil",
+    "seir",
+    "seko",
+    "sekt",
+    "sele",
+    "self",
+    "sell",
+    "seme",
+    "semi",
+    "sena",
+    "send",
+    "sent",
+    "sept",
+    "sere",
+    "serf",
+    "serk",
+    "serr",
+    "seth",
+    "sett",
+    "sexe",
+    "sext",
+    "shad",
+    "shae",
+    "shag",
+    "shah",
+    "sham",
+    "shan",
+    "shap",
+    "shas",
+    "shaw",
+    "shay",
+    "shea",
+    "shed",
+    "shet",
+    "shew",
+    "shie",
+    "shim",
+    "shin",
+    "ship",
+    "shir",
+    "shit",
+    "shiv",
+    "shoe",
+    "shog",
+    "shon",
+    "shoo",
+    "shop",
+    "shot",
+    "show",
+    "shri",
+    "shul",
+    "shun",
+    "shus",
+    "shut",
+    "shwa",
+    "sial",
+    "sibb",
+    "sice",
+    "sick",
+    "sida",
+    "side",
+    "sids",
+    "sien",
+    "sift",
+    "sigh",
+    "sign",
+    "sijo",
+    "sika",
+    "sike",
+    "sila",
+    "sild",
+    "sile",
+    "silk",
+    "sill",
+    "silo",
+    "silt",
+    "sima",
+    "simi",
+    "simp",
+    "sina",
+    "sind",
+    "sine",
+    "sing",
+    "sinh",
+    "sink",
+    "sinu",
+    "sipe",
+    "sire",
+    "siri",
+    "sise",
+    "sist",
+    "site",
+    "situ",
+    "sius",
+    "sixe",
+    "size",
+    "sizy",
+    "skag",
+    "skat",
+    "skaw",
+    "skee",
+    "skeg",
+    "skeo",
+    "skep",
+    "sker",
+    "skew",
+    "skid",
+    "skie",
+    "skim",
+    "skin",
+    "skio",
+    "skip",
+    "skit",
+    "skua",
+    "skug",
+    "skye",
+    "skyr",
+    "skys",
+    "slab",
+    "slae",
+    "slag",
+    "slam",
+    "slap",
+    "slat",
+    "slaw",
+    "slay",
+    "sled",
+    "slew",
+    "sley",
+    "slim",
+    "slip",
+    "slit",
+    "slob",
+    "sloe",
+    "slog",
+    "slop",
+    "slot",
+    "slow",
+    "slub",
+    "slue",
+    "slug",
+    "slum",
+    "slur",
+    "slut",
+    "smee",
+    "smew",
+    "smir",
+    "smit",
+    "smog",
+    "smug",
+    "smur",
+    "smut",
+    "snab",
+    "snag",
+    "snap",
+    "snar",
+    "snaw",
+    "sneb",
+    "sned",
+    "snee",
+    "snib",
+    "snie",
+    "snig",
+    "snip",
+    "snit",
+    "snob",
+    "snod",
+    "snog",
+    "snot",
+    "snow",
+    "snub",
+    "snug",
+    "snye",
+    "soak",
+    "soap",
+    "soar",
+    "soca",
+    "sock",
+    "soda",
+    "sofa",
+    "soft",
+    "soil",
+    "soja",
+    "soke",
+    "sola",
+    "sold",
+    "sole",
+    "solo",
+    "sols",
+    "solu",
+    "soma",
+    "sona",
+    "sone",
+    "song",
+    "sons",
+    "sook",
+    "soom",
+    "soon",
+    "soop",
+    "soos",
+    "soot",
+    "soph",
+    "sora",
+    "sorb",
+    "sord",
+    "sore",
+    "sorn",
+    "sort",
+    "soru",
+    "soth",
+    "souk",
+    "soul",
+    "soum",
+    "soup",
+    "sour",
+    "sout",
+    "sowf",
+    "sowl",
+    "sowm",
+    "sowp",
+    "sows",
+    "soya",
+    "spae",
+    "spag",
+    "spam",
+    "span",
+    "spar",
+    "spat",
+    "spaw",
+    "spay",
+    "spec",
+    "spek",
+    "speo",
+    "spet",
+    "spew",
+    "spic",
+    "spie",
+    "spik",
+    "spin",
+    "spit",
+    "spiv",
+    "spot",
+    "spry",
+    "spud",
+    "spue",
+    "spur",
+    "stab",
+    "stag",
+    "stan",
+    "stap",
+    "star",
+    "stat",
+    "staw",
+    "stay",
+    "sted",
+    "stem",
+    "sten",
+    "step",
+    "stet",
+    "stew",
+    "stey",
+    "stie",
+    "stir",
+    "stoa",
+    "stob",
+    "stop",
+    "stot",
+    "stow",
+    "stub",
+    "stud",
+    "stum",
+    "stun",
+    "stye",
+    "suba",
+    "such",
+    "suck",
+    "sudd",
+    "suer",
+    "sues",
+    "suet",
+    "sugh",
+    "suid",
+    "suit",
+    "sukh",
+    "suks",
+    "sulk",
+    "sulu",
+    "sumo",
+    "sump",
+    "sung",
+    "suni",
+    "sunk",
+    "sunn",
+    "suns",
+    "supe",
+    "sura",
+    "surd",
+    "sure",
+    "surf",
+    "suse",
+    "susu",
+    "susy",
+    "suzi",
+    "suzy",
+    "swab",
+    "swad",
+    "swag",
+    "swan",
+    "swap",
+    "swat",
+    "sway",
+    "swee",
+    "swey",
+    "swie",
+    "swig",
+    "swim",
+    "swit",
+    "swob",
+    "swop",
+    "swot",
+    "syce",
+    "syen",
+    "syke",
+    "syli",
+    "sync",
+    "synd",
+    "syne",
+    "sype",
+    "syph",
+    "tabe",
+    "tabi",
+    "tabu",
+    "tace",
+    "tach",
+    "tack",
+    "taco",
+    "tact",
+    "tads",
+    "tael",
+    "taha",
+    "tahr",
+    "tail",
+    "tain",
+    "tais",
+    "tait",
+    "taje",
+    "taka",
+    "take",
+    "taki",
+    "tala",
+    "talc",
+    "tale",
+    "talk",
+    "tall",
+    "talu",
+    "tama",
+    "tame",
+    "tami",
+    "tamp",
+    "tams",
+    "tana",
+    "tang",
+    "tanh",
+    "tank",
+    "tapa",
+    "tape",
+    "tapi",
+    "tapu",
+    "tara",
+    "tare",
+    "tari",
+    "tarn",
+    "taro",
+    "tarp",
+    "tart",
+    "task",
+    "tate",
+    "tath",
+    "tatt",
+    "tatu",
+    "taut",
+    "tava",
+    "tawa",
+    "tawt",
+    "taxe",
+    "taxi",
+    "taxu",
+    "tead",
+    "teak",
+    "teal",
+    "team",
+    "tear",
+    "teat",
+    "tech",
+    "teds",
+    "teel",
+    "teem",
+    "teen",
+    "teer",
+    "teff",
+    "tegg",
+    "tegu",
+    "tehr",
+    "teil",
+    "tele",
+    "tell",
+    "telo",
+    "teme",
+    "temp",
+    "tena",
+    "tend",
+    "tene",
+    "tent",
+    "tepa",
+    "tera",
+    "tere",
+    "terf",
+    "teri",
+    "term",
+    "tern",
+    "terr",
+    "tert",
+    "tess",
+    "test",
+    "tete",
+    "teth",
+    "texa",
+    "text",
+    "thad",
+    "than",
+    "thao",
+    "thar",
+    "that",
+    "thaw",
+    "thea",
+    "thee",
+    "then",
+    "theo",
+    "thew",
+    "they",
+    "thig",
+    "thin",
+    "thio",
+    "this",
+    "thou",
+    "thud",
+    "thug",
+    "thus",
+    "thuy",
+    "tiar",
+    "tias",
+    "tice",
+    "tick",
+    "tide",
+    "tidy",
+    "tied",
+    "tien",
+    "tier",
+    "tiff",
+    "tift",
+    "tige",
+    "tika",
+    "tike",
+    "tiki",
+    "tile",
+    "till",
+    "tilt",
+    "time",
+    "timp",
+    "tims",
+    "tina",
+    "tind",
+    "tine",
+    "ting",
+    "tink",
+    "tint",
+    "tiny",
+    "tipi",
+    "tire",
+    "tirl",
+    "tiro",
+    "tirr",
+    "tisa",
+    "tish",
+    "titi",
+    "toad",
+    "tobi",
+    "toby",
+    "tock",
+    "toco",
+    "todd",
+    "tods",
+    "tody",
+    "toea",
+    "toed",
+    "toey",
+    "toff",
+    "toft",
+    "tofu",
+    "toga",
+    "toge",
+    "toho",
+    "toil",
+    "tois",
+    "toit",
+    "toke",
+    "toko",
+    "tola",
+    "tole",
+    "toll",
+    "tolt",
+    "tolu",
+    "tomb",
+    "tome",
+    "tomi",
+    "toms",
+    "tona",
+    "tone",
+    "tong",
+    "toni",
+    "tonk",
+    "tonu",
+    "tony",
+    "tool",
+    "toom",
+    "toon",
+    "toot",
+    "tope",
+    "toph",
+    "topi",
+    "topo",
+    "tops",
+    "tora",
+    "torc",
+    "tore",
+    "tori",
+    "toro",
+    "torr",
+    "tort",
+    "toru",
+    "tory",
+    "tosa",
+    "tose",
+    "toss",
+    "tote",
+    "touk",
+    "toun",
+    "tour",
+    "tout",
+    "tova",
+    "town",
+    "towt",
+    "towy",
+    "toya",
+    "toyo",
+    "toze",
+    "trad",
+    "tram",
+    "tran",
+    "trap",
+    "trat",
+    "tray",
+    "tree",
+    "tref",
+    "trek",
+    "tres",
+    "tret",
+    "trew",
+    "trey",
+    "trie",
+    "trig",
+    "trim",
+    "trin",
+    "trio",
+    "trip",
+    "trod",
+    "trog",
+    "troi",
+    "tron",
+    "trot",
+    "trow",
+    "troy",
+    "true",
+    "trug",
+    "tryp",
+    "tsar",
+    "tuan",
+    "tuba",
+    "tube",
+    "tuck",
+    "tufa",
+    "tuff",
+    "tuft",
+    "tula",
+    "tule",
+    "tump",
+    "tuna",
+    "tund",
+    "tune",
+    "tung",
+    "turd",
+    "turf",
+    "turk",
+    "turm",
+    "turn",
+    "turp",
+    "tusk",
+    "tutu",
+    "tuxe",
+    "twae",
+    "twal",
+    "twat",
+    "tway",
+    "twee",
+    "twig",
+    "twin",
+    "twit",
+    "tyee",
+    "tyer",
+    "tyke",
+    "tymp",
+    "tyne",
+    "type",
+    "typo",
+    "typp",
+    "tyra",
+    "tyre",
+    "tyro",
+    "tzar",
+    "udal",
+    "ugli",
+    "ugly",
+    "ulan",
+    "ulas",
+    "ulna",
+    "ulva",
+    "umbo",
+    "umma",
+    "umph",
+    "unai",
+    "unas",
+    "unau",
+    "unce",
+    "unco",
+    "uncu",
+    "unda",
+    "undo",
+    "unit",
+    "updo",
+    "upon",
+    "urao",
+    "urdy",
+    "urea",
+    "urge",
+    "uric",
+    "urva",
+    "used",
+    "user",
+    "usha",
+    "utes",
+    "uvea",
+    "vada",
+    "vade",
+    "vagu",
+    "vail",
+    "vair",
+    "vale",
+    "vali",
+    "vals",
+    "vamp",
+    "vane",
+    "vang",
+    "vans",
+    "vant",
+    "vara",
+    "vare",
+    "varu",
+    "vase",
+    "vast",
+    "vatu",
+    "vaut",
+    "veal",
+    "veda",
+    "veep",
+    "veer",
+    "vega",
+    "vege",
+    "veil",
+    "vein",
+    "veld",
+    "vele",
+    "vell",
+    "vena",
+    "vend",
+    "vent",
+    "venu",
+    "vera",
+    "verb",
+    "vern",
+    "vert",
+    "very",
+    "vest",
+    "veta",
+    "vexe",
+    "vial",
+    "vibe",
+    "vice",
+    "vida",
+    "vier",
+    "view",
+    "viga",
+    "viki",
+    "vile",
+    "vill",
+    "vina",
+    "vine",
+    "vino",
+    "vint",
+    "viol",
+    "vire",
+    "virl",
+    "viru",
+    "visa",
+    "vise",
+    "vita",
+    "vito",
+    "viva",
+    "vive",
+    "vlei",
+    "vlie",
+    "voar",
+    "voce",
+    "void",
+    "vole",
+    "volk",
+    "volt",
+    "vons",
+    "voop",
+    "vote",
+    "vril",
+    "vrow",
+    "vugg",
+    "vugh",
+    "vuln",
+    "wack",
+    "wada",
+    "wadd",
+    "wade",
+    "wadi",
+    "wadt",
+    "waff",
+    "waft",
+    "wage",
+    "waif",
+    "wail",
+    "wain",
+    "wair",
+    "wais",
+    "wait",
+    "waka",
+    "wake",
+    "wakf",
+    "wald",
+    "wale",
+    "wali",
+    "walk",
+    "wall",
+    "waly",
+    "wame",
+    "wamu",
+    "wand",
+    "wane",
+    "wang",
+    "wank",
+    "wans",
+    "want",
+    "waqf",
+    "ward",
+    "ware",
+    "wark",
+    "warm",
+    "warn",
+    "warp",
+    "wart",
+    "wary",
+    "wase",
+    "wash",
+    "wasm",
+    "wasp",
+    "wast",
+    "watt",
+    "wauk",
+    "waul",
+    "waur",
+    "wava",
+    "wave",
+    "wavy",
+    "wawa",
+    "wawe",
+    "wawl",
+    "waxe",
+    "waxy",
+    "weak",
+    "weal",
+    "wean",
+    "wear",
+    "weck",
+    "weed",
+    "week",
+    "weel",
+    "weem",
+    "ween",
+    "weep",
+    "weer",
+    "weet",
+    "weft",
+    "weid",
+    "weil",
+    "weir",
+    "weis",
+    "weka",
+    "weld",
+    "welk",
+    "well",
+    "welt",
+    "wemb",
+    "wend",
+    "wens",
+    "went",
+    "west",
+    "weta",
+    "wexe",
+    "wham",
+    "whap",
+    "what",
+    "when",
+    "whet",
+    "whew",
+    "whey",
+    "whid",
+    "whig",
+    "whim",
+    "whin",
+    "whip",
+    "whir",
+    "whit",
+    "whop",
+    "whup",
+    "wick",
+    "wide",
+    "wiel",
+    "wife",
+    "wild",
+    "wile",
+    "wili",
+    "will",
+    "wilt",
+    "wily",
+    "wimp",
+    "wind",
+    "wine",
+    "wing",
+    "wink",
+    "winn",
+    "wino",
+    "winy",
+    "wipe",
+    "wire",
+    "wiry",
+    "wise",
+    "wish",
+    "wisp",
+    "wist",
+    "wite",
+    "with",
+    "wive",
+    "wize",
+    "woad",
+    "wock",
+    "wold",
+    "wolf",
+    "womb",
+    "wonk",
+    "wont",
+    "wood",
+    "woof",
+    "wool",
+    "woon",
+    "woop",
+    "word",
+    "work",
+    "worm",
+    "wort",
+    "wrap",
+    "wren",
+    "wrie",
+    "writ",
+    "wull",
+    "wuse",
+    "wyle",
+    "wynd",
+    "wynn",
+    "wyte",
+    "xavi",
+    "xeru",
+    "xiao",
+    "xuan",
+    "xyst",
+    "yack",
+    "yael",
+    "yaff",
+    "yagi",
+    "yair",
+    "yaks",
+    "yale",
+    "yang",
+    "yank",
+    "yans",
+    "yapp",
+    "yard",
+    "yarn",
+    "yarr",
+    "yate",
+    "yaud",
+    "yaup",
+    "yawl",
+    "yawn",
+    "yawp",
+    "yead",
+    "yeah",
+    "yean",
+    "year",
+    "yech",
+    "yede",
+    "yeed",
+    "yees",
+    "yegg",
+    "yeld",
+    "yelk",
+    "yell",
+    "yelm",
+    "yelp",
+    "yelt",
+    "yens",
+    "yerd",
+    "yerk",
+    "yers",
+    "yese",
+    "yesk",
+    "yest",
+    "yeti",
+    "yett",
+    "yeuk",
+    "yeve",
+    "yexe",
+    "yike",
+    "yill",
+    "ying",
+    "yipe",
+    "yird",
+    "yirk",
+    "yirr",
+    "yite",
+    "ylem",
+    "ylke",
+    "ympe",
+    "yock",
+    "yodh",
+    "yoga",
+    "yogh",
+    "yogi",
+    "yoke",
+    "yoko",
+    "yolk",
+    "yomp",
+    "yong",
+    "yoni",
+    "yonk",
+    "yons",
+    "yoof",
+    "yoop",
+    "yore",
+    "york",
+    "youk",
+    "your",
+    "yowe",
+    "yowl",
+    "yuan",
+    "yuca",
+    "yuck",
+    "yuft",
+    "yuga",
+    "yuke",
+    "yuki",
+    "yuko",
+    "yuks",
+    "yule",
+    "yump",
+    "yung",
+    "yuns",
+    "yuri",
+    "yurt",
+    "zack",
+    "zada",
+    "zaid",
+    "zain",
+    "zana",
+    "zane",
+    "zany",
+    "zara",
+    "zarf",
+    "zari",
+    "zati",
+    "zaxe",
+    "zayn",
+    "zeal",
+    "zebu",
+    "zein",
+    "zeke",
+    "zena",
+    "zerk",
+    "zero",
+    "zest",
+    "zeta",
+    "zexe",
+    "zeze",
+    "ziff",
+    "zila",
+    "zill",
+    "zimb",
+    "zina",
+    "zinc",
+    "zine",
+    "zing",
+    "zion",
+    "zita",
+    "ziti",
+    "zobo",
+    "zobu",
+    "zoea",
+    "zoes",
+    "zoey",
+    "zoie",
+    "zola",
+    "zona",
+    "zone",
+    "zonk",
+    "zook",
+    "zoom",
+    "zoon",
+    "zora",
+    "zori",
+    "zouk",
+    "zula",
+    "zulu",
+    "zupa",
+    "zurf",
+    "zuri",
+    "zyme"
+};
+
+
+static SIX_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "aadens",
+    "aaravs",
+    "aarons",
+    "abacks",
+    "abases",
+    "abashs",
+    "abasic",
+    "abbeys",
+    "abbies",
+    "abbots",
+    "abdiel",
+    "abduls",
+    "abends",
+    "abhors",
+    "abides",
+    "abient",
+    "abject",
+    "ableds",
+    "ablest",
+    "ablush",
+    "abodes",
+    "aboral",
+    "aborts",
+    "abouts",
+    "aboves",
+    "abrams",
+    "abrils",
+    "abrupt",
+    "absent",
+    "absorb",
+    "absurd",
+    "abulic",
+    "abuzzs",
+    "access",
+    "accuse",
+    "acetic",
+    "acheds",
+    "achoos",
+    "acidic",
+    "acidly",
+    "acings",
+    "acinic",
+    "acorns",
+    "acrids",
+    "across",
+    "action",
+    "active",
+    "actons",
+    "actors",
+    "actual",
+    "acuate",
+    "acutes",
+    "adages",
+    "adalyn",
+    "adapts",
+    "adders",
+    "addict",
+    "addies",
+    "addles",
+    "adelas",
+    "adeles",
+    "adelia",
+    "adella",
+    "adelle",
+    "adells",
+    "adelyn",
+    "adenas",
+    "adepts",
+    "adient",
+    "adinas",
+    "aditya",
+    "adjust",
+    "admins",
+    "admits",
+    "admixs",
+    "adnate",
+    "adobes",
+    "adolfo",
+    "adolph",
+    "adonis",
+    "adopts",
+    "adored",
+    "adores",
+    "adorns",
+    "adrian",
+    "adrias",
+    "adriel",
+    "adrien",
+    "adroit",
+    "adults",
+    "advice",
+    "aecial",
+    "aerily",
+    "afeard",
+    "affair",
+    "affine",
+    "affixs",
+    "afford",
+    "afocal",
+    "afoots",
+    "afouls",
+    "afraid",
+    "afters",
+    "aftons",
+    "agains",
+    "agamas",
+    "agamic",
+    "agasts",
+    "agatha",
+    "agaves",
+    "agedly",
+    "agents",
+    "aghast",
+    "agiles",
+    "agings",
+    "agisms",
+    "agleam",
+    "aglint",
+    "aglows",
+    "agonal",
+    "agonic",
+    "agouti",
+    "agreed",
+    "agrees",
+    "agueda",
+    "aguish",
+    "aheads",
+    "aheaps",
+    "ahmads",
+    "ahmeds",
+    "aholds",
+    "aidans",
+    "aidens",
+    "aidful",
+    "aidyns",
+    "aileen",
+    "ailene",
+    "ailing",
+    "aimeds",
+    "aimees",
+    "aimful",
+    "aiolis",
+    "aireds",
+    "airier",
+    "airily",
+    "aishas",
+    "aisled",
+    "aisles",
+    "aiyana",
+    "akikos",
+    "akilah",
+    "akitas",
+    "alaina",
+    "alaine",
+    "alanas",
+    "alanes",
+    "alanis",
+    "alanna",
+    "alarms",
+    "alayah",
+    "alayas",
+    "alayna",
+    "albert",
+    "albina",
+    "albums",
+    "alclad",
+    "aldens",
+    "aleahs",
+    "alease",
+    "alecia",
+    "aleena",
+    "aleens",
+    "aleida",
+    "alenas",
+    "alenes",
+    "alerts",
+    "alesha",
+    "alesia",
+    "aletas",
+    "aletha",
+    "alexas",
+    "alexia",
+    "alexis",
+    "alfred",
+    "algaes",
+    "algoid",
+    "aliana",
+    "alibis",
+    "alible",
+    "alicas",
+    "alices",
+    "alicia",
+    "alidas",
+    "aliens",
+    "aligns",
+    "alijah",
+    "alikes",
+    "alinas",
+    "alines",
+    "alisas",
+    "alises",
+    "alisha",
+    "alisia",
+    "alison",
+    "alissa",
+    "alitas",
+    "alives",
+    "alivia",
+    "aliyah",
+    "aliyas",
+    "alizas",
+    "allans",
+    "alleen",
+    "allena",
+    "allene",
+    "allens",
+    "allied",
+    "allies",
+    "alline",
+    "allots",
+    "allows",
+    "allyns",
+    "almeda",
+    "almeta",
+    "almost",
+    "alofts",
+    "alonas",
+    "alones",
+    "alongs",
+    "alonso",
+    "alonzo",
+    "aloofs",
+    "alouds",
+    "alpaca",
+    "alphas",
+    "alpine",
+    "altars",
+    "altern",
+    "alters",
+    "althas",
+    "althea",
+    "altons",
+    "alular",
+    "alvaro",
+    "alvera",
+    "alvina",
+    "alvine",
+    "alvins",
+    "always",
+    "alwite",
+    "alyces",
+    "alycia",
+    "alysas",
+    "alyses",
+    "alysha",
+    "alysia",
+    "alyson",
+    "alyssa",
+    "alyvia",
+    "amadas",
+    "amados",
+    "amalia",
+    "amanda",
+    "amanis",
+    "amaras",
+    "amares",
+    "amaris",
+    "amayas",
+    "amazed",
+    "ambers",
+    "ambery",
+    "ambles",
+    "amebic",
+    "ameers",
+    "amelia",
+    "amelie",
+    "amends",
+    "amidic",
+    "amiees",
+    "aminas",
+    "aminic",
+    "amirah",
+    "amiras",
+    "amiyah",
+    "amiyas",
+    "ammies",
+    "ammono",
+    "amoeba",
+    "amongs",
+    "amoral",
+    "amount",
+    "amparo",
+    "ampeds",
+    "ampler",
+    "amples",
+    "amused",
+    "amuses",
+    "amylic",
+    "anabel",
+    "anahis",
+    "anayas",
+    "anchor",
+    "andera",
+    "anders",
+    "andras",
+    "andrea",
+    "andree",
+    "andres",
+    "andrew",
+    "andria",
+    "anemic",
+    "anette",
+    "angela",
+    "angele",
+    "angelo",
+    "angels",
+    "angers",
+    "angies",
+    "angila",
+    "anglas",
+    "anglea",
+    "angler",
+    "angles",
+    "anglos",
+    "angora",
+    "angsts",
+    "anibal",
+    "anikas",
+    "animal",
+    "animas",
+    "animes",
+    "anions",
+    "anisas",
+    "anises",
+    "anisha",
+    "anisic",
+    "anissa",
+    "anitas",
+    "anitra",
+    "aniyah",
+    "aniyas",
+    "ankles",
+    "annals",
+    "annett",
+    "annexs",
+    "annice",
+    "annies",
+    "annika",
+    "annita",
+    "annual",
+    "annuls",
+    "anodes",
+    "anodic",
+    "anomic",
+    "anoxic",
+    "ansate",
+    "ansley",
+    "ansons",
+    "answer",
+    "antone",
+    "antons",
+    "antony",
+    "antral",
+    "antwan",
+    "anuric",
+    "anvils",
+    "anyway",
+    "aortal",
+    "aortas",
+    "aortic",
+    "apaces",
+    "aparts",
+    "aperts",
+    "aphids",
+    "apians",
+    "apical",
+    "apings",
+    "apneal",
+    "apneic",
+    "apodal",
+    "apollo",
+    "appear",
+    "appels",
+    "apples",
+    "aprils",
+    "aprons",
+    "aprowl",
+    "apryls",
+    "arbors",
+    "arcane",
+    "arceds",
+    "arched",
+    "archer",
+    "archie",
+    "archly",
+    "arctic",
+    "ardath",
+    "ardeid",
+    "ardell",
+    "ardens",
+    "ardent",
+    "ardith",
+    "ardors",
+    "arears",
+    "arelys",
+    "arenas",
+    "aretha",
+    "argons",
+    "argues",
+    "ariahs",
+    "ariana",
+    "ariane",
+    "arians",
+    "aricas",
+    "aridly",
+    "ariels",
+    "ariose",
+    "ariyah",
+    "ariyas",
+    "arjuns",
+    "arlean",
+    "arleen",
+    "arlena",
+    "arlene",
+    "arlens",
+    "arlies",
+    "arline",
+    "arlyne",
+    "armand",
+    "armani",
+    "armeds",
+    "armida",
+    "armors",
+    "arnavs",
+    "arnita",
+    "arnold",
+    "aroids",
+    "aromas",
+    "aroses",
+    "around",
+    "arrant",
+    "arrest",
+    "arrive",
+    "arrons",
+    "arrows",
+    "arrowy",
+    "arseds",
+    "arseno",
+    "arsino",
+    "arsons",
+    "artful",
+    "arthur",
+    "artier",
+    "arties",
+    "artily",
+    "artist",
+    "arturo",
+    "aryana",
+    "aryans",
+    "asemic",
+    "ashake",
+    "ashely",
+    "ashens",
+    "ashers",
+    "ashier",
+    "ashlea",
+    "ashlee",
+    "ashley",
+    "ashlie",
+    "ashlis",
+    "ashlyn",
+    "ashlys",
+    "ashton",
+    "asides",
+    "askeds",
+    "askews",
+    "asleys",
+    "aspect",
+    "aspens",
+    "aspish",
+    "assets",
+    "assist",
+    "assume",
+    "asthma",
+    "astral",
+    "astrid",
+    "astute",
+    "aswarm",
+    "atavic",
+    "ataxic",
+    "atelic",
+    "athena",
+    "atilts",
+    "atomic",
+    "atonal",
+    "atones",
+    "atrial",
+    "attack",
+    "attend",
+    "attent",
+    "attics",
+    "aubree",
+    "aubrey",
+    "aubrie",
+    "aubris",
+    "audies",
+    "audios",
+    "audits",
+    "audras",
+    "audrea",
+    "audrey",
+    "audria",
+    "audrie",
+    "audrys",
+    "augers",
+    "aughts",
+    "augurs",
+    "august",
+    "auntly",
+    "aurals",
+    "aureas",
+    "aurora",
+    "aurore",
+    "aurous",
+    "austin",
+    "author",
+    "autumn",
+    "avails",
+    "avalyn",
+    "avants",
+    "avasts",
+    "averie",
+    "averis",
+    "averse",
+    "averts",
+    "averys",
+    "aviana",
+    "avians",
+    "avidly",
+    "avocet",
+    "avoids",
+    "avowed",
+    "avrils",
+    "awaits",
+    "awakes",
+    "awards",
+    "awares",
+    "awashs",
+    "awedly",
+    "aweigh",
+    "awfuls",
+    "awhile",
+    "awhirl",
+    "awilda",
+    "awless",
+    "awokes",
+    "axenic",
+    "axials",
+    "axioms",
+    "axions",
+    "axised",
+    "axlike",
+    "axmans",
+    "axonal",
+    "axtons",
+    "ayaans",
+    "ayakos",
+    "ayanas",
+    "ayanna",
+    "aydans",
+    "aydens",
+    "aydins",
+    "ayesha",
+    "ayleen",
+    "aylins",
+    "azalea",
+    "azalee",
+    "azaria",
+    "azonal",
+    "azonic",
+    "azoted",
+    "azotic",
+    "azures",
+    "azzies",
+    "babara",
+    "babels",
+    "baboon",
+    "backed",
+    "bacons",
+    "badger",
+    "badges",
+    "bagels",
+    "bailee",
+    "bailey",
+    "baizes",
+    "bakeds",
+    "bakers",
+    "baldly",
+    "baleds",
+    "balers",
+    "bambis",
+    "bamboo",
+    "banals",
+    "banana",
+    "banded",
+    "bangup",
+    "banjos",
+    "banner",
+    "barbar",
+    "barbed",
+    "barbel",
+    "barbet",
+    "barbie",
+    "barbra",
+    "bardic",
+    "bareds",
+    "barely",
+    "barers",
+    "barest",
+    "barfly",
+    "barges",
+    "barney",
+    "barons",
+    "barrel",
+    "barrie",
+    "barrys",
+    "barton",
+    "basals",
+    "baseds",
+    "basely",
+    "basers",
+    "basest",
+    "basics",
+    "basils",
+    "basins",
+    "basket",
+    "basset",
+    "bastes",
+    "batchs",
+    "bateds",
+    "bathes",
+    "batons",
+    "battle",
+    "baylee",
+    "bayous",
+    "bazars",
+    "bazoos",
+    "beachs",
+    "beachy",
+    "beaded",
+    "beagle",
+    "beaked",
+    "beards",
+    "beasts",
+    "beatas",
+    "beaten",
+    "beauty",
+    "bebops",
+    "beckie",
+    "beckis",
+    "beckys",
+    "become",
+    "bedbug",
+    "bedrid",
+    "beechy",
+    "beetle",
+    "befits",
+    "befogs",
+    "before",
+    "begans",
+    "begats",
+    "begets",
+    "begins",
+    "begots",
+    "beguns",
+    "behave",
+    "behind",
+    "beiges",
+    "beings",
+    "belchs",
+    "belens",
+    "belias",
+    "belkis",
+    "bellas",
+    "belles",
+    "beloid",
+    "belows",
+    "belted",
+    "belvas",
+    "benchs",
+    "bengal",
+    "benign",
+    "benita",
+    "benito",
+    "bennie",
+    "bennys",
+    "benson",
+    "bently",
+    "benton",
+    "benzal",
+    "bereft",
+    "bernas",
+    "bernie",
+    "berrys",
+    "bertas",
+    "bertha",
+    "bertie",
+    "beryls",
+    "besets",
+    "bessie",
+    "bethel",
+    "betray",
+    "betsey",
+    "betsys",
+    "better",
+    "bettes",
+    "bettie",
+    "bettye",
+    "bettys",
+    "beulah",
+    "beulas",
+    "bevels",
+    "beyond",
+    "bezels",
+    "bianca",
+    "biased",
+    "bibles",
+    "biceps",
+    "biflex",
+    "biform",
+    "bigeye",
+    "bigger",
+    "bigots",
+    "bikeds",
+    "bikers",
+    "bilges",
+    "billie",
+    "billis",
+    "billye",
+    "billys",
+    "bimbos",
+    "binate",
+    "binges",
+    "bingos",
+    "biomes",
+    "bionic",
+    "biotic",
+    "bipeds",
+    "bipods",
+    "birchs",
+    "birdie",
+    "birgit",
+    "births",
+    "bisons",
+    "bitchs",
+    "biters",
+    "biting",
+    "bitter",
+    "blacks",
+    "blades",
+    "blaine",
+    "blairs",
+    "blaise",
+    "blakes",
+    "blames",
+    "blanca",
+    "blanch",
+    "blanks",
+    "blares",
+    "blasty",
+    "blazes",
+    "bleaks",
+    "bleary",
+    "bleats",
+    "blebby",
+    "bleeds",
+    "bleeps",
+    "blends",
+    "blenny",
+    "blimps",
+    "blinds",
+    "blithe",
+    "blitzs",
+    "bloats",
+    "blocks",
+    "blocky",
+    "blonds",
+    "bloods",
+    "blooms",
+    "bloomy",
+    "blotto",
+    "blotty",
+    "blouse",
+    "blousy",
+    "blowns",
+    "blowsy",
+    "blowzy",
+    "bluely",
+    "bluffs",
+    "bluish",
+    "blunks",
+    "blunts",
+    "blurbs",
+    "blurry",
+    "blurts",
+    "blushs",
+    "blythe",
+    "boards",
+    "boasts",
+    "bobbie",
+    "bobbis",
+    "bobbye",
+    "bobbys",
+    "bobcat",
+    "bodger",
+    "bodhis",
+    "bodily",
+    "bogies",
+    "boiled",
+    "boings",
+    "boinks",
+    "boldly",
+    "bonded",
+    "boneds",
+    "boners",
+    "bongos",
+    "bonier",
+    "bonita",
+    "bonito",
+    "bonnie",
+    "bonnys",
+    "bonobo",
+    "bonzer",
+    "booeds",
+    "booker",
+    "boosts",
+    "booted",
+    "booths",
+    "boozes",
+    "boraxs",
+    "border",
+    "boreal",
+    "boreds",
+    "borers",
+    "boring",
+    "borrow",
+    "bosker",
+    "bosoms",
+    "bosomy",
+    "bosons",
+    "boston",
+    "botchy",
+    "botfly",
+    "bottom",
+    "boughs",
+    "bounce",
+    "bouncy",
+    "bowels",
+    "bowens",
+    "bowers",
+    "bowfin",
+    "boxeds",
+    "boxers",
+    "boxily",
+    "boyces",
+    "boyish",
+    "braden",
+    "bradly",
+    "bradys",
+    "braids",
+    "brails",
+    "brains",
+    "brainy",
+    "brakes",
+    "branda",
+    "brande",
+    "brandi",
+    "brandy",
+    "branky",
+    "branny",
+    "brants",
+    "brashs",
+    "brashy",
+    "bratty",
+    "braver",
+    "braves",
+    "bravos",
+    "brawls",
+    "brawly",
+    "brawns",
+    "brawny",
+    "brayan",
+    "breads",
+    "breaks",
+    "breams",
+    "breana",
+    "breann",
+    "breeds",
+    "breeze",
+    "breezy",
+    "brenda",
+    "brenna",
+    "brents",
+    "bretts",
+    "briana",
+    "brians",
+    "briars",
+    "briary",
+    "brices",
+    "bricks",
+    "bricky",
+    "brides",
+    "bridge",
+    "briefs",
+    "briers",
+    "briery",
+    "briggs",
+    "bright",
+    "brigid",
+    "briley",
+    "brills",
+    "brinda",
+    "brines",
+    "brings",
+    "brinks",
+    "brisks",
+    "britni",
+    "britta",
+    "britts",
+    "broads",
+    "brocks",
+    "brodie",
+    "brodys",
+    "broils",
+    "broken",
+    "brokes",
+    "brolly",
+    "bromic",
+    "bronze",
+    "bronzy",
+    "broods",
+    "brooke",
+    "brooks",
+    "brooms",
+    "broomy",
+    "broths",
+    "brothy",
+    "browns",
+    "browny",
+    "bruces",
+    "brumal",
+    "brunas",
+    "brunos",
+    "brushy",
+    "brutal",
+    "bryans",
+    "bryant",
+    "brycen",
+    "bryces",
+    "brylee",
+    "brynns",
+    "bryons",
+    "brysen",
+    "bryson",
+    "bubble",
+    "bubbly",
+    "buboed",
+    "buccal",
+    "bucked",
+    "buckos",
+    "buddys",
+    "budges",
+    "budget",
+    "buenas",
+    "buffys",
+    "buford",
+    "bugles",
+    "builds",
+    "builts",
+    "bulahs",
+    "bulbar",
+    "bulbul",
+    "bulges",
+    "bullet",
+    "bunchs",
+    "bunchy",
+    "bundle",
+    "bunker",
+    "bunnys",
+    "bunted",
+    "burbly",
+    "burbot",
+    "burden",
+    "burger",
+    "burmas",
+    "burned",
+    "burnts",
+    "burros",
+    "bursal",
+    "burton",
+    "bushed",
+    "busied",
+    "busier",
+    "busily",
+    "busked",
+    "buster",
+    "butchs",
+    "butter",
+    "buxoms",
+    "buyers",
+    "bylaws",
+    "byrons",
+    "byssal",
+    "cabals",
+    "cabins",
+    "cables",
+    "cacaos",
+    "caches",
+    "cactis",
+    "cactus",
+    "cadens",
+    "cadent",
+    "cadets",
+    "cadges",
+    "cadmic",
+    "cadres",
+    "caecal",
+    "cageds",
+    "cagier",
+    "cagily",
+    "caiden",
+    "caiman",
+    "cairns",
+    "cairny",
+    "cajuns",
+    "calced",
+    "calcic",
+    "calebs",
+    "callan",
+    "callen",
+    "callie",
+    "callow",
+    "callum",
+    "calmly",
+    "calvin",
+    "calvus",
+    "camden",
+    "camdyn",
+    "camels",
+    "cameos",
+    "camera",
+    "camies",
+    "camila",
+    "camilo",
+    "cammie",
+    "cammys",
+    "camren",
+    "camron",
+    "camryn",
+    "canals",
+    "cancel",
+    "candid",
+    "candie",
+    "candis",
+    "candra",
+    "candys",
+    "caneds",
+    "canned",
+    "cannon",
+    "canoes",
+    "canons",
+    "cantic",
+    "canvas",
+    "canyon",
+    "capeds",
+    "capers",
+    "caputs",
+    "carats",
+    "carbon",
+    "careds",
+    "carens",
+    "carers",
+    "carets",
+    "careys",
+    "caries",
+    "carina",
+    "caring",
+    "carins",
+    "carisa",
+    "carita",
+    "carlas",
+    "carlee",
+    "carley",
+    "carlie",
+    "carlis",
+    "carlos",
+    "carlyn",
+    "carlys",
+    "carman",
+    "carmas",
+    "carmel",
+    "carmen",
+    "carmon",
+    "carnal",
+    "carola",
+    "carole",
+    "caroll",
+    "carols",
+    "carons",
+    "carpet",
+    "carrie",
+    "carris",
+    "carrol",
+    "carrys",
+    "carson",
+    "carter",
+    "caryls",
+    "caryns",
+    "caseds",
+    "casens",
+    "caseys",
+    "cashed",
+    "casies",
+    "casino",
+    "casons",
+    "castes",
+    "castle",
+    "casual",
+    "catchs",
+    "catchy",
+    "caters",
+    "cathey",
+    "cathie",
+    "cathis",
+    "cathys",
+    "catina",
+    "cattle",
+    "caudal",
+    "caught",
+    "caulks",
+    "causal",
+    "causes",
+    "cavate",
+    "caveds",
+    "cayden",
+    "caylas",
+    "caylee",
+    "cayson",
+    "ceases",
+    "cecila",
+    "cecile",
+    "cecils",
+    "cecily",
+    "cedarn",
+    "cedars",
+    "cededs",
+    "cedric",
+    "celena",
+    "celery",
+    "celiac",
+    "celias",
+    "celina",
+    "celine",
+    "cellos",
+    "celsas",
+    "cement",
+    "census",
+    "centum",
+    "ceolas",
+    "cercal",
+    "cereal",
+    "cerous",
+    "cesars",
+    "chaces",
+    "chafes",
+    "chaffs",
+    "chaffy",
+    "chaims",
+    "chains",
+    "chairs",
+    "chalks",
+    "chalky",
+    "chally",
+    "champs",
+    "champy",
+    "chanas",
+    "chance",
+    "chancy",
+    "chanda",
+    "chanel",
+    "change",
+    "changs",
+    "chante",
+    "chants",
+    "charas",
+    "charge",
+    "charis",
+    "charla",
+    "charli",
+    "charms",
+    "charry",
+    "charts",
+    "chases",
+    "chasms",
+    "chasmy",
+    "chaste",
+    "chatty",
+    "chayas",
+    "cheaps",
+    "cheats",
+    "checks",
+    "checky",
+    "cheeks",
+    "cheeky",
+    "cheers",
+    "cheery",
+    "cheese",
+    "cheesy",
+    "chemic",
+    "chequy",
+    "cheree",
+    "cheres",
+    "cherie",
+    "cheris",
+    "cherly",
+    "cherri",
+    "cherry",
+    "cherty",
+    "cheryl",
+    "cherys",
+    "chests",
+    "chesty",
+    "chicks",
+    "chicly",
+    "chiefs",
+    "chieko",
+    "childs",
+    "chilis",
+    "chills",
+    "chilly",
+    "chimes",
+    "chimps",
+    "chinas",
+    "chings",
+    "chinks",
+    "chinos",
+    "chirpy",
+    "chives",
+    "chloes",
+    "chocks",
+    "choice",
+    "choirs",
+    "chokes",
+    "chomps",
+    "chongs",
+    "choose",
+    "choosy",
+    "choppy",
+    "chords",
+    "chores",
+    "choric",
+    "choses",
+    "chubby",
+    "chucks",
+    "chuffy",
+    "chummy",
+    "chumps",
+    "chungs",
+    "chunks",
+    "chunky",
+    "chymic",
+    "ciaras",
+    "cicada",
+    "cicely",
+    "ciders",
+    "cieras",
+    "cierra",
+    "cigars",
+    "cilias",
+    "cindas",
+    "cindie",
+    "cindis",
+    "cindys",
+    "circas",
+    "circle",
+    "ciscos",
+    "cisela",
+    "cisted",
+    "cistic",
+    "citeds",
+    "citied",
+    "citric",
+    "civets",
+    "civics",
+    "claims",
+    "claire",
+    "clairs",
+    "clammy",
+    "clamps",
+    "clangs",
+    "clanks",
+    "claras",
+    "clares",
+    "claris",
+    "clarks",
+    "clashs",
+    "clasps",
+    "classy",
+    "claude",
+    "clauds",
+    "claval",
+    "clayey",
+    "cleans",
+    "clears",
+    "clecha",
+    "clefts",
+    "clelia",
+    "cleora",
+    "clerks",
+    "cletas",
+    "cletus",
+    "clever",
+    "clicks",
+    "client",
+    "cliffs",
+    "cliffy",
+    "climbs",
+    "clinal",
+    "clings",
+    "clingy",
+    "clinic",
+    "clinks",
+    "clints",
+    "cliquy",
+    "cloaks",
+    "clocks",
+    "cloddy",
+    "cloggy",
+    "clones",
+    "clonic",
+    "cloque",
+    "cloras",
+    "closed",
+    "closer",
+    "closes",
+    "cloths",
+    "clotty",
+    "clouds",
+    "cloudy",
+    "clouts",
+    "cloves",
+    "clowns",
+    "clubby",
+    "clucky",
+    "clumps",
+    "clumpy",
+    "clumsy",
+    "clutch",
+    "clydes",
+    "cnemic",
+    "coachs",
+    "coarse",
+    "coasts",
+    "coated",
+    "coatis",
+    "cobias",
+    "cobras",
+    "coccal",
+    "coccic",
+    "cocoas",
+    "codeds",
+    "codexs",
+    "coffee",
+    "cogent",
+    "cohens",
+    "coital",
+    "colbys",
+    "coldly",
+    "coleen",
+    "colene",
+    "colins",
+    "collen",
+    "collie",
+    "collin",
+    "colons",
+    "colors",
+    "colory",
+    "colten",
+    "colton",
+    "column",
+    "comate",
+    "combos",
+    "comely",
+    "comets",
+    "commas",
+    "common",
+    "comose",
+    "comply",
+    "concha",
+    "conchs",
+    "condor",
+    "condos",
+    "congas",
+    "conger",
+    "conics",
+    "conner",
+    "connie",
+    "connor",
+    "conors",
+    "conrad",
+    "coolly",
+    "cooper",
+    "copeds",
+    "copper",
+    "corals",
+    "corban",
+    "corbin",
+    "corded",
+    "cordia",
+    "cordie",
+    "cordis",
+    "coreds",
+    "coreen",
+    "corene",
+    "coreys",
+    "corgis",
+    "cories",
+    "corina",
+    "corine",
+    "corked",
+    "corned",
+    "corrie",
+    "corrin",
+    "cortez",
+    "coseys",
+    "cosier",
+    "cosies",
+    "cosily",
+    "cosmic",
+    "costly",
+    "cotton",
+    "coucha",
+    "couchs",
+    "cougar",
+    "coughs",
+    "coulds",
+    "counts",
+    "couped",
+    "coupes",
+    "couple",
+    "course",
+    "courts",
+    "cousin",
+    "couths",
+    "covens",
+    "covers",
+    "covets",
+    "coweds",
+    "cowers",
+    "cowled",
+    "coyish",
+    "coyote",
+    "cozens",
+    "cozeys",
+    "cozier",
+    "cozies",
+    "cozily",
+    "crabby",
+    "cracks",
+    "cradle",
+    "crafts",
+    "crafty",
+    "craggy",
+    "craigs",
+    "crakes",
+    "cramps",
+    "cranes",
+    "cranks",
+    "cranky",
+    "crashs",
+    "crater",
+    "crates",
+    "craves",
+    "crawls",
+    "crawly",
+    "crazed",
+    "crazes",
+    "creaks",
+    "creaky",
+    "creams",
+    "creamy",
+    "creasy",
+    "credit",
+    "credos",
+    "creeds",
+    "creeks",
+    "creeps",
+    "creepy",
+    "creola",
+    "crepts",
+    "cressy",
+    "crests",
+    "cresyl",
+    "cribla",
+    "cricks",
+    "crieds",
+    "criers",
+    "crimes",
+    "crimps",
+    "crimpy",
+    "crinal",
+    "crined",
+    "crisic",
+    "crisps",
+    "crispy",
+    "crissy",
+    "crista",
+    "cristi",
+    "cristy",
+    "critic",
+    "croaks",
+    "croaky",
+    "crocks",
+    "crofts",
+    "crones",
+    "crooks",
+    "croons",
+    "crosby",
+    "crouch",
+    "croupy",
+    "crouse",
+    "crowds",
+    "crowns",
+    "cruder",
+    "crudes",
+    "cruels",
+    "crufts",
+    "cruise",
+    "crumbs",
+    "crumby",
+    "crumps",
+    "crunch",
+    "crural",
+    "crushs",
+    "crusts",
+    "crusty",
+    "crypts",
+    "crysta",
+    "cubeds",
+    "cubics",
+    "cubits",
+    "cuckoo",
+    "cuddly",
+    "cullen",
+    "cultic",
+    "cumins",
+    "cuneal",
+    "cupped",
+    "cupric",
+    "cureds",
+    "curial",
+    "curlew",
+    "cursed",
+    "curtis",
+    "curtly",
+    "curule",
+    "curves",
+    "cuspal",
+    "cusped",
+    "cussed",
+    "custom",
+    "cutcha",
+    "cutely",
+    "cuters",
+    "cutest",
+    "cyanic",
+    "cycles",
+    "cyclic",
+    "cyetic",
+    "cymoid",
+    "cymose",
+    "cyndis",
+    "cyndys",
+    "cynics",
+    "cyrils",
+    "cystic",
+    "cythia",
+    "cytoid",
+    "dacias",
+    "daedal",
+    "daftly",
+    "dagaga",
+    "dagmar",
+    "dagnys",
+    "dahlia",
+    "daimen",
+    "dainas",
+    "daines",
+    "dainty",
+    "daisey",
+    "daisys",
+    "dakota",
+    "dalene",
+    "dalias",
+    "dalila",
+    "dallas",
+    "dalton",
+    "damage",
+    "damian",
+    "damien",
+    "damion",
+    "damoda",
+    "damons",
+    "damply",
+    "danaes",
+    "dances",
+    "danged",
+    "danger",
+    "dangly",
+    "danial",
+    "danias",
+    "danica",
+    "daniel",
+    "danika",
+    "danilo",
+    "danita",
+    "dankly",
+    "dannas",
+    "dannie",
+    "dannys",
+    "dantes",
+    "danuta",
+    "danyel",
+    "daphne",
+    "dapper",
+    "darbys",
+    "darcel",
+    "darcey",
+    "darcie",
+    "darcis",
+    "darcys",
+    "dareds",
+    "darell",
+    "darens",
+    "darian",
+    "darias",
+    "darien",
+    "daring",
+    "darins",
+    "darios",
+    "darius",
+    "darkly",
+    "darlas",
+    "darons",
+    "darrel",
+    "darren",
+    "darrin",
+    "darron",
+    "darryl",
+    "darter",
+    "darwin",
+    "daryls",
+    "dassie",
+    "dateds",
+    "davian",
+    "davida",
+    "davids",
+    "davina",
+    "davins",
+    "davion",
+    "davons",
+    "dawnas",
+    "dawnes",
+    "dawson",
+    "daxton",
+    "dayana",
+    "dayfly",
+    "dayles",
+    "daynas",
+    "daysis",
+    "dayton",
+    "dazeds",
+    "deacon",
+    "deadly",
+    "deadra",
+    "deafly",
+    "dealts",
+    "deanas",
+    "deanes",
+    "deanna",
+    "deanne",
+    "deanns",
+    "dearly",
+    "debate",
+    "debbie",
+    "debbis",
+    "debbra",
+    "debbys",
+    "debera",
+    "debits",
+    "debora",
+    "debrah",
+    "debras",
+    "debris",
+    "debugs",
+    "decade",
+    "decafs",
+    "decent",
+    "decide",
+    "declan",
+    "decors",
+    "dedras",
+    "deeann",
+    "deedee",
+    "deedra",
+    "deegan",
+    "deenas",
+    "deeper",
+    "deeply",
+    "deetta",
+    "defers",
+    "define",
+    "defogs",
+    "deftly",
+    "degree",
+    "deidra",
+    "deidre",
+    "deific",
+    "deigns",
+    "deists",
+    "delana",
+    "delcie",
+    "delena",
+    "delias",
+    "delila",
+    "delisa",
+    "dellas",
+    "delmar",
+    "delmas",
+    "delmer",
+    "delmys",
+    "delois",
+    "delora",
+    "delpha",
+    "delsie",
+    "deltas",
+    "deluxe",
+    "demand",
+    "demise",
+    "demons",
+    "demure",
+    "demurs",
+    "denaes",
+    "denary",
+    "deneen",
+    "denese",
+    "denial",
+    "denice",
+    "denims",
+    "denise",
+    "denita",
+    "dennas",
+    "dennis",
+    "dennys",
+    "denser",
+    "denses",
+    "densus",
+    "denver",
+    "denyse",
+    "denzel",
+    "deonna",
+    "depart",
+    "depend",
+    "depots",
+    "depths",
+    "deputy",
+    "dereks",
+    "derick",
+    "derive",
+    "dermal",
+    "dermic",
+    "desert",
+    "desexs",
+    "design",
+    "desire",
+    "dessie",
+    "detail",
+    "detect",
+    "deters",
+    "detoxs",
+    "detras",
+    "deuces",
+    "device",
+    "devils",
+    "devins",
+    "devoid",
+    "devona",
+    "devons",
+    "devora",
+    "devote",
+    "devout",
+    "devyns",
+    "deweys",
+    "dewily",
+    "dewitt",
+    "dexter",
+    "dextro",
+    "dholes",
+    "dhooly",
+    "dianas",
+    "dianes",
+    "dianna",
+    "dianne",
+    "dianns",
+    "diarch",
+    "diceds",
+    "diddly",
+    "diedra",
+    "diedre",
+    "diegos",
+    "diesel",
+    "differ",
+    "dignas",
+    "dilans",
+    "dillon",
+    "dimmed",
+    "dimple",
+    "dimply",
+    "dinahs",
+    "dineds",
+    "diners",
+    "dinges",
+    "dingos",
+    "dinkly",
+    "dinkum",
+    "dinner",
+    "diodes",
+    "diones",
+    "dionna",
+    "dionne",
+    "diotic",
+    "diplex",
+    "dipper",
+    "direct",
+    "direly",
+    "direst",
+    "dirges",
+    "discos",
+    "dished",
+    "dismal",
+    "distal",
+    "ditchs",
+    "dittos",
+    "divans",
+    "divers",
+    "divert",
+    "divide",
+    "divina",
+    "divine",
+    "divisi",
+    "divots",
+    "dixies",
+    "djinns",
+    "doable",
+    "docile",
+    "doctor",
+    "dodges",
+    "dodies",
+    "dogged",
+    "dogmas",
+    "doiled",
+    "doings",
+    "doited",
+    "dollie",
+    "dollys",
+    "domain",
+    "donald",
+    "donate",
+    "donita",
+    "donkey",
+    "donnas",
+    "donnie",
+    "donnys",
+    "donors",
+    "donsie",
+    "dontes",
+    "donuts",
+    "donyas",
+    "dopeds",
+    "dopier",
+    "dopily",
+    "dorcas",
+    "doreen",
+    "dorene",
+    "dorian",
+    "dorias",
+    "dories",
+    "dorine",
+    "dorlas",
+    "dormie",
+    "dorris",
+    "dorsad",
+    "dorsal",
+    "dorsey",
+    "dortha",
+    "dorthy",
+    "doseds",
+    "dotier",
+    "doting",
+    "dotted",
+    "dottie",
+    "dottys",
+    "double",
+    "doubly",
+    "doughs",
+    "doughy",
+    "dourly",
+    "douses",
+    "dovies",
+    "dovish",
+    "dowels",
+    "dowers",
+    "dowily",
+    "dowses",
+    "doyles",
+    "dozens",
+    "dozers",
+    "dozier",
+    "dozily",
+    "drably",
+    "draffy",
+    "drafts",
+    "drafty",
+    "draggy",
+    "dragon",
+    "drains",
+    "drakes",
+    "dramas",
+    "dranks",
+    "drapes",
+    "draven",
+    "drawls",
+    "drawly",
+    "drawns",
+    "dreads",
+    "dreama",
+    "dreams",
+    "dreamy",
+    "dreary",
+    "dreggy",
+    "dreich",
+    "dremas",
+    "dressy",
+    "drieds",
+    "driers",
+    "driest",
+    "drifts",
+    "drifty",
+    "drills",
+    "drinks",
+    "drippy",
+    "driven",
+    "drives",
+    "droids",
+    "drolls",
+    "drolly",
+    "drones",
+    "drongo",
+    "drooly",
+    "droops",
+    "droopy",
+    "drossy",
+    "droves",
+    "drowns",
+    "drowsy",
+    "druids",
+    "drumly",
+    "drunks",
+    "dryads",
+    "dryers",
+    "drying",
+    "dually",
+    "duanes",
+    "duckie",
+    "dudish",
+    "dudley",
+    "dugong",
+    "dulces",
+    "dulcet",
+    "dulcie",
+    "dumbly",
+    "duncan",
+    "dunces",
+    "dunlin",
+    "dupeds",
+    "during",
+    "dustin",
+    "dustis",
+    "dustys",
+    "dutchs",
+    "duvets",
+    "dwains",
+    "dwanas",
+    "dwarfs",
+    "dwayne",
+    "dweebs",
+    "dwells",
+    "dwelts",
+    "dwight",
+    "dyable",
+    "dyings",
+    "dykeds",
+    "dylans",
+    "eagers",
+    "eagles",
+    "earles",
+    "earlie",
+    "eartha",
+    "earths",
+    "earthy",
+    "earwig",
+    "easeds",
+    "easels",
+    "easier",
+    "easily",
+    "easter",
+    "easton",
+    "eatens",
+    "ebbeds",
+    "ebonie",
+    "ebonis",
+    "ebonys",
+    "ecesic",
+    "echoic",
+    "eddies",
+    "edgars",
+    "edgeds",
+    "edgier",
+    "edgily",
+    "edicts",
+    "edison",
+    "ediths",
+    "edmond",
+    "edmund",
+    "edward",
+    "edwina",
+    "edwins",
+    "edythe",
+    "edyths",
+    "eelier",
+    "eerier",
+    "eeries",
+    "eerily",
+    "effete",
+    "effies",
+    "effort",
+    "efrain",
+    "efrens",
+    "egrets",
+    "ehtels",
+    "eiders",
+    "eights",
+    "eileen",
+    "eilene",
+    "either",
+    "ejects",
+    "eladia",
+    "elaina",
+    "elaine",
+    "elanas",
+    "elands",
+    "elanes",
+    "elanor",
+    "elayne",
+    "elbert",
+    "elbows",
+    "eldens",
+    "elders",
+    "eldons",
+    "eldora",
+    "elease",
+    "elects",
+    "elenas",
+    "elenes",
+    "elenis",
+    "elenor",
+    "elfins",
+    "eliana",
+    "elians",
+    "elicia",
+    "elidas",
+    "elidia",
+    "elijah",
+    "elinas",
+    "elinor",
+    "elisas",
+    "eliseo",
+    "elises",
+    "elisha",
+    "elissa",
+    "elites",
+    "elizas",
+    "ellans",
+    "ellena",
+    "ellens",
+    "ellies",
+    "elliot",
+    "ellyns",
+    "elmers",
+    "elmier",
+    "elmira",
+    "elnora",
+    "elodia",
+    "eloisa",
+    "eloise",
+    "elroys",
+    "elsies",
+    "eltons",
+    "elvera",
+    "elvers",
+    "elvias",
+    "elvies",
+    "elvina",
+    "elvins",
+    "elvira",
+    "elvish",
+    "elwood",
+    "elyses",
+    "emails",
+    "embark",
+    "embeds",
+    "embers",
+    "embody",
+    "emboly",
+    "emelda",
+    "emelia",
+    "emelys",
+    "emerge",
+    "emerie",
+    "emerys",
+    "emikos",
+    "emilee",
+    "emiles",
+    "emilia",
+    "emilie",
+    "emilio",
+    "emilys",
+    "emmett",
+    "emmies",
+    "emmitt",
+    "emorys",
+    "emotes",
+    "employ",
+    "enable",
+    "enacts",
+    "endeds",
+    "eneida",
+    "energy",
+    "engaga",
+    "engage",
+    "engine",
+    "enlist",
+    "ennuis",
+    "enochs",
+    "enolas",
+    "enolic",
+    "enough",
+    "enrapt",
+    "enrich",
+    "enrico",
+    "enroll",
+    "ensues",
+    "ensure",
+    "entire",
+    "eonian",
+    "epochs",
+    "equals",
+    "equips",
+    "erases",
+    "erasmo",
+    "erects",
+    "ericas",
+    "erichs",
+    "ericka",
+    "ericks",
+    "erikas",
+    "erinns",
+    "erlene",
+    "erline",
+    "ermine",
+    "ernest",
+    "ernies",
+    "erodes",
+    "errant",
+    "erring",
+    "errols",
+    "errors",
+    "erupts",
+    "ervins",
+    "erwins",
+    "escape",
+    "essies",
+    "estate",
+    "estela",
+    "estell",
+    "esters",
+    "esther",
+    "eterne",
+    "ethans",
+    "ethels",
+    "ethics",
+    "ethnic",
+    "ethyls",
+    "etsuko",
+    "etties",
+    "euclid",
+    "eugena",
+    "eugene",
+    "eulahs",
+    "eunice",
+    "evades",
+    "evalyn",
+    "evelia",
+    "evelin",
+    "evelyn",
+    "evenly",
+    "events",
+    "everly",
+    "evette",
+    "evicts",
+    "evilly",
+    "evitas",
+    "evolve",
+    "evonne",
+    "exacts",
+    "exalts",
+    "excels",
+    "excess",
+    "excite",
+    "excuse",
+    "exerts",
+    "exiles",
+    "exilic",
+    "exists",
+    "exotic",
+    "expand",
+    "expats",
+    "expect",
+    "expert",
+    "expire",
+    "expose",
+    "extant",
+    "extend",
+    "extols",
+    "extras",
+    "exudes",
+    "eyings",
+    "fabian",
+    "fabled",
+    "fables",
+    "fabric",
+    "faceds",
+    "facete",
+    "facets",
+    "facile",
+    "faders",
+    "faecal",
+    "faints",
+    "fairly",
+    "fairys",
+    "faiths",
+    "fakeds",
+    "fakers",
+    "falcon",
+    "fallon",
+    "falser",
+    "falses",
+    "fameds",
+    "family",
+    "famous",
+    "fanged",
+    "fannie",
+    "fannys",
+    "farahs",
+    "farand",
+    "farces",
+    "fareds",
+    "farrah",
+    "faster",
+    "fatals",
+    "fateds",
+    "father",
+    "fatima",
+    "fatter",
+    "faucal",
+    "faults",
+    "faulty",
+    "faunal",
+    "faunas",
+    "fausto",
+    "faxeds",
+    "feasts",
+    "featly",
+    "feckly",
+    "fecund",
+    "feeble",
+    "feebly",
+    "feigns",
+    "feints",
+    "feirie",
+    "feisty",
+    "felica",
+    "felice",
+    "feline",
+    "felipa",
+    "felipe",
+    "felisa",
+    "felixs",
+    "felons",
+    "felsic",
+    "felton",
+    "female",
+    "femurs",
+    "fences",
+    "fennec",
+    "feodal",
+    "ferals",
+    "ferial",
+    "ferine",
+    "fermin",
+    "fernes",
+    "ferret",
+    "ferric",
+    "fervid",
+    "festal",
+    "fetchs",
+    "fetids",
+    "feudal",
+    "fevers",
+    "fewers",
+    "fezzed",
+    "fibers",
+    "fibres",
+    "fickle",
+    "fickly",
+    "fiddly",
+    "fidela",
+    "fidels",
+    "fields",
+    "fiends",
+    "fierce",
+    "fifths",
+    "fights",
+    "figure",
+    "filate",
+    "filchs",
+    "fileds",
+    "filets",
+    "filial",
+    "fillys",
+    "filose",
+    "filter",
+    "filths",
+    "filthy",
+    "filtre",
+    "finals",
+    "finchs",
+    "fineds",
+    "finely",
+    "finers",
+    "finger",
+    "finish",
+    "finite",
+    "finley",
+    "finned",
+    "fionas",
+    "fireds",
+    "firmly",
+    "firsts",
+    "fiscal",
+    "fisher",
+    "fistic",
+    "fitchy",
+    "fitful",
+    "fitted",
+    "fitten",
+    "fixeds",
+    "fixers",
+    "fjords",
+    "flabby",
+    "flaggy",
+    "flails",
+    "flairs",
+    "flakes",
+    "flamba",
+    "flanks",
+    "flappy",
+    "flares",
+    "flashs",
+    "flashy",
+    "flasks",
+    "flatly",
+    "flavia",
+    "flavor",
+    "flawed",
+    "flaxen",
+    "flecky",
+    "fledgy",
+    "fleecy",
+    "fleets",
+    "fleshs",
+    "fleshy",
+    "fletas",
+    "fleury",
+    "flexed",
+    "flicks",
+    "flight",
+    "flings",
+    "flinty",
+    "flirts",
+    "floats",
+    "floaty",
+    "flocks",
+    "flocky",
+    "floods",
+    "floors",
+    "floral",
+    "floras",
+    "floria",
+    "florid",
+    "flossy",
+    "floury",
+    "flower",
+    "flowns",
+    "floyds",
+    "fluent",
+    "fluffs",
+    "fluffy",
+    "fluids",
+    "flukes",
+    "flukey",
+    "flunks",
+    "flushs",
+    "fluted",
+    "flutes",
+    "flutey",
+    "flyers",
+    "flying",
+    "flynns",
+    "focals",
+    "fodgel",
+    "foetal",
+    "foetid",
+    "fogged",
+    "foiled",
+    "foists",
+    "foliar",
+    "folios",
+    "folksy",
+    "follow",
+    "fondas",
+    "fondly",
+    "fondus",
+    "fontal",
+    "footed",
+    "forced",
+    "forest",
+    "forget",
+    "forked",
+    "formae",
+    "formic",
+    "forums",
+    "fossas",
+    "fossil",
+    "foster",
+    "foully",
+    "founds",
+    "founts",
+    "foveal",
+    "foxier",
+    "foxily",
+    "fozier",
+    "frails",
+    "france",
+    "franco",
+    "franks",
+    "freaks",
+    "freaky",
+    "fredas",
+    "fredda",
+    "freddy",
+    "fredia",
+    "freeda",
+    "freeds",
+    "freely",
+    "freida",
+    "freshs",
+    "fretty",
+    "freyas",
+    "friars",
+    "fridas",
+    "frieda",
+    "frieds",
+    "friend",
+    "friers",
+    "frigid",
+    "frilly",
+    "fringe",
+    "frisky",
+    "fritzs",
+    "frizzy",
+    "frocks",
+    "froggy",
+    "fronds",
+    "fronts",
+    "frosts",
+    "frosty",
+    "froths",
+    "frothy",
+    "frousy",
+    "frouzy",
+    "frowns",
+    "frowsy",
+    "frowzy",
+    "frozen",
+    "frugal",
+    "fruits",
+    "fruity",
+    "frumpy",
+    "fsiest",
+    "fudges",
+    "fugues",
+    "fulgid",
+    "fulmar",
+    "fumier",
+    "fumiko",
+    "fundic",
+    "funest",
+    "fungal",
+    "fungic",
+    "fungis",
+    "funked",
+    "furors",
+    "furred",
+    "fusile",
+    "futile",
+    "future",
+    "gabled",
+    "gadfly",
+    "gadget",
+    "gainly",
+    "gaited",
+    "galaxy",
+    "galens",
+    "galina",
+    "gallic",
+    "galore",
+    "gameds",
+    "gamely",
+    "gamers",
+    "gamier",
+    "gamily",
+    "gammas",
+    "gangly",
+    "gannet",
+    "gannon",
+    "garage",
+    "garden",
+    "garish",
+    "garlic",
+    "garnet",
+    "garret",
+    "garrys",
+    "garths",
+    "gaslit",
+    "gaston",
+    "gateds",
+    "gather",
+    "gators",
+    "gauche",
+    "gauges",
+    "gauzes",
+    "gavels",
+    "gavins",
+    "gavyns",
+    "gaylas",
+    "gayles",
+    "geckos",
+    "geeses",
+    "gemmas",
+    "genaro",
+    "genets",
+    "geneva",
+    "genial",
+    "genias",
+    "genies",
+    "genius",
+    "gennas",
+    "gennie",
+    "gennys",
+    "genres",
+    "gentil",
+    "gentle",
+    "gently",
+    "genual",
+    "geodic",
+    "george",
+    "gerald",
+    "gerard",
+    "gerbil",
+    "gerdas",
+    "german",
+    "gerris",
+    "gerrys",
+    "gertha",
+    "gertie",
+    "gertys",
+    "gestic",
+    "ghosts",
+    "ghouls",
+    "giadas",
+    "gianas",
+    "gianna",
+    "gianni",
+    "giants",
+    "gibbed",
+    "gibbon",
+    "gibson",
+    "gideon",
+    "gidget",
+    "gifted",
+    "giggle",
+    "giggly",
+    "gildas",
+    "gilmas",
+    "ginger",
+    "ginnys",
+    "girths",
+    "gisela",
+    "gisele",
+    "givens",
+    "givers",
+    "giving",
+    "glades",
+    "gladis",
+    "gladly",
+    "gladys",
+    "glairy",
+    "glance",
+    "glayds",
+    "glazes",
+    "gleans",
+    "glebal",
+    "gleety",
+    "glegly",
+    "glenda",
+    "glenna",
+    "glenns",
+    "glibly",
+    "glider",
+    "glinda",
+    "glints",
+    "glisky",
+    "glitzs",
+    "gloats",
+    "global",
+    "globes",
+    "gloomy",
+    "gloria",
+    "glorys",
+    "gloves",
+    "glueds",
+    "gluier",
+    "gluily",
+    "glumly",
+    "glumpy",
+    "glynda",
+    "glynis",
+    "glyphs",
+    "gnarly",
+    "gnashs",
+    "gnatty",
+    "gnomes",
+    "gnomic",
+    "goblin",
+    "gobony",
+    "godwit",
+    "goggly",
+    "goings",
+    "goldas",
+    "golden",
+    "goldie",
+    "golems",
+    "goniac",
+    "gonial",
+    "goodly",
+    "googly",
+    "gooier",
+    "gooses",
+    "gopher",
+    "gordon",
+    "gorier",
+    "gorily",
+    "gospel",
+    "gossip",
+    "goutta",
+    "govern",
+    "gowany",
+    "goyish",
+    "graced",
+    "graces",
+    "gracia",
+    "gracie",
+    "grades",
+    "gradys",
+    "graeme",
+    "grafts",
+    "graham",
+    "graigs",
+    "grails",
+    "grains",
+    "grainy",
+    "grands",
+    "grants",
+    "grapes",
+    "graphs",
+    "grasps",
+    "grates",
+    "graves",
+    "gravid",
+    "grayce",
+    "grayly",
+    "greasy",
+    "greats",
+    "grebes",
+    "greedy",
+    "greens",
+    "greggs",
+    "gretas",
+    "gretta",
+    "greyly",
+    "griefs",
+    "grifts",
+    "grilla",
+    "grills",
+    "grimes",
+    "grimly",
+    "grinds",
+    "gripes",
+    "gripey",
+    "grippy",
+    "grisel",
+    "grisly",
+    "grison",
+    "gritty",
+    "groans",
+    "groggy",
+    "grooms",
+    "groovy",
+    "gropes",
+    "grotty",
+    "grouse",
+    "grouty",
+    "groved",
+    "grover",
+    "groves",
+    "growls",
+    "growly",
+    "growns",
+    "gruffy",
+    "grumly",
+    "grumpy",
+    "grunts",
+    "gudrun",
+    "guests",
+    "guided",
+    "guides",
+    "guilds",
+    "guilty",
+    "guinea",
+    "guitar",
+    "gulags",
+    "gulchs",
+    "gunnar",
+    "gunned",
+    "gunnel",
+    "gunner",
+    "guppys",
+    "gussie",
+    "gwenda",
+    "gwenns",
+    "gyrose",
+    "habile",
+    "habits",
+    "hackly",
+    "hadlee",
+    "hadley",
+    "haemal",
+    "haemic",
+    "haikus",
+    "hailee",
+    "hailey",
+    "halest",
+    "haleys",
+    "halina",
+    "halles",
+    "halley",
+    "hallie",
+    "haloed",
+    "hammer",
+    "hamzas",
+    "handed",
+    "hannah",
+    "hannas",
+    "haptic",
+    "harbor",
+    "harder",
+    "hardly",
+    "harlan",
+    "harlee",
+    "harley",
+    "harlow",
+    "harold",
+    "harper",
+    "harris",
+    "harrys",
+    "harshs",
+    "harvey",
+    "hastes",
+    "hatchs",
+    "hateds",
+    "haters",
+    "hattie",
+    "havens",
+    "haydee",
+    "hayden",
+    "haylee",
+    "hayley",
+    "hazard",
+    "hazels",
+    "hazier",
+    "hazily",
+    "headed",
+    "health",
+    "hearty",
+    "heated",
+    "heaths",
+    "heathy",
+    "heaven",
+    "hector",
+    "hedges",
+    "hedwig",
+    "heeled",
+    "heides",
+    "heidis",
+    "heidys",
+    "height",
+    "heikes",
+    "helena",
+    "helene",
+    "helens",
+    "helgas",
+    "helixs",
+    "hellen",
+    "hellos",
+    "helmed",
+    "helmet",
+    "helped",
+    "hemoid",
+    "hempen",
+    "henley",
+    "henrys",
+    "herman",
+    "hermas",
+    "hermit",
+    "heroic",
+    "herons",
+    "hertas",
+    "hertha",
+    "hester",
+    "hetero",
+    "hettie",
+    "hexeds",
+    "hidden",
+    "hiedis",
+    "hiemal",
+    "higher",
+    "highly",
+    "hikers",
+    "hilary",
+    "hildas",
+    "hildes",
+    "hilmas",
+    "hilton",
+    "hipped",
+    "hipper",
+    "hippos",
+    "hirams",
+    "hireds",
+    "hiroko",
+    "hisako",
+    "hispid",
+    "histie",
+    "hitchy",
+    "hoarse",
+    "hobert",
+    "hockey",
+    "hogged",
+    "hoists",
+    "hokily",
+    "holden",
+    "holier",
+    "holies",
+    "holily",
+    "holley",
+    "hollie",
+    "hollis",
+    "hollow",
+    "hollys",
+    "holmic",
+    "homely",
+    "homers",
+    "homier",
+    "homily",
+    "honeds",
+    "honest",
+    "honeys",
+    "honied",
+    "hooded",
+    "hoofed",
+    "hooked",
+    "hoopoe",
+    "horace",
+    "horary",
+    "hordes",
+    "hormic",
+    "horned",
+    "hornet",
+    "horrid",
+    "horror",
+    "horses",
+    "horsey",
+    "hoseas",
+    "hostly",
+    "hotels",
+    "hounds",
+    "houndy",
+    "hourly",
+    "houses",
+    "hovels",
+    "howard",
+    "hubbly",
+    "hubert",
+    "hudson",
+    "hugely",
+    "hugest",
+    "huldas",
+    "humane",
+    "humans",
+    "humble",
+    "humbly",
+    "humids",
+    "hummel",
+    "hunchs",
+    "hungry",
+    "hunted",
+    "hunter",
+    "huongs",
+    "hurdle",
+    "hurtly",
+    "huskys",
+    "hybrid",
+    "hydric",
+    "hyenas",
+    "hyenic",
+    "hyetal",
+    "hymans",
+    "hypers",
+    "hyphal",
+    "hyraxs",
+    "iatric",
+    "iciest",
+    "icings",
+    "ickier",
+    "ickily",
+    "iconic",
+    "idalia",
+    "ideals",
+    "idella",
+    "idells",
+    "idioms",
+    "idiots",
+    "idlest",
+    "ieshas",
+    "igloos",
+    "ignore",
+    "iguana",
+    "ilanas",
+    "ileana",
+    "ileens",
+    "ilenes",
+    "iliana",
+    "ilonas",
+    "images",
+    "imanis",
+    "imbues",
+    "imelda",
+    "imidic",
+    "immane",
+    "immune",
+    "impact",
+    "impala",
+    "impish",
+    "impose",
+    "impure",
+    "inanes",
+    "inbond",
+    "inborn",
+    "inbred",
+    "income",
+    "incult",
+    "incurs",
+    "indias",
+    "indign",
+    "indira",
+    "indoor",
+    "inells",
+    "inepts",
+    "inerts",
+    "infant",
+    "infelt",
+    "infers",
+    "infirm",
+    "inform",
+    "ingers",
+    "ingrid",
+    "inhale",
+    "inject",
+    "injury",
+    "inkier",
+    "inlaid",
+    "inlaws",
+    "inlets",
+    "inmate",
+    "inmost",
+    "innate",
+    "insane",
+    "insect",
+    "inside",
+    "intact",
+    "intent",
+    "intime",
+    "intoed",
+    "intown",
+    "invest",
+    "invite",
+    "inward",
+    "iodous",
+    "ionics",
+    "iraida",
+    "irates",
+    "irenas",
+    "irenes",
+    "irenic",
+    "iridic",
+    "irinas",
+    "irishs",
+    "iritic",
+    "ironic",
+    "irving",
+    "irvins",
+    "irwins",
+    "isaacs",
+    "isabel",
+    "isaiah",
+    "isaias",
+    "isaura",
+    "iselas",
+    "ishaan",
+    "isiahs",
+    "isidra",
+    "isidro",
+    "island",
+    "islets",
+    "ismael",
+    "isobel",
+    "israel",
+    "isreal",
+    "issacs",
+    "itzels",
+    "ivanas",
+    "ivanna",
+    "ivette",
+    "ivonne",
+    "ivorys",
+    "izaiah",
+    "izayah",
+    "izetta",
+    "izolas",
+    "jabiru",
+    "jackal",
+    "jacket",
+    "jackie",
+    "jackis",
+    "jaclyn",
+    "jacobs",
+    "jacoby",
+    "jacque",
+    "jacqui",
+    "jadeds",
+    "jadens",
+    "jadiel",
+    "jadish",
+    "jadons",
+    "jaeger",
+    "jaelyn",
+    "jagged",
+    "jagger",
+    "jaguar",
+    "jaidas",
+    "jaiden",
+    "jaimee",
+    "jaimes",
+    "jaimie",
+    "jairos",
+    "jakobe",
+    "jakobs",
+    "jalens",
+    "jalisa",
+    "jamaal",
+    "jamals",
+    "jamari",
+    "jamars",
+    "jamees",
+    "jamels",
+    "jameys",
+    "jamies",
+    "jamika",
+    "jamila",
+    "jamirs",
+    "jammie",
+    "janaes",
+    "janays",
+    "janean",
+    "janeen",
+    "janees",
+    "janell",
+    "janels",
+    "janene",
+    "janeth",
+    "janets",
+    "janett",
+    "janeys",
+    "jangly",
+    "janice",
+    "janies",
+    "janina",
+    "janine",
+    "janise",
+    "janita",
+    "janiya",
+    "jannas",
+    "jannet",
+    "jannie",
+    "janyce",
+    "jareds",
+    "jarods",
+    "jarred",
+    "jarrod",
+    "jarvis",
+    "jasiah",
+    "jasmin",
+    "jasons",
+    "jasper",
+    "jaunts",
+    "jaunty",
+    "javier",
+    "javion",
+    "javons",
+    "jaxens",
+    "jaxons",
+    "jaxson",
+    "jaxton",
+    "jaycee",
+    "jayces",
+    "jaycob",
+    "jaydas",
+    "jayden",
+    "jaydes",
+    "jaydon",
+    "jaylah",
+    "jaylas",
+    "jaylee",
+    "jaylen",
+    "jaylin",
+    "jaymes",
+    "jaymie",
+    "jaynas",
+    "jaynes",
+    "jayses",
+    "jayson",
+    "jazlyn",
+    "jazmin",
+    "jeanas",
+    "jeanes",
+    "jeanie",
+    "jeanna",
+    "jeanne",
+    "jeffie",
+    "jeffry",
+    "jejune",
+    "jemmas",
+    "jenaes",
+    "jenees",
+    "jenell",
+    "jeneva",
+    "jenice",
+    "jenine",
+    "jenise",
+    "jennas",
+    "jennet",
+    "jennie",
+    "jennis",
+    "jennys",
+    "jensen",
+    "jerald",
+    "jeramy",
+    "jerboa",
+    "jeremy",
+    "jerica",
+    "jerold",
+    "jerome",
+    "jeromy",
+    "jerrie",
+    "jerris",
+    "jerrod",
+    "jerrys",
+    "jesica",
+    "jesses",
+    "jessia",
+    "jessie",
+    "jessis",
+    "jettas",
+    "jettie",
+    "jewell",
+    "jewels",
+    "jiggly",
+    "jimena",
+    "jimmie",
+    "jimmys",
+    "jimply",
+    "jingly",
+    "jingos",
+    "jinnys",
+    "joanas",
+    "joanes",
+    "joanie",
+    "joanna",
+    "joanne",
+    "joanns",
+    "jocose",
+    "jocund",
+    "jodees",
+    "jodies",
+    "joeann",
+    "joella",
+    "joelle",
+    "joesph",
+    "joetta",
+    "joette",
+    "johana",
+    "johans",
+    "johnas",
+    "johnie",
+    "johnna",
+    "johnny",
+    "joists",
+    "jokers",
+    "jokily",
+    "joleen",
+    "jolene",
+    "jolies",
+    "joline",
+    "jolynn",
+    "jolyns",
+    "jonahs",
+    "jonell",
+    "jonies",
+    "jonnas",
+    "jonnie",
+    "jordan",
+    "jorden",
+    "jordon",
+    "jordyn",
+    "jordys",
+    "jorges",
+    "josefa",
+    "josefs",
+    "joseph",
+    "joshua",
+    "josiah",
+    "josies",
+    "joslyn",
+    "jospeh",
+    "josues",
+    "joules",
+    "jousts",
+    "jovani",
+    "jovans",
+    "jovial",
+    "jovita",
+    "jowled",
+    "joyces",
+    "joyful",
+    "joyous",
+    "joziah",
+    "juanas",
+    "jubate",
+    "judahs",
+    "judges",
+    "judies",
+    "judith",
+    "judson",
+    "juelzs",
+    "jugate",
+    "juices",
+    "julees",
+    "julene",
+    "julian",
+    "julias",
+    "julien",
+    "julies",
+    "juliet",
+    "julios",
+    "julius",
+    "jumbos",
+    "juncos",
+    "jungle",
+    "jungly",
+    "junies",
+    "junior",
+    "junita",
+    "junkos",
+    "jurnee",
+    "jurors",
+    "justas",
+    "justin",
+    "justly",
+    "justus",
+    "juttas",
+    "kaceys",
+    "kacies",
+    "kadens",
+    "kaeden",
+    "kaelyn",
+    "kaiden",
+    "kailas",
+    "kailee",
+    "kailey",
+    "kailyn",
+    "kairis",
+    "kaiyas",
+    "kakapo",
+    "kalebs",
+    "kalels",
+    "kaleys",
+    "kallie",
+    "kalong",
+    "kalyns",
+    "kamala",
+    "kamari",
+    "kamden",
+    "kamdyn",
+    "kamila",
+    "kamron",
+    "kamryn",
+    "kandis",
+    "kandra",
+    "kandys",
+    "kannon",
+    "karans",
+    "kareem",
+    "kareen",
+    "karena",
+    "karens",
+    "kareys",
+    "karies",
+    "karima",
+    "karina",
+    "karine",
+    "karins",
+    "karisa",
+    "karlas",
+    "karlee",
+    "karlie",
+    "karlyn",
+    "karlys",
+    "karmas",
+    "karmen",
+    "karmic",
+    "karole",
+    "karols",
+    "karons",
+    "karren",
+    "karrie",
+    "karris",
+    "karrys",
+    "karson",
+    "karsyn",
+    "karter",
+    "karyls",
+    "karyns",
+    "kasens",
+    "kaseys",
+    "kashas",
+    "kasies",
+    "kasons",
+    "kathes",
+    "kathey",
+    "kathie",
+    "kathis",
+    "kathys",
+    "katias",
+    "katies",
+    "katina",
+    "katlyn",
+    "kattie",
+    "kayaks",
+    "kaycee",
+    "kayces",
+    "kayden",
+    "kaylas",
+    "kaylee",
+    "kaylen",
+    "kaylie",
+    "kaylin",
+    "kaylyn",
+    "kaysen",
+    "kayson",
+    "kazoos",
+    "kazuko",
+    "keagan",
+    "keaton",
+    "kecias",
+    "keegan",
+    "keeley",
+    "keelys",
+    "keenan",
+    "keenas",
+    "keenly",
+    "keesha",
+    "keikos",
+    "keilas",
+    "keiras",
+    "keisha",
+    "keitha",
+    "keiths",
+    "kellan",
+    "kellee",
+    "kellen",
+    "kelles",
+    "kelley",
+    "kellie",
+    "kellis",
+    "kellye",
+    "kellys",
+    "kelpie",
+    "kelsey",
+    "kelsie",
+    "kelsis",
+    "kelvin",
+    "kendal",
+    "kendas",
+    "kendra",
+    "keneth",
+    "kenias",
+    "kenley",
+    "kennas",
+    "kennys",
+    "kenton",
+    "kenyas",
+    "kenzie",
+    "kerens",
+    "kermit",
+    "kerrie",
+    "kerris",
+    "kerrys",
+    "keshas",
+    "keshia",
+    "kevens",
+    "kevins",
+    "keylas",
+    "khalil",
+    "khloes",
+    "kianas",
+    "kiaras",
+    "kidney",
+    "kieran",
+    "kieras",
+    "kiesha",
+    "kieths",
+    "kileys",
+    "kilted",
+    "kimber",
+    "kimbra",
+    "kimiko",
+    "kimora",
+    "kindly",
+    "kindra",
+    "kingly",
+    "kinkly",
+    "kinley",
+    "kirbys",
+    "kishas",
+    "kitten",
+    "kittie",
+    "kittys",
+    "kiyoko",
+    "kizzie",
+    "kizzys",
+    "klaras",
+    "klutzy",
+    "knacks",
+    "knaggy",
+    "knarry",
+    "knobby",
+    "knocks",
+    "knolls",
+    "knolly",
+    "knotty",
+    "knowns",
+    "knurly",
+    "koalas",
+    "kodiak",
+    "kohens",
+    "kolbys",
+    "kolten",
+    "kolton",
+    "konner",
+    "konnor",
+    "korbin",
+    "koreys",
+    "kraigs",
+    "kraits",
+    "krills",
+    "krishs",
+    "krissy",
+    "krista",
+    "kristi",
+    "kristy",
+    "krysta",
+    "kurtis",
+    "kutcha",
+    "kylahs",
+    "kylans",
+    "kylees",
+    "kylers",
+    "kylies",
+    "kymani",
+    "kynlee",
+    "kyokos",
+    "kyongs",
+    "kyrees",
+    "kyries",
+    "kysons",
+    "kyungs",
+    "labels",
+    "labias",
+    "labile",
+    "laceds",
+    "laceys",
+    "lacier",
+    "lacies",
+    "lacily",
+    "lactic",
+    "ladawn",
+    "ladder",
+    "ladens",
+    "lagers",
+    "lahoma",
+    "lailah",
+    "lailas",
+    "laines",
+    "lainey",
+    "lakias",
+    "lakier",
+    "lakita",
+    "lamars",
+    "lamely",
+    "lamest",
+    "laming",
+    "lamont",
+    "lanate",
+    "lances",
+    "landed",
+    "landen",
+    "landon",
+    "landry",
+    "landyn",
+    "lanell",
+    "lanely",
+    "laneys",
+    "langur",
+    "lanies",
+    "lanita",
+    "lankly",
+    "lannie",
+    "lannys",
+    "lanora",
+    "lanose",
+    "lapels",
+    "laptop",
+    "laraes",
+    "larchs",
+    "larees",
+    "larger",
+    "larine",
+    "larisa",
+    "larita",
+    "larrys",
+    "larues",
+    "larval",
+    "larvas",
+    "lashay",
+    "lashed",
+    "lashon",
+    "lastly",
+    "lately",
+    "latent",
+    "laters",
+    "latexs",
+    "lathes",
+    "latias",
+    "latina",
+    "latish",
+    "latoya",
+    "laughs",
+    "launas",
+    "lauran",
+    "lauras",
+    "laurel",
+    "lauren",
+    "laures",
+    "lauric",
+    "laurie",
+    "lauris",
+    "lauryn",
+    "lavada",
+    "lavera",
+    "lavern",
+    "laveta",
+    "lavina",
+    "lavish",
+    "lavona",
+    "lavone",
+    "lavons",
+    "lawana",
+    "lawful",
+    "lawson",
+    "laylah",
+    "laylas",
+    "laynes",
+    "layton",
+    "lazaro",
+    "lazier",
+    "lazily",
+    "leachy",
+    "leaden",
+    "leader",
+    "leafed",
+    "leally",
+    "leanas",
+    "leanly",
+    "leanna",
+    "leanne",
+    "leanns",
+    "leapts",
+    "learns",
+    "leashs",
+    "leasts",
+    "leatha",
+    "leaved",
+    "lecias",
+    "ledges",
+    "leeann",
+    "leechs",
+    "leenas",
+    "leesas",
+    "legals",
+    "legend",
+    "legged",
+    "leidas",
+    "leigha",
+    "leighs",
+    "leilas",
+    "leisas",
+    "leisha",
+    "lekker",
+    "lelahs",
+    "leland",
+    "lelias",
+    "lemons",
+    "lemony",
+    "lemuel",
+    "lemurs",
+    "lenard",
+    "length",
+    "lenita",
+    "lennas",
+    "lennie",
+    "lennon",
+    "lennox",
+    "lennys",
+    "lenora",
+    "lenore",
+    "lenses",
+    "lenten",
+    "lentic",
+    "leolas",
+    "leomas",
+    "leonas",
+    "leonel",
+    "leones",
+    "leonia",
+    "leonie",
+    "leonor",
+    "leoras",
+    "leotas",
+    "leroys",
+    "leshas",
+    "lesias",
+    "leslee",
+    "lesley",
+    "leslie",
+    "leslis",
+    "leslys",
+    "lessie",
+    "lesson",
+    "lester",
+    "lethal",
+    "lethas",
+    "letter",
+    "lettie",
+    "lettys",
+    "levees",
+    "levels",
+    "levers",
+    "lewdly",
+    "lexies",
+    "leylas",
+    "lezlie",
+    "liable",
+    "lianas",
+    "lianes",
+    "lianne",
+    "libbie",
+    "libbys",
+    "lidded",
+    "lidias",
+    "liefly",
+    "lienal",
+    "ligers",
+    "ligias",
+    "likeds",
+    "likely",
+    "lilacs",
+    "lilahs",
+    "lilian",
+    "lilias",
+    "lilied",
+    "lilith",
+    "lillas",
+    "lillia",
+    "lillie",
+    "lillis",
+    "lillys",
+    "limbed",
+    "limber",
+    "limbic",
+    "limbos",
+    "limier",
+    "limpet",
+    "limpid",
+    "limply",
+    "limpsy",
+    "lindas",
+    "lindsy",
+    "lindys",
+    "lineal",
+    "linear",
+    "lineds",
+    "linens",
+    "lineny",
+    "liners",
+    "lingos",
+    "linier",
+    "linked",
+    "linnea",
+    "linnet",
+    "linnie",
+    "linsey",
+    "lionel",
+    "lionly",
+    "liquid",
+    "lishas",
+    "lissas",
+    "lissom",
+    "listed",
+    "lither",
+    "lithes",
+    "lithic",
+    "litten",
+    "little",
+    "liveds",
+    "lively",
+    "livers",
+    "livest",
+    "livias",
+    "livids",
+    "living",
+    "lizard",
+    "lizeth",
+    "lizzie",
+    "llamas",
+    "lloyds",
+    "loachs",
+    "loaded",
+    "lobate",
+    "lobose",
+    "locust",
+    "logans",
+    "logics",
+    "logier",
+    "logily",
+    "loidas",
+    "loises",
+    "londas",
+    "london",
+    "londyn",
+    "lonely",
+    "loners",
+    "longly",
+    "lonnas",
+    "lonnie",
+    "lonnys",
+    "looped",
+    "looser",
+    "lordly",
+    "lorean",
+    "loreen",
+    "lorees",
+    "lorena",
+    "lorene",
+    "lorens",
+    "loreta",
+    "lorias",
+    "lories",
+    "lorina",
+    "lorine",
+    "lorita",
+    "lornas",
+    "lorrie",
+    "lorris",
+    "losing",
+    "lostly",
+    "lottie",
+    "louann",
+    "louche",
+    "louder",
+    "loudly",
+    "louies",
+    "louisa",
+    "louise",
+    "lounge",
+    "loungy",
+    "louras",
+    "lourie",
+    "louses",
+    "louvar",
+    "loveds",
+    "lovely",
+    "lovers",
+    "lovies",
+    "loving",
+    "lowell",
+    "lowers",
+    "lowery",
+    "lowish",
+    "lowser",
+    "loyces",
+    "luanas",
+    "luanna",
+    "luanne",
+    "luanns",
+    "lubric",
+    "luccas",
+    "lucent",
+    "lucian",
+    "lucias",
+    "lucids",
+    "lucien",
+    "lucies",
+    "lucila",
+    "lucile",
+    "lucina",
+    "lucios",
+    "lucius",
+    "ludies",
+    "luella",
+    "luetic",
+    "luetta",
+    "luigis",
+    "luisas",
+    "luises",
+    "lumbar",
+    "lumber",
+    "lumpen",
+    "lunars",
+    "lunchs",
+    "lunier",
+    "lunies",
+    "lupita",
+    "lupous",
+    "lurids",
+    "lushly",
+    "luteal",
+    "luther",
+    "lutose",
+    "luxury",
+    "lydias",
+    "lylahs",
+    "lymans",
+    "lynchs",
+    "lyndas",
+    "lyndia",
+    "lyndon",
+    "lynell",
+    "lynnas",
+    "lynnes",
+    "lynsey",
+    "lyrate",
+    "lyrics",
+    "mabels",
+    "mables",
+    "macaws",
+    "maceys",
+    "machos",
+    "macies",
+    "macled",
+    "madden",
+    "maddie",
+    "maddox",
+    "madges",
+    "madies",
+    "madlyn",
+    "maegan",
+    "maeves",
+    "magali",
+    "magaly",
+    "magans",
+    "magdas",
+    "magens",
+    "maggie",
+    "maggot",
+    "magics",
+    "magmas",
+    "magnet",
+    "magnus",
+    "magpie",
+    "maidas",
+    "maiden",
+    "mailed",
+    "mailes",
+    "mainly",
+    "mairas",
+    "maires",
+    "maisha",
+    "maisie",
+    "maison",
+    "majors",
+    "makais",
+    "makeda",
+    "makena",
+    "makhis",
+    "malaya",
+    "malcom",
+    "maleah",
+    "malena",
+    "maliah",
+    "malias",
+    "malika",
+    "maliks",
+    "malisa",
+    "malkas",
+    "mallie",
+    "mambas",
+    "mamies",
+    "mammal",
+    "mammie",
+    "manage",
+    "mandas",
+    "mandie",
+    "mandis",
+    "mandys",
+    "manful",
+    "mangey",
+    "mangos",
+    "manias",
+    "manics",
+    "manies",
+    "manned",
+    "manors",
+    "manqua",
+    "mantas",
+    "mantic",
+    "mantis",
+    "manual",
+    "manuel",
+    "maples",
+    "marble",
+    "marbly",
+    "marcel",
+    "marcia",
+    "marcie",
+    "marcis",
+    "marcos",
+    "marcus",
+    "marcys",
+    "marens",
+    "margay",
+    "marges",
+    "marget",
+    "margie",
+    "margin",
+    "margit",
+    "margos",
+    "margot",
+    "margys",
+    "marhta",
+    "mariah",
+    "mariam",
+    "marian",
+    "marias",
+    "marica",
+    "mariel",
+    "maries",
+    "mariko",
+    "marilu",
+    "marina",
+    "marine",
+    "marins",
+    "marion",
+    "marios",
+    "marisa",
+    "marish",
+    "marita",
+    "marked",
+    "market",
+    "markus",
+    "marlas",
+    "marled",
+    "marlee",
+    "marlen",
+    "marley",
+    "marlin",
+    "marlon",
+    "marlos",
+    "marlyn",
+    "marlys",
+    "marmot",
+    "marnas",
+    "marnie",
+    "marnis",
+    "marrys",
+    "marsha",
+    "marshy",
+    "martas",
+    "marten",
+    "martha",
+    "marths",
+    "martin",
+    "martis",
+    "martys",
+    "marvas",
+    "marvel",
+    "marvin",
+    "marvis",
+    "maryam",
+    "maryas",
+    "maryjo",
+    "maryln",
+    "masako",
+    "masked",
+    "masons",
+    "masses",
+    "master",
+    "matchs",
+    "mateds",
+    "mateos",
+    "mathas",
+    "mathew",
+    "matias",
+    "matrix",
+    "matted",
+    "matteo",
+    "matter",
+    "mattes",
+    "mattie",
+    "mature",
+    "maudes",
+    "maudie",
+    "maungy",
+    "mauras",
+    "mauros",
+    "mauves",
+    "mawger",
+    "maxies",
+    "maxima",
+    "maximo",
+    "maxims",
+    "maxine",
+    "maxton",
+    "maybes",
+    "mayfly",
+    "maymes",
+    "mayola",
+    "mayras",
+    "mayson",
+    "mazier",
+    "mazies",
+    "mazily",
+    "meadow",
+    "meagan",
+    "meager",
+    "meagre",
+    "meanly",
+    "meants",
+    "measly",
+    "meatal",
+    "medals",
+    "medfly",
+    "medias",
+    "medics",
+    "meekly",
+    "meetly",
+    "megans",
+    "meggan",
+    "meghan",
+    "mekhis",
+    "melani",
+    "melany",
+    "melbas",
+    "meldas",
+    "melias",
+    "melida",
+    "melina",
+    "melisa",
+    "melita",
+    "mellie",
+    "mellow",
+    "melodi",
+    "melody",
+    "melony",
+    "melvas",
+    "melvin",
+    "member",
+    "memory",
+    "mendys",
+    "mensal",
+    "mental",
+    "mercys",
+    "merely",
+    "merest",
+    "merits",
+    "merles",
+    "merlin",
+    "merlyn",
+    "mernas",
+    "merrie",
+    "merris",
+    "merrys",
+    "mertie",
+    "mervin",
+    "meryls",
+    "mesial",
+    "metals",
+    "meters",
+    "method",
+    "micahs",
+    "michal",
+    "michas",
+    "michel",
+    "mickey",
+    "mickie",
+    "mickis",
+    "middle",
+    "midges",
+    "midsts",
+    "miesha",
+    "miffed",
+    "mights",
+    "mighty",
+    "mignon",
+    "miguel",
+    "mikels",
+    "mikkis",
+    "milana",
+    "milans",
+    "mildas",
+    "mildly",
+    "milena",
+    "mileys",
+    "milled",
+    "miller",
+    "millie",
+    "millys",
+    "milton",
+    "mindas",
+    "minded",
+    "mindis",
+    "mindys",
+    "minnas",
+    "minnie",
+    "minnow",
+    "mintas",
+    "minute",
+    "miquel",
+    "mireya",
+    "miriam",
+    "mirian",
+    "mirier",
+    "mirnas",
+    "mirror",
+    "mirtas",
+    "mirtha",
+    "misael",
+    "misery",
+    "mishas",
+    "missys",
+    "mistie",
+    "mistis",
+    "mistys",
+    "mitchs",
+    "mitral",
+    "mitsue",
+    "mittie",
+    "mitzie",
+    "mitzis",
+    "mixeds",
+    "mixers",
+    "miyoko",
+    "mizzly",
+    "mnemic",
+    "mobile",
+    "modals",
+    "models",
+    "modems",
+    "modern",
+    "modest",
+    "modify",
+    "modish",
+    "moguls",
+    "moiest",
+    "moiras",
+    "moises",
+    "molars",
+    "moline",
+    "mollie",
+    "mollys",
+    "moment",
+    "monads",
+    "monets",
+    "monica",
+    "monied",
+    "monika",
+    "monkey",
+    "monnie",
+    "monroe",
+    "montes",
+    "montys",
+    "mooned",
+    "mooses",
+    "mopier",
+    "morals",
+    "morays",
+    "morbid",
+    "morgan",
+    "moriah",
+    "morish",
+    "mornay",
+    "morose",
+    "morphs",
+    "morris",
+    "morton",
+    "moshes",
+    "mostly",
+    "motels",
+    "mother",
+    "motifs",
+    "motion",
+    "motmot",
+    "motors",
+    "mottos",
+    "mouill",
+    "mounds",
+    "mounts",
+    "mourns",
+    "mouses",
+    "mousey",
+    "mouths",
+    "mouthy",
+    "movies",
+    "moving",
+    "mowers",
+    "mozell",
+    "muchly",
+    "mucoid",
+    "mucous",
+    "muddly",
+    "mudfat",
+    "muffin",
+    "mulish",
+    "mullet",
+    "mulley",
+    "mumbly",
+    "murals",
+    "muriel",
+    "murkly",
+    "murray",
+    "murres",
+    "murrey",
+    "muscle",
+    "muscly",
+    "museum",
+    "musics",
+    "muskox",
+    "muteds",
+    "mutely",
+    "mutual",
+    "myesha",
+    "mynahs",
+    "myongs",
+    "myopic",
+    "myriam",
+    "myrles",
+    "myrnas",
+    "myrons",
+    "myrtas",
+    "myrtie",
+    "myrtis",
+    "myrtle",
+    "myself",
+    "myungs",
+    "myxoid",
+    "nacred",
+    "nadene",
+    "nadias",
+    "nadine",
+    "nahlas",
+    "naiads",
+    "naiant",
+    "naidas",
+    "naives",
+    "nakeds",
+    "nakias",
+    "nakita",
+    "namely",
+    "nancee",
+    "nancey",
+    "nancie",
+    "nancis",
+    "nancys",
+    "nannie",
+    "nanoid",
+    "naomas",
+    "naomis",
+    "napkin",
+    "narial",
+    "narrow",
+    "nasals",
+    "nasial",
+    "nasirs",
+    "nataly",
+    "natant",
+    "nathan",
+    "nation",
+    "native",
+    "nature",
+    "navals",
+    "navels",
+    "nayeli",
+    "neaped",
+    "nearby",
+    "nearer",
+    "nearly",
+    "neatly",
+    "nebule",
+    "nebuly",
+    "necole",
+    "nedras",
+    "needed",
+    "neelys",
+    "neidas",
+    "neighs",
+    "neldas",
+    "nelias",
+    "nelida",
+    "nellas",
+    "nelles",
+    "nellie",
+    "nellys",
+    "nelson",
+    "nenita",
+    "neomas",
+    "neomis",
+    "nephew",
+    "neriah",
+    "nerval",
+    "nestor",
+    "nether",
+    "nettie",
+    "nettly",
+    "neumic",
+    "neural",
+    "nevada",
+    "nevaeh",
+    "nevers",
+    "nevoid",
+    "newers",
+    "newish",
+    "newton",
+    "neymar",
+    "nguyet",
+    "nicely",
+    "nicest",
+    "nichol",
+    "nickie",
+    "nickis",
+    "nickys",
+    "nicola",
+    "nicole",
+    "nicols",
+    "nidias",
+    "niesha",
+    "nieves",
+    "nigels",
+    "niggly",
+    "nights",
+    "nikias",
+    "nikita",
+    "nikkis",
+    "nikola",
+    "nikole",
+    "nildas",
+    "nilgai",
+    "nilsas",
+    "nimble",
+    "nimbly",
+    "ninfas",
+    "ninjas",
+    "niobic",
+    "nishas",
+    "nitric",
+    "nixeds",
+    "nixons",
+    "nobler",
+    "nobles",
+    "nobuko",
+    "nocent",
+    "noctis",
+    "nodose",
+    "nodous",
+    "noelia",
+    "noella",
+    "noelle",
+    "noemis",
+    "noetic",
+    "nohemi",
+    "nolans",
+    "nomads",
+    "nonfat",
+    "nonrun",
+    "nontan",
+    "noodle",
+    "norahs",
+    "noreen",
+    "norene",
+    "noriko",
+    "norine",
+    "normal",
+    "norman",
+    "normas",
+    "norris",
+    "norths",
+    "nosier",
+    "nosily",
+    "notchy",
+    "nothus",
+    "notice",
+    "notour",
+    "nubbly",
+    "nubias",
+    "nubile",
+    "nudges",
+    "nukeds",
+    "numbat",
+    "number",
+    "numbly",
+    "nutant",
+    "nutria",
+    "nyalas",
+    "nydias",
+    "nylahs",
+    "nylons",
+    "nymphs",
+    "oafish",
+    "oakens",
+    "oakley",
+    "obeses",
+    "object",
+    "oblige",
+    "obtain",
+    "obtect",
+    "obtuse",
+    "occurs",
+    "oceans",
+    "ocelot",
+    "ochery",
+    "odelia",
+    "odells",
+    "odessa",
+    "odette",
+    "odilia",
+    "odious",
+    "odylic",
+    "ofelia",
+    "offers",
+    "office",
+    "offish",
+    "oftens",
+    "oilier",
+    "oilily",
+    "okapis",
+    "olders",
+    "oldest",
+    "olenes",
+    "oleoyl",
+    "oletas",
+    "olevia",
+    "olinda",
+    "olivas",
+    "oliver",
+    "olives",
+    "olivia",
+    "ollies",
+    "omaris",
+    "omegas",
+    "onager",
+    "oneida",
+    "oniony",
+    "onitas",
+    "online",
+    "onsets",
+    "ontics",
+    "onward",
+    "oozier",
+    "oozily",
+    "opacus",
+    "openly",
+    "opines",
+    "opiums",
+    "oppose",
+    "opteds",
+    "option",
+    "oralee",
+    "oralia",
+    "orally",
+    "orange",
+    "orangy",
+    "orbits",
+    "orders",
+    "oretha",
+    "organs",
+    "orient",
+    "oriole",
+    "orions",
+    "ornate",
+    "ornery",
+    "orphan",
+    "orphas",
+    "orvals",
+    "oscars",
+    "osmous",
+    "osprey",
+    "ossies",
+    "osteal",
+    "otelia",
+    "otilia",
+    "otiose",
+    "otters",
+    "ouidas",
+    "ounces",
+    "outfly",
+    "output",
+    "ouzels",
+    "ovally",
+    "overly",
+    "overts",
+    "ovoids",
+    "ovular",
+    "owlets",
+    "owlish",
+    "owners",
+    "oxalic",
+    "oxides",
+    "oxidic",
+    "oxlike",
+    "oxygen",
+    "oyster",
+    "ozella",
+    "ozells",
+    "ozonic",
+    "pablos",
+    "packly",
+    "paddle",
+    "pagans",
+    "paiges",
+    "pained",
+    "paints",
+    "paityn",
+    "palace",
+    "palely",
+    "palest",
+    "palish",
+    "pallid",
+    "palmar",
+    "palmas",
+    "palmer",
+    "paloma",
+    "paltry",
+    "pamala",
+    "pamela",
+    "pamila",
+    "pamula",
+    "pandas",
+    "pansys",
+    "paolas",
+    "papals",
+    "papers",
+    "papery",
+    "parade",
+    "parent",
+    "pareve",
+    "parker",
+    "parrot",
+    "parted",
+    "partis",
+    "partly",
+    "pastas",
+    "pastes",
+    "pastys",
+    "patchy",
+    "patios",
+    "patria",
+    "patrol",
+    "patsys",
+    "pattae",
+    "pattie",
+    "pattis",
+    "pattys",
+    "paulas",
+    "pausal",
+    "paveds",
+    "paxton",
+    "payees",
+    "payton",
+    "peaces",
+    "peachs",
+    "peachy",
+    "peaked",
+    "peanut",
+    "pearle",
+    "pearls",
+    "pearly",
+    "pebbly",
+    "pectic",
+    "pedate",
+    "pedros",
+    "peggie",
+    "peggys",
+    "pelvic",
+    "pencil",
+    "penile",
+    "penney",
+    "pennie",
+    "pennis",
+    "pennys",
+    "people",
+    "pepper",
+    "peptic",
+    "perchs",
+    "percys",
+    "perils",
+    "perlas",
+    "permit",
+    "peroxy",
+    "perrys",
+    "person",
+    "pertly",
+    "pestos",
+    "peters",
+    "petite",
+    "petras",
+    "petrel",
+    "pewees",
+    "peyton",
+    "phases",
+    "phasic",
+    "phatic",
+    "phebes",
+    "philip",
+    "phoebe",
+    "phonal",
+    "phonic",
+    "photic",
+    "photos",
+    "phrase",
+    "phungs",
+    "phuong",
+    "phylar",
+    "phylic",
+    "phylis",
+    "pianic",
+    "pianos",
+    "picked",
+    "picnic",
+    "picric",
+    "piddly",
+    "pieces",
+    "piedad",
+    "pierce",
+    "pierre",
+    "pigeon",
+    "piglet",
+    "pilars",
+    "pilose",
+    "pimply",
+    "pineal",
+    "pinier",
+    "pinkie",
+    "pinkly",
+    "pinnal",
+    "pipers",
+    "pipier",
+    "pipits",
+    "pissed",
+    "pistol",
+    "pitchy",
+    "pittas",
+    "pivots",
+    "pixels",
+    "pixies",
+    "pizzas",
+    "places",
+    "placid",
+    "plagal",
+    "plaice",
+    "plaids",
+    "planar",
+    "planes",
+    "planet",
+    "planks",
+    "plants",
+    "plashy",
+    "plated",
+    "plates",
+    "platys",
+    "please",
+    "plebby",
+    "pledge",
+    "pliant",
+    "plical",
+    "plover",
+    "plucks",
+    "plucky",
+    "plumes",
+    "plummy",
+    "plumps",
+    "plunge",
+    "poachs",
+    "poachy",
+    "pocked",
+    "poetic",
+    "points",
+    "poised",
+    "pokers",
+    "pokier",
+    "pokies",
+    "pokily",
+    "polars",
+    "police",
+    "polios",
+    "polite",
+    "polkas",
+    "polled",
+    "pollys",
+    "pommae",
+    "poodle",
+    "poorly",
+    "popish",
+    "porchs",
+    "porgys",
+    "porous",
+    "porsha",
+    "porter",
+    "portia",
+    "portly",
+    "poshly",
+    "posits",
+    "possum",
+    "postal",
+    "potato",
+    "potent",
+    "potted",
+    "pouchs",
+    "pounds",
+    "powder",
+    "praise",
+    "pranks",
+    "prawns",
+    "prefer",
+    "pretty",
+    "prewar",
+    "prices",
+    "pricey",
+    "pricks",
+    "prides",
+    "primal",
+    "primed",
+    "primes",
+    "primly",
+    "prince",
+    "prions",
+    "priors",
+    "prisms",
+    "prison",
+    "prissy",
+    "proart",
+    "profit",
+    "prolix",
+    "prompt",
+    "prongs",
+    "proofs",
+    "proper",
+    "proses",
+    "prossy",
+    "protax",
+    "prouds",
+    "proven",
+    "prowar",
+    "prowed",
+    "prowls",
+    "prudes",
+    "psalms",
+    "ptotic",
+    "public",
+    "puddly",
+    "puffer",
+    "puffin",
+    "puisne",
+    "pukeds",
+    "puling",
+    "pumped",
+    "punchs",
+    "punchy",
+    "punier",
+    "punily",
+    "pupils",
+    "purely",
+    "purest",
+    "purges",
+    "purity",
+    "purply",
+    "pushed",
+    "pussly",
+    "putrid",
+    "puzzle",
+    "pyemic",
+    "pyknic",
+    "pylons",
+    "python",
+    "qianas",
+    "quacks",
+    "quagga",
+    "quaggy",
+    "quahog",
+    "quails",
+    "quaint",
+    "quakes",
+    "qualms",
+    "quarks",
+    "quarts",
+    "quashs",
+    "queasy",
+    "queens",
+    "queers",
+    "quells",
+    "queues",
+    "quiana",
+    "quicks",
+    "quiets",
+    "quills",
+    "quilts",
+    "quincy",
+    "quinns",
+    "quirks",
+    "quites",
+    "quotas",
+    "quotes",
+    "quyens",
+    "rabbis",
+    "rabbit",
+    "rabids",
+    "racers",
+    "rachal",
+    "rachel",
+    "racial",
+    "racier",
+    "racily",
+    "rackle",
+    "radias",
+    "raeann",
+    "raegan",
+    "raelyn",
+    "rafael",
+    "ragged",
+    "raguel",
+    "raiden",
+    "rainas",
+    "raisas",
+    "raises",
+    "rakeds",
+    "rakish",
+    "ralphs",
+    "ramiro",
+    "ramona",
+    "ramons",
+    "ramose",
+    "ranaes",
+    "ranchs",
+    "rancid",
+    "randal",
+    "randas",
+    "randee",
+    "randis",
+    "random",
+    "randys",
+    "ranees",
+    "rankly",
+    "raptly",
+    "raptor",
+    "raquel",
+    "rarely",
+    "rarest",
+    "raring",
+    "rashad",
+    "rashly",
+    "ratels",
+    "rather",
+    "ratios",
+    "rattly",
+    "ravens",
+    "rawish",
+    "rayans",
+    "rayden",
+    "raylan",
+    "raymon",
+    "raynas",
+    "raynes",
+    "razors",
+    "reachs",
+    "reacts",
+    "reagan",
+    "really",
+    "reanna",
+    "reason",
+    "reatha",
+    "rebars",
+    "rebeca",
+    "rebels",
+    "reborn",
+    "recall",
+    "recaps",
+    "recent",
+    "recipe",
+    "record",
+    "rectal",
+    "redder",
+    "reduce",
+    "reeces",
+    "reenas",
+    "reeses",
+    "refers",
+    "reform",
+    "refuse",
+    "regans",
+    "regena",
+    "reggie",
+    "regina",
+    "regine",
+    "region",
+    "regnal",
+    "regret",
+    "reigns",
+    "reikos",
+    "reinas",
+    "reitas",
+    "reject",
+    "relics",
+    "relief",
+    "remain",
+    "remind",
+    "remiss",
+    "remixs",
+    "remona",
+    "remora",
+    "remove",
+    "renaes",
+    "renata",
+    "renate",
+    "renato",
+    "renays",
+    "rendas",
+    "render",
+    "reneas",
+    "renees",
+    "renita",
+    "rennas",
+    "reopen",
+    "repair",
+    "repand",
+    "repeat",
+    "repels",
+    "report",
+    "rescue",
+    "resets",
+    "resist",
+    "ressie",
+    "rested",
+    "result",
+    "rethas",
+    "retial",
+    "retire",
+    "retral",
+    "rettas",
+    "return",
+    "retuse",
+    "reuben",
+    "reuses",
+    "reveal",
+    "revels",
+    "review",
+    "reward",
+    "reynas",
+    "rhebas",
+    "rhetts",
+    "rheumy",
+    "rhinal",
+    "rhinos",
+    "rhodas",
+    "rhodic",
+    "rhonas",
+    "rhonda",
+    "rhotic",
+    "rhymes",
+    "rhythm",
+    "ribbon",
+    "richie",
+    "richly",
+    "rickey",
+    "rickie",
+    "rickis",
+    "rickys",
+    "rident",
+    "riders",
+    "ridges",
+    "rifely",
+    "rifles",
+    "rights",
+    "rigids",
+    "rigors",
+    "rikkis",
+    "rileys",
+    "rimose",
+    "ringed",
+    "ripely",
+    "ripens",
+    "ripple",
+    "ripply",
+    "risens",
+    "risers",
+    "ritual",
+    "rivals",
+    "rivens",
+    "rivers",
+    "rivets",
+    "rivkas",
+    "roachs",
+    "roasts",
+    "robbie",
+    "robbin",
+    "robbis",
+    "robbyn",
+    "robbys",
+    "robena",
+    "robert",
+    "robins",
+    "robust",
+    "robyns",
+    "roccos",
+    "rochel",
+    "rocios",
+    "rocket",
+    "rockys",
+    "rodent",
+    "rodeos",
+    "rodger",
+    "rodney",
+    "rogers",
+    "rogues",
+    "rohans",
+    "roland",
+    "roller",
+    "romana",
+    "romans",
+    "romeos",
+    "romona",
+    "ronald",
+    "ronans",
+    "rondas",
+    "ronins",
+    "ronnas",
+    "ronnie",
+    "ronnis",
+    "ronnys",
+    "rookie",
+    "roosts",
+    "rooted",
+    "ropier",
+    "ropily",
+    "rosana",
+    "rosann",
+    "roscoe",
+    "rosena",
+    "rosias",
+    "rosies",
+    "rosily",
+    "rosina",
+    "rosiny",
+    "rosios",
+    "rosita",
+    "roslyn",
+    "rossie",
+    "rotate",
+    "rotten",
+    "rotund",
+    "roughs",
+    "roughy",
+    "roupet",
+    "roused",
+    "rouses",
+    "rowans",
+    "rowena",
+    "rowens",
+    "rowers",
+    "roxana",
+    "roxane",
+    "roxann",
+    "roxies",
+    "royals",
+    "royces",
+    "rubber",
+    "rubbly",
+    "rubens",
+    "rubied",
+    "rubies",
+    "rubins",
+    "rubyes",
+    "rudely",
+    "rudish",
+    "rudolf",
+    "rueben",
+    "rueful",
+    "ruffed",
+    "ruffly",
+    "rufina",
+    "rufous",
+    "rugged",
+    "rugose",
+    "ruleds",
+    "rulers",
+    "ruling",
+    "rumbly",
+    "rumply",
+    "runway",
+    "rupert",
+    "russel",
+    "rustys",
+    "ruthas",
+    "ruthes",
+    "ruthie",
+    "ryanns",
+    "ryders",
+    "rykers",
+    "ryland",
+    "rylans",
+    "rylees",
+    "rylies",
+    "sabers",
+    "sabina",
+    "sabine",
+    "sables",
+    "sabras",
+    "sabres",
+    "sachas",
+    "sacral",
+    "sacred",
+    "saddle",
+    "sadies",
+    "sadyes",
+    "safely",
+    "sagely",
+    "saiges",
+    "salads",
+    "salena",
+    "salina",
+    "salley",
+    "sallie",
+    "sallys",
+    "salmas",
+    "salmon",
+    "salome",
+    "salons",
+    "salsas",
+    "salted",
+    "salute",
+    "samara",
+    "sambar",
+    "samira",
+    "samirs",
+    "sammie",
+    "sammys",
+    "sample",
+    "samson",
+    "samual",
+    "samuel",
+    "sandas",
+    "sanded",
+    "sandee",
+    "sandie",
+    "sandis",
+    "sandra",
+    "sandys",
+    "sanely",
+    "sanest",
+    "saniya",
+    "sanora",
+    "santas",
+    "santos",
+    "sarahi",
+    "sarahs",
+    "sarais",
+    "sarans",
+    "sariah",
+    "sarina",
+    "sarita",
+    "sashas",
+    "satiny",
+    "satyrs",
+    "sauces",
+    "sauger",
+    "saunas",
+    "sauncy",
+    "saurys",
+    "saveds",
+    "saving",
+    "sawfly",
+    "sawyer",
+    "saylor",
+    "scabby",
+    "scalds",
+    "scales",
+    "scalps",
+    "scenes",
+    "scenic",
+    "scents",
+    "scheme",
+    "school",
+    "scolds",
+    "scones",
+    "scoops",
+    "scopes",
+    "scores",
+    "scorns",
+    "scoter",
+    "scotts",
+    "scotty",
+    "scours",
+    "scouts",
+    "scowls",
+    "screen",
+    "screws",
+    "screwy",
+    "script",
+    "scrubs",
+    "scrums",
+    "scubas",
+    "scuffs",
+    "scungy",
+    "seamus",
+    "search",
+    "season",
+    "second",
+    "secret",
+    "secund",
+    "secure",
+    "sedans",
+    "sedged",
+    "seemas",
+    "seemly",
+    "segues",
+    "seizes",
+    "sejant",
+    "selahs",
+    "seldom",
+    "select",
+    "selena",
+    "selene",
+    "selina",
+    "selmas",
+    "semajs",
+    "senary",
+    "senile",
+    "senior",
+    "senses",
+    "sepias",
+    "septal",
+    "serena",
+    "serene",
+    "sergio",
+    "series",
+    "serifs",
+    "serina",
+    "serita",
+    "serous",
+    "serval",
+    "servos",
+    "setose",
+    "setter",
+    "settle",
+    "sevens",
+    "severe",
+    "severs",
+    "sewers",
+    "shabby",
+    "shacks",
+    "shades",
+    "shadow",
+    "shafts",
+    "shaggy",
+    "shaina",
+    "shakes",
+    "shakia",
+    "shalas",
+    "shales",
+    "shalls",
+    "shalon",
+    "shames",
+    "shanae",
+    "shanas",
+    "shanda",
+    "shandi",
+    "shanel",
+    "shanes",
+    "shanis",
+    "shanks",
+    "shanna",
+    "shanon",
+    "shanta",
+    "shante",
+    "shanti",
+    "shapes",
+    "sharan",
+    "sharas",
+    "sharda",
+    "shards",
+    "sharee",
+    "sharen",
+    "shares",
+    "sharie",
+    "sharis",
+    "sharks",
+    "sharla",
+    "sharon",
+    "sharps",
+    "sharri",
+    "sharyl",
+    "sharyn",
+    "shasta",
+    "shauna",
+    "shauns",
+    "shaven",
+    "shaves",
+    "shavon",
+    "shawls",
+    "shawna",
+    "shawns",
+    "shayla",
+    "shayna",
+    "shayne",
+    "shears",
+    "shebas",
+    "sheena",
+    "sheens",
+    "sheeps",
+    "sheila",
+    "shelas",
+    "shelba",
+    "shelby",
+    "shelfs",
+    "shelia",
+    "shella",
+    "shelli",
+    "shells",
+    "shelly",
+    "shenas",
+    "shenna",
+    "sheras",
+    "sheree",
+    "sherie",
+    "sheris",
+    "sherly",
+    "sheron",
+    "sherri",
+    "sherry",
+    "sheryl",
+    "sherys",
+    "shieds",
+    "shiela",
+    "shield",
+    "shiest",
+    "shifts",
+    "shifty",
+    "shilas",
+    "shiloh",
+    "shiner",
+    "shines",
+    "shiras",
+    "shires",
+    "shirks",
+    "shirls",
+    "shirly",
+    "shirts",
+    "shirty",
+    "shiver",
+    "shizue",
+    "shoaly",
+    "shocks",
+    "shonas",
+    "shonda",
+    "shonna",
+    "shonta",
+    "shores",
+    "shoves",
+    "showns",
+    "shrewd",
+    "shrews",
+    "shrike",
+    "shrimp",
+    "shrubs",
+    "shrugs",
+    "shyest",
+    "shylas",
+    "sibyls",
+    "sickly",
+    "sickos",
+    "sidney",
+    "sieges",
+    "sienas",
+    "sienna",
+    "sierra",
+    "sights",
+    "sigils",
+    "sigmas",
+    "signes",
+    "sigrid",
+    "silent",
+    "silken",
+    "silvas",
+    "silver",
+    "silvia",
+    "simona",
+    "simone",
+    "simons",
+    "simple",
+    "simply",
+    "sinces",
+    "sindys",
+    "sinews",
+    "sinewy",
+    "sinful",
+    "singes",
+    "singly",
+    "sireds",
+    "sirena",
+    "sirens",
+    "sirupy",
+    "siskin",
+    "sister",
+    "sitars",
+    "sixtas",
+    "sixths",
+    "sizier",
+    "skanks",
+    "skater",
+    "skates",
+    "skeins",
+    "sketch",
+    "skiffs",
+    "skimps",
+    "skimpy",
+    "skinks",
+    "skirts",
+    "skulls",
+    "skunks",
+    "skylar",
+    "skylas",
+    "skyler",
+    "slacks",
+    "slaggy",
+    "slains",
+    "slakes",
+    "slangs",
+    "slangy",
+    "slants",
+    "slashs",
+    "slaves",
+    "sleazy",
+    "sleeks",
+    "sleeky",
+    "sleeps",
+    "sleepy",
+    "sleets",
+    "sleety",
+    "slepts",
+    "slices",
+    "slicks",
+    "slides",
+    "sliest",
+    "slight",
+    "slimes",
+    "slimly",
+    "slimsy",
+    "slings",
+    "slinks",
+    "slinky",
+    "slippy",
+    "sloane",
+    "sloans",
+    "slogan",
+    "slopes",
+    "sloppy",
+    "sloshs",
+    "sloshy",
+    "sloths",
+    "slower",
+    "slowly",
+    "slumps",
+    "slyvia",
+    "smacks",
+    "smalls",
+    "smarms",
+    "smarmy",
+    "smarts",
+    "smarty",
+    "smashs",
+    "smears",
+    "smeary",
+    "smeeky",
+    "smells",
+    "smelly",
+    "smelts",
+    "smiles",
+    "smirks",
+    "smiths",
+    "smocks",
+    "smokes",
+    "smooth",
+    "smugly",
+    "snacks",
+    "snafus",
+    "snaggy",
+    "snails",
+    "snakes",
+    "snappy",
+    "snares",
+    "snarks",
+    "snarls",
+    "snarly",
+    "snazzy",
+    "sneaks",
+    "sneaky",
+    "snider",
+    "sniffs",
+    "sniffy",
+    "snipes",
+    "snippy",
+    "snodly",
+    "snooks",
+    "snoopy",
+    "snooty",
+    "snoozy",
+    "snores",
+    "snorts",
+    "snubby",
+    "snuffs",
+    "snuffy",
+    "snugly",
+    "soaked",
+    "sobers",
+    "soccer",
+    "social",
+    "sofias",
+    "softer",
+    "softly",
+    "soigna",
+    "soilas",
+    "solars",
+    "solely",
+    "solemn",
+    "solves",
+    "somber",
+    "sombre",
+    "somers",
+    "sommer",
+    "sondra",
+    "sonias",
+    "sonics",
+    "sonjas",
+    "sonnys",
+    "sonyas",
+    "sophia",
+    "sophie",
+    "soraya",
+    "sorbic",
+    "sordid",
+    "sorely",
+    "sorens",
+    "sorest",
+    "sotted",
+    "sought",
+    "sounds",
+    "source",
+    "sourly",
+    "spaced",
+    "spades",
+    "spanks",
+    "spares",
+    "sparks",
+    "sparry",
+    "sparse",
+    "spasms",
+    "spates",
+    "spawns",
+    "speaks",
+    "spears",
+    "speeds",
+    "speedy",
+    "spells",
+    "spends",
+    "spents",
+    "sphere",
+    "sphery",
+    "spices",
+    "spider",
+    "spieds",
+    "spiffy",
+    "spikes",
+    "spills",
+    "spined",
+    "spines",
+    "spires",
+    "spirit",
+    "spitzs",
+    "splits",
+    "spoils",
+    "spokes",
+    "sponge",
+    "spongy",
+    "spoofs",
+    "spooks",
+    "spooky",
+    "spools",
+    "spoons",
+    "spoors",
+    "sporal",
+    "sporty",
+    "spotty",
+    "spouts",
+    "sprats",
+    "spread",
+    "sprees",
+    "sprews",
+    "sprier",
+    "sprigs",
+    "spring",
+    "spruce",
+    "spryer",
+    "spryly",
+    "squads",
+    "square",
+    "squats",
+    "squids",
+    "squshy",
+    "stable",
+    "stably",
+    "stacee",
+    "stacey",
+    "stacia",
+    "stacie",
+    "stacis",
+    "stacks",
+    "stacys",
+    "staffs",
+    "stages",
+    "stagey",
+    "staids",
+    "stains",
+    "stairs",
+    "stakes",
+    "stales",
+    "stalks",
+    "stalky",
+    "stalls",
+    "stamps",
+    "stands",
+    "stares",
+    "starks",
+    "starla",
+    "starrs",
+    "starry",
+    "starts",
+    "stashs",
+    "stasia",
+    "stated",
+    "states",
+    "staves",
+    "steads",
+    "steady",
+    "steaks",
+    "steals",
+    "steams",
+    "steamy",
+    "steely",
+    "stefan",
+    "stelar",
+    "stella",
+    "stereo",
+    "steric",
+    "sterns",
+    "steven",
+    "steves",
+    "stevie",
+    "stewed",
+    "stiles",
+    "stills",
+    "stilly",
+    "stilts",
+    "stings",
+    "stinko",
+    "stinks",
+    "stints",
+    "stoats",
+    "stocks",
+    "stocky",
+    "stodgy",
+    "stoics",
+    "stokes",
+    "stoles",
+    "stolid",
+    "stomps",
+    "stoned",
+    "stoney",
+    "stoods",
+    "stools",
+    "stoops",
+    "stores",
+    "storks",
+    "storms",
+    "stormy",
+    "stouts",
+    "stoves",
+    "straps",
+    "straws",
+    "street",
+    "strict",
+    "strike",
+    "strips",
+    "stripy",
+    "strong",
+    "strung",
+    "stuart",
+    "studly",
+    "stuffs",
+    "stuffy",
+    "stumps",
+    "stumpy",
+    "stunts",
+    "stunty",
+    "sturdy",
+    "sturty",
+    "stylar",
+    "styles",
+    "suable",
+    "suably",
+    "suanne",
+    "suaves",
+    "submit",
+    "subpar",
+    "subtle",
+    "subtly",
+    "subway",
+    "sudden",
+    "sudies",
+    "sueann",
+    "suffer",
+    "sugars",
+    "sugary",
+    "suings",
+    "suited",
+    "suites",
+    "sulema",
+    "sultry",
+    "sumiko",
+    "summer",
+    "sunday",
+    "sunlit",
+    "sunnis",
+    "sunnys",
+    "sunset",
+    "superb",
+    "supple",
+    "supply",
+    "surely",
+    "surest",
+    "surges",
+    "survey",
+    "susana",
+    "susann",
+    "susans",
+    "sushis",
+    "susies",
+    "sutton",
+    "suzann",
+    "suzans",
+    "suzies",
+    "svelte",
+    "swamps",
+    "swampy",
+    "swanks",
+    "swarms",
+    "swaths",
+    "swears",
+    "sweats",
+    "sweaty",
+    "sweeps",
+    "swepts",
+    "swifts",
+    "swills",
+    "swines",
+    "swings",
+    "swipes",
+    "swirls",
+    "swirly",
+    "swishy",
+    "switch",
+    "swoons",
+    "swoops",
+    "swords",
+    "swores",
+    "sworns",
+    "sybils",
+    "sybles",
+    "sydney",
+    "sylphs",
+    "sylphy",
+    "sylvia",
+    "sylvie",
+    "symbol",
+    "syrups",
+    "syrupy",
+    "system",
+    "tables",
+    "taboos",
+    "tackle",
+    "tainas",
+    "taints",
+    "taipan",
+    "taisha",
+    "takako",
+    "takens",
+    "takins",
+    "talent",
+    "talias",
+    "talons",
+    "tamala",
+    "tamara",
+    "tamars",
+    "tambra",
+    "tameds",
+    "tameka",
+    "tamela",
+    "tamely",
+    "tamera",
+    "tamest",
+    "tamias",
+    "tamica",
+    "tamies",
+    "tamika",
+    "tamiko",
+    "taming",
+    "tammie",
+    "tammis",
+    "tammys",
+    "tamras",
+    "tandra",
+    "tandys",
+    "taneka",
+    "tangly",
+    "tangos",
+    "tanias",
+    "tanika",
+    "tanjas",
+    "tanked",
+    "tannas",
+    "tanner",
+    "tannic",
+    "tanyas",
+    "tapeds",
+    "tapers",
+    "tapirs",
+    "tarahs",
+    "tarens",
+    "target",
+    "tarots",
+    "tarpon",
+    "tarras",
+    "tarsha",
+    "tartly",
+    "taryns",
+    "tashas",
+    "tashia",
+    "tasias",
+    "tastes",
+    "taters",
+    "tattoo",
+    "tatums",
+    "taunts",
+    "taunya",
+    "tautly",
+    "tawana",
+    "tawdry",
+    "tawnas",
+    "tawnya",
+    "tawnys",
+    "taxeds",
+    "taylor",
+    "taynas",
+    "tayras",
+    "teachs",
+    "teagan",
+    "teases",
+    "teddys",
+    "teenas",
+    "teensy",
+    "teeths",
+    "tegans",
+    "teisha",
+    "telial",
+    "telmas",
+    "temeka",
+    "temika",
+    "tempie",
+    "temple",
+    "tempos",
+    "tenant",
+    "tenchs",
+    "tender",
+    "tenley",
+    "tennie",
+    "tennis",
+    "tenors",
+    "tenrec",
+    "tensed",
+    "tenser",
+    "tenses",
+    "tented",
+    "tenths",
+    "tentie",
+    "terbic",
+    "teresa",
+    "terese",
+    "terete",
+    "tergal",
+    "terica",
+    "terina",
+    "terisa",
+    "termly",
+    "terras",
+    "terrie",
+    "terris",
+    "terrys",
+    "teshas",
+    "tessas",
+    "tessie",
+    "tetchy",
+    "tetras",
+    "thalia",
+    "thanhs",
+    "thecal",
+    "thedas",
+    "thefts",
+    "theirs",
+    "thelma",
+    "theola",
+    "theory",
+    "theres",
+    "theron",
+    "thersa",
+    "theses",
+    "thetic",
+    "thiago",
+    "thicks",
+    "thiefs",
+    "thighs",
+    "things",
+    "thinks",
+    "thinly",
+    "thirds",
+    "thomas",
+    "thongs",
+    "thoras",
+    "thoric",
+    "thorns",
+    "thorny",
+    "thoses",
+    "thrawn",
+    "threes",
+    "thresa",
+    "threws",
+    "thrice",
+    "thrive",
+    "throbs",
+    "throws",
+    "thrush",
+    "thumbs",
+    "thumps",
+    "thusly",
+    "thymes",
+    "thymic",
+    "tianas",
+    "tianna",
+    "tiaras",
+    "tibial",
+    "ticket",
+    "tiddly",
+    "tidily",
+    "tieras",
+    "tierra",
+    "tiesha",
+    "tifany",
+    "tigers",
+    "tights",
+    "tiglic",
+    "tildas",
+    "tileds",
+    "tillie",
+    "timber",
+    "timeds",
+    "timely",
+    "timers",
+    "timids",
+    "timika",
+    "timmys",
+    "tineal",
+    "tingly",
+    "tinier",
+    "tinily",
+    "tinkly",
+    "tinley",
+    "tinned",
+    "tinpot",
+    "tireds",
+    "tishas",
+    "tissue",
+    "tithes",
+    "titles",
+    "toasts",
+    "tobias",
+    "tobies",
+    "togaed",
+    "togate",
+    "toilet",
+    "tokens",
+    "toluic",
+    "tomasa",
+    "tomato",
+    "tomcat",
+    "tomeka",
+    "tomial",
+    "tomika",
+    "tomiko",
+    "tommie",
+    "tommye",
+    "tommys",
+    "tomoko",
+    "tondas",
+    "toneys",
+    "tongue",
+    "tonias",
+    "tonics",
+    "tonier",
+    "tonies",
+    "tonish",
+    "tonita",
+    "tonjas",
+    "tonyas",
+    "tooths",
+    "toothy",
+    "topics",
+    "topple",
+    "torchs",
+    "torchy",
+    "tories",
+    "tornly",
+    "torose",
+    "torpid",
+    "torrid",
+    "torrie",
+    "torris",
+    "torsos",
+    "toshas",
+    "toshia",
+    "totals",
+    "toteds",
+    "toucan",
+    "touchs",
+    "touchy",
+    "toughs",
+    "touted",
+    "toward",
+    "towels",
+    "towers",
+    "towery",
+    "towhee",
+    "toxics",
+    "toxins",
+    "toyeds",
+    "tracee",
+    "traces",
+    "tracey",
+    "tracie",
+    "tracis",
+    "tracks",
+    "tracts",
+    "tracys",
+    "trades",
+    "tragic",
+    "trails",
+    "trains",
+    "traits",
+    "tramps",
+    "trangs",
+    "trappy",
+    "trashs",
+    "trashy",
+    "travel",
+    "travis",
+    "trawls",
+    "treads",
+    "treasa",
+    "treats",
+    "trebly",
+    "treena",
+    "trenas",
+    "trends",
+    "trents",
+    "tresas",
+    "tressa",
+    "tressy",
+    "trevas",
+    "trevor",
+    "triads",
+    "trials",
+    "tribal",
+    "tribes",
+    "tricia",
+    "tricks",
+    "tricky",
+    "trieds",
+    "trifid",
+    "trigly",
+    "trills",
+    "trimly",
+    "trinal",
+    "trinas",
+    "trinhs",
+    "triply",
+    "tripps",
+    "trisha",
+    "trishs",
+    "trista",
+    "triste",
+    "triter",
+    "trites",
+    "trogon",
+    "trolls",
+    "trolly",
+    "troops",
+    "trophy",
+    "trotty",
+    "trouts",
+    "truces",
+    "trucks",
+    "trudie",
+    "trudis",
+    "trudys",
+    "truing",
+    "trulas",
+    "truman",
+    "trumps",
+    "trunks",
+    "trusts",
+    "trusty",
+    "truths",
+    "trying",
+    "trysts",
+    "tubate",
+    "tubers",
+    "tuboid",
+    "tucker",
+    "tufted",
+    "tumble",
+    "tumors",
+    "tuners",
+    "tunics",
+    "tunnel",
+    "tunnys",
+    "turaco",
+    "turbid",
+    "turbos",
+    "turbot",
+    "turgid",
+    "turkey",
+    "turner",
+    "turtle",
+    "tushed",
+    "tussal",
+    "tutors",
+    "tuyets",
+    "twanas",
+    "twanda",
+    "twangs",
+    "twanna",
+    "tweaks",
+    "tweeds",
+    "tweedy",
+    "tweets",
+    "twelve",
+    "twenty",
+    "twerps",
+    "twices",
+    "twiggy",
+    "twilas",
+    "twines",
+    "twirls",
+    "twirly",
+    "twists",
+    "twylas",
+    "tyesha",
+    "tyings",
+    "tyisha",
+    "tylers",
+    "typeds",
+    "tyrees",
+    "tyrell",
+    "tyrone",
+    "tyrons",
+    "tysons",
+    "uglily",
+    "ugsome",
+    "ulcers",
+    "ulises",
+    "ulrike",
+    "ultras",
+    "umbral",
+    "umteen",
+    "unable",
+    "unaged",
+    "unarms",
+    "unavid",
+    "unawed",
+    "unaxed",
+    "unbase",
+    "unbled",
+    "unbold",
+    "unborn",
+    "unbred",
+    "uncalm",
+    "uncaps",
+    "uncast",
+    "unchid",
+    "unclad",
+    "uncles",
+    "uncuts",
+    "undeaf",
+    "undeep",
+    "undeft",
+    "undewy",
+    "undids",
+    "undone",
+    "unduly",
+    "undyed",
+    "uneasy",
+    "unepic",
+    "uneven",
+    "unevil",
+    "unfair",
+    "unfelt",
+    "unfine",
+    "unfirm",
+    "unfits",
+    "unflat",
+    "unfold",
+    "unfond",
+    "unfoul",
+    "unfoxy",
+    "unfull",
+    "ungamy",
+    "ungilt",
+    "unglad",
+    "unglib",
+    "ungual",
+    "unhale",
+    "unhazy",
+    "unheld",
+    "unhewn",
+    "unhoed",
+    "unholy",
+    "unhued",
+    "unhung",
+    "unhurt",
+    "unidle",
+    "unidly",
+    "unific",
+    "unions",
+    "unique",
+    "united",
+    "unites",
+    "unjust",
+    "unkept",
+    "unkind",
+    "unlaid",
+    "unlame",
+    "unlean",
+    "unleft",
+    "unlent",
+    "unlimp",
+    "unlits",
+    "unlock",
+    "unlost",
+    "unlush",
+    "unmeet",
+    "unmiry",
+    "unmown",
+    "unneat",
+    "unnice",
+    "unnigh",
+    "unoily",
+    "unoral",
+    "unpaid",
+    "unpale",
+    "unpent",
+    "unprim",
+    "unpure",
+    "unrash",
+    "unread",
+    "unreal",
+    "unrent",
+    "unrife",
+    "unripe",
+    "unrude",
+    "unrued",
+    "unruly",
+    "unrung",
+    "unsafe",
+    "unsage",
+    "unsaid",
+    "unsawn",
+    "unseen",
+    "unsent",
+    "unsere",
+    "unshed",
+    "unshod",
+    "unshut",
+    "unsick",
+    "unslim",
+    "unslow",
+    "unsmug",
+    "unsnug",
+    "unsoft",
+    "unsore",
+    "unsour",
+    "unsown",
+    "unspun",
+    "unsued",
+    "unsung",
+    "unsunk",
+    "unsure",
+    "untame",
+    "untaut",
+    "unties",
+    "untils",
+    "untold",
+    "untorn",
+    "untrig",
+    "untrod",
+    "untrue",
+    "unused",
+    "unvain",
+    "unveil",
+    "unvoid",
+    "unwary",
+    "unwept",
+    "unwild",
+    "unwily",
+    "unwise",
+    "unworn",
+    "upbeat",
+    "update",
+    "upends",
+    "uphold",
+    "upmost",
+    "uppers",
+    "uppish",
+    "uppity",
+    "upsets",
+    "uptown",
+    "upward",
+    "uranic",
+    "uratic",
+    "urbane",
+    "urchin",
+    "ureido",
+    "uremic",
+    "uretic",
+    "urgent",
+    "uriahs",
+    "urials",
+    "uriels",
+    "urijah",
+    "urines",
+    "ursine",
+    "ursula",
+    "usable",
+    "usably",
+    "usages",
+    "useful",
+    "ushers",
+    "usings",
+    "usuals",
+    "usurps",
+    "utmost",
+    "vacant",
+    "vacuum",
+    "vadose",
+    "vagrom",
+    "vaguer",
+    "vagues",
+    "vainly",
+    "valdas",
+    "valene",
+    "valeri",
+    "valery",
+    "valets",
+    "valgus",
+    "valids",
+    "valley",
+    "vallie",
+    "valors",
+    "valrie",
+    "valued",
+    "values",
+    "valval",
+    "valvar",
+    "valves",
+    "vances",
+    "vandas",
+    "vanesa",
+    "vanias",
+    "vanish",
+    "vanita",
+    "vannas",
+    "vapids",
+    "vapors",
+    "vapory",
+    "varied",
+    "vashti",
+    "vastly",
+    "vaughn",
+    "vaults",
+    "vaunty",
+    "veerys",
+    "vegans",
+    "veiled",
+    "veinal",
+    "velate",
+    "veldas",
+    "velias",
+    "vellas",
+    "velmas",
+    "velvas",
+    "velvet",
+    "venals",
+    "vendor",
+    "venial",
+    "venice",
+    "venita",
+    "vennie",
+    "venoms",
+    "venose",
+    "venous",
+    "venues",
+    "veolas",
+    "verdas",
+    "verdie",
+    "verdin",
+    "verena",
+    "verges",
+    "vergie",
+    "verier",
+    "verify",
+    "verily",
+    "verlas",
+    "verlie",
+    "vernal",
+    "vernas",
+    "vernia",
+    "vernie",
+    "vernon",
+    "verona",
+    "versed",
+    "verses",
+    "versie",
+    "vertie",
+    "verves",
+    "vervet",
+    "vessel",
+    "vestas",
+    "vested",
+    "vexeds",
+    "viable",
+    "viably",
+    "vickey",
+    "vickie",
+    "vickis",
+    "vickys",
+    "victor",
+    "vicuna",
+    "vigils",
+    "vihaan",
+    "vikkis",
+    "vilely",
+    "vilmas",
+    "vinces",
+    "vinita",
+    "vinnie",
+    "vinous",
+    "vinyls",
+    "violas",
+    "violet",
+    "violin",
+    "vipers",
+    "virals",
+    "vireos",
+    "virgen",
+    "virgie",
+    "virgil",
+    "virile",
+    "virled",
+    "viscid",
+    "vistas",
+    "visual",
+    "vitals",
+    "vitric",
+    "vivans",
+    "vivian",
+    "vivids",
+    "vivien",
+    "vixens",
+    "vocals",
+    "vogues",
+    "voiced",
+    "voices",
+    "voided",
+    "volant",
+    "volume",
+    "vomits",
+    "vondas",
+    "vonnie",
+    "vorant",
+    "voteds",
+    "voters",
+    "votive",
+    "vouchs",
+    "voweds",
+    "vowels",
+    "vowely",
+    "voyage",
+    "vulgar",
+    "vulned",
+    "vyings",
+    "wabbly",
+    "waddly",
+    "wadeds",
+    "wafery",
+    "waffly",
+    "wageds",
+    "wagers",
+    "waggly",
+    "wagons",
+    "wahoos",
+    "waists",
+    "waldos",
+    "walker",
+    "wallys",
+    "walnut",
+    "walrus",
+    "walter",
+    "walton",
+    "waltzs",
+    "wambly",
+    "wandas",
+    "waneds",
+    "waneta",
+    "wanier",
+    "wanita",
+    "wanner",
+    "wanted",
+    "wapiti",
+    "warded",
+    "warier",
+    "warily",
+    "warmly",
+    "warner",
+    "warren",
+    "washed",
+    "wastes",
+    "watchs",
+    "waters",
+    "watery",
+    "waveds",
+    "wavier",
+    "wavily",
+    "waxeds",
+    "waxens",
+    "waxier",
+    "waxily",
+    "waylon",
+    "waynes",
+    "weakly",
+    "wealth",
+    "weapon",
+    "weasel",
+    "webbed",
+    "wedded",
+    "wedges",
+    "weekly",
+    "weevil",
+    "weighs",
+    "weirds",
+    "weldon",
+    "wenchs",
+    "wendie",
+    "wendis",
+    "wendys",
+    "wenona",
+    "werner",
+    "wesley",
+    "westin",
+    "weston",
+    "whacks",
+    "whacky",
+    "whales",
+    "wheats",
+    "wheels",
+    "wheezy",
+    "whelps",
+    "wheres",
+    "wheyey",
+    "whichs",
+    "whiffs",
+    "whines",
+    "whirls",
+    "whirly",
+    "whited",
+    "whites",
+    "wholes",
+    "wholly",
+    "whorls",
+    "whoses",
+    "whydah",
+    "wiccas",
+    "wicked",
+    "widely",
+    "widens",
+    "widers",
+    "widest",
+    "widish",
+    "widows",
+    "widths",
+    "wields",
+    "wieldy",
+    "wifely",
+    "wigeon",
+    "wiggly",
+    "wilber",
+    "wilbur",
+    "wildas",
+    "wildly",
+    "wileys",
+    "wilful",
+    "wilier",
+    "wilily",
+    "willas",
+    "willed",
+    "willet",
+    "willia",
+    "willie",
+    "willis",
+    "willow",
+    "willys",
+    "wilmas",
+    "wilmer",
+    "wilson",
+    "wilton",
+    "winces",
+    "winchs",
+    "winded",
+    "window",
+    "windys",
+    "winged",
+    "winier",
+    "winish",
+    "winner",
+    "winnie",
+    "winona",
+    "winter",
+    "wintry",
+    "wipers",
+    "wireds",
+    "wirily",
+    "wisdom",
+    "wisely",
+    "wisest",
+    "witchs",
+    "witchy",
+    "witted",
+    "woaded",
+    "wobbly",
+    "woeful",
+    "womans",
+    "wombat",
+    "wombed",
+    "womens",
+    "wondas",
+    "wonder",
+    "wonted",
+    "wooded",
+    "woodsy",
+    "woolly",
+    "worked",
+    "worlds",
+    "worser",
+    "worses",
+    "worsts",
+    "worths",
+    "worthy",
+    "woulds",
+    "wounds",
+    "wovens",
+    "wracks",
+    "wrasse",
+    "wraths",
+    "wrecks",
+    "wriest",
+    "wrings",
+    "wrists",
+    "writes",
+    "wyatts",
+    "wynell",
+    "wynona",
+    "wynter",
+    "xander",
+    "xavier",
+    "xenias",
+    "xenons",
+    "ximena",
+    "xyloid",
+    "yachts",
+    "yachty",
+    "yadiel",
+    "yadira",
+    "yaekos",
+    "yahirs",
+    "yanira",
+    "yareli",
+    "yarely",
+    "yarest",
+    "yasmin",
+    "yasuko",
+    "yearly",
+    "yearns",
+    "yeasts",
+    "yeasty",
+    "yelena",
+    "yellow",
+    "yester",
+    "yettas",
+    "yields",
+    "yosefs",
+    "yoshie",
+    "youngs",
+    "yousef",
+    "yttric",
+    "yuette",
+    "yukiko",
+    "yuonne",
+    "yuriko",
+    "yusufs",
+    "yvette",
+    "yvones",
+    "yvonne",
+    "zahras",
+    "zaidas",
+    "zaiden",
+    "zainab",
+    "zaires",
+    "zander",
+    "zandra",
+    "zanier",
+    "zanies",
+    "zanily",
+    "zariah",
+    "zarias",
+    "zavier",
+    "zayden",
+    "zaynes",
+    "zebras",
+    "zeldas",
+    "zellas",
+    "zelmas",
+    "zenias",
+    "zeroth",
+    "zettas",
+    "zincic",
+    "zincky",
+    "zingel",
+    "zofias",
+    "zoilas",
+    "zonate",
+    "zonias",
+    "zonked",
+    "zoonal",
+    "zulema",
+    "zulmas"
+};
+
+
+static SEVEN_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "aaliyah",
+    "abaised",
+    "abandon",
+    "abaxial",
+    "abdiels",
+    "abeyant",
+    "abiding",
+    "abigail",
+    "ability",
+    "abiotic",
+    "aboulic",
+    "abraham",
+    "abusive",
+    "abysmal",
+    "abyssal",
+    "acaroid",
+    "acaudal",
+    "account",
+    "acerate",
+    "acerbic",
+    "acerose",
+    "acerous",
+    "acetous",
+    "achieve",
+    "acholic",
+    "aciform",
+    "acinous",
+    "acnodal",
+    "acorned",
+    "acquire",
+    "acridly",
+    "acronal",
+    "acrotic",
+    "actable",
+    "actinal",
+    "actinic",
+    "actorly",
+    "actress",
+    "acutely",
+    "acyclic",
+    "adagial",
+    "adaline",
+    "adalynn",
+    "adalyns",
+    "adapted",
+    "adaxial",
+    "addable",
+    "addedly",
+    "addible",
+    "addilyn",
+    "addison",
+    "addisyn",
+    "address",
+    "addyson",
+    "adelias",
+    "adelina",
+    "adeline",
+    "adellas",
+    "adelles",
+    "adelynn",
+    "adelyns",
+    "adeptly",
+    "adityas",
+    "admiral",
+    "admired",
+    "adolfos",
+    "adolphs",
+    "adopted",
+    "adoring",
+    "adorsed",
+    "adriana",
+    "adriane",
+    "adrians",
+    "adriels",
+    "adriene",
+    "adriens",
+    "adultly",
+    "advance",
+    "adverse",
+    "advised",
+    "aeneous",
+    "aeonian",
+    "aerobic",
+    "affable",
+    "affably",
+    "affinal",
+    "affined",
+    "affixal",
+    "aftmost",
+    "agathas",
+    "agatoid",
+    "ageless",
+    "agelong",
+    "ageusic",
+    "aggadic",
+    "agilely",
+    "agnatic",
+    "agoutis",
+    "agravic",
+    "aguedas",
+    "agustin",
+    "aidless",
+    "aileens",
+    "ailenes",
+    "aimless",
+    "ainsley",
+    "airiest",
+    "airless",
+    "airlike",
+    "airport",
+    "airsick",
+    "aiyanas",
+    "akilahs",
+    "alainas",
+    "alaines",
+    "alannah",
+    "alannas",
+    "alayahs",
+    "alaynas",
+    "alaysia",
+    "alberta",
+    "alberto",
+    "alberts",
+    "albinas",
+    "albinic",
+    "albitic",
+    "alcohol",
+    "aleases",
+    "alecias",
+    "aleenas",
+    "aleidas",
+    "aleigha",
+    "aleisha",
+    "alertly",
+    "aleshas",
+    "aleshia",
+    "alesias",
+    "alethas",
+    "alethea",
+    "alethia",
+    "alethic",
+    "alewife",
+    "alexias",
+    "alfonso",
+    "alfonzo",
+    "alfreda",
+    "alfredo",
+    "alfreds",
+    "algesic",
+    "algetic",
+    "alianas",
+    "alicias",
+    "alienly",
+    "aliform",
+    "alijahs",
+    "alishas",
+    "alishia",
+    "alisias",
+    "alisons",
+    "alissas",
+    "alisson",
+    "alivias",
+    "aliyahs",
+    "alkalic",
+    "alkylic",
+    "alleens",
+    "alleged",
+    "allegra",
+    "allelic",
+    "allenas",
+    "allenes",
+    "allines",
+    "allison",
+    "allonga",
+    "allowed",
+    "allylic",
+    "allyson",
+    "almedas",
+    "almetas",
+    "almondy",
+    "alodial",
+    "aloetic",
+    "alondra",
+    "alonsos",
+    "alonzos",
+    "aloofly",
+    "alpacas",
+    "already",
+    "altheas",
+    "alvaros",
+    "alveras",
+    "alverta",
+    "alvinas",
+    "alycias",
+    "alyshas",
+    "alysias",
+    "alysons",
+    "alyssas",
+    "alyvias",
+    "amalias",
+    "amandas",
+    "amateur",
+    "amative",
+    "amatory",
+    "amazing",
+    "amberly",
+    "ambient",
+    "ambrose",
+    "ameboid",
+    "amelias",
+    "amelies",
+    "amental",
+    "america",
+    "amiable",
+    "amiably",
+    "amirahs",
+    "amiyahs",
+    "ammonic",
+    "amoebas",
+    "amoebic",
+    "amorous",
+    "amparos",
+    "amplest",
+    "amusing",
+    "amusive",
+    "amyelic",
+    "anabels",
+    "anaemic",
+    "analyst",
+    "anatine",
+    "anchory",
+    "anchovy",
+    "ancient",
+    "anconal",
+    "anderas",
+    "andreas",
+    "andrees",
+    "andrews",
+    "andrias",
+    "anemone",
+    "anergic",
+    "anettes",
+    "aneuric",
+    "angelas",
+    "angeles",
+    "angelia",
+    "angelic",
+    "angella",
+    "angelos",
+    "angelyn",
+    "angerly",
+    "angilas",
+    "anginal",
+    "angleas",
+    "anglers",
+    "angoras",
+    "angrier",
+    "angrily",
+    "anguine",
+    "angular",
+    "anhinga",
+    "anibals",
+    "anishas",
+    "anissas",
+    "anitras",
+    "aniyahs",
+    "annabel",
+    "annalee",
+    "annamae",
+    "annelle",
+    "annetta",
+    "annette",
+    "annetts",
+    "annices",
+    "annikas",
+    "annitas",
+    "annular",
+    "anoetic",
+    "anomaly",
+    "anosmic",
+    "another",
+    "ansleys",
+    "antenna",
+    "anthony",
+    "anticly",
+    "antione",
+    "antique",
+    "antiwar",
+    "antlike",
+    "antlion",
+    "antoine",
+    "antones",
+    "antonia",
+    "antonio",
+    "antonys",
+    "antwans",
+    "anurous",
+    "anxiety",
+    "anxious",
+    "apelike",
+    "apetaly",
+    "aphacic",
+    "aphetic",
+    "aphonic",
+    "aphotic",
+    "aphylly",
+    "apishly",
+    "aplitic",
+    "apnoeal",
+    "apnoeic",
+    "apodous",
+    "apogeal",
+    "apollos",
+    "apology",
+    "appauma",
+    "applied",
+    "approve",
+    "apraxic",
+    "apsidal",
+    "apteral",
+    "aqueous",
+    "aquiver",
+    "araceli",
+    "aracely",
+    "arbored",
+    "arcelia",
+    "arcform",
+    "archaic",
+    "archers",
+    "archies",
+    "arcuate",
+    "ardaths",
+    "ardelia",
+    "ardella",
+    "ardelle",
+    "ardells",
+    "ardiths",
+    "arduous",
+    "areally",
+    "arenose",
+    "areolar",
+    "arethas",
+    "argelia",
+    "argotic",
+    "arianas",
+    "arianes",
+    "arianna",
+    "arianne",
+    "ariella",
+    "arielle",
+    "ariyahs",
+    "arkosic",
+    "arleans",
+    "arleens",
+    "arlenas",
+    "arlenes",
+    "arletha",
+    "arletta",
+    "arlette",
+    "arlinda",
+    "arlines",
+    "arlynes",
+    "armanda",
+    "armando",
+    "armands",
+    "armanis",
+    "armidas",
+    "arminda",
+    "armless",
+    "armlike",
+    "armored",
+    "arnetta",
+    "arnette",
+    "arnitas",
+    "arnoldo",
+    "arnolds",
+    "arnulfo",
+    "aroused",
+    "arrange",
+    "arrased",
+    "arthurs",
+    "artiest",
+    "artless",
+    "arturos",
+    "artwork",
+    "arvilla",
+    "aryanas",
+    "aryanna",
+    "ascitic",
+    "aseptic",
+    "ashamed",
+    "ashanti",
+    "ashelys",
+    "ashiest",
+    "ashleas",
+    "ashlees",
+    "ashless",
+    "ashleys",
+    "ashlies",
+    "ashlynn",
+    "ashlyns",
+    "ashtons",
+    "asinine",
+    "askance",
+    "asocial",
+    "assault",
+    "assured",
+    "astable",
+    "astatic",
+    "astrids",
+    "astylar",
+    "asunder",
+    "atactic",
+    "athenas",
+    "athirst",
+    "athlete",
+    "athrill",
+    "atingle",
+    "atresic",
+    "atticus",
+    "attired",
+    "attract",
+    "aubrees",
+    "aubreys",
+    "aubries",
+    "auction",
+    "audible",
+    "audibly",
+    "audient",
+    "audreas",
+    "audreys",
+    "audrias",
+    "audries",
+    "audrina",
+    "augitic",
+    "augural",
+    "augusta",
+    "augusts",
+    "aundrea",
+    "aurally",
+    "aureate",
+    "aurelia",
+    "aurelio",
+    "aurific",
+    "auroral",
+    "auroras",
+    "aurores",
+    "austere",
+    "austins",
+    "autumns",
+    "avalyns",
+    "avelina",
+    "avellan",
+    "average",
+    "averies",
+    "avianas",
+    "avianna",
+    "aviatic",
+    "avocado",
+    "avocets",
+    "avulsed",
+    "awaited",
+    "aweless",
+    "awesome",
+    "awfully",
+    "awildas",
+    "awkward",
+    "awnless",
+    "axially",
+    "axolotl",
+    "ayannas",
+    "ayeshas",
+    "ayleens",
+    "azaleas",
+    "azalees",
+    "azariah",
+    "azarias",
+    "azucena",
+    "azygous",
+    "babaras",
+    "babette",
+    "baboons",
+    "babyish",
+    "baccate",
+    "baddish",
+    "badgers",
+    "baggier",
+    "baggily",
+    "bailees",
+    "baileys",
+    "bairnly",
+    "balance",
+    "balcony",
+    "balding",
+    "baldish",
+    "baleful",
+    "balkier",
+    "balkily",
+    "balmier",
+    "balmily",
+    "balneal",
+    "balsamy",
+    "bananas",
+    "baneful",
+    "bangled",
+    "barbara",
+    "barbars",
+    "barbate",
+    "barbels",
+    "barbera",
+    "barbets",
+    "barbies",
+    "barbras",
+    "bardier",
+    "bardily",
+    "bardish",
+    "bargain",
+    "barkier",
+    "barless",
+    "barmier",
+    "barneys",
+    "barrett",
+    "barries",
+    "barruly",
+    "bartons",
+    "barwise",
+    "barytic",
+    "basally",
+    "bashful",
+    "basilar",
+    "basilia",
+    "basilic",
+    "basined",
+    "bassets",
+    "batfish",
+    "bathyal",
+    "batlike",
+    "battier",
+    "battled",
+    "bausond",
+    "bawdier",
+    "bawdily",
+    "baylees",
+    "beadier",
+    "beadily",
+    "beagles",
+    "beamier",
+    "beamily",
+    "beaming",
+    "beamish",
+    "bearded",
+    "bearish",
+    "beastly",
+    "beatris",
+    "beatriz",
+    "beauish",
+    "beaulah",
+    "because",
+    "beckett",
+    "beckham",
+    "beckies",
+    "bedbugs",
+    "bedfast",
+    "bedless",
+    "bedlike",
+    "beechen",
+    "beefier",
+    "beefily",
+    "beelike",
+    "beerier",
+    "beetles",
+    "behenic",
+    "belated",
+    "believe",
+    "belinda",
+    "beloved",
+    "bemazed",
+    "bemused",
+    "benefic",
+    "benefit",
+    "bengals",
+    "benitas",
+    "benitos",
+    "benmost",
+    "bennett",
+    "bennies",
+    "bensons",
+    "benthal",
+    "benthic",
+    "bentlee",
+    "bentley",
+    "bentlys",
+    "bentons",
+    "benzoic",
+    "bernard",
+    "bernice",
+    "bernies",
+    "bernita",
+    "berthas",
+    "berties",
+    "bertram",
+    "bespoke",
+    "bessies",
+    "bestial",
+    "bethann",
+    "bethany",
+    "bethels",
+    "betseys",
+    "betties",
+    "bettina",
+    "bettong",
+    "bettyes",
+    "between",
+    "beulahs",
+    "beverly",
+    "bezanty",
+    "biancas",
+    "biaxial",
+    "bibasic",
+    "bibless",
+    "biblike",
+    "bicolor",
+    "bicycle",
+    "bifidly",
+    "bifilar",
+    "bifocal",
+    "bigeyes",
+    "biggest",
+    "biggish",
+    "bigoted",
+    "bilgier",
+    "biliary",
+    "bilious",
+    "billety",
+    "billies",
+    "billowy",
+    "billyes",
+    "bimodal",
+    "biology",
+    "bioptic",
+    "biparty",
+    "bipedal",
+    "bipolar",
+    "birchen",
+    "birdies",
+    "birgits",
+    "bistred",
+    "bitable",
+    "bitless",
+    "bitonal",
+    "bittern",
+    "bizarre",
+    "bizonal",
+    "blackly",
+    "blaines",
+    "blaises",
+    "blakely",
+    "blancas",
+    "blanche",
+    "blanchi",
+    "blanchs",
+    "blandly",
+    "blanket",
+    "blankly",
+    "blatant",
+    "blately",
+    "blazing",
+    "bleakly",
+    "blennys",
+    "blesbok",
+    "blessed",
+    "blindly",
+    "bloated",
+    "blocked",
+    "blooded",
+    "bloomed",
+    "blossom",
+    "blotchy",
+    "blowfly",
+    "blowier",
+    "blowzed",
+    "blueish",
+    "bluejay",
+    "bluffly",
+    "bluntly",
+    "blythes",
+    "boarish",
+    "bobbies",
+    "bobbyes",
+    "bobcats",
+    "bobette",
+    "boggish",
+    "bogusly",
+    "bolshie",
+    "bombous",
+    "boniest",
+    "bonitas",
+    "bonitos",
+    "bonkers",
+    "bonnier",
+    "bonnies",
+    "bonnily",
+    "bonobos",
+    "bookers",
+    "bookish",
+    "boolean",
+    "boorish",
+    "boozier",
+    "boozily",
+    "borable",
+    "boracic",
+    "boredly",
+    "boronic",
+    "boskier",
+    "bosomed",
+    "bossier",
+    "bossily",
+    "bostons",
+    "bouilli",
+    "bounded",
+    "bounden",
+    "bowfins",
+    "bowless",
+    "bowlike",
+    "boxfish",
+    "boxlike",
+    "bracket",
+    "bracted",
+    "bradens",
+    "bradley",
+    "bradlys",
+    "braeden",
+    "braelyn",
+    "braided",
+    "braiden",
+    "brambly",
+    "brandas",
+    "brandee",
+    "branden",
+    "brandes",
+    "brandie",
+    "brandis",
+    "brandon",
+    "brandys",
+    "brankie",
+    "branson",
+    "brashly",
+    "bravely",
+    "braxton",
+    "brayans",
+    "brayden",
+    "braydon",
+    "braylee",
+    "braylen",
+    "braylon",
+    "breanas",
+    "breanna",
+    "breanne",
+    "breanns",
+    "breathy",
+    "brecken",
+    "brendan",
+    "brendas",
+    "brenden",
+    "brendon",
+    "brennan",
+    "brennas",
+    "brenton",
+    "brianas",
+    "brianna",
+    "brianne",
+    "brickle",
+    "bridger",
+    "bridget",
+    "briefly",
+    "briella",
+    "brielle",
+    "brigida",
+    "brigids",
+    "brileys",
+    "brimful",
+    "brindas",
+    "brinier",
+    "brinish",
+    "brinley",
+    "brisant",
+    "briskly",
+    "bristly",
+    "bristol",
+    "britany",
+    "britney",
+    "britnis",
+    "brittas",
+    "brittly",
+    "brittni",
+    "brittny",
+    "broadly",
+    "brocket",
+    "brodies",
+    "bronson",
+    "bronwyn",
+    "brookes",
+    "brother",
+    "brumous",
+    "brushed",
+    "brusque",
+    "brutely",
+    "brutish",
+    "bryanna",
+    "bryants",
+    "brycens",
+    "brylees",
+    "brynlee",
+    "brysens",
+    "brysons",
+    "bubonic",
+    "buckish",
+    "budding",
+    "budless",
+    "budlike",
+    "buffalo",
+    "bufords",
+    "buggier",
+    "buirdly",
+    "bulbous",
+    "bulbuls",
+    "bulimic",
+    "bulkier",
+    "bulkily",
+    "bullate",
+    "bulldog",
+    "bullish",
+    "bullous",
+    "bumpier",
+    "bumpily",
+    "bunting",
+    "buoyant",
+    "burbots",
+    "burghal",
+    "burlier",
+    "burlily",
+    "burrier",
+    "bursate",
+    "burtons",
+    "bushier",
+    "bushily",
+    "busiest",
+    "bustard",
+    "busters",
+    "bustier",
+    "bustled",
+    "busying",
+    "butyric",
+    "butyryl",
+    "buxomly",
+    "buyable",
+    "buzzard",
+    "byssoid",
+    "cabbage",
+    "cabbagy",
+    "cactoid",
+    "caddish",
+    "cadence",
+    "cadgily",
+    "cagiest",
+    "caidens",
+    "caimans",
+    "caitlin",
+    "caitlyn",
+    "calista",
+    "callans",
+    "callens",
+    "callies",
+    "callose",
+    "callums",
+    "calmier",
+    "calming",
+    "calvins",
+    "calvous",
+    "cambial",
+    "camdens",
+    "camdyns",
+    "camelia",
+    "cameral",
+    "cameron",
+    "camilas",
+    "camilla",
+    "camille",
+    "camilos",
+    "cammies",
+    "campily",
+    "camrens",
+    "camrons",
+    "camryns",
+    "candace",
+    "candent",
+    "candice",
+    "candida",
+    "candied",
+    "candies",
+    "candras",
+    "candyce",
+    "cannier",
+    "cannily",
+    "cannons",
+    "canthal",
+    "cantily",
+    "cantish",
+    "canular",
+    "capable",
+    "capably",
+    "capelin",
+    "capital",
+    "capless",
+    "caprice",
+    "caprine",
+    "captain",
+    "caracal",
+    "careful",
+    "caribou",
+    "caridad",
+    "carinal",
+    "carinas",
+    "carious",
+    "carisas",
+    "carissa",
+    "caritas",
+    "carking",
+    "carleen",
+    "carlees",
+    "carlena",
+    "carlene",
+    "carless",
+    "carleys",
+    "carlies",
+    "carline",
+    "carlish",
+    "carlita",
+    "carlota",
+    "carlton",
+    "carlyns",
+    "carmans",
+    "carmela",
+    "carmelo",
+    "carmels",
+    "carmens",
+    "carmina",
+    "carmine",
+    "carmons",
+    "carnose",
+    "carnous",
+    "carolas",
+    "carolee",
+    "caroles",
+    "carolin",
+    "carolls",
+    "carolyn",
+    "caroyln",
+    "carries",
+    "carroll",
+    "carrols",
+    "carroty",
+    "carsick",
+    "carsons",
+    "carters",
+    "carylon",
+    "caseous",
+    "casqued",
+    "castiel",
+    "castled",
+    "catalog",
+    "catbird",
+    "catfish",
+    "cathern",
+    "catheys",
+    "cathies",
+    "cathryn",
+    "catinas",
+    "catlike",
+    "catrice",
+    "catrina",
+    "cattily",
+    "cattish",
+    "cattles",
+    "cauline",
+    "caution",
+    "cavally",
+    "caydens",
+    "caylees",
+    "caysons",
+    "cecally",
+    "cecelia",
+    "cecilas",
+    "ceciles",
+    "cecilia",
+    "cecille",
+    "cecilys",
+    "cedrick",
+    "cedrics",
+    "ceiling",
+    "celenas",
+    "celesta",
+    "celeste",
+    "celinas",
+    "celinda",
+    "celines",
+    "censual",
+    "central",
+    "centric",
+    "century",
+    "cerated",
+    "cereous",
+    "certain",
+    "cervine",
+    "cestoid",
+    "cesural",
+    "chamois",
+    "chances",
+    "chandas",
+    "chandra",
+    "chanell",
+    "chanels",
+    "chantal",
+    "chantay",
+    "chantel",
+    "chantes",
+    "chaotic",
+    "chapter",
+    "charier",
+    "charily",
+    "charise",
+    "charita",
+    "charity",
+    "charlas",
+    "charlee",
+    "charles",
+    "charley",
+    "charlie",
+    "charlis",
+    "charlyn",
+    "charmed",
+    "chasidy",
+    "chasity",
+    "chasmal",
+    "chasmed",
+    "chasmic",
+    "chaster",
+    "cheaply",
+    "checked",
+    "cheerly",
+    "cheesed",
+    "cheetah",
+    "chelsea",
+    "chelsey",
+    "chelsie",
+    "cherees",
+    "cheries",
+    "cherise",
+    "cherish",
+    "cherlyn",
+    "cherlys",
+    "cherrie",
+    "cherris",
+    "cherryl",
+    "cherrys",
+    "cheryle",
+    "cheryll",
+    "cheryls",
+    "chester",
+    "chewier",
+    "chicken",
+    "chiefly",
+    "chiekos",
+    "chigger",
+    "childly",
+    "chimbly",
+    "chimney",
+    "chinchy",
+    "chinook",
+    "chintzy",
+    "chipper",
+    "chloric",
+    "chocker",
+    "choicer",
+    "chokier",
+    "choosey",
+    "chordal",
+    "chorded",
+    "choreal",
+    "choreic",
+    "chorial",
+    "chrissy",
+    "christa",
+    "christi",
+    "christy",
+    "chromic",
+    "chronic",
+    "chuckle",
+    "churchy",
+    "chylous",
+    "chymous",
+    "cicadas",
+    "cicelys",
+    "cichlid",
+    "cierras",
+    "ciliary",
+    "cindery",
+    "cindies",
+    "cinthia",
+    "cirrate",
+    "cirrose",
+    "cirsoid",
+    "citable",
+    "citizen",
+    "civilly",
+    "claires",
+    "clamant",
+    "clapped",
+    "clarice",
+    "clarify",
+    "clarine",
+    "clarisa",
+    "clarita",
+    "classic",
+    "clastic",
+    "claudes",
+    "claudia",
+    "claudie",
+    "claudio",
+    "clausal",
+    "clavate",
+    "clayish",
+    "clayton",
+    "cleanly",
+    "clearer",
+    "clearly",
+    "clelias",
+    "clement",
+    "clemmie",
+    "cleoras",
+    "clerkly",
+    "clifton",
+    "climant",
+    "clinton",
+    "cliquey",
+    "cloacal",
+    "closely",
+    "closing",
+    "clouded",
+    "clovery",
+    "cloying",
+    "cluster",
+    "clutchy",
+    "clypeal",
+    "cnemial",
+    "coalier",
+    "coarser",
+    "coastal",
+    "coaxial",
+    "coccous",
+    "coconut",
+    "codling",
+    "coeliac",
+    "cofinal",
+    "coldish",
+    "coleens",
+    "coleman",
+    "colenes",
+    "coletta",
+    "colette",
+    "colicky",
+    "colitic",
+    "collect",
+    "colleen",
+    "collene",
+    "collens",
+    "collies",
+    "collins",
+    "colobus",
+    "coltens",
+    "coltish",
+    "coltons",
+    "comatic",
+    "combine",
+    "comedic",
+    "comfier",
+    "comfily",
+    "comfort",
+    "comical",
+    "company",
+    "compony",
+    "concert",
+    "conchal",
+    "conchas",
+    "conched",
+    "concise",
+    "condign",
+    "condors",
+    "conduct",
+    "confirm",
+    "congers",
+    "conical",
+    "connate",
+    "connect",
+    "conners",
+    "connies",
+    "connors",
+    "conrads",
+    "content",
+    "control",
+    "coolish",
+    "coopers",
+    "copious",
+    "coppery",
+    "copular",
+    "coralee",
+    "coralie",
+    "corazon",
+    "corbans",
+    "corbins",
+    "cordate",
+    "cordell",
+    "cordial",
+    "cordias",
+    "cordies",
+    "coreens",
+    "corenes",
+    "coretta",
+    "corinas",
+    "corines",
+    "corinna",
+    "corinne",
+    "corkier",
+    "corking",
+    "corliss",
+    "cormoid",
+    "cormous",
+    "corneal",
+    "cornell",
+    "cornfed",
+    "cornier",
+    "cornily",
+    "cornual",
+    "cornute",
+    "correct",
+    "corries",
+    "corrina",
+    "corrine",
+    "corrins",
+    "cortezs",
+    "cortney",
+    "corvine",
+    "cosiest",
+    "cosmoid",
+    "costate",
+    "costive",
+    "cotidal",
+    "cotinga",
+    "cottony",
+    "cougars",
+    "country",
+    "courser",
+    "courtly",
+    "couthie",
+    "cowbird",
+    "cowedly",
+    "cowfish",
+    "cowlike",
+    "coyotes",
+    "coziest",
+    "crabbed",
+    "cracked",
+    "crackly",
+    "cramped",
+    "cranely",
+    "cranial",
+    "cranked",
+    "crankly",
+    "crappie",
+    "crawdad",
+    "crazier",
+    "crazily",
+    "credent",
+    "creedal",
+    "creeded",
+    "creeper",
+    "crenate",
+    "creolas",
+    "crested",
+    "cricket",
+    "crinkly",
+    "crinose",
+    "crisply",
+    "crissal",
+    "crissys",
+    "cristal",
+    "cristas",
+    "cristen",
+    "cristie",
+    "cristin",
+    "cristis",
+    "cristys",
+    "croaker",
+    "crocked",
+    "cronish",
+    "crooked",
+    "crosbys",
+    "crossed",
+    "crossly",
+    "crowded",
+    "crowned",
+    "crucial",
+    "crudely",
+    "crudest",
+    "cruelly",
+    "crumble",
+    "crumbly",
+    "crumply",
+    "crunchy",
+    "crusily",
+    "crustal",
+    "crusted",
+    "cryptal",
+    "cryptic",
+    "crystal",
+    "crystas",
+    "crystle",
+    "ctenoid",
+    "cubbish",
+    "cubical",
+    "cubicly",
+    "cubital",
+    "cuckoos",
+    "cullens",
+    "cultish",
+    "cultual",
+    "culture",
+    "cuneate",
+    "cunning",
+    "cuplike",
+    "cuprous",
+    "curable",
+    "curably",
+    "curatic",
+    "curdier",
+    "curious",
+    "curlews",
+    "curlier",
+    "curlike",
+    "curlily",
+    "current",
+    "currish",
+    "cursory",
+    "curstly",
+    "curtain",
+    "curtate",
+    "curvier",
+    "cushier",
+    "cushily",
+    "cushion",
+    "cynical",
+    "cynthia",
+    "cyrstal",
+    "cythias",
+    "daffier",
+    "daffily",
+    "dagmars",
+    "dahlias",
+    "daiseys",
+    "daisied",
+    "dakotas",
+    "dalenes",
+    "daleyza",
+    "dalilah",
+    "dalilas",
+    "daltons",
+    "damaris",
+    "damians",
+    "damiens",
+    "damions",
+    "dampish",
+    "dandily",
+    "danelle",
+    "danette",
+    "dangelo",
+    "danials",
+    "danicas",
+    "daniela",
+    "daniele",
+    "daniell",
+    "daniels",
+    "danikas",
+    "danille",
+    "danilos",
+    "danitas",
+    "dannies",
+    "danutas",
+    "danyell",
+    "danyels",
+    "daphine",
+    "daphnes",
+    "darcels",
+    "darceys",
+    "darcies",
+    "darells",
+    "darians",
+    "dariens",
+    "darkish",
+    "darleen",
+    "darlena",
+    "darlene",
+    "darline",
+    "darling",
+    "darnell",
+    "darrell",
+    "darrels",
+    "darrens",
+    "darrick",
+    "darrins",
+    "darrons",
+    "darryls",
+    "darters",
+    "darwins",
+    "dashier",
+    "dashing",
+    "dassies",
+    "datable",
+    "datedly",
+    "datival",
+    "daturic",
+    "davians",
+    "davidas",
+    "davinas",
+    "davions",
+    "dawsons",
+    "daxtons",
+    "dayanas",
+    "daylily",
+    "daytons",
+    "dazedly",
+    "dazzled",
+    "dcollet",
+    "deacons",
+    "deadpan",
+    "deadras",
+    "dealate",
+    "deandra",
+    "deandre",
+    "deannas",
+    "deannes",
+    "deathly",
+    "debbies",
+    "debbras",
+    "deberas",
+    "deborah",
+    "deboras",
+    "debrahs",
+    "debroah",
+    "decadal",
+    "decided",
+    "decimus",
+    "declans",
+    "decline",
+    "declive",
+    "deeanna",
+    "deeanns",
+    "deedees",
+    "deedras",
+    "deegans",
+    "deerfly",
+    "deettas",
+    "defense",
+    "defiant",
+    "defunct",
+    "deidras",
+    "deidres",
+    "deiform",
+    "deirdre",
+    "deistic",
+    "delaine",
+    "delanas",
+    "delaney",
+    "delbert",
+    "delcies",
+    "delenas",
+    "delfina",
+    "delicia",
+    "delilah",
+    "delilas",
+    "delinda",
+    "delisas",
+    "deliver",
+    "delmars",
+    "delmers",
+    "deloise",
+    "deloras",
+    "delores",
+    "deloris",
+    "delorse",
+    "delphas",
+    "delphia",
+    "delsies",
+    "deltaic",
+    "demetra",
+    "demoded",
+    "demonic",
+    "demurer",
+    "dendric",
+    "deneens",
+    "deneses",
+    "denices",
+    "denises",
+    "denisha",
+    "denisse",
+    "denitas",
+    "dennise",
+    "densely",
+    "densest",
+    "dentate",
+    "dentist",
+    "dentoid",
+    "denvers",
+    "denyses",
+    "denzels",
+    "deonnas",
+    "deontic",
+    "deposit",
+    "dericks",
+    "dernier",
+    "derrick",
+    "deshawn",
+    "desirae",
+    "desired",
+    "desiree",
+    "desires",
+    "desmond",
+    "despair",
+    "despina",
+    "dessies",
+    "destiny",
+    "destroy",
+    "develop",
+    "deviled",
+    "devious",
+    "devonas",
+    "devorah",
+    "devoras",
+    "devoted",
+    "dewayne",
+    "dewitts",
+    "dewless",
+    "dexters",
+    "dextral",
+    "diagram",
+    "diamond",
+    "diannas",
+    "diannes",
+    "dibasic",
+    "dicycly",
+    "diedras",
+    "diedres",
+    "dierdre",
+    "digital",
+    "dignity",
+    "dilemma",
+    "dillons",
+    "dimmest",
+    "dimming",
+    "dimples",
+    "dineric",
+    "dingier",
+    "dingily",
+    "dinkier",
+    "dinorah",
+    "dionnas",
+    "dionnes",
+    "diphase",
+    "diploic",
+    "dipodic",
+    "dipolar",
+    "dippers",
+    "dippier",
+    "direful",
+    "dirtier",
+    "dirtily",
+    "disease",
+    "dismiss",
+    "display",
+    "distant",
+    "distent",
+    "distyle",
+    "disused",
+    "ditzily",
+    "diverse",
+    "divided",
+    "divinas",
+    "divorce",
+    "dizzied",
+    "dizzier",
+    "dizzily",
+    "dodgily",
+    "dogfish",
+    "doggier",
+    "doggish",
+    "dogless",
+    "doglike",
+    "doleful",
+    "dollies",
+    "dollish",
+    "dolores",
+    "doloris",
+    "dolphin",
+    "doltish",
+    "domenic",
+    "domical",
+    "dominga",
+    "domingo",
+    "dominic",
+    "dominik",
+    "donalds",
+    "donella",
+    "donetta",
+    "donette",
+    "donitas",
+    "donnard",
+    "donnell",
+    "donnies",
+    "donnish",
+    "donovan",
+    "doomily",
+    "dopiest",
+    "dorathy",
+    "doreens",
+    "dorenes",
+    "doretha",
+    "doretta",
+    "dorians",
+    "dorinda",
+    "dorines",
+    "dormant",
+    "dorotha",
+    "dorothy",
+    "dorseys",
+    "dorthas",
+    "dorthea",
+    "dorthey",
+    "dorthys",
+    "dotiest",
+    "dotlike",
+    "dottier",
+    "dotties",
+    "dottily",
+    "doucely",
+    "doughty",
+    "douglas",
+    "dowable",
+    "dowdily",
+    "downier",
+    "downily",
+    "dozenth",
+    "doziest",
+    "dragons",
+    "drastic",
+    "dratted",
+    "dravens",
+    "dreamas",
+    "dribbly",
+    "driving",
+    "drizzly",
+    "drongos",
+    "dronish",
+    "drouthy",
+    "druffen",
+    "druidic",
+    "drunken",
+    "dryable",
+    "dryadic",
+    "dubious",
+    "ducally",
+    "duckier",
+    "ductile",
+    "dudleys",
+    "dugongs",
+    "dulcies",
+    "dullish",
+    "dulotic",
+    "dumpier",
+    "dumpily",
+    "dumpish",
+    "duncans",
+    "duncish",
+    "dunlins",
+    "dunnock",
+    "duopoly",
+    "dupable",
+    "durable",
+    "durably",
+    "duskier",
+    "duskily",
+    "duskish",
+    "dustier",
+    "dustily",
+    "dustins",
+    "duteous",
+    "dutiful",
+    "dwaynes",
+    "dwights",
+    "dyeable",
+    "dyeline",
+    "dynamic",
+    "dysuric",
+    "eagerly",
+    "earlean",
+    "earleen",
+    "earlene",
+    "earless",
+    "earlies",
+    "earlike",
+    "earline",
+    "earnest",
+    "earthas",
+    "earthen",
+    "earthly",
+    "earwigs",
+    "easeful",
+    "easeled",
+    "easiest",
+    "eastern",
+    "easters",
+    "eastons",
+    "ebonies",
+    "eccrine",
+    "ecdemic",
+    "echidna",
+    "ecology",
+    "economy",
+    "ectally",
+    "ectatic",
+    "ectopic",
+    "ectypal",
+    "edaphic",
+    "edgardo",
+    "edgiest",
+    "edictal",
+    "edisons",
+    "edmonds",
+    "edmundo",
+    "edmunds",
+    "eduardo",
+    "educate",
+    "edwardo",
+    "edwards",
+    "edwinas",
+    "edythes",
+    "eeliest",
+    "eellike",
+    "eelpout",
+    "eeriest",
+    "effable",
+    "efrains",
+    "eggless",
+    "eidetic",
+    "eileens",
+    "eilenes",
+    "eirenic",
+    "eladias",
+    "elainas",
+    "elaines",
+    "elanors",
+    "elaynes",
+    "elberts",
+    "elderly",
+    "eldoras",
+    "eleanor",
+    "eleases",
+    "elegant",
+    "element",
+    "elenora",
+    "elenore",
+    "elenors",
+    "eleonor",
+    "elflike",
+    "elfreda",
+    "elianas",
+    "elianna",
+    "elicias",
+    "elidias",
+    "elijahs",
+    "elinore",
+    "elinors",
+    "eliseos",
+    "elishas",
+    "elissas",
+    "ellamae",
+    "ellenas",
+    "elliana",
+    "elliots",
+    "elliott",
+    "ellison",
+    "elmiest",
+    "elmiras",
+    "elnoras",
+    "elodias",
+    "eloisas",
+    "eloises",
+    "elouise",
+    "elritch",
+    "elusive",
+    "eluvial",
+    "elveras",
+    "elvinas",
+    "elviras",
+    "elwanda",
+    "elwoods",
+    "emanant",
+    "emanuel",
+    "embolic",
+    "embowed",
+    "embrace",
+    "emeldas",
+    "emelias",
+    "emelina",
+    "emeline",
+    "emerald",
+    "emeries",
+    "emerita",
+    "emersed",
+    "emerson",
+    "emersyn",
+    "emilees",
+    "emilias",
+    "emilies",
+    "emilios",
+    "eminent",
+    "emmalee",
+    "emmalyn",
+    "emmetts",
+    "emmitts",
+    "emogene",
+    "emotion",
+    "emotive",
+    "emperor",
+    "empower",
+    "emptied",
+    "empties",
+    "emptily",
+    "emulous",
+    "enabled",
+    "encinal",
+    "endarch",
+    "endless",
+    "endmost",
+    "endorse",
+    "endowed",
+    "enedina",
+    "eneidas",
+    "enforce",
+    "engaged",
+    "enhance",
+    "enjoyed",
+    "enricos",
+    "enrique",
+    "enteral",
+    "enteric",
+    "enticed",
+    "entomic",
+    "entopic",
+    "envious",
+    "eosinic",
+    "epaxial",
+    "epeiric",
+    "ephebic",
+    "ephoral",
+    "ephraim",
+    "epiboly",
+    "epigeal",
+    "epigene",
+    "episode",
+    "epizoic",
+    "epochal",
+    "equable",
+    "equably",
+    "equally",
+    "erasmos",
+    "erectly",
+    "ergodic",
+    "erickas",
+    "ericoid",
+    "erlenes",
+    "erlinda",
+    "erlines",
+    "ermined",
+    "ermines",
+    "erminia",
+    "ernesto",
+    "ernests",
+    "erodent",
+    "erosely",
+    "erosion",
+    "erosive",
+    "errable",
+    "erudite",
+    "escolar",
+    "essence",
+    "esteban",
+    "estelas",
+    "estella",
+    "estelle",
+    "estells",
+    "esthers",
+    "estival",
+    "estrous",
+    "estrual",
+    "eternal",
+    "etesian",
+    "ethelyn",
+    "ethical",
+    "ethylic",
+    "ethynyl",
+    "etsukos",
+    "euclids",
+    "eufemia",
+    "eugenas",
+    "eugenes",
+    "eugenia",
+    "eugenic",
+    "eugenie",
+    "eugenio",
+    "euglena",
+    "eugonic",
+    "eulalia",
+    "eunices",
+    "eupneic",
+    "eusebia",
+    "eusebio",
+    "eustyle",
+    "evalyns",
+    "evasive",
+    "evelias",
+    "evelina",
+    "eveline",
+    "evelins",
+    "evelyne",
+    "evelynn",
+    "evelyns",
+    "everett",
+    "everlys",
+    "evettes",
+    "evident",
+    "evolved",
+    "evonnes",
+    "exactly",
+    "exalted",
+    "example",
+    "exarate",
+    "excited",
+    "exclude",
+    "execute",
+    "exedral",
+    "exhaust",
+    "exhibit",
+    "exigent",
+    "explain",
+    "exposed",
+    "express",
+    "extinct",
+    "exuvial",
+    "eyeable",
+    "eyebrow",
+    "eyeless",
+    "eyelike",
+    "eyesome",
+    "ezekiel",
+    "fabians",
+    "fabiola",
+    "factful",
+    "factual",
+    "facular",
+    "faculty",
+    "fadable",
+    "faddier",
+    "faddish",
+    "fadedly",
+    "fadlike",
+    "faintly",
+    "fairily",
+    "fairish",
+    "falcate",
+    "falcial",
+    "falcons",
+    "fallons",
+    "falsely",
+    "falsest",
+    "fanback",
+    "fancied",
+    "fancily",
+    "fangled",
+    "fanlike",
+    "fannies",
+    "fantail",
+    "fantasy",
+    "faradic",
+    "faraway",
+    "farrahs",
+    "fascial",
+    "fashion",
+    "fatally",
+    "fateful",
+    "fatidic",
+    "fatigue",
+    "fatimah",
+    "fatimas",
+    "fatless",
+    "fatlike",
+    "fattier",
+    "fattily",
+    "fattish",
+    "fatuous",
+    "faucial",
+    "faustos",
+    "faviola",
+    "favored",
+    "fearful",
+    "feature",
+    "febrile",
+    "federal",
+    "feebler",
+    "feeless",
+    "feigned",
+    "felecia",
+    "felicas",
+    "felices",
+    "felicia",
+    "felines",
+    "felipas",
+    "felipes",
+    "felisas",
+    "felisha",
+    "feltons",
+    "femoral",
+    "fennecs",
+    "ferally",
+    "fermina",
+    "fermins",
+    "fernier",
+    "ferrets",
+    "ferrety",
+    "ferrous",
+    "fertile",
+    "fervent",
+    "festive",
+    "fetidly",
+    "fibered",
+    "fibrous",
+    "fibular",
+    "fictile",
+    "fiction",
+    "fictive",
+    "fidelas",
+    "fidelia",
+    "fidgety",
+    "fiendly",
+    "fiercer",
+    "fierier",
+    "fierily",
+    "fifthly",
+    "figgier",
+    "figural",
+    "figured",
+    "filmier",
+    "filmily",
+    "filosus",
+    "finable",
+    "finally",
+    "finfoot",
+    "fingery",
+    "finical",
+    "finicky",
+    "finless",
+    "finleys",
+    "finlike",
+    "finnier",
+    "firefly",
+    "firstly",
+    "fishers",
+    "fishier",
+    "fishily",
+    "fissile",
+    "fitness",
+    "fitting",
+    "fixable",
+    "fixedly",
+    "fizzier",
+    "flaccid",
+    "flakier",
+    "flakily",
+    "flamier",
+    "flaunty",
+    "flavias",
+    "flavory",
+    "flawier",
+    "fleetly",
+    "fleshly",
+    "flexile",
+    "flighty",
+    "flooded",
+    "florene",
+    "florias",
+    "florida",
+    "florine",
+    "florrie",
+    "flossie",
+    "flowery",
+    "flowing",
+    "fluidal",
+    "fluidic",
+    "fluidly",
+    "flukier",
+    "flukily",
+    "fluoric",
+    "flutier",
+    "fluvial",
+    "flyable",
+    "flyless",
+    "foamier",
+    "foamily",
+    "focally",
+    "foggier",
+    "foggily",
+    "fogless",
+    "fogyish",
+    "foliose",
+    "folkish",
+    "foolish",
+    "footier",
+    "foppish",
+    "foreign",
+    "forests",
+    "forkier",
+    "forlorn",
+    "forrest",
+    "fortune",
+    "forward",
+    "forworn",
+    "fosters",
+    "fourcha",
+    "foveate",
+    "foxiest",
+    "foxlike",
+    "foziest",
+    "fractus",
+    "fragile",
+    "frailly",
+    "frances",
+    "francie",
+    "francis",
+    "francos",
+    "frankie",
+    "frankly",
+    "frantic",
+    "fratchy",
+    "freckly",
+    "freddas",
+    "freddie",
+    "freddys",
+    "fredias",
+    "fredric",
+    "freedas",
+    "freeman",
+    "freidas",
+    "fremdly",
+    "freshly",
+    "fretful",
+    "fretted",
+    "friable",
+    "friarly",
+    "friedas",
+    "frizzly",
+    "frogged",
+    "fronded",
+    "frosted",
+    "froward",
+    "frowsty",
+    "fructed",
+    "fruited",
+    "fubsier",
+    "fugally",
+    "fuggily",
+    "fulgent",
+    "fulmars",
+    "fulsome",
+    "fulvous",
+    "fumaric",
+    "fumiest",
+    "fumikos",
+    "fungoid",
+    "fungous",
+    "funkier",
+    "funkily",
+    "funnier",
+    "funnily",
+    "furious",
+    "furless",
+    "furnace",
+    "furrily",
+    "furrowy",
+    "further",
+    "furtive",
+    "fuscous",
+    "fusible",
+    "fusibly",
+    "fusilly",
+    "fussier",
+    "fussily",
+    "fustier",
+    "fustily",
+    "fuzzier",
+    "fuzzily",
+    "gabriel",
+    "gadgety",
+    "gadwall",
+    "gainful",
+    "galeate",
+    "galenic",
+    "galilea",
+    "galileo",
+    "galinas",
+    "gallant",
+    "gallery",
+    "gallfly",
+    "galling",
+    "gametic",
+    "gamiest",
+    "gannets",
+    "gannons",
+    "gapless",
+    "garbage",
+    "gardant",
+    "garfish",
+    "gargety",
+    "garland",
+    "garment",
+    "garnets",
+    "garnett",
+    "garpike",
+    "garrets",
+    "garrett",
+    "gaseous",
+    "gasless",
+    "gastons",
+    "gastric",
+    "gaudily",
+    "gauntly",
+    "gauzier",
+    "gauzily",
+    "gawkier",
+    "gawkily",
+    "gaylene",
+    "gaylord",
+    "gaynell",
+    "gazelle",
+    "geekily",
+    "gelding",
+    "gelidly",
+    "gemeled",
+    "gemless",
+    "gemlich",
+    "gemlike",
+    "gemmier",
+    "gemmily",
+    "gemsbok",
+    "genaros",
+    "general",
+    "generic",
+    "genesis",
+    "genetic",
+    "genevas",
+    "genevie",
+    "genital",
+    "gennies",
+    "genomic",
+    "genteel",
+    "gentled",
+    "gentler",
+    "genuine",
+    "geoidal",
+    "georges",
+    "georgia",
+    "georgie",
+    "geraldo",
+    "geralds",
+    "geralyn",
+    "gerardo",
+    "gerards",
+    "gerbils",
+    "gerenuk",
+    "germane",
+    "germans",
+    "gerthas",
+    "gerties",
+    "gertrud",
+    "gertude",
+    "gesture",
+    "getable",
+    "ghastly",
+    "ghostly",
+    "giannas",
+    "giannis",
+    "gibbons",
+    "gibbous",
+    "gibsons",
+    "giddied",
+    "giddier",
+    "giddily",
+    "gideons",
+    "gidgets",
+    "gilbert",
+    "gillian",
+    "gimlety",
+    "ginette",
+    "gingely",
+    "gingers",
+    "gingery",
+    "giovani",
+    "giraffe",
+    "girlish",
+    "gironny",
+    "giselas",
+    "giseles",
+    "giselle",
+    "glacial",
+    "glaikit",
+    "glaived",
+    "glarier",
+    "glaring",
+    "glazily",
+    "gleeful",
+    "glendas",
+    "glennas",
+    "glennie",
+    "glennis",
+    "glenoid",
+    "glibber",
+    "gliders",
+    "glimpse",
+    "glindas",
+    "globate",
+    "globose",
+    "glorias",
+    "glottal",
+    "glottic",
+    "glowfly",
+    "glowing",
+    "gluiest",
+    "glummer",
+    "gluteal",
+    "glyndas",
+    "glyphic",
+    "glyptic",
+    "gnarled",
+    "gnathic",
+    "gnomish",
+    "goateed",
+    "goatish",
+    "gobbler",
+    "goblins",
+    "goddess",
+    "godless",
+    "godlier",
+    "godlike",
+    "godlily",
+    "godsent",
+    "godwits",
+    "goldens",
+    "goldies",
+    "gonadal",
+    "gonidic",
+    "gonzalo",
+    "goodish",
+    "goofier",
+    "goofily",
+    "gooiest",
+    "gophers",
+    "gordons",
+    "goriest",
+    "gorilla",
+    "goshawk",
+    "gossipy",
+    "gourami",
+    "goutier",
+    "goutily",
+    "goutish",
+    "gowaned",
+    "gracias",
+    "gracies",
+    "gracile",
+    "grackle",
+    "gradely",
+    "graemes",
+    "grahams",
+    "grained",
+    "grandly",
+    "graphic",
+    "grapier",
+    "gravely",
+    "gravest",
+    "gravity",
+    "grayces",
+    "grayish",
+    "grayson",
+    "grazyna",
+    "greatly",
+    "greaved",
+    "greenly",
+    "gregory",
+    "grettas",
+    "greyish",
+    "greyson",
+    "griffin",
+    "griffon",
+    "grilled",
+    "grimier",
+    "grimily",
+    "grimmer",
+    "gripier",
+    "grippal",
+    "gripple",
+    "grisels",
+    "grisons",
+    "gristly",
+    "grizzly",
+    "grocery",
+    "grossly",
+    "grouchy",
+    "grouper",
+    "grouses",
+    "grovers",
+    "growing",
+    "gruffly",
+    "grumbly",
+    "grummer",
+    "grumous",
+    "grunter",
+    "grushie",
+    "guanaco",
+    "guarded",
+    "gudgeon",
+    "gudruns",
+    "guiding",
+    "guineas",
+    "gumless",
+    "gumlike",
+    "gummier",
+    "gummous",
+    "gunless",
+    "gunnars",
+    "gunnels",
+    "gunners",
+    "gurnard",
+    "gushier",
+    "gushily",
+    "gussies",
+    "gustavo",
+    "gustier",
+    "gustily",
+    "gutless",
+    "gutlike",
+    "gutsier",
+    "gutsily",
+    "guttate",
+    "gwendas",
+    "gwyneth",
+    "gynecic",
+    "gyrally",
+    "gyronny",
+    "habited",
+    "hacking",
+    "haddock",
+    "hadlees",
+    "hadleys",
+    "haemoid",
+    "hagborn",
+    "hagfish",
+    "haggish",
+    "haglike",
+    "hailees",
+    "haileys",
+    "hairier",
+    "haleigh",
+    "halfway",
+    "halibut",
+    "halinas",
+    "halleys",
+    "hallies",
+    "halting",
+    "hammier",
+    "hammily",
+    "hamster",
+    "hamular",
+    "handier",
+    "handily",
+    "hannahs",
+    "hapless",
+    "happier",
+    "happily",
+    "hardier",
+    "hardily",
+    "harland",
+    "harlans",
+    "harlees",
+    "harleys",
+    "harlows",
+    "harmful",
+    "harmony",
+    "harolds",
+    "harpers",
+    "harrier",
+    "harriet",
+    "harshly",
+    "harvest",
+    "harveys",
+    "hastate",
+    "hastier",
+    "hastily",
+    "hatable",
+    "hateful",
+    "hatless",
+    "hatlike",
+    "hatties",
+    "haughty",
+    "haunted",
+    "hawkish",
+    "haydees",
+    "haydens",
+    "haylees",
+    "hayleys",
+    "haywood",
+    "hazelly",
+    "haziest",
+    "headier",
+    "headily",
+    "healing",
+    "healthy",
+    "heaping",
+    "hearted",
+    "heatful",
+    "heather",
+    "heavens",
+    "heavier",
+    "heavies",
+    "heavily",
+    "hebetic",
+    "hectors",
+    "hedgier",
+    "hedonic",
+    "hedwigs",
+    "heedful",
+    "heftier",
+    "heftily",
+    "heimish",
+    "heinous",
+    "helaine",
+    "helenas",
+    "helenes",
+    "helical",
+    "heliced",
+    "hellens",
+    "helpful",
+    "helping",
+    "hematal",
+    "hendrix",
+    "henleys",
+    "henlike",
+    "hennish",
+    "herbert",
+    "herbier",
+    "hermans",
+    "hermila",
+    "hermina",
+    "hermine",
+    "hermits",
+    "hernial",
+    "herring",
+    "hershel",
+    "herthas",
+    "hesters",
+    "hetties",
+    "hewable",
+    "hexadic",
+    "hexylic",
+    "hidable",
+    "hideous",
+    "hieland",
+    "hilaria",
+    "hilario",
+    "hilarys",
+    "hildred",
+    "hillary",
+    "hillier",
+    "hiltons",
+    "hipless",
+    "hiplike",
+    "hippest",
+    "hippier",
+    "hipshot",
+    "hirable",
+    "hircine",
+    "hirokos",
+    "hirstie",
+    "hirsute",
+    "hisakos",
+    "histoid",
+    "history",
+    "hitless",
+    "hoarier",
+    "hoarily",
+    "hoatzin",
+    "hoberts",
+    "hoblike",
+    "hoelike",
+    "hoggish",
+    "hoglike",
+    "holdens",
+    "holiday",
+    "holiest",
+    "holleys",
+    "hollies",
+    "homiest",
+    "honeyed",
+    "hookier",
+    "hoopoes",
+    "hopeful",
+    "horaces",
+    "horacio",
+    "hornets",
+    "hornily",
+    "hornish",
+    "horrent",
+    "horsier",
+    "horsily",
+    "hostile",
+    "hotting",
+    "hottish",
+    "houston",
+    "howards",
+    "however",
+    "huberts",
+    "hudsons",
+    "hueless",
+    "huffier",
+    "huffily",
+    "huffish",
+    "hulkier",
+    "hulking",
+    "humanly",
+    "humbled",
+    "humbler",
+    "humeral",
+    "humidly",
+    "humilis",
+    "humoral",
+    "humpier",
+    "hundred",
+    "hunters",
+    "hurried",
+    "hurtful",
+    "husband",
+    "hushful",
+    "huskier",
+    "huskily",
+    "hutlike",
+    "hyaenic",
+    "hyaloid",
+    "hydrazo",
+    "hydrous",
+    "hydroxy",
+    "hyenine",
+    "hyenoid",
+    "hypatia",
+    "hypnoid",
+    "hypoxic",
+    "ibrahim",
+    "iceless",
+    "icelike",
+    "icicled",
+    "ickiest",
+    "icteric",
+    "idalias",
+    "ideaful",
+    "ideally",
+    "idellas",
+    "identic",
+    "idiotic",
+    "idyllic",
+    "ignacia",
+    "ignacio",
+    "igneous",
+    "ignoble",
+    "ignobly",
+    "iguanas",
+    "ileanas",
+    "ilianas",
+    "illegal",
+    "illicit",
+    "illness",
+    "imeldas",
+    "imitate",
+    "immense",
+    "immoral",
+    "imogene",
+    "impalas",
+    "impavid",
+    "impious",
+    "implied",
+    "improve",
+    "impulse",
+    "inanely",
+    "inaptly",
+    "inbound",
+    "incised",
+    "incivil",
+    "include",
+    "indiras",
+    "indrawn",
+    "ineptly",
+    "inertly",
+    "inexact",
+    "inflict",
+    "ingrids",
+    "ingrown",
+    "inherit",
+    "inhuman",
+    "initial",
+    "injured",
+    "inkiest",
+    "inkless",
+    "inklike",
+    "inlying",
+    "innerly",
+    "innless",
+    "inphase",
+    "inquiry",
+    "insects",
+    "insides",
+    "insipid",
+    "inspire",
+    "install",
+    "instead",
+    "inswept",
+    "intense",
+    "intimal",
+    "inutile",
+    "involve",
+    "inwards",
+    "iracund",
+    "iraidas",
+    "irately",
+    "ireland",
+    "ireless",
+    "iridous",
+    "irksome",
+    "irmgard",
+    "irvings",
+    "isabela",
+    "isabell",
+    "isabels",
+    "isadora",
+    "isaiahs",
+    "isauras",
+    "ishaans",
+    "isidras",
+    "isidros",
+    "isleted",
+    "ismaels",
+    "isoamyl",
+    "isobels",
+    "isodose",
+    "isolate",
+    "israels",
+    "isreals",
+    "issuant",
+    "itchier",
+    "itchily",
+    "iterant",
+    "ivannas",
+    "ivettes",
+    "ivonnes",
+    "ivylike",
+    "izaiahs",
+    "izayahs",
+    "izettas",
+    "jabirus",
+    "jacalyn",
+    "jacamar",
+    "jacelyn",
+    "jacinda",
+    "jacinta",
+    "jacinto",
+    "jackals",
+    "jackdaw",
+    "jackies",
+    "jackleg",
+    "jacklyn",
+    "jackson",
+    "jaclyns",
+    "jacobys",
+    "jacques",
+    "jacquie",
+    "jacquis",
+    "jadedly",
+    "jadiels",
+    "jadwiga",
+    "jaegers",
+    "jaelynn",
+    "jaelyns",
+    "jaggers",
+    "jaggier",
+    "jagless",
+    "jaguars",
+    "jaidens",
+    "jaimees",
+    "jaimies",
+    "jakobes",
+    "jalapic",
+    "jaleesa",
+    "jalisas",
+    "jaliyah",
+    "jamaals",
+    "jamaris",
+    "jameson",
+    "jamikas",
+    "jamilas",
+    "jamison",
+    "jamlike",
+    "jammies",
+    "janeans",
+    "janeens",
+    "janella",
+    "janelle",
+    "janells",
+    "janenes",
+    "janessa",
+    "janeths",
+    "janetta",
+    "janette",
+    "janetts",
+    "janices",
+    "janiece",
+    "janinas",
+    "janines",
+    "janises",
+    "janitas",
+    "janiyah",
+    "janiyas",
+    "jannets",
+    "jannies",
+    "jannock",
+    "january",
+    "janyces",
+    "jarless",
+    "jarreds",
+    "jarrett",
+    "jarrods",
+    "jaseyed",
+    "jasiahs",
+    "jasmine",
+    "jasmins",
+    "jaspers",
+    "jaspery",
+    "jaunita",
+    "javelin",
+    "javiers",
+    "javions",
+    "jawfish",
+    "jawless",
+    "jawlike",
+    "jaxsons",
+    "jaxtons",
+    "jaybird",
+    "jaycees",
+    "jayceon",
+    "jaycobs",
+    "jaydens",
+    "jaydons",
+    "jaylahs",
+    "jayleen",
+    "jaylees",
+    "jaylene",
+    "jaylens",
+    "jaylins",
+    "jaylynn",
+    "jaymies",
+    "jaysons",
+    "jazlynn",
+    "jazlyns",
+    "jazmine",
+    "jazmins",
+    "jazzier",
+    "jazzily",
+    "jealous",
+    "jeanene",
+    "jeanett",
+    "jeanice",
+    "jeanies",
+    "jeanine",
+    "jeannas",
+    "jeannes",
+    "jeannie",
+    "jeffery",
+    "jeffies",
+    "jeffrey",
+    "jeffrys",
+    "jejunal",
+    "jellied",
+    "jenelle",
+    "jenells",
+    "jenette",
+    "jenevas",
+    "jenices",
+    "jenifer",
+    "jenines",
+    "jenises",
+    "jennell",
+    "jennets",
+    "jennies",
+    "jennine",
+    "jensens",
+    "jeralds",
+    "jeramys",
+    "jerboas",
+    "jeremys",
+    "jericas",
+    "jericho",
+    "jerilyn",
+    "jerkier",
+    "jerkily",
+    "jerlene",
+    "jerolds",
+    "jeromes",
+    "jeromys",
+    "jerrell",
+    "jerrica",
+    "jerries",
+    "jerrods",
+    "jerrold",
+    "jesenia",
+    "jesicas",
+    "jessant",
+    "jessias",
+    "jessica",
+    "jessies",
+    "jessika",
+    "jestful",
+    "jestine",
+    "jesting",
+    "jetties",
+    "jewells",
+    "jewfish",
+    "jiggish",
+    "jiglike",
+    "jillian",
+    "jimenas",
+    "jimmies",
+    "jittery",
+    "joanies",
+    "joannas",
+    "joannes",
+    "joannie",
+    "joaquin",
+    "jocelyn",
+    "jocular",
+    "joeanns",
+    "joellas",
+    "joellen",
+    "joelles",
+    "joesphs",
+    "joettas",
+    "joettes",
+    "johanas",
+    "johanna",
+    "johanne",
+    "johnies",
+    "johnnas",
+    "johnnie",
+    "johnnys",
+    "johnsie",
+    "johnson",
+    "jointed",
+    "jointly",
+    "jolanda",
+    "joleens",
+    "jolenes",
+    "jolines",
+    "jollily",
+    "joltier",
+    "joltily",
+    "jolynns",
+    "jonelle",
+    "jonells",
+    "jonnick",
+    "jonnies",
+    "jordans",
+    "jordens",
+    "jordons",
+    "jordynn",
+    "jordyns",
+    "josefas",
+    "joselyn",
+    "josephs",
+    "josette",
+    "joshuas",
+    "josiahs",
+    "joslyns",
+    "jospehs",
+    "journee",
+    "journey",
+    "jovanis",
+    "jovanni",
+    "jovitas",
+    "jowlier",
+    "joyless",
+    "joziahs",
+    "juanita",
+    "judiths",
+    "judsons",
+    "juicier",
+    "juicily",
+    "julenes",
+    "juliana",
+    "juliane",
+    "juliann",
+    "julians",
+    "juliens",
+    "julieta",
+    "juliets",
+    "julissa",
+    "jumpier",
+    "jumpily",
+    "jungled",
+    "juniors",
+    "juniper",
+    "junitas",
+    "jurally",
+    "jurnees",
+    "jussive",
+    "justice",
+    "justina",
+    "justine",
+    "justins",
+    "juvenal",
+    "kadence",
+    "kaedens",
+    "kaelyns",
+    "kaidens",
+    "kailani",
+    "kailees",
+    "kaileys",
+    "kailyns",
+    "kaitlin",
+    "kaitlyn",
+    "kakapos",
+    "kaleigh",
+    "kaliyah",
+    "kallies",
+    "kalongs",
+    "kamalas",
+    "kamaris",
+    "kamdens",
+    "kamdyns",
+    "kameron",
+    "kamilah",
+    "kamilas",
+    "kamrons",
+    "kamryns",
+    "kandace",
+    "kandice",
+    "kandras",
+    "kanesha",
+    "kanisha",
+    "kannons",
+    "kareems",
+    "kareens",
+    "karenas",
+    "karimas",
+    "karinas",
+    "karines",
+    "karisas",
+    "karissa",
+    "karleen",
+    "karlees",
+    "karlene",
+    "karlies",
+    "karlyns",
+    "karmens",
+    "karoles",
+    "karolyn",
+    "karrens",
+    "karries",
+    "karsons",
+    "karstic",
+    "karsyns",
+    "karters",
+    "katelin",
+    "katelyn",
+    "kathern",
+    "katheys",
+    "kathies",
+    "kathlyn",
+    "kathrin",
+    "kathryn",
+    "kathyrn",
+    "katinas",
+    "katlyns",
+    "katrice",
+    "katrina",
+    "katties",
+    "katydid",
+    "kaycees",
+    "kaydens",
+    "kayleen",
+    "kaylees",
+    "kaylene",
+    "kaylens",
+    "kaylies",
+    "kaylins",
+    "kaylynn",
+    "kaylyns",
+    "kaysens",
+    "kaysons",
+    "kazukos",
+    "keagans",
+    "keatons",
+    "keegans",
+    "keeleys",
+    "keenans",
+    "keeshas",
+    "keishas",
+    "keithas",
+    "kellans",
+    "kellees",
+    "kellens",
+    "kelleys",
+    "kellies",
+    "kellyes",
+    "kelpies",
+    "kelseys",
+    "kelsies",
+    "kelvins",
+    "kendall",
+    "kendals",
+    "kendras",
+    "keneths",
+    "kenisha",
+    "kenleys",
+    "kennedi",
+    "kennedy",
+    "kenneth",
+    "kennith",
+    "kenotic",
+    "kensley",
+    "kentons",
+    "kenzies",
+    "keramic",
+    "kermits",
+    "kerries",
+    "kerstin",
+    "keshias",
+    "kestrel",
+    "ketchup",
+    "ketonic",
+    "keturah",
+    "keyless",
+    "khalils",
+    "kidlike",
+    "kierans",
+    "kieshas",
+    "killian",
+    "kimbers",
+    "kimbery",
+    "kimbras",
+    "kimikos",
+    "kimoras",
+    "kindras",
+    "kindred",
+    "kinesic",
+    "kinetic",
+    "kingdom",
+    "kinglet",
+    "kinkier",
+    "kinkily",
+    "kinkled",
+    "kinless",
+    "kinleys",
+    "kinsley",
+    "kirsten",
+    "kirstie",
+    "kirstin",
+    "kirtled",
+    "kitchen",
+    "kittens",
+    "kitties",
+    "kiyokos",
+    "kizzies",
+    "knarred",
+    "knavish",
+    "knitted",
+    "knobbly",
+    "knotted",
+    "knowing",
+    "knuckly",
+    "knurled",
+    "kodiaks",
+    "kokobeh",
+    "koltens",
+    "koltons",
+    "konners",
+    "konnors",
+    "kookier",
+    "kookily",
+    "korbins",
+    "kortney",
+    "krishna",
+    "krissys",
+    "kristal",
+    "kristan",
+    "kristas",
+    "kristel",
+    "kristen",
+    "kristie",
+    "kristin",
+    "kristis",
+    "kristle",
+    "kristyn",
+    "kristys",
+    "krystal",
+    "krystas",
+    "krysten",
+    "krystin",
+    "krystle",
+    "kyleigh",
+    "kymanis",
+    "kyndall",
+    "kynlees",
+    "labored",
+    "lachlan",
+    "laciest",
+    "laconic",
+    "lactary",
+    "lacunal",
+    "ladawns",
+    "laddery",
+    "laddish",
+    "ladonna",
+    "ladybug",
+    "ladyish",
+    "lahomas",
+    "lailahs",
+    "laineys",
+    "lairdly",
+    "laithly",
+    "lajuana",
+    "lakenya",
+    "lakesha",
+    "lakiest",
+    "lakisha",
+    "lakitas",
+    "lambent",
+    "laminar",
+    "lamonts",
+    "lamprey",
+    "landens",
+    "landons",
+    "landrys",
+    "landyns",
+    "lanelle",
+    "lanells",
+    "lanette",
+    "langued",
+    "languid",
+    "langurs",
+    "lanitas",
+    "lankily",
+    "lannies",
+    "lanoras",
+    "lapwing",
+    "laquita",
+    "laraine",
+    "larcher",
+    "lardier",
+    "largely",
+    "largest",
+    "largish",
+    "larisas",
+    "larissa",
+    "laritas",
+    "larkish",
+    "laronda",
+    "lashaun",
+    "lashawn",
+    "lashays",
+    "lashell",
+    "lashons",
+    "lasonya",
+    "lasting",
+    "latanya",
+    "latasha",
+    "latesha",
+    "lathery",
+    "lathier",
+    "laticia",
+    "latinas",
+    "latisha",
+    "latonia",
+    "latonya",
+    "latoria",
+    "latosha",
+    "latoyas",
+    "latoyia",
+    "latrice",
+    "latrina",
+    "laundry",
+    "laurans",
+    "laureen",
+    "laurels",
+    "laurena",
+    "laurene",
+    "laurens",
+    "laurice",
+    "lauries",
+    "laurine",
+    "lauroyl",
+    "lauryns",
+    "lavadas",
+    "lavelle",
+    "lavenia",
+    "laveras",
+    "laverna",
+    "laverne",
+    "laverns",
+    "lavetas",
+    "lavette",
+    "lavinas",
+    "lavinia",
+    "lavonas",
+    "lavonda",
+    "lavones",
+    "lavonia",
+    "lavonna",
+    "lavonne",
+    "lawanas",
+    "lawanda",
+    "lawanna",
+    "lawless",
+    "lawlike",
+    "lawsons",
+    "lawsuit",
+    "laylahs",
+    "laytons",
+    "lazaros",
+    "laziest",
+    "lazyish",
+    "leadier",
+    "leading",
+    "leafier",
+    "leakily",
+    "leandra",
+    "leandro",
+    "leannas",
+    "leannes",
+    "leanora",
+    "learned",
+    "leathas",
+    "lecture",
+    "ledgier",
+    "leeanna",
+    "leeanne",
+    "leeanns",
+    "leerier",
+    "leerily",
+    "legally",
+    "legends",
+    "leggier",
+    "legible",
+    "legibly",
+    "legless",
+    "leglike",
+    "leighas",
+    "leilani",
+    "leishas",
+    "leisure",
+    "lekisha",
+    "lelands",
+    "lemming",
+    "lemuels",
+    "lenards",
+    "lengthy",
+    "lenient",
+    "lenitas",
+    "lenitic",
+    "lennies",
+    "lennons",
+    "lennoxs",
+    "lenoras",
+    "lenores",
+    "leonard",
+    "leonels",
+    "leonias",
+    "leonida",
+    "leonies",
+    "leonila",
+    "leonine",
+    "leonora",
+    "leonore",
+    "leonors",
+    "leopard",
+    "leprose",
+    "leprous",
+    "leslees",
+    "lesleys",
+    "leslies",
+    "lessies",
+    "lesters",
+    "leticia",
+    "letisha",
+    "letties",
+    "levelly",
+    "lexical",
+    "lezlies",
+    "liannes",
+    "lianoid",
+    "libbies",
+    "liberal",
+    "liberty",
+    "librada",
+    "library",
+    "license",
+    "lichtly",
+    "licitly",
+    "lidless",
+    "lifeful",
+    "lighter",
+    "lightly",
+    "ligular",
+    "likable",
+    "liliana",
+    "lilians",
+    "liliths",
+    "lilliam",
+    "lillian",
+    "lillias",
+    "lillies",
+    "lilyana",
+    "limbate",
+    "limiest",
+    "liminal",
+    "limpets",
+    "limpkin",
+    "linable",
+    "lincoln",
+    "lindsay",
+    "lindsey",
+    "lindsys",
+    "lineate",
+    "linette",
+    "liniest",
+    "linneas",
+    "linnets",
+    "linnies",
+    "linseys",
+    "lintier",
+    "linwood",
+    "lionels",
+    "lioness",
+    "lipemic",
+    "lipless",
+    "liplike",
+    "liquory",
+    "lisbeth",
+    "lisette",
+    "lispily",
+    "lissome",
+    "lithely",
+    "lithest",
+    "lithoid",
+    "littery",
+    "livable",
+    "lividly",
+    "lizards",
+    "lizbeth",
+    "lizeths",
+    "lizette",
+    "lizzies",
+    "loathly",
+    "lobster",
+    "lobular",
+    "locally",
+    "lochial",
+    "lochlan",
+    "locular",
+    "locusts",
+    "loessal",
+    "loftier",
+    "loftily",
+    "loggish",
+    "logical",
+    "logiest",
+    "londons",
+    "londyns",
+    "longish",
+    "lonnies",
+    "loonier",
+    "loonies",
+    "loonily",
+    "loopily",
+    "loosely",
+    "loraine",
+    "loralee",
+    "loreans",
+    "loreens",
+    "lorelai",
+    "lorelei",
+    "lorenas",
+    "lorenes",
+    "lorenza",
+    "lorenzo",
+    "loretas",
+    "loretta",
+    "lorette",
+    "loriann",
+    "lorilee",
+    "lorinas",
+    "lorinda",
+    "lorines",
+    "loritas",
+    "lorries",
+    "lorrine",
+    "losable",
+    "lottery",
+    "lotties",
+    "louanne",
+    "louanns",
+    "loudish",
+    "louella",
+    "louetta",
+    "louisas",
+    "louises",
+    "lourdes",
+    "louries",
+    "louring",
+    "lousier",
+    "lousily",
+    "loutish",
+    "louvars",
+    "louvred",
+    "lovable",
+    "lovably",
+    "lovella",
+    "loverly",
+    "lovetta",
+    "lowborn",
+    "lowbred",
+    "lowells",
+    "lowlier",
+    "lowlily",
+    "lowsest",
+    "loyally",
+    "lozengy",
+    "luannas",
+    "luannes",
+    "luciana",
+    "luciano",
+    "lucians",
+    "lucidly",
+    "luciens",
+    "lucilas",
+    "luciles",
+    "lucilla",
+    "lucille",
+    "lucinas",
+    "lucinda",
+    "luckier",
+    "luckily",
+    "luellas",
+    "luettas",
+    "luggage",
+    "lumpier",
+    "lumpily",
+    "lumpish",
+    "luniest",
+    "lunular",
+    "lupitas",
+    "luridly",
+    "lurlene",
+    "lurline",
+    "lushier",
+    "lustful",
+    "lustier",
+    "lustily",
+    "lustral",
+    "lustred",
+    "luteous",
+    "luthers",
+    "luvenia",
+    "lyingly",
+    "lyncean",
+    "lyndias",
+    "lyndons",
+    "lyndsay",
+    "lyndsey",
+    "lynelle",
+    "lynells",
+    "lynetta",
+    "lynette",
+    "lynseys",
+    "lynwood",
+    "lyrical",
+    "mabelle",
+    "macabre",
+    "macaque",
+    "machine",
+    "macular",
+    "madalyn",
+    "maddens",
+    "maddest",
+    "maddies",
+    "madding",
+    "maddish",
+    "maddoxs",
+    "madelyn",
+    "madilyn",
+    "madison",
+    "madisyn",
+    "madlyns",
+    "madonna",
+    "madyson",
+    "maegans",
+    "mafalda",
+    "magalis",
+    "magalys",
+    "magaret",
+    "maggies",
+    "maggots",
+    "maggoty",
+    "magical",
+    "magpies",
+    "mahalia",
+    "maishas",
+    "maisies",
+    "maisons",
+    "majorie",
+    "majorly",
+    "makable",
+    "makayla",
+    "makedas",
+    "makenas",
+    "makenna",
+    "malachi",
+    "malakai",
+    "malayas",
+    "malcolm",
+    "malcoms",
+    "maleahs",
+    "malenas",
+    "maliahs",
+    "malikas",
+    "malinda",
+    "malisas",
+    "malissa",
+    "maliyah",
+    "mallard",
+    "mallies",
+    "mallory",
+    "malonic",
+    "malonyl",
+    "malorie",
+    "malvina",
+    "mammals",
+    "mammary",
+    "mammies",
+    "mammoth",
+    "manakin",
+    "manasic",
+    "manatee",
+    "mandate",
+    "mandies",
+    "mangier",
+    "mangily",
+    "manless",
+    "manlier",
+    "manlike",
+    "manlily",
+    "mannish",
+    "mansion",
+    "mantric",
+    "manuals",
+    "manuela",
+    "manuels",
+    "maranda",
+    "marcela",
+    "marcell",
+    "marcelo",
+    "marcels",
+    "marcene",
+    "marcias",
+    "marcies",
+    "mardell",
+    "margart",
+    "margays",
+    "margene",
+    "margert",
+    "margery",
+    "margets",
+    "margies",
+    "margits",
+    "margots",
+    "margret",
+    "marhtas",
+    "mariahs",
+    "mariams",
+    "mariana",
+    "mariann",
+    "mariano",
+    "marians",
+    "maribel",
+    "maricas",
+    "mariela",
+    "mariels",
+    "marikos",
+    "marilee",
+    "marilou",
+    "marilus",
+    "marilyn",
+    "marinas",
+    "marinda",
+    "marines",
+    "marions",
+    "marisas",
+    "marisha",
+    "marisol",
+    "marissa",
+    "marital",
+    "maritas",
+    "maritza",
+    "marivel",
+    "mariyah",
+    "marjory",
+    "markhor",
+    "markita",
+    "marlana",
+    "marleen",
+    "marlees",
+    "marlena",
+    "marlene",
+    "marlens",
+    "marleys",
+    "marline",
+    "marlins",
+    "marlons",
+    "marlyns",
+    "marmots",
+    "marnies",
+    "marquis",
+    "marshas",
+    "martela",
+    "martens",
+    "marthas",
+    "martial",
+    "martina",
+    "martine",
+    "martins",
+    "marvels",
+    "marvins",
+    "maryams",
+    "maryann",
+    "maryjos",
+    "marylee",
+    "marylin",
+    "marylns",
+    "marylou",
+    "marylyn",
+    "masakos",
+    "masonic",
+    "massive",
+    "mastiff",
+    "mathews",
+    "mathias",
+    "matilda",
+    "matilde",
+    "matless",
+    "matteos",
+    "mattery",
+    "matthew",
+    "matties",
+    "maudies",
+    "maudlin",
+    "maureen",
+    "maurice",
+    "maurine",
+    "maurita",
+    "mawkish",
+    "maximal",
+    "maximas",
+    "maximos",
+    "maximum",
+    "maximus",
+    "maxines",
+    "maxtons",
+    "maxwell",
+    "maybell",
+    "mayflys",
+    "maynard",
+    "mayolas",
+    "mayoral",
+    "maysons",
+    "mazedly",
+    "maziest",
+    "mckayla",
+    "mckenna",
+    "meadows",
+    "meagans",
+    "meaghan",
+    "mealier",
+    "measled",
+    "measure",
+    "meatier",
+    "meatily",
+    "meerkat",
+    "meggans",
+    "meghann",
+    "meghans",
+    "meiotic",
+    "melaine",
+    "melania",
+    "melanic",
+    "melanie",
+    "melanis",
+    "melanys",
+    "melidas",
+    "melinas",
+    "melinda",
+    "melisas",
+    "melissa",
+    "melitas",
+    "mellies",
+    "mellisa",
+    "melodee",
+    "melodic",
+    "melodie",
+    "melodis",
+    "melodys",
+    "melonie",
+    "melonys",
+    "melvina",
+    "melvins",
+    "melynda",
+    "memphis",
+    "menadic",
+    "mention",
+    "merilyn",
+    "merissa",
+    "merited",
+    "merlene",
+    "merlins",
+    "merlyns",
+    "merrier",
+    "merries",
+    "merrill",
+    "merrily",
+    "merties",
+    "mervins",
+    "mesally",
+    "mesarch",
+    "meshuga",
+    "message",
+    "messiah",
+    "messier",
+    "messily",
+    "methoxy",
+    "metopic",
+    "mettled",
+    "miasmal",
+    "miasmic",
+    "micaela",
+    "michael",
+    "michale",
+    "michals",
+    "micheal",
+    "michele",
+    "michell",
+    "michels",
+    "michiko",
+    "mickeys",
+    "mickies",
+    "mieshas",
+    "miffier",
+    "mignons",
+    "miguels",
+    "mikaela",
+    "mikayla",
+    "milagro",
+    "milanas",
+    "milania",
+    "mildewy",
+    "mildred",
+    "milenas",
+    "miliary",
+    "milissa",
+    "milkier",
+    "milkily",
+    "millard",
+    "millers",
+    "millies",
+    "million",
+    "miltons",
+    "mimetic",
+    "mimical",
+    "minable",
+    "mincing",
+    "mindful",
+    "minerva",
+    "minimal",
+    "minimum",
+    "minimus",
+    "minivet",
+    "minnies",
+    "minnows",
+    "minxish",
+    "miquels",
+    "miracle",
+    "miranda",
+    "mirella",
+    "mireyas",
+    "miriams",
+    "mirians",
+    "miriest",
+    "mirkier",
+    "mirkily",
+    "mirthas",
+    "misaels",
+    "misally",
+    "miserly",
+    "misrely",
+    "missing",
+    "mistake",
+    "mistier",
+    "misties",
+    "mistily",
+    "mitchel",
+    "mitered",
+    "mitotic",
+    "mitsues",
+    "mitsuko",
+    "mitties",
+    "mitzies",
+    "mixable",
+    "mixedly",
+    "mixible",
+    "mixture",
+    "miyokos",
+    "moanful",
+    "mobbish",
+    "modally",
+    "moderne",
+    "modesta",
+    "modesto",
+    "modular",
+    "moistly",
+    "moitier",
+    "mollies",
+    "mollusk",
+    "monarch",
+    "mondial",
+    "moneyed",
+    "mongrel",
+    "monicas",
+    "monikas",
+    "monique",
+    "monitor",
+    "monkeys",
+    "monkish",
+    "monnies",
+    "monodic",
+    "monroes",
+    "monster",
+    "montane",
+    "monthly",
+    "moodier",
+    "moodily",
+    "mooneye",
+    "moonily",
+    "moonish",
+    "moonlit",
+    "moorhen",
+    "mopiest",
+    "morally",
+    "moreish",
+    "morgans",
+    "moriahs",
+    "morning",
+    "moronic",
+    "mortary",
+    "mortons",
+    "mossier",
+    "mothier",
+    "motmots",
+    "mottled",
+    "mouflon",
+    "mouilla",
+    "mounted",
+    "mousier",
+    "mousily",
+    "movably",
+    "mozella",
+    "mozelle",
+    "mozells",
+    "muckier",
+    "muckily",
+    "mucosal",
+    "muddier",
+    "muddily",
+    "mudfish",
+    "muggier",
+    "muggily",
+    "mullets",
+    "mundane",
+    "murally",
+    "muriels",
+    "murkily",
+    "murrays",
+    "museful",
+    "mushier",
+    "mushily",
+    "musical",
+    "muskier",
+    "muskily",
+    "muskoxs",
+    "muskrat",
+    "mussier",
+    "mussily",
+    "mustafa",
+    "mustang",
+    "mustier",
+    "mustily",
+    "mutable",
+    "mutably",
+    "mutedly",
+    "muttony",
+    "mutular",
+    "muzzily",
+    "myalgic",
+    "myeloid",
+    "myeshas",
+    "myriams",
+    "myrtice",
+    "myrties",
+    "myrtles",
+    "mystery",
+    "nadenes",
+    "nadines",
+    "nadiral",
+    "naevoid",
+    "naggier",
+    "naggish",
+    "naively",
+    "nakedly",
+    "nakesha",
+    "nakisha",
+    "nakitas",
+    "nancees",
+    "nanceys",
+    "nancies",
+    "nanette",
+    "nannies",
+    "naovely",
+    "napless",
+    "nappier",
+    "narcisa",
+    "narcose",
+    "nardine",
+    "narwhal",
+    "nasally",
+    "nascent",
+    "nastier",
+    "nastily",
+    "natacha",
+    "natalee",
+    "natalia",
+    "natalie",
+    "natalya",
+    "natalys",
+    "natasha",
+    "nathaly",
+    "nathans",
+    "natisha",
+    "natosha",
+    "nattier",
+    "nattily",
+    "natural",
+    "navally",
+    "nayelis",
+    "necoles",
+    "needful",
+    "needier",
+    "needily",
+    "neglect",
+    "neither",
+    "nelidas",
+    "nellies",
+    "nelsons",
+    "nematic",
+    "nenitas",
+    "nephric",
+    "nepotic",
+    "nereida",
+    "neriahs",
+    "nerissa",
+    "neritic",
+    "nervate",
+    "nervily",
+    "nervous",
+    "nestors",
+    "netlike",
+    "netties",
+    "network",
+    "neustic",
+    "neutral",
+    "nevadas",
+    "nevaehs",
+    "neville",
+    "newborn",
+    "newsier",
+    "newtons",
+    "neymars",
+    "nguyets",
+    "niblike",
+    "nichole",
+    "nichols",
+    "nickies",
+    "nickole",
+    "nicolas",
+    "nicoles",
+    "nicolle",
+    "nieshas",
+    "niftier",
+    "nifties",
+    "niftily",
+    "nightly",
+    "nikitas",
+    "nikolai",
+    "nikolas",
+    "nikoles",
+    "nilgais",
+    "ninthly",
+    "niobous",
+    "nippily",
+    "nipping",
+    "nitrous",
+    "nittier",
+    "niveous",
+    "nobbier",
+    "nobbily",
+    "noblest",
+    "nobukos",
+    "nocuous",
+    "nodally",
+    "nodical",
+    "nodular",
+    "noelias",
+    "noellas",
+    "noelles",
+    "nohemis",
+    "noisier",
+    "noisily",
+    "noisome",
+    "nomadic",
+    "nominee",
+    "nonbusy",
+    "noncash",
+    "nondark",
+    "nondeaf",
+    "nondeep",
+    "nonevil",
+    "nonfarm",
+    "nonlive",
+    "nonoily",
+    "nonoral",
+    "nonpaid",
+    "nonsane",
+    "nonslip",
+    "nonzero",
+    "norbert",
+    "noreens",
+    "norenes",
+    "norikos",
+    "norines",
+    "noritic",
+    "normand",
+    "normans",
+    "nosiest",
+    "notable",
+    "notably",
+    "notedly",
+    "nothing",
+    "novella",
+    "novelly",
+    "noxious",
+    "nuclear",
+    "nuggety",
+    "numbats",
+    "numbers",
+    "numbing",
+    "numeric",
+    "nummary",
+    "nunlike",
+    "nuptial",
+    "nutlike",
+    "nutrias",
+    "nuttily",
+    "nymphal",
+    "oakleys",
+    "oarfish",
+    "oarless",
+    "oarlike",
+    "oasitic",
+    "obconic",
+    "obdulia",
+    "obesely",
+    "obovate",
+    "obovoid",
+    "obscene",
+    "obscure",
+    "observe",
+    "obvious",
+    "ocellar",
+    "oceloid",
+    "ocelots",
+    "ocreate",
+    "octadic",
+    "octaval",
+    "octavia",
+    "octavio",
+    "october",
+    "octopus",
+    "octuply",
+    "odelias",
+    "odessas",
+    "odettes",
+    "odilias",
+    "odorful",
+    "odorous",
+    "oedipal",
+    "ofelias",
+    "ogreish",
+    "oidioid",
+    "oilbird",
+    "oiliest",
+    "oilless",
+    "oillike",
+    "oldwife",
+    "olevias",
+    "olimpia",
+    "olindas",
+    "olivary",
+    "olivers",
+    "olivias",
+    "olympia",
+    "olympic",
+    "omental",
+    "ominous",
+    "omnific",
+    "onagers",
+    "oncotic",
+    "oneidas",
+    "oneiric",
+    "onerous",
+    "ongoing",
+    "onwards",
+    "onymous",
+    "oolitic",
+    "ooziest",
+    "operose",
+    "ophelia",
+    "ophitic",
+    "opinion",
+    "opossum",
+    "opsonic",
+    "optical",
+    "opticly",
+    "optimal",
+    "optimum",
+    "opulent",
+    "oralees",
+    "oralias",
+    "orchard",
+    "orderly",
+    "orectic",
+    "orethas",
+    "organic",
+    "orioles",
+    "orlando",
+    "orotund",
+    "orville",
+    "oscular",
+    "osiered",
+    "osmious",
+    "ospreys",
+    "osseous",
+    "osteoid",
+    "ostrich",
+    "osvaldo",
+    "oswaldo",
+    "otalgic",
+    "otelias",
+    "otilias",
+    "oulitic",
+    "outdoor",
+    "outeyed",
+    "outmost",
+    "outside",
+    "outward",
+    "ovarian",
+    "ovately",
+    "overage",
+    "overapt",
+    "overbig",
+    "overcoy",
+    "overdry",
+    "overdue",
+    "overfat",
+    "overfew",
+    "overfit",
+    "overfly",
+    "overhot",
+    "overlax",
+    "overply",
+    "oversad",
+    "overtly",
+    "oviform",
+    "owllike",
+    "oxblood",
+    "oysters",
+    "ozellas",
+    "ozonous",
+    "pacific",
+    "paginal",
+    "painful",
+    "painted",
+    "paislee",
+    "paisley",
+    "paityns",
+    "palaced",
+    "pallial",
+    "palmary",
+    "palmate",
+    "palmers",
+    "palmier",
+    "palmira",
+    "palomas",
+    "paludal",
+    "pamalas",
+    "pamelas",
+    "pamelia",
+    "pamella",
+    "pamilas",
+    "pampean",
+    "pamulas",
+    "pandora",
+    "panicky",
+    "panoply",
+    "panther",
+    "papally",
+    "papayan",
+    "paplike",
+    "pappose",
+    "papular",
+    "papyral",
+    "pardine",
+    "parkers",
+    "parodic",
+    "parotic",
+    "parrots",
+    "partway",
+    "pastier",
+    "pastose",
+    "patient",
+    "patrias",
+    "patrica",
+    "patrice",
+    "patrick",
+    "patrina",
+    "pattern",
+    "patties",
+    "paulene",
+    "paulina",
+    "pauline",
+    "paulita",
+    "paunchy",
+    "pawkily",
+    "paxtons",
+    "payable",
+    "payably",
+    "payment",
+    "paytons",
+    "peacock",
+    "peafowl",
+    "peakily",
+    "peakish",
+    "pealike",
+    "pearles",
+    "pearlie",
+    "pearlys",
+    "peartly",
+    "peasant",
+    "peatier",
+    "peccant",
+    "peccary",
+    "peckier",
+    "peckish",
+    "pectous",
+    "peevish",
+    "pegasus",
+    "peggies",
+    "pegless",
+    "peglike",
+    "pelagic",
+    "pelican",
+    "pelitic",
+    "peloric",
+    "peltate",
+    "penally",
+    "penalty",
+    "penguin",
+    "pennate",
+    "penneys",
+    "pennied",
+    "pennies",
+    "pensile",
+    "pensive",
+    "peppery",
+    "peppier",
+    "peppily",
+    "perfect",
+    "perfumy",
+    "perkier",
+    "perkily",
+    "perkish",
+    "persons",
+    "peskier",
+    "peskily",
+    "petaled",
+    "petrels",
+    "petrina",
+    "petrous",
+    "pettier",
+    "pettily",
+    "pettish",
+    "peytons",
+    "phallic",
+    "phaseal",
+    "philips",
+    "phillip",
+    "phillis",
+    "phlegmy",
+    "phocine",
+    "phoebes",
+    "phoenix",
+    "phonier",
+    "phonies",
+    "phonily",
+    "phrasal",
+    "phrenic",
+    "phugoid",
+    "phuongs",
+    "phyliss",
+    "phyllis",
+    "piceous",
+    "pickier",
+    "pickily",
+    "pickled",
+    "picture",
+    "piedads",
+    "pielike",
+    "pierces",
+    "pierres",
+    "pigeons",
+    "pigfish",
+    "piggish",
+    "piglets",
+    "pileate",
+    "pileous",
+    "pillowy",
+    "pinfire",
+    "pinguid",
+    "piniest",
+    "pinkies",
+    "pinkish",
+    "pinnate",
+    "pintail",
+    "pioneer",
+    "piously",
+    "pipiest",
+    "piquant",
+    "piranha",
+    "piratic",
+    "piscine",
+    "piteous",
+    "pithily",
+    "pitiful",
+    "pivotal",
+    "pixyish",
+    "placoid",
+    "plaices",
+    "plaided",
+    "plainly",
+    "plantar",
+    "plastic",
+    "platier",
+    "playful",
+    "pleased",
+    "pleonal",
+    "pleonic",
+    "pleural",
+    "pliable",
+    "pliably",
+    "plicate",
+    "plotful",
+    "plovers",
+    "plumate",
+    "plumbic",
+    "plumier",
+    "plumose",
+    "plumply",
+    "plushed",
+    "plusher",
+    "plushly",
+    "pluteal",
+    "plutean",
+    "poacher",
+    "pochard",
+    "pockier",
+    "pockily",
+    "podgier",
+    "podgily",
+    "poditic",
+    "pointed",
+    "pointer",
+    "pokable",
+    "pokiest",
+    "polecat",
+    "politic",
+    "pollock",
+    "pomfret",
+    "pompano",
+    "pompous",
+    "pontine",
+    "poodles",
+    "popeyed",
+    "poppied",
+    "popular",
+    "porcine",
+    "porkier",
+    "porsche",
+    "porshas",
+    "porters",
+    "portias",
+    "portion",
+    "possums",
+    "potamic",
+    "potenty",
+    "potlike",
+    "potoroo",
+    "pottery",
+    "pottier",
+    "pouched",
+    "poutful",
+    "poutily",
+    "poverty",
+    "powered",
+    "practic",
+    "praised",
+    "preachy",
+    "preacid",
+    "preborn",
+    "precise",
+    "predark",
+    "predial",
+    "predict",
+    "pregame",
+    "premier",
+    "premium",
+    "preotic",
+    "prepare",
+    "present",
+    "presley",
+    "pressor",
+    "preston",
+    "prevent",
+    "priapic",
+    "pricily",
+    "prickly",
+    "primary",
+    "primate",
+    "primely",
+    "primsie",
+    "princes",
+    "priorly",
+    "private",
+    "privier",
+    "privies",
+    "privily",
+    "proarmy",
+    "problem",
+    "process",
+    "procity",
+    "produce",
+    "profuse",
+    "program",
+    "project",
+    "prolate",
+    "promote",
+    "pronavy",
+    "pronely",
+    "prosaic",
+    "prosely",
+    "prosily",
+    "prosper",
+    "protean",
+    "protect",
+    "proudly",
+    "provide",
+    "prudent",
+    "prudish",
+    "prunted",
+    "psalmic",
+    "psoatic",
+    "psychic",
+    "puckery",
+    "pudding",
+    "pudgily",
+    "puerile",
+    "puffers",
+    "puffier",
+    "puffily",
+    "puffins",
+    "puggish",
+    "pulpier",
+    "pulpily",
+    "pumpkin",
+    "pungent",
+    "puniest",
+    "purpose",
+    "pursier",
+    "pursily",
+    "pushier",
+    "pushily",
+    "puslike",
+    "pyaemic",
+    "pygmoid",
+    "pyloric",
+    "pyramid",
+    "pyretic",
+    "pyridic",
+    "pyritic",
+    "pyruvic",
+    "pythons",
+    "quaggas",
+    "quahogs",
+    "quakier",
+    "quakily",
+    "quality",
+    "quantal",
+    "quantum",
+    "quartan",
+    "quarter",
+    "quartus",
+    "quavery",
+    "queenie",
+    "queenly",
+    "queerly",
+    "quentin",
+    "quetzal",
+    "quianas",
+    "quicker",
+    "quickly",
+    "quietly",
+    "quilted",
+    "quinate",
+    "quincys",
+    "quintan",
+    "quintic",
+    "quintin",
+    "quinton",
+    "quivery",
+    "quondam",
+    "rabbits",
+    "rabidly",
+    "raccoon",
+    "racemed",
+    "racemic",
+    "rachael",
+    "rachals",
+    "racheal",
+    "rachele",
+    "rachell",
+    "rachels",
+    "raciest",
+    "rackety",
+    "racquel",
+    "raddled",
+    "radiant",
+    "radular",
+    "raeanns",
+    "raegans",
+    "raelene",
+    "raelynn",
+    "raelyns",
+    "rafaela",
+    "rafaels",
+    "raffish",
+    "raggedy",
+    "raguels",
+    "raidens",
+    "rainier",
+    "rainily",
+    "raisiny",
+    "rajasic",
+    "raleigh",
+    "ralline",
+    "ramiros",
+    "ramlike",
+    "rammish",
+    "ramonas",
+    "rampant",
+    "randall",
+    "randals",
+    "randees",
+    "randell",
+    "randily",
+    "rangier",
+    "rankish",
+    "ranular",
+    "raphael",
+    "rapidly",
+    "raptors",
+    "raquels",
+    "rashads",
+    "rashida",
+    "raspier",
+    "raspily",
+    "ratable",
+    "ratably",
+    "rathely",
+    "ratlike",
+    "rattail",
+    "rattier",
+    "rattish",
+    "rattler",
+    "raucous",
+    "raunchy",
+    "ravelly",
+    "raviney",
+    "raydens",
+    "rayford",
+    "raylans",
+    "raylene",
+    "rayless",
+    "raymond",
+    "raymons",
+    "readier",
+    "readily",
+    "reagans",
+    "reannas",
+    "reapply",
+    "reathas",
+    "rebbeca",
+    "rebecas",
+    "rebecca",
+    "rebecka",
+    "rebekah",
+    "reblown",
+    "reboant",
+    "rebuild",
+    "receive",
+    "recycle",
+    "redbird",
+    "reddest",
+    "reddish",
+    "redfish",
+    "redhead",
+    "redpoll",
+    "reduced",
+    "reedier",
+    "reedily",
+    "refined",
+    "reflect",
+    "refugia",
+    "refugio",
+    "regally",
+    "regenas",
+    "regenia",
+    "reggies",
+    "reginas",
+    "regines",
+    "reginia",
+    "regnant",
+    "regular",
+    "related",
+    "relaxed",
+    "relaxer",
+    "release",
+    "reliant",
+    "remonas",
+    "remoras",
+    "remoter",
+    "removed",
+    "renaldo",
+    "renatas",
+    "renates",
+    "renatos",
+    "renetta",
+    "renewed",
+    "renitas",
+    "replace",
+    "replete",
+    "reptant",
+    "reptile",
+    "require",
+    "ressies",
+    "restful",
+    "resting",
+    "restive",
+    "retally",
+    "retiary",
+    "retreat",
+    "reubens",
+    "reunion",
+    "revered",
+    "revived",
+    "rheumic",
+    "rhombic",
+    "rhondas",
+    "riantly",
+    "ribbony",
+    "ribless",
+    "riblike",
+    "ricarda",
+    "ricardo",
+    "richard",
+    "richies",
+    "rickety",
+    "rickeys",
+    "rickies",
+    "ridable",
+    "ridgier",
+    "rightly",
+    "rigidly",
+    "rihanna",
+    "rimfire",
+    "rimless",
+    "ringent",
+    "riotous",
+    "ripping",
+    "risible",
+    "risibly",
+    "riskier",
+    "riskily",
+    "rissola",
+    "ritzier",
+    "ritzily",
+    "robbies",
+    "robbins",
+    "robbyns",
+    "robenas",
+    "roberta",
+    "roberto",
+    "roberts",
+    "rochell",
+    "rochels",
+    "rockier",
+    "rodents",
+    "rodgers",
+    "rodless",
+    "rodlike",
+    "rodneys",
+    "rodolfo",
+    "rodrick",
+    "rodrigo",
+    "rogelio",
+    "roguish",
+    "roilier",
+    "rolanda",
+    "rolande",
+    "rolando",
+    "rolands",
+    "rolland",
+    "rollers",
+    "rolltop",
+    "romaine",
+    "romanas",
+    "romance",
+    "romelia",
+    "romonas",
+    "rompish",
+    "ronalds",
+    "ronnies",
+    "rookier",
+    "roomier",
+    "roomily",
+    "rooster",
+    "ropable",
+    "ropiest",
+    "rosalba",
+    "rosalee",
+    "rosalia",
+    "rosalie",
+    "rosalva",
+    "rosalyn",
+    "rosanas",
+    "rosanna",
+    "rosanne",
+    "rosanns",
+    "rosaria",
+    "rosario",
+    "rosaura",
+    "roscoes",
+    "roseann",
+    "roseate",
+    "roselee",
+    "roselia",
+    "rosella",
+    "roselle",
+    "roselyn",
+    "rosenas",
+    "rosenda",
+    "rosendo",
+    "rosetta",
+    "rosette",
+    "rosinas",
+    "rositas",
+    "roslyns",
+    "rossana",
+    "rossies",
+    "rostral",
+    "roughly",
+    "roughys",
+    "rounded",
+    "roundly",
+    "roupily",
+    "rousing",
+    "rowable",
+    "rowdily",
+    "rowenas",
+    "roxanas",
+    "roxanes",
+    "roxanna",
+    "roxanne",
+    "roxanns",
+    "royally",
+    "rozanne",
+    "rozella",
+    "rubbery",
+    "rubbisy",
+    "rubidic",
+    "rubious",
+    "ruddier",
+    "ruddily",
+    "rudolfs",
+    "rudolph",
+    "ruebens",
+    "ruffled",
+    "rufinas",
+    "ruinous",
+    "rumless",
+    "runnier",
+    "runtier",
+    "runtish",
+    "ruperts",
+    "rurally",
+    "rushier",
+    "russell",
+    "russels",
+    "russety",
+    "rustier",
+    "rustily",
+    "rustred",
+    "ruthann",
+    "ruthful",
+    "ruthies",
+    "ruttier",
+    "ruttily",
+    "ruttish",
+    "rylands",
+    "ryleigh",
+    "sabinas",
+    "sabines",
+    "saboted",
+    "sabrina",
+    "sachiko",
+    "saclike",
+    "sadness",
+    "sainted",
+    "saintly",
+    "salable",
+    "salably",
+    "salenas",
+    "salinas",
+    "salleys",
+    "sallies",
+    "sallowy",
+    "salmons",
+    "salomes",
+    "saltant",
+    "saltily",
+    "saltish",
+    "samaras",
+    "samatha",
+    "sambars",
+    "samella",
+    "samiras",
+    "samiyah",
+    "sammies",
+    "samsons",
+    "samuals",
+    "samuels",
+    "sandees",
+    "sandfly",
+    "sandier",
+    "sandies",
+    "sandras",
+    "sanford",
+    "sanious",
+    "saniyah",
+    "saniyas",
+    "sanoras",
+    "santana",
+    "santina",
+    "santino",
+    "sapient",
+    "sappier",
+    "sappily",
+    "sarahis",
+    "sarcous",
+    "sardine",
+    "sariahs",
+    "sarinas",
+    "saritas",
+    "saronic",
+    "satanic",
+    "satedly",
+    "satisfy",
+    "satoshi",
+    "sattvic",
+    "satyric",
+    "saucier",
+    "saucily",
+    "saugers",
+    "saundra",
+    "sausage",
+    "savable",
+    "savanna",
+    "savvily",
+    "sawfish",
+    "sawflys",
+    "sawlike",
+    "sawyers",
+    "sayable",
+    "saylors",
+    "scaldic",
+    "scalene",
+    "scalier",
+    "scalled",
+    "scallop",
+    "scandic",
+    "scantly",
+    "scapose",
+    "scarcer",
+    "scarily",
+    "scarlet",
+    "scatter",
+    "scented",
+    "sciatic",
+    "science",
+    "scopate",
+    "scoters",
+    "scottie",
+    "scottys",
+    "scraggy",
+    "scrappy",
+    "scrawly",
+    "scrawny",
+    "screaky",
+    "screwed",
+    "scribal",
+    "scroggy",
+    "scrotal",
+    "scrubby",
+    "scruffy",
+    "sculpin",
+    "scutate",
+    "seagirt",
+    "seagull",
+    "seamier",
+    "seasons",
+    "sebacic",
+    "sebrina",
+    "sectile",
+    "section",
+    "secured",
+    "seeable",
+    "seedier",
+    "seedily",
+    "seelily",
+    "segment",
+    "seismic",
+    "selenas",
+    "selenes",
+    "selenic",
+    "selfish",
+    "selinas",
+    "sematic",
+    "semidry",
+    "semimat",
+    "seminal",
+    "seminar",
+    "semiraw",
+    "senaida",
+    "sensate",
+    "sensory",
+    "sensual",
+    "sepaled",
+    "septate",
+    "serenas",
+    "serfish",
+    "sergios",
+    "seriate",
+    "seriema",
+    "serinas",
+    "serious",
+    "seritas",
+    "serried",
+    "serumal",
+    "servals",
+    "service",
+    "servile",
+    "sessile",
+    "session",
+    "setsuko",
+    "setters",
+    "settled",
+    "severer",
+    "sewable",
+    "seymour",
+    "shadfly",
+    "shadily",
+    "shadowy",
+    "shainas",
+    "shakias",
+    "shakier",
+    "shakily",
+    "shakira",
+    "shakita",
+    "shallow",
+    "shalons",
+    "shameka",
+    "shamika",
+    "shanaes",
+    "shandas",
+    "shandis",
+    "shandra",
+    "shaneka",
+    "shanell",
+    "shanels",
+    "shanice",
+    "shanika",
+    "shanita",
+    "shannan",
+    "shannas",
+    "shannon",
+    "shanons",
+    "shantae",
+    "shantas",
+    "shantay",
+    "shantel",
+    "shantes",
+    "shantis",
+    "shapely",
+    "sharans",
+    "shardas",
+    "sharees",
+    "sharell",
+    "sharens",
+    "sharice",
+    "sharies",
+    "sharika",
+    "sharing",
+    "sharita",
+    "sharlas",
+    "sharons",
+    "sharply",
+    "sharris",
+    "sharron",
+    "sharyls",
+    "sharyns",
+    "shastas",
+    "shaunas",
+    "shaunda",
+    "shaunna",
+    "shaunta",
+    "shaunte",
+    "shavons",
+    "shawana",
+    "shawnas",
+    "shawnda",
+    "shawnee",
+    "shawnna",
+    "shawnta",
+    "shaylas",
+    "shaynas",
+    "shaynes",
+    "sheathy",
+    "sheenas",
+    "sheenly",
+    "sheerly",
+    "sheilah",
+    "sheilas",
+    "shelbas",
+    "shelbys",
+    "sheldon",
+    "shelias",
+    "shellas",
+    "shelled",
+    "shelley",
+    "shellie",
+    "shellis",
+    "shellys",
+    "shelton",
+    "shemeka",
+    "shemika",
+    "shenika",
+    "shenita",
+    "shennas",
+    "sherees",
+    "sherell",
+    "sherice",
+    "sheries",
+    "sheriff",
+    "sherika",
+    "sherill",
+    "sherise",
+    "sherita",
+    "sherley",
+    "sherlyn",
+    "sherlys",
+    "sherman",
+    "sherons",
+    "sherrie",
+    "sherril",
+    "sherris",
+    "sherron",
+    "sherryl",
+    "sherrys",
+    "sheryll",
+    "sheryls",
+    "shielas",
+    "shilohs",
+    "shiners",
+    "shingly",
+    "shinier",
+    "shinily",
+    "shining",
+    "shirely",
+    "shirlee",
+    "shirley",
+    "shirlys",
+    "shivery",
+    "shizues",
+    "shizuko",
+    "shondas",
+    "shondra",
+    "shonnas",
+    "shontas",
+    "shoofly",
+    "shortly",
+    "shotten",
+    "showery",
+    "showier",
+    "showily",
+    "shrieky",
+    "shrikes",
+    "shrilly",
+    "shrimps",
+    "shrubby",
+    "shuffle",
+    "sialoid",
+    "sibling",
+    "sibylic",
+    "sidneys",
+    "siennas",
+    "sierras",
+    "sighful",
+    "sighted",
+    "sightly",
+    "sigmate",
+    "sigrids",
+    "silicic",
+    "silkier",
+    "silkily",
+    "sillily",
+    "siltier",
+    "silvana",
+    "silvern",
+    "silvery",
+    "silvias",
+    "similar",
+    "simious",
+    "simonas",
+    "simones",
+    "simonne",
+    "sincere",
+    "sinless",
+    "sinlike",
+    "sinuate",
+    "sinuous",
+    "siobhan",
+    "sirenas",
+    "sirenic",
+    "siskins",
+    "situate",
+    "sixthly",
+    "sizable",
+    "sizably",
+    "siziest",
+    "skaldic",
+    "skaters",
+    "sketchy",
+    "skiable",
+    "skilful",
+    "skilled",
+    "skimmer",
+    "skinned",
+    "skookum",
+    "skylark",
+    "skylars",
+    "skylers",
+    "skyless",
+    "skylike",
+    "skyward",
+    "slackly",
+    "slantly",
+    "slatier",
+    "slavish",
+    "sleekit",
+    "sleekly",
+    "sleeper",
+    "slender",
+    "slickly",
+    "sliding",
+    "slimier",
+    "slimily",
+    "sloanes",
+    "sloshed",
+    "slouchy",
+    "sloughy",
+    "slyvias",
+    "smartly",
+    "smashed",
+    "smectic",
+    "smiling",
+    "smitten",
+    "smokier",
+    "smokily",
+    "smugger",
+    "snakier",
+    "snakily",
+    "snapper",
+    "snecked",
+    "snidely",
+    "snidest",
+    "sniffly",
+    "snively",
+    "snouted",
+    "snowier",
+    "snowily",
+    "snuffly",
+    "snugger",
+    "soapier",
+    "soapily",
+    "soaring",
+    "soberly",
+    "socorro",
+    "sodless",
+    "softish",
+    "soggily",
+    "solange",
+    "soldier",
+    "soledad",
+    "solidly",
+    "solomon",
+    "soluble",
+    "solubly",
+    "solutus",
+    "somatic",
+    "somehow",
+    "someone",
+    "sommers",
+    "sondras",
+    "songful",
+    "sonless",
+    "sonlike",
+    "soothed",
+    "soothly",
+    "sootily",
+    "sophias",
+    "sophies",
+    "soppier",
+    "soppily",
+    "sorayas",
+    "soritic",
+    "sorrier",
+    "sorrily",
+    "sottily",
+    "sottish",
+    "soulful",
+    "soundly",
+    "soupier",
+    "sourish",
+    "soutenu",
+    "sowlike",
+    "sozzled",
+    "spacial",
+    "spangly",
+    "spaniel",
+    "sparely",
+    "sparing",
+    "sparkle",
+    "sparkly",
+    "sparrow",
+    "sparser",
+    "spathic",
+    "spatial",
+    "spaviet",
+    "special",
+    "spencer",
+    "spermic",
+    "sphenic",
+    "spheral",
+    "spiders",
+    "spidery",
+    "spikier",
+    "spikily",
+    "spindly",
+    "spinier",
+    "spinose",
+    "spinous",
+    "spiroid",
+    "spissus",
+    "splashy",
+    "spleeny",
+    "splenic",
+    "splurgy",
+    "sponges",
+    "sponsor",
+    "sporoid",
+    "spotted",
+    "spouted",
+    "sprawly",
+    "spriest",
+    "spriggy",
+    "springs",
+    "springy",
+    "sprucer",
+    "spryest",
+    "squabby",
+    "squalid",
+    "squally",
+    "squashy",
+    "squatly",
+    "squeeze",
+    "squiffy",
+    "squinty",
+    "squirmy",
+    "squishy",
+    "stacees",
+    "staceys",
+    "stacias",
+    "stacies",
+    "stacked",
+    "stadium",
+    "stagier",
+    "stagily",
+    "staidly",
+    "stalely",
+    "stalked",
+    "stanley",
+    "stannic",
+    "stanton",
+    "starchy",
+    "starkly",
+    "starlas",
+    "starred",
+    "stasias",
+    "statant",
+    "stately",
+    "statued",
+    "staunch",
+    "stearic",
+    "steeply",
+    "stefani",
+    "stefans",
+    "stefany",
+    "stellar",
+    "stellas",
+    "stemmed",
+    "stephan",
+    "stephen",
+    "sterile",
+    "sternal",
+    "sternly",
+    "stetson",
+    "stevens",
+    "stevies",
+    "stewart",
+    "sthenic",
+    "stibial",
+    "sticket",
+    "stickit",
+    "stiffly",
+    "stilted",
+    "stirred",
+    "stoical",
+    "stomach",
+    "stonier",
+    "stonily",
+    "stopped",
+    "storied",
+    "stormys",
+    "stoutly",
+    "straked",
+    "stratal",
+    "streaky",
+    "streamy",
+    "stringy",
+    "striped",
+    "strobic",
+    "stromal",
+    "stroppy",
+    "stuarts",
+    "stubbly",
+    "student",
+    "studied",
+    "stuffed",
+    "stumble",
+    "stylish",
+    "styloid",
+    "suannes",
+    "suasory",
+    "suavely",
+    "subacid",
+    "subaqua",
+    "subarid",
+    "subdued",
+    "suberic",
+    "subject",
+    "sublime",
+    "sublong",
+    "submiss",
+    "suboral",
+    "subpial",
+    "subtile",
+    "subzero",
+    "success",
+    "sueanns",
+    "suellen",
+    "sugared",
+    "suggest",
+    "sulcate",
+    "sulemas",
+    "sulkier",
+    "sulkies",
+    "sulkily",
+    "sumikos",
+    "summary",
+    "summers",
+    "sunback",
+    "sunbeam",
+    "sunbird",
+    "sundays",
+    "sunfast",
+    "sunfish",
+    "sunless",
+    "sunlike",
+    "sunnier",
+    "sunnily",
+    "suppled",
+    "suppler",
+    "supreme",
+    "surface",
+    "surlily",
+    "susanas",
+    "susanna",
+    "susanne",
+    "susanns",
+    "suspect",
+    "sustain",
+    "suttons",
+    "sutural",
+    "suzanna",
+    "suzanne",
+    "suzanns",
+    "suzette",
+    "svelter",
+    "swacked",
+    "swallow",
+    "swarthy",
+    "swayful",
+    "swaying",
+    "sweated",
+    "sweeper",
+    "sweetly",
+    "swiftly",
+    "swinish",
+    "swithly",
+    "sydneys",
+    "sylphic",
+    "sylvias",
+    "sylvies",
+    "symptom",
+    "synetic",
+    "synodal",
+    "synodic",
+    "synthia",
+    "syreeta",
+    "tabatha",
+    "tabetha",
+    "tabitha",
+    "tabular",
+    "tacitly",
+    "tackier",
+    "tackily",
+    "tactful",
+    "tactile",
+    "tactual",
+    "tadpole",
+    "taglike",
+    "taipans",
+    "taishas",
+    "tajuana",
+    "takakos",
+    "takisha",
+    "talcose",
+    "talisha",
+    "talitha",
+    "taliyah",
+    "talkier",
+    "tallowy",
+    "taloned",
+    "tamalas",
+    "tamaras",
+    "tamarin",
+    "tamasic",
+    "tamatha",
+    "tambras",
+    "tameika",
+    "tamekas",
+    "tamekia",
+    "tamelas",
+    "tameras",
+    "tamesha",
+    "tamicas",
+    "tamikas",
+    "tamikos",
+    "tamisha",
+    "tammara",
+    "tammera",
+    "tammies",
+    "tanager",
+    "tandras",
+    "tanekas",
+    "tanesha",
+    "tangela",
+    "tangled",
+    "tanikas",
+    "tanisha",
+    "tanners",
+    "tannish",
+    "tapetal",
+    "tardier",
+    "tardily",
+    "tarpons",
+    "tarshas",
+    "tarsier",
+    "tartily",
+    "tartish",
+    "tashias",
+    "tashina",
+    "tastily",
+    "tatiana",
+    "tattily",
+    "tattler",
+    "tatyana",
+    "taunyas",
+    "tawanas",
+    "tawanda",
+    "tawanna",
+    "tawnier",
+    "tawnily",
+    "tawnyas",
+    "taxably",
+    "taxitic",
+    "taxpaid",
+    "taylors",
+    "teagans",
+    "tealess",
+    "tearful",
+    "tearier",
+    "tearily",
+    "techier",
+    "techily",
+    "tedious",
+    "teeming",
+    "teenier",
+    "tegular",
+    "teishas",
+    "telford",
+    "temekas",
+    "temikas",
+    "tempies",
+    "templed",
+    "temples",
+    "tenable",
+    "tenably",
+    "tenesha",
+    "tenisha",
+    "tenleys",
+    "tennies",
+    "tenrecs",
+    "tensely",
+    "tensest",
+    "tensile",
+    "tensing",
+    "tensive",
+    "tenthly",
+    "tentier",
+    "tenuous",
+    "teodora",
+    "teodoro",
+    "teofila",
+    "tepidly",
+    "tequila",
+    "tereasa",
+    "terebic",
+    "terefah",
+    "terence",
+    "teresas",
+    "tereses",
+    "teresia",
+    "teressa",
+    "tericas",
+    "terinas",
+    "terisas",
+    "ternate",
+    "terrell",
+    "terresa",
+    "terrier",
+    "terries",
+    "tersely",
+    "tertius",
+    "tessies",
+    "testily",
+    "tetched",
+    "teughly",
+    "textual",
+    "thalias",
+    "thallic",
+    "thatchy",
+    "thecate",
+    "thecial",
+    "thegnly",
+    "thelmas",
+    "theolas",
+    "theresa",
+    "therese",
+    "thermic",
+    "theroid",
+    "therons",
+    "thersas",
+    "thiagos",
+    "thickly",
+    "thiolic",
+    "thionic",
+    "thirdly",
+    "thirsty",
+    "thistly",
+    "thought",
+    "thready",
+    "thresas",
+    "thrifty",
+    "throaty",
+    "thrushs",
+    "thunder",
+    "thurman",
+    "thymier",
+    "tiannas",
+    "tiaraed",
+    "tickled",
+    "tidally",
+    "tideful",
+    "tierced",
+    "tierras",
+    "tieshas",
+    "tifanys",
+    "tiffani",
+    "tiffany",
+    "tiffiny",
+    "tighter",
+    "tightly",
+    "tijuana",
+    "tilapia",
+    "tillies",
+    "timbery",
+    "timeous",
+    "timidly",
+    "timikas",
+    "timothy",
+    "tinamou",
+    "tindery",
+    "tiniest",
+    "tinisha",
+    "tinleys",
+    "tinlike",
+    "tinnier",
+    "tinnily",
+    "tipless",
+    "tippier",
+    "tippily",
+    "tipsier",
+    "tipsily",
+    "tiredly",
+    "tissual",
+    "tissuey",
+    "toadish",
+    "tobacco",
+    "toccara",
+    "toddler",
+    "toeless",
+    "toelike",
+    "toilful",
+    "tomasas",
+    "tomcats",
+    "tomekas",
+    "tomikas",
+    "tomikos",
+    "tommies",
+    "tommyes",
+    "tomokos",
+    "tonally",
+    "tonetic",
+    "tonette",
+    "tongued",
+    "toniest",
+    "tonight",
+    "tonisha",
+    "tonitas",
+    "tonnish",
+    "toothed",
+    "topfull",
+    "topical",
+    "topline",
+    "topmost",
+    "tornado",
+    "torries",
+    "tortile",
+    "toshias",
+    "toshiko",
+    "totable",
+    "totally",
+    "totemic",
+    "toucans",
+    "touched",
+    "toughly",
+    "tourist",
+    "towable",
+    "towanda",
+    "towered",
+    "towhees",
+    "townish",
+    "toxemic",
+    "toyless",
+    "toylike",
+    "tracees",
+    "traceys",
+    "tracies",
+    "traffic",
+    "treacly",
+    "treasas",
+    "treenas",
+    "trembly",
+    "trenton",
+    "tressas",
+    "tressed",
+    "tressie",
+    "trevors",
+    "triable",
+    "triacid",
+    "triadic",
+    "triaryl",
+    "tricias",
+    "trickly",
+    "tricksy",
+    "trifold",
+    "triform",
+    "trigger",
+    "trinary",
+    "trinity",
+    "triplex",
+    "trishas",
+    "trismic",
+    "tristan",
+    "tristas",
+    "tristen",
+    "tristin",
+    "triston",
+    "tritely",
+    "tritest",
+    "trivial",
+    "trochal",
+    "trogons",
+    "trophic",
+    "trouble",
+    "trudies",
+    "trumans",
+    "trumpet",
+    "trussed",
+    "trusted",
+    "tryptic",
+    "tuatara",
+    "tubbier",
+    "tublike",
+    "tubular",
+    "tuckers",
+    "tuftily",
+    "tugless",
+    "tuition",
+    "tumidly",
+    "tumular",
+    "tunable",
+    "tunably",
+    "tuneful",
+    "turacos",
+    "turbots",
+    "turdine",
+    "turfier",
+    "turgent",
+    "turkeys",
+    "turners",
+    "turtles",
+    "tussive",
+    "twaddly",
+    "twandas",
+    "twannas",
+    "twiddly",
+    "tyeshas",
+    "tyishas",
+    "tynisha",
+    "typhous",
+    "typical",
+    "tyrells",
+    "tyrones",
+    "tyronic",
+    "ullaged",
+    "ulrikes",
+    "ululant",
+    "ulysses",
+    "umbonal",
+    "umbonic",
+    "umbrose",
+    "unacted",
+    "unadded",
+    "unadept",
+    "unadult",
+    "unagile",
+    "unaging",
+    "unaided",
+    "unaimed",
+    "unaired",
+    "unangry",
+    "unaptly",
+    "unarmed",
+    "unasked",
+    "unawake",
+    "unaware",
+    "unawful",
+    "unawned",
+    "unaxled",
+    "unbaked",
+    "unbased",
+    "unbated",
+    "unbeset",
+    "unblent",
+    "unblown",
+    "unblued",
+    "unboggy",
+    "unboned",
+    "unbored",
+    "unborne",
+    "unbowed",
+    "unbrave",
+    "unbrief",
+    "unbroke",
+    "unburly",
+    "unburnt",
+    "unburst",
+    "uncaged",
+    "uncaned",
+    "uncanny",
+    "uncaped",
+    "unceded",
+    "unchary",
+    "uncited",
+    "uncivic",
+    "uncivil",
+    "unclean",
+    "unclear",
+    "uncleft",
+    "uncoded",
+    "uncoked",
+    "uncomic",
+    "uncouth",
+    "uncover",
+    "uncowed",
+    "uncoyly",
+    "uncried",
+    "uncrude",
+    "uncruel",
+    "uncubic",
+    "uncured",
+    "undared",
+    "undated",
+    "undazed",
+    "undealt",
+    "undewed",
+    "undiked",
+    "undimly",
+    "undomed",
+    "undoped",
+    "undried",
+    "undrunk",
+    "unducal",
+    "unduped",
+    "undusty",
+    "undying",
+    "uneager",
+    "uneaten",
+    "uneaved",
+    "unebbed",
+    "unempty",
+    "unended",
+    "unequal",
+    "unerect",
+    "unfaced",
+    "unfaded",
+    "unfaked",
+    "unfancy",
+    "unfated",
+    "unfatty",
+    "unfeted",
+    "unfiery",
+    "unfined",
+    "unfired",
+    "unfitly",
+    "unflaky",
+    "unflown",
+    "unfluid",
+    "unfoggy",
+    "unfound",
+    "unfoxed",
+    "unfrail",
+    "unfrank",
+    "unfried",
+    "unfugal",
+    "unfully",
+    "unfunny",
+    "unfused",
+    "unfussy",
+    "ungated",
+    "ungaudy",
+    "ungiddy",
+    "ungiven",
+    "ungnawn",
+    "ungodly",
+    "ungored",
+    "ungouty",
+    "ungrand",
+    "ungross",
+    "ungrown",
+    "ungruff",
+    "ungular",
+    "unguled",
+    "unguyed",
+    "ungyved",
+    "unhairy",
+    "unhappi",
+    "unhappy",
+    "unharsh",
+    "unhasty",
+    "unhated",
+    "unhayed",
+    "unhazed",
+    "unheady",
+    "unheard",
+    "unheavy",
+    "unhewed",
+    "unhilly",
+    "unhired",
+    "unhoary",
+    "unhoned",
+    "unhosed",
+    "unhuman",
+    "unhumid",
+    "unicorn",
+    "unideal",
+    "unified",
+    "uniform",
+    "uninert",
+    "uninked",
+    "unitage",
+    "unitary",
+    "unitive",
+    "unjaded",
+    "unjolly",
+    "unjoyed",
+    "unjuicy",
+    "unkempt",
+    "unkeyed",
+    "unknown",
+    "unladen",
+    "unlamed",
+    "unlarge",
+    "unlaved",
+    "unleaky",
+    "unlegal",
+    "unlevel",
+    "unlight",
+    "unliked",
+    "unlimed",
+    "unlined",
+    "unloath",
+    "unlobed",
+    "unlocal",
+    "unlofty",
+    "unloved",
+    "unlowly",
+    "unloyal",
+    "unlucid",
+    "unlucky",
+    "unlumpy",
+    "unlunar",
+    "unlured",
+    "unlusty",
+    "unlying",
+    "unlyric",
+    "unmaned",
+    "unmanly",
+    "unmeant",
+    "unmerry",
+    "unmeted",
+    "unmined",
+    "unmired",
+    "unmixed",
+    "unmoldy",
+    "unmoody",
+    "unmoral",
+    "unmossy",
+    "unmoved",
+    "unmowed",
+    "unmuddy",
+    "unmuted",
+    "unnaked",
+    "unnamed",
+    "unnaove",
+    "unnasal",
+    "unneedy",
+    "unnoisy",
+    "unnosed",
+    "unnoted",
+    "unnovel",
+    "unoared",
+    "unobese",
+    "unogled",
+    "unoiled",
+    "unopted",
+    "unorbed",
+    "unovert",
+    "unowing",
+    "unowned",
+    "unpaced",
+    "unpagan",
+    "unpaged",
+    "unpaled",
+    "unpapal",
+    "unpared",
+    "unpaved",
+    "unpawed",
+    "unpenal",
+    "unpious",
+    "unpiped",
+    "unplied",
+    "unplumb",
+    "unpoled",
+    "unposed",
+    "unproud",
+    "unquick",
+    "unrainy",
+    "unraked",
+    "unraspy",
+    "unrated",
+    "unrayed",
+    "unrazed",
+    "unready",
+    "unregal",
+    "unrigid",
+    "unrimed",
+    "unrisen",
+    "unrisky",
+    "unrived",
+    "unriven",
+    "unrocky",
+    "unroomy",
+    "unroped",
+    "unrosed",
+    "unrowdy",
+    "unrowed",
+    "unruled",
+    "unrural",
+    "unsadly",
+    "unsalty",
+    "unsated",
+    "unsaved",
+    "unsawed",
+    "unscaly",
+    "unshady",
+    "unsharp",
+    "unsheer",
+    "unshiny",
+    "unshoed",
+    "unshorn",
+    "unshort",
+    "unshown",
+    "unshowy",
+    "unshyly",
+    "unsided",
+    "unsight",
+    "unsilly",
+    "unsized",
+    "unslack",
+    "unslain",
+    "unsleek",
+    "unslung",
+    "unslyly",
+    "unsmoky",
+    "unsnaky",
+    "unsober",
+    "unsoggy",
+    "unsolar",
+    "unsolid",
+    "unsonsy",
+    "unsooty",
+    "unsorry",
+    "unsound",
+    "unsowed",
+    "unspelt",
+    "unspent",
+    "unspied",
+    "unspilt",
+    "unsplit",
+    "unstack",
+    "unstagy",
+    "unstaid",
+    "unstern",
+    "unstiff",
+    "unstoic",
+    "unstony",
+    "unstout",
+    "unstuck",
+    "unstung",
+    "unsulky",
+    "unsunny",
+    "unsurly",
+    "unswept",
+    "unswung",
+    "untamed",
+    "untaped",
+    "untasty",
+    "untawed",
+    "untelic",
+    "untense",
+    "unterse",
+    "unthick",
+    "untidal",
+    "untiled",
+    "untimed",
+    "untimid",
+    "untired",
+    "untoned",
+    "untough",
+    "untoxic",
+    "untreed",
+    "untried",
+    "untrite",
+    "untruly",
+    "untumid",
+    "untyped",
+    "unupset",
+    "unurban",
+    "unurged",
+    "unusual",
+    "unvague",
+    "unvenal",
+    "unvexed",
+    "unvital",
+    "unvivid",
+    "unvocal",
+    "unvoted",
+    "unvowed",
+    "unvying",
+    "unwaded",
+    "unwaked",
+    "unwaned",
+    "unwaved",
+    "unwaxed",
+    "unweary",
+    "unwhipt",
+    "unwhite",
+    "unwindy",
+    "unwiped",
+    "unwired",
+    "unwitty",
+    "unwooed",
+    "unwormy",
+    "unwrung",
+    "upbound",
+    "upgrade",
+    "upright",
+    "upsetly",
+    "uptight",
+    "uranous",
+    "urchins",
+    "uredial",
+    "urijahs",
+    "urinant",
+    "urinous",
+    "urnlike",
+    "ursulas",
+    "useably",
+    "useless",
+    "usually",
+    "uterine",
+    "utility",
+    "utterly",
+    "uxorial",
+    "vacuous",
+    "vagally",
+    "vaguely",
+    "vaguest",
+    "valarie",
+    "valenes",
+    "valeria",
+    "valeric",
+    "valerie",
+    "valeris",
+    "valerys",
+    "valiant",
+    "validly",
+    "vallate",
+    "vallies",
+    "valorie",
+    "valries",
+    "valvate",
+    "vanadic",
+    "vanesas",
+    "vanessa",
+    "vanetta",
+    "vanitas",
+    "vannesa",
+    "vapidly",
+    "vapoury",
+    "various",
+    "vashtis",
+    "vaughns",
+    "vaulted",
+    "vaunted",
+    "vegetal",
+    "vehicle",
+    "veinier",
+    "velvets",
+    "velvety",
+    "venally",
+    "venatic",
+    "vendace",
+    "venessa",
+    "venetta",
+    "venices",
+    "venitas",
+    "vennies",
+    "ventral",
+    "venture",
+    "venular",
+    "verbose",
+    "verdant",
+    "verdell",
+    "verdies",
+    "verdins",
+    "verenas",
+    "vergies",
+    "verlene",
+    "verlies",
+    "verline",
+    "vernell",
+    "vernias",
+    "vernice",
+    "vernies",
+    "vernita",
+    "vernons",
+    "veronas",
+    "versies",
+    "version",
+    "verties",
+    "vervets",
+    "vesical",
+    "vespine",
+    "vestral",
+    "veteran",
+    "vexedly",
+    "vibrant",
+    "vicarly",
+    "vicenta",
+    "vicente",
+    "vicinal",
+    "vicious",
+    "vickeys",
+    "vickies",
+    "victors",
+    "victory",
+    "vicunas",
+    "viewier",
+    "vihaans",
+    "village",
+    "villagy",
+    "villose",
+    "villous",
+    "viminal",
+    "vincent",
+    "vinitas",
+    "vinnies",
+    "vintage",
+    "violent",
+    "violeta",
+    "violets",
+    "violety",
+    "virally",
+    "viremic",
+    "virgens",
+    "virgies",
+    "virgils",
+    "virgina",
+    "virtual",
+    "visaged",
+    "viscoid",
+    "viscous",
+    "visibly",
+    "vitally",
+    "vitreum",
+    "viviana",
+    "vivians",
+    "vividly",
+    "viviens",
+    "vixenly",
+    "vocably",
+    "vocalic",
+    "vocally",
+    "volcano",
+    "voluble",
+    "volubly",
+    "volumed",
+    "voluted",
+    "volvate",
+    "voncile",
+    "vonnies",
+    "votable",
+    "vowelly",
+    "vowless",
+    "vulpine",
+    "vulture",
+    "vyingly",
+    "wackier",
+    "wackily",
+    "wadable",
+    "waggish",
+    "wagtail",
+    "wailful",
+    "waisted",
+    "wakeful",
+    "walkers",
+    "wallaby",
+    "wallace",
+    "walleye",
+    "walters",
+    "waltons",
+    "wanetas",
+    "wanetta",
+    "waniest",
+    "wanitas",
+    "wannest",
+    "wanning",
+    "wannish",
+    "wapitis",
+    "warbler",
+    "warfare",
+    "wariest",
+    "warless",
+    "warlike",
+    "warmish",
+    "warners",
+    "warrens",
+    "warrior",
+    "warthog",
+    "wartier",
+    "washier",
+    "waspier",
+    "waspily",
+    "waspish",
+    "waviest",
+    "waxbill",
+    "waxiest",
+    "waxlike",
+    "waxwing",
+    "wayless",
+    "waylons",
+    "wayward",
+    "wayworn",
+    "weakish",
+    "wealthy",
+    "wearied",
+    "wearier",
+    "wearily",
+    "wearish",
+    "weasels",
+    "weasely",
+    "weather",
+    "webbier",
+    "webless",
+    "weblike",
+    "wedding",
+    "wedgier",
+    "weedier",
+    "weedily",
+    "weekend",
+    "weepier",
+    "weepily",
+    "weevils",
+    "weevily",
+    "weighty",
+    "weirdly",
+    "welcome",
+    "weldons",
+    "wendell",
+    "wendies",
+    "wenonas",
+    "werners",
+    "wesleys",
+    "westins",
+    "westlin",
+    "westons",
+    "wettish",
+    "wheaten",
+    "wheeled",
+    "whelked",
+    "whinier",
+    "whinily",
+    "whippet",
+    "whisper",
+    "whistly",
+    "whitely",
+    "whiting",
+    "whitish",
+    "whitley",
+    "whitney",
+    "whorish",
+    "whorled",
+    "whydahs",
+    "widowly",
+    "wigeons",
+    "wigless",
+    "wiglike",
+    "wilbers",
+    "wilbert",
+    "wilburn",
+    "wilburs",
+    "wildcat",
+    "wilford",
+    "wilfred",
+    "wiliest",
+    "willard",
+    "willena",
+    "willene",
+    "willets",
+    "willful",
+    "william",
+    "willian",
+    "willias",
+    "willies",
+    "willing",
+    "willows",
+    "willowy",
+    "wilmers",
+    "wilsons",
+    "wiltons",
+    "wimpily",
+    "windier",
+    "windily",
+    "windowy",
+    "winford",
+    "winfred",
+    "winiest",
+    "winnies",
+    "winning",
+    "winonas",
+    "winsome",
+    "winston",
+    "winters",
+    "wintery",
+    "wirable",
+    "wishful",
+    "wispier",
+    "wispily",
+    "wistful",
+    "witless",
+    "witness",
+    "wittier",
+    "wittily",
+    "witting",
+    "wizened",
+    "wobbily",
+    "woesome",
+    "wofully",
+    "wolfish",
+    "womanly",
+    "wombats",
+    "woodier",
+    "woodrow",
+    "woozier",
+    "woozily",
+    "wordier",
+    "wordily",
+    "working",
+    "workshy",
+    "worldly",
+    "wormish",
+    "worried",
+    "wrasses",
+    "wrestle",
+    "wriggly",
+    "wrinkly",
+    "wrongly",
+    "wryneck",
+    "wynells",
+    "wynonas",
+    "wynters",
+    "xanders",
+    "xanthic",
+    "xaviers",
+    "xerarch",
+    "ximenas",
+    "xiomara",
+    "xochitl",
+    "xzavier",
+    "yadiels",
+    "yadiras",
+    "yahaira",
+    "yajaira",
+    "yaniras",
+    "yarelis",
+    "yaretzi",
+    "yaritza",
+    "yasmine",
+    "yasmins",
+    "yasukos",
+    "yawnful",
+    "yawning",
+    "yelenas",
+    "yesenia",
+    "yestern",
+    "yevette",
+    "yolanda",
+    "yolande",
+    "yolando",
+    "yolonda",
+    "yoshies",
+    "yoshiko",
+    "yousefs",
+    "yuettes",
+    "yukikos",
+    "yulanda",
+    "yuonnes",
+    "yurikos",
+    "yvettes",
+    "yvonnes",
+    "zachary",
+    "zachery",
+    "zackary",
+    "zaidens",
+    "zainabs",
+    "zanders",
+    "zandras",
+    "zaniest",
+    "zaniyah",
+    "zanyish",
+    "zariahs",
+    "zariyah",
+    "zaviers",
+    "zaydens",
+    "zealous",
+    "zebraic",
+    "zebrine",
+    "zenaida",
+    "zenobia",
+    "zestful",
+    "zestily",
+    "zincoid",
+    "zincous",
+    "zingels",
+    "zonally",
+    "zonular",
+    "zoraida",
+    "zorilla",
+    "zulemas"
+};
+
+
+static EIGHT_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "aaliyahs",
+    "aardvark",
+    "aardwolf",
+    "abasedly",
+    "abatable",
+    "abatedly",
+    "abbatial",
+    "abbigail",
+    "abdullah",
+    "abigails",
+    "abjectly",
+    "abnormal",
+    "aborally",
+    "abortive",
+    "abrahams",
+    "abrielle",
+    "abruptly",
+    "absently",
+    "absolute",
+    "absolved",
+    "absonant",
+    "absorbed",
+    "abstract",
+    "abstruse",
+    "absurdly",
+    "abundant",
+    "abusable",
+    "abusedly",
+    "acapella",
+    "acapnial",
+    "acardiac",
+    "acarpous",
+    "accentor",
+    "accepted",
+    "accident",
+    "accosted",
+    "accurate",
+    "accursed",
+    "acentric",
+    "acervate",
+    "acescent",
+    "acetated",
+    "acetonic",
+    "acetylic",
+    "achenial",
+    "achilary",
+    "achingly",
+    "achromic",
+    "acicular",
+    "acidotic",
+    "aciduric",
+    "acoelous",
+    "aconitic",
+    "acoustic",
+    "actinoid",
+    "actively",
+    "actually",
+    "aculeate",
+    "adalines",
+    "adalynns",
+    "adapting",
+    "adaptive",
+    "addicted",
+    "addilyns",
+    "addisons",
+    "addisyns",
+    "additory",
+    "addorsed",
+    "adducent",
+    "addysons",
+    "adelaida",
+    "adelaide",
+    "adelinas",
+    "adelines",
+    "adelynns",
+    "adequate",
+    "adjusted",
+    "adjutant",
+    "admirals",
+    "adoptive",
+    "adorable",
+    "adorably",
+    "adrianas",
+    "adrianes",
+    "adrianna",
+    "adrianne",
+    "adrienes",
+    "adrienne",
+    "adroitly",
+    "adumbral",
+    "advanced",
+    "advisory",
+    "adynamic",
+    "aedeagal",
+    "aerially",
+    "aeriform",
+    "aestival",
+    "afebrile",
+    "affected",
+    "afferent",
+    "affinely",
+    "afflated",
+    "affluent",
+    "affronta",
+    "agenetic",
+    "agential",
+    "aghastly",
+    "agitable",
+    "aglimmer",
+    "aglisten",
+    "aglitter",
+    "agminate",
+    "agraphic",
+    "agrestal",
+    "agrestic",
+    "agripina",
+    "aguelike",
+    "aguishly",
+    "agustina",
+    "agustins",
+    "aimfully",
+    "ainsleys",
+    "airborne",
+    "airedale",
+    "airtight",
+    "akinetic",
+    "alannahs",
+    "alaysias",
+    "albacore",
+    "albertas",
+    "albertha",
+    "albertos",
+    "alchemic",
+    "alcidine",
+    "alderfly",
+    "aleatory",
+    "aleighas",
+    "aleishas",
+    "aleshias",
+    "aletheas",
+    "alethias",
+    "alewifes",
+    "alexinic",
+    "alfonsos",
+    "alfonzos",
+    "alfredas",
+    "alfredia",
+    "alfredos",
+    "alishias",
+    "alissons",
+    "alkaline",
+    "allegras",
+    "allergic",
+    "alliable",
+    "allisons",
+    "allodial",
+    "allowing",
+    "alluring",
+    "allusive",
+    "allysons",
+    "alondras",
+    "alopecic",
+    "alphonse",
+    "alphonso",
+    "alpinely",
+    "aluminic",
+    "alveated",
+    "alvertas",
+    "amandine",
+    "amazedly",
+    "amberlys",
+    "amberous",
+    "ambroise",
+    "ambroses",
+    "ambulant",
+    "ameiotic",
+    "amenable",
+    "amenably",
+    "americas",
+    "amicable",
+    "amicably",
+    "amitotic",
+    "amnestic",
+    "amniotic",
+    "amoeboid",
+    "amorally",
+    "amphoral",
+    "amphoric",
+    "ampliate",
+    "amusable",
+    "amusedly",
+    "anabatic",
+    "anabella",
+    "anabelle",
+    "anabolic",
+    "anaconda",
+    "anacusic",
+    "anagogic",
+    "anamaria",
+    "anarchic",
+    "anchovys",
+    "anconoid",
+    "anderson",
+    "anechoic",
+    "anemones",
+    "angelena",
+    "angelias",
+    "angelica",
+    "angelics",
+    "angelika",
+    "angelina",
+    "angeline",
+    "angelita",
+    "angellas",
+    "angelyns",
+    "angriest",
+    "angulous",
+    "anhingas",
+    "aniconic",
+    "anilidic",
+    "animalic",
+    "animally",
+    "animated",
+    "anjelica",
+    "annabell",
+    "annabels",
+    "annalees",
+    "annalisa",
+    "annalise",
+    "annamaes",
+    "annelles",
+    "annettas",
+    "annettes",
+    "annmarie",
+    "announce",
+    "annually",
+    "annulate",
+    "annulose",
+    "anodally",
+    "anointed",
+    "anorthic",
+    "anourous",
+    "anoxemic",
+    "anserine",
+    "anteater",
+    "antelope",
+    "antennal",
+    "antheral",
+    "anthonys",
+    "antiones",
+    "antirent",
+    "antiskid",
+    "antislip",
+    "antlered",
+    "antliate",
+    "antlions",
+    "antoines",
+    "antonias",
+    "antonina",
+    "antonios",
+    "antrorse",
+    "anuretic",
+    "aoristic",
+    "apatetic",
+    "aphakial",
+    "aphelian",
+    "apiarian",
+    "apically",
+    "aplastic",
+    "apocopic",
+    "apocrine",
+    "apodemal",
+    "apogamic",
+    "apologal",
+    "apolonia",
+    "apparent",
+    "apposite",
+    "apterial",
+    "apterous",
+    "apyretic",
+    "aquarial",
+    "aquarian",
+    "aquiline",
+    "arabella",
+    "aracelis",
+    "aracelys",
+    "araceous",
+    "arachnid",
+    "araneose",
+    "arapaima",
+    "arbitral",
+    "arboreal",
+    "arborous",
+    "arcanely",
+    "arcelias",
+    "archival",
+    "arciform",
+    "ardelias",
+    "ardellas",
+    "ardelles",
+    "ardently",
+    "areolate",
+    "argelias",
+    "argental",
+    "argentic",
+    "arguable",
+    "arguably",
+    "ariannas",
+    "ariannes",
+    "ariellas",
+    "arielles",
+    "arillate",
+    "arilloid",
+    "aristate",
+    "arlethas",
+    "arlettas",
+    "arlettes",
+    "arlindas",
+    "armandas",
+    "armandos",
+    "armindas",
+    "armoured",
+    "arnettas",
+    "arnettes",
+    "arnoldos",
+    "arnulfos",
+    "arrantly",
+    "arriving",
+    "arrogant",
+    "arsenous",
+    "artefact",
+    "arterial",
+    "artesian",
+    "artfully",
+    "artistic",
+    "arumlike",
+    "arvillas",
+    "aryannas",
+    "arythmic",
+    "asconoid",
+    "ashantis",
+    "ashleigh",
+    "ashlynns",
+    "aspirant",
+    "aspiring",
+    "assuring",
+    "asternal",
+    "astigmic",
+    "astonied",
+    "astrally",
+    "astutely",
+    "asuncion",
+    "athetoid",
+    "athletic",
+    "athonite",
+    "atlantal",
+    "atonable",
+    "atonally",
+    "atrophic",
+    "attached",
+    "attently",
+    "attested",
+    "attitude",
+    "atwitter",
+    "atypical",
+    "auditive",
+    "audriana",
+    "audrinas",
+    "augustas",
+    "augustly",
+    "augustus",
+    "aundreas",
+    "auntlike",
+    "aurelias",
+    "aurelios",
+    "auricled",
+    "auriform",
+    "aurorean",
+    "autarkic",
+    "autistic",
+    "autopsic",
+    "autumnal",
+    "avelinas",
+    "aversely",
+    "aversive",
+    "aviannas",
+    "avowable",
+    "avowably",
+    "avowedly",
+    "awninged",
+    "axolotls",
+    "azariahs",
+    "azucenas",
+    "babettes",
+    "babylike",
+    "bachelor",
+    "backdoor",
+    "backless",
+    "backmost",
+    "backward",
+    "baculine",
+    "badgerly",
+    "baetylic",
+    "baffling",
+    "baggiest",
+    "bailable",
+    "bairnish",
+    "balanced",
+    "baleless",
+    "balkiest",
+    "balladic",
+    "balletic",
+    "balmiest",
+    "balmlike",
+    "balsamic",
+    "banausic",
+    "bandboxy",
+    "bandless",
+    "bankable",
+    "bankerly",
+    "bannered",
+    "barabara",
+    "barbaras",
+    "barbaric",
+    "barberas",
+    "barbless",
+    "bardiest",
+    "bardlike",
+    "barkiest",
+    "barkless",
+    "barmiest",
+    "barnacle",
+    "barnlike",
+    "baronial",
+    "barrable",
+    "barrenly",
+    "barretts",
+    "basaltic",
+    "baseborn",
+    "baseless",
+    "basidial",
+    "basilias",
+    "basilisk",
+    "batfishs",
+    "bathetic",
+    "bathless",
+    "battiest",
+    "bawdiest",
+    "bayleigh",
+    "beadiest",
+    "beadlike",
+    "beakless",
+    "beaklike",
+    "beamiest",
+    "beamless",
+    "beamlike",
+    "beanlike",
+    "bearable",
+    "bearably",
+    "bearlike",
+    "beatable",
+    "beatific",
+    "beatrice",
+    "beatrizs",
+    "beaulahs",
+    "becalmed",
+    "becketts",
+    "beckhams",
+    "becoming",
+    "beddable",
+    "beefiest",
+    "beefless",
+    "beeriest",
+    "beetlike",
+    "begabled",
+    "beggarly",
+    "beholden",
+    "belindas",
+    "bellbird",
+    "belonoid",
+    "beltless",
+    "bendable",
+    "bendwise",
+    "beneaped",
+    "benedict",
+    "benignly",
+    "benjamin",
+    "bennetts",
+    "bentlees",
+    "bentleys",
+    "benzylic",
+    "berenice",
+    "bernarda",
+    "bernardo",
+    "bernards",
+    "berneice",
+    "bernetta",
+    "bernices",
+    "berniece",
+    "bernitas",
+    "bertrams",
+    "beryline",
+    "besotted",
+    "besprent",
+    "bethanie",
+    "bethanns",
+    "bethanys",
+    "bettinas",
+    "bettongs",
+    "bettyann",
+    "beverlee",
+    "beverley",
+    "beverlys",
+    "bevilled",
+    "biannual",
+    "biasedly",
+    "biblical",
+    "bibulous",
+    "biconvex",
+    "bicyclic",
+    "biddable",
+    "biddably",
+    "bienvenu",
+    "bifacial",
+    "biforate",
+    "biforked",
+    "bigamous",
+    "bihourly",
+    "bijugate",
+    "bilgiest",
+    "bilinear",
+    "billable",
+    "billfish",
+    "bilobate",
+    "bimanous",
+    "bimanual",
+    "bimensal",
+    "binately",
+    "binaural",
+    "bindable",
+    "biogenic",
+    "biolytic",
+    "bionomic",
+    "biotypic",
+    "biparous",
+    "biracial",
+    "biradial",
+    "biramous",
+    "birdless",
+    "birdlike",
+    "biserial",
+    "bistable",
+    "bistered",
+    "biteable",
+    "bitingly",
+    "bitterly",
+    "bitterns",
+    "biunique",
+    "biweekly",
+    "biyearly",
+    "blackcap",
+    "blackfly",
+    "blackish",
+    "bladdery",
+    "blakelys",
+    "blamable",
+    "blamably",
+    "blameful",
+    "blanches",
+    "bleakish",
+    "blearier",
+    "blearily",
+    "blesboks",
+    "blissful",
+    "blistery",
+    "blithely",
+    "blockier",
+    "blockish",
+    "blondell",
+    "blondish",
+    "bloodier",
+    "bloodily",
+    "bloomier",
+    "blooming",
+    "blossoms",
+    "blossomy",
+    "blotless",
+    "blousier",
+    "blousily",
+    "blowfish",
+    "blowiest",
+    "blowsier",
+    "blowsily",
+    "blowzier",
+    "blowzily",
+    "blubbery",
+    "bluebill",
+    "bluebird",
+    "bluefish",
+    "bluegill",
+    "bluejays",
+    "bluishly",
+    "blurrily",
+    "blushful",
+    "blustery",
+    "boarfish",
+    "boastful",
+    "boatable",
+    "boatbill",
+    "boatless",
+    "bobettes",
+    "bobolink",
+    "bobwhite",
+    "bodiless",
+    "bodingly",
+    "boilable",
+    "boltless",
+    "boltlike",
+    "bombable",
+    "bondless",
+    "bonefish",
+    "boneless",
+    "bonelike",
+    "bonniest",
+    "bontebok",
+    "bookless",
+    "booklike",
+    "boomless",
+    "boonless",
+    "bootless",
+    "booziest",
+    "bordered",
+    "boreable",
+    "boresome",
+    "boringly",
+    "bornitic",
+    "boskiest",
+    "bosseyed",
+    "bossiest",
+    "botchier",
+    "botchily",
+    "botryose",
+    "boughten",
+    "bouncily",
+    "bounding",
+    "bovinely",
+    "bowingly",
+    "bowllike",
+    "boxfishs",
+    "boyishly",
+    "brachial",
+    "brackish",
+    "bracteal",
+    "bradford",
+    "bradleys",
+    "braedens",
+    "braelynn",
+    "braelyns",
+    "bragless",
+    "braidens",
+    "brainier",
+    "brainily",
+    "brandees",
+    "brandens",
+    "brandies",
+    "brandons",
+    "brankier",
+    "brannier",
+    "bransons",
+    "brantlee",
+    "brantley",
+    "brashier",
+    "brattier",
+    "brattily",
+    "brattish",
+    "brawnily",
+    "braxtons",
+    "braydens",
+    "braydons",
+    "braylees",
+    "braylens",
+    "braylons",
+    "brazenly",
+    "breannas",
+    "breannes",
+    "breathed",
+    "breccial",
+    "breckens",
+    "breezier",
+    "breezily",
+    "brendans",
+    "brendens",
+    "brendons",
+    "brennans",
+    "brentley",
+    "brentons",
+    "briannas",
+    "briannes",
+    "bribable",
+    "brickier",
+    "brickish",
+    "brickred",
+    "bridally",
+    "bridgers",
+    "bridgets",
+    "bridgett",
+    "briellas",
+    "brielles",
+    "brigette",
+    "brightly",
+    "brigidas",
+    "brigitte",
+    "brimless",
+    "brimming",
+    "brindled",
+    "briniest",
+    "brinleys",
+    "bristols",
+    "britanys",
+    "britneys",
+    "brittani",
+    "brittany",
+    "britteny",
+    "brittney",
+    "brittnis",
+    "brittnys",
+    "broadish",
+    "broccoli",
+    "brockets",
+    "broguish",
+    "brokenly",
+    "bromidic",
+    "bronsons",
+    "bronwyns",
+    "broodier",
+    "broodily",
+    "brooklyn",
+    "brouilla",
+    "browless",
+    "brownish",
+    "brunilda",
+    "brutally",
+    "bryannas",
+    "bryleigh",
+    "brynlees",
+    "bubaline",
+    "bubblier",
+    "buccally",
+    "buckshee",
+    "buffable",
+    "buffalos",
+    "buggiest",
+    "bulbless",
+    "bulimiac",
+    "bulkiest",
+    "bulldogs",
+    "bullfrog",
+    "bullhead",
+    "bulllike",
+    "bumpiest",
+    "bunchier",
+    "bunchily",
+    "bunodont",
+    "buntings",
+    "burdened",
+    "burliest",
+    "burnable",
+    "burriest",
+    "bursting",
+    "bushbuck",
+    "bushiest",
+    "bushless",
+    "bushlike",
+    "business",
+    "buskined",
+    "bustards",
+    "bustiest",
+    "bustling",
+    "buzzards",
+    "caboched",
+    "caboshed",
+    "cachexic",
+    "caddiced",
+    "caddised",
+    "cadences",
+    "caducean",
+    "caducous",
+    "caecally",
+    "caesural",
+    "caesuric",
+    "caftaned",
+    "cageless",
+    "cagelike",
+    "caitlins",
+    "caitlyns",
+    "calandra",
+    "calcific",
+    "calcitic",
+    "calfless",
+    "calflike",
+    "calibred",
+    "calicoed",
+    "caliphal",
+    "calistas",
+    "callable",
+    "callosal",
+    "callowly",
+    "calmiest",
+    "calycate",
+    "calycine",
+    "camailed",
+    "camelias",
+    "camellia",
+    "camerons",
+    "camillas",
+    "camilles",
+    "campbell",
+    "cancered",
+    "cancrine",
+    "candaces",
+    "candance",
+    "candices",
+    "candidas",
+    "candidly",
+    "candyces",
+    "canelike",
+    "cankered",
+    "cannabic",
+    "canniest",
+    "cannular",
+    "canorous",
+    "cantonal",
+    "cantoral",
+    "cantoris",
+    "capelins",
+    "caprices",
+    "caprylic",
+    "capsular",
+    "captious",
+    "capuched",
+    "capuchin",
+    "capybara",
+    "caracals",
+    "caracara",
+    "carbamic",
+    "carbolic",
+    "carbonic",
+    "carboyed",
+    "cardinal",
+    "carditic",
+    "carefree",
+    "careless",
+    "careworn",
+    "caribous",
+    "caridads",
+    "caringly",
+    "carissas",
+    "caritive",
+    "carleens",
+    "carlenas",
+    "carlenes",
+    "carletta",
+    "carlines",
+    "carlitas",
+    "carlotas",
+    "carlotta",
+    "carltons",
+    "carmelas",
+    "carmelia",
+    "carmella",
+    "carmelos",
+    "carminas",
+    "carmines",
+    "carnally",
+    "carneous",
+    "carolann",
+    "carolees",
+    "carolina",
+    "caroline",
+    "carolins",
+    "carolyne",
+    "carolynn",
+    "carolyns",
+    "caroylns",
+    "carrolls",
+    "cartable",
+    "carylons",
+    "casandra",
+    "caseless",
+    "cashable",
+    "casimira",
+    "casklike",
+    "castable",
+    "castiels",
+    "casually",
+    "cataleya",
+    "catalina",
+    "catarina",
+    "catbirds",
+    "catchfly",
+    "catchier",
+    "category",
+    "caterina",
+    "catfaced",
+    "catfishs",
+    "catherin",
+    "catherns",
+    "catheryn",
+    "cathleen",
+    "cathodic",
+    "cathrine",
+    "cathryns",
+    "cationic",
+    "catrices",
+    "catrinas",
+    "caudally",
+    "caulomic",
+    "causable",
+    "causally",
+    "cavelike",
+    "cavicorn",
+    "cavitied",
+    "cayenned",
+    "cecelias",
+    "cecilias",
+    "cecilles",
+    "cedricks",
+    "celestas",
+    "celestes",
+    "celindas",
+    "cellular",
+    "centered",
+    "ceorlish",
+    "cephalic",
+    "ceratoid",
+    "cercelae",
+    "cerebral",
+    "cerebric",
+    "cereless",
+    "cernuous",
+    "cervical",
+    "chadless",
+    "chadwick",
+    "chaffier",
+    "chalazal",
+    "chaliced",
+    "chalkier",
+    "champion",
+    "chancier",
+    "chancily",
+    "chandler",
+    "chandras",
+    "chanelle",
+    "chanells",
+    "channing",
+    "chantals",
+    "chantays",
+    "chantell",
+    "chantels",
+    "chariest",
+    "charises",
+    "charissa",
+    "charisse",
+    "charitas",
+    "charitys",
+    "charleen",
+    "charlees",
+    "charlena",
+    "charlene",
+    "charleys",
+    "charlies",
+    "charline",
+    "charlize",
+    "charlott",
+    "charlsie",
+    "charlyns",
+    "charmain",
+    "charming",
+    "charquid",
+    "charrier",
+    "chasidys",
+    "chasitys",
+    "chastely",
+    "chastest",
+    "chattery",
+    "chattier",
+    "chattily",
+    "chauncey",
+    "cheekier",
+    "cheekily",
+    "cheerful",
+    "cheerier",
+    "cheerily",
+    "cheesily",
+    "cheetahs",
+    "chelseas",
+    "chelseys",
+    "chelsies",
+    "cherelle",
+    "cherilyn",
+    "cherises",
+    "cherishs",
+    "cherlyns",
+    "cherries",
+    "cherryls",
+    "cherubic",
+    "cheryles",
+    "cherylls",
+    "chesters",
+    "chestier",
+    "chestily",
+    "chewable",
+    "chewiest",
+    "cheyenne",
+    "chiasmal",
+    "chiasmic",
+    "chiastic",
+    "chickens",
+    "chiggers",
+    "childing",
+    "childish",
+    "chillier",
+    "chillily",
+    "chimaera",
+    "chinless",
+    "chinooks",
+    "chipmunk",
+    "chiquita",
+    "chirpier",
+    "chirpily",
+    "chirrupy",
+    "chlorous",
+    "choicely",
+    "choicest",
+    "chokiest",
+    "choleric",
+    "chondral",
+    "choosier",
+    "choppier",
+    "choppily",
+    "choragic",
+    "chorally",
+    "choreoid",
+    "chrismal",
+    "chrissys",
+    "christal",
+    "christas",
+    "christel",
+    "christen",
+    "christia",
+    "christie",
+    "christin",
+    "christis",
+    "christys",
+    "chromous",
+    "chrystal",
+    "chubbier",
+    "chubbily",
+    "chuffier",
+    "chuffily",
+    "chummier",
+    "chummily",
+    "chumpish",
+    "chunkier",
+    "chunkily",
+    "churchly",
+    "churlish",
+    "cibarial",
+    "cibarian",
+    "cichlids",
+    "ciderish",
+    "cinerary",
+    "cingular",
+    "cinnamic",
+    "cinnamon",
+    "cinnamyl",
+    "cinthias",
+    "cislunar",
+    "citatory",
+    "citeable",
+    "citified",
+    "citreous",
+    "cityfied",
+    "cityless",
+    "citylike",
+    "clamlike",
+    "clammily",
+    "clanless",
+    "clannish",
+    "clarence",
+    "claretha",
+    "claretta",
+    "claribel",
+    "clarices",
+    "clarinda",
+    "clarines",
+    "clarisas",
+    "clarissa",
+    "claritas",
+    "clasping",
+    "clattery",
+    "claudias",
+    "claudies",
+    "claudine",
+    "claudios",
+    "clawless",
+    "claylike",
+    "claytons",
+    "cleidoic",
+    "clemente",
+    "clements",
+    "clemmies",
+    "clerical",
+    "clerkish",
+    "cleverly",
+    "cliental",
+    "cliffier",
+    "clifford",
+    "cliftons",
+    "climatic",
+    "climbing",
+    "clinally",
+    "clingier",
+    "clinical",
+    "clintons",
+    "cliquish",
+    "cloddily",
+    "cloddish",
+    "clodlike",
+    "cloggily",
+    "clonally",
+    "clorinda",
+    "clotilde",
+    "cloudier",
+    "cloudily",
+    "clovered",
+    "clownish",
+    "clubbier",
+    "clubbily",
+    "clueless",
+    "clumpish",
+    "clumsier",
+    "clumsily",
+    "clustery",
+    "clypeate",
+    "coactive",
+    "coaliest",
+    "coalless",
+    "coarsely",
+    "coarsest",
+    "coatless",
+    "cobaltic",
+    "cobwebby",
+    "cochlear",
+    "cockatoo",
+    "codeless",
+    "codlings",
+    "coercive",
+    "coevally",
+    "coffered",
+    "cogently",
+    "cognatic",
+    "coherent",
+    "cohesive",
+    "coinable",
+    "coitally",
+    "cokelike",
+    "colemans",
+    "colettas",
+    "colettes",
+    "colleens",
+    "collenes",
+    "collette",
+    "colorful",
+    "colossal",
+    "coloured",
+    "columbic",
+    "columbus",
+    "columnar",
+    "columned",
+    "comatose",
+    "combless",
+    "comedial",
+    "comelier",
+    "comelily",
+    "cometary",
+    "comfiest",
+    "comitial",
+    "commonly",
+    "communal",
+    "complete",
+    "composed",
+    "conative",
+    "concetta",
+    "conchate",
+    "conchita",
+    "concrete",
+    "condylar",
+    "confined",
+    "confocal",
+    "congress",
+    "conidial",
+    "conidian",
+    "conjoint",
+    "conjugal",
+    "consider",
+    "consuela",
+    "consuelo",
+    "consular",
+    "contessa",
+    "contrate",
+    "contrite",
+    "convexly",
+    "convince",
+    "cooingly",
+    "cookable",
+    "cookless",
+    "coplanar",
+    "coppiced",
+    "copremic",
+    "coralees",
+    "coralies",
+    "coraline",
+    "corazons",
+    "cordelia",
+    "cordells",
+    "cordless",
+    "cordlike",
+    "coreless",
+    "corettas",
+    "corinnas",
+    "corinnes",
+    "corkiest",
+    "corklike",
+    "cormlike",
+    "cornelia",
+    "cornells",
+    "corneous",
+    "cornered",
+    "corniest",
+    "corrinas",
+    "corrines",
+    "corrinne",
+    "cortical",
+    "cortneys",
+    "corymbed",
+    "costally",
+    "costless",
+    "costlier",
+    "cotingas",
+    "cottaged",
+    "couchant",
+    "coursers",
+    "courtney",
+    "cousinly",
+    "couthily",
+    "covalent",
+    "covertly",
+    "covetous",
+    "covinous",
+    "cowardly",
+    "cowbirds",
+    "cowfishs",
+    "coxalgic",
+    "crabbier",
+    "crabbily",
+    "crablike",
+    "craftier",
+    "craftily",
+    "craggier",
+    "craggily",
+    "craglike",
+    "crankier",
+    "crankily",
+    "crankous",
+    "crannied",
+    "crappies",
+    "crashing",
+    "crateral",
+    "cravenly",
+    "crawdads",
+    "crawlier",
+    "crayfish",
+    "crazedly",
+    "craziest",
+    "creakier",
+    "creakily",
+    "creamier",
+    "creamily",
+    "creative",
+    "credible",
+    "credibly",
+    "credited",
+    "creepers",
+    "creepier",
+    "creepily",
+    "crenella",
+    "crescive",
+    "cressier",
+    "cresylic",
+    "creviced",
+    "crewless",
+    "crewneck",
+    "crickets",
+    "crimpier",
+    "crinated",
+    "criselda",
+    "crispate",
+    "crispier",
+    "crispily",
+    "cristals",
+    "cristate",
+    "cristens",
+    "cristian",
+    "cristies",
+    "cristina",
+    "cristine",
+    "cristins",
+    "critical",
+    "croakers",
+    "croakier",
+    "croakily",
+    "crocused",
+    "cropless",
+    "crotched",
+    "croupily",
+    "croupous",
+    "crousely",
+    "crowning",
+    "cruciate",
+    "crumbier",
+    "crummier",
+    "crunodal",
+    "crustier",
+    "crustily",
+    "crutched",
+    "cryingly",
+    "crystals",
+    "crystles",
+    "cubiform",
+    "cubistic",
+    "culinary",
+    "culpable",
+    "culpably",
+    "cultrate",
+    "cultural",
+    "cultured",
+    "cuneatic",
+    "cupboard",
+    "cupreous",
+    "cupulate",
+    "curassow",
+    "curbable",
+    "curbless",
+    "curblike",
+    "curdiest",
+    "cureless",
+    "curledly",
+    "curliest",
+    "cursedly",
+    "curvedly",
+    "curviest",
+    "cushiest",
+    "cushiony",
+    "cuspidal",
+    "cussedly",
+    "cuttable",
+    "cyaneous",
+    "cyanitic",
+    "cyanotic",
+    "cyanuric",
+    "cyclicly",
+    "cyclonal",
+    "cyclonic",
+    "cymosely",
+    "cynthias",
+    "cyrstals",
+    "cytozoic",
+    "dabchick",
+    "daemonic",
+    "daffiest",
+    "daimonic",
+    "daintier",
+    "dainties",
+    "daintily",
+    "daleyzas",
+    "dalilahs",
+    "daltonic",
+    "damnably",
+    "dancetta",
+    "dandyish",
+    "danelles",
+    "danettes",
+    "dangelos",
+    "danielas",
+    "danieles",
+    "daniella",
+    "danielle",
+    "daniells",
+    "danilles",
+    "dannette",
+    "danyelle",
+    "danyells",
+    "daphines",
+    "dapperly",
+    "daringly",
+    "darksome",
+    "darleens",
+    "darlenas",
+    "darlenes",
+    "darlines",
+    "darnells",
+    "darrells",
+    "darricks",
+    "dashedly",
+    "dashiest",
+    "dateable",
+    "dateless",
+    "datively",
+    "daughter",
+    "dawnlike",
+    "dazzling",
+    "deadlier",
+    "dealfish",
+    "deandras",
+    "deandrea",
+    "deandres",
+    "deangelo",
+    "deathful",
+    "debonair",
+    "deborahs",
+    "debroahs",
+    "debtless",
+    "december",
+    "decenary",
+    "decently",
+    "deciding",
+    "decidual",
+    "decisive",
+    "declared",
+    "decorate",
+    "decorous",
+    "decrease",
+    "decrepit",
+    "decurved",
+    "deeannas",
+    "deedless",
+    "deferred",
+    "defiable",
+    "definite",
+    "deflexed",
+    "deformed",
+    "degraded",
+    "deicidal",
+    "deirdres",
+    "dejected",
+    "delaines",
+    "delaneys",
+    "delberts",
+    "delfinas",
+    "delicate",
+    "delicias",
+    "delilahs",
+    "delindas",
+    "deloises",
+    "delorses",
+    "delphias",
+    "delphine",
+    "delusive",
+    "demarcus",
+    "demented",
+    "demersal",
+    "demetras",
+    "demetria",
+    "demonian",
+    "demurely",
+    "demurest",
+    "dendroid",
+    "deniable",
+    "deniably",
+    "denishas",
+    "denisses",
+    "dennises",
+    "denotive",
+    "dentally",
+    "dentiled",
+    "dentinal",
+    "depraved",
+    "deprived",
+    "deranged",
+    "derisive",
+    "dermatic",
+    "derricks",
+    "describe",
+    "deserted",
+    "desertic",
+    "deserved",
+    "deshawns",
+    "designed",
+    "desinent",
+    "desiraes",
+    "desirees",
+    "desirous",
+    "desmonds",
+    "despinas",
+    "despotic",
+    "destined",
+    "destinee",
+    "destinys",
+    "detached",
+    "detailed",
+    "detrital",
+    "deucedly",
+    "deviable",
+    "devorahs",
+    "devoutly",
+    "dewaynes",
+    "dextrous",
+    "diabasic",
+    "diabolic",
+    "diacidic",
+    "diaconal",
+    "dialogic",
+    "dialytic",
+    "diamonds",
+    "diarchic",
+    "diastral",
+    "diatomic",
+    "diatonic",
+    "dicastic",
+    "dichroic",
+    "dicrotic",
+    "didactic",
+    "didymous",
+    "diecious",
+    "dierdres",
+    "dieretic",
+    "dietetic",
+    "digamous",
+    "digitate",
+    "dihydric",
+    "dilatate",
+    "dilative",
+    "dilatory",
+    "dilemmic",
+    "diligent",
+    "dilutely",
+    "diluvial",
+    "dimerous",
+    "dimetric",
+    "dingiest",
+    "dinkiest",
+    "dinorahs",
+    "dinosaur",
+    "dintless",
+    "dioicous",
+    "dioptral",
+    "dioptric",
+    "dioramic",
+    "dioritic",
+    "diplegic",
+    "diplopic",
+    "dippiest",
+    "diprotic",
+    "dipteral",
+    "directed",
+    "directly",
+    "dirgeful",
+    "diriment",
+    "dirtiest",
+    "disagree",
+    "discover",
+    "discreet",
+    "discrete",
+    "diseased",
+    "disklike",
+    "disloyal",
+    "dismally",
+    "disorder",
+    "disposed",
+    "distally",
+    "distance",
+    "distinct",
+    "distingu",
+    "distrait",
+    "dividual",
+    "divinely",
+    "divisive",
+    "dizziest",
+    "dizzying",
+    "doberman",
+    "docilely",
+    "doctoral",
+    "doctorly",
+    "document",
+    "doddered",
+    "dogfishs",
+    "doggedly",
+    "doggiest",
+    "doggoned",
+    "dogmatic",
+    "dolesome",
+    "dolmenic",
+    "dolorous",
+    "dolphins",
+    "domanial",
+    "domelike",
+    "domenica",
+    "domenics",
+    "dominant",
+    "domingas",
+    "domingos",
+    "dominica",
+    "dominick",
+    "dominics",
+    "dominiks",
+    "dominque",
+    "domitila",
+    "donellas",
+    "donettas",
+    "donettes",
+    "donnells",
+    "donnered",
+    "donnetta",
+    "donnette",
+    "donovans",
+    "doorless",
+    "dorathys",
+    "doreatha",
+    "dorethas",
+    "dorethea",
+    "dorettas",
+    "dorindas",
+    "dormered",
+    "dormient",
+    "dormouse",
+    "dorothas",
+    "dorothea",
+    "dorothys",
+    "dorsally",
+    "dortheas",
+    "dortheys",
+    "dotardly",
+    "dotingly",
+    "dotterel",
+    "dottiest",
+    "doubtful",
+    "doughier",
+    "dovelike",
+    "dowdyish",
+    "downiest",
+    "downless",
+    "downlike",
+    "downward",
+    "doxastic",
+    "drachmal",
+    "draconic",
+    "draftier",
+    "draftily",
+    "dragonet",
+    "dramatic",
+    "drapable",
+    "drawable",
+    "dreadful",
+    "dreamful",
+    "dreamier",
+    "dreamily",
+    "drearier",
+    "drearies",
+    "drearily",
+    "dressier",
+    "dressily",
+    "driftier",
+    "drippily",
+    "drivable",
+    "droolier",
+    "droopier",
+    "droopily",
+    "droplike",
+    "dropsied",
+    "drossier",
+    "droughty",
+    "drowsier",
+    "drowsily",
+    "drucilla",
+    "drugless",
+    "drumlier",
+    "drusilla",
+    "duckbill",
+    "duckiest",
+    "duckling",
+    "ductless",
+    "dudishly",
+    "dulcetly",
+    "dumpiest",
+    "duncical",
+    "dunnocks",
+    "duodenal",
+    "duskiest",
+    "dustiest",
+    "dustless",
+    "dutiable",
+    "dwarfish",
+    "dyarchic",
+    "dynastic",
+    "dyostyle",
+    "dysgenic",
+    "dysgonic",
+    "dyspneal",
+    "dyspneic",
+    "dyspnoic",
+    "dystonic",
+    "earleans",
+    "earleens",
+    "earlenes",
+    "earlines",
+    "earnests",
+    "earthier",
+    "earthily",
+    "earwiggy",
+    "easterly",
+    "eastmost",
+    "eastward",
+    "easylike",
+    "ecaudate",
+    "ecdysial",
+    "echidnas",
+    "echinate",
+    "echoless",
+    "eclectic",
+    "ecologic",
+    "economic",
+    "ecotonal",
+    "ecotypic",
+    "ecstatic",
+    "ectozoic",
+    "edacious",
+    "edelmira",
+    "edgardos",
+    "edgeless",
+    "edgingly",
+    "edmundos",
+    "eduardos",
+    "educable",
+    "educated",
+    "educible",
+    "eductive",
+    "edwardos",
+    "eelpouts",
+    "efferent",
+    "effetely",
+    "effigial",
+    "effusive",
+    "egestive",
+    "egoistic",
+    "eighthly",
+    "elatedly",
+    "eldridge",
+    "eldritch",
+    "eleanora",
+    "eleanore",
+    "eleanors",
+    "elective",
+    "electric",
+    "elenctic",
+    "elenoras",
+    "elenores",
+    "eleonora",
+    "eleonore",
+    "eleonors",
+    "elephant",
+    "elevated",
+    "elevator",
+    "elfishly",
+    "elfredas",
+    "elfrieda",
+    "elfriede",
+    "eliannas",
+    "elidible",
+    "eligible",
+    "eligibly",
+    "elinores",
+    "elizabet",
+    "elizbeth",
+    "ellamaes",
+    "ellianas",
+    "elliotts",
+    "elliptic",
+    "ellisons",
+    "eloquent",
+    "elouises",
+    "elvishly",
+    "elwandas",
+    "elytroid",
+    "elytrous",
+    "emanuels",
+    "embryoid",
+    "emelinas",
+    "emelines",
+    "emeralds",
+    "emerging",
+    "emeritas",
+    "emersons",
+    "emersyns",
+    "emiliano",
+    "emissive",
+    "emmalees",
+    "emmaline",
+    "emmalynn",
+    "emmalyns",
+    "emmanuel",
+    "emogenes",
+    "empathic",
+    "emperors",
+    "empestic",
+    "emptiest",
+    "empyemic",
+    "empyreal",
+    "emulated",
+    "emulsive",
+    "enabling",
+    "enactive",
+    "enactory",
+    "enarched",
+    "encastra",
+    "endeared",
+    "endermic",
+    "endorsed",
+    "endurant",
+    "enduring",
+    "enedinas",
+    "engaging",
+    "enginous",
+    "enhanced",
+    "enjambed",
+    "enneadic",
+    "ennuyant",
+    "enormous",
+    "enriched",
+    "enriques",
+    "ensiform",
+    "enthetic",
+    "enticing",
+    "entirely",
+    "entozoic",
+    "enuretic",
+    "envelope",
+    "enviable",
+    "enviably",
+    "eolithic",
+    "epagogic",
+    "ephraims",
+    "epibolic",
+    "epically",
+    "epiclike",
+    "epidotic",
+    "epifania",
+    "epifocal",
+    "epigamic",
+    "epigeous",
+    "epigonic",
+    "epimeric",
+    "epiploic",
+    "episodic",
+    "epitaxic",
+    "epitomic",
+    "eponymic",
+    "equiform",
+    "equinely",
+    "equipped",
+    "equitant",
+    "erasable",
+    "erective",
+    "eremitic",
+    "erlindas",
+    "erminias",
+    "ernestos",
+    "erodable",
+    "erodible",
+    "erosible",
+    "errantly",
+    "erringly",
+    "erumpent",
+    "eruptive",
+    "escargot",
+    "escolars",
+    "esoteric",
+    "especial",
+    "espiagle",
+    "estebans",
+    "esteemed",
+    "estefana",
+    "estellas",
+    "estelles",
+    "estrella",
+    "esurient",
+    "ethelene",
+    "ethelyns",
+    "ethereal",
+    "eucarpic",
+    "eufemias",
+    "eugenias",
+    "eugenies",
+    "eugenios",
+    "euglenas",
+    "euhedral",
+    "eulachon",
+    "eulalias",
+    "eupeptic",
+    "euphonic",
+    "euphoric",
+    "euphotic",
+    "eusebias",
+    "eusebios",
+    "eustatic",
+    "eustolia",
+    "evadable",
+    "evadible",
+    "evelinas",
+    "evelines",
+    "evelynes",
+    "evelynns",
+    "eventful",
+    "eventual",
+    "everette",
+    "everetts",
+    "everyday",
+    "evidence",
+    "evincive",
+    "evitable",
+    "evocable",
+    "evolving",
+    "exacting",
+    "exalting",
+    "exarchal",
+    "exchange",
+    "exciting",
+    "excretal",
+    "excurved",
+    "excusive",
+    "exegetic",
+    "exequial",
+    "exercise",
+    "exergual",
+    "exertive",
+    "exigeant",
+    "exigible",
+    "exiguous",
+    "exilable",
+    "eximious",
+    "exoergic",
+    "exorable",
+    "exordial",
+    "exoteric",
+    "exotoxic",
+    "expanded",
+    "expertly",
+    "expiable",
+    "explicit",
+    "exserted",
+    "extended",
+    "extremal",
+    "extremer",
+    "extrorse",
+    "exultant",
+    "exulting",
+    "ezekiels",
+    "ezequiel",
+    "fabiolas",
+    "fabulous",
+    "faceable",
+    "faceless",
+    "facetely",
+    "facially",
+    "facilely",
+    "factious",
+    "faddiest",
+    "fadeless",
+    "faintish",
+    "faithful",
+    "falconet",
+    "fallible",
+    "fallibly",
+    "fameless",
+    "familial",
+    "familiar",
+    "famished",
+    "famously",
+    "fanciful",
+    "fangless",
+    "fanglike",
+    "fantails",
+    "farcical",
+    "farinose",
+    "farmable",
+    "farouche",
+    "fasciate",
+    "fastuous",
+    "fatherly",
+    "fatigued",
+    "fatimahs",
+    "fattiest",
+    "faultier",
+    "faultily",
+    "faunally",
+    "faunlike",
+    "faustina",
+    "faustino",
+    "faviolas",
+    "favonian",
+    "favorite",
+    "favoured",
+    "fawnlike",
+    "fearless",
+    "fearsome",
+    "feasible",
+    "feasibly",
+    "feastful",
+    "feathery",
+    "febrific",
+    "february",
+    "feckless",
+    "feculent",
+    "federico",
+    "feeblest",
+    "feeblish",
+    "feedable",
+    "feetless",
+    "feistily",
+    "felecias",
+    "felicias",
+    "felicita",
+    "felicity",
+    "felinely",
+    "felishas",
+    "fellable",
+    "fellowly",
+    "felsitic",
+    "feminine",
+    "fendered",
+    "ferminas",
+    "fernanda",
+    "fernande",
+    "fernando",
+    "ferniest",
+    "fernless",
+    "fernlike",
+    "ferreous",
+    "fervidly",
+    "fesswise",
+    "festally",
+    "festival",
+    "fetching",
+    "feudally",
+    "feverish",
+    "feverous",
+    "fibratus",
+    "fibrotic",
+    "ficklely",
+    "fiddling",
+    "fidelias",
+    "fiducial",
+    "fiendish",
+    "fiercely",
+    "fiercest",
+    "fieriest",
+    "figgiest",
+    "figurate",
+    "filarial",
+    "filefish",
+    "filially",
+    "filiform",
+    "fillable",
+    "filmable",
+    "filmiest",
+    "filmlike",
+    "filomena",
+    "filthier",
+    "filthily",
+    "fimbrial",
+    "findable",
+    "fineable",
+    "finespun",
+    "finfoots",
+    "fingered",
+    "finialed",
+    "finished",
+    "finitely",
+    "finnegan",
+    "finnicky",
+    "finniest",
+    "fireback",
+    "firebrat",
+    "fireflys",
+    "fireless",
+    "fiscally",
+    "fishable",
+    "fishiest",
+    "fishless",
+    "fissural",
+    "fitfully",
+    "fittable",
+    "fizziest",
+    "flabbier",
+    "flabbily",
+    "flaggier",
+    "flagless",
+    "flagrant",
+    "flakiest",
+    "flamiest",
+    "flamingo",
+    "flamless",
+    "flapless",
+    "flappier",
+    "flashier",
+    "flashily",
+    "flatfish",
+    "flathead",
+    "flattish",
+    "flavoury",
+    "flawiest",
+    "flawless",
+    "fledgier",
+    "fleecier",
+    "fleecily",
+    "fleeting",
+    "fleshier",
+    "fleshily",
+    "fletcher",
+    "flexible",
+    "flexibly",
+    "flexuous",
+    "flexural",
+    "fleyedly",
+    "fleysome",
+    "flickery",
+    "flimsier",
+    "flimsies",
+    "flimsily",
+    "flintier",
+    "flintily",
+    "flippest",
+    "floatier",
+    "floating",
+    "floccose",
+    "flockier",
+    "floppier",
+    "floppily",
+    "florally",
+    "florance",
+    "florence",
+    "florenes",
+    "floretta",
+    "floretty",
+    "floridas",
+    "floridly",
+    "florinda",
+    "florines",
+    "florries",
+    "flossies",
+    "flossily",
+    "flounder",
+    "flowable",
+    "flowered",
+    "fluently",
+    "fluffier",
+    "fluffily",
+    "flukiest",
+    "flurried",
+    "flutiest",
+    "fluttery",
+    "flyblown",
+    "foamiest",
+    "foamless",
+    "foamlike",
+    "fogbound",
+    "foggiest",
+    "foilable",
+    "foldable",
+    "foldaway",
+    "foliaged",
+    "foliated",
+    "folksier",
+    "folksily",
+    "foodless",
+    "footiest",
+    "footless",
+    "footling",
+    "footsore",
+    "footworn",
+    "forcedly",
+    "forceful",
+    "forcible",
+    "forcibly",
+    "fordable",
+    "fordless",
+    "foregone",
+    "foremost",
+    "forensic",
+    "foresaid",
+    "forestal",
+    "foreworn",
+    "forkedly",
+    "forkiest",
+    "forkless",
+    "forklike",
+    "formable",
+    "formably",
+    "formally",
+    "formerly",
+    "formless",
+    "fornical",
+    "forrests",
+    "forspent",
+    "fortyish",
+    "foughten",
+    "fourthly",
+    "foveolar",
+    "foxhound",
+    "fragrant",
+    "framable",
+    "francene",
+    "francies",
+    "francina",
+    "francine",
+    "frankies",
+    "franklin",
+    "franklyn",
+    "fraudful",
+    "frazzled",
+    "freakier",
+    "freakily",
+    "freakish",
+    "freddies",
+    "frederic",
+    "fredrick",
+    "fredrics",
+    "freeborn",
+    "freemans",
+    "freewill",
+    "frenetic",
+    "frenular",
+    "frenzied",
+    "frenzily",
+    "frequent",
+    "fretless",
+    "frettier",
+    "friended",
+    "friendly",
+    "frigging",
+    "frigidly",
+    "friskier",
+    "friskily",
+    "frizzier",
+    "frizzily",
+    "frogeyed",
+    "frogfish",
+    "froggier",
+    "froglike",
+    "frolicly",
+    "frostier",
+    "frostily",
+    "frothier",
+    "frothily",
+    "frousier",
+    "frouzier",
+    "frowsier",
+    "frowsily",
+    "frowzier",
+    "frowzily",
+    "frozenly",
+    "frugally",
+    "fruitful",
+    "fruitier",
+    "fruitily",
+    "fruitive",
+    "frumpier",
+    "frumpily",
+    "frumpish",
+    "fubsiest",
+    "fulminic",
+    "fumeless",
+    "fumelike",
+    "fumingly",
+    "funerary",
+    "funereal",
+    "funkiest",
+    "funniest",
+    "furcular",
+    "furibund",
+    "furlable",
+    "furriest",
+    "fuseless",
+    "fuselike",
+    "fusiform",
+    "fussiest",
+    "fustiest",
+    "futilely",
+    "fuzziest",
+    "gabbroic",
+    "gabbroid",
+    "gabelled",
+    "gabriela",
+    "gabriele",
+    "gabriels",
+    "gadwalls",
+    "gainable",
+    "gainless",
+    "galactic",
+    "galileas",
+    "galileos",
+    "gallused",
+    "galvanic",
+    "gameless",
+    "gamelike",
+    "gamesome",
+    "ganglial",
+    "gangliar",
+    "ganglier",
+    "gangling",
+    "gapingly",
+    "garbless",
+    "garfield",
+    "garfishs",
+    "garganey",
+    "garishly",
+    "garlands",
+    "garlicky",
+    "garnetts",
+    "garpikes",
+    "garreted",
+    "garretts",
+    "garrison",
+    "gasiform",
+    "gastight",
+    "gateless",
+    "gatelike",
+    "gauchely",
+    "gaumless",
+    "gauziest",
+    "gawkiest",
+    "gaylenes",
+    "gaylords",
+    "gaynelle",
+    "gaynells",
+    "gazeless",
+    "gazelles",
+    "gazingly",
+    "gearless",
+    "geldings",
+    "gemmiest",
+    "gemsboks",
+    "generous",
+    "genevies",
+    "genevive",
+    "genially",
+    "genoveva",
+    "gentlest",
+    "gentling",
+    "geodetic",
+    "geoffrey",
+    "geologic",
+    "geoponic",
+    "georgann",
+    "georgene",
+    "georgias",
+    "georgies",
+    "georgina",
+    "georgine",
+    "geraldos",
+    "geralyns",
+    "gerardos",
+    "gerenuks",
+    "germaine",
+    "germfree",
+    "germless",
+    "germlike",
+    "gertrude",
+    "gertruds",
+    "gertudes",
+    "gestural",
+    "gettable",
+    "gewgawed",
+    "geyseral",
+    "geyseric",
+    "ghastful",
+    "ghostily",
+    "ghoulish",
+    "giavanna",
+    "gibingly",
+    "giddiest",
+    "giddying",
+    "giftedly",
+    "giftless",
+    "gigantic",
+    "gigglier",
+    "gilberte",
+    "gilberto",
+    "gilberts",
+    "gildable",
+    "gillians",
+    "gimmicky",
+    "ginettes",
+    "gingelly",
+    "gingerly",
+    "gingival",
+    "giovanis",
+    "giovanna",
+    "giovanni",
+    "giovanny",
+    "gipseian",
+    "gipsyish",
+    "giraffes",
+    "giselles",
+    "giuliana",
+    "giuseppe",
+    "giveable",
+    "glabrate",
+    "glabrous",
+    "gladiate",
+    "gladsome",
+    "glairier",
+    "glariest",
+    "glaucous",
+    "gleaming",
+    "gleesome",
+    "gleetier",
+    "glendora",
+    "glenlike",
+    "glennies",
+    "glibbest",
+    "gliddery",
+    "glittery",
+    "globally",
+    "globular",
+    "gloomful",
+    "gloomier",
+    "gloomily",
+    "gloopily",
+    "glorious",
+    "glossier",
+    "glossies",
+    "glossily",
+    "glowworm",
+    "glucidic",
+    "glucinic",
+    "glucosic",
+    "gluelike",
+    "glummest",
+    "glumpier",
+    "glumpily",
+    "glycemic",
+    "glyceric",
+    "glycolic",
+    "gnarlier",
+    "gnatlike",
+    "gnattier",
+    "gnawable",
+    "gneissic",
+    "gnomonic",
+    "goadlike",
+    "goalless",
+    "goatfish",
+    "goatlike",
+    "gobblers",
+    "godliest",
+    "goitrous",
+    "goldenly",
+    "goldfish",
+    "gonadial",
+    "gonglike",
+    "gonidial",
+    "gonydeal",
+    "gonydial",
+    "gonzalos",
+    "goodlier",
+    "goofiest",
+    "gorbelly",
+    "gorgedly",
+    "gorgeous",
+    "gorgeted",
+    "gorillas",
+    "gormless",
+    "goshawks",
+    "gospelly",
+    "gouramis",
+    "goutiest",
+    "graceful",
+    "gracelyn",
+    "graciela",
+    "gracious",
+    "grackles",
+    "grainier",
+    "granitic",
+    "granular",
+    "grapiest",
+    "grateful",
+    "gravelly",
+    "gravidly",
+    "grayling",
+    "graysons",
+    "grazynas",
+    "greasier",
+    "greasily",
+    "greedier",
+    "greedily",
+    "greenfly",
+    "greenish",
+    "gregoria",
+    "gregorio",
+    "gregorys",
+    "gretchen",
+    "grewsome",
+    "greysons",
+    "gricelda",
+    "grievous",
+    "griffins",
+    "griffons",
+    "grimiest",
+    "grimmest",
+    "gripeful",
+    "gripiest",
+    "gripless",
+    "grippier",
+    "griselda",
+    "griseous",
+    "grislier",
+    "gritless",
+    "grittier",
+    "grittily",
+    "grizzled",
+    "grizzlys",
+    "groggier",
+    "groggily",
+    "groomish",
+    "groovier",
+    "grosbeak",
+    "grottoed",
+    "grounded",
+    "groupers",
+    "groutier",
+    "growable",
+    "grubbier",
+    "grubbily",
+    "grubworm",
+    "gruesome",
+    "gruffier",
+    "gruffily",
+    "gruffish",
+    "grummest",
+    "grumpier",
+    "grumpily",
+    "grunters",
+    "gruntled",
+    "guanacos",
+    "guardant",
+    "gudgeons",
+    "guidable",
+    "guileful",
+    "guiltier",
+    "guiltily",
+    "gulflike",
+    "gullable",
+    "gullably",
+    "gullible",
+    "gullibly",
+    "gulllike",
+    "gummiest",
+    "gurnards",
+    "gushiest",
+    "gustavos",
+    "gustiest",
+    "gustless",
+    "gustoish",
+    "gutsiest",
+    "gwyneths",
+    "gynaecic",
+    "gynecoid",
+    "gypseian",
+    "gypseous",
+    "gypsyish",
+    "gyratory",
+    "gyroidal",
+    "habitual",
+    "haddocks",
+    "haematal",
+    "hagfishs",
+    "haggadic",
+    "hairiest",
+    "hairless",
+    "hairlike",
+    "hairtail",
+    "haleighs",
+    "halfbeak",
+    "halibuts",
+    "hallowed",
+    "hallucal",
+    "halolike",
+    "haltless",
+    "hammered",
+    "hammiest",
+    "hamsters",
+    "hamulate",
+    "hamulous",
+    "handiest",
+    "handless",
+    "handlike",
+    "handmade",
+    "handsewn",
+    "handsome",
+    "hangable",
+    "haplitic",
+    "happiest",
+    "haptical",
+    "hardened",
+    "hardiest",
+    "harelike",
+    "harlands",
+    "harmless",
+    "harmonic",
+    "harmonys",
+    "harpless",
+    "harplike",
+    "harriers",
+    "harriets",
+    "harriett",
+    "harrison",
+    "hasteful",
+    "hastiest",
+    "hatcheck",
+    "hateable",
+    "haunched",
+    "haunting",
+    "hauriant",
+    "hawfinch",
+    "hawklike",
+    "haywoods",
+    "hazeless",
+    "headachy",
+    "headiest",
+    "headless",
+    "headlike",
+    "headlong",
+    "headmost",
+    "healable",
+    "hearable",
+    "heartier",
+    "hearties",
+    "heartily",
+    "heatable",
+    "heatedly",
+    "heathers",
+    "heathery",
+    "heathier",
+    "heatless",
+    "heatlike",
+    "heavenly",
+    "heaviest",
+    "heavyset",
+    "hecticly",
+    "hedgehog",
+    "hedgiest",
+    "heedless",
+    "heelless",
+    "heftiest",
+    "heirless",
+    "helaines",
+    "heliacal",
+    "heliaean",
+    "hellbent",
+    "helmeted",
+    "helmless",
+    "helpable",
+    "helpless",
+    "hematoid",
+    "hemiopic",
+    "hemplike",
+    "hendrixs",
+    "heralded",
+    "heraldic",
+    "herbaged",
+    "herberts",
+    "herbiest",
+    "herbless",
+    "herblike",
+    "herlinda",
+    "hermaean",
+    "hermetic",
+    "hermilas",
+    "herminas",
+    "hermines",
+    "herminia",
+    "hermitic",
+    "herolike",
+    "herpetic",
+    "herrings",
+    "herschel",
+    "hershels",
+    "hesitant",
+    "hetaeric",
+    "hetairic",
+    "hexaplar",
+    "hezekiah",
+    "hibernal",
+    "hiddenly",
+    "hideless",
+    "hidrotic",
+    "highborn",
+    "highbred",
+    "hilarias",
+    "hilarios",
+    "hildreds",
+    "hillarys",
+    "hilliest",
+    "hillocky",
+    "hiltless",
+    "hindmost",
+    "hipolito",
+    "hippiest",
+    "hireable",
+    "histioid",
+    "historic",
+    "hitchier",
+    "hitchily",
+    "hittable",
+    "hiveless",
+    "hivelike",
+    "hoariest",
+    "hoarsely",
+    "hoatzins",
+    "holdable",
+    "holeless",
+    "holistic",
+    "hollowly",
+    "holozoic",
+    "homebred",
+    "homelier",
+    "homelike",
+    "homemade",
+    "homesick",
+    "hominine",
+    "homodont",
+    "homodyne",
+    "honestly",
+    "honeybee",
+    "honeyful",
+    "honorary",
+    "hoodless",
+    "hoodlike",
+    "hoofless",
+    "hooflike",
+    "hookiest",
+    "hookless",
+    "hooklike",
+    "hookworm",
+    "hoopless",
+    "hooplike",
+    "hopeless",
+    "hopingly",
+    "hoplitic",
+    "horacios",
+    "hormonal",
+    "hormonic",
+    "hornbill",
+    "hornless",
+    "hornlike",
+    "horntail",
+    "horrible",
+    "horribly",
+    "horridly",
+    "horrific",
+    "horsefly",
+    "horsiest",
+    "hortense",
+    "hoseless",
+    "hoselike",
+    "hospital",
+    "hostless",
+    "houndish",
+    "hourless",
+    "housefly",
+    "houstons",
+    "hoverfly",
+    "huffiest",
+    "huggable",
+    "hulkiest",
+    "humanely",
+    "humberto",
+    "humblest",
+    "hummocky",
+    "humorful",
+    "humorous",
+    "humpback",
+    "humpiest",
+    "humpless",
+    "hungerly",
+    "hungrily",
+    "huntable",
+    "huntedly",
+    "hurtable",
+    "hurtless",
+    "hurtling",
+    "hushedly",
+    "huskiest",
+    "husklike",
+    "hyacinth",
+    "hydrarch",
+    "hydrated",
+    "hydremic",
+    "hydropic",
+    "hygienic",
+    "hylozoic",
+    "hymenial",
+    "hymnless",
+    "hymnlike",
+    "hypatias",
+    "hyphenic",
+    "hypoacid",
+    "hypogeal",
+    "hypogene",
+    "ibrahims",
+    "icebound",
+    "ichorous",
+    "ichthyic",
+    "idealess",
+    "ideative",
+    "identify",
+    "idoneous",
+    "ignacias",
+    "ignacios",
+    "ignorant",
+    "illiquid",
+    "illusive",
+    "illusory",
+    "illuvial",
+    "imaginal",
+    "imitable",
+    "immanely",
+    "immanent",
+    "immanuel",
+    "immature",
+    "immersed",
+    "imminent",
+    "immobile",
+    "immodest",
+    "immortal",
+    "immotile",
+    "imogenes",
+    "impacted",
+    "impishly",
+    "implicit",
+    "impolite",
+    "imposing",
+    "impotent",
+    "improper",
+    "improved",
+    "impudent",
+    "impurely",
+    "inactive",
+    "incisive",
+    "incisory",
+    "included",
+    "increase",
+    "increate",
+    "incubous",
+    "incudate",
+    "indebted",
+    "indecent",
+    "indented",
+    "indevout",
+    "indicate",
+    "indicial",
+    "indignly",
+    "indirect",
+    "indocile",
+    "indolent",
+    "indusial",
+    "industry",
+    "inedible",
+    "inedibly",
+    "inedited",
+    "inerrant",
+    "inertial",
+    "inexpert",
+    "infamous",
+    "infecund",
+    "infinite",
+    "infirmly",
+    "inflated",
+    "inflexed",
+    "informal",
+    "informed",
+    "infusive",
+    "ingeborg",
+    "inguinal",
+    "inherent",
+    "inhumane",
+    "inimical",
+    "innately",
+    "innocent",
+    "insanely",
+    "insectan",
+    "insecure",
+    "inserted",
+    "insolent",
+    "inspired",
+    "instable",
+    "intactly",
+    "integral",
+    "intently",
+    "interest",
+    "internal",
+    "intimate",
+    "intortus",
+    "intrepid",
+    "introrse",
+    "inturned",
+    "inundant",
+    "inurbane",
+    "invasive",
+    "invected",
+    "inviable",
+    "inviably",
+    "inviting",
+    "involved",
+    "inwardly",
+    "irefully",
+    "irelands",
+    "irmgards",
+    "ironical",
+    "ironless",
+    "ironlike",
+    "irrorate",
+    "isabelas",
+    "isabella",
+    "isabelle",
+    "isabells",
+    "isadoras",
+    "ischemic",
+    "isidioid",
+    "isleless",
+    "isobaric",
+    "isocyano",
+    "isodomic",
+    "isolable",
+    "isomeric",
+    "isonomic",
+    "isoporic",
+    "isotimic",
+    "isotonic",
+    "isotopic",
+    "isotypic",
+    "issuable",
+    "issuably",
+    "isthmian",
+    "isthmoid",
+    "itchiest",
+    "ivelisse",
+    "izabella",
+    "jacalyns",
+    "jacamars",
+    "jacelyns",
+    "jacindas",
+    "jacintas",
+    "jacintos",
+    "jackdaws",
+    "jackelyn",
+    "jacketed",
+    "jacklyns",
+    "jacksons",
+    "jacquies",
+    "jacqulyn",
+    "jadelike",
+    "jadishly",
+    "jadwigas",
+    "jaelynns",
+    "jaggedly",
+    "jaggiest",
+    "jailless",
+    "jaillike",
+    "jaleesas",
+    "jaliyahs",
+    "jamarion",
+    "jamesons",
+    "jamisons",
+    "janellas",
+    "janelles",
+    "janessas",
+    "janettas",
+    "janettes",
+    "janieces",
+    "janiform",
+    "janiyahs",
+    "jannette",
+    "januarys",
+    "japingly",
+    "jaquelyn",
+    "jargonal",
+    "jarretts",
+    "jasmined",
+    "jasmines",
+    "jaspered",
+    "jaunitas",
+    "jauntier",
+    "jauntily",
+    "javelina",
+    "javelins",
+    "jawfishs",
+    "jaybirds",
+    "jayceons",
+    "jayleens",
+    "jaylenes",
+    "jaylynns",
+    "jazlynns",
+    "jazmines",
+    "jazziest",
+    "jeanelle",
+    "jeanenes",
+    "jeanetta",
+    "jeanette",
+    "jeanetts",
+    "jeanices",
+    "jeanines",
+    "jeannies",
+    "jeannine",
+    "jedidiah",
+    "jefferey",
+    "jefferys",
+    "jeffreys",
+    "jejunely",
+    "jenelles",
+    "jenettes",
+    "jenifers",
+    "jeniffer",
+    "jennefer",
+    "jennells",
+    "jennette",
+    "jennifer",
+    "jennines",
+    "jeremiah",
+    "jerichos",
+    "jerilyns",
+    "jerkiest",
+    "jerlenes",
+    "jermaine",
+    "jerrells",
+    "jerricas",
+    "jerrolds",
+    "jerseyed",
+    "jesenias",
+    "jessenia",
+    "jessicas",
+    "jessikas",
+    "jestines",
+    "jewfishs",
+    "jibingly",
+    "jiggered",
+    "jillians",
+    "jingoish",
+    "joannies",
+    "joaquina",
+    "joaquins",
+    "jocelynn",
+    "jocelyns",
+    "jocosely",
+    "jocundly",
+    "joellens",
+    "johannas",
+    "johannes",
+    "johnetta",
+    "johnette",
+    "johnnies",
+    "johnsies",
+    "johnsons",
+    "joinable",
+    "jokeless",
+    "jokingly",
+    "jolandas",
+    "jolliest",
+    "joltiest",
+    "joltless",
+    "jonathan",
+    "jonathon",
+    "jonelles",
+    "jordynns",
+    "josefina",
+    "josefine",
+    "joselyns",
+    "josettes",
+    "josphine",
+    "journees",
+    "journeys",
+    "jovannis",
+    "jovially",
+    "jowliest",
+    "joycelyn",
+    "joyfully",
+    "joyously",
+    "juanitas",
+    "jubilant",
+    "judicial",
+    "juiciest",
+    "julianas",
+    "julianes",
+    "julianna",
+    "julianne",
+    "julianns",
+    "julieann",
+    "julienne",
+    "julietas",
+    "julietta",
+    "juliette",
+    "julissas",
+    "jumpable",
+    "jumpiest",
+    "junipers",
+    "juratory",
+    "juristic",
+    "juryless",
+    "justices",
+    "justinas",
+    "justines",
+    "jutelike",
+    "kadences",
+    "kaidence",
+    "kailanis",
+    "kaitlins",
+    "kaitlynn",
+    "kaitlyns",
+    "kaleighs",
+    "kaliyahs",
+    "kamerons",
+    "kamilahs",
+    "kandaces",
+    "kandices",
+    "kaneshas",
+    "kangaroo",
+    "kanishas",
+    "kaolinic",
+    "karissas",
+    "karleens",
+    "karlenes",
+    "karoline",
+    "karolyns",
+    "kasandra",
+    "katalina",
+    "katelins",
+    "katelynn",
+    "katelyns",
+    "katerine",
+    "katharyn",
+    "katherin",
+    "katherns",
+    "katheryn",
+    "kathleen",
+    "kathlene",
+    "kathline",
+    "kathlyns",
+    "kathodic",
+    "kathrine",
+    "kathrins",
+    "kathryne",
+    "kathryns",
+    "kathyrns",
+    "katrices",
+    "katrinas",
+    "katydids",
+    "kaydence",
+    "kayleens",
+    "kayleigh",
+    "kaylenes",
+    "kaylynns",
+    "keelless",
+    "keepable",
+    "keloidal",
+    "kemberly",
+    "kendalls",
+    "kendrick",
+    "kenishas",
+    "kennedis",
+    "kennedys",
+    "kenneths",
+    "kenniths",
+    "kensleys",
+    "kenyatta",
+    "kenyetta",
+    "keratoid",
+    "keratose",
+    "kernelly",
+    "kerstins",
+    "kestrels",
+    "keturahs",
+    "khadijah",
+    "khalilah",
+    "khedival",
+    "kickable",
+    "kickless",
+    "kiersten",
+    "killable",
+    "killdeer",
+    "killians",
+    "kiltlike",
+    "kimberli",
+    "kimberly",
+    "kimberys",
+    "kimonoed",
+    "kindless",
+    "kindlier",
+    "kingbird",
+    "kingfish",
+    "kingless",
+    "kinglets",
+    "kinglier",
+    "kinglike",
+    "kingsley",
+    "kingston",
+    "kinkajou",
+    "kinkiest",
+    "kinsleys",
+    "kirklike",
+    "kirstens",
+    "kirsties",
+    "kirstins",
+    "kissable",
+    "kissably",
+    "kitcheny",
+    "kitelike",
+    "klephtic",
+    "knaggier",
+    "knightly",
+    "knobbier",
+    "knoblike",
+    "knotless",
+    "knotlike",
+    "knottier",
+    "knottily",
+    "knowable",
+    "knurlier",
+    "kookiest",
+    "kortneys",
+    "kourtney",
+    "krishnas",
+    "kristals",
+    "kristans",
+    "kristeen",
+    "kristels",
+    "kristens",
+    "kristian",
+    "kristies",
+    "kristina",
+    "kristine",
+    "kristins",
+    "kristles",
+    "kristyns",
+    "krystals",
+    "krystens",
+    "krystina",
+    "krystins",
+    "krystles",
+    "krystyna",
+    "kyleighs",
+    "kymberly",
+    "kyndalls",
+    "kyphotic",
+    "labially",
+    "laboured",
+    "labrador",
+    "laceless",
+    "lacelike",
+    "lacerant",
+    "lacewing",
+    "lachelle",
+    "lachlans",
+    "lacresha",
+    "lacrimal",
+    "lacteous",
+    "lactonic",
+    "lacunose",
+    "ladonnas",
+    "ladybird",
+    "ladybugs",
+    "ladyless",
+    "ladylike",
+    "lagoonal",
+    "laically",
+    "lajuanas",
+    "lakeesha",
+    "lakeisha",
+    "lakendra",
+    "lakenyas",
+    "lakeshas",
+    "lakeshia",
+    "lakiesha",
+    "lakishas",
+    "lambdoid",
+    "lamblike",
+    "lamellar",
+    "lamented",
+    "laminose",
+    "laminous",
+    "lamonica",
+    "lampless",
+    "lampreys",
+    "lanceted",
+    "landless",
+    "landlike",
+    "lanelles",
+    "lanettes",
+    "langston",
+    "language",
+    "lapelled",
+    "lappeted",
+    "lapsable",
+    "lapsible",
+    "lapwings",
+    "laquanda",
+    "laquitas",
+    "laraines",
+    "lardiest",
+    "lardlike",
+    "largando",
+    "larhonda",
+    "larissas",
+    "larksome",
+    "larondas",
+    "larraine",
+    "lasandra",
+    "lashanda",
+    "lashauns",
+    "lashawna",
+    "lashawns",
+    "lashells",
+    "lashless",
+    "lashonda",
+    "lashunda",
+    "lasonyas",
+    "latanyas",
+    "latarsha",
+    "latashas",
+    "latashia",
+    "latently",
+    "lateshas",
+    "lathiest",
+    "lathlike",
+    "laticias",
+    "latishas",
+    "latonias",
+    "latonyas",
+    "latorias",
+    "latoshas",
+    "latoyias",
+    "latrices",
+    "latricia",
+    "latrinas",
+    "latrisha",
+    "latterly",
+    "latticed",
+    "laudable",
+    "laudably",
+    "lauralee",
+    "laureate",
+    "laureens",
+    "laurenas",
+    "laurence",
+    "laurenes",
+    "lauretta",
+    "laurette",
+    "laurices",
+    "laurinda",
+    "laurines",
+    "lavelles",
+    "lavenias",
+    "lavernas",
+    "lavernes",
+    "lavettes",
+    "lavinias",
+    "lavishly",
+    "lavondas",
+    "lavonias",
+    "lavonnas",
+    "lavonnes",
+    "lawandas",
+    "lawannas",
+    "lawfully",
+    "lawrence",
+    "lawyerly",
+    "leachier",
+    "leadenly",
+    "leadiest",
+    "leadless",
+    "leafiest",
+    "leafless",
+    "leaflike",
+    "leakless",
+    "leandras",
+    "leandros",
+    "leanoras",
+    "learning",
+    "leasable",
+    "leathern",
+    "leathery",
+    "leatrice",
+    "lecithal",
+    "ledgiest",
+    "leeannas",
+    "leeannes",
+    "leeriest",
+    "leftward",
+    "legatine",
+    "leggiest",
+    "leighann",
+    "leighton",
+    "leilanis",
+    "leisured",
+    "lekishas",
+    "lemmings",
+    "lemonish",
+    "lendable",
+    "lensless",
+    "lenslike",
+    "lentando",
+    "leonarda",
+    "leonardo",
+    "leonards",
+    "leonidas",
+    "leonilas",
+    "leonoras",
+    "leonores",
+    "leontine",
+    "leopards",
+    "leopoldo",
+    "lepidote",
+    "leporine",
+    "leprotic",
+    "lethally",
+    "leticias",
+    "letishas",
+    "lettered",
+    "leucemic",
+    "leucitic",
+    "leucotic",
+    "leukemic",
+    "leviable",
+    "libelous",
+    "libertys",
+    "libradas",
+    "lienable",
+    "lifeless",
+    "lifelike",
+    "lifelong",
+    "liftable",
+    "ligative",
+    "lightful",
+    "lightish",
+    "ligneous",
+    "lignitic",
+    "ligulate",
+    "liguloid",
+    "likeable",
+    "likelier",
+    "likewise",
+    "lilianas",
+    "lilianna",
+    "lilliams",
+    "lilliana",
+    "lillians",
+    "lillyana",
+    "lilyanas",
+    "lilyanna",
+    "lilylike",
+    "limacine",
+    "limberly",
+    "limbless",
+    "limeless",
+    "limelike",
+    "limitary",
+    "limnetic",
+    "limpidly",
+    "limpkins",
+    "lincolns",
+    "lindsays",
+    "lindseys",
+    "lineable",
+    "lineally",
+    "linearly",
+    "linebred",
+    "lineless",
+    "linelike",
+    "linettes",
+    "linoleic",
+    "lintiest",
+    "lintless",
+    "linwoods",
+    "lionfish",
+    "lionlike",
+    "lipaemic",
+    "liquidly",
+    "lisabeth",
+    "lisandra",
+    "lisbeths",
+    "lisettes",
+    "lissette",
+    "lissomly",
+    "listless",
+    "literary",
+    "literate",
+    "lithemic",
+    "littlish",
+    "liveable",
+    "livelier",
+    "livelily",
+    "liveried",
+    "liverish",
+    "livingly",
+    "lizabeth",
+    "lizbeths",
+    "lizettes",
+    "lizzette",
+    "loadless",
+    "loamless",
+    "loanable",
+    "loathful",
+    "lobately",
+    "loblolly",
+    "lobsters",
+    "lobulate",
+    "lochlans",
+    "lockable",
+    "lockless",
+    "loculate",
+    "locustal",
+    "loessial",
+    "loftiest",
+    "loftless",
+    "lonelier",
+    "lonelily",
+    "longhorn",
+    "longsome",
+    "longspur",
+    "longtime",
+    "longwall",
+    "looniest",
+    "lopsided",
+    "loraines",
+    "loralees",
+    "lordless",
+    "lordlier",
+    "lordlike",
+    "lordotic",
+    "lorelais",
+    "loreleis",
+    "loreless",
+    "lorenzas",
+    "lorenzos",
+    "lorettas",
+    "lorettes",
+    "lorianns",
+    "loricate",
+    "lorikeet",
+    "lorilees",
+    "lorindas",
+    "lorraine",
+    "lorretta",
+    "lorriane",
+    "lorrines",
+    "losingly",
+    "lothsome",
+    "louannes",
+    "louellas",
+    "louettas",
+    "lousiest",
+    "louvenia",
+    "louvered",
+    "loveable",
+    "loveably",
+    "loveless",
+    "lovelier",
+    "lovelily",
+    "lovellas",
+    "lovelorn",
+    "lovesick",
+    "lovesome",
+    "lovettas",
+    "lovingly",
+    "lowliest",
+    "lozenged",
+    "lubberly",
+    "lucently",
+    "lucianas",
+    "lucianos",
+    "lucienne",
+    "lucillas",
+    "lucilles",
+    "lucindas",
+    "luckiest",
+    "luckless",
+    "lucrecia",
+    "lucretia",
+    "luculent",
+    "ludivina",
+    "lukewarm",
+    "lumberly",
+    "luminous",
+    "lumpiest",
+    "lunately",
+    "lungeous",
+    "lungfish",
+    "lunulate",
+    "luringly",
+    "lurlenes",
+    "lurlines",
+    "luscious",
+    "lushiest",
+    "lustered",
+    "lustiest",
+    "lustrous",
+    "luvenias",
+    "lymphoid",
+    "lyndsays",
+    "lyndseys",
+    "lynelles",
+    "lynettas",
+    "lynettes",
+    "lynnette",
+    "lynwoods",
+    "lynxlike",
+    "lyolytic",
+    "lyrately",
+    "lyrebird",
+    "lyriform",
+    "mabelles",
+    "macaques",
+    "machelle",
+    "mackerel",
+    "madalene",
+    "madaline",
+    "madalynn",
+    "madalyns",
+    "maddison",
+    "madelene",
+    "madeline",
+    "madelynn",
+    "madelyns",
+    "madilynn",
+    "madilyns",
+    "madisons",
+    "madisyns",
+    "madonnas",
+    "madysons",
+    "maenadic",
+    "mafaldas",
+    "magarets",
+    "magaziny",
+    "magdalen",
+    "magmatic",
+    "magnesic",
+    "magnetic",
+    "magnific",
+    "magnolia",
+    "mahalias",
+    "maidenly",
+    "maieutic",
+    "mailable",
+    "mailless",
+    "majestic",
+    "majories",
+    "makaylas",
+    "makeless",
+    "makennas",
+    "makenzie",
+    "malachis",
+    "malacoid",
+    "malakais",
+    "malamute",
+    "malarial",
+    "malarian",
+    "malaysia",
+    "malcolms",
+    "malignly",
+    "malindas",
+    "malissas",
+    "maliyahs",
+    "mallards",
+    "mallorys",
+    "malories",
+    "malvinas",
+    "mammoths",
+    "manakins",
+    "manatees",
+    "manatoid",
+    "mandrill",
+    "maneless",
+    "manfully",
+    "manganic",
+    "mangiest",
+    "maniacal",
+    "manifest",
+    "maniform",
+    "manistic",
+    "manliest",
+    "mannered",
+    "mannerly",
+    "manorial",
+    "manually",
+    "manuelas",
+    "mappable",
+    "maragret",
+    "marandas",
+    "marcelas",
+    "marcella",
+    "marcelle",
+    "marcells",
+    "marcelos",
+    "marcenes",
+    "mardells",
+    "margaret",
+    "margaric",
+    "margarts",
+    "margenes",
+    "margeret",
+    "margerts",
+    "margerys",
+    "marginal",
+    "margorie",
+    "margrets",
+    "margrett",
+    "marianas",
+    "marianna",
+    "marianne",
+    "marianns",
+    "marianos",
+    "maribels",
+    "maribeth",
+    "maricela",
+    "maricruz",
+    "marielas",
+    "mariella",
+    "marielle",
+    "marietta",
+    "mariette",
+    "marilees",
+    "marilous",
+    "marilynn",
+    "marilyns",
+    "marindas",
+    "marisela",
+    "marishas",
+    "marisols",
+    "marissas",
+    "maritime",
+    "maritzas",
+    "marivels",
+    "mariyahs",
+    "marjorie",
+    "marjorys",
+    "markedly",
+    "marketta",
+    "markhors",
+    "markitas",
+    "marlanas",
+    "marleens",
+    "marleigh",
+    "marlenas",
+    "marlenes",
+    "marlines",
+    "marlitic",
+    "marmoset",
+    "marquita",
+    "marriage",
+    "marshall",
+    "marshier",
+    "martinas",
+    "martines",
+    "martyrly",
+    "marvella",
+    "maryanna",
+    "maryanne",
+    "maryanns",
+    "marybeth",
+    "maryetta",
+    "maryjane",
+    "maryland",
+    "marylees",
+    "marylins",
+    "marylous",
+    "marylynn",
+    "marylyns",
+    "maryrose",
+    "masklike",
+    "masterly",
+    "mastiffs",
+    "mastless",
+    "mastlike",
+    "mastodon",
+    "material",
+    "maternal",
+    "mathilda",
+    "mathilde",
+    "matildas",
+    "matildes",
+    "matronal",
+    "matronly",
+    "mattedly",
+    "matthews",
+    "matthias",
+    "maturely",
+    "maturing",
+    "maureens",
+    "maurices",
+    "mauricio",
+    "maurines",
+    "mauritas",
+    "maverick",
+    "maximina",
+    "maxwells",
+    "maybelle",
+    "maybells",
+    "maynards",
+    "mazelike",
+    "mckaylas",
+    "mckennas",
+    "mckenzie",
+    "mckinley",
+    "meagerly",
+    "meaghans",
+    "meagrely",
+    "mealiest",
+    "mealless",
+    "mealworm",
+    "measlier",
+    "measured",
+    "meatiest",
+    "mechanic",
+    "mechelle",
+    "medallic",
+    "medially",
+    "medianly",
+    "medieval",
+    "mediocre",
+    "meerkats",
+    "megadont",
+    "meghanns",
+    "melaines",
+    "melanias",
+    "melanies",
+    "melanoid",
+    "melanous",
+    "melindas",
+    "melissas",
+    "melissia",
+    "mellisas",
+    "mellissa",
+    "mellowly",
+    "melodees",
+    "melodies",
+    "melonies",
+    "meltable",
+    "melvinas",
+    "melyndas",
+    "memoried",
+    "mendable",
+    "menhaden",
+    "menially",
+    "menseful",
+    "mensural",
+    "mentally",
+    "mephitic",
+    "mercapto",
+    "mercedes",
+    "mercedez",
+    "merciful",
+    "mercuric",
+    "meredith",
+    "merideth",
+    "meridith",
+    "merilyns",
+    "merissas",
+    "meristic",
+    "merlenes",
+    "merriest",
+    "merrilee",
+    "merrills",
+    "mesially",
+    "mesodont",
+    "messiahs",
+    "messiest",
+    "metalled",
+    "metallic",
+    "metazoal",
+    "metazoic",
+    "meteoric",
+    "methenyl",
+    "methylic",
+    "metrical",
+    "meuniare",
+    "micaelas",
+    "micellar",
+    "michaela",
+    "michaele",
+    "michaels",
+    "michales",
+    "micheals",
+    "micheles",
+    "michelle",
+    "michells",
+    "michikos",
+    "microbic",
+    "midnight",
+    "miffiest",
+    "migdalia",
+    "mightier",
+    "mightily",
+    "mikaelas",
+    "mikaylas",
+    "milagros",
+    "milanias",
+    "mildreds",
+    "milissas",
+    "milkiest",
+    "milkless",
+    "millards",
+    "milliary",
+    "minatory",
+    "mindless",
+    "mineable",
+    "minervas",
+    "minhagic",
+    "minivets",
+    "minutely",
+    "minutial",
+    "miracles",
+    "mirandas",
+    "mireille",
+    "mirellas",
+    "mirkiest",
+    "mirthful",
+    "misapply",
+    "miscible",
+    "misproud",
+    "mistaken",
+    "mistiest",
+    "mistyped",
+    "mitchell",
+    "mitchels",
+    "mitsukos",
+    "moccasin",
+    "modernly",
+    "modestas",
+    "modestly",
+    "modestos",
+    "modiolar",
+    "modishly",
+    "moistful",
+    "moitiest",
+    "moldable",
+    "mollusks",
+    "moltenly",
+    "molybdic",
+    "momently",
+    "monarchs",
+    "monaural",
+    "monaxial",
+    "monetary",
+    "mongoose",
+    "mongrels",
+    "monilial",
+    "moniques",
+    "monistic",
+    "monitors",
+    "monkfish",
+    "monocled",
+    "monopoly",
+    "monsters",
+    "moodiest",
+    "mooneyes",
+    "moonfish",
+    "moonless",
+    "moorhens",
+    "mopingly",
+    "mopishly",
+    "morainal",
+    "morainic",
+    "morbidly",
+    "morbific",
+    "morganic",
+    "moribund",
+    "morosely",
+    "mortally",
+    "moschate",
+    "mosquito",
+    "mossiest",
+    "motherly",
+    "mothiest",
+    "motional",
+    "mouflons",
+    "mouldier",
+    "mountain",
+    "mournful",
+    "mousiest",
+    "mouthier",
+    "mouthily",
+    "moveably",
+    "moveless",
+    "movingly",
+    "mowburnt",
+    "mozellas",
+    "mozelles",
+    "muckiest",
+    "muddiest",
+    "mudfishs",
+    "muggiest",
+    "mulishly",
+    "mullocky",
+    "multifid",
+    "multiply",
+    "muriatic",
+    "muricate",
+    "murrelet",
+    "muscular",
+    "mushiest",
+    "mushroom",
+    "musingly",
+    "muskiest",
+    "muskrats",
+    "mussiest",
+    "mustafas",
+    "mustangs",
+    "mustiest",
+    "muticous",
+    "mutinous",
+    "mutually",
+    "mycelial",
+    "myelinic",
+    "myogenic",
+    "myologic",
+    "myriadly",
+    "myrtices",
+    "mystical",
+    "mysticly",
+    "mythical",
+    "nabobish",
+    "nacreous",
+    "naggiest",
+    "nailless",
+    "naillike",
+    "naissant",
+    "nakeshas",
+    "nakishas",
+    "nameless",
+    "nanettes",
+    "nannette",
+    "napiform",
+    "napoleon",
+    "nappiest",
+    "narcisas",
+    "narrowly",
+    "narwhals",
+    "nastiest",
+    "natachas",
+    "natalees",
+    "natalias",
+    "natalies",
+    "natalyas",
+    "natantly",
+    "natashas",
+    "natashia",
+    "natatory",
+    "nathalie",
+    "nathalys",
+    "national",
+    "natishas",
+    "natively",
+    "natoshas",
+    "nattiest",
+    "nauplial",
+    "nauseous",
+    "nautical",
+    "nebulose",
+    "nebulous",
+    "neckless",
+    "necklike",
+    "necrotic",
+    "neediest",
+    "needless",
+    "negative",
+    "nehemiah",
+    "nektonic",
+    "neologic",
+    "neonatal",
+    "nereidas",
+    "nerissas",
+    "nescient",
+    "nestable",
+    "nestlike",
+    "nettable",
+    "neumatic",
+    "neurally",
+    "neuritic",
+    "neuronic",
+    "nevilles",
+    "newsiest",
+    "newsless",
+    "nichelle",
+    "nicholas",
+    "nicholes",
+    "nicholle",
+    "nickelic",
+    "nickolas",
+    "nickoles",
+    "nicolasa",
+    "nicolles",
+    "niftiest",
+    "niggling",
+    "nightjar",
+    "nikolais",
+    "nimbused",
+    "ninnyish",
+    "nirvanic",
+    "nitrolic",
+    "nittiest",
+    "nobbiest",
+    "noisiest",
+    "nomistic",
+    "nonacute",
+    "nonadept",
+    "nonbasic",
+    "nonclose",
+    "noneager",
+    "nonempty",
+    "nonethic",
+    "nonfatal",
+    "nonflaky",
+    "nonfused",
+    "nongreen",
+    "nonhuman",
+    "nonideal",
+    "noninert",
+    "nonionic",
+    "nonirate",
+    "nonlegal",
+    "nonlevel",
+    "nonloyal",
+    "nonlucid",
+    "nonlyric",
+    "nonmodal",
+    "nonmoral",
+    "nonnatty",
+    "nonnaval",
+    "nonoptic",
+    "nonpapal",
+    "nonparty",
+    "nonpenal",
+    "nonrated",
+    "nonrigid",
+    "nonround",
+    "nonroyal",
+    "nonrural",
+    "nonsober",
+    "nonsolar",
+    "nonspill",
+    "nonspiny",
+    "nonstick",
+    "nontelic",
+    "nontidal",
+    "nontoned",
+    "nontonic",
+    "nontoxic",
+    "nontuned",
+    "nonurban",
+    "nonusing",
+    "nonvalid",
+    "nonvenal",
+    "nonvital",
+    "nonwoody",
+    "nonwoven",
+    "nonzonal",
+    "nooklike",
+    "norberto",
+    "norberts",
+    "normally",
+    "normands",
+    "notarial",
+    "noteless",
+    "notional",
+    "noumenal",
+    "nounally",
+    "novellas",
+    "novercal",
+    "nubblier",
+    "nubilous",
+    "nuciform",
+    "nudicaul",
+    "nugatory",
+    "numerary",
+    "numerous",
+    "numinous",
+    "nummular",
+    "nunnated",
+    "nurtured",
+    "nutbrown",
+    "nuthatch",
+    "nymphean",
+    "oafishly",
+    "oarfishs",
+    "obdulias",
+    "obdurate",
+    "obedient",
+    "obeisant",
+    "obeyable",
+    "oblately",
+    "oblatory",
+    "obliging",
+    "oblongly",
+    "obsolete",
+    "obtusely",
+    "obvolute",
+    "occlusal",
+    "occultly",
+    "ocherous",
+    "ochreous",
+    "octantal",
+    "octavias",
+    "octavios",
+    "ocularly",
+    "odiously",
+    "odontoid",
+    "odorless",
+    "odourful",
+    "oenochoe",
+    "oestrous",
+    "offishly",
+    "ogrishly",
+    "oilbirds",
+    "oilfired",
+    "oiltight",
+    "oldwifes",
+    "olefinic",
+    "olimpias",
+    "olympias",
+    "omissive",
+    "ommateal",
+    "onwardly",
+    "oogamous",
+    "oophoric",
+    "oophytic",
+    "oosporic",
+    "opaquely",
+    "operable",
+    "operably",
+    "operatic",
+    "ophelias",
+    "opossums",
+    "opsonoid",
+    "optional",
+    "oracular",
+    "orchitic",
+    "ordinary",
+    "ordurous",
+    "orgastic",
+    "orgulous",
+    "oriented",
+    "original",
+    "orlandos",
+    "ornately",
+    "ornerily",
+    "ornithic",
+    "orogenic",
+    "orthodox",
+    "orvilles",
+    "oscitant",
+    "osculant",
+    "ossified",
+    "osteitic",
+    "ostiolar",
+    "ostrichs",
+    "osvaldos",
+    "oswaldos",
+    "otiosely",
+    "ouphoric",
+    "ouphytic",
+    "ousporic",
+    "outbound",
+    "outbully",
+    "outdated",
+    "outgoing",
+    "outlying",
+    "outmoded",
+    "outwards",
+    "ovenbird",
+    "ovenlike",
+    "overable",
+    "overably",
+    "overbold",
+    "overbusy",
+    "overcold",
+    "overcool",
+    "overdear",
+    "overdeep",
+    "overeasy",
+    "overfast",
+    "overflat",
+    "overfond",
+    "overfoul",
+    "overfree",
+    "overglad",
+    "overhard",
+    "overhead",
+    "overhigh",
+    "overholy",
+    "overhuge",
+    "overidle",
+    "overidly",
+    "overkeen",
+    "overkind",
+    "overlate",
+    "overlewd",
+    "overloud",
+    "overlush",
+    "overmany",
+    "overmean",
+    "overmeek",
+    "overmild",
+    "overmuch",
+    "overneat",
+    "overnice",
+    "overrash",
+    "overrich",
+    "overrife",
+    "overrude",
+    "overshot",
+    "overslow",
+    "oversoft",
+    "oversour",
+    "oversure",
+    "overtame",
+    "overtart",
+    "overthin",
+    "overtrue",
+    "overwary",
+    "overweak",
+    "overwide",
+    "overwild",
+    "overwily",
+    "owlishly",
+    "oxidable",
+    "oxidasic",
+    "oxpecker",
+    "oxydasic",
+    "oxygenic",
+    "packable",
+    "painedly",
+    "painless",
+    "paislees",
+    "paisleys",
+    "pajamaed",
+    "palatial",
+    "palladic",
+    "pallidly",
+    "palmiest",
+    "palmiras",
+    "palmitic",
+    "palpable",
+    "palpably",
+    "paltrier",
+    "paltrily",
+    "pamelias",
+    "pamellas",
+    "pandoras",
+    "paneless",
+    "pangolin",
+    "panicled",
+    "panoptic",
+    "panthers",
+    "pantonal",
+    "panurgic",
+    "papillar",
+    "papillon",
+    "papistly",
+    "papulose",
+    "parakeet",
+    "parallel",
+    "paravail",
+    "paraxial",
+    "parental",
+    "parklike",
+    "parsable",
+    "parsonic",
+    "partible",
+    "particia",
+    "pasquale",
+    "pastiest",
+    "pastorly",
+    "pastural",
+    "patchier",
+    "patchily",
+    "patellar",
+    "patently",
+    "paternal",
+    "pathless",
+    "patience",
+    "patricas",
+    "patrices",
+    "patricia",
+    "patricks",
+    "patrinas",
+    "patronal",
+    "patronly",
+    "patterny",
+    "patulous",
+    "paulenes",
+    "pauletta",
+    "paulette",
+    "paulinas",
+    "paulines",
+    "paulitas",
+    "pauseful",
+    "pavonine",
+    "pawnable",
+    "peaceful",
+    "peachier",
+    "peacocks",
+    "peafowls",
+    "peakless",
+    "peaklike",
+    "pearlene",
+    "pearlier",
+    "pearlies",
+    "pearline",
+    "peatiest",
+    "peccable",
+    "peccarys",
+    "peckiest",
+    "pedantic",
+    "pedately",
+    "pediform",
+    "peelable",
+    "peerless",
+    "peevedly",
+    "pelagial",
+    "pelicans",
+    "pellucid",
+    "pendente",
+    "penelope",
+    "penguins",
+    "pennoned",
+    "pentomic",
+    "peperine",
+    "peplosed",
+    "peppiest",
+    "peptonic",
+    "peridial",
+    "perigeal",
+    "perigean",
+    "perilous",
+    "perineal",
+    "periodic",
+    "periotic",
+    "perished",
+    "perjured",
+    "perkiest",
+    "perlitic",
+    "permeant",
+    "peroneal",
+    "perspiry",
+    "perverse",
+    "pervious",
+    "peskiest",
+    "petaline",
+    "petalled",
+    "petaloid",
+    "petalous",
+    "petiolar",
+    "petrinas",
+    "petrolic",
+    "petrosal",
+    "pettedly",
+    "pettiest",
+    "petulant",
+    "pheasant",
+    "phenetic",
+    "phialine",
+    "phillips",
+    "phimotic",
+    "phleboid",
+    "phoenixs",
+    "phonetic",
+    "phoniest",
+    "photopic",
+    "phreatic",
+    "phthalic",
+    "phyletic",
+    "phylicia",
+    "phylloid",
+    "phymatic",
+    "physical",
+    "piacular",
+    "piazzaed",
+    "piazzian",
+    "pickerel",
+    "pickiest",
+    "picrated",
+    "piddling",
+    "piercing",
+    "pigfishs",
+    "pikelike",
+    "pilchard",
+    "pileated",
+    "piliform",
+    "pillared",
+    "pindling",
+    "pinelike",
+    "pinniped",
+    "pinnular",
+    "pinscher",
+    "pintails",
+    "pipefish",
+    "pipeless",
+    "pipelike",
+    "pipingly",
+    "piranhas",
+    "pitchier",
+    "pitchily",
+    "pitiable",
+    "pitiably",
+    "pitiless",
+    "placable",
+    "placably",
+    "placidly",
+    "plaguily",
+    "plangent",
+    "planular",
+    "plashier",
+    "plastery",
+    "plastics",
+    "platiest",
+    "platinic",
+    "platypus",
+    "plausive",
+    "playable",
+    "pleasant",
+    "pleasing",
+    "pliantly",
+    "plotless",
+    "plowable",
+    "pluckier",
+    "pluckily",
+    "plugless",
+    "pluglike",
+    "plugugly",
+    "plumaged",
+    "plumbous",
+    "plumiest",
+    "plumlike",
+    "plummier",
+    "plumular",
+    "plurally",
+    "plushest",
+    "plushily",
+    "plutonic",
+    "pluvious",
+    "plyingly",
+    "poaceous",
+    "poachers",
+    "poachier",
+    "pochards",
+    "pockiest",
+    "podgiest",
+    "podsolic",
+    "podzolic",
+    "poetless",
+    "poetlike",
+    "poignant",
+    "pointers",
+    "poisedly",
+    "pokingly",
+    "polecats",
+    "polished",
+    "politely",
+    "pollable",
+    "pollened",
+    "pollinic",
+    "polliwog",
+    "pollocks",
+    "polluted",
+    "polypoid",
+    "polypous",
+    "polyzoic",
+    "pomfrets",
+    "pompanos",
+    "ponchoed",
+    "pontific",
+    "popeless",
+    "popelike",
+    "popishly",
+    "poplared",
+    "populous",
+    "porelike",
+    "porfirio",
+    "poriform",
+    "porkiest",
+    "porously",
+    "porpoise",
+    "porsches",
+    "portably",
+    "portaled",
+    "portless",
+    "portlier",
+    "posingly",
+    "position",
+    "positive",
+    "possible",
+    "possibly",
+    "postally",
+    "postlike",
+    "postoral",
+    "postural",
+    "potatory",
+    "potbelly",
+    "potentae",
+    "potently",
+    "potoroos",
+    "pottiest",
+    "pourable",
+    "powerful",
+    "practice",
+    "praedial",
+    "prandial",
+    "preadult",
+    "prealtar",
+    "preapply",
+    "preaxial",
+    "prebasal",
+    "prebrute",
+    "precious",
+    "prefixal",
+    "preflood",
+    "preggers",
+    "pregnant",
+    "prehuman",
+    "prelatic",
+    "prelegal",
+    "premoral",
+    "premorse",
+    "prenasal",
+    "prenaval",
+    "preoptic",
+    "prepared",
+    "prepense",
+    "prepious",
+    "preppily",
+    "preradio",
+    "preready",
+    "preregal",
+    "prerenal",
+    "preroyal",
+    "prescout",
+    "presleys",
+    "presolar",
+    "prestons",
+    "pretonic",
+    "prettied",
+    "prettier",
+    "prettily",
+    "preunion",
+    "prevalid",
+    "previous",
+    "pricilla",
+    "prideful",
+    "priestly",
+    "primates",
+    "primeval",
+    "princely",
+    "princess",
+    "priority",
+    "priscila",
+    "prissily",
+    "pristine",
+    "priviest",
+    "proalien",
+    "probable",
+    "probably",
+    "procivic",
+    "proemial",
+    "profound",
+    "prolabor",
+    "prolific",
+    "prolixly",
+    "promoted",
+    "promptly",
+    "pronaval",
+    "propenyl",
+    "properly",
+    "property",
+    "propless",
+    "propylic",
+    "prorebel",
+    "proslave",
+    "prosodic",
+    "protonic",
+    "protozoa",
+    "protrade",
+    "prounion",
+    "provable",
+    "provably",
+    "provenly",
+    "proximal",
+    "prudence",
+    "pruinose",
+    "prunable",
+    "prurient",
+    "pruritic",
+    "pryingly",
+    "psammead",
+    "psilotic",
+    "publicly",
+    "puffiest",
+    "puissant",
+    "pulingly",
+    "pulpally",
+    "pulpiest",
+    "pulpital",
+    "pulpless",
+    "pulplike",
+    "punchier",
+    "punchily",
+    "punctate",
+    "punctual",
+    "punditic",
+    "punitive",
+    "puppyish",
+    "purblind",
+    "purchase",
+    "purified",
+    "puriform",
+    "puristic",
+    "purplish",
+    "purpuric",
+    "pursiest",
+    "pursuant",
+    "purulent",
+    "puruloid",
+    "pushiest",
+    "pustular",
+    "pustuled",
+    "putative",
+    "putridly",
+    "pyelitic",
+    "pygmyish",
+    "pyogenic",
+    "pyriform",
+    "pyrrolic",
+    "quaggier",
+    "quaintly",
+    "quakiest",
+    "qualmish",
+    "queanish",
+    "queasier",
+    "queasily",
+    "queenies",
+    "quentins",
+    "quercine",
+    "question",
+    "quetzals",
+    "quinsied",
+    "quintins",
+    "quintons",
+    "quippish",
+    "quirkily",
+    "quixotic",
+    "quotable",
+    "quotably",
+    "raccoons",
+    "racemose",
+    "racemous",
+    "rachaels",
+    "racheals",
+    "racheles",
+    "rachelle",
+    "rachells",
+    "rachitic",
+    "racially",
+    "racquels",
+    "radiable",
+    "radiably",
+    "radially",
+    "radiatus",
+    "radicant",
+    "raelenes",
+    "raelynns",
+    "rafaelas",
+    "raggedly",
+    "ragingly",
+    "ragtimey",
+    "rainbowy",
+    "rainiest",
+    "rainless",
+    "raisable",
+    "rakishly",
+    "raleighs",
+    "ramiform",
+    "ramonita",
+    "ramosely",
+    "ramulose",
+    "rancidly",
+    "randalls",
+    "randells",
+    "randolph",
+    "randomly",
+    "rangiest",
+    "rankless",
+    "raphaels",
+    "rapiered",
+    "rarefied",
+    "rascally",
+    "rasheeda",
+    "rashidas",
+    "rashlike",
+    "rasorial",
+    "raspiest",
+    "rateable",
+    "rateably",
+    "rational",
+    "rattails",
+    "rattiest",
+    "rattlers",
+    "ravening",
+    "ravenous",
+    "ravingly",
+    "rawboned",
+    "rayfords",
+    "raylenes",
+    "raymonde",
+    "raymonds",
+    "raymundo",
+    "reactive",
+    "readable",
+    "readably",
+    "readerly",
+    "reapable",
+    "rearmost",
+    "reasoned",
+    "rebbecas",
+    "rebbecca",
+    "rebeccas",
+    "rebeckas",
+    "rebekahs",
+    "recently",
+    "recherch",
+    "reckless",
+    "rectally",
+    "redbirds",
+    "redfishs",
+    "redheads",
+    "redolent",
+    "redpolls",
+    "redshank",
+    "redstart",
+    "reedbuck",
+    "reediest",
+    "reflexly",
+    "refluent",
+    "reformed",
+    "refugias",
+    "refugios",
+    "regenias",
+    "reginald",
+    "reginias",
+    "regional",
+    "reguline",
+    "reinaldo",
+    "reindeer",
+    "relative",
+    "relaxing",
+    "relevant",
+    "reliable",
+    "reliably",
+    "relieved",
+    "relished",
+    "relucent",
+    "remanent",
+    "remedial",
+    "remedios",
+    "remember",
+    "remiform",
+    "remigial",
+    "remissly",
+    "remotely",
+    "remotest",
+    "renaldos",
+    "rendible",
+    "renettas",
+    "renewing",
+    "reniform",
+    "renitent",
+    "renowned",
+    "rentable",
+    "renversa",
+    "repandly",
+    "repeated",
+    "repoussa",
+    "reptiles",
+    "resemble",
+    "reserved",
+    "resigned",
+    "resinous",
+    "resolute",
+    "resolved",
+    "resonant",
+    "resource",
+    "response",
+    "restless",
+    "resupine",
+    "resupply",
+    "retarded",
+    "reticent",
+    "retiform",
+    "retinued",
+    "retiring",
+    "retrally",
+    "retrorse",
+    "retrouss",
+    "reusable",
+    "revenual",
+    "revenued",
+    "reverent",
+    "revisory",
+    "revolute",
+    "rewarded",
+    "reynalda",
+    "reynaldo",
+    "rhematic",
+    "rheumily",
+    "rhiannon",
+    "ribaldly",
+    "ricardas",
+    "ricardos",
+    "richards",
+    "richelle",
+    "ridgiest",
+    "riftless",
+    "rightful",
+    "rightish",
+    "rigorous",
+    "rihannas",
+    "rimosely",
+    "rindless",
+    "ringless",
+    "ringlike",
+    "ringtail",
+    "riskiest",
+    "riskless",
+    "risquely",
+    "riteless",
+    "ritually",
+    "ritziest",
+    "riverine",
+    "roadless",
+    "robeless",
+    "robertas",
+    "robertos",
+    "robustly",
+    "rochelle",
+    "rochells",
+    "rockable",
+    "rockered",
+    "rockfish",
+    "rockiest",
+    "rockling",
+    "roderick",
+    "rodolfos",
+    "rodricks",
+    "rodrigos",
+    "rogatory",
+    "rogelios",
+    "roiliest",
+    "rolandas",
+    "rolandes",
+    "rolandos",
+    "rollable",
+    "rollands",
+    "romaines",
+    "romantic",
+    "romelias",
+    "roofless",
+    "rooflike",
+    "rookiest",
+    "roomiest",
+    "roosters",
+    "rootless",
+    "rootlike",
+    "ropeable",
+    "rosalbas",
+    "rosalees",
+    "rosalias",
+    "rosalies",
+    "rosalina",
+    "rosalind",
+    "rosaline",
+    "rosalvas",
+    "rosalyns",
+    "rosamond",
+    "rosannas",
+    "rosannes",
+    "rosarias",
+    "rosarios",
+    "rosauras",
+    "roseanna",
+    "roseanne",
+    "roseanns",
+    "roselees",
+    "roseless",
+    "roselias",
+    "roselike",
+    "roseline",
+    "rosellas",
+    "roselles",
+    "roselyns",
+    "rosemary",
+    "rosendas",
+    "rosendos",
+    "roseolar",
+    "rosettas",
+    "rosettes",
+    "rossanas",
+    "rotative",
+    "rotatory",
+    "rotproof",
+    "rottenly",
+    "rotundly",
+    "roughish",
+    "roundish",
+    "rovingly",
+    "rowdyish",
+    "roxannas",
+    "roxannes",
+    "rozannes",
+    "rozellas",
+    "rubblier",
+    "rubeolar",
+    "rubicund",
+    "ruddiest",
+    "rudolphs",
+    "ruefully",
+    "rufflike",
+    "ruggedly",
+    "rugosely",
+    "rugulose",
+    "ruinable",
+    "rumpless",
+    "runelike",
+    "runniest",
+    "runtiest",
+    "rushiest",
+    "russells",
+    "rusticly",
+    "rustiest",
+    "ruthanne",
+    "ruthanns",
+    "ruthenic",
+    "ruthless",
+    "rutilant",
+    "ruttiest",
+    "ryleighs",
+    "sabrinas",
+    "sabulous",
+    "saccular",
+    "sachemic",
+    "sachikos",
+    "sacklike",
+    "sacredly",
+    "sadistic",
+    "sagittal",
+    "sailfish",
+    "sailorly",
+    "salaried",
+    "saleable",
+    "saleably",
+    "sallowly",
+    "saltless",
+    "salutary",
+    "salvable",
+    "salvably",
+    "salvador",
+    "samantha",
+    "samathas",
+    "samellas",
+    "samiyahs",
+    "sanative",
+    "sanatory",
+    "sandfish",
+    "sandiest",
+    "sandless",
+    "sandlike",
+    "sanfords",
+    "sanitary",
+    "saniyahs",
+    "sanjuana",
+    "santanas",
+    "santiago",
+    "santinas",
+    "santinos",
+    "saporous",
+    "sappiest",
+    "sapremic",
+    "sardines",
+    "sardonic",
+    "sarkless",
+    "satiable",
+    "satiably",
+    "sauciest",
+    "sauncier",
+    "saundras",
+    "savagely",
+    "savannah",
+    "savannas",
+    "saveable",
+    "savingly",
+    "savorier",
+    "savorily",
+    "savorous",
+    "sawdusty",
+    "sawfishs",
+    "scabbier",
+    "scabbily",
+    "scablike",
+    "scabrous",
+    "scalable",
+    "scalably",
+    "scaliest",
+    "scallops",
+    "scampish",
+    "scandent",
+    "scantier",
+    "scantily",
+    "scaphoid",
+    "scarcely",
+    "scarcest",
+    "scaredly",
+    "scarious",
+    "scarless",
+    "scarlets",
+    "scarlett",
+    "sceptral",
+    "schmalzy",
+    "scissile",
+    "scissors",
+    "sciuroid",
+    "scleroid",
+    "sclerous",
+    "scornful",
+    "scorpion",
+    "scotopic",
+    "scotties",
+    "scowlful",
+    "scrabbly",
+    "scraggly",
+    "scrannel",
+    "scratchy",
+    "screamer",
+    "scribbly",
+    "sculpins",
+    "scurrile",
+    "scurvily",
+    "scyphate",
+    "seaborne",
+    "seagoing",
+    "seagulls",
+    "seahorse",
+    "sealable",
+    "seallike",
+    "seamanly",
+    "seamiest",
+    "seamless",
+    "seasnail",
+    "seasonal",
+    "seasoned",
+    "seatless",
+    "sebrinas",
+    "secantly",
+    "secluded",
+    "secondly",
+    "secretly",
+    "sectoral",
+    "secundly",
+    "securely",
+    "security",
+    "sedately",
+    "seducive",
+    "sedulous",
+    "seediest",
+    "seedless",
+    "seedlike",
+    "seemlier",
+    "segreant",
+    "seisable",
+    "seizable",
+    "seldomly",
+    "selected",
+    "selectly",
+    "selenous",
+    "selfless",
+    "selfsame",
+    "semantic",
+    "semiacid",
+    "semiarid",
+    "semibald",
+    "semideaf",
+    "semiepic",
+    "semifine",
+    "semihard",
+    "semimild",
+    "semiopen",
+    "semiotic",
+    "semioval",
+    "semirare",
+    "semiwild",
+    "senaidas",
+    "sendable",
+    "senilely",
+    "sensible",
+    "sensibly",
+    "sensuous",
+    "sentence",
+    "sepalled",
+    "sepaloid",
+    "septimal",
+    "sequined",
+    "serafina",
+    "seraphic",
+    "serenely",
+    "serenity",
+    "serflike",
+    "serially",
+    "sericate",
+    "seriemas",
+    "servable",
+    "sesamoid",
+    "setiform",
+    "setsukos",
+    "settling",
+    "setulose",
+    "severely",
+    "severest",
+    "seymours",
+    "shabbier",
+    "shabbily",
+    "shadeful",
+    "shadowed",
+    "shaggier",
+    "shaggily",
+    "shaglike",
+    "shakable",
+    "shakenly",
+    "shakiest",
+    "shakiras",
+    "shakitas",
+    "shalanda",
+    "shalonda",
+    "shamably",
+    "shamanic",
+    "shameful",
+    "shamekas",
+    "shamikas",
+    "shandras",
+    "shanekas",
+    "shanelle",
+    "shanells",
+    "shanices",
+    "shanikas",
+    "shaniqua",
+    "shanitas",
+    "shannans",
+    "shannons",
+    "shantaes",
+    "shantays",
+    "shantell",
+    "shantels",
+    "shapable",
+    "shaquana",
+    "shaquita",
+    "sharable",
+    "sharells",
+    "sharices",
+    "sharikas",
+    "sharilyn",
+    "sharitas",
+    "sharleen",
+    "sharlene",
+    "sharolyn",
+    "sharonda",
+    "sharrons",
+    "shastrik",
+    "shaundas",
+    "shaunnas",
+    "shauntas",
+    "shauntes",
+    "shavable",
+    "shavonda",
+    "shavonne",
+    "shawanas",
+    "shawanda",
+    "shawanna",
+    "shawndas",
+    "shawnees",
+    "shawnnas",
+    "shawntas",
+    "shedable",
+    "shedlike",
+    "sheenier",
+    "sheepdog",
+    "sheepish",
+    "sheilahs",
+    "sheldons",
+    "shelduck",
+    "shelleys",
+    "shellier",
+    "shellies",
+    "sheltons",
+    "shemekas",
+    "shemikas",
+    "shenikas",
+    "shenitas",
+    "shepherd",
+    "sherells",
+    "sherices",
+    "sheridan",
+    "sherikas",
+    "sherills",
+    "sherilyn",
+    "sherises",
+    "sheritas",
+    "sherlene",
+    "sherleys",
+    "sherlyns",
+    "shermans",
+    "sherrell",
+    "sherries",
+    "sherrill",
+    "sherrils",
+    "sherrons",
+    "sherryls",
+    "sherwood",
+    "sherylls",
+    "shiftier",
+    "shiftily",
+    "shimmery",
+    "shiniest",
+    "shipless",
+    "shirelys",
+    "shirleen",
+    "shirlees",
+    "shirlene",
+    "shirleys",
+    "shizukos",
+    "shocking",
+    "shoddily",
+    "shoebill",
+    "shoeless",
+    "shogunal",
+    "shondras",
+    "shopworn",
+    "shortish",
+    "shoshana",
+    "shoulder",
+    "shoveler",
+    "showiest",
+    "shrewdly",
+    "shrewish",
+    "shrieval",
+    "sibyllic",
+    "sickerly",
+    "sicklied",
+    "sicklier",
+    "sicklily",
+    "sideless",
+    "sidereal",
+    "sideways",
+    "sighless",
+    "sighlike",
+    "signally",
+    "silently",
+    "silkiest",
+    "silklike",
+    "silkworm",
+    "siltiest",
+    "silvanas",
+    "silverly",
+    "simonnes",
+    "simulant",
+    "sinceres",
+    "sinfully",
+    "singable",
+    "singular",
+    "sinister",
+    "siobhans",
+    "siphonal",
+    "siphonic",
+    "sireless",
+    "sissyish",
+    "sisterly",
+    "sistroid",
+    "sixpenny",
+    "sizeable",
+    "sizeably",
+    "skillful",
+    "skimmers",
+    "skimpily",
+    "skinking",
+    "skinless",
+    "skinlike",
+    "skinnier",
+    "skittish",
+    "skyborne",
+    "skylarks",
+    "slabbery",
+    "slaggier",
+    "slakable",
+    "slangier",
+    "slangily",
+    "slatiest",
+    "sleazier",
+    "sleazily",
+    "sledlike",
+    "sleekier",
+    "sleepers",
+    "sleepful",
+    "sleepier",
+    "sleepily",
+    "sleetier",
+    "slidable",
+    "slightly",
+    "slimiest",
+    "slimline",
+    "slimming",
+    "slinkier",
+    "slinkily",
+    "slipless",
+    "slippery",
+    "slippier",
+    "slippily",
+    "slipshod",
+    "slithery",
+    "slitless",
+    "slitlike",
+    "slobbery",
+    "sloppier",
+    "sloppily",
+    "sloshier",
+    "sloshily",
+    "slothful",
+    "slovenly",
+    "sludgier",
+    "sluggish",
+    "sluglike",
+    "slummier",
+    "slushier",
+    "slushily",
+    "smallish",
+    "smarmily",
+    "smartish",
+    "smashing",
+    "smearier",
+    "smellier",
+    "smirkily",
+    "smokiest",
+    "smoothly",
+    "smothery",
+    "smudgily",
+    "smuggest",
+    "snaglike",
+    "snakiest",
+    "snapless",
+    "snappers",
+    "snappier",
+    "snappily",
+    "snarkily",
+    "snazzier",
+    "sneakier",
+    "sneakily",
+    "sneaking",
+    "sneerful",
+    "sniffier",
+    "sniffily",
+    "sniffish",
+    "snippier",
+    "snippily",
+    "snobbily",
+    "snobbish",
+    "snoopier",
+    "snoopily",
+    "snootier",
+    "snootily",
+    "snottily",
+    "snowiest",
+    "snowless",
+    "snowlike",
+    "snubbier",
+    "snuffier",
+    "snuffily",
+    "snugging",
+    "soapiest",
+    "soapless",
+    "soaplike",
+    "soarable",
+    "sociable",
+    "sociably",
+    "socially",
+    "societal",
+    "sockless",
+    "socorros",
+    "sodaless",
+    "soddenly",
+    "solanges",
+    "soledads",
+    "soleless",
+    "solemnly",
+    "solidary",
+    "solomons",
+    "solution",
+    "solvable",
+    "somberly",
+    "sombrely",
+    "sombrous",
+    "somewhat",
+    "somnific",
+    "sonantal",
+    "songless",
+    "songlike",
+    "sonorous",
+    "soothing",
+    "soppiest",
+    "sordidly",
+    "soricine",
+    "sorriest",
+    "sortable",
+    "sortably",
+    "sottedly",
+    "soulless",
+    "soullike",
+    "soupiest",
+    "soupless",
+    "souplike",
+    "southern",
+    "sovranly",
+    "sowbelly",
+    "spacious",
+    "spagyric",
+    "spanemic",
+    "spaniels",
+    "spanking",
+    "sparkily",
+    "sparkish",
+    "sparkles",
+    "sparlike",
+    "sparrows",
+    "sparsely",
+    "sparsest",
+    "spathose",
+    "spavined",
+    "specious",
+    "spectral",
+    "specular",
+    "speedful",
+    "speedier",
+    "speedily",
+    "spelaean",
+    "spencers",
+    "spermous",
+    "sphygmic",
+    "spiffier",
+    "spiffily",
+    "spiffing",
+    "spikiest",
+    "spinally",
+    "spiniest",
+    "spirally",
+    "spirited",
+    "spiteful",
+    "splendid",
+    "splenial",
+    "spondaic",
+    "spongier",
+    "spongily",
+    "spookier",
+    "spookily",
+    "sporadic",
+    "sportful",
+    "sportier",
+    "sportily",
+    "sporting",
+    "sportive",
+    "sporular",
+    "spotless",
+    "spotlike",
+    "spottily",
+    "sprayful",
+    "springer",
+    "spritely",
+    "sprucely",
+    "sprucing",
+    "spurious",
+    "spurless",
+    "spurlike",
+    "spurtive",
+    "squamate",
+    "squamous",
+    "squarely",
+    "squarish",
+    "squeaker",
+    "squiggly",
+    "squirrel",
+    "squshier",
+    "stagiest",
+    "staglike",
+    "stagnant",
+    "stainful",
+    "stalkily",
+    "stallion",
+    "stalwart",
+    "staminal",
+    "stanchly",
+    "stanford",
+    "stanleys",
+    "stannous",
+    "stantons",
+    "stanzaed",
+    "starfish",
+    "starless",
+    "starlike",
+    "starling",
+    "starrier",
+    "starrily",
+    "statable",
+    "statedly",
+    "stavable",
+    "stayable",
+    "steadily",
+    "stealthy",
+    "steamier",
+    "steamily",
+    "stedfast",
+    "steelily",
+    "steenbok",
+    "steepled",
+    "stefania",
+    "stefanie",
+    "stefanis",
+    "stefanys",
+    "stellate",
+    "stemless",
+    "stemlike",
+    "stenosed",
+    "stepanie",
+    "stephane",
+    "stephani",
+    "stephans",
+    "stephany",
+    "stephens",
+    "stephine",
+    "stephnie",
+    "stepless",
+    "steplike",
+    "sterling",
+    "stetsons",
+    "stewarts",
+    "stickier",
+    "stickily",
+    "stiffish",
+    "stingily",
+    "stingray",
+    "stinkbug",
+    "stipular",
+    "stirless",
+    "stirring",
+    "stockier",
+    "stockily",
+    "stockish",
+    "stodgier",
+    "stodgily",
+    "stolidly",
+    "stomachy",
+    "stomatal",
+    "stomatic",
+    "stonable",
+    "stonefly",
+    "stoniest",
+    "storeyed",
+    "stormier",
+    "stormily",
+    "stotious",
+    "stoutish",
+    "straggly",
+    "straight",
+    "strained",
+    "straitly",
+    "strapped",
+    "strategy",
+    "stratous",
+    "straucht",
+    "strawhat",
+    "stretchy",
+    "stricken",
+    "strictly",
+    "strident",
+    "strigose",
+    "striking",
+    "stringed",
+    "stripier",
+    "striving",
+    "strongly",
+    "strophic",
+    "struggle",
+    "stubbily",
+    "stubbled",
+    "stubborn",
+    "studious",
+    "stuffily",
+    "stumpier",
+    "stumpily",
+    "stunning",
+    "stupidly",
+    "sturdied",
+    "sturdier",
+    "sturdily",
+    "sturgeon",
+    "stylitic",
+    "subacrid",
+    "subacute",
+    "subalary",
+    "subalate",
+    "subareal",
+    "subaural",
+    "subaxial",
+    "subaxile",
+    "subbasal",
+    "subconic",
+    "subcubic",
+    "subequal",
+    "suberect",
+    "subgular",
+    "subhuman",
+    "subhumid",
+    "subhyoid",
+    "subjugal",
+    "subloral",
+    "submanic",
+    "subnasal",
+    "subocean",
+    "subolive",
+    "suboptic",
+    "subovate",
+    "subovoid",
+    "subpolar",
+    "subpubic",
+    "subrigid",
+    "subsolar",
+    "subsonic",
+    "subtepid",
+    "subtilis",
+    "subulate",
+    "subvocal",
+    "subzonal",
+    "succinct",
+    "succinic",
+    "suddenly",
+    "suellens",
+    "suffixal",
+    "suicidal",
+    "suitable",
+    "suitably",
+    "sulfinyl",
+    "sulfitic",
+    "sulfonyl",
+    "sulfuric",
+    "sulfuryl",
+    "sulkiest",
+    "sullenly",
+    "sullivan",
+    "sultanic",
+    "sultrier",
+    "sultrily",
+    "summerly",
+    "summital",
+    "sumption",
+    "sunbaked",
+    "sunbeams",
+    "sunbeamy",
+    "sunbirds",
+    "sundrily",
+    "sunfishs",
+    "sunniest",
+    "sunproof",
+    "sunshine",
+    "sunshiny",
+    "superbly",
+    "superior",
+    "supernal",
+    "supinely",
+    "supplely",
+    "supplest",
+    "suppling",
+    "supposed",
+    "surbased",
+    "surfable",
+    "surfbird",
+    "surflike",
+    "surgical",
+    "suricate",
+    "surprise",
+    "surround",
+    "susannah",
+    "susannas",
+    "susannes",
+    "suzannas",
+    "suzannes",
+    "suzettes",
+    "sveltely",
+    "sveltest",
+    "svetlana",
+    "swainish",
+    "swampier",
+    "swayable",
+    "sweatier",
+    "sweatily",
+    "sweepers",
+    "sweeping",
+    "swiftlet",
+    "swimmily",
+    "swindled",
+    "swirlier",
+    "swishier",
+    "syenitic",
+    "sylphish",
+    "sylvatic",
+    "symbolic",
+    "synaptic",
+    "syndetic",
+    "syngamic",
+    "synoetic",
+    "synovial",
+    "synthias",
+    "syntonic",
+    "syntypic",
+    "syreetas",
+    "systemic",
+    "syzygial",
+    "tabarded",
+    "tabathas",
+    "tabethas",
+    "tabithas",
+    "taciturn",
+    "tacketed",
+    "tackiest",
+    "tackless",
+    "tactical",
+    "tactless",
+    "tadpoles",
+    "tagmemic",
+    "tailless",
+    "taillike",
+    "tajuanas",
+    "takingly",
+    "takishas",
+    "talented",
+    "talishas",
+    "talithas",
+    "taliyahs",
+    "talkable",
+    "talkiest",
+    "tamandua",
+    "tamarins",
+    "tamathas",
+    "tameable",
+    "tameikas",
+    "tamekias",
+    "tameless",
+    "tameshas",
+    "tamishas",
+    "tammaras",
+    "tammeras",
+    "tanagers",
+    "taneshas",
+    "tangelas",
+    "tangible",
+    "tangibly",
+    "tangiest",
+    "tanishas",
+    "tankless",
+    "tanklike",
+    "tannable",
+    "tantalic",
+    "tapeless",
+    "tapelike",
+    "tappable",
+    "tardiest",
+    "tarnally",
+    "tarsiers",
+    "tartaric",
+    "tashinas",
+    "tasteful",
+    "tatianas",
+    "tattlers",
+    "tatyanas",
+    "tawandas",
+    "tawannas",
+    "tawdrier",
+    "tawdrily",
+    "tawniest",
+    "taxingly",
+    "teaching",
+    "tearable",
+    "teariest",
+    "tearless",
+    "teasable",
+    "techiest",
+    "tectonic",
+    "teeniest",
+    "teetotal",
+    "tegminal",
+    "telluric",
+    "telsonic",
+    "tempered",
+    "temporal",
+    "tempting",
+    "tenderly",
+    "teneshas",
+    "tenishas",
+    "tennille",
+    "tenpenny",
+    "tensible",
+    "tensibly",
+    "tentiest",
+    "tentless",
+    "tentlike",
+    "tenurial",
+    "teodoras",
+    "teodoros",
+    "teofilas",
+    "tequilas",
+    "teratoid",
+    "tereasas",
+    "terences",
+    "teresias",
+    "teresita",
+    "teressas",
+    "termitic",
+    "termless",
+    "terpenic",
+    "terrance",
+    "terrapin",
+    "terrells",
+    "terrence",
+    "terresas",
+    "terrible",
+    "terribly",
+    "terriers",
+    "terrific",
+    "terrilyn",
+    "testable",
+    "tetchily",
+    "tetracid",
+    "textless",
+    "thaddeus",
+    "thalloid",
+    "thallous",
+    "thankful",
+    "thatcher",
+    "theistic",
+    "theodora",
+    "theodore",
+    "theresas",
+    "thereses",
+    "theresia",
+    "theressa",
+    "theurgic",
+    "thickety",
+    "thievish",
+    "thinnish",
+    "thoracic",
+    "thornier",
+    "thornily",
+    "thorough",
+    "thowless",
+    "thrasher",
+    "thrawnly",
+    "thrilled",
+    "thriving",
+    "thuggish",
+    "thundery",
+    "thurmans",
+    "thwartly",
+    "thymiest",
+    "thyrsoid",
+    "ticklish",
+    "tideless",
+    "tidelike",
+    "tiderode",
+    "tiffaney",
+    "tiffanie",
+    "tiffanis",
+    "tiffanys",
+    "tiffinys",
+    "tigerish",
+    "tijuanas",
+    "tilapias",
+    "tilefish",
+    "tilelike",
+    "tillable",
+    "timbered",
+    "timeless",
+    "timeworn",
+    "timorous",
+    "timothys",
+    "tinamous",
+    "tinglier",
+    "tinishas",
+    "tinklier",
+    "tinniest",
+    "tinselly",
+    "tintless",
+    "tippable",
+    "tippiest",
+    "tireless",
+    "tiresome",
+    "tiringly",
+    "titmouse",
+    "toadfish",
+    "toadless",
+    "toadlike",
+    "toadyish",
+    "toccaras",
+    "together",
+    "toilsome",
+    "tolerant",
+    "tombless",
+    "tomblike",
+    "tomorrow",
+    "toneless",
+    "tonettes",
+    "tonishas",
+    "tonishly",
+    "tonsilar",
+    "toolless",
+    "toothier",
+    "toothily",
+    "topazine",
+    "toplofty",
+    "topnotch",
+    "toreutic",
+    "tornadic",
+    "torpidly",
+    "torquate",
+    "torridly",
+    "tortious",
+    "tortoise",
+    "tortuous",
+    "toshikos",
+    "touchier",
+    "touchily",
+    "touching",
+    "toughish",
+    "touristy",
+    "tournois",
+    "towandas",
+    "towardly",
+    "towering",
+    "townless",
+    "tractile",
+    "tragopan",
+    "tramless",
+    "tranquil",
+    "transbay",
+    "transfer",
+    "traplike",
+    "trappean",
+    "trappier",
+    "trashily",
+    "treefrog",
+    "treelike",
+    "trembler",
+    "trendily",
+    "trentons",
+    "trepidly",
+    "tressier",
+    "tressies",
+    "trevally",
+    "triaxial",
+    "tribadic",
+    "tribally",
+    "tribasic",
+    "trichoid",
+    "trickier",
+    "trickily",
+    "trickish",
+    "triethyl",
+    "trigonal",
+    "trilobed",
+    "trimeric",
+    "trimodal",
+    "trinidad",
+    "trinitys",
+    "tripedal",
+    "tripodal",
+    "tripodic",
+    "trippant",
+    "trisomic",
+    "tristans",
+    "tristens",
+    "tristful",
+    "tristian",
+    "tristins",
+    "tristons",
+    "trollopy",
+    "trophied",
+    "tropical",
+    "truantly",
+    "trueborn",
+    "truffled",
+    "truistic",
+    "trustful",
+    "trustily",
+    "trusting",
+    "truthful",
+    "tryingly",
+    "tsunamic",
+    "tuataras",
+    "tubbable",
+    "tubbiest",
+    "tuberoid",
+    "tuberous",
+    "tubulous",
+    "tumulose",
+    "tumulous",
+    "tuneable",
+    "tuneably",
+    "tuneless",
+    "tuppenny",
+    "turbaned",
+    "turbidly",
+    "turfiest",
+    "turfless",
+    "turflike",
+    "turgidly",
+    "turreted",
+    "tuskless",
+    "twangily",
+    "tweedier",
+    "twiggier",
+    "twigless",
+    "twiglike",
+    "twinborn",
+    "twittery",
+    "twopenny",
+    "tympanic",
+    "tynishas",
+    "typhonic",
+    "ulcerous",
+    "ulterior",
+    "ultimate",
+    "ultrared",
+    "umbellar",
+    "umbonate",
+    "umbrella",
+    "unabased",
+    "unabated",
+    "unabject",
+    "unabused",
+    "unacetic",
+    "unaching",
+    "unacidic",
+    "unacting",
+    "unaddled",
+    "unadored",
+    "unadroit",
+    "unafraid",
+    "unagreed",
+    "unaiding",
+    "unaiming",
+    "unairily",
+    "unaisled",
+    "unallied",
+    "unamazed",
+    "unamused",
+    "unaneled",
+    "unanemic",
+    "unarched",
+    "unarchly",
+    "unargued",
+    "unartful",
+    "unasking",
+    "unatoned",
+    "unavidly",
+    "unawaked",
+    "unawares",
+    "unaxised",
+    "unbacked",
+    "unbadged",
+    "unbagged",
+    "unbailed",
+    "unbalked",
+    "unbanded",
+    "unbanned",
+    "unbarbed",
+    "unbarren",
+    "unbasted",
+    "unbathed",
+    "unbating",
+    "unbatted",
+    "unbeaded",
+    "unbeamed",
+    "unbeaten",
+    "unbeaued",
+    "unbegged",
+    "unbeheld",
+    "unbelied",
+    "unbenign",
+    "unbiased",
+    "unbidden",
+    "unbilled",
+    "unbinned",
+    "unbiting",
+    "unbitten",
+    "unbitter",
+    "unblamed",
+    "unbloody",
+    "unbobbed",
+    "unbodied",
+    "unboding",
+    "unboiled",
+    "unboldly",
+    "unbolted",
+    "unbombed",
+    "unbonded",
+    "unbooted",
+    "unboring",
+    "unbossed",
+    "unbought",
+    "unbowing",
+    "unbowled",
+    "unboyish",
+    "unbraved",
+    "unbrawny",
+    "unbrazen",
+    "unbreezy",
+    "unbrewed",
+    "unbribed",
+    "unbright",
+    "unbrined",
+    "unbroken",
+    "unbudged",
+    "unbuffed",
+    "unbumped",
+    "unbuoyed",
+    "unburied",
+    "unburned",
+    "unbusily",
+    "unbuying",
+    "uncabled",
+    "uncalked",
+    "uncalled",
+    "uncalmly",
+    "uncamped",
+    "uncandid",
+    "uncanned",
+    "uncarded",
+    "uncaring",
+    "uncarted",
+    "uncarved",
+    "uncashed",
+    "uncasked",
+    "uncasual",
+    "uncaught",
+    "uncausal",
+    "uncaused",
+    "unceased",
+    "unchafed",
+    "unchalky",
+    "unchancy",
+    "uncharge",
+    "unchased",
+    "unchaste",
+    "uncheery",
+    "unchewed",
+    "unchicly",
+    "unchided",
+    "unchoked",
+    "unchosen",
+    "uncially",
+    "uncinate",
+    "uncitied",
+    "unclawed",
+    "unclayed",
+    "unclever",
+    "uncloudy",
+    "uncloven",
+    "uncloyed",
+    "uncoarse",
+    "uncoaxal",
+    "uncoaxed",
+    "uncogent",
+    "uncogged",
+    "uncoifed",
+    "uncoined",
+    "uncombed",
+    "uncomely",
+    "uncommon",
+    "unconned",
+    "uncooked",
+    "uncooled",
+    "uncopied",
+    "uncorned",
+    "uncostly",
+    "uncrafty",
+    "uncraggy",
+    "uncrated",
+    "uncraven",
+    "uncrying",
+    "unctuous",
+    "uncuffed",
+    "unculled",
+    "uncupped",
+    "uncurbed",
+    "uncursed",
+    "uncurved",
+    "uncusped",
+    "undainty",
+    "undamped",
+    "undapper",
+    "undaring",
+    "undarned",
+    "undaubed",
+    "undawned",
+    "undazing",
+    "undecked",
+    "undeeded",
+    "undeeply",
+    "undefied",
+    "undelved",
+    "undemure",
+    "undenied",
+    "undented",
+    "underage",
+    "underlit",
+    "undevout",
+    "undewily",
+    "undialed",
+    "undilute",
+    "undimmed",
+    "undipped",
+    "undoable",
+    "undocked",
+    "undodged",
+    "undoting",
+    "undotted",
+    "undowned",
+    "undreamt",
+    "undriven",
+    "undrossy",
+    "undrying",
+    "undubbed",
+    "undulant",
+    "undulled",
+    "undumped",
+    "undusted",
+    "undyable",
+    "unearned",
+    "uneasily",
+    "uneating",
+    "unebbing",
+    "unechoed",
+    "unechoic",
+    "uneddied",
+    "unedible",
+    "unedited",
+    "uneduced",
+    "uneffete",
+    "unelated",
+    "unelided",
+    "uneloped",
+    "uneluded",
+    "unending",
+    "unendued",
+    "unenvied",
+    "unequine",
+    "unerased",
+    "uneroded",
+    "unerrant",
+    "unerring",
+    "unespied",
+    "unetched",
+    "unevaded",
+    "unevenly",
+    "unevilly",
+    "unevoked",
+    "unexempt",
+    "unexiled",
+    "unexotic",
+    "unexpert",
+    "unexuded",
+    "unfabled",
+    "unfacile",
+    "unfading",
+    "unfagged",
+    "unfailed",
+    "unfairly",
+    "unfallen",
+    "unfanged",
+    "unfanned",
+    "unfarced",
+    "unfarmed",
+    "unfatted",
+    "unfaulty",
+    "unfeared",
+    "unfecund",
+    "unfeeble",
+    "unfeebly",
+    "unfeline",
+    "unfelled",
+    "unfelted",
+    "unfemale",
+    "unfended",
+    "unfervid",
+    "unfeudal",
+    "unfibred",
+    "unfickle",
+    "unfierce",
+    "unfilial",
+    "unfilled",
+    "unfilmed",
+    "unfinite",
+    "unfiring",
+    "unfirmly",
+    "unfiscal",
+    "unfished",
+    "unfitted",
+    "unflaked",
+    "unflared",
+    "unflashy",
+    "unflawed",
+    "unflayed",
+    "unflexed",
+    "unflorid",
+    "unflossy",
+    "unfluent",
+    "unfluffy",
+    "unfluked",
+    "unfluted",
+    "unflying",
+    "unfoaled",
+    "unfoamed",
+    "unfogged",
+    "unfoiled",
+    "unfondly",
+    "unfooled",
+    "unforced",
+    "unforded",
+    "unforged",
+    "unformed",
+    "unfought",
+    "unfouled",
+    "unfoully",
+    "unframed",
+    "unfrayed",
+    "unfretty",
+    "unfrigid",
+    "unfrilly",
+    "unfrisky",
+    "unfrizzy",
+    "unfrosty",
+    "unfrozen",
+    "unfrugal",
+    "unfruity",
+    "unfudged",
+    "unfueled",
+    "unfuming",
+    "unfunded",
+    "unfurred",
+    "unfussed",
+    "unfutile",
+    "ungabled",
+    "ungained",
+    "ungainly",
+    "ungaited",
+    "ungalled",
+    "unganged",
+    "ungaping",
+    "ungarbed",
+    "ungashed",
+    "ungauged",
+    "ungazing",
+    "ungeared",
+    "ungelded",
+    "ungenial",
+    "ungentle",
+    "ungently",
+    "ungifted",
+    "ungilded",
+    "ungilled",
+    "ungiving",
+    "ungladly",
+    "unglazed",
+    "unglibly",
+    "ungloomy",
+    "unglossy",
+    "ungloved",
+    "unglozed",
+    "ungnawed",
+    "ungoaded",
+    "ungolden",
+    "ungorged",
+    "ungothic",
+    "ungotten",
+    "ungouged",
+    "ungowned",
+    "ungraced",
+    "ungraded",
+    "ungrated",
+    "ungraved",
+    "ungraven",
+    "ungrayed",
+    "ungrazed",
+    "ungreasy",
+    "ungreedy",
+    "ungreyed",
+    "ungrimed",
+    "ungritty",
+    "unground",
+    "ungrumpy",
+    "unguided",
+    "ungummed",
+    "ungutted",
+    "unhacked",
+    "unhailed",
+    "unhaloed",
+    "unhalted",
+    "unhalved",
+    "unhanged",
+    "unhanked",
+    "unharked",
+    "unharmed",
+    "unharped",
+    "unhashed",
+    "unhasted",
+    "unhating",
+    "unhauled",
+    "unhawked",
+    "unhazily",
+    "unheaded",
+    "unhealed",
+    "unheaped",
+    "unhearty",
+    "unheated",
+    "unheaved",
+    "unhectic",
+    "unheeded",
+    "unheeled",
+    "unhefted",
+    "unhelped",
+    "unhelved",
+    "unhemmed",
+    "unherded",
+    "unheroic",
+    "unhidden",
+    "unhinted",
+    "unhipped",
+    "unhissed",
+    "unhoaxed",
+    "unhocked",
+    "unhogged",
+    "unholily",
+    "unhollow",
+    "unhomely",
+    "unhonied",
+    "unhoofed",
+    "unhooped",
+    "unhooted",
+    "unhoping",
+    "unhorned",
+    "unhugged",
+    "unhumane",
+    "unhumble",
+    "unhumbly",
+    "unhunted",
+    "unhurled",
+    "unhusked",
+    "unhymned",
+    "uniambic",
+    "uniaxial",
+    "unicolor",
+    "unicorns",
+    "unideaed",
+    "unidling",
+    "unifilar",
+    "unilobed",
+    "unimaged",
+    "unimbued",
+    "uninlaid",
+    "uninnate",
+    "unintent",
+    "uninured",
+    "unipolar",
+    "uniquely",
+    "unirenic",
+    "unironed",
+    "unissued",
+    "unitable",
+    "unitedly",
+    "universe",
+    "unjagged",
+    "unjailed",
+    "unjarred",
+    "unjaunty",
+    "unjeered",
+    "unjelled",
+    "unjilted",
+    "unjocose",
+    "unjocund",
+    "unjogged",
+    "unjoking",
+    "unjolted",
+    "unjovial",
+    "unjoyful",
+    "unjoyous",
+    "unjudged",
+    "unjustly",
+    "unkeeled",
+    "unkenned",
+    "unkilled",
+    "unkilned",
+    "unkindly",
+    "unkinged",
+    "unkingly",
+    "unkissed",
+    "unknotty",
+    "unladled",
+    "unlanced",
+    "unlanded",
+    "unlapped",
+    "unlapsed",
+    "unlarded",
+    "unlauded",
+    "unlaving",
+    "unlavish",
+    "unlawful",
+    "unleaded",
+    "unlearnt",
+    "unleased",
+    "unleaved",
+    "unledged",
+    "unlensed",
+    "unlethal",
+    "unlevied",
+    "unliable",
+    "unlidded",
+    "unlifted",
+    "unlikely",
+    "unlimned",
+    "unlineal",
+    "unliquid",
+    "unlisted",
+    "unlively",
+    "unliving",
+    "unloaned",
+    "unlodged",
+    "unlogged",
+    "unlonely",
+    "unlooked",
+    "unlooted",
+    "unlopped",
+    "unlotted",
+    "unloudly",
+    "unlovely",
+    "unloving",
+    "unlucent",
+    "unluffed",
+    "unlugged",
+    "unlumped",
+    "unlunate",
+    "unmadded",
+    "unmailed",
+    "unmaimed",
+    "unmalted",
+    "unmanful",
+    "unmanned",
+    "unmanual",
+    "unmarine",
+    "unmarked",
+    "unmarled",
+    "unmarred",
+    "unmashed",
+    "unmasked",
+    "unmatted",
+    "unmature",
+    "unmauled",
+    "unmeetly",
+    "unmellow",
+    "unmelted",
+    "unmended",
+    "unmenial",
+    "unmental",
+    "unmetred",
+    "unmetric",
+    "unmilked",
+    "unmilled",
+    "unmilted",
+    "unminced",
+    "unminted",
+    "unmisled",
+    "unmissed",
+    "unmoaned",
+    "unmoated",
+    "unmobbed",
+    "unmobile",
+    "unmocked",
+    "unmodern",
+    "unmodest",
+    "unmodish",
+    "unmoiled",
+    "unmolten",
+    "unmooted",
+    "unmopped",
+    "unmorbid",
+    "unmorose",
+    "unmortal",
+    "unmossed",
+    "unmotile",
+    "unmouldy",
+    "unmoving",
+    "unmudded",
+    "unmulish",
+    "unmulled",
+    "unmusing",
+    "unmusked",
+    "unmutant",
+    "unmutual",
+    "unmyopic",
+    "unmystic",
+    "unnagged",
+    "unnapped",
+    "unnarrow",
+    "unnative",
+    "unneatly",
+    "unneeded",
+    "unnetted",
+    "unneural",
+    "unnibbed",
+    "unnicely",
+    "unniched",
+    "unnicked",
+    "unnimble",
+    "unnimbly",
+    "unnipped",
+    "unnoised",
+    "unnoosed",
+    "unnormal",
+    "unnoting",
+    "unnumbed",
+    "unobeyed",
+    "unocular",
+    "unodious",
+    "unodored",
+    "unoiling",
+    "unomened",
+    "unopened",
+    "unopined",
+    "unorally",
+    "unornate",
+    "unousted",
+    "unpadded",
+    "unpained",
+    "unpaired",
+    "unpalled",
+    "unpaltry",
+    "unparked",
+    "unparsed",
+    "unparted",
+    "unpatent",
+    "unpatted",
+    "unpaving",
+    "unpawned",
+    "unpaying",
+    "unpeaked",
+    "unpealed",
+    "unpecked",
+    "unpeeled",
+    "unpelted",
+    "unpetted",
+    "unphased",
+    "unpicked",
+    "unpieced",
+    "unpiqued",
+    "unpitied",
+    "unpitted",
+    "unplaced",
+    "unplacid",
+    "unplaned",
+    "unplated",
+    "unplayed",
+    "unpliant",
+    "unplowed",
+    "unplumed",
+    "unpodded",
+    "unpoetic",
+    "unpoised",
+    "unpolite",
+    "unpolled",
+    "unpooled",
+    "unporous",
+    "unportly",
+    "unposing",
+    "unposted",
+    "unpotent",
+    "unpoured",
+    "unpretty",
+    "unpriced",
+    "unprimed",
+    "unprimly",
+    "unprized",
+    "unprobed",
+    "unprolix",
+    "unprompt",
+    "unproper",
+    "unproved",
+    "unproven",
+    "unpruned",
+    "unprying",
+    "unpublic",
+    "unpuffed",
+    "unpulped",
+    "unpumped",
+    "unpurely",
+    "unpurged",
+    "unpurled",
+    "unpushed",
+    "unputrid",
+    "unquayed",
+    "unquoted",
+    "unraided",
+    "unrailed",
+    "unraised",
+    "unraking",
+    "unrammed",
+    "unramped",
+    "unrancid",
+    "unranked",
+    "unrashly",
+    "unrasped",
+    "unraving",
+    "unreally",
+    "unreaped",
+    "unreared",
+    "unreefed",
+    "unreined",
+    "unremote",
+    "unrented",
+    "unrepaid",
+    "unrested",
+    "unretted",
+    "unribbed",
+    "unridden",
+    "unridged",
+    "unrifled",
+    "unrifted",
+    "unrinsed",
+    "unripely",
+    "unrising",
+    "unrisked",
+    "unritual",
+    "unrobbed",
+    "unrobust",
+    "unrocked",
+    "unrodded",
+    "unroiled",
+    "unrotary",
+    "unrotted",
+    "unrotten",
+    "unrotund",
+    "unrouged",
+    "unroused",
+    "unrouted",
+    "unroving",
+    "unrubbed",
+    "unrudely",
+    "unrueful",
+    "unruffed",
+    "unrugged",
+    "unrushed",
+    "unrustic",
+    "unsabled",
+    "unsabred",
+    "unsacked",
+    "unsacred",
+    "unsafely",
+    "unsagely",
+    "unsailed",
+    "unsaline",
+    "unsallow",
+    "unsalted",
+    "unsalved",
+    "unsanded",
+    "unsapped",
+    "unsashed",
+    "unsating",
+    "unsauced",
+    "unsavage",
+    "unsaving",
+    "unsavory",
+    "unscaled",
+    "unscanty",
+    "unscarce",
+    "unscared",
+    "unscenic",
+    "unscored",
+    "unsealed",
+    "unseared",
+    "unsecure",
+    "unsedate",
+    "unseeded",
+    "unseeing",
+    "unseemly",
+    "unseized",
+    "unselect",
+    "unsenile",
+    "unsensed",
+    "unserene",
+    "unserved",
+    "unsevere",
+    "unshabby",
+    "unshaken",
+    "unshamed",
+    "unshaped",
+    "unshapen",
+    "unshared",
+    "unshaved",
+    "unshifty",
+    "unshined",
+    "unshored",
+    "unshoved",
+    "unshowed",
+    "unshrewd",
+    "unshrill",
+    "unshrunk",
+    "unsicker",
+    "unsickly",
+    "unsiding",
+    "unsieged",
+    "unsieved",
+    "unsifted",
+    "unsigned",
+    "unsilent",
+    "unsimple",
+    "unsimply",
+    "unsinewy",
+    "unsinful",
+    "unsinged",
+    "unsingle",
+    "unsipped",
+    "unskewed",
+    "unslaked",
+    "unslated",
+    "unsleepy",
+    "unsliced",
+    "unslimly",
+    "unsloped",
+    "unslowed",
+    "unslowly",
+    "unsmoked",
+    "unsmooth",
+    "unsmugly",
+    "unsnared",
+    "unsneaky",
+    "unsnugly",
+    "unsoaked",
+    "unsoaped",
+    "unsocial",
+    "unsoftly",
+    "unsoiled",
+    "unsolemn",
+    "unsolved",
+    "unsomber",
+    "unsombre",
+    "unsonant",
+    "unsordid",
+    "unsorely",
+    "unsotted",
+    "unsought",
+    "unsourly",
+    "unsoused",
+    "unspaced",
+    "unspaded",
+    "unspared",
+    "unsparse",
+    "unspayed",
+    "unspeedy",
+    "unspewed",
+    "unspiral",
+    "unspired",
+    "unspited",
+    "unspoilt",
+    "unspoken",
+    "unspongy",
+    "unspread",
+    "unsprung",
+    "unspying",
+    "unstable",
+    "unstably",
+    "unstaged",
+    "unstaled",
+    "unstanch",
+    "unstated",
+    "unstatic",
+    "unstaved",
+    "unstewed",
+    "unsticky",
+    "unstoked",
+    "unstolen",
+    "unstoned",
+    "unstormy",
+    "unstrewn",
+    "unstrict",
+    "unstrung",
+    "unstuffy",
+    "unstupid",
+    "unsturdy",
+    "unstyled",
+    "unsubtle",
+    "unsubtly",
+    "unsugary",
+    "unsuited",
+    "unsullen",
+    "unsultry",
+    "unsunken",
+    "unsupine",
+    "unsupple",
+    "unsupply",
+    "unsurely",
+    "unswampy",
+    "unswayed",
+    "untabled",
+    "untagged",
+    "untailed",
+    "untaking",
+    "untamely",
+    "untanned",
+    "untapped",
+    "untarred",
+    "untasked",
+    "untasted",
+    "untaught",
+    "untautly",
+    "untawdry",
+    "untaxied",
+    "untaxing",
+    "unteamed",
+    "unteased",
+    "untedded",
+    "untended",
+    "untested",
+    "unthawed",
+    "unthorny",
+    "unthrown",
+    "unthrust",
+    "untidied",
+    "untidier",
+    "untidily",
+    "untiered",
+    "untilled",
+    "untimely",
+    "untinged",
+    "untinned",
+    "untinted",
+    "untiring",
+    "untogaed",
+    "untolled",
+    "untombed",
+    "untooled",
+    "untopped",
+    "untorpid",
+    "untorrid",
+    "untossed",
+    "untotted",
+    "untoured",
+    "untoward",
+    "untraced",
+    "untraded",
+    "untragic",
+    "untribal",
+    "untriced",
+    "untropic",
+    "untruant",
+    "untrying",
+    "untubbed",
+    "untufted",
+    "untugged",
+    "unturbid",
+    "unturfed",
+    "unturgid",
+    "unturned",
+    "untusked",
+    "ununique",
+    "ununited",
+    "unurbane",
+    "unurgent",
+    "unurging",
+    "unusable",
+    "unusably",
+    "unuseful",
+    "unvacant",
+    "unvainly",
+    "unvalued",
+    "unvamped",
+    "unvaried",
+    "unvatted",
+    "unveined",
+    "unvended",
+    "unvenial",
+    "unvented",
+    "unverbal",
+    "unversed",
+    "unvested",
+    "unvetoed",
+    "unviable",
+    "unviewed",
+    "unvinous",
+    "unvirgin",
+    "unvirile",
+    "unvisual",
+    "unvoiced",
+    "unvoided",
+    "unvoting",
+    "unvulgar",
+    "unwadded",
+    "unwading",
+    "unwafted",
+    "unwagged",
+    "unwailed",
+    "unwaived",
+    "unwaking",
+    "unwalked",
+    "unwaning",
+    "unwanted",
+    "unwanton",
+    "unwarily",
+    "unwarmed",
+    "unwarned",
+    "unwarped",
+    "unwasted",
+    "unwatery",
+    "unwaving",
+    "unweaned",
+    "unwebbed",
+    "unwedded",
+    "unweeded",
+    "unweened",
+    "unwelded",
+    "unwelted",
+    "unwetted",
+    "unwhited",
+    "unwicked",
+    "unwieldy",
+    "unwifely",
+    "unwildly",
+    "unwilful",
+    "unwilled",
+    "unwilted",
+    "unwinded",
+    "unwinged",
+    "unwintry",
+    "unwisely",
+    "unwished",
+    "unwoeful",
+    "unwonted",
+    "unwooded",
+    "unworked",
+    "unworthy",
+    "uplifted",
+    "uppishly",
+    "upturned",
+    "upwardly",
+    "uranitic",
+    "uranylic",
+    "urbanely",
+    "ureteral",
+    "ureteric",
+    "urethral",
+    "ureylene",
+    "urgently",
+    "urgingly",
+    "uromeric",
+    "uropodal",
+    "ursiform",
+    "urticant",
+    "usefully",
+    "ustulate",
+    "usurious",
+    "uvularly",
+    "uxorious",
+    "vacantly",
+    "vaccinal",
+    "vacuolar",
+    "valanced",
+    "valaries",
+    "valencia",
+    "valentin",
+    "valerias",
+    "valeries",
+    "valories",
+    "valorous",
+    "valuable",
+    "valuably",
+    "valvular",
+    "vampiric",
+    "vanadous",
+    "vaneless",
+    "vanessas",
+    "vanettas",
+    "vanillic",
+    "vanitied",
+    "vannesas",
+    "vannessa",
+    "vaporish",
+    "vaporous",
+    "variable",
+    "variably",
+    "varicose",
+    "variedly",
+    "varietal",
+    "variform",
+    "varnishy",
+    "vascular",
+    "vaselike",
+    "vasiliki",
+    "vaulting",
+    "vaunting",
+    "vehement",
+    "veiledly",
+    "veilless",
+    "veillike",
+    "veiniest",
+    "veinless",
+    "vendaces",
+    "vendibly",
+    "venenose",
+    "venereal",
+    "venessas",
+    "venettas",
+    "vengeful",
+    "venially",
+    "venously",
+    "ventless",
+    "venulose",
+    "verbally",
+    "verbatim",
+    "verbless",
+    "verboten",
+    "verdells",
+    "verecund",
+    "verified",
+    "veristic",
+    "verlenes",
+    "verlines",
+    "vernally",
+    "vernells",
+    "vernetta",
+    "vernices",
+    "vernitas",
+    "veronica",
+    "veronika",
+    "vesseled",
+    "vestally",
+    "vexingly",
+    "vibrioid",
+    "vibronic",
+    "vicarial",
+    "viceless",
+    "vicenary",
+    "vicentas",
+    "vicentes",
+    "victoria",
+    "viewable",
+    "viewiest",
+    "viewless",
+    "vigilant",
+    "vigoroso",
+    "vigorous",
+    "villagey",
+    "villatic",
+    "vincents",
+    "vincenza",
+    "vincenzo",
+    "vincible",
+    "vincibly",
+    "vineless",
+    "vinelike",
+    "vinously",
+    "violable",
+    "violably",
+    "violetas",
+    "violette",
+    "viperine",
+    "viperish",
+    "viperous",
+    "virgilio",
+    "virginas",
+    "virginia",
+    "virilely",
+    "virtuous",
+    "virulent",
+    "visceral",
+    "viscidly",
+    "viselike",
+    "visional",
+    "visually",
+    "vitiable",
+    "vitiated",
+    "vitreous",
+    "vituline",
+    "vivianas",
+    "vivienne",
+    "vixenish",
+    "vizarded",
+    "vizcacha",
+    "vizirial",
+    "voiceful",
+    "voidable",
+    "volcanic",
+    "volitant",
+    "volitive",
+    "vomerine",
+    "vonciles",
+    "vortical",
+    "voteable",
+    "voteless",
+    "votively",
+    "vulgarly",
+    "vultures",
+    "wackiest",
+    "wadeable",
+    "wageless",
+    "wagtails",
+    "wailsome",
+    "wainable",
+    "wakeless",
+    "wakerife",
+    "wallabys",
+    "wallaces",
+    "wallaroo",
+    "walleyed",
+    "walleyes",
+    "waltraud",
+    "wandlike",
+    "wanettas",
+    "wantless",
+    "wantonly",
+    "warblers",
+    "wardless",
+    "warmouth",
+    "warthogs",
+    "wartiest",
+    "wartless",
+    "wartlike",
+    "washable",
+    "washiest",
+    "waspiest",
+    "wastable",
+    "wasteful",
+    "watchful",
+    "waterily",
+    "waterish",
+    "waterlog",
+    "wattless",
+    "waveless",
+    "wavelike",
+    "wavingly",
+    "waxbills",
+    "waxwings",
+    "weaklier",
+    "weaponed",
+    "weariest",
+    "weariful",
+    "wearying",
+    "weaselly",
+    "webbiest",
+    "wedgiest",
+    "weediest",
+    "weepiest",
+    "weevilly",
+    "weighted",
+    "weirless",
+    "welcomed",
+    "weldable",
+    "weldless",
+    "wellborn",
+    "wendells",
+    "wendolyn",
+    "werewolf",
+    "westerly",
+    "westward",
+    "wetproof",
+    "wettable",
+    "whackier",
+    "wheatear",
+    "wheezier",
+    "wheezily",
+    "wheylike",
+    "whimbrel",
+    "whiniest",
+    "whiplike",
+    "whippets",
+    "whistler",
+    "whitefly",
+    "whitings",
+    "whitleys",
+    "whitneys",
+    "wickedly",
+    "wickless",
+    "wieldier",
+    "wifeless",
+    "wifelier",
+    "wigglier",
+    "wilberts",
+    "wilburns",
+    "wildcats",
+    "wildfowl",
+    "wilfords",
+    "wilfredo",
+    "wilfreds",
+    "wilfully",
+    "willable",
+    "willards",
+    "willenas",
+    "willenes",
+    "willetta",
+    "willette",
+    "williams",
+    "willians",
+    "willyard",
+    "windedly",
+    "windiest",
+    "windrode",
+    "wineless",
+    "winfords",
+    "winfreds",
+    "wingedly",
+    "wingless",
+    "winglike",
+    "winifred",
+    "winnable",
+    "winstons",
+    "winterly",
+    "wintrier",
+    "wintrily",
+    "wirelike",
+    "wirespun",
+    "wispiest",
+    "wisplike",
+    "witchier",
+    "wittiest",
+    "wizardly",
+    "wobbling",
+    "wobegone",
+    "woefully",
+    "wolffish",
+    "wolflike",
+    "womanish",
+    "wondrous",
+    "wontedly",
+    "woodcock",
+    "woodenly",
+    "woodiest",
+    "woodless",
+    "woodrows",
+    "wooingly",
+    "woollily",
+    "wooziest",
+    "wordiest",
+    "wordless",
+    "workable",
+    "workably",
+    "workaday",
+    "workless",
+    "wormless",
+    "wormlike",
+    "worthily",
+    "wrathful",
+    "wrathily",
+    "wreckful",
+    "wretched",
+    "writerly",
+    "wrongful",
+    "wrynecks",
+    "xanthous",
+    "xiomaras",
+    "xochitls",
+    "xzaviers",
+    "yahairas",
+    "yajairas",
+    "yamileth",
+    "yaretzis",
+    "yaritzas",
+    "yasmines",
+    "yearlong",
+    "yeastily",
+    "yellowly",
+    "yeomanly",
+    "yesenias",
+    "yessenia",
+    "yevettes",
+    "yokelish",
+    "yolandas",
+    "yolandes",
+    "yolandos",
+    "yolkless",
+    "yolondas",
+    "yoshikos",
+    "youlanda",
+    "youthful",
+    "yulandas",
+    "zacharys",
+    "zacherys",
+    "zackarys",
+    "zaniyahs",
+    "zariyahs",
+    "zenaidas",
+    "zenithal",
+    "zenobias",
+    "zeolitic",
+    "zestless",
+    "zippered",
+    "zipppier",
+    "zirconic",
+    "zodiacal",
+    "zoogleal",
+    "zoophily",
+    "zoraidas",
+    "zorillas"
+};
@@ -0,0 +1,7268 @@
+use phf::{phf_set, Set};
+
+/* 
+ * 
+ * 
+ * 
+ * 
+ * FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+ * FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+ * FFFFFFFFFFFFFFF                               FFFFFFFFFFFFFFF
+ * FFFFFFFFFFFFFFF  ALL ACCEPTABLE FRENCH WORDS  FFFFFFFFFFFFFFF
+ * FFFFFFFFFFFFFFF                               FFFFFFFFFFFFFFF
+ * FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+ * FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+ * 
+ * 
+ * 
+ * 
+ * Guesses in French games are checked against these.
+ * Common French nouns and adjectives (with their feminine and plural forms),
+ * plus the infinitives and the most-used conjugations of common verbs.
+ * 
+ * Accents are stripped and ligatures spelled out (ie: "etoile", "coeur"),
+ * the same way utils::no_caps treats a guess, so players may type either.
+ * See words_all for the lookups.
+ * 
+ * 
+*/
+
+
+pub static FOUR_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "abri",
+    "acte",
+    "ages",
+    "agir",
+    "aida",
+    "aide",
+    "aigu",
+    "aile",
+    "aima",
+    "aime",
+    "airs",
+    "aise",
+    "amer",
+    "ames",
+    "amie",
+    "amis",
+    "anes",
+    "ange",
+    "arcs",
+    "arme",
+    "arts",
+    "aube",
+    "aura",
+    "auto",
+    "avec",
+    "avez",
+    "avis",
+    "azur",
+    "baie",
+    "bain",
+    "banc",
+    "base",
+    "beau",
+    "bebe",
+    "becs",
+    "bete",
+    "bien",
+    "bise",
+    "bles",
+    "bleu",
+    "bloc",
+    "bois",
+    "boit",
+    "bols",
+    "bond",
+    "bons",
+    "bord",
+    "boue",
+    "bout",
+    "boxe",
+    "bras",
+    "bref",
+    "brin",
+    "brun",
+    "buts",
+    "cafe",
+    "cage",
+    "camp",
+    "caps",
+    "case",
+    "cave",
+    "cela",
+    "cent",
+    "cerf",
+    "chat",
+    "chef",
+    "cher",
+    "chez",
+    "chic",
+    "chou",
+    "ciel",
+    "cinq",
+    "cles",
+    "clou",
+    "code",
+    "coin",
+    "cols",
+    "coqs",
+    "cote",
+    "coup",
+    "cour",
+    "cous",
+    "cria",
+    "crie",
+    "cris",
+    "cuir",
+    "cure",
+    "dame",
+    "dans",
+    "date",
+    "defi",
+    "deja",
+    "dent",
+    "deux",
+    "dieu",
+    "dina",
+    "dine",
+    "dire",
+    "dois",
+    "doit",
+    "donc",
+    "dons",
+    "dont",
+    "dora",
+    "dore",
+    "dors",
+    "dort",
+    "doux",
+    "drap",
+    "dune",
+    "dura",
+    "dure",
+    "durs",
+    "eaux",
+    "egal",
+    "elan",
+    "epee",
+    "ests",
+    "etat",
+    "etes",
+    "etre",
+    "exil",
+    "face",
+    "faim",
+    "fais",
+    "fait",
+    "faut",
+    "faux",
+    "fees",
+    "fera",
+    "fers",
+    "feta",
+    "fete",
+    "feux",
+    "feve",
+    "fier",
+    "file",
+    "film",
+    "fils",
+    "fine",
+    "fini",
+    "fins",
+    "fixa",
+    "fixe",
+    "fois",
+    "fond",
+    "font",
+    "fort",
+    "four",
+    "fous",
+    "fuma",
+    "fume",
+    "gaie",
+    "gais",
+    "gant",
+    "gare",
+    "gata",
+    "gate",
+    "gels",
+    "gena",
+    "gene",
+    "gout",
+    "gras",
+    "gris",
+    "gros",
+    "haie",
+    "haut",
+    "hier",
+    "idee",
+    "iles",
+    "irai",
+    "irez",
+    "jeux",
+    "joie",
+    "joli",
+    "joua",
+    "joue",
+    "jour",
+    "juge",
+    "jupe",
+    "jura",
+    "jure",
+    "lacs",
+    "laid",
+    "lait",
+    "lava",
+    "lave",
+    "lent",
+    "leva",
+    "leve",
+    "lien",
+    "lieu",
+    "lime",
+    "lion",
+    "lire",
+    "lits",
+    "loin",
+    "lois",
+    "long",
+    "loua",
+    "loue",
+    "loup",
+    "lune",
+    "luxe",
+    "main",
+    "mais",
+    "mare",
+    "mari",
+    "mars",
+    "maux",
+    "meme",
+    "mena",
+    "mene",
+    "menu",
+    "mere",
+    "mers",
+    "mets",
+    "midi",
+    "miel",
+    "mine",
+    "mise",
+    "mode",
+    "mois",
+    "mord",
+    "mort",
+    "mots",
+    "mous",
+    "muet",
+    "mure",
+    "murs",
+    "nage",
+    "naif",
+    "nerf",
+    "neuf",
+    "nids",
+    "noce",
+    "noel",
+    "noir",
+    "noms",
+    "nota",
+    "note",
+    "nous",
+    "nues",
+    "nuit",
+    "nuls",
+    "oeil",
+    "oeuf",
+    "oies",
+    "onze",
+    "osai",
+    "osas",
+    "osee",
+    "oser",
+    "oses",
+    "osez",
+    "ours",
+    "page",
+    "pain",
+    "paix",
+    "pale",
+    "papa",
+    "parc",
+    "pars",
+    "part",
+    "pate",
+    "pave",
+    "paya",
+    "paye",
+    "pays",
+    "peau",
+    "perd",
+    "pesa",
+    "pese",
+    "peur",
+    "peut",
+    "peux",
+    "pied",
+    "pile",
+    "pins",
+    "pire",
+    "plan",
+    "plat",
+    "plia",
+    "plie",
+    "plus",
+    "pneu",
+    "poil",
+    "pois",
+    "pole",
+    "poli",
+    "pont",
+    "porc",
+    "port",
+    "posa",
+    "pose",
+    "pots",
+    "pour",
+    "pres",
+    "pria",
+    "prie",
+    "pris",
+    "prix",
+    "puce",
+    "puis",
+    "pull",
+    "pure",
+    "purs",
+    "quai",
+    "race",
+    "rage",
+    "rang",
+    "rare",
+    "rata",
+    "rate",
+    "rats",
+    "ravi",
+    "recu",
+    "reel",
+    "rend",
+    "reva",
+    "reve",
+    "rien",
+    "riez",
+    "rire",
+    "rive",
+    "robe",
+    "rocs",
+    "rois",
+    "role",
+    "rond",
+    "rose",
+    "roue",
+    "roux",
+    "rude",
+    "rues",
+    "ruse",
+    "sacs",
+    "sage",
+    "sain",
+    "sais",
+    "sait",
+    "sale",
+    "sang",
+    "sans",
+    "saut",
+    "scie",
+    "seau",
+    "secs",
+    "sels",
+    "sens",
+    "sept",
+    "sera",
+    "seul",
+    "site",
+    "skis",
+    "soie",
+    "soif",
+    "soir",
+    "sols",
+    "sons",
+    "sont",
+    "sors",
+    "sort",
+    "sots",
+    "sous",
+    "suca",
+    "suce",
+    "suds",
+    "suis",
+    "suit",
+    "sure",
+    "surs",
+    "tant",
+    "tard",
+    "taxi",
+    "tele",
+    "tete",
+    "thes",
+    "tira",
+    "tire",
+    "toit",
+    "tour",
+    "tout",
+    "toux",
+    "tres",
+    "trop",
+    "trou",
+    "truc",
+    "tuai",
+    "tuas",
+    "tube",
+    "tuee",
+    "tuer",
+    "tues",
+    "tuez",
+    "type",
+    "usai",
+    "usas",
+    "usee",
+    "user",
+    "uses",
+    "usez",
+    "vaut",
+    "veau",
+    "vecu",
+    "velo",
+    "vend",
+    "vent",
+    "venu",
+    "vers",
+    "vert",
+    "veut",
+    "veux",
+    "vide",
+    "vies",
+    "vifs",
+    "vins",
+    "vite",
+    "vive",
+    "voeu",
+    "voie",
+    "voir",
+    "vois",
+    "voit",
+    "voix",
+    "vola",
+    "vole",
+    "vols",
+    "vont",
+    "vota",
+    "vote",
+    "vous",
+    "vrai",
+    "vues",
+    "yeux",
+    "zero",
+    "zone"
+};
+
+
+pub static FIVE_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "abris",
+    "acces",
+    "achat",
+    "acier",
+    "actes",
+    "actif",
+    "adieu",
+    "adora",
+    "adore",
+    "agent",
+    "agile",
+    "aidai",
+    "aidas",
+    "aidee",
+    "aider",
+    "aides",
+    "aidez",
+    "aigle",
+    "aigue",
+    "aigus",
+    "ailes",
+    "aimai",
+    "aimas",
+    "aimee",
+    "aimer",
+    "aimes",
+    "aimez",
+    "aises",
+    "album",
+    "allee",
+    "aller",
+    "allez",
+    "amant",
+    "amena",
+    "amene",
+    "amere",
+    "amers",
+    "amies",
+    "amour",
+    "ample",
+    "amusa",
+    "amuse",
+    "anges",
+    "angle",
+    "anime",
+    "annee",
+    "appel",
+    "appui",
+    "arbre",
+    "arche",
+    "armee",
+    "armes",
+    "arret",
+    "asile",
+    "atout",
+    "aubes",
+    "aurai",
+    "auras",
+    "aurez",
+    "aussi",
+    "autos",
+    "autre",
+    "avais",
+    "avait",
+    "avant",
+    "aviez",
+    "avion",
+    "avoir",
+    "avons",
+    "avril",
+    "ayant",
+    "azurs",
+    "bague",
+    "baies",
+    "bains",
+    "balai",
+    "balle",
+    "bancs",
+    "bande",
+    "barbe",
+    "barre",
+    "bases",
+    "basse",
+    "batir",
+    "baton",
+    "bazar",
+    "beaux",
+    "bebes",
+    "belle",
+    "betes",
+    "biche",
+    "bidon",
+    "biens",
+    "bijou",
+    "bille",
+    "bises",
+    "bisou",
+    "blanc",
+    "bleue",
+    "bleus",
+    "blocs",
+    "blond",
+    "boeuf",
+    "boire",
+    "boite",
+    "bombe",
+    "bonds",
+    "bonne",
+    "bords",
+    "bosse",
+    "botte",
+    "boues",
+    "boule",
+    "bouts",
+    "boxes",
+    "brefs",
+    "breve",
+    "brins",
+    "brise",
+    "bruit",
+    "brula",
+    "brule",
+    "brume",
+    "brune",
+    "bruns",
+    "bulle",
+    "buvez",
+    "cacha",
+    "cache",
+    "cadre",
+    "cafes",
+    "cages",
+    "calma",
+    "calme",
+    "camps",
+    "canal",
+    "canne",
+    "canot",
+    "capot",
+    "carre",
+    "carte",
+    "cases",
+    "cassa",
+    "casse",
+    "causa",
+    "cause",
+    "caves",
+    "celle",
+    "celui",
+    "cerfs",
+    "chair",
+    "champ",
+    "chant",
+    "chats",
+    "chaud",
+    "chefs",
+    "chene",
+    "chere",
+    "chers",
+    "chice",
+    "chics",
+    "chien",
+    "choix",
+    "chose",
+    "choux",
+    "chuta",
+    "chute",
+    "cieux",
+    "clair",
+    "clous",
+    "clown",
+    "codes",
+    "coeur",
+    "cogna",
+    "cogne",
+    "coins",
+    "colis",
+    "colla",
+    "colle",
+    "comme",
+    "comte",
+    "connu",
+    "conte",
+    "copie",
+    "corde",
+    "corps",
+    "cotes",
+    "coton",
+    "coude",
+    "coula",
+    "coule",
+    "coupa",
+    "coupe",
+    "coups",
+    "cours",
+    "court",
+    "couru",
+    "couta",
+    "coute",
+    "crabe",
+    "craie",
+    "creme",
+    "crepe",
+    "creux",
+    "criai",
+    "crias",
+    "criee",
+    "crier",
+    "cries",
+    "criez",
+    "crime",
+    "crise",
+    "crois",
+    "croit",
+    "croix",
+    "cruel",
+    "cuire",
+    "cuirs",
+    "cures",
+    "cygne",
+    "dames",
+    "dansa",
+    "danse",
+    "dates",
+    "debut",
+    "defis",
+    "degre",
+    "delai",
+    "dents",
+    "desir",
+    "dette",
+    "devez",
+    "dieux",
+    "digne",
+    "dinai",
+    "dinas",
+    "dinde",
+    "dinee",
+    "diner",
+    "dines",
+    "dinez",
+    "divin",
+    "doigt",
+    "donna",
+    "donne",
+    "dorai",
+    "doras",
+    "doree",
+    "dorer",
+    "dores",
+    "dorez",
+    "douce",
+    "douta",
+    "doute",
+    "douze",
+    "draps",
+    "droit",
+    "drole",
+    "dunes",
+    "durai",
+    "duras",
+    "duree",
+    "durer",
+    "dures",
+    "durez",
+    "ecole",
+    "ecran",
+    "ecris",
+    "ecrit",
+    "effet",
+    "egale",
+    "egaux",
+    "elans",
+    "eleva",
+    "eleve",
+    "email",
+    "encre",
+    "enfin",
+    "ennui",
+    "entra",
+    "entre",
+    "envie",
+    "epais",
+    "epees",
+    "epela",
+    "epele",
+    "epice",
+    "epine",
+    "essai",
+    "etage",
+    "etais",
+    "etait",
+    "etang",
+    "etats",
+    "etiez",
+    "etude",
+    "evier",
+    "evita",
+    "evite",
+    "exact",
+    "exils",
+    "fable",
+    "faces",
+    "facha",
+    "fache",
+    "faims",
+    "faire",
+    "faute",
+    "femme",
+    "ferai",
+    "ferez",
+    "ferma",
+    "ferme",
+    "fetai",
+    "fetas",
+    "fetee",
+    "feter",
+    "fetes",
+    "fetez",
+    "feves",
+    "fiche",
+    "fiere",
+    "fiers",
+    "files",
+    "filet",
+    "fille",
+    "films",
+    "final",
+    "fines",
+    "finir",
+    "finis",
+    "finit",
+    "fixai",
+    "fixas",
+    "fixee",
+    "fixer",
+    "fixes",
+    "fixez",
+    "fleur",
+    "flute",
+    "foire",
+    "folie",
+    "folle",
+    "fonds",
+    "force",
+    "foret",
+    "forma",
+    "forme",
+    "forts",
+    "fosse",
+    "foule",
+    "fours",
+    "frais",
+    "franc",
+    "frere",
+    "froid",
+    "front",
+    "fruit",
+    "fumai",
+    "fumas",
+    "fumee",
+    "fumer",
+    "fumes",
+    "fumez",
+    "fusee",
+    "fusil",
+    "futur",
+    "gagna",
+    "gagne",
+    "gaies",
+    "gants",
+    "garda",
+    "garde",
+    "gares",
+    "gatai",
+    "gatas",
+    "gatee",
+    "gater",
+    "gates",
+    "gatez",
+    "gazon",
+    "genai",
+    "genas",
+    "genee",
+    "gener",
+    "genes",
+    "genez",
+    "genie",
+    "genou",
+    "genre",
+    "geste",
+    "gilet",
+    "glace",
+    "gland",
+    "gomme",
+    "gorge",
+    "gouta",
+    "goute",
+    "gouts",
+    "grain",
+    "grand",
+    "grave",
+    "grise",
+    "guepe",
+    "guide",
+    "habit",
+    "hache",
+    "haies",
+    "haute",
+    "hauts",
+    "herbe",
+    "heros",
+    "heure",
+    "hibou",
+    "hiver",
+    "homme",
+    "honte",
+    "hotel",
+    "huile",
+    "hurla",
+    "hurle",
+    "hutte",
+    "idees",
+    "idiot",
+    "igloo",
+    "image",
+    "impot",
+    "iront",
+    "jambe",
+    "jaune",
+    "jeudi",
+    "jeune",
+    "joies",
+    "jolie",
+    "jolis",
+    "jouai",
+    "jouas",
+    "jouee",
+    "jouer",
+    "joues",
+    "jouet",
+    "jouez",
+    "jours",
+    "juges",
+    "jupes",
+    "jurai",
+    "juras",
+    "juree",
+    "jurer",
+    "jures",
+    "jurez",
+    "juste",
+    "kayak",
+    "laide",
+    "laids",
+    "laine",
+    "laits",
+    "lampe",
+    "lanca",
+    "lance",
+    "lapin",
+    "large",
+    "larme",
+    "lavai",
+    "lavas",
+    "lavee",
+    "laver",
+    "laves",
+    "lavez",
+    "lecon",
+    "leger",
+    "lente",
+    "lents",
+    "levai",
+    "levas",
+    "levee",
+    "lever",
+    "leves",
+    "levez",
+    "levre",
+    "libre",
+    "liens",
+    "lieux",
+    "ligne",
+    "limes",
+    "linge",
+    "lions",
+    "lisez",
+    "livra",
+    "livre",
+    "longs",
+    "louai",
+    "louas",
+    "louee",
+    "louer",
+    "loues",
+    "louez",
+    "loupe",
+    "loups",
+    "lourd",
+    "loyal",
+    "lundi",
+    "lunes",
+    "lutin",
+    "luxes",
+    "lycee",
+    "macon",
+    "mains",
+    "maire",
+    "malin",
+    "maman",
+    "mange",
+    "mardi",
+    "mares",
+    "marin",
+    "maris",
+    "match",
+    "matin",
+    "melon",
+    "menai",
+    "menas",
+    "menee",
+    "mener",
+    "menes",
+    "menez",
+    "menus",
+    "merci",
+    "meres",
+    "merle",
+    "metal",
+    "metre",
+    "midis",
+    "miels",
+    "mieux",
+    "mille",
+    "mince",
+    "mines",
+    "modes",
+    "moins",
+    "molle",
+    "momie",
+    "monde",
+    "monta",
+    "monte",
+    "mordu",
+    "morts",
+    "moyen",
+    "muets",
+    "mures",
+    "musee",
+    "nagea",
+    "nagee",
+    "nager",
+    "nages",
+    "nagez",
+    "naifs",
+    "naive",
+    "nappe",
+    "natal",
+    "neige",
+    "nerfs",
+    "neufs",
+    "neuve",
+    "neveu",
+    "niece",
+    "noble",
+    "noces",
+    "noels",
+    "noeud",
+    "noire",
+    "noirs",
+    "notai",
+    "notas",
+    "notee",
+    "noter",
+    "notes",
+    "notez",
+    "nuage",
+    "nuits",
+    "nulle",
+    "obeir",
+    "objet",
+    "ocean",
+    "odeur",
+    "oeufs",
+    "offre",
+    "olive",
+    "ombre",
+    "oncle",
+    "ongle",
+    "opera",
+    "orage",
+    "ordre",
+    "orgue",
+    "osais",
+    "osait",
+    "osant",
+    "osees",
+    "osent",
+    "osera",
+    "osiez",
+    "osons",
+    "otage",
+    "outil",
+    "ouvre",
+    "pages",
+    "pains",
+    "pales",
+    "panne",
+    "papas",
+    "parce",
+    "parcs",
+    "parla",
+    "parle",
+    "parmi",
+    "parti",
+    "parts",
+    "passa",
+    "passe",
+    "pates",
+    "patte",
+    "pause",
+    "paves",
+    "payai",
+    "payas",
+    "payee",
+    "payer",
+    "payes",
+    "payez",
+    "peaux",
+    "pecha",
+    "peche",
+    "peine",
+    "peint",
+    "pelle",
+    "pensa",
+    "pense",
+    "pente",
+    "perds",
+    "perdu",
+    "perle",
+    "pesai",
+    "pesas",
+    "pesee",
+    "peser",
+    "peses",
+    "pesez",
+    "petit",
+    "peurs",
+    "phare",
+    "photo",
+    "piano",
+    "piece",
+    "pieds",
+    "piege",
+    "piles",
+    "piqua",
+    "pique",
+    "pires",
+    "piste",
+    "placa",
+    "place",
+    "plage",
+    "plait",
+    "plans",
+    "plate",
+    "plats",
+    "plein",
+    "pleut",
+    "pliai",
+    "plias",
+    "pliee",
+    "plier",
+    "plies",
+    "pliez",
+    "pluie",
+    "plume",
+    "pneus",
+    "poche",
+    "poele",
+    "poeme",
+    "poete",
+    "poids",
+    "poils",
+    "poing",
+    "point",
+    "poire",
+    "poles",
+    "polie",
+    "polis",
+    "pomme",
+    "pompe",
+    "ponts",
+    "porcs",
+    "porta",
+    "porte",
+    "ports",
+    "posai",
+    "posas",
+    "posee",
+    "poser",
+    "poses",
+    "posez",
+    "poste",
+    "poule",
+    "prend",
+    "preta",
+    "prete",
+    "priai",
+    "prias",
+    "priee",
+    "prier",
+    "pries",
+    "priez",
+    "prise",
+    "prune",
+    "puces",
+    "puits",
+    "pulls",
+    "punir",
+    "puree",
+    "pures",
+    "quais",
+    "quand",
+    "quart",
+    "queue",
+    "races",
+    "radio",
+    "radis",
+    "rages",
+    "range",
+    "rangs",
+    "rares",
+    "ratai",
+    "ratas",
+    "ratee",
+    "rater",
+    "rates",
+    "ratez",
+    "ravie",
+    "ravis",
+    "rayon",
+    "recit",
+    "reels",
+    "regle",
+    "reine",
+    "rends",
+    "rendu",
+    "repas",
+    "repos",
+    "resta",
+    "reste",
+    "revai",
+    "revas",
+    "revee",
+    "rever",
+    "reves",
+    "revez",
+    "revue",
+    "rhume",
+    "riche",
+    "rient",
+    "rions",
+    "rires",
+    "rives",
+    "robes",
+    "robot",
+    "roche",
+    "roles",
+    "roman",
+    "ronde",
+    "ronds",
+    "roses",
+    "roues",
+    "rouge",
+    "roula",
+    "roule",
+    "route",
+    "ruban",
+    "rudes",
+    "ruses",
+    "sable",
+    "sabot",
+    "sages",
+    "saine",
+    "sains",
+    "saint",
+    "sales",
+    "salir",
+    "salle",
+    "salon",
+    "salua",
+    "salue",
+    "sangs",
+    "sante",
+    "sapin",
+    "sauce",
+    "saule",
+    "sauta",
+    "saute",
+    "sauts",
+    "sauva",
+    "sauve",
+    "savez",
+    "savon",
+    "scene",
+    "scies",
+    "seaux",
+    "seche",
+    "seize",
+    "selon",
+    "serai",
+    "seras",
+    "serez",
+    "serra",
+    "serre",
+    "seuil",
+    "seule",
+    "seuls",
+    "siege",
+    "signa",
+    "signe",
+    "singe",
+    "sinon",
+    "sirop",
+    "sites",
+    "soeur",
+    "soies",
+    "soifs",
+    "soirs",
+    "somme",
+    "songe",
+    "sonna",
+    "sonne",
+    "sorts",
+    "sotte",
+    "souci",
+    "soupe",
+    "sourd",
+    "sport",
+    "stade",
+    "style",
+    "stylo",
+    "sucai",
+    "sucas",
+    "sucee",
+    "sucer",
+    "suces",
+    "sucez",
+    "sucre",
+    "sueur",
+    "suite",
+    "suivi",
+    "sujet",
+    "sures",
+    "table",
+    "tache",
+    "tante",
+    "tapis",
+    "tarda",
+    "tarde",
+    "tarte",
+    "tasse",
+    "taupe",
+    "taxis",
+    "teles",
+    "temps",
+    "tenez",
+    "tenir",
+    "tente",
+    "terre",
+    "tetes",
+    "texte",
+    "tiede",
+    "tiens",
+    "tient",
+    "tigre",
+    "tirai",
+    "tiras",
+    "tiree",
+    "tirer",
+    "tires",
+    "tirez",
+    "tissu",
+    "titre",
+    "toile",
+    "toits",
+    "tomba",
+    "tombe",
+    "tordu",
+    "total",
+    "tours",
+    "toute",
+    "touts",
+    "traca",
+    "trace",
+    "train",
+    "trait",
+    "tribu",
+    "trois",
+    "tronc",
+    "trous",
+    "trucs",
+    "tuais",
+    "tuait",
+    "tuant",
+    "tubes",
+    "tuees",
+    "tuent",
+    "tuera",
+    "tuiez",
+    "tuile",
+    "tuons",
+    "tuyau",
+    "types",
+    "usage",
+    "usais",
+    "usait",
+    "usant",
+    "usees",
+    "usent",
+    "usera",
+    "usiez",
+    "usine",
+    "usons",
+    "utile",
+    "vache",
+    "vague",
+    "valsa",
+    "valse",
+    "vaste",
+    "veaux",
+    "veine",
+    "velos",
+    "vends",
+    "vendu",
+    "venez",
+    "venir",
+    "vents",
+    "venue",
+    "verre",
+    "versa",
+    "verse",
+    "verte",
+    "verts",
+    "veste",
+    "vides",
+    "viens",
+    "vient",
+    "vieux",
+    "ville",
+    "vitre",
+    "vives",
+    "vivez",
+    "vivre",
+    "voeux",
+    "voici",
+    "voies",
+    "voila",
+    "voile",
+    "volai",
+    "volas",
+    "volee",
+    "voler",
+    "voles",
+    "volez",
+    "votai",
+    "votas",
+    "votee",
+    "voter",
+    "votes",
+    "votez",
+    "voyez",
+    "vraie",
+    "vrais",
+    "wagon",
+    "zebre",
+    "zeros",
+    "zones"
+};
+
+
+pub static SIX_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "absent",
+    "accent",
+    "accord",
+    "achats",
+    "aciers",
+    "acteur",
+    "actifs",
+    "action",
+    "active",
+    "adieux",
+    "admira",
+    "admire",
+    "adorai",
+    "adoras",
+    "adoree",
+    "adorer",
+    "adores",
+    "adorez",
+    "adroit",
+    "adulte",
+    "agence",
+    "agents",
+    "agiles",
+    "agneau",
+    "agrafe",
+    "aidais",
+    "aidait",
+    "aidant",
+    "aidees",
+    "aident",
+    "aidera",
+    "aidiez",
+    "aidons",
+    "aigles",
+    "aigues",
+    "aimais",
+    "aimait",
+    "aimant",
+    "aimees",
+    "aiment",
+    "aimera",
+    "aimiez",
+    "aimons",
+    "ajouta",
+    "ajoute",
+    "albums",
+    "alcool",
+    "allais",
+    "allait",
+    "allees",
+    "allons",
+    "alluma",
+    "allume",
+    "allure",
+    "alpage",
+    "amande",
+    "amants",
+    "amenai",
+    "amenas",
+    "amende",
+    "amenee",
+    "amener",
+    "amenes",
+    "amenez",
+    "ameres",
+    "amitie",
+    "amours",
+    "amples",
+    "amusai",
+    "amusas",
+    "amusee",
+    "amuser",
+    "amuses",
+    "amusez",
+    "ananas",
+    "ancien",
+    "angles",
+    "animal",
+    "animes",
+    "anneau",
+    "annees",
+    "appels",
+    "appuie",
+    "appuis",
+    "appuya",
+    "arbres",
+    "arches",
+    "argent",
+    "argile",
+    "armees",
+    "arreta",
+    "arrete",
+    "arrets",
+    "arriva",
+    "arrive",
+    "arrosa",
+    "arrose",
+    "asiles",
+    "aspect",
+    "atouts",
+    "attend",
+    "aurait",
+    "aurons",
+    "auront",
+    "auteur",
+    "avanca",
+    "avance",
+    "avenir",
+    "avenue",
+    "avions",
+    "avocat",
+    "avoine",
+    "avrils",
+    "bagage",
+    "bagues",
+    "baiser",
+    "baissa",
+    "baisse",
+    "balais",
+    "balaya",
+    "balaye",
+    "balcon",
+    "balles",
+    "ballon",
+    "banane",
+    "bandes",
+    "banque",
+    "barbes",
+    "barque",
+    "barres",
+    "basses",
+    "bassin",
+    "bateau",
+    "batons",
+    "bavard",
+    "bazars",
+    "beaute",
+    "belles",
+    "berger",
+    "besoin",
+    "beurre",
+    "biches",
+    "bidons",
+    "bijoux",
+    "billes",
+    "billet",
+    "bisous",
+    "blague",
+    "blancs",
+    "blessa",
+    "blesse",
+    "bleues",
+    "bleuet",
+    "blonde",
+    "blonds",
+    "blouse",
+    "boeufs",
+    "boites",
+    "bombes",
+    "bonbon",
+    "bonnes",
+    "bonnet",
+    "bosses",
+    "bottes",
+    "bouche",
+    "boucle",
+    "bougie",
+    "boules",
+    "bourse",
+    "bouton",
+    "brebis",
+    "breves",
+    "brilla",
+    "brille",
+    "brique",
+    "brises",
+    "brossa",
+    "brosse",
+    "bruits",
+    "brulai",
+    "brulas",
+    "brulee",
+    "bruler",
+    "brules",
+    "brulez",
+    "brumes",
+    "brunes",
+    "budget",
+    "buffet",
+    "buffle",
+    "bulles",
+    "bureau",
+    "buvons",
+    "cabane",
+    "cabine",
+    "cachai",
+    "cachas",
+    "cachee",
+    "cacher",
+    "caches",
+    "cachez",
+    "cadeau",
+    "cadres",
+    "cahier",
+    "caisse",
+    "calcul",
+    "calmai",
+    "calmas",
+    "calmee",
+    "calmer",
+    "calmes",
+    "calmez",
+    "camera",
+    "camion",
+    "canape",
+    "canard",
+    "canaux",
+    "cannes",
+    "canots",
+    "capots",
+    "carafe",
+    "carnet",
+    "carres",
+    "cartes",
+    "carton",
+    "casque",
+    "cassai",
+    "cassas",
+    "cassee",
+    "casser",
+    "casses",
+    "cassez",
+    "castor",
+    "causai",
+    "causas",
+    "causee",
+    "causer",
+    "causes",
+    "causez",
+    "celeri",
+    "cendre",
+    "centre",
+    "cercle",
+    "cerise",
+    "certes",
+    "chaine",
+    "chairs",
+    "chaise",
+    "champs",
+    "chance",
+    "change",
+    "chanta",
+    "chante",
+    "chants",
+    "chaque",
+    "charge",
+    "charme",
+    "chassa",
+    "chasse",
+    "chaton",
+    "chaude",
+    "chauds",
+    "chemin",
+    "chenes",
+    "cheque",
+    "cheres",
+    "cheval",
+    "cheveu",
+    "chevre",
+    "chices",
+    "chiens",
+    "chimie",
+    "choisi",
+    "choses",
+    "chutai",
+    "chutas",
+    "chutee",
+    "chuter",
+    "chutes",
+    "chutez",
+    "cierge",
+    "cigale",
+    "cinema",
+    "cirque",
+    "ciseau",
+    "citron",
+    "claire",
+    "clairs",
+    "clarte",
+    "classa",
+    "classe",
+    "client",
+    "climat",
+    "cloche",
+    "clowns",
+    "cochon",
+    "coeurs",
+    "coffre",
+    "cognai",
+    "cognas",
+    "cognee",
+    "cogner",
+    "cognes",
+    "cognez",
+    "colere",
+    "collai",
+    "collas",
+    "collee",
+    "coller",
+    "colles",
+    "collez",
+    "combat",
+    "commun",
+    "compta",
+    "compte",
+    "comtes",
+    "confia",
+    "confie",
+    "contes",
+    "contre",
+    "copain",
+    "copies",
+    "cordes",
+    "cotons",
+    "coucha",
+    "couche",
+    "coudes",
+    "coulai",
+    "coulas",
+    "coulee",
+    "couler",
+    "coules",
+    "coulez",
+    "coupai",
+    "coupas",
+    "coupee",
+    "couper",
+    "coupes",
+    "coupez",
+    "courba",
+    "courbe",
+    "courez",
+    "courir",
+    "course",
+    "courte",
+    "courts",
+    "coutai",
+    "coutas",
+    "coutee",
+    "couter",
+    "coutes",
+    "coutez",
+    "crabes",
+    "craies",
+    "crayon",
+    "cremes",
+    "crepes",
+    "creusa",
+    "creuse",
+    "criais",
+    "criait",
+    "criant",
+    "criees",
+    "crient",
+    "criera",
+    "criiez",
+    "crimes",
+    "crions",
+    "crises",
+    "croire",
+    "croute",
+    "croyez",
+    "cruels",
+    "cuisse",
+    "cygnes",
+    "danger",
+    "dansai",
+    "dansas",
+    "dansee",
+    "danser",
+    "danses",
+    "dansez",
+    "debout",
+    "debuts",
+    "decida",
+    "decide",
+    "decora",
+    "decore",
+    "dedans",
+    "defaut",
+    "degage",
+    "degres",
+    "dehors",
+    "delais",
+    "demain",
+    "depart",
+    "deposa",
+    "depose",
+    "depuis",
+    "desert",
+    "desira",
+    "desire",
+    "desirs",
+    "dessin",
+    "dessus",
+    "destin",
+    "detail",
+    "dettes",
+    "devait",
+    "devant",
+    "devina",
+    "devine",
+    "devoir",
+    "devons",
+    "diable",
+    "dictee",
+    "dignes",
+    "dinais",
+    "dinait",
+    "dinant",
+    "dindes",
+    "dinees",
+    "dinent",
+    "dinera",
+    "diners",
+    "diniez",
+    "dinons",
+    "direct",
+    "disait",
+    "disent",
+    "disons",
+    "disque",
+    "divine",
+    "divins",
+    "doigts",
+    "donnai",
+    "donnas",
+    "donnee",
+    "donner",
+    "donnes",
+    "donnez",
+    "dorais",
+    "dorait",
+    "dorant",
+    "dorees",
+    "dorent",
+    "dorera",
+    "doriez",
+    "dormez",
+    "dormir",
+    "dorons",
+    "douane",
+    "douces",
+    "douche",
+    "doutai",
+    "doutas",
+    "doutee",
+    "douter",
+    "doutes",
+    "doutez",
+    "dragon",
+    "droits",
+    "droles",
+    "durais",
+    "durait",
+    "durant",
+    "durees",
+    "durent",
+    "durera",
+    "duriez",
+    "durons",
+    "eclair",
+    "ecoles",
+    "ecorce",
+    "ecouta",
+    "ecoute",
+    "ecrans",
+    "ecrire",
+    "ecurie",
+    "effets",
+    "effort",
+    "egales",
+    "eglise",
+    "elevai",
+    "elevas",
+    "elevee",
+    "elever",
+    "eleves",
+    "elevez",
+    "emails",
+    "emmena",
+    "emmene",
+    "empire",
+    "emploi",
+    "encore",
+    "encres",
+    "enfant",
+    "enleva",
+    "enleve",
+    "ennemi",
+    "ennuis",
+    "enorme",
+    "entend",
+    "entier",
+    "entrai",
+    "entras",
+    "entree",
+    "entrer",
+    "entres",
+    "entrez",
+    "envies",
+    "envola",
+    "envole",
+    "epaule",
+    "epelai",
+    "epelas",
+    "epelee",
+    "epeler",
+    "epeles",
+    "epelez",
+    "epices",
+    "epines",
+    "eponge",
+    "epoque",
+    "equipe",
+    "erreur",
+    "espace",
+    "espece",
+    "espera",
+    "espere",
+    "espoir",
+    "esprit",
+    "essais",
+    "essuie",
+    "essuya",
+    "etages",
+    "etangs",
+    "etions",
+    "etoile",
+    "etonna",
+    "etonne",
+    "etroit",
+    "etudes",
+    "etudia",
+    "etudie",
+    "eviers",
+    "evitai",
+    "evitas",
+    "evitee",
+    "eviter",
+    "evites",
+    "evitez",
+    "exacte",
+    "exacts",
+    "examen",
+    "excuse",
+    "exista",
+    "existe",
+    "expose",
+    "fables",
+    "facade",
+    "fachai",
+    "fachas",
+    "fachee",
+    "facher",
+    "faches",
+    "fachez",
+    "facile",
+    "faible",
+    "faites",
+    "farine",
+    "faucon",
+    "fausse",
+    "fautes",
+    "faveur",
+    "favori",
+    "femmes",
+    "fermai",
+    "fermas",
+    "fermee",
+    "fermer",
+    "fermes",
+    "fermez",
+    "feroce",
+    "feront",
+    "fetais",
+    "fetait",
+    "fetant",
+    "fetees",
+    "fetent",
+    "fetera",
+    "fetiez",
+    "fetons",
+    "fiches",
+    "fidele",
+    "fieres",
+    "fievre",
+    "figure",
+    "filets",
+    "filles",
+    "finale",
+    "finaux",
+    "fixais",
+    "fixait",
+    "fixant",
+    "fixees",
+    "fixent",
+    "fixera",
+    "fixiez",
+    "fixons",
+    "flacon",
+    "flamme",
+    "flaque",
+    "fleche",
+    "fleurs",
+    "fleuve",
+    "flocon",
+    "flotta",
+    "flotte",
+    "flutes",
+    "foires",
+    "folies",
+    "folles",
+    "forces",
+    "forets",
+    "formai",
+    "formas",
+    "formee",
+    "former",
+    "formes",
+    "formez",
+    "fosses",
+    "foudre",
+    "foules",
+    "fourmi",
+    "fraise",
+    "francs",
+    "frappa",
+    "frappe",
+    "freres",
+    "froide",
+    "froids",
+    "fronts",
+    "fruits",
+    "fumais",
+    "fumait",
+    "fumant",
+    "fumees",
+    "fument",
+    "fumera",
+    "fumier",
+    "fumiez",
+    "fumons",
+    "fusain",
+    "fusees",
+    "fusils",
+    "futurs",
+    "gagnai",
+    "gagnas",
+    "gagnee",
+    "gagner",
+    "gagnes",
+    "gagnez",
+    "garage",
+    "garcon",
+    "gardai",
+    "gardas",
+    "gardee",
+    "garder",
+    "gardes",
+    "gardez",
+    "gatais",
+    "gatait",
+    "gatant",
+    "gateau",
+    "gatees",
+    "gatent",
+    "gatera",
+    "gatiez",
+    "gatons",
+    "gazons",
+    "genais",
+    "genait",
+    "genant",
+    "genees",
+    "genent",
+    "genera",
+    "genies",
+    "geniez",
+    "genons",
+    "genoux",
+    "genres",
+    "gentil",
+    "gestes",
+    "gilets",
+    "girafe",
+    "glaces",
+    "glacon",
+    "glands",
+    "glissa",
+    "glisse",
+    "gloire",
+    "gommes",
+    "gorges",
+    "gourde",
+    "goutai",
+    "goutas",
+    "goutee",
+    "gouter",
+    "goutes",
+    "goutez",
+    "goutte",
+    "gradin",
+    "graine",
+    "grains",
+    "grande",
+    "grands",
+    "grange",
+    "grappe",
+    "grasse",
+    "graves",
+    "griffe",
+    "grille",
+    "grimpa",
+    "grimpe",
+    "grises",
+    "gronda",
+    "gronde",
+    "grosse",
+    "grotte",
+    "groupe",
+    "guepes",
+    "guerir",
+    "guerre",
+    "guides",
+    "habile",
+    "habita",
+    "habite",
+    "habits",
+    "haches",
+    "hangar",
+    "hasard",
+    "hautes",
+    "herbes",
+    "hesita",
+    "hesite",
+    "heures",
+    "hiboux",
+    "hivers",
+    "homard",
+    "hommes",
+    "hontes",
+    "hotels",
+    "huiles",
+    "huitre",
+    "humain",
+    "humeur",
+    "humide",
+    "humour",
+    "hurlai",
+    "hurlas",
+    "hurlee",
+    "hurler",
+    "hurles",
+    "hurlez",
+    "huttes",
+    "idiote",
+    "idiots",
+    "igloos",
+    "ignora",
+    "ignore",
+    "images",
+    "impots",
+    "indice",
+    "invita",
+    "invite",
+    "ivoire",
+    "jaloux",
+    "jamais",
+    "jambes",
+    "jambon",
+    "jardin",
+    "jaunes",
+    "jeudis",
+    "jeunes",
+    "jolies",
+    "jouais",
+    "jouait",
+    "jouant",
+    "jouees",
+    "jouent",
+    "jouera",
+    "jouets",
+    "joueur",
+    "jouiez",
+    "jouons",
+    "joyeux",
+    "jument",
+    "jurais",
+    "jurait",
+    "jurant",
+    "jurees",
+    "jurent",
+    "jurera",
+    "juriez",
+    "jurons",
+    "jusque",
+    "justes",
+    "kayaks",
+    "lagune",
+    "laides",
+    "laines",
+    "laissa",
+    "laisse",
+    "lampes",
+    "lancai",
+    "lancas",
+    "lancee",
+    "lancer",
+    "lances",
+    "lancez",
+    "langue",
+    "lapins",
+    "larges",
+    "larmes",
+    "lavabo",
+    "lavais",
+    "lavait",
+    "lavant",
+    "lavees",
+    "lavent",
+    "lavera",
+    "laviez",
+    "lavons",
+    "lecons",
+    "legere",
+    "legers",
+    "legume",
+    "lentes",
+    "lettre",
+    "levais",
+    "levait",
+    "levant",
+    "levees",
+    "levent",
+    "levera",
+    "leviez",
+    "levons",
+    "levres",
+    "lezard",
+    "libres",
+    "lievre",
+    "lignes",
+    "limace",
+    "linges",
+    "lisait",
+    "lisent",
+    "lisons",
+    "livrai",
+    "livras",
+    "livree",
+    "livrer",
+    "livres",
+    "livrez",
+    "loisir",
+    "louais",
+    "louait",
+    "louant",
+    "louees",
+    "louent",
+    "louera",
+    "louiez",
+    "louons",
+    "loupes",
+    "lourde",
+    "lourds",
+    "loyale",
+    "loyaux",
+    "lundis",
+    "lutins",
+    "lycees",
+    "macons",
+    "madame",
+    "maigre",
+    "maires",
+    "mairie",
+    "maison",
+    "maitre",
+    "malade",
+    "malgre",
+    "malins",
+    "mamans",
+    "manche",
+    "manege",
+    "mangea",
+    "mangee",
+    "manger",
+    "manges",
+    "mangez",
+    "manqua",
+    "manque",
+    "marbre",
+    "marcha",
+    "marche",
+    "mardis",
+    "marins",
+    "marqua",
+    "marque",
+    "marron",
+    "masque",
+    "matchs",
+    "matins",
+    "melons",
+    "membre",
+    "menace",
+    "menais",
+    "menait",
+    "menant",
+    "menees",
+    "menent",
+    "menera",
+    "meniez",
+    "menons",
+    "menton",
+    "mercis",
+    "merita",
+    "merite",
+    "merles",
+    "mesura",
+    "mesure",
+    "metaux",
+    "metier",
+    "metres",
+    "mettez",
+    "mettre",
+    "meuble",
+    "milieu",
+    "milles",
+    "minces",
+    "minute",
+    "miroir",
+    "modele",
+    "moitie",
+    "molles",
+    "mollet",
+    "moment",
+    "momies",
+    "mondes",
+    "montai",
+    "montas",
+    "montee",
+    "monter",
+    "montes",
+    "montez",
+    "montra",
+    "montre",
+    "morale",
+    "mordre",
+    "moteur",
+    "mouche",
+    "moulin",
+    "mousse",
+    "mouton",
+    "moyens",
+    "muette",
+    "muguet",
+    "muscle",
+    "musees",
+    "nageai",
+    "nageas",
+    "nagees",
+    "nagent",
+    "nagera",
+    "nagiez",
+    "naitre",
+    "naives",
+    "nappes",
+    "narine",
+    "natale",
+    "natals",
+    "nature",
+    "navire",
+    "neiges",
+    "neuves",
+    "neveux",
+    "nieces",
+    "niveau",
+    "nobles",
+    "noeuds",
+    "noires",
+    "nombre",
+    "notais",
+    "notait",
+    "notant",
+    "notees",
+    "notent",
+    "notera",
+    "notiez",
+    "notons",
+    "nuages",
+    "nuance",
+    "nulles",
+    "numero",
+    "objets",
+    "obscur",
+    "oceans",
+    "odeurs",
+    "oeuvre",
+    "offert",
+    "offres",
+    "offrir",
+    "oignon",
+    "oiseau",
+    "olives",
+    "ombres",
+    "oncles",
+    "ongles",
+    "operas",
+    "orages",
+    "orange",
+    "ordres",
+    "orgues",
+    "orteil",
+    "oserai",
+    "oseras",
+    "oserez",
+    "osions",
+    "otages",
+    "otarie",
+    "oublia",
+    "oublie",
+    "outils",
+    "ouvert",
+    "ouvrir",
+    "paille",
+    "palais",
+    "panier",
+    "pannes",
+    "papier",
+    "paquet",
+    "pareil",
+    "parent",
+    "parfum",
+    "parlai",
+    "parlas",
+    "parlee",
+    "parler",
+    "parles",
+    "parlez",
+    "parole",
+    "partez",
+    "partie",
+    "partir",
+    "passai",
+    "passas",
+    "passee",
+    "passer",
+    "passes",
+    "passez",
+    "patina",
+    "patine",
+    "patron",
+    "pattes",
+    "pauses",
+    "pauvre",
+    "payais",
+    "payait",
+    "payant",
+    "payees",
+    "payent",
+    "payera",
+    "payiez",
+    "payons",
+    "paysan",
+    "pechai",
+    "pechas",
+    "pechee",
+    "pecher",
+    "peches",
+    "pechez",
+    "pedala",
+    "pedale",
+    "peigna",
+    "peigne",
+    "peines",
+    "pelles",
+    "pensai",
+    "pensas",
+    "pensee",
+    "penser",
+    "penses",
+    "pensez",
+    "pensif",
+    "pentes",
+    "perdre",
+    "perles",
+    "pesais",
+    "pesait",
+    "pesant",
+    "pesees",
+    "pesent",
+    "pesera",
+    "pesiez",
+    "pesons",
+    "petale",
+    "petite",
+    "petits",
+    "peuple",
+    "phares",
+    "photos",
+    "phrase",
+    "pianos",
+    "pieces",
+    "pieges",
+    "pierre",
+    "pigeon",
+    "pilota",
+    "pilote",
+    "piquai",
+    "piquas",
+    "piquee",
+    "piquer",
+    "piques",
+    "piquez",
+    "pirate",
+    "pistes",
+    "placai",
+    "placas",
+    "placee",
+    "placer",
+    "places",
+    "placez",
+    "plages",
+    "plaine",
+    "plaire",
+    "planta",
+    "plante",
+    "plates",
+    "pleins",
+    "pleura",
+    "pleure",
+    "pliais",
+    "pliait",
+    "pliant",
+    "pliees",
+    "plient",
+    "pliera",
+    "pliiez",
+    "plions",
+    "plonge",
+    "pluies",
+    "plumes",
+    "plutot",
+    "poches",
+    "poeles",
+    "poemes",
+    "poesie",
+    "poetes",
+    "poings",
+    "pointe",
+    "points",
+    "poires",
+    "poivre",
+    "police",
+    "polies",
+    "pommes",
+    "pompes",
+    "portai",
+    "portas",
+    "portee",
+    "porter",
+    "portes",
+    "portez",
+    "posais",
+    "posait",
+    "posant",
+    "posees",
+    "posent",
+    "posera",
+    "posiez",
+    "posons",
+    "postes",
+    "potage",
+    "poteau",
+    "poules",
+    "poulet",
+    "poupee",
+    "pourra",
+    "poussa",
+    "pousse",
+    "poutre",
+    "pouvez",
+    "prends",
+    "prenez",
+    "presse",
+    "pretai",
+    "pretas",
+    "pretee",
+    "preter",
+    "pretes",
+    "pretez",
+    "preuve",
+    "priais",
+    "priait",
+    "priant",
+    "priees",
+    "prient",
+    "priera",
+    "priiez",
+    "prince",
+    "prions",
+    "prison",
+    "projet",
+    "propre",
+    "prouva",
+    "prouve",
+    "prunes",
+    "public",
+    "purees",
+    "quarts",
+    "quatre",
+    "queues",
+    "quinze",
+    "quitta",
+    "quitte",
+    "racine",
+    "radios",
+    "raisin",
+    "raison",
+    "rameau",
+    "ramena",
+    "ramene",
+    "rangea",
+    "rangee",
+    "ranger",
+    "ranges",
+    "rangez",
+    "rapide",
+    "ratais",
+    "ratait",
+    "ratant",
+    "ratees",
+    "ratent",
+    "ratera",
+    "ratiez",
+    "ratons",
+    "ravies",
+    "rayons",
+    "recits",
+    "reelle",
+    "reflet",
+    "refuge",
+    "regard",
+    "regles",
+    "reines",
+    "renard",
+    "rendre",
+    "rentra",
+    "rentre",
+    "repara",
+    "repare",
+    "repeta",
+    "repete",
+    "repond",
+    "reposa",
+    "repose",
+    "requin",
+    "reseau",
+    "restai",
+    "restas",
+    "restee",
+    "rester",
+    "restes",
+    "restez",
+    "retard",
+    "retour",
+    "revais",
+    "revait",
+    "revant",
+    "revees",
+    "reveil",
+    "revent",
+    "revera",
+    "reviez",
+    "revons",
+    "revues",
+    "rhumes",
+    "riches",
+    "rideau",
+    "rigola",
+    "rigole",
+    "rigolo",
+    "rivage",
+    "robots",
+    "rocher",
+    "roches",
+    "romans",
+    "rondes",
+    "roseau",
+    "rouges",
+    "rougir",
+    "roulai",
+    "roulas",
+    "roulee",
+    "rouler",
+    "roules",
+    "roulez",
+    "rousse",
+    "routes",
+    "rubans",
+    "rythme",
+    "sables",
+    "sabots",
+    "safran",
+    "saines",
+    "sainte",
+    "saints",
+    "saison",
+    "salade",
+    "salles",
+    "salons",
+    "saluai",
+    "saluas",
+    "saluee",
+    "saluer",
+    "salues",
+    "saluez",
+    "samedi",
+    "santes",
+    "sapins",
+    "sauces",
+    "saules",
+    "saumon",
+    "sautai",
+    "sautas",
+    "sautee",
+    "sauter",
+    "sautes",
+    "sautez",
+    "sauvai",
+    "sauvas",
+    "sauvee",
+    "sauver",
+    "sauves",
+    "sauvez",
+    "savait",
+    "savant",
+    "savent",
+    "savoir",
+    "savons",
+    "scenes",
+    "seches",
+    "secret",
+    "sembla",
+    "semble",
+    "separa",
+    "separe",
+    "serait",
+    "serons",
+    "seront",
+    "serrai",
+    "serras",
+    "serree",
+    "serrer",
+    "serres",
+    "serrez",
+    "seuils",
+    "seules",
+    "siecle",
+    "sieges",
+    "siffla",
+    "siffle",
+    "signai",
+    "signas",
+    "signee",
+    "signer",
+    "signes",
+    "signez",
+    "simple",
+    "singes",
+    "sirene",
+    "sirops",
+    "soeurs",
+    "soigna",
+    "soigne",
+    "soiree",
+    "soldat",
+    "soleil",
+    "sombre",
+    "sommes",
+    "sommet",
+    "songes",
+    "sonnai",
+    "sonnas",
+    "sonnee",
+    "sonner",
+    "sonnes",
+    "sonnez",
+    "sortez",
+    "sortie",
+    "sortir",
+    "sottes",
+    "soucis",
+    "soupes",
+    "souple",
+    "source",
+    "sourde",
+    "sourds",
+    "souris",
+    "sports",
+    "stades",
+    "statue",
+    "styles",
+    "stylos",
+    "subtil",
+    "sucais",
+    "sucait",
+    "sucant",
+    "sucees",
+    "sucent",
+    "sucera",
+    "suciez",
+    "sucons",
+    "sucres",
+    "sueurs",
+    "suites",
+    "suivre",
+    "sujets",
+    "tables",
+    "taches",
+    "taille",
+    "talent",
+    "tandis",
+    "tantes",
+    "tardai",
+    "tardas",
+    "tardee",
+    "tarder",
+    "tardes",
+    "tardez",
+    "tardif",
+    "tartes",
+    "tasses",
+    "taupes",
+    "temoin",
+    "tendre",
+    "tennis",
+    "tenons",
+    "tentes",
+    "terres",
+    "textes",
+    "ticket",
+    "tiedes",
+    "tigres",
+    "timbre",
+    "timide",
+    "tirais",
+    "tirait",
+    "tirant",
+    "tirees",
+    "tirent",
+    "tirera",
+    "tiriez",
+    "tiroir",
+    "tirons",
+    "tissus",
+    "titres",
+    "toiles",
+    "tomate",
+    "tombai",
+    "tombas",
+    "tombee",
+    "tomber",
+    "tombes",
+    "tombez",
+    "tondre",
+    "tordue",
+    "tordus",
+    "tortue",
+    "totaux",
+    "toucan",
+    "toucha",
+    "touche",
+    "tourna",
+    "tourne",
+    "toussa",
+    "tousse",
+    "toutes",
+    "tracai",
+    "tracas",
+    "tracee",
+    "tracer",
+    "traces",
+    "tracez",
+    "trains",
+    "traits",
+    "trajet",
+    "treize",
+    "trente",
+    "tresor",
+    "tribus",
+    "tricha",
+    "triche",
+    "tricot",
+    "triste",
+    "trompa",
+    "trompe",
+    "troncs",
+    "trotta",
+    "trotte",
+    "troupe",
+    "trouva",
+    "trouve",
+    "truite",
+    "tuerai",
+    "tueras",
+    "tuerez",
+    "tuiles",
+    "tuions",
+    "tulipe",
+    "tunnel",
+    "tuyaux",
+    "unique",
+    "urgent",
+    "usages",
+    "userai",
+    "useras",
+    "userez",
+    "usines",
+    "usions",
+    "utiles",
+    "vaches",
+    "vagues",
+    "valeur",
+    "valise",
+    "vallee",
+    "valoir",
+    "valsai",
+    "valsas",
+    "valsee",
+    "valser",
+    "valses",
+    "valsez",
+    "vapeur",
+    "vastes",
+    "veille",
+    "veines",
+    "venait",
+    "vendre",
+    "venons",
+    "ventre",
+    "verger",
+    "verite",
+    "vernis",
+    "verres",
+    "versai",
+    "versas",
+    "versee",
+    "verser",
+    "verses",
+    "versez",
+    "vertes",
+    "vestes",
+    "viande",
+    "vilain",
+    "villes",
+    "violet",
+    "violon",
+    "vipere",
+    "virage",
+    "visage",
+    "visita",
+    "visite",
+    "vitres",
+    "vivant",
+    "vivent",
+    "vivons",
+    "voient",
+    "voiles",
+    "voisin",
+    "volais",
+    "volait",
+    "volant",
+    "volcan",
+    "volees",
+    "volent",
+    "volera",
+    "voleur",
+    "voliez",
+    "volons",
+    "votais",
+    "votait",
+    "votant",
+    "votees",
+    "votent",
+    "votera",
+    "votiez",
+    "votons",
+    "voulez",
+    "voyage",
+    "voyait",
+    "voyons",
+    "vraies",
+    "wagons",
+    "yaourt",
+    "zebres"
+};
+
+
+pub static SEVEN_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "abeille",
+    "abricot",
+    "absence",
+    "absente",
+    "absents",
+    "accents",
+    "accepta",
+    "accepte",
+    "accords",
+    "accueil",
+    "acteurs",
+    "actions",
+    "actives",
+    "admirai",
+    "admiras",
+    "admiree",
+    "admirer",
+    "admires",
+    "admirez",
+    "adorais",
+    "adorait",
+    "adorant",
+    "adorees",
+    "adorent",
+    "adorera",
+    "adoriez",
+    "adorons",
+    "adresse",
+    "adroite",
+    "adroits",
+    "adultes",
+    "affaire",
+    "affiche",
+    "agences",
+    "agneaux",
+    "agrafes",
+    "aiderai",
+    "aideras",
+    "aiderez",
+    "aidions",
+    "aimable",
+    "aimants",
+    "aimerai",
+    "aimeras",
+    "aimerez",
+    "aimions",
+    "ajoutai",
+    "ajoutas",
+    "ajoutee",
+    "ajouter",
+    "ajoutes",
+    "ajoutez",
+    "alcools",
+    "aliment",
+    "allonge",
+    "allumai",
+    "allumas",
+    "allumee",
+    "allumer",
+    "allumes",
+    "allumez",
+    "allures",
+    "alpages",
+    "amandes",
+    "amateur",
+    "amenais",
+    "amenait",
+    "amenant",
+    "amendes",
+    "amenees",
+    "amenent",
+    "amenera",
+    "ameniez",
+    "amenons",
+    "amities",
+    "ampoule",
+    "amusais",
+    "amusait",
+    "amusant",
+    "amusees",
+    "amusent",
+    "amusera",
+    "amusiez",
+    "amusons",
+    "ancetre",
+    "anciens",
+    "anglais",
+    "animaux",
+    "anneaux",
+    "annonca",
+    "annonce",
+    "antenne",
+    "anxieux",
+    "appetit",
+    "apporta",
+    "apporte",
+    "appuiee",
+    "appuies",
+    "appuyai",
+    "appuyas",
+    "appuyer",
+    "appuyez",
+    "arbuste",
+    "ardoise",
+    "argents",
+    "argiles",
+    "armoire",
+    "arracha",
+    "arrache",
+    "arretai",
+    "arretas",
+    "arretee",
+    "arreter",
+    "arretes",
+    "arretez",
+    "arrivai",
+    "arrivas",
+    "arrivee",
+    "arriver",
+    "arrives",
+    "arrivez",
+    "arrosai",
+    "arrosas",
+    "arrosee",
+    "arroser",
+    "arroses",
+    "arrosez",
+    "article",
+    "artiste",
+    "aspects",
+    "asseoir",
+    "assista",
+    "assiste",
+    "atelier",
+    "attacha",
+    "attache",
+    "attends",
+    "attendu",
+    "attente",
+    "attrapa",
+    "attrape",
+    "auberge",
+    "auteurs",
+    "autobus",
+    "autocar",
+    "automne",
+    "avaient",
+    "avancai",
+    "avancas",
+    "avancee",
+    "avancer",
+    "avances",
+    "avancez",
+    "avenirs",
+    "avenues",
+    "avocats",
+    "avoines",
+    "bagages",
+    "bagarre",
+    "baisers",
+    "baissai",
+    "baissas",
+    "baissee",
+    "baisser",
+    "baisses",
+    "baissez",
+    "balance",
+    "balayai",
+    "balayas",
+    "balayee",
+    "balayer",
+    "balayes",
+    "balayez",
+    "balcons",
+    "baleine",
+    "ballons",
+    "bananes",
+    "banques",
+    "barques",
+    "barrage",
+    "bassins",
+    "bateaux",
+    "bavarda",
+    "bavarde",
+    "bavards",
+    "beautes",
+    "bercail",
+    "bergers",
+    "besoins",
+    "beurres",
+    "bibelot",
+    "biberon",
+    "bientot",
+    "billard",
+    "billets",
+    "biscuit",
+    "bizarre",
+    "blagues",
+    "blanche",
+    "blessai",
+    "blessas",
+    "blessee",
+    "blesser",
+    "blesses",
+    "blessez",
+    "bleuets",
+    "blondes",
+    "blouses",
+    "blouson",
+    "boisson",
+    "boivent",
+    "bonbons",
+    "bonheur",
+    "bonnets",
+    "boucher",
+    "bouches",
+    "boucles",
+    "bougies",
+    "bouleau",
+    "bouquet",
+    "bourses",
+    "boutons",
+    "branche",
+    "bricola",
+    "bricole",
+    "brillai",
+    "brillas",
+    "brillee",
+    "briller",
+    "brilles",
+    "brillez",
+    "brioche",
+    "briques",
+    "brossai",
+    "brossas",
+    "brossee",
+    "brosser",
+    "brosses",
+    "brossez",
+    "brulais",
+    "brulait",
+    "brulant",
+    "brulees",
+    "brulent",
+    "brulera",
+    "bruliez",
+    "brulons",
+    "brusque",
+    "budgets",
+    "buffets",
+    "buffles",
+    "buisson",
+    "bureaux",
+    "cabanes",
+    "cabines",
+    "cachais",
+    "cachait",
+    "cachant",
+    "cachees",
+    "cachent",
+    "cachera",
+    "cachiez",
+    "cachons",
+    "cadeaux",
+    "cadenas",
+    "cahiers",
+    "caillou",
+    "caisses",
+    "calculs",
+    "calmais",
+    "calmait",
+    "calmant",
+    "calmees",
+    "calment",
+    "calmera",
+    "calmiez",
+    "calmons",
+    "cameras",
+    "camions",
+    "canapes",
+    "canards",
+    "canevas",
+    "cantine",
+    "capable",
+    "caprice",
+    "capuche",
+    "carafes",
+    "caramel",
+    "carnets",
+    "carotte",
+    "carreau",
+    "cartons",
+    "cascade",
+    "casques",
+    "cassais",
+    "cassait",
+    "cassant",
+    "cassees",
+    "cassent",
+    "cassera",
+    "cassiez",
+    "cassons",
+    "castors",
+    "causais",
+    "causait",
+    "causant",
+    "causees",
+    "causent",
+    "causera",
+    "causiez",
+    "causons",
+    "caverne",
+    "celeris",
+    "cendres",
+    "centres",
+    "cerceau",
+    "cercles",
+    "cerises",
+    "cerveau",
+    "chagrin",
+    "chaines",
+    "chaises",
+    "chaleur",
+    "chambre",
+    "chameau",
+    "chances",
+    "changea",
+    "changee",
+    "changer",
+    "changes",
+    "changez",
+    "chanson",
+    "chantai",
+    "chantas",
+    "chantee",
+    "chanter",
+    "chantes",
+    "chantez",
+    "chapeau",
+    "charbon",
+    "chargea",
+    "chargee",
+    "charger",
+    "charges",
+    "chargez",
+    "chariot",
+    "charmes",
+    "chassai",
+    "chassas",
+    "chassee",
+    "chasser",
+    "chasses",
+    "chassez",
+    "chateau",
+    "chatons",
+    "chaudes",
+    "chemins",
+    "chemise",
+    "cheques",
+    "chercha",
+    "cherche",
+    "chevaux",
+    "cheveux",
+    "chevres",
+    "chiffon",
+    "chiffre",
+    "chimies",
+    "choisir",
+    "choisis",
+    "choisit",
+    "chorale",
+    "chutais",
+    "chutait",
+    "chutant",
+    "chutees",
+    "chutent",
+    "chutera",
+    "chutiez",
+    "chutons",
+    "cierges",
+    "cigales",
+    "cinemas",
+    "cirques",
+    "ciseaux",
+    "citoyen",
+    "citrons",
+    "claires",
+    "clartes",
+    "classai",
+    "classas",
+    "classee",
+    "classer",
+    "classes",
+    "classez",
+    "clavier",
+    "clients",
+    "climats",
+    "clocher",
+    "cloches",
+    "cochons",
+    "cocotte",
+    "coffres",
+    "cognais",
+    "cognait",
+    "cognant",
+    "cognees",
+    "cognent",
+    "cognera",
+    "cogniez",
+    "cognons",
+    "coleres",
+    "collais",
+    "collait",
+    "collant",
+    "collees",
+    "college",
+    "collent",
+    "collera",
+    "collier",
+    "colliez",
+    "colline",
+    "collons",
+    "colombe",
+    "colonne",
+    "coloria",
+    "colorie",
+    "combats",
+    "combien",
+    "comedie",
+    "comique",
+    "comment",
+    "commune",
+    "communs",
+    "compara",
+    "compare",
+    "complet",
+    "comptai",
+    "comptas",
+    "comptee",
+    "compter",
+    "comptes",
+    "comptez",
+    "concert",
+    "confiai",
+    "confias",
+    "confiee",
+    "confier",
+    "confies",
+    "confiez",
+    "connais",
+    "connait",
+    "conseil",
+    "consola",
+    "console",
+    "content",
+    "contrat",
+    "copains",
+    "corbeau",
+    "correct",
+    "corrige",
+    "costume",
+    "couchai",
+    "couchas",
+    "couchee",
+    "coucher",
+    "couches",
+    "couchez",
+    "coulais",
+    "coulait",
+    "coulant",
+    "coulees",
+    "coulent",
+    "coulera",
+    "couleur",
+    "couliez",
+    "couloir",
+    "coulons",
+    "coupais",
+    "coupait",
+    "coupant",
+    "coupees",
+    "coupent",
+    "coupera",
+    "coupiez",
+    "coupons",
+    "courage",
+    "courant",
+    "courbai",
+    "courbas",
+    "courbee",
+    "courber",
+    "courbes",
+    "courbez",
+    "courent",
+    "courons",
+    "courses",
+    "courtes",
+    "coutais",
+    "coutait",
+    "coutant",
+    "couteau",
+    "coutees",
+    "coutent",
+    "coutera",
+    "coutiez",
+    "coutons",
+    "coutume",
+    "crainte",
+    "crapaud",
+    "cravate",
+    "crayons",
+    "creatif",
+    "creusai",
+    "creusas",
+    "creusee",
+    "creuser",
+    "creuses",
+    "creusez",
+    "crierai",
+    "crieras",
+    "crierez",
+    "criions",
+    "cristal",
+    "croient",
+    "croutes",
+    "croyons",
+    "cruelle",
+    "cuisina",
+    "cuisine",
+    "cuisses",
+    "culture",
+    "curieux",
+    "dangers",
+    "dansais",
+    "dansait",
+    "dansant",
+    "dansees",
+    "dansent",
+    "dansera",
+    "danseur",
+    "dansiez",
+    "dansons",
+    "dauphin",
+    "decidai",
+    "decidas",
+    "decidee",
+    "decider",
+    "decides",
+    "decidez",
+    "declara",
+    "declare",
+    "decolla",
+    "decolle",
+    "decorai",
+    "decoras",
+    "decoree",
+    "decorer",
+    "decores",
+    "decorez",
+    "decoupa",
+    "decoupe",
+    "defauts",
+    "degagea",
+    "degagee",
+    "degager",
+    "degages",
+    "degagez",
+    "dejeuna",
+    "dejeune",
+    "delicat",
+    "delivra",
+    "delivre",
+    "demanda",
+    "demande",
+    "departs",
+    "depassa",
+    "depasse",
+    "deplaca",
+    "deplace",
+    "deposai",
+    "deposas",
+    "deposee",
+    "deposer",
+    "deposes",
+    "deposez",
+    "dernier",
+    "descend",
+    "deserte",
+    "deserts",
+    "desirai",
+    "desiras",
+    "desiree",
+    "desirer",
+    "desires",
+    "desirez",
+    "dessert",
+    "dessina",
+    "dessine",
+    "dessins",
+    "dessous",
+    "destins",
+    "detacha",
+    "detache",
+    "details",
+    "devinai",
+    "devinas",
+    "devinee",
+    "deviner",
+    "devines",
+    "devinez",
+    "devoirs",
+    "devrait",
+    "diables",
+    "diamant",
+    "dictees",
+    "dinerai",
+    "dineras",
+    "dinerez",
+    "dinions",
+    "diplome",
+    "directe",
+    "directs",
+    "discret",
+    "discuta",
+    "discute",
+    "dispute",
+    "disques",
+    "divines",
+    "docteur",
+    "doivent",
+    "domaine",
+    "dommage",
+    "donnais",
+    "donnait",
+    "donnant",
+    "donnees",
+    "donnent",
+    "donnera",
+    "donniez",
+    "donnons",
+    "dorerai",
+    "doreras",
+    "dorerez",
+    "dorions",
+    "dorment",
+    "dormons",
+    "dossier",
+    "douanes",
+    "douches",
+    "douleur",
+    "doutais",
+    "doutait",
+    "doutant",
+    "doutees",
+    "doutent",
+    "doutera",
+    "doutiez",
+    "doutons",
+    "dragons",
+    "drapeau",
+    "durerai",
+    "dureras",
+    "durerez",
+    "durions",
+    "echange",
+    "echarpe",
+    "echelle",
+    "eclaira",
+    "eclaire",
+    "eclairs",
+    "eclipse",
+    "ecorces",
+    "ecoutai",
+    "ecoutas",
+    "ecoutee",
+    "ecouter",
+    "ecoutes",
+    "ecoutez",
+    "ecrivez",
+    "ecuries",
+    "edifice",
+    "efforts",
+    "effraya",
+    "effraye",
+    "eglises",
+    "elegant",
+    "elevais",
+    "elevait",
+    "elevant",
+    "elevees",
+    "elevent",
+    "elevera",
+    "eleviez",
+    "elevons",
+    "emballa",
+    "emballe",
+    "emmenai",
+    "emmenas",
+    "emmenee",
+    "emmener",
+    "emmenes",
+    "emmenez",
+    "emotion",
+    "empecha",
+    "empeche",
+    "empires",
+    "emplois",
+    "emporta",
+    "emporte",
+    "encadre",
+    "encores",
+    "endormi",
+    "endroit",
+    "energie",
+    "enfance",
+    "enfants",
+    "enferma",
+    "enferme",
+    "engrais",
+    "enlevai",
+    "enlevas",
+    "enlevee",
+    "enlever",
+    "enleves",
+    "enlevez",
+    "ennemis",
+    "enormes",
+    "enquete",
+    "ensuite",
+    "entends",
+    "entendu",
+    "entiere",
+    "entiers",
+    "entoura",
+    "entoure",
+    "entrais",
+    "entrait",
+    "entrant",
+    "entrees",
+    "entrent",
+    "entrera",
+    "entriez",
+    "entrons",
+    "environ",
+    "envolai",
+    "envolas",
+    "envolee",
+    "envoler",
+    "envoles",
+    "envolez",
+    "epaisse",
+    "epaules",
+    "epelais",
+    "epelait",
+    "epelant",
+    "epelees",
+    "epelent",
+    "epelera",
+    "epeliez",
+    "epelons",
+    "episode",
+    "eponges",
+    "epoques",
+    "equipes",
+    "erreurs",
+    "espaces",
+    "especes",
+    "esperai",
+    "esperas",
+    "esperee",
+    "esperer",
+    "esperes",
+    "esperez",
+    "espoirs",
+    "esprits",
+    "essuiee",
+    "essuies",
+    "essuyai",
+    "essuyas",
+    "essuyer",
+    "essuyez",
+    "estomac",
+    "etagere",
+    "etaient",
+    "etoiles",
+    "etonnai",
+    "etonnas",
+    "etonnee",
+    "etonner",
+    "etonnes",
+    "etonnez",
+    "etrange",
+    "etroite",
+    "etroits",
+    "etudiai",
+    "etudias",
+    "etudiee",
+    "etudier",
+    "etudies",
+    "etudiez",
+    "evasion",
+    "evitais",
+    "evitait",
+    "evitant",
+    "evitees",
+    "evitent",
+    "evitera",
+    "evitiez",
+    "evitons",
+    "exactes",
+    "examens",
+    "examina",
+    "examine",
+    "excuses",
+    "exemple",
+    "existai",
+    "existas",
+    "existee",
+    "exister",
+    "existes",
+    "existez",
+    "explosa",
+    "explose",
+    "exposes",
+    "facades",
+    "fachais",
+    "fachait",
+    "fachant",
+    "fachees",
+    "fachent",
+    "fachera",
+    "fachiez",
+    "fachons",
+    "faciles",
+    "facteur",
+    "faibles",
+    "faisait",
+    "faisons",
+    "fallait",
+    "falloir",
+    "famille",
+    "farines",
+    "fatigue",
+    "faucons",
+    "fausses",
+    "faveurs",
+    "favoris",
+    "fenetre",
+    "fenouil",
+    "fermais",
+    "fermait",
+    "fermant",
+    "fermees",
+    "ferment",
+    "fermera",
+    "fermiez",
+    "fermons",
+    "feroces",
+    "feterai",
+    "feteras",
+    "feterez",
+    "fetions",
+    "feuille",
+    "ficelle",
+    "fideles",
+    "fievres",
+    "figures",
+    "finales",
+    "fixerai",
+    "fixeras",
+    "fixerez",
+    "fixions",
+    "flacons",
+    "flamant",
+    "flammes",
+    "flaques",
+    "fleches",
+    "fleuves",
+    "flocons",
+    "flottai",
+    "flottas",
+    "flottee",
+    "flotter",
+    "flottes",
+    "flottez",
+    "formais",
+    "formait",
+    "formant",
+    "formees",
+    "forment",
+    "formera",
+    "formiez",
+    "formons",
+    "fortune",
+    "foudres",
+    "fouilla",
+    "fouille",
+    "fourmis",
+    "fragile",
+    "fraiche",
+    "fraises",
+    "franche",
+    "frappai",
+    "frappas",
+    "frappee",
+    "frapper",
+    "frappes",
+    "frappez",
+    "frisson",
+    "froides",
+    "fromage",
+    "fumerai",
+    "fumeras",
+    "fumerez",
+    "fumiers",
+    "fumions",
+    "furieux",
+    "fusains",
+    "gagnais",
+    "gagnait",
+    "gagnant",
+    "gagnees",
+    "gagnent",
+    "gagnera",
+    "gagniez",
+    "gagnons",
+    "galerie",
+    "galette",
+    "garages",
+    "garcons",
+    "gardais",
+    "gardait",
+    "gardant",
+    "gardees",
+    "gardent",
+    "gardera",
+    "gardien",
+    "gardiez",
+    "gardons",
+    "gateaux",
+    "gaterai",
+    "gateras",
+    "gaterez",
+    "gations",
+    "gazelle",
+    "generai",
+    "generas",
+    "generez",
+    "genions",
+    "gentils",
+    "girafes",
+    "glacial",
+    "glacons",
+    "glissai",
+    "glissas",
+    "glissee",
+    "glisser",
+    "glisses",
+    "glissez",
+    "gloires",
+    "gobelet",
+    "goeland",
+    "gorille",
+    "gouffre",
+    "gourdes",
+    "goutais",
+    "goutait",
+    "goutant",
+    "goutees",
+    "goutent",
+    "goutera",
+    "goutiez",
+    "goutons",
+    "gouttes",
+    "gradins",
+    "graines",
+    "grandes",
+    "grandir",
+    "grandit",
+    "granges",
+    "grappes",
+    "grasses",
+    "gratuit",
+    "grenade",
+    "grenier",
+    "griffes",
+    "grilles",
+    "grimace",
+    "grimpai",
+    "grimpas",
+    "grimpee",
+    "grimper",
+    "grimpes",
+    "grimpez",
+    "grondai",
+    "grondas",
+    "grondee",
+    "gronder",
+    "grondes",
+    "grondez",
+    "grosses",
+    "grossir",
+    "grottes",
+    "groupes",
+    "guerres",
+    "guitare",
+    "gymnase",
+    "habiles",
+    "habilla",
+    "habille",
+    "habitai",
+    "habitas",
+    "habitee",
+    "habiter",
+    "habites",
+    "habitez",
+    "hamster",
+    "hangars",
+    "haricot",
+    "hasards",
+    "hauteur",
+    "hesitai",
+    "hesitas",
+    "hesitee",
+    "hesiter",
+    "hesites",
+    "hesitez",
+    "heureux",
+    "homards",
+    "honnete",
+    "honneur",
+    "hopital",
+    "horizon",
+    "horloge",
+    "huitres",
+    "humaine",
+    "humains",
+    "humeurs",
+    "humides",
+    "humours",
+    "hurlais",
+    "hurlait",
+    "hurlant",
+    "hurlees",
+    "hurlent",
+    "hurlera",
+    "hurliez",
+    "hurlons",
+    "hygiene",
+    "idiotes",
+    "ignorai",
+    "ignoras",
+    "ignoree",
+    "ignorer",
+    "ignores",
+    "ignorez",
+    "imagina",
+    "imagine",
+    "immense",
+    "indices",
+    "inquiet",
+    "insecte",
+    "instant",
+    "inutile",
+    "inventa",
+    "invente",
+    "invitai",
+    "invitas",
+    "invitee",
+    "inviter",
+    "invites",
+    "invitez",
+    "ivoires",
+    "jalouse",
+    "jambons",
+    "jardina",
+    "jardine",
+    "jardins",
+    "javelot",
+    "joindre",
+    "jouerai",
+    "joueras",
+    "jouerez",
+    "joueurs",
+    "jouions",
+    "journal",
+    "journee",
+    "joyeuse",
+    "jumelle",
+    "juments",
+    "jurerai",
+    "jureras",
+    "jurerez",
+    "jurions",
+    "justice",
+    "kiosque",
+    "lagunes",
+    "laissai",
+    "laissas",
+    "laissee",
+    "laisser",
+    "laisses",
+    "laissez",
+    "lancais",
+    "lancait",
+    "lancant",
+    "lancees",
+    "lancent",
+    "lancera",
+    "lanciez",
+    "lancons",
+    "langues",
+    "lavabos",
+    "laverai",
+    "laveras",
+    "laverez",
+    "lavions",
+    "legende",
+    "legeres",
+    "legumes",
+    "lettres",
+    "leverai",
+    "leveras",
+    "leverez",
+    "levions",
+    "lezards",
+    "liberte",
+    "lievres",
+    "limaces",
+    "liquide",
+    "livrais",
+    "livrait",
+    "livrant",
+    "livrees",
+    "livrent",
+    "livrera",
+    "livriez",
+    "livrons",
+    "loisirs",
+    "lorsque",
+    "louange",
+    "louerai",
+    "loueras",
+    "louerez",
+    "louions",
+    "lourdes",
+    "loyales",
+    "lucarne",
+    "lumiere",
+    "lunette",
+    "machine",
+    "madames",
+    "magasin",
+    "maigres",
+    "maigrir",
+    "maillot",
+    "mainate",
+    "mairies",
+    "maisons",
+    "maitres",
+    "malades",
+    "maladie",
+    "maligne",
+    "manches",
+    "manchot",
+    "maneges",
+    "mangeai",
+    "mangeas",
+    "mangees",
+    "mangent",
+    "mangera",
+    "mangiez",
+    "maniere",
+    "manquai",
+    "manquas",
+    "manquee",
+    "manquer",
+    "manques",
+    "manquez",
+    "manteau",
+    "marbres",
+    "marchai",
+    "marchas",
+    "marchee",
+    "marcher",
+    "marches",
+    "marchez",
+    "mariage",
+    "marmite",
+    "marquai",
+    "marquas",
+    "marquee",
+    "marquer",
+    "marques",
+    "marquez",
+    "marrone",
+    "marrons",
+    "masques",
+    "matelas",
+    "matelot",
+    "mauvais",
+    "mechant",
+    "medecin",
+    "melange",
+    "melodie",
+    "membres",
+    "memoire",
+    "menaces",
+    "menerai",
+    "meneras",
+    "menerez",
+    "menions",
+    "mentons",
+    "meritai",
+    "meritas",
+    "meritee",
+    "meriter",
+    "merites",
+    "meritez",
+    "mesange",
+    "message",
+    "mesurai",
+    "mesuras",
+    "mesuree",
+    "mesurer",
+    "mesures",
+    "mesurez",
+    "metiers",
+    "mettait",
+    "mettent",
+    "mettons",
+    "meubles",
+    "meunier",
+    "microbe",
+    "milieux",
+    "minutes",
+    "miracle",
+    "miroirs",
+    "mission",
+    "modeles",
+    "moderne",
+    "moineau",
+    "moities",
+    "mollets",
+    "moments",
+    "monnaie",
+    "montais",
+    "montait",
+    "montant",
+    "montees",
+    "montent",
+    "montera",
+    "montiez",
+    "montons",
+    "montrai",
+    "montras",
+    "montree",
+    "montrer",
+    "montres",
+    "montrez",
+    "morales",
+    "morceau",
+    "moteurs",
+    "mouches",
+    "mouette",
+    "moulins",
+    "mousses",
+    "moutons",
+    "muettes",
+    "muguets",
+    "murmura",
+    "murmure",
+    "muscles",
+    "musique",
+    "mystere",
+    "nageais",
+    "nageait",
+    "nageant",
+    "nageons",
+    "nagerai",
+    "nageras",
+    "nagerez",
+    "nagions",
+    "narines",
+    "natales",
+    "naturel",
+    "natures",
+    "navette",
+    "navires",
+    "nerveux",
+    "niveaux",
+    "nombres",
+    "nombril",
+    "notaire",
+    "noterai",
+    "noteras",
+    "noterez",
+    "notions",
+    "nourrir",
+    "nouveau",
+    "nuances",
+    "numeros",
+    "obscure",
+    "obscurs",
+    "observa",
+    "observe",
+    "oeuvres",
+    "oignons",
+    "oiseaux",
+    "opinion",
+    "oranges",
+    "oreille",
+    "orteils",
+    "osaient",
+    "oserais",
+    "oserait",
+    "oserent",
+    "oserons",
+    "oseront",
+    "otaries",
+    "oubliai",
+    "oublias",
+    "oubliee",
+    "oublier",
+    "oublies",
+    "oubliez",
+    "ouragan",
+    "ouvrier",
+    "pailles",
+    "palmier",
+    "paniers",
+    "panneau",
+    "papiers",
+    "paquets",
+    "parapet",
+    "pareils",
+    "parents",
+    "paresse",
+    "parfait",
+    "parfois",
+    "parfums",
+    "parlais",
+    "parlait",
+    "parlant",
+    "parlees",
+    "parlent",
+    "parlera",
+    "parliez",
+    "parlons",
+    "paroles",
+    "partage",
+    "partait",
+    "partent",
+    "parties",
+    "partons",
+    "partout",
+    "passage",
+    "passais",
+    "passait",
+    "passant",
+    "passees",
+    "passent",
+    "passera",
+    "passiez",
+    "passons",
+    "patient",
+    "patinai",
+    "patinas",
+    "patinee",
+    "patiner",
+    "patines",
+    "patinez",
+    "patrons",
+    "pauvres",
+    "payerai",
+    "payeras",
+    "payerez",
+    "payions",
+    "paysage",
+    "paysans",
+    "pechais",
+    "pechait",
+    "pechant",
+    "pechees",
+    "pechent",
+    "pechera",
+    "pechiez",
+    "pechons",
+    "pedalai",
+    "pedalas",
+    "pedalee",
+    "pedaler",
+    "pedales",
+    "pedalez",
+    "peignai",
+    "peignas",
+    "peignee",
+    "peigner",
+    "peignes",
+    "peignez",
+    "peindre",
+    "peintre",
+    "pelican",
+    "pelouse",
+    "pendant",
+    "peniche",
+    "pensais",
+    "pensait",
+    "pensant",
+    "pensees",
+    "pensent",
+    "pensera",
+    "pensiez",
+    "pensifs",
+    "pensive",
+    "pensons",
+    "peserai",
+    "peseras",
+    "peserez",
+    "pesions",
+    "petales",
+    "petites",
+    "petrole",
+    "peuples",
+    "peureux",
+    "peuvent",
+    "phrases",
+    "pierres",
+    "pigeons",
+    "pilotai",
+    "pilotas",
+    "pilotee",
+    "piloter",
+    "pilotes",
+    "pilotez",
+    "pinceau",
+    "piquais",
+    "piquait",
+    "piquant",
+    "piquees",
+    "piquent",
+    "piquera",
+    "piquiez",
+    "piquons",
+    "pirates",
+    "pirogue",
+    "piscine",
+    "placais",
+    "placait",
+    "placant",
+    "placard",
+    "placees",
+    "placent",
+    "placera",
+    "placiez",
+    "placons",
+    "plafond",
+    "plaines",
+    "plaisir",
+    "planche",
+    "planete",
+    "plantai",
+    "plantas",
+    "plantee",
+    "planter",
+    "plantes",
+    "plantez",
+    "plateau",
+    "pleurai",
+    "pleuras",
+    "pleuree",
+    "pleurer",
+    "pleures",
+    "pleurez",
+    "plierai",
+    "plieras",
+    "plierez",
+    "pliions",
+    "plongea",
+    "plongee",
+    "plonger",
+    "plonges",
+    "plongez",
+    "plumage",
+    "poesies",
+    "poignee",
+    "poignet",
+    "pointes",
+    "poireau",
+    "poisson",
+    "poivres",
+    "polices",
+    "pompier",
+    "portail",
+    "portais",
+    "portait",
+    "portant",
+    "portees",
+    "portent",
+    "portera",
+    "portiez",
+    "portons",
+    "poserai",
+    "poseras",
+    "poserez",
+    "posions",
+    "potager",
+    "potages",
+    "poteaux",
+    "poulets",
+    "poupees",
+    "poussai",
+    "poussas",
+    "poussee",
+    "pousser",
+    "pousses",
+    "poussez",
+    "poussin",
+    "poutres",
+    "pouvait",
+    "pouvoir",
+    "pouvons",
+    "prairie",
+    "premier",
+    "prenait",
+    "prendre",
+    "prenons",
+    "prepara",
+    "prepare",
+    "present",
+    "presque",
+    "presses",
+    "pretais",
+    "pretait",
+    "pretant",
+    "pretees",
+    "pretent",
+    "pretera",
+    "pretiez",
+    "pretons",
+    "preuves",
+    "prierai",
+    "prieras",
+    "prierez",
+    "priions",
+    "princes",
+    "prisons",
+    "profita",
+    "profite",
+    "profond",
+    "projets",
+    "propres",
+    "protege",
+    "prouvai",
+    "prouvas",
+    "prouvee",
+    "prouver",
+    "prouves",
+    "prouvez",
+    "prudent",
+    "publics",
+    "qualite",
+    "quelque",
+    "quittai",
+    "quittas",
+    "quittee",
+    "quitter",
+    "quittes",
+    "quittez",
+    "racines",
+    "raconta",
+    "raconte",
+    "raisins",
+    "raisons",
+    "ramassa",
+    "ramasse",
+    "rameaux",
+    "ramenai",
+    "ramenas",
+    "ramenee",
+    "ramener",
+    "ramenes",
+    "ramenez",
+    "rangeai",
+    "rangeas",
+    "rangees",
+    "rangent",
+    "rangera",
+    "rangiez",
+    "rapides",
+    "rapport",
+    "rassura",
+    "rassure",
+    "raterai",
+    "rateras",
+    "raterez",
+    "rations",
+    "recette",
+    "reclama",
+    "reclame",
+    "recolta",
+    "recolte",
+    "recopia",
+    "recopie",
+    "reelles",
+    "reflets",
+    "refuges",
+    "regarda",
+    "regarde",
+    "regards",
+    "remplir",
+    "renards",
+    "renfort",
+    "rentrai",
+    "rentras",
+    "rentree",
+    "rentrer",
+    "rentres",
+    "rentrez",
+    "reparai",
+    "reparas",
+    "reparee",
+    "reparer",
+    "repares",
+    "reparez",
+    "repassa",
+    "repasse",
+    "repetai",
+    "repetas",
+    "repetee",
+    "repeter",
+    "repetes",
+    "repetez",
+    "reponds",
+    "repondu",
+    "reponse",
+    "reposai",
+    "reposas",
+    "reposee",
+    "reposer",
+    "reposes",
+    "reposez",
+    "requete",
+    "requins",
+    "reseaux",
+    "reserva",
+    "reserve",
+    "resista",
+    "resiste",
+    "respira",
+    "respire",
+    "restais",
+    "restait",
+    "restant",
+    "restees",
+    "restent",
+    "restera",
+    "restiez",
+    "restons",
+    "retards",
+    "retours",
+    "reunion",
+    "reussir",
+    "reveils",
+    "reverai",
+    "reveras",
+    "reverez",
+    "revions",
+    "rideaux",
+    "rigolai",
+    "rigolas",
+    "rigolee",
+    "rigoler",
+    "rigoles",
+    "rigolez",
+    "rigoloe",
+    "rigolos",
+    "rivages",
+    "riviere",
+    "rochers",
+    "roseaux",
+    "roulais",
+    "roulait",
+    "roulant",
+    "roulees",
+    "roulent",
+    "roulera",
+    "rouliez",
+    "roulons",
+    "rousses",
+    "rythmes",
+    "sacoche",
+    "safrans",
+    "sagesse",
+    "saintes",
+    "saisons",
+    "salades",
+    "salaire",
+    "saluais",
+    "saluait",
+    "saluant",
+    "saluees",
+    "saluent",
+    "saluera",
+    "saluiez",
+    "saluons",
+    "samedis",
+    "sandale",
+    "saumons",
+    "sautais",
+    "sautait",
+    "sautant",
+    "sautees",
+    "sautent",
+    "sautera",
+    "sautiez",
+    "sautons",
+    "sauvage",
+    "sauvais",
+    "sauvait",
+    "sauvant",
+    "sauvees",
+    "sauvent",
+    "sauvera",
+    "sauviez",
+    "sauvons",
+    "savante",
+    "savants",
+    "science",
+    "scooter",
+    "secrete",
+    "secrets",
+    "semaine",
+    "semblai",
+    "semblas",
+    "semblee",
+    "sembler",
+    "sembles",
+    "semblez",
+    "semelle",
+    "sentier",
+    "separai",
+    "separas",
+    "separee",
+    "separer",
+    "separes",
+    "separez",
+    "serieux",
+    "serpent",
+    "serrais",
+    "serrait",
+    "serrant",
+    "serrees",
+    "serrent",
+    "serrera",
+    "serriez",
+    "serrons",
+    "serrure",
+    "service",
+    "siecles",
+    "sifflai",
+    "sifflas",
+    "sifflee",
+    "siffler",
+    "siffles",
+    "sifflet",
+    "sifflez",
+    "signais",
+    "signait",
+    "signant",
+    "signees",
+    "signent",
+    "signera",
+    "signiez",
+    "signons",
+    "silence",
+    "simples",
+    "sincere",
+    "sirenes",
+    "soignai",
+    "soignas",
+    "soignee",
+    "soigner",
+    "soignes",
+    "soignez",
+    "soirees",
+    "soldats",
+    "soleils",
+    "sombres",
+    "sommeil",
+    "sommets",
+    "sonnais",
+    "sonnait",
+    "sonnant",
+    "sonnees",
+    "sonnent",
+    "sonnera",
+    "sonniez",
+    "sonnons",
+    "sorcier",
+    "sortent",
+    "sorties",
+    "sortons",
+    "souffla",
+    "souffle",
+    "soulier",
+    "souples",
+    "sources",
+    "sourcil",
+    "sourdes",
+    "sourire",
+    "souvent",
+    "spatule",
+    "statues",
+    "stupide",
+    "subtile",
+    "subtils",
+    "sucerai",
+    "suceras",
+    "sucerez",
+    "sucions",
+    "superbe",
+    "surface",
+    "surtout",
+    "systeme",
+    "tableau",
+    "tailles",
+    "talents",
+    "tambour",
+    "taquina",
+    "taquine",
+    "tardais",
+    "tardait",
+    "tardant",
+    "tardees",
+    "tardent",
+    "tardera",
+    "tardiez",
+    "tardifs",
+    "tardive",
+    "tardons",
+    "tartine",
+    "taureau",
+    "temoins",
+    "tempete",
+    "tendres",
+    "termina",
+    "termine",
+    "terrain",
+    "theatre",
+    "tickets",
+    "tilleul",
+    "timbres",
+    "timides",
+    "tirerai",
+    "tireras",
+    "tirerez",
+    "tirions",
+    "tiroirs",
+    "tomates",
+    "tombais",
+    "tombait",
+    "tombant",
+    "tombees",
+    "tombent",
+    "tombera",
+    "tombiez",
+    "tombons",
+    "tonneau",
+    "torchon",
+    "tordues",
+    "tornade",
+    "tortues",
+    "toucans",
+    "touchai",
+    "touchas",
+    "touchee",
+    "toucher",
+    "touches",
+    "touchez",
+    "tournai",
+    "tournas",
+    "tournee",
+    "tourner",
+    "tournes",
+    "tournez",
+    "tournoi",
+    "toussai",
+    "toussas",
+    "toussee",
+    "tousser",
+    "tousses",
+    "toussez",
+    "tracais",
+    "tracait",
+    "tracant",
+    "tracees",
+    "tracent",
+    "tracera",
+    "traciez",
+    "tracons",
+    "trajets",
+    "tramway",
+    "tranche",
+    "trapeze",
+    "travail",
+    "travaux",
+    "trembla",
+    "tremble",
+    "tresors",
+    "trichai",
+    "trichas",
+    "trichee",
+    "tricher",
+    "triches",
+    "trichez",
+    "tricots",
+    "tristes",
+    "trompai",
+    "trompas",
+    "trompee",
+    "tromper",
+    "trompes",
+    "trompez",
+    "trophee",
+    "trottai",
+    "trottas",
+    "trottee",
+    "trotter",
+    "trottes",
+    "trottez",
+    "troupes",
+    "trouvai",
+    "trouvas",
+    "trouvee",
+    "trouver",
+    "trouves",
+    "trouvez",
+    "truites",
+    "tuaient",
+    "tuerais",
+    "tuerait",
+    "tuerent",
+    "tuerons",
+    "tueront",
+    "tulipes",
+    "tunnels",
+    "uniques",
+    "univers",
+    "urgente",
+    "urgents",
+    "usaient",
+    "userais",
+    "userait",
+    "userent",
+    "userons",
+    "useront",
+    "valeurs",
+    "valises",
+    "vallees",
+    "valsais",
+    "valsait",
+    "valsant",
+    "valsees",
+    "valsent",
+    "valsera",
+    "valsiez",
+    "valsons",
+    "vampire",
+    "vanille",
+    "vapeurs",
+    "vautour",
+    "vedette",
+    "veilles",
+    "vendeur",
+    "ventres",
+    "verdure",
+    "vergers",
+    "verifia",
+    "verifie",
+    "verites",
+    "versais",
+    "versait",
+    "versant",
+    "versees",
+    "versent",
+    "versera",
+    "versiez",
+    "versons",
+    "veulent",
+    "viandes",
+    "vieille",
+    "vilaine",
+    "vilains",
+    "village",
+    "violets",
+    "violons",
+    "viperes",
+    "virages",
+    "visages",
+    "visitai",
+    "visitas",
+    "visitee",
+    "visiter",
+    "visites",
+    "visitez",
+    "vitesse",
+    "vitrine",
+    "vivante",
+    "vivants",
+    "voisine",
+    "voisins",
+    "voiture",
+    "volants",
+    "volcans",
+    "volerai",
+    "voleras",
+    "volerez",
+    "voleurs",
+    "volions",
+    "volonte",
+    "voterai",
+    "voteras",
+    "voterez",
+    "votions",
+    "voulait",
+    "vouloir",
+    "voulons",
+    "voyagea",
+    "voyagee",
+    "voyager",
+    "voyages",
+    "voyagez",
+    "voyelle",
+    "yaourts"
+};
+
+
+pub static EIGHT_LETTER_WORDS: Set<&'static str> = phf_set! {
+    "abeilles",
+    "abricots",
+    "absences",
+    "absentes",
+    "acceptai",
+    "acceptas",
+    "acceptee",
+    "accepter",
+    "acceptes",
+    "acceptez",
+    "accident",
+    "accrocha",
+    "accroche",
+    "accueils",
+    "activite",
+    "addition",
+    "admirais",
+    "admirait",
+    "admirant",
+    "admirees",
+    "admirent",
+    "admirera",
+    "admiriez",
+    "admirons",
+    "adorerai",
+    "adoreras",
+    "adorerez",
+    "adorions",
+    "adresses",
+    "adroites",
+    "aeroport",
+    "affaires",
+    "affiches",
+    "affronta",
+    "affronte",
+    "agreable",
+    "aidaient",
+    "aiderais",
+    "aiderait",
+    "aiderent",
+    "aiderons",
+    "aideront",
+    "aiguille",
+    "ailleurs",
+    "aimables",
+    "aimaient",
+    "aimerais",
+    "aimerait",
+    "aimerent",
+    "aimerons",
+    "aimeront",
+    "ajoutais",
+    "ajoutait",
+    "ajoutant",
+    "ajoutees",
+    "ajoutent",
+    "ajoutera",
+    "ajoutiez",
+    "ajoutons",
+    "aliments",
+    "allongea",
+    "allongee",
+    "allonger",
+    "allonges",
+    "allongez",
+    "allumais",
+    "allumait",
+    "allumant",
+    "allumees",
+    "allument",
+    "allumera",
+    "allumiez",
+    "allumons",
+    "alphabet",
+    "amateurs",
+    "ambiance",
+    "amenerai",
+    "ameneras",
+    "amenerez",
+    "amenions",
+    "amoureux",
+    "ampoules",
+    "amusante",
+    "amusants",
+    "amuserai",
+    "amuseras",
+    "amuserez",
+    "amusions",
+    "ancetres",
+    "ancienne",
+    "anglaise",
+    "annoncai",
+    "annoncas",
+    "annoncee",
+    "annoncer",
+    "annonces",
+    "annoncez",
+    "antennes",
+    "anxieuse",
+    "appareil",
+    "appetits",
+    "applique",
+    "apportai",
+    "apportas",
+    "apportee",
+    "apporter",
+    "apportes",
+    "apportez",
+    "apprenti",
+    "approcha",
+    "approche",
+    "appuiees",
+    "appuient",
+    "appuiera",
+    "appuyais",
+    "appuyait",
+    "appuyant",
+    "appuyiez",
+    "appuyons",
+    "aquarium",
+    "araignee",
+    "arbustes",
+    "ardoises",
+    "armoires",
+    "arrachai",
+    "arrachas",
+    "arrachee",
+    "arracher",
+    "arraches",
+    "arrachez",
+    "arretais",
+    "arretait",
+    "arretant",
+    "arretees",
+    "arretent",
+    "arretera",
+    "arretiez",
+    "arretons",
+    "arrivais",
+    "arrivait",
+    "arrivant",
+    "arrivees",
+    "arrivent",
+    "arrivera",
+    "arriviez",
+    "arrivons",
+    "arrosais",
+    "arrosait",
+    "arrosant",
+    "arrosees",
+    "arrosent",
+    "arrosera",
+    "arrosiez",
+    "arrosoir",
+    "arrosons",
+    "articles",
+    "artistes",
+    "assiette",
+    "assistai",
+    "assistas",
+    "assistee",
+    "assister",
+    "assistes",
+    "assistez",
+    "ateliers",
+    "attachai",
+    "attachas",
+    "attachee",
+    "attacher",
+    "attaches",
+    "attachez",
+    "attendre",
+    "attentes",
+    "attentif",
+    "attitude",
+    "attrapai",
+    "attrapas",
+    "attrapee",
+    "attraper",
+    "attrapes",
+    "attrapez",
+    "auberges",
+    "audience",
+    "augmenta",
+    "augmente",
+    "autocars",
+    "automnes",
+    "autorite",
+    "autruche",
+    "avancais",
+    "avancait",
+    "avancant",
+    "avancees",
+    "avancent",
+    "avancera",
+    "avanciez",
+    "avancons",
+    "aventure",
+    "aviateur",
+    "bagarres",
+    "baguette",
+    "baissais",
+    "baissait",
+    "baissant",
+    "baissees",
+    "baissent",
+    "baissera",
+    "baissiez",
+    "baissons",
+    "balances",
+    "balayais",
+    "balayait",
+    "balayant",
+    "balayees",
+    "balayent",
+    "balayera",
+    "balayiez",
+    "balayons",
+    "baleines",
+    "banniere",
+    "barrages",
+    "barriere",
+    "bataille",
+    "bavardai",
+    "bavardas",
+    "bavardee",
+    "bavarder",
+    "bavardes",
+    "bavardez",
+    "beaucoup",
+    "bercails",
+    "bergerie",
+    "bibelots",
+    "biberons",
+    "bienfait",
+    "billards",
+    "biologie",
+    "biscuits",
+    "bizarres",
+    "blaireau",
+    "blanches",
+    "blessais",
+    "blessait",
+    "blessant",
+    "blessees",
+    "blessent",
+    "blessera",
+    "blessiez",
+    "blessons",
+    "blessure",
+    "blousons",
+    "boissons",
+    "bonheurs",
+    "bouchers",
+    "bouleaux",
+    "bouquets",
+    "boussole",
+    "boutique",
+    "branches",
+    "bricolai",
+    "bricolas",
+    "bricolee",
+    "bricoler",
+    "bricoles",
+    "bricolez",
+    "brillais",
+    "brillait",
+    "brillant",
+    "brillees",
+    "brillent",
+    "brillera",
+    "brilliez",
+    "brillons",
+    "brioches",
+    "brossais",
+    "brossait",
+    "brossant",
+    "brossees",
+    "brossent",
+    "brossera",
+    "brossiez",
+    "brossons",
+    "brouette",
+    "brulerai",
+    "bruleras",
+    "brulerez",
+    "brulions",
+    "brusques",
+    "buissons",
+    "bulletin",
+    "cacherai",
+    "cacheras",
+    "cacherez",
+    "cachions",
+    "cailloux",
+    "calmerai",
+    "calmeras",
+    "calmerez",
+    "calmions",
+    "camarade",
+    "campagne",
+    "cantines",
+    "capables",
+    "caprices",
+    "capuches",
+    "caramels",
+    "caravane",
+    "carottes",
+    "carreaux",
+    "carriere",
+    "cartable",
+    "cascades",
+    "casserai",
+    "casseras",
+    "casserez",
+    "cassions",
+    "causerai",
+    "causeras",
+    "causerez",
+    "causions",
+    "cavernes",
+    "ceinture",
+    "cerceaux",
+    "cerveaux",
+    "chagrins",
+    "chaleurs",
+    "chambres",
+    "chameaux",
+    "champion",
+    "chandail",
+    "changeai",
+    "changeas",
+    "changees",
+    "changent",
+    "changera",
+    "changiez",
+    "chansons",
+    "chantais",
+    "chantait",
+    "chantant",
+    "chantees",
+    "chantent",
+    "chantera",
+    "chanteur",
+    "chantier",
+    "chantiez",
+    "chantons",
+    "chapeaux",
+    "chapitre",
+    "charbons",
+    "chargeai",
+    "chargeas",
+    "chargees",
+    "chargent",
+    "chargera",
+    "chargiez",
+    "chariots",
+    "charmant",
+    "chassais",
+    "chassait",
+    "chassant",
+    "chassees",
+    "chassent",
+    "chassera",
+    "chasseur",
+    "chassiez",
+    "chassons",
+    "chateaux",
+    "chaudron",
+    "cheminee",
+    "chemises",
+    "chenille",
+    "cherchai",
+    "cherchas",
+    "cherchee",
+    "chercher",
+    "cherches",
+    "cherchez",
+    "chiffons",
+    "chiffres",
+    "chocolat",
+    "chorales",
+    "chuchota",
+    "chuchote",
+    "chuterai",
+    "chuteras",
+    "chuterez",
+    "chutions",
+    "citoyens",
+    "classais",
+    "classait",
+    "classant",
+    "classees",
+    "classent",
+    "classera",
+    "classiez",
+    "classons",
+    "claviers",
+    "clochers",
+    "cocottes",
+    "cognerai",
+    "cogneras",
+    "cognerez",
+    "cognions",
+    "colleges",
+    "collegue",
+    "collerai",
+    "colleras",
+    "collerez",
+    "colliers",
+    "collines",
+    "collions",
+    "colombes",
+    "colonnes",
+    "coloriai",
+    "colorias",
+    "coloriee",
+    "colorier",
+    "colories",
+    "coloriez",
+    "comedies",
+    "comiques",
+    "commanda",
+    "commande",
+    "commenca",
+    "commence",
+    "commerce",
+    "communes",
+    "comparai",
+    "comparas",
+    "comparee",
+    "comparer",
+    "compares",
+    "comparez",
+    "complete",
+    "complets",
+    "comptais",
+    "comptait",
+    "comptant",
+    "comptees",
+    "comptent",
+    "comptera",
+    "comptiez",
+    "comptoir",
+    "comptons",
+    "concerts",
+    "concours",
+    "conduire",
+    "confiais",
+    "confiait",
+    "confiant",
+    "confiees",
+    "confient",
+    "confiera",
+    "confiiez",
+    "confions",
+    "conseils",
+    "conserva",
+    "conserve",
+    "consolai",
+    "consolas",
+    "consolee",
+    "consoler",
+    "consoles",
+    "consolez",
+    "contente",
+    "contents",
+    "continua",
+    "continue",
+    "contrats",
+    "controla",
+    "controle",
+    "coquille",
+    "corbeaux",
+    "correcte",
+    "corrects",
+    "corrigea",
+    "corrigee",
+    "corriger",
+    "corriges",
+    "corrigez",
+    "costumes",
+    "couchais",
+    "couchait",
+    "couchant",
+    "couchees",
+    "couchent",
+    "couchera",
+    "couchiez",
+    "couchons",
+    "coulerai",
+    "couleras",
+    "coulerez",
+    "couleurs",
+    "coulions",
+    "couloirs",
+    "coupable",
+    "couperai",
+    "couperas",
+    "couperez",
+    "coupions",
+    "courages",
+    "courants",
+    "courbais",
+    "courbait",
+    "courbant",
+    "courbees",
+    "courbent",
+    "courbera",
+    "courbiez",
+    "courbons",
+    "couronne",
+    "courrier",
+    "couteaux",
+    "couterai",
+    "couteras",
+    "couterez",
+    "coutions",
+    "coutumes",
+    "craindre",
+    "craintes",
+    "craintif",
+    "crapauds",
+    "cravates",
+    "creatifs",
+    "creative",
+    "creusais",
+    "creusait",
+    "creusant",
+    "creusees",
+    "creusent",
+    "creusera",
+    "creusiez",
+    "creusons",
+    "crevette",
+    "criaient",
+    "crierais",
+    "crierait",
+    "crierent",
+    "crierons",
+    "crieront",
+    "cristaux",
+    "critique",
+    "cruelles",
+    "cuillere",
+    "cuisinai",
+    "cuisinas",
+    "cuisinee",
+    "cuisiner",
+    "cuisines",
+    "cuisinez",
+    "cultures",
+    "curieuse",
+    "cyclisme",
+    "danserai",
+    "danseras",
+    "danserez",
+    "danseurs",
+    "dansions",
+    "dauphins",
+    "decidais",
+    "decidait",
+    "decidant",
+    "decidees",
+    "decident",
+    "decidera",
+    "decidiez",
+    "decidons",
+    "declarai",
+    "declaras",
+    "declaree",
+    "declarer",
+    "declares",
+    "declarez",
+    "decollai",
+    "decollas",
+    "decollee",
+    "decoller",
+    "decolles",
+    "decollez",
+    "decorais",
+    "decorait",
+    "decorant",
+    "decorees",
+    "decorent",
+    "decorera",
+    "decoriez",
+    "decorons",
+    "decoupai",
+    "decoupas",
+    "decoupee",
+    "decouper",
+    "decoupes",
+    "decoupez",
+    "degageai",
+    "degageas",
+    "degagees",
+    "degagent",
+    "degagera",
+    "degagiez",
+    "dejeunai",
+    "dejeunas",
+    "dejeunee",
+    "dejeuner",
+    "dejeunes",
+    "dejeunez",
+    "delicate",
+    "delicats",
+    "delivrai",
+    "delivras",
+    "delivree",
+    "delivrer",
+    "delivres",
+    "delivrez",
+    "demandai",
+    "demandas",
+    "demandee",
+    "demander",
+    "demandes",
+    "demandez",
+    "demarche",
+    "demenage",
+    "dentiste",
+    "depassai",
+    "depassas",
+    "depassee",
+    "depasser",
+    "depasses",
+    "depassez",
+    "deplacai",
+    "deplacas",
+    "deplacee",
+    "deplacer",
+    "deplaces",
+    "deplacez",
+    "deposais",
+    "deposait",
+    "deposant",
+    "deposees",
+    "deposent",
+    "deposera",
+    "deposiez",
+    "deposons",
+    "derniere",
+    "derniers",
+    "derriere",
+    "descendu",
+    "descente",
+    "desertes",
+    "desirais",
+    "desirait",
+    "desirant",
+    "desirees",
+    "desirent",
+    "desirera",
+    "desiriez",
+    "desirons",
+    "desserts",
+    "dessinai",
+    "dessinas",
+    "dessinee",
+    "dessiner",
+    "dessines",
+    "dessinez",
+    "detachai",
+    "detachas",
+    "detachee",
+    "detacher",
+    "detaches",
+    "detachez",
+    "detruire",
+    "devinais",
+    "devinait",
+    "devinant",
+    "devinees",
+    "devinent",
+    "devinera",
+    "deviniez",
+    "devinons",
+    "diamants",
+    "diapason",
+    "dimanche",
+    "dinaient",
+    "dinerais",
+    "dinerait",
+    "dinerent",
+    "dinerons",
+    "dineront",
+    "diplomes",
+    "directes",
+    "discours",
+    "discrete",
+    "discrets",
+    "discutai",
+    "discutas",
+    "discutee",
+    "discuter",
+    "discutes",
+    "discutez",
+    "disputes",
+    "distance",
+    "docteurs",
+    "domaines",
+    "domicile",
+    "dommages",
+    "dompteur",
+    "donnerai",
+    "donneras",
+    "donnerez",
+    "donnions",
+    "doraient",
+    "dorerais",
+    "dorerait",
+    "dorerent",
+    "dorerons",
+    "doreront",
+    "dossiers",
+    "douleurs",
+    "douterai",
+    "douteras",
+    "douterez",
+    "doutions",
+    "douzaine",
+    "drapeaux",
+    "duraient",
+    "durerais",
+    "durerait",
+    "durerent",
+    "durerons",
+    "dureront",
+    "echanges",
+    "echarpes",
+    "echelles",
+    "eclairai",
+    "eclairas",
+    "eclairee",
+    "eclairer",
+    "eclaires",
+    "eclairez",
+    "eclipses",
+    "economie",
+    "ecoutais",
+    "ecoutait",
+    "ecoutant",
+    "ecoutees",
+    "ecoutent",
+    "ecoutera",
+    "ecoutiez",
+    "ecoutons",
+    "ecriture",
+    "ecrivain",
+    "ecrivent",
+    "ecrivons",
+    "ecureuil",
+    "edifices",
+    "efficace",
+    "effrayai",
+    "effrayas",
+    "effrayee",
+    "effrayer",
+    "effrayes",
+    "effrayez",
+    "elegance",
+    "elegante",
+    "elegants",
+    "elephant",
+    "eleverai",
+    "eleveras",
+    "eleverez",
+    "elevions",
+    "emballai",
+    "emballas",
+    "emballee",
+    "emballer",
+    "emballes",
+    "emballez",
+    "embarras",
+    "embrassa",
+    "embrasse",
+    "emission",
+    "emmenais",
+    "emmenait",
+    "emmenant",
+    "emmenees",
+    "emmenent",
+    "emmenera",
+    "emmeniez",
+    "emmenons",
+    "emotions",
+    "emouvant",
+    "empechai",
+    "empechas",
+    "empechee",
+    "empecher",
+    "empeches",
+    "empechez",
+    "emportai",
+    "emportas",
+    "emportee",
+    "emporter",
+    "emportes",
+    "emportez",
+    "emprunta",
+    "emprunte",
+    "encadres",
+    "endormie",
+    "endormis",
+    "endroits",
+    "energies",
+    "enfances",
+    "enfermai",
+    "enfermas",
+    "enfermee",
+    "enfermer",
+    "enfermes",
+    "enfermez",
+    "enlevais",
+    "enlevait",
+    "enlevant",
+    "enlevees",
+    "enlevent",
+    "enlevera",
+    "enleviez",
+    "enlevons",
+    "ennuyeux",
+    "enquetes",
+    "enseigna",
+    "enseigne",
+    "ensemble",
+    "entendre",
+    "entieres",
+    "entourai",
+    "entouras",
+    "entouree",
+    "entourer",
+    "entoures",
+    "entourez",
+    "entracte",
+    "entraina",
+    "entraine",
+    "entrepot",
+    "entrerai",
+    "entreras",
+    "entrerez",
+    "entrions",
+    "envolais",
+    "envolait",
+    "envolant",
+    "envolees",
+    "envolent",
+    "envolera",
+    "envoliez",
+    "envolons",
+    "epaisses",
+    "epelerai",
+    "epeleras",
+    "epelerez",
+    "epelions",
+    "epicerie",
+    "episodes",
+    "equateur",
+    "escalada",
+    "escalade",
+    "escalier",
+    "escargot",
+    "esperais",
+    "esperait",
+    "esperant",
+    "esperees",
+    "esperent",
+    "esperera",
+    "esperiez",
+    "esperons",
+    "essuiees",
+    "essuient",
+    "essuiera",
+    "essuyais",
+    "essuyait",
+    "essuyant",
+    "essuyiez",
+    "essuyons",
+    "estomacs",
+    "etageres",
+    "eteindre",
+    "etonnais",
+    "etonnait",
+    "etonnant",
+    "etonnees",
+    "etonnent",
+    "etonnera",
+    "etonniez",
+    "etonnons",
+    "etranges",
+    "etroites",
+    "etudiais",
+    "etudiait",
+    "etudiant",
+    "etudiees",
+    "etudient",
+    "etudiera",
+    "etudiiez",
+    "etudions",
+    "evasions",
+    "eventail",
+    "eviterai",
+    "eviteras",
+    "eviterez",
+    "evitions",
+    "examinai",
+    "examinas",
+    "examinee",
+    "examiner",
+    "examines",
+    "examinez",
+    "exemples",
+    "exercice",
+    "existais",
+    "existait",
+    "existant",
+    "existees",
+    "existent",
+    "existera",
+    "existiez",
+    "existons",
+    "expliqua",
+    "explique",
+    "explosai",
+    "explosas",
+    "explosee",
+    "exploser",
+    "exploses",
+    "explosez",
+    "fabriqua",
+    "fabrique",
+    "facherai",
+    "facheras",
+    "facherez",
+    "fachions",
+    "facteurs",
+    "familier",
+    "familles",
+    "fatigues",
+    "fauteuil",
+    "favorite",
+    "fenetres",
+    "fenouils",
+    "fermerai",
+    "fermeras",
+    "fermerez",
+    "fermions",
+    "festival",
+    "fetaient",
+    "feterais",
+    "feterait",
+    "feterent",
+    "feterons",
+    "feteront",
+    "feuilles",
+    "ficelles",
+    "figurine",
+    "finissez",
+    "fixaient",
+    "fixerais",
+    "fixerait",
+    "fixerent",
+    "fixerons",
+    "fixeront",
+    "flamants",
+    "flottais",
+    "flottait",
+    "flottant",
+    "flottees",
+    "flottent",
+    "flottera",
+    "flottiez",
+    "flottons",
+    "fontaine",
+    "forgeron",
+    "formerai",
+    "formeras",
+    "formerez",
+    "formions",
+    "fortunes",
+    "fouillai",
+    "fouillas",
+    "fouillee",
+    "fouiller",
+    "fouilles",
+    "fouillez",
+    "fourneau",
+    "fourrure",
+    "fraction",
+    "fragiles",
+    "fragment",
+    "fraiches",
+    "francais",
+    "franches",
+    "frappais",
+    "frappait",
+    "frappant",
+    "frappees",
+    "frappent",
+    "frappera",
+    "frappiez",
+    "frappons",
+    "frissons",
+    "fromager",
+    "fromages",
+    "fumaient",
+    "fumerais",
+    "fumerait",
+    "fumerent",
+    "fumerons",
+    "fumeront",
+    "furieuse",
+    "gagnerai",
+    "gagneras",
+    "gagnerez",
+    "gagnions",
+    "galeries",
+    "galettes",
+    "garderai",
+    "garderas",
+    "garderez",
+    "garderie",
+    "gardiens",
+    "gardions",
+    "gaspilla",
+    "gaspille",
+    "gataient",
+    "gaterais",
+    "gaterait",
+    "gaterent",
+    "gaterons",
+    "gateront",
+    "gazelles",
+    "genaient",
+    "gendarme",
+    "generais",
+    "generait",
+    "generent",
+    "genereux",
+    "generons",
+    "generont",
+    "gentille",
+    "glaciale",
+    "glaciaux",
+    "glissais",
+    "glissait",
+    "glissant",
+    "glissees",
+    "glissent",
+    "glissera",
+    "glissiez",
+    "glissons",
+    "glorieux",
+    "gobelets",
+    "goelands",
+    "gorilles",
+    "gouffres",
+    "gourmand",
+    "gouterai",
+    "gouteras",
+    "gouterez",
+    "goutions",
+    "gracieux",
+    "gratuite",
+    "gratuits",
+    "grenades",
+    "greniers",
+    "grignota",
+    "grignote",
+    "grillage",
+    "grimaces",
+    "grimpais",
+    "grimpait",
+    "grimpant",
+    "grimpees",
+    "grimpent",
+    "grimpera",
+    "grimpiez",
+    "grimpons",
+    "grondais",
+    "grondait",
+    "grondant",
+    "grondees",
+    "grondent",
+    "grondera",
+    "grondiez",
+    "grondons",
+    "guitares",
+    "gymnases",
+    "habillai",
+    "habillas",
+    "habillee",
+    "habiller",
+    "habilles",
+    "habillez",
+    "habitais",
+    "habitait",
+    "habitant",
+    "habitees",
+    "habitent",
+    "habitera",
+    "habitiez",
+    "habitons",
+    "habitude",
+    "hamsters",
+    "haricots",
+    "harmonie",
+    "hauteurs",
+    "herisson",
+    "heritage",
+    "hesitais",
+    "hesitait",
+    "hesitant",
+    "hesitees",
+    "hesitent",
+    "hesitera",
+    "hesitiez",
+    "hesitons",
+    "heureuse",
+    "histoire",
+    "honnetes",
+    "honneurs",
+    "hopitaux",
+    "horizons",
+    "horloges",
+    "horrible",
+    "humaines",
+    "hurlerai",
+    "hurleras",
+    "hurlerez",
+    "hurlions",
+    "hygienes",
+    "ignorais",
+    "ignorait",
+    "ignorant",
+    "ignorees",
+    "ignorent",
+    "ignorera",
+    "ignoriez",
+    "ignorons",
+    "imaginai",
+    "imaginas",
+    "imaginee",
+    "imaginer",
+    "imagines",
+    "imaginez",
+    "immenses",
+    "incendie",
+    "innocent",
+    "inquiete",
+    "inquiets",
+    "insectes",
+    "instants",
+    "instinct",
+    "inutiles",
+    "inventai",
+    "inventas",
+    "inventee",
+    "inventer",
+    "inventes",
+    "inventez",
+    "invitais",
+    "invitait",
+    "invitant",
+    "invitees",
+    "invitent",
+    "invitera",
+    "invitiez",
+    "invitons",
+    "jacinthe",
+    "jalouses",
+    "jardinai",
+    "jardinas",
+    "jardinee",
+    "jardiner",
+    "jardines",
+    "jardinez",
+    "javelots",
+    "jouaient",
+    "jouerais",
+    "jouerait",
+    "jouerent",
+    "jouerons",
+    "joueront",
+    "journaux",
+    "journees",
+    "joyeuses",
+    "jumelles",
+    "juraient",
+    "jurerais",
+    "jurerait",
+    "jurerent",
+    "jurerons",
+    "jureront",
+    "justices",
+    "kiosques",
+    "laissais",
+    "laissait",
+    "laissant",
+    "laissees",
+    "laissent",
+    "laissera",
+    "laissiez",
+    "laissons",
+    "lancerai",
+    "lanceras",
+    "lancerez",
+    "lancions",
+    "lanterne",
+    "lavaient",
+    "laverais",
+    "laverait",
+    "laverent",
+    "laverons",
+    "laveront",
+    "legendes",
+    "levaient",
+    "leverais",
+    "leverait",
+    "leverent",
+    "leverons",
+    "leveront",
+    "libertes",
+    "limonade",
+    "liquides",
+    "livrerai",
+    "livreras",
+    "livrerez",
+    "livrions",
+    "logement",
+    "lointain",
+    "louaient",
+    "louanges",
+    "louerais",
+    "louerait",
+    "louerent",
+    "louerons",
+    "loueront",
+    "lucarnes",
+    "lumieres",
+    "lumineux",
+    "lunettes",
+    "machines",
+    "magasins",
+    "magicien",
+    "magnolia",
+    "maillots",
+    "mainates",
+    "maladies",
+    "malignes",
+    "manchots",
+    "mangeais",
+    "mangeait",
+    "mangeant",
+    "mangeons",
+    "mangerai",
+    "mangeras",
+    "mangerez",
+    "mangions",
+    "manieres",
+    "manquais",
+    "manquait",
+    "manquant",
+    "manquees",
+    "manquent",
+    "manquera",
+    "manquiez",
+    "manquons",
+    "manteaux",
+    "marchais",
+    "marchait",
+    "marchand",
+    "marchant",
+    "marchees",
+    "marchent",
+    "marchera",
+    "marchiez",
+    "marchons",
+    "mariages",
+    "marmites",
+    "marmotte",
+    "marquais",
+    "marquait",
+    "marquant",
+    "marquees",
+    "marquent",
+    "marquera",
+    "marquiez",
+    "marquons",
+    "marrones",
+    "martinet",
+    "matelots",
+    "mechante",
+    "mechants",
+    "medaille",
+    "medecins",
+    "meilleur",
+    "melangea",
+    "melangee",
+    "melanger",
+    "melanges",
+    "melangez",
+    "melodies",
+    "memoires",
+    "menaient",
+    "menerais",
+    "menerait",
+    "menerent",
+    "menerons",
+    "meneront",
+    "mensonge",
+    "mercredi",
+    "meritais",
+    "meritait",
+    "meritant",
+    "meritees",
+    "meritent",
+    "meritera",
+    "meritiez",
+    "meritons",
+    "mesanges",
+    "messages",
+    "mesurais",
+    "mesurait",
+    "mesurant",
+    "mesurees",
+    "mesurent",
+    "mesurera",
+    "mesuriez",
+    "mesurons",
+    "meuniers",
+    "microbes",
+    "minuteur",
+    "miracles",
+    "missions",
+    "modernes",
+    "moelleux",
+    "moineaux",
+    "monnaies",
+    "monsieur",
+    "montagne",
+    "monterai",
+    "monteras",
+    "monterez",
+    "montions",
+    "montrais",
+    "montrait",
+    "montrant",
+    "montrees",
+    "montrent",
+    "montrera",
+    "montriez",
+    "montrons",
+    "monument",
+    "morceaux",
+    "mosaique",
+    "mouchoir",
+    "mouettes",
+    "muraille",
+    "murmurai",
+    "murmuras",
+    "murmuree",
+    "murmurer",
+    "murmures",
+    "murmurez",
+    "musiques",
+    "mysteres",
+    "nageoire",
+    "nagerais",
+    "nagerait",
+    "nagerent",
+    "nagerons",
+    "nageront",
+    "naturels",
+    "naufrage",
+    "navettes",
+    "nerveuse",
+    "noisette",
+    "nombreux",
+    "nombrils",
+    "notaient",
+    "notaires",
+    "noterais",
+    "noterait",
+    "noterent",
+    "noterons",
+    "noteront",
+    "nouveaux",
+    "nouvelle",
+    "obscures",
+    "observai",
+    "observas",
+    "observee",
+    "observer",
+    "observes",
+    "observez",
+    "obstacle",
+    "officier",
+    "offrande",
+    "oiseleur",
+    "omelette",
+    "opinions",
+    "oreiller",
+    "oreilles",
+    "organisa",
+    "organise",
+    "orphelin",
+    "oubliais",
+    "oubliait",
+    "oubliant",
+    "oubliees",
+    "oublient",
+    "oubliera",
+    "oubliiez",
+    "oublions",
+    "ouragans",
+    "ouvriers",
+    "paisible",
+    "palmiers",
+    "panneaux",
+    "panorama",
+    "pantalon",
+    "papillon",
+    "paraitre",
+    "parapets",
+    "paravent",
+    "parcours",
+    "pardonna",
+    "pardonne",
+    "pareille",
+    "paresses",
+    "parfaite",
+    "parfaits",
+    "parlerai",
+    "parleras",
+    "parlerez",
+    "parlions",
+    "partagea",
+    "partagee",
+    "partager",
+    "partages",
+    "partagez",
+    "passager",
+    "passages",
+    "passerai",
+    "passeras",
+    "passerez",
+    "passions",
+    "passoire",
+    "pasteque",
+    "patience",
+    "patienta",
+    "patiente",
+    "patients",
+    "patinais",
+    "patinait",
+    "patinant",
+    "patinees",
+    "patinent",
+    "patinera",
+    "patiniez",
+    "patinons",
+    "paupiere",
+    "payaient",
+    "payerais",
+    "payerait",
+    "payerent",
+    "payerons",
+    "payeront",
+    "paysages",
+    "pecherai",
+    "pecheras",
+    "pecherez",
+    "pechions",
+    "pedalais",
+    "pedalait",
+    "pedalant",
+    "pedalees",
+    "pedalent",
+    "pedalera",
+    "pedaliez",
+    "pedalons",
+    "peignais",
+    "peignait",
+    "peignant",
+    "peignees",
+    "peignent",
+    "peignera",
+    "peigniez",
+    "peignons",
+    "peintres",
+    "pelicans",
+    "pelouses",
+    "peniches",
+    "penserai",
+    "penseras",
+    "penserez",
+    "pensions",
+    "pensives",
+    "personne",
+    "pesaient",
+    "peserais",
+    "peserait",
+    "peserent",
+    "peserons",
+    "peseront",
+    "petroles",
+    "peureuse",
+    "physique",
+    "pilotais",
+    "pilotait",
+    "pilotant",
+    "pilotees",
+    "pilotent",
+    "pilotera",
+    "pilotiez",
+    "pilotons",
+    "pinceaux",
+    "pingouin",
+    "piquerai",
+    "piqueras",
+    "piquerez",
+    "piquions",
+    "pirogues",
+    "piscines",
+    "pistache",
+    "placards",
+    "placerai",
+    "placeras",
+    "placerez",
+    "placions",
+    "plafonds",
+    "plaindre",
+    "plaisirs",
+    "planches",
+    "planetes",
+    "plantais",
+    "plantait",
+    "plantant",
+    "plantees",
+    "plantent",
+    "plantera",
+    "plantiez",
+    "plantons",
+    "plateaux",
+    "pleurais",
+    "pleurait",
+    "pleurant",
+    "pleurees",
+    "pleurent",
+    "pleurera",
+    "pleuriez",
+    "pleurons",
+    "pleuvoir",
+    "pliaient",
+    "plierais",
+    "plierait",
+    "plierent",
+    "plierons",
+    "plieront",
+    "plongeai",
+    "plongeas",
+    "plongees",
+    "plongent",
+    "plongera",
+    "plongeur",
+    "plongiez",
+    "plumages",
+    "poignees",
+    "poignets",
+    "poireaux",
+    "poissons",
+    "poitrine",
+    "pompiers",
+    "portails",
+    "porterai",
+    "porteras",
+    "porterez",
+    "portiere",
+    "portions",
+    "portrait",
+    "posaient",
+    "poserais",
+    "poserait",
+    "poserent",
+    "poserons",
+    "poseront",
+    "potagers",
+    "poubelle",
+    "pourquoi",
+    "pourtant",
+    "poussais",
+    "poussait",
+    "poussant",
+    "poussees",
+    "poussent",
+    "poussera",
+    "poussiez",
+    "poussins",
+    "poussons",
+    "pouvoirs",
+    "prairies",
+    "pratiqua",
+    "pratique",
+    "precieux",
+    "premiers",
+    "prennent",
+    "preparai",
+    "preparas",
+    "preparee",
+    "preparer",
+    "prepares",
+    "preparez",
+    "presenta",
+    "presente",
+    "presents",
+    "pression",
+    "preterai",
+    "preteras",
+    "preterez",
+    "pretions",
+    "priaient",
+    "prierais",
+    "prierait",
+    "prierent",
+    "prierons",
+    "prieront",
+    "principe",
+    "probleme",
+    "prochain",
+    "produire",
+    "profitai",
+    "profitas",
+    "profitee",
+    "profiter",
+    "profites",
+    "profitez",
+    "profonde",
+    "profonds",
+    "promesse",
+    "protegea",
+    "protegee",
+    "proteger",
+    "proteges",
+    "protegez",
+    "prouvais",
+    "prouvait",
+    "prouvant",
+    "prouvees",
+    "prouvent",
+    "prouvera",
+    "prouviez",
+    "prouvons",
+    "prudente",
+    "prudents",
+    "prunelle",
+    "publique",
+    "puissant",
+    "punition",
+    "pyramide",
+    "qualites",
+    "quantite",
+    "quartier",
+    "question",
+    "quittais",
+    "quittait",
+    "quittant",
+    "quittees",
+    "quittent",
+    "quittera",
+    "quittiez",
+    "quittons",
+    "racontai",
+    "racontas",
+    "racontee",
+    "raconter",
+    "racontes",
+    "racontez",
+    "ramassai",
+    "ramassas",
+    "ramassee",
+    "ramasser",
+    "ramasses",
+    "ramassez",
+    "ramenais",
+    "ramenait",
+    "ramenant",
+    "ramenees",
+    "ramenent",
+    "ramenera",
+    "rameniez",
+    "ramenons",
+    "rangeais",
+    "rangeait",
+    "rangeant",
+    "rangeons",
+    "rangerai",
+    "rangeras",
+    "rangerez",
+    "rangions",
+    "rapports",
+    "raquette",
+    "rassurai",
+    "rassuras",
+    "rassuree",
+    "rassurer",
+    "rassures",
+    "rassurez",
+    "rataient",
+    "raterais",
+    "raterait",
+    "raterent",
+    "raterons",
+    "rateront",
+    "recettes",
+    "recevoir",
+    "reclamai",
+    "reclamas",
+    "reclamee",
+    "reclamer",
+    "reclames",
+    "reclamez",
+    "recoltai",
+    "recoltas",
+    "recoltee",
+    "recolter",
+    "recoltes",
+    "recoltez",
+    "recopiai",
+    "recopias",
+    "recopiee",
+    "recopier",
+    "recopies",
+    "recopiez",
+    "regardai",
+    "regardas",
+    "regardee",
+    "regarder",
+    "regardes",
+    "regardez",
+    "regiment",
+    "regretta",
+    "regrette",
+    "regulier",
+    "relation",
+    "remarqua",
+    "remarque",
+    "remercia",
+    "remercie",
+    "remorque",
+    "renforts",
+    "rentrais",
+    "rentrait",
+    "rentrant",
+    "rentrees",
+    "rentrent",
+    "rentrera",
+    "rentriez",
+    "rentrons",
+    "reparais",
+    "reparait",
+    "reparant",
+    "reparees",
+    "reparent",
+    "reparera",
+    "repariez",
+    "reparons",
+    "repassai",
+    "repassas",
+    "repassee",
+    "repasser",
+    "repasses",
+    "repassez",
+    "repetais",
+    "repetait",
+    "repetant",
+    "repetees",
+    "repetent",
+    "repetera",
+    "repetiez",
+    "repetons",
+    "repondre",
+    "reponses",
+    "reposais",
+    "reposait",
+    "reposant",
+    "reposees",
+    "reposent",
+    "reposera",
+    "reposiez",
+    "reposons",
+    "requetes",
+    "reservai",
+    "reservas",
+    "reservee",
+    "reserver",
+    "reserves",
+    "reservez",
+    "resistai",
+    "resistas",
+    "resistee",
+    "resister",
+    "resistes",
+    "resistez",
+    "respirai",
+    "respiras",
+    "respiree",
+    "respirer",
+    "respires",
+    "respirez",
+    "resterai",
+    "resteras",
+    "resterez",
+    "restions",
+    "resultat",
+    "retourna",
+    "retourne",
+    "retrouva",
+    "retrouve",
+    "reunions",
+    "revaient",
+    "revanche",
+    "reveilla",
+    "reveille",
+    "reverais",
+    "reverait",
+    "reverent",
+    "reverons",
+    "reveront",
+    "richesse",
+    "ridicule",
+    "rigolais",
+    "rigolait",
+    "rigolant",
+    "rigolees",
+    "rigolent",
+    "rigolera",
+    "rigoliez",
+    "rigoloes",
+    "rigolons",
+    "rivieres",
+    "roulerai",
+    "rouleras",
+    "roulerez",
+    "roulions",
+    "roulotte",
+    "rubrique",
+    "ruisseau",
+    "sacoches",
+    "sagesses",
+    "saladier",
+    "salaires",
+    "saluerai",
+    "salueras",
+    "saluerez",
+    "saluions",
+    "sandales",
+    "sanglier",
+    "sanglota",
+    "sanglote",
+    "saucisse",
+    "sauterai",
+    "sauteras",
+    "sauterez",
+    "sautions",
+    "sauvages",
+    "sauverai",
+    "sauveras",
+    "sauverez",
+    "sauvions",
+    "savantes",
+    "scarabee",
+    "sciences",
+    "scooters",
+    "secretes",
+    "semaines",
+    "semblais",
+    "semblait",
+    "semblant",
+    "semblees",
+    "semblent",
+    "semblera",
+    "sembliez",
+    "semblons",
+    "semelles",
+    "sentiers",
+    "separais",
+    "separait",
+    "separant",
+    "separees",
+    "separent",
+    "separera",
+    "separiez",
+    "separons",
+    "serieuse",
+    "serpents",
+    "serrerai",
+    "serreras",
+    "serrerez",
+    "serrions",
+    "serrures",
+    "services",
+    "sifflais",
+    "sifflait",
+    "sifflant",
+    "sifflees",
+    "sifflent",
+    "sifflera",
+    "sifflets",
+    "siffliez",
+    "sifflons",
+    "signerai",
+    "signeras",
+    "signerez",
+    "signions",
+    "silences",
+    "sinceres",
+    "soignais",
+    "soignait",
+    "soignant",
+    "soignees",
+    "soignent",
+    "soignera",
+    "soigniez",
+    "soignons",
+    "sommeils",
+    "sonnerai",
+    "sonneras",
+    "sonnerez",
+    "sonnions",
+    "sorciere",
+    "sorciers",
+    "soucoupe",
+    "soufflai",
+    "soufflas",
+    "soufflee",
+    "souffler",
+    "souffles",
+    "soufflez",
+    "souffrir",
+    "souhaita",
+    "souhaite",
+    "souliers",
+    "sourcils",
+    "sourires",
+    "souvenir",
+    "spatules",
+    "stupides",
+    "subtiles",
+    "sucaient",
+    "sucerais",
+    "sucerait",
+    "sucerent",
+    "sucerons",
+    "suceront",
+    "superbes",
+    "supporta",
+    "supporte",
+    "surfaces",
+    "surprise",
+    "suspense",
+    "systemes",
+    "tableaux",
+    "tabouret",
+    "tambours",
+    "taquinai",
+    "taquinas",
+    "taquinee",
+    "taquiner",
+    "taquines",
+    "taquinez",
+    "tarderai",
+    "tarderas",
+    "tarderez",
+    "tardions",
+    "tardives",
+    "tartines",
+    "taureaux",
+    "tempetes",
+    "terminai",
+    "terminas",
+    "terminee",
+    "terminer",
+    "termines",
+    "terminez",
+    "terrains",
+    "terrasse",
+    "terrible",
+    "theatres",
+    "tiennent",
+    "tilleuls",
+    "tiraient",
+    "tirelire",
+    "tirerais",
+    "tirerait",
+    "tirerent",
+    "tirerons",
+    "tireront",
+    "toboggan",
+    "tomberai",
+    "tomberas",
+    "tomberez",
+    "tombions",
+    "tonneaux",
+    "tonnerre",
+    "torchons",
+    "tornades",
+    "touchais",
+    "touchait",
+    "touchant",
+    "touchees",
+    "touchent",
+    "touchera",
+    "touchiez",
+    "touchons",
+    "toujours",
+    "tournais",
+    "tournait",
+    "tournant",
+    "tournees",
+    "tournent",
+    "tournera",
+    "tourniez",
+    "tournois",
+    "tournons",
+    "toussais",
+    "toussait",
+    "toussant",
+    "toussees",
+    "toussent",
+    "toussera",
+    "toussiez",
+    "toussons",
+    "tracerai",
+    "traceras",
+    "tracerez",
+    "tracions",
+    "tracteur",
+    "traduire",
+    "traineau",
+    "tramways",
+    "tranches",
+    "trapezes",
+    "traversa",
+    "traverse",
+    "tremblai",
+    "tremblas",
+    "tremblee",
+    "trembler",
+    "trembles",
+    "tremblez",
+    "tremplin",
+    "tribunal",
+    "trichais",
+    "trichait",
+    "trichant",
+    "trichees",
+    "trichent",
+    "trichera",
+    "trichiez",
+    "trichons",
+    "tricycle",
+    "triomphe",
+    "trompais",
+    "trompait",
+    "trompant",
+    "trompees",
+    "trompent",
+    "trompera",
+    "trompiez",
+    "trompons",
+    "trophees",
+    "trottais",
+    "trottait",
+    "trottant",
+    "trottees",
+    "trottent",
+    "trottera",
+    "trottiez",
+    "trottoir",
+    "trottons",
+    "troupeau",
+    "trouvais",
+    "trouvait",
+    "trouvant",
+    "trouvees",
+    "trouvent",
+    "trouvera",
+    "trouviez",
+    "trouvons",
+    "uniforme",
+    "urgentes",
+    "vacances",
+    "vaisseau",
+    "valserai",
+    "valseras",
+    "valserez",
+    "valsions",
+    "vampires",
+    "vanilles",
+    "vautours",
+    "vedettes",
+    "vendeurs",
+    "vendredi",
+    "verdures",
+    "verifiai",
+    "verifias",
+    "verifiee",
+    "verifier",
+    "verifies",
+    "verifiez",
+    "verserai",
+    "verseras",
+    "verserez",
+    "versions",
+    "vetement",
+    "victoire",
+    "vieilles",
+    "viennent",
+    "vignoble",
+    "vilaines",
+    "villages",
+    "vinaigre",
+    "violette",
+    "visitais",
+    "visitait",
+    "visitant",
+    "visitees",
+    "visitent",
+    "visitera",
+    "visitiez",
+    "visitons",
+    "vitesses",
+    "vitrines",
+    "vivantes",
+    "voisines",
+    "voitures",
+    "volaient",
+    "volaille",
+    "volerais",
+    "volerait",
+    "volerent",
+    "volerons",
+    "voleront",
+    "volontes",
+    "votaient",
+    "voterais",
+    "voterait",
+    "voterent",
+    "voterons",
+    "voteront",
+    "voudrait",
+    "voyageai",
+    "voyageas",
+    "voyagees",
+    "voyagent",
+    "voyagera",
+    "voyageur",
+    "voyagiez",
+    "voyelles"
+};
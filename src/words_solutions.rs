@@ -1,5 +1,9 @@
 use rand::Rng;
-use crate::utils;
+use time::Date;
+use crate::{ utils::{ self, SupportedLangs }, words_solutions_fr };
+
+// Spreads consecutive days across the list so neighbouring days don't get neighbouring words
+const DAILY_WORD_STEP: i64 = 7919;
 
 /* 
  * 
@@ -16,11 +20,12 @@ use crate::utils;
  * 
  * 
  * 
- * The list of recognizable words from which we randomly
- * select a "solution" word for each game. This is a subset of
- * the master list in words_all.
+ * The lists of recognizable words from which we randomly
+ * select a "solution" word for each game, one list per word length.
+ * Each is a subset of the matching master list in words_all.
  * 
- * Both these lists come from YAWL (yet another word list)
+ * The five-letter lists come from YAWL (yet another word list)
+ * French games draw from words_solutions_fr.
  * 
  * 
  * 
@@ -31,10 +36,36 @@ use crate::utils;
  * Randomly select one of these words as a "solution."
  * The solution will go in the DB for the game that
  * selected the word.
+ * Unsupported lengths fall back to five letters.
+ */
+pub fn get_random_word(word_length: u8, lang: &SupportedLangs) -> String {
+    let solutions: &[&str] = match (lang, word_length) {
+        (SupportedLangs::English, 4) => &FOUR_LETTER_SOLUTIONS,
+        (SupportedLangs::English, 6) => &SIX_LETTER_SOLUTIONS,
+        (SupportedLangs::English, 7) => &SEVEN_LETTER_SOLUTIONS,
+        (SupportedLangs::English, 8) => &EIGHT_LETTER_SOLUTIONS,
+        (SupportedLangs::English, _) => &SOLUTIONS,
+        (SupportedLangs::French, 4) => &words_solutions_fr::FOUR_LETTER_SOLUTIONS,
+        (SupportedLangs::French, 6) => &words_solutions_fr::SIX_LETTER_SOLUTIONS,
+        (SupportedLangs::French, 7) => &words_solutions_fr::SEVEN_LETTER_SOLUTIONS,
+        (SupportedLangs::French, 8) => &words_solutions_fr::EIGHT_LETTER_SOLUTIONS,
+        (SupportedLangs::French, _) => &words_solutions_fr::FIVE_LETTER_SOLUTIONS
+    };
+
+    let index: usize = rand::rng().random_range(0..solutions.len());
+    utils::all_caps(solutions[index])
+}
+
+
+/**
+ * The daily challenge word for a given (UTC) day.
+ * No randomness: the same day always gives the same five-letter word,
+ * so everybody gets the same puzzle and we can reproduce it later.
  */
-pub fn get_random_word() -> String {
-    let index: usize = rand::rng().random_range(0..SOLUTIONS.len());
-    utils::all_caps( SOLUTIONS[index])
+pub fn get_daily_word(challenge_date: Date) -> String {
+    let day_number: i64 = challenge_date.to_julian_day() as i64;
+    let index: usize = (day_number * DAILY_WORD_STEP).rem_euclid(SOLUTIONS.len() as i64) as usize;
+    utils::all_caps(SOLUTIONS[index])
 }
 
 
@@ -2312,4 +2343,3222 @@ static SOLUTIONS: [&str; 2273] = [
     "zebra",
     "zesty",
     "zones"
-];
\ No newline at end of file
+];
+
+
+static FOUR_LETTER_SOLUTIONS: [&str; 572] = [
+    "able",
+    "ably",
+    "acid",
+    "airy",
+    "also",
+    "anoa",
+    "arch",
+    "area",
+    "army",
+    "atom",
+    "aunt",
+    "auto",
+    "avid",
+    "away",
+    "awed",
+    "axis",
+    "baby",
+    "ball",
+    "base",
+    "bass",
+    "bean",
+    "bear",
+    "beef",
+    "belt",
+    "best",
+    "beta",
+    "bike",
+    "bind",
+    "bird",
+    "blue",
+    "blur",
+    "boar",
+    "boat",
+    "body",
+    "boil",
+    "bold",
+    "bomb",
+    "bone",
+    "book",
+    "boss",
+    "buck",
+    "buff",
+    "bulb",
+    "bulk",
+    "bull",
+    "busy",
+    "buzz",
+    "cage",
+    "cake",
+    "calf",
+    "call",
+    "calm",
+    "camp",
+    "card",
+    "carp",
+    "cart",
+    "case",
+    "cash",
+    "cave",
+    "char",
+    "chat",
+    "chef",
+    "chic",
+    "chow",
+    "chub",
+    "city",
+    "clam",
+    "clap",
+    "claw",
+    "clay",
+    "clip",
+    "clog",
+    "club",
+    "code",
+    "coil",
+    "coin",
+    "colt",
+    "come",
+    "cony",
+    "cook",
+    "cool",
+    "copy",
+    "core",
+    "corn",
+    "cost",
+    "crab",
+    "cram",
+    "crew",
+    "crop",
+    "crow",
+    "cube",
+    "cusk",
+    "cute",
+    "dace",
+    "damp",
+    "dane",
+    "dash",
+    "dawn",
+    "deal",
+    "dear",
+    "deep",
+    "deer",
+    "deft",
+    "defy",
+    "deny",
+    "desk",
+    "dial",
+    "dice",
+    "diet",
+    "dirt",
+    "dish",
+    "dodo",
+    "doll",
+    "door",
+    "dory",
+    "dose",
+    "dove",
+    "draw",
+    "drip",
+    "drop",
+    "drum",
+    "duck",
+    "duly",
+    "dumb",
+    "dune",
+    "dust",
+    "duty",
+    "earn",
+    "east",
+    "easy",
+    "echo",
+    "edge",
+    "edit",
+    "else",
+    "epic",
+    "evil",
+    "exit",
+    "face",
+    "fade",
+    "fair",
+    "fall",
+    "fame",
+    "farm",
+    "fast",
+    "fawn",
+    "feed",
+    "feel",
+    "file",
+    "film",
+    "find",
+    "fine",
+    "fire",
+    "firm",
+    "fish",
+    "flag",
+    "flat",
+    "flea",
+    "flee",
+    "flip",
+    "foal",
+    "foam",
+    "foil",
+    "fold",
+    "fond",
+    "food",
+    "foot",
+    "fork",
+    "fowl",
+    "foxy",
+    "free",
+    "frog",
+    "fuel",
+    "full",
+    "fury",
+    "gain",
+    "game",
+    "gasp",
+    "gate",
+    "gaur",
+    "gaze",
+    "gift",
+    "girl",
+    "give",
+    "glad",
+    "glow",
+    "glue",
+    "gnat",
+    "goat",
+    "goby",
+    "gold",
+    "good",
+    "gown",
+    "grab",
+    "grid",
+    "grit",
+    "grow",
+    "grub",
+    "guan",
+    "gull",
+    "hair",
+    "hake",
+    "hale",
+    "half",
+    "hand",
+    "hard",
+    "hare",
+    "have",
+    "hawk",
+    "head",
+    "help",
+    "hero",
+    "high",
+    "hill",
+    "hint",
+    "hire",
+    "hold",
+    "hole",
+    "holy",
+    "home",
+    "hood",
+    "hope",
+    "horn",
+    "host",
+    "hour",
+    "huge",
+    "hunt",
+    "hurt",
+    "ibex",
+    "ibis",
+    "icon",
+    "idea",
+    "idle",
+    "idly",
+    "inch",
+    "into",
+    "iron",
+    "item",
+    "jazz",
+    "joey",
+    "join",
+    "joke",
+    "jump",
+    "junk",
+    "just",
+    "kagu",
+    "keen",
+    "keep",
+    "kick",
+    "kind",
+    "kiss",
+    "kite",
+    "kiwi",
+    "knee",
+    "know",
+    "koel",
+    "kudu",
+    "lady",
+    "lake",
+    "lamb",
+    "lamp",
+    "lark",
+    "lava",
+    "lawn",
+    "lazy",
+    "leaf",
+    "left",
+    "lend",
+    "lens",
+    "liar",
+    "life",
+    "lift",
+    "like",
+    "limb",
+    "ling",
+    "link",
+    "lion",
+    "list",
+    "live",
+    "load",
+    "loan",
+    "lock",
+    "long",
+    "loon",
+    "loop",
+    "loud",
+    "love",
+    "lush",
+    "lynx",
+    "maid",
+    "mail",
+    "main",
+    "make",
+    "mako",
+    "many",
+    "mara",
+    "mare",
+    "mask",
+    "mass",
+    "math",
+    "maze",
+    "mean",
+    "meat",
+    "meek",
+    "meet",
+    "melt",
+    "menu",
+    "mesh",
+    "milk",
+    "mind",
+    "mink",
+    "mint",
+    "miss",
+    "mite",
+    "mola",
+    "mole",
+    "moon",
+    "more",
+    "moth",
+    "move",
+    "much",
+    "mule",
+    "must",
+    "mutt",
+    "myth",
+    "name",
+    "near",
+    "neat",
+    "neck",
+    "need",
+    "nest",
+    "news",
+    "newt",
+    "next",
+    "nice",
+    "nose",
+    "note",
+    "obey",
+    "odor",
+    "okay",
+    "omit",
+    "once",
+    "only",
+    "opah",
+    "open",
+    "orca",
+    "oryx",
+    "oval",
+    "oven",
+    "over",
+    "pact",
+    "page",
+    "pair",
+    "palm",
+    "park",
+    "pass",
+    "path",
+    "pave",
+    "pear",
+    "pert",
+    "pika",
+    "pike",
+    "pill",
+    "pink",
+    "pipe",
+    "play",
+    "plug",
+    "poem",
+    "poet",
+    "pole",
+    "pond",
+    "pony",
+    "pool",
+    "post",
+    "pull",
+    "pulp",
+    "puma",
+    "pure",
+    "push",
+    "quit",
+    "quiz",
+    "race",
+    "rack",
+    "racy",
+    "rail",
+    "rain",
+    "ramp",
+    "rapt",
+    "rare",
+    "rate",
+    "real",
+    "rely",
+    "rent",
+    "rhea",
+    "rice",
+    "rich",
+    "ride",
+    "ring",
+    "riot",
+    "risk",
+    "road",
+    "roof",
+    "rook",
+    "room",
+    "rose",
+    "rosy",
+    "rudd",
+    "rude",
+    "ruff",
+    "rule",
+    "safe",
+    "sage",
+    "sail",
+    "salt",
+    "same",
+    "sand",
+    "save",
+    "scad",
+    "scan",
+    "scup",
+    "seal",
+    "seat",
+    "seed",
+    "seek",
+    "sell",
+    "shad",
+    "shed",
+    "ship",
+    "shoe",
+    "shop",
+    "sick",
+    "side",
+    "sign",
+    "silk",
+    "sing",
+    "size",
+    "skin",
+    "skua",
+    "slab",
+    "slam",
+    "slim",
+    "slot",
+    "slow",
+    "slug",
+    "smew",
+    "snap",
+    "snow",
+    "snug",
+    "soap",
+    "sock",
+    "soda",
+    "soft",
+    "sole",
+    "song",
+    "soon",
+    "sora",
+    "sort",
+    "soul",
+    "soup",
+    "spin",
+    "spot",
+    "spry",
+    "stag",
+    "star",
+    "stay",
+    "stem",
+    "step",
+    "stud",
+    "such",
+    "suit",
+    "sure",
+    "swan",
+    "swap",
+    "swim",
+    "tahr",
+    "tail",
+    "talk",
+    "tank",
+    "tape",
+    "task",
+    "taxi",
+    "teal",
+    "team",
+    "tell",
+    "tent",
+    "term",
+    "tern",
+    "test",
+    "text",
+    "that",
+    "then",
+    "they",
+    "this",
+    "tick",
+    "tide",
+    "tidy",
+    "tilt",
+    "time",
+    "tiny",
+    "toad",
+    "tody",
+    "tone",
+    "tool",
+    "topi",
+    "tops",
+    "toss",
+    "town",
+    "trap",
+    "tray",
+    "tree",
+    "trim",
+    "trip",
+    "true",
+    "tube",
+    "tuna",
+    "turn",
+    "twin",
+    "type",
+    "ugly",
+    "undo",
+    "unit",
+    "upon",
+    "urge",
+    "used",
+    "vast",
+    "verb",
+    "very",
+    "view",
+    "visa",
+    "void",
+    "vole",
+    "vote",
+    "wage",
+    "wait",
+    "walk",
+    "wall",
+    "want",
+    "warm",
+    "wash",
+    "wasp",
+    "wave",
+    "wear",
+    "weka",
+    "well",
+    "west",
+    "what",
+    "when",
+    "whip",
+    "wide",
+    "wife",
+    "wild",
+    "will",
+    "wine",
+    "wing",
+    "wink",
+    "wire",
+    "wise",
+    "wish",
+    "wolf",
+    "wood",
+    "wool",
+    "word",
+    "work",
+    "worm",
+    "wrap",
+    "wren",
+    "yard",
+    "year",
+    "yeti",
+    "zany",
+    "zebu",
+    "zero",
+    "zone"
+];
+
+
+static SIX_LETTER_SOLUTIONS: [&str; 916] = [
+    "absent",
+    "absorb",
+    "absurd",
+    "access",
+    "accuse",
+    "acidly",
+    "across",
+    "action",
+    "active",
+    "actual",
+    "addict",
+    "adjust",
+    "adored",
+    "adroit",
+    "advice",
+    "affair",
+    "afford",
+    "afraid",
+    "agouti",
+    "airily",
+    "allied",
+    "almost",
+    "alpaca",
+    "always",
+    "amazed",
+    "amoeba",
+    "amount",
+    "amused",
+    "anchor",
+    "angler",
+    "angora",
+    "animal",
+    "annual",
+    "answer",
+    "appear",
+    "archly",
+    "arctic",
+    "ardent",
+    "around",
+    "arrest",
+    "arrive",
+    "artful",
+    "artist",
+    "aspect",
+    "assist",
+    "assume",
+    "asthma",
+    "astute",
+    "attack",
+    "attend",
+    "august",
+    "author",
+    "autumn",
+    "avidly",
+    "avocet",
+    "baboon",
+    "badger",
+    "baldly",
+    "bamboo",
+    "banana",
+    "banner",
+    "barbel",
+    "barbet",
+    "barely",
+    "barrel",
+    "basely",
+    "basket",
+    "basset",
+    "battle",
+    "beagle",
+    "beauty",
+    "become",
+    "bedbug",
+    "beetle",
+    "before",
+    "behave",
+    "behind",
+    "bengal",
+    "benign",
+    "betray",
+    "better",
+    "beyond",
+    "bigeye",
+    "bitter",
+    "blenny",
+    "blithe",
+    "blouse",
+    "bobcat",
+    "bodily",
+    "boldly",
+    "bonito",
+    "bonobo",
+    "border",
+    "boring",
+    "borrow",
+    "bottom",
+    "bounce",
+    "bowfin",
+    "brainy",
+    "brawny",
+    "breeze",
+    "breezy",
+    "bridge",
+    "bright",
+    "broken",
+    "bronze",
+    "bubble",
+    "bubbly",
+    "budget",
+    "bulbul",
+    "bullet",
+    "bundle",
+    "bunker",
+    "burbot",
+    "burden",
+    "burger",
+    "busily",
+    "butter",
+    "cactus",
+    "cagily",
+    "caiman",
+    "calmly",
+    "camera",
+    "cancel",
+    "cannon",
+    "canvas",
+    "canyon",
+    "carbon",
+    "caring",
+    "carpet",
+    "casino",
+    "castle",
+    "casual",
+    "cattle",
+    "caught",
+    "causal",
+    "celery",
+    "cement",
+    "census",
+    "cereal",
+    "change",
+    "charge",
+    "cheese",
+    "cherry",
+    "choice",
+    "choose",
+    "chummy",
+    "cicada",
+    "circle",
+    "classy",
+    "clever",
+    "client",
+    "clinic",
+    "clutch",
+    "coffee",
+    "cogent",
+    "coldly",
+    "collie",
+    "column",
+    "comely",
+    "common",
+    "condor",
+    "conger",
+    "coolly",
+    "copper",
+    "cosmic",
+    "cotton",
+    "cougar",
+    "couple",
+    "course",
+    "cousin",
+    "coyote",
+    "cradle",
+    "crater",
+    "creamy",
+    "credit",
+    "critic",
+    "crouch",
+    "cruise",
+    "crunch",
+    "cuckoo",
+    "cuddly",
+    "curlew",
+    "curtly",
+    "custom",
+    "cutely",
+    "dainty",
+    "damage",
+    "damply",
+    "danger",
+    "dapper",
+    "daring",
+    "darkly",
+    "darter",
+    "dassie",
+    "deadly",
+    "dearly",
+    "debate",
+    "debris",
+    "decade",
+    "decent",
+    "decide",
+    "deeply",
+    "define",
+    "deftly",
+    "degree",
+    "deluxe",
+    "demand",
+    "demise",
+    "denial",
+    "depart",
+    "depend",
+    "deputy",
+    "derive",
+    "desert",
+    "design",
+    "detail",
+    "detect",
+    "device",
+    "devote",
+    "devout",
+    "diesel",
+    "differ",
+    "dinner",
+    "dipper",
+    "direct",
+    "divert",
+    "divide",
+    "divine",
+    "doctor",
+    "domain",
+    "donate",
+    "donkey",
+    "doting",
+    "double",
+    "doubly",
+    "dourly",
+    "drably",
+    "dragon",
+    "dreamy",
+    "driven",
+    "drongo",
+    "dugong",
+    "dumbly",
+    "dunlin",
+    "during",
+    "earthy",
+    "earwig",
+    "easily",
+    "eerily",
+    "effort",
+    "either",
+    "embark",
+    "embody",
+    "emerge",
+    "employ",
+    "enable",
+    "energy",
+    "engage",
+    "engine",
+    "enlist",
+    "enough",
+    "enrich",
+    "enroll",
+    "ensure",
+    "entire",
+    "ermine",
+    "escape",
+    "estate",
+    "ethics",
+    "evenly",
+    "evilly",
+    "evolve",
+    "excess",
+    "excite",
+    "excuse",
+    "exotic",
+    "expand",
+    "expect",
+    "expert",
+    "expire",
+    "expose",
+    "extend",
+    "fabric",
+    "fairly",
+    "falcon",
+    "family",
+    "famous",
+    "father",
+    "feebly",
+    "feline",
+    "female",
+    "fennec",
+    "ferret",
+    "figure",
+    "filter",
+    "finely",
+    "finger",
+    "finish",
+    "firmly",
+    "fiscal",
+    "fisher",
+    "flashy",
+    "flatly",
+    "flavor",
+    "flight",
+    "flower",
+    "fluent",
+    "flying",
+    "follow",
+    "fondly",
+    "forest",
+    "forget",
+    "fossil",
+    "foster",
+    "foully",
+    "freely",
+    "friend",
+    "fringe",
+    "frisky",
+    "frozen",
+    "fulmar",
+    "future",
+    "gadget",
+    "galaxy",
+    "galore",
+    "gamely",
+    "gannet",
+    "garage",
+    "garden",
+    "garlic",
+    "gather",
+    "genial",
+    "genius",
+    "gentle",
+    "gently",
+    "gerbil",
+    "gibbon",
+    "gifted",
+    "giggle",
+    "ginger",
+    "giving",
+    "gladly",
+    "glance",
+    "glibly",
+    "glider",
+    "glumly",
+    "goblin",
+    "godwit",
+    "golden",
+    "goodly",
+    "gopher",
+    "gospel",
+    "gossip",
+    "govern",
+    "graced",
+    "grimly",
+    "grison",
+    "groovy",
+    "grouse",
+    "guided",
+    "guinea",
+    "guitar",
+    "gunnel",
+    "haloed",
+    "hammer",
+    "harbor",
+    "hardly",
+    "hazard",
+    "hazily",
+    "health",
+    "hearty",
+    "height",
+    "helmet",
+    "helped",
+    "hermit",
+    "heroic",
+    "hidden",
+    "highly",
+    "hockey",
+    "hollow",
+    "homely",
+    "honest",
+    "hoopoe",
+    "hornet",
+    "horror",
+    "hourly",
+    "hugely",
+    "humane",
+    "humble",
+    "humbly",
+    "hungry",
+    "hurdle",
+    "hybrid",
+    "ignore",
+    "iguana",
+    "immune",
+    "impact",
+    "impala",
+    "impish",
+    "impose",
+    "income",
+    "indoor",
+    "infant",
+    "inform",
+    "inhale",
+    "inject",
+    "injury",
+    "inmate",
+    "innate",
+    "insane",
+    "insect",
+    "inside",
+    "intact",
+    "intent",
+    "invest",
+    "invite",
+    "island",
+    "jabiru",
+    "jackal",
+    "jacket",
+    "jaeger",
+    "jaguar",
+    "jaunty",
+    "jennet",
+    "jerboa",
+    "jovial",
+    "joyful",
+    "joyous",
+    "jungle",
+    "junior",
+    "justly",
+    "kakapo",
+    "kalong",
+    "keenly",
+    "kelpie",
+    "kidney",
+    "kindly",
+    "kingly",
+    "kitten",
+    "kodiak",
+    "ladder",
+    "lamely",
+    "langur",
+    "laptop",
+    "lastly",
+    "lately",
+    "lavish",
+    "lawful",
+    "lazily",
+    "leader",
+    "legend",
+    "length",
+    "lesson",
+    "letter",
+    "lewdly",
+    "likely",
+    "limber",
+    "limpet",
+    "limply",
+    "linnet",
+    "liquid",
+    "little",
+    "lively",
+    "living",
+    "lizard",
+    "locust",
+    "lonely",
+    "lordly",
+    "loudly",
+    "lounge",
+    "louvar",
+    "lovely",
+    "loving",
+    "lumber",
+    "luxury",
+    "lyrics",
+    "maggot",
+    "magnet",
+    "magpie",
+    "maiden",
+    "mainly",
+    "mammal",
+    "manage",
+    "mantis",
+    "manual",
+    "marble",
+    "margay",
+    "margin",
+    "marine",
+    "market",
+    "marlin",
+    "marmot",
+    "marten",
+    "martin",
+    "master",
+    "matrix",
+    "matter",
+    "mature",
+    "mayfly",
+    "meadow",
+    "meanly",
+    "meekly",
+    "mellow",
+    "melody",
+    "member",
+    "memory",
+    "merely",
+    "merlin",
+    "method",
+    "middle",
+    "mighty",
+    "mildly",
+    "minnow",
+    "minute",
+    "mirror",
+    "misery",
+    "mobile",
+    "modern",
+    "modest",
+    "modify",
+    "moment",
+    "monkey",
+    "mostly",
+    "mother",
+    "motion",
+    "motmot",
+    "moving",
+    "muffin",
+    "mullet",
+    "muscle",
+    "museum",
+    "muskox",
+    "mutely",
+    "mutual",
+    "myself",
+    "namely",
+    "napkin",
+    "narrow",
+    "nation",
+    "native",
+    "nature",
+    "nearby",
+    "nearly",
+    "neatly",
+    "needed",
+    "nephew",
+    "nicely",
+    "nilgai",
+    "nimble",
+    "nimbly",
+    "noodle",
+    "normal",
+    "notice",
+    "numbat",
+    "number",
+    "numbly",
+    "nutria",
+    "object",
+    "oblige",
+    "obtain",
+    "ocelot",
+    "office",
+    "onager",
+    "online",
+    "openly",
+    "oppose",
+    "option",
+    "orally",
+    "orange",
+    "orient",
+    "oriole",
+    "orphan",
+    "osprey",
+    "output",
+    "overly",
+    "oxygen",
+    "oyster",
+    "paddle",
+    "palace",
+    "parade",
+    "parent",
+    "parrot",
+    "partly",
+    "patrol",
+    "peanut",
+    "pencil",
+    "people",
+    "pepper",
+    "permit",
+    "person",
+    "pertly",
+    "petite",
+    "petrel",
+    "phoebe",
+    "phrase",
+    "picked",
+    "picnic",
+    "pigeon",
+    "piglet",
+    "pistol",
+    "placid",
+    "plaice",
+    "planet",
+    "please",
+    "pledge",
+    "plover",
+    "plucky",
+    "plunge",
+    "poetic",
+    "poised",
+    "police",
+    "polite",
+    "poodle",
+    "poorly",
+    "possum",
+    "potato",
+    "potent",
+    "powder",
+    "praise",
+    "prefer",
+    "pretty",
+    "primal",
+    "primed",
+    "primly",
+    "prison",
+    "profit",
+    "prompt",
+    "proper",
+    "proven",
+    "public",
+    "puffer",
+    "puffin",
+    "pumped",
+    "purely",
+    "purity",
+    "puzzle",
+    "python",
+    "quagga",
+    "quahog",
+    "quaint",
+    "rabbit",
+    "racily",
+    "random",
+    "raptor",
+    "rarely",
+    "rashly",
+    "rather",
+    "really",
+    "reason",
+    "recall",
+    "recipe",
+    "record",
+    "reduce",
+    "reform",
+    "refuse",
+    "region",
+    "regret",
+    "reject",
+    "relief",
+    "remain",
+    "remind",
+    "remora",
+    "remove",
+    "render",
+    "reopen",
+    "repair",
+    "repeat",
+    "report",
+    "rescue",
+    "resist",
+    "rested",
+    "result",
+    "retire",
+    "return",
+    "reveal",
+    "review",
+    "reward",
+    "rhythm",
+    "ribbon",
+    "richly",
+    "ripely",
+    "ripple",
+    "ritual",
+    "robust",
+    "rocket",
+    "rodent",
+    "roller",
+    "rookie",
+    "rosily",
+    "rotate",
+    "roughy",
+    "roused",
+    "rubber",
+    "rudely",
+    "ruling",
+    "runway",
+    "sacred",
+    "saddle",
+    "safely",
+    "sagely",
+    "salmon",
+    "salute",
+    "sambar",
+    "sample",
+    "sanely",
+    "sauger",
+    "saving",
+    "sawfly",
+    "scheme",
+    "school",
+    "scoter",
+    "screen",
+    "script",
+    "search",
+    "season",
+    "second",
+    "secret",
+    "secure",
+    "seemly",
+    "select",
+    "senior",
+    "serene",
+    "series",
+    "serval",
+    "setter",
+    "settle",
+    "shadow",
+    "shield",
+    "shiner",
+    "shiver",
+    "shrewd",
+    "shrike",
+    "shrimp",
+    "sickly",
+    "silent",
+    "silver",
+    "simple",
+    "simply",
+    "sinewy",
+    "singly",
+    "siskin",
+    "sister",
+    "skater",
+    "sketch",
+    "slight",
+    "slogan",
+    "slowly",
+    "smooth",
+    "smugly",
+    "snappy",
+    "snugly",
+    "soccer",
+    "social",
+    "softly",
+    "solely",
+    "sorely",
+    "sought",
+    "source",
+    "sourly",
+    "speedy",
+    "sphere",
+    "spider",
+    "spirit",
+    "sponge",
+    "spread",
+    "spring",
+    "spruce",
+    "square",
+    "stable",
+    "stably",
+    "stairs",
+    "steady",
+    "stereo",
+    "street",
+    "strike",
+    "strong",
+    "sturdy",
+    "submit",
+    "subtle",
+    "subtly",
+    "subway",
+    "sudden",
+    "suffer",
+    "suited",
+    "summer",
+    "sunset",
+    "superb",
+    "supple",
+    "supply",
+    "surely",
+    "survey",
+    "switch",
+    "symbol",
+    "system",
+    "tackle",
+    "taipan",
+    "talent",
+    "tamely",
+    "target",
+    "tarpon",
+    "tartly",
+    "tattoo",
+    "tautly",
+    "tenant",
+    "tender",
+    "tennis",
+    "tenrec",
+    "termly",
+    "theory",
+    "thinly",
+    "thrive",
+    "thrush",
+    "ticket",
+    "tidily",
+    "timber",
+    "timely",
+    "tissue",
+    "toilet",
+    "tomato",
+    "tomcat",
+    "tongue",
+    "topple",
+    "toucan",
+    "touted",
+    "toward",
+    "towhee",
+    "tragic",
+    "travel",
+    "trimly",
+    "triply",
+    "trogon",
+    "trophy",
+    "trusty",
+    "tumble",
+    "tunnel",
+    "turaco",
+    "turbot",
+    "turkey",
+    "turtle",
+    "twelve",
+    "twenty",
+    "unable",
+    "unduly",
+    "unfair",
+    "unfold",
+    "unholy",
+    "unique",
+    "united",
+    "unlock",
+    "unveil",
+    "upbeat",
+    "update",
+    "uphold",
+    "uptown",
+    "upward",
+    "urbane",
+    "urchin",
+    "usable",
+    "useful",
+    "utmost",
+    "vacant",
+    "vacuum",
+    "vainly",
+    "valley",
+    "valued",
+    "vanish",
+    "vastly",
+    "velvet",
+    "vendor",
+    "verdin",
+    "verify",
+    "verily",
+    "versed",
+    "vervet",
+    "vessel",
+    "viable",
+    "vicuna",
+    "vilely",
+    "violin",
+    "virile",
+    "visual",
+    "volume",
+    "voyage",
+    "walnut",
+    "walrus",
+    "wanted",
+    "wapiti",
+    "warily",
+    "warmly",
+    "weakly",
+    "wealth",
+    "weapon",
+    "weasel",
+    "weekly",
+    "weevil",
+    "wholly",
+    "whydah",
+    "widely",
+    "wigeon",
+    "wildly",
+    "willet",
+    "window",
+    "winged",
+    "winner",
+    "winter",
+    "wisdom",
+    "wisely",
+    "wombat",
+    "wonder",
+    "worthy",
+    "wrasse",
+    "yearly",
+    "yellow",
+    "zander",
+    "zingel"
+];
+
+
+static SEVEN_LETTER_SOLUTIONS: [&str; 885] = [
+    "abandon",
+    "abiding",
+    "ability",
+    "account",
+    "achieve",
+    "acquire",
+    "actress",
+    "acutely",
+    "adapted",
+    "address",
+    "admiral",
+    "admired",
+    "adoring",
+    "advance",
+    "aerobic",
+    "affable",
+    "affably",
+    "agilely",
+    "airport",
+    "alcohol",
+    "alertly",
+    "alewife",
+    "allowed",
+    "already",
+    "amateur",
+    "amazing",
+    "amiable",
+    "amiably",
+    "amusing",
+    "analyst",
+    "anchovy",
+    "ancient",
+    "anemone",
+    "angelic",
+    "angrily",
+    "anhinga",
+    "another",
+    "antenna",
+    "antique",
+    "antlion",
+    "anxiety",
+    "apology",
+    "approve",
+    "aroused",
+    "arrange",
+    "artwork",
+    "assault",
+    "assured",
+    "athlete",
+    "attract",
+    "auction",
+    "audibly",
+    "aurally",
+    "average",
+    "avocado",
+    "awaited",
+    "awesome",
+    "awfully",
+    "awkward",
+    "axolotl",
+    "balance",
+    "balcony",
+    "bargain",
+    "batfish",
+    "bawdily",
+    "beaming",
+    "beastly",
+    "because",
+    "believe",
+    "beloved",
+    "benefit",
+    "bettong",
+    "between",
+    "bicycle",
+    "biology",
+    "bittern",
+    "blandly",
+    "blanket",
+    "blankly",
+    "blazing",
+    "bleakly",
+    "blesbok",
+    "blessed",
+    "blindly",
+    "blossom",
+    "bluejay",
+    "bluntly",
+    "boxfish",
+    "bracket",
+    "brashly",
+    "bravely",
+    "briefly",
+    "briskly",
+    "broadly",
+    "brocket",
+    "brother",
+    "budding",
+    "buffalo",
+    "bulldog",
+    "bunting",
+    "buoyant",
+    "bustard",
+    "buzzard",
+    "cabbage",
+    "calming",
+    "cannily",
+    "capable",
+    "capably",
+    "capelin",
+    "capital",
+    "captain",
+    "caracal",
+    "careful",
+    "caribou",
+    "catalog",
+    "catbird",
+    "catfish",
+    "caution",
+    "ceiling",
+    "central",
+    "century",
+    "certain",
+    "chamois",
+    "chapter",
+    "charily",
+    "charmed",
+    "cheaply",
+    "cheetah",
+    "chicken",
+    "chiefly",
+    "chigger",
+    "chimney",
+    "chinook",
+    "chipper",
+    "chronic",
+    "chuckle",
+    "cichlid",
+    "citizen",
+    "civilly",
+    "clarify",
+    "classic",
+    "cleanly",
+    "clearly",
+    "closely",
+    "closing",
+    "cluster",
+    "coconut",
+    "codling",
+    "collect",
+    "colobus",
+    "combine",
+    "comfort",
+    "comical",
+    "company",
+    "concert",
+    "concise",
+    "conduct",
+    "confirm",
+    "connect",
+    "content",
+    "control",
+    "copious",
+    "cordial",
+    "corking",
+    "correct",
+    "cotinga",
+    "country",
+    "courser",
+    "courtly",
+    "cowbird",
+    "cowfish",
+    "cranked",
+    "crappie",
+    "crawdad",
+    "crazily",
+    "creeper",
+    "cricket",
+    "crisply",
+    "croaker",
+    "crossly",
+    "crucial",
+    "crudely",
+    "cruelly",
+    "crumble",
+    "crystal",
+    "culture",
+    "cunning",
+    "curious",
+    "current",
+    "curtain",
+    "cushion",
+    "darling",
+    "dashing",
+    "dazzled",
+    "deathly",
+    "decline",
+    "defense",
+    "defiant",
+    "deliver",
+    "densely",
+    "dentist",
+    "deposit",
+    "desired",
+    "despair",
+    "destroy",
+    "develop",
+    "devoted",
+    "diagram",
+    "diamond",
+    "digital",
+    "dignity",
+    "dilemma",
+    "dingily",
+    "disease",
+    "dismiss",
+    "display",
+    "diverse",
+    "divorce",
+    "dizzily",
+    "dogfish",
+    "dolphin",
+    "dowdily",
+    "drastic",
+    "driving",
+    "dunnock",
+    "durable",
+    "durably",
+    "dutiful",
+    "dynamic",
+    "eagerly",
+    "earnest",
+    "earthly",
+    "echidna",
+    "ecology",
+    "economy",
+    "educate",
+    "eelpout",
+    "elegant",
+    "element",
+    "embrace",
+    "eminent",
+    "emotion",
+    "emperor",
+    "empower",
+    "enabled",
+    "endless",
+    "endorse",
+    "endowed",
+    "enforce",
+    "engaged",
+    "enhance",
+    "enjoyed",
+    "enticed",
+    "episode",
+    "equably",
+    "equally",
+    "erectly",
+    "erosion",
+    "erudite",
+    "escolar",
+    "essence",
+    "eternal",
+    "ethical",
+    "euglena",
+    "evident",
+    "evolved",
+    "exactly",
+    "exalted",
+    "example",
+    "excited",
+    "exclude",
+    "execute",
+    "exhaust",
+    "exhibit",
+    "explain",
+    "express",
+    "eyebrow",
+    "factual",
+    "faculty",
+    "faintly",
+    "falsely",
+    "fantail",
+    "fantasy",
+    "fashion",
+    "fatally",
+    "fatigue",
+    "feature",
+    "federal",
+    "fertile",
+    "fervent",
+    "festive",
+    "fiction",
+    "finally",
+    "finfoot",
+    "firefly",
+    "firstly",
+    "fitness",
+    "fitting",
+    "fixedly",
+    "fleshly",
+    "flowing",
+    "fortune",
+    "forward",
+    "fragile",
+    "frankly",
+    "freshly",
+    "funnily",
+    "furnace",
+    "fussily",
+    "gadwall",
+    "gainful",
+    "gallant",
+    "gallery",
+    "garbage",
+    "garfish",
+    "garment",
+    "garpike",
+    "gaudily",
+    "gazelle",
+    "gelding",
+    "gemsbok",
+    "general",
+    "genuine",
+    "gerenuk",
+    "gesture",
+    "ghastly",
+    "ghostly",
+    "giddily",
+    "giraffe",
+    "glimpse",
+    "glowing",
+    "gobbler",
+    "goddess",
+    "godlike",
+    "gorilla",
+    "goshawk",
+    "gourami",
+    "grackle",
+    "grandly",
+    "gravely",
+    "gravity",
+    "greatly",
+    "griffon",
+    "grizzly",
+    "grocery",
+    "grossly",
+    "grouper",
+    "growing",
+    "gruffly",
+    "grunter",
+    "guanaco",
+    "gudgeon",
+    "guiding",
+    "gurnard",
+    "haddock",
+    "hagfish",
+    "halibut",
+    "hamster",
+    "handily",
+    "happily",
+    "hardily",
+    "harrier",
+    "harshly",
+    "harvest",
+    "hastily",
+    "healing",
+    "healthy",
+    "heavily",
+    "helpful",
+    "helping",
+    "herring",
+    "history",
+    "hoatzin",
+    "holiday",
+    "honeyed",
+    "hopeful",
+    "huffily",
+    "humanly",
+    "hundred",
+    "husband",
+    "huskily",
+    "ideally",
+    "ignobly",
+    "illegal",
+    "illness",
+    "imitate",
+    "immense",
+    "improve",
+    "impulse",
+    "inanely",
+    "include",
+    "ineptly",
+    "inertly",
+    "inflict",
+    "inherit",
+    "initial",
+    "inquiry",
+    "inspire",
+    "install",
+    "intense",
+    "involve",
+    "irately",
+    "isolate",
+    "jacamar",
+    "jackdaw",
+    "javelin",
+    "jawfish",
+    "jaybird",
+    "jealous",
+    "jerkily",
+    "jesting",
+    "jewfish",
+    "jocular",
+    "jointed",
+    "jointly",
+    "journey",
+    "katydid",
+    "kestrel",
+    "ketchup",
+    "kindred",
+    "kingdom",
+    "kinglet",
+    "kitchen",
+    "knowing",
+    "ladybug",
+    "lamprey",
+    "lapwing",
+    "largely",
+    "lasting",
+    "laundry",
+    "lawsuit",
+    "leading",
+    "lecture",
+    "legally",
+    "legible",
+    "legibly",
+    "leisure",
+    "lemming",
+    "lenient",
+    "leopard",
+    "liberal",
+    "liberty",
+    "library",
+    "license",
+    "lightly",
+    "limpkin",
+    "lioness",
+    "lithely",
+    "lividly",
+    "lobster",
+    "locally",
+    "loftily",
+    "logical",
+    "loosely",
+    "lottery",
+    "lovable",
+    "loyally",
+    "lucidly",
+    "luckily",
+    "luggage",
+    "luridly",
+    "lustily",
+    "macaque",
+    "machine",
+    "magical",
+    "mallard",
+    "mammoth",
+    "manakin",
+    "manatee",
+    "mandate",
+    "mansion",
+    "markhor",
+    "massive",
+    "mastiff",
+    "maximal",
+    "maximum",
+    "measure",
+    "meerkat",
+    "mention",
+    "merrily",
+    "message",
+    "messily",
+    "million",
+    "mindful",
+    "minimum",
+    "minivet",
+    "miracle",
+    "mistake",
+    "mistily",
+    "mixture",
+    "moistly",
+    "mollusk",
+    "monarch",
+    "mongrel",
+    "monitor",
+    "monster",
+    "monthly",
+    "moodily",
+    "mooneye",
+    "moorhen",
+    "morally",
+    "morning",
+    "mouflon",
+    "mudfish",
+    "murkily",
+    "musical",
+    "muskrat",
+    "mustang",
+    "mystery",
+    "naively",
+    "nakedly",
+    "narwhal",
+    "nasally",
+    "nastily",
+    "nattily",
+    "natural",
+    "neglect",
+    "neither",
+    "network",
+    "neutral",
+    "newborn",
+    "nightly",
+    "noisily",
+    "nominee",
+    "notable",
+    "notably",
+    "nothing",
+    "nuclear",
+    "oarfish",
+    "obscure",
+    "observe",
+    "obvious",
+    "october",
+    "octopus",
+    "oilbird",
+    "oldwife",
+    "olympic",
+    "opinion",
+    "opossum",
+    "optimal",
+    "optimum",
+    "opulent",
+    "orchard",
+    "orderly",
+    "organic",
+    "ostrich",
+    "outdoor",
+    "outside",
+    "overtly",
+    "pacific",
+    "panther",
+    "patient",
+    "pattern",
+    "payment",
+    "peacock",
+    "peafowl",
+    "peasant",
+    "peccary",
+    "pegasus",
+    "pelican",
+    "penalty",
+    "penguin",
+    "perfect",
+    "perkily",
+    "pettily",
+    "phoenix",
+    "picture",
+    "pigfish",
+    "pintail",
+    "pioneer",
+    "piously",
+    "piranha",
+    "pithily",
+    "pivotal",
+    "plainly",
+    "plastic",
+    "playful",
+    "pleased",
+    "pliable",
+    "poacher",
+    "pochard",
+    "pointer",
+    "polecat",
+    "pollock",
+    "pomfret",
+    "pompano",
+    "popular",
+    "portion",
+    "potoroo",
+    "pottery",
+    "poverty",
+    "praised",
+    "precise",
+    "predict",
+    "premier",
+    "premium",
+    "prepare",
+    "present",
+    "prevent",
+    "primary",
+    "primate",
+    "private",
+    "problem",
+    "process",
+    "produce",
+    "profuse",
+    "program",
+    "project",
+    "promote",
+    "prosper",
+    "protect",
+    "proudly",
+    "provide",
+    "prudent",
+    "psychic",
+    "pudding",
+    "pumpkin",
+    "purpose",
+    "pyramid",
+    "quality",
+    "quantum",
+    "quarter",
+    "queenly",
+    "queerly",
+    "quetzal",
+    "quickly",
+    "quietly",
+    "rabidly",
+    "raccoon",
+    "radiant",
+    "rapidly",
+    "rattail",
+    "rattler",
+    "readily",
+    "rebuild",
+    "receive",
+    "recycle",
+    "redbird",
+    "redfish",
+    "redhead",
+    "redpoll",
+    "refined",
+    "reflect",
+    "regally",
+    "regular",
+    "related",
+    "relaxed",
+    "release",
+    "renewed",
+    "replace",
+    "replete",
+    "reptile",
+    "require",
+    "restful",
+    "retreat",
+    "reunion",
+    "revered",
+    "revived",
+    "rightly",
+    "rigidly",
+    "romance",
+    "rooster",
+    "roughly",
+    "roundly",
+    "rousing",
+    "rowdily",
+    "royally",
+    "sadness",
+    "saintly",
+    "sardine",
+    "satisfy",
+    "satoshi",
+    "saucily",
+    "sausage",
+    "sawfish",
+    "scallop",
+    "scatter",
+    "scented",
+    "science",
+    "sculpin",
+    "seagull",
+    "section",
+    "secured",
+    "segment",
+    "seminar",
+    "sensual",
+    "seriema",
+    "service",
+    "session",
+    "settled",
+    "shakily",
+    "shallow",
+    "shapely",
+    "sharing",
+    "sharply",
+    "sheriff",
+    "shining",
+    "shortly",
+    "showily",
+    "shrilly",
+    "shuffle",
+    "sibling",
+    "similar",
+    "sincere",
+    "situate",
+    "skilled",
+    "skimmer",
+    "skylark",
+    "slackly",
+    "sleekly",
+    "sleeper",
+    "slender",
+    "slickly",
+    "smartly",
+    "smiling",
+    "smitten",
+    "snapper",
+    "snidely",
+    "soaring",
+    "soberly",
+    "soldier",
+    "solidly",
+    "someone",
+    "soothed",
+    "soundly",
+    "spaniel",
+    "sparely",
+    "sparrow",
+    "spatial",
+    "special",
+    "sponsor",
+    "squeeze",
+    "stadium",
+    "staidly",
+    "starkly",
+    "stately",
+    "staunch",
+    "steeply",
+    "stellar",
+    "sternly",
+    "stiffly",
+    "stirred",
+    "stomach",
+    "stonily",
+    "stoutly",
+    "student",
+    "stumble",
+    "stylish",
+    "suavely",
+    "subject",
+    "sublime",
+    "success",
+    "suggest",
+    "sulkily",
+    "summary",
+    "sunbeam",
+    "sunbird",
+    "sunfish",
+    "supreme",
+    "surface",
+    "suspect",
+    "sustain",
+    "swallow",
+    "swaying",
+    "sweeper",
+    "sweetly",
+    "swiftly",
+    "symptom",
+    "tacitly",
+    "tactful",
+    "tadpole",
+    "tamarin",
+    "tanager",
+    "tardily",
+    "tarsier",
+    "tattler",
+    "teeming",
+    "tenable",
+    "tensely",
+    "terrier",
+    "tersely",
+    "testily",
+    "thickly",
+    "thirdly",
+    "thought",
+    "thunder",
+    "tickled",
+    "tightly",
+    "tilapia",
+    "timidly",
+    "tinamou",
+    "tipsily",
+    "tiredly",
+    "tobacco",
+    "toddler",
+    "tonight",
+    "topical",
+    "tornado",
+    "totally",
+    "touched",
+    "toughly",
+    "tourist",
+    "traffic",
+    "trigger",
+    "tritely",
+    "trouble",
+    "trumpet",
+    "trusted",
+    "tuatara",
+    "tuition",
+    "tuneful",
+    "typical",
+    "unaware",
+    "uncover",
+    "ungodly",
+    "unhappy",
+    "unicorn",
+    "unified",
+    "uniform",
+    "unknown",
+    "unmanly",
+    "unusual",
+    "upgrade",
+    "upright",
+    "useless",
+    "usually",
+    "utility",
+    "utterly",
+    "vaguely",
+    "valiant",
+    "validly",
+    "various",
+    "vehicle",
+    "venally",
+    "vendace",
+    "venture",
+    "version",
+    "veteran",
+    "vibrant",
+    "vicious",
+    "victory",
+    "village",
+    "vintage",
+    "virtual",
+    "visibly",
+    "vitally",
+    "vividly",
+    "vocally",
+    "volcano",
+    "volubly",
+    "vulture",
+    "wagtail",
+    "wallaby",
+    "walleye",
+    "warbler",
+    "warfare",
+    "warrior",
+    "warthog",
+    "waxbill",
+    "waxwing",
+    "wealthy",
+    "wearily",
+    "weather",
+    "wedding",
+    "weekend",
+    "weighty",
+    "weirdly",
+    "welcome",
+    "whippet",
+    "whisper",
+    "whiting",
+    "wildcat",
+    "willing",
+    "winning",
+    "winsome",
+    "witness",
+    "wittily",
+    "womanly",
+    "working",
+    "worldly",
+    "wrestle",
+    "wrongly",
+    "wryneck",
+    "zealous",
+    "zorilla"
+];
+
+
+static EIGHT_LETTER_SOLUTIONS: [&str; 785] = [
+    "aardvark",
+    "aardwolf",
+    "abjectly",
+    "abruptly",
+    "absently",
+    "absolute",
+    "absolved",
+    "abstract",
+    "absurdly",
+    "abundant",
+    "accentor",
+    "accepted",
+    "accident",
+    "accurate",
+    "achingly",
+    "acoustic",
+    "actively",
+    "actually",
+    "adapting",
+    "adaptive",
+    "adequate",
+    "adjusted",
+    "adjutant",
+    "adorable",
+    "adorably",
+    "adroitly",
+    "advanced",
+    "affluent",
+    "airedale",
+    "albacore",
+    "allowing",
+    "alluring",
+    "amenable",
+    "amicable",
+    "amicably",
+    "amorally",
+    "anaconda",
+    "animated",
+    "announce",
+    "annually",
+    "anointed",
+    "anteater",
+    "antelope",
+    "apparent",
+    "apposite",
+    "arachnid",
+    "arapaima",
+    "ardently",
+    "arguably",
+    "arriving",
+    "artefact",
+    "artfully",
+    "artistic",
+    "aspirant",
+    "aspiring",
+    "assuring",
+    "astutely",
+    "athletic",
+    "attitude",
+    "avowedly",
+    "bachelor",
+    "balanced",
+    "barnacle",
+    "basilisk",
+    "becoming",
+    "beggarly",
+    "bellbird",
+    "benignly",
+    "billfish",
+    "bitingly",
+    "bitterly",
+    "biweekly",
+    "blackcap",
+    "blissful",
+    "blithely",
+    "bloodily",
+    "blooming",
+    "blowfish",
+    "bluebill",
+    "bluebird",
+    "bluefish",
+    "bluegill",
+    "boarfish",
+    "boatbill",
+    "bobolink",
+    "bobwhite",
+    "bonefish",
+    "bontebok",
+    "boringly",
+    "bounding",
+    "boyishly",
+    "brazenly",
+    "breezily",
+    "brightly",
+    "brimming",
+    "broccoli",
+    "brutally",
+    "bullfrog",
+    "bullhead",
+    "bursting",
+    "bushbuck",
+    "business",
+    "bustling",
+    "candidly",
+    "capuchin",
+    "capybara",
+    "caracara",
+    "cardinal",
+    "carefree",
+    "carnally",
+    "casually",
+    "category",
+    "causally",
+    "cerebral",
+    "champion",
+    "charming",
+    "chastely",
+    "chattily",
+    "cheekily",
+    "cheerful",
+    "cheerily",
+    "chimaera",
+    "chipmunk",
+    "cinnamon",
+    "cleverly",
+    "climbing",
+    "clumsily",
+    "coarsely",
+    "cockatoo",
+    "cogently",
+    "coherent",
+    "colossal",
+    "commonly",
+    "communal",
+    "complete",
+    "composed",
+    "concrete",
+    "congress",
+    "consider",
+    "convince",
+    "covertly",
+    "cowardly",
+    "craftily",
+    "crayfish",
+    "creakily",
+    "creative",
+    "credible",
+    "credibly",
+    "credited",
+    "cultured",
+    "cupboard",
+    "curassow",
+    "cussedly",
+    "dabchick",
+    "daintily",
+    "damnably",
+    "daringly",
+    "daughter",
+    "dazzling",
+    "dealfish",
+    "debonair",
+    "december",
+    "decently",
+    "deciding",
+    "decisive",
+    "decorate",
+    "decorous",
+    "decrease",
+    "definite",
+    "delicate",
+    "demurely",
+    "describe",
+    "desirous",
+    "destined",
+    "devoutly",
+    "diligent",
+    "dinosaur",
+    "directly",
+    "disagree",
+    "discover",
+    "discreet",
+    "discrete",
+    "dismally",
+    "disorder",
+    "distance",
+    "distinct",
+    "divinely",
+    "doberman",
+    "document",
+    "doggedly",
+    "dominant",
+    "dormouse",
+    "dotingly",
+    "dotterel",
+    "dragonet",
+    "dreamily",
+    "drearily",
+    "drowsily",
+    "duckbill",
+    "duckling",
+    "easterly",
+    "eclectic",
+    "economic",
+    "ecstatic",
+    "educated",
+    "electric",
+    "elephant",
+    "elevated",
+    "elevator",
+    "eligible",
+    "eloquent",
+    "emerging",
+    "emulated",
+    "enabling",
+    "endeared",
+    "endorsed",
+    "enduring",
+    "engaging",
+    "enhanced",
+    "enormous",
+    "enriched",
+    "enticing",
+    "entirely",
+    "envelope",
+    "enviably",
+    "equipped",
+    "escargot",
+    "especial",
+    "esteemed",
+    "ethereal",
+    "eulachon",
+    "eventful",
+    "evidence",
+    "evolving",
+    "exalting",
+    "exchange",
+    "exciting",
+    "exercise",
+    "expertly",
+    "exultant",
+    "exulting",
+    "fabulous",
+    "facially",
+    "faithful",
+    "falconet",
+    "fallibly",
+    "familiar",
+    "famously",
+    "fatherly",
+    "faultily",
+    "favorite",
+    "fearless",
+    "feasible",
+    "feasibly",
+    "february",
+    "feminine",
+    "fervidly",
+    "festival",
+    "fetching",
+    "fiercely",
+    "filefish",
+    "filthily",
+    "finitely",
+    "fireback",
+    "firebrat",
+    "fiscally",
+    "fitfully",
+    "flamingo",
+    "flashily",
+    "flatfish",
+    "flathead",
+    "flawless",
+    "flexible",
+    "flexibly",
+    "flimsily",
+    "floridly",
+    "flounder",
+    "fluently",
+    "forceful",
+    "forcibly",
+    "foremost",
+    "formally",
+    "formerly",
+    "fourthly",
+    "foxhound",
+    "fragrant",
+    "frequent",
+    "friendly",
+    "frigidly",
+    "friskily",
+    "frogfish",
+    "frostily",
+    "frugally",
+    "fruitful",
+    "futilely",
+    "garganey",
+    "garishly",
+    "generous",
+    "genially",
+    "gingerly",
+    "gleaming",
+    "globally",
+    "gloomily",
+    "glorious",
+    "glowworm",
+    "goatfish",
+    "goldfish",
+    "gorgeous",
+    "graceful",
+    "gracious",
+    "grateful",
+    "grayling",
+    "greedily",
+    "grosbeak",
+    "grounded",
+    "grubworm",
+    "grumpily",
+    "guiltily",
+    "hairtail",
+    "halfbeak",
+    "handsome",
+    "harmless",
+    "harmonic",
+    "haunting",
+    "hawfinch",
+    "heartily",
+    "heatedly",
+    "heavenly",
+    "hedgehog",
+    "heralded",
+    "hoarsely",
+    "hollowly",
+    "honestly",
+    "honeybee",
+    "honorary",
+    "hookworm",
+    "hornbill",
+    "horntail",
+    "horribly",
+    "horridly",
+    "hospital",
+    "humanely",
+    "humorous",
+    "humpback",
+    "hungrily",
+    "hygienic",
+    "identify",
+    "immortal",
+    "impishly",
+    "improved",
+    "impurely",
+    "incisive",
+    "included",
+    "increase",
+    "indicate",
+    "industry",
+    "infinite",
+    "informed",
+    "innately",
+    "innocent",
+    "insanely",
+    "inspired",
+    "integral",
+    "intently",
+    "interest",
+    "internal",
+    "intimate",
+    "intrepid",
+    "inviting",
+    "inwardly",
+    "jaggedly",
+    "jauntily",
+    "javelina",
+    "jocosely",
+    "jocundly",
+    "jokingly",
+    "jovially",
+    "joyfully",
+    "joyously",
+    "jubilant",
+    "kangaroo",
+    "killdeer",
+    "kingbird",
+    "kingfish",
+    "kinkajou",
+    "knightly",
+    "labrador",
+    "lacewing",
+    "ladybird",
+    "ladylike",
+    "language",
+    "latterly",
+    "laudable",
+    "laudably",
+    "laureate",
+    "lavishly",
+    "lawfully",
+    "learning",
+    "lethally",
+    "lettered",
+    "limpidly",
+    "lineally",
+    "linearly",
+    "lionfish",
+    "literary",
+    "literate",
+    "longhorn",
+    "longspur",
+    "lorikeet",
+    "lovingly",
+    "luminous",
+    "lungfish",
+    "luscious",
+    "lustrous",
+    "lyrebird",
+    "mackerel",
+    "magnetic",
+    "maidenly",
+    "majestic",
+    "malamute",
+    "mandrill",
+    "manfully",
+    "manifest",
+    "mannerly",
+    "manually",
+    "markedly",
+    "marmoset",
+    "marriage",
+    "masterly",
+    "mastodon",
+    "material",
+    "maternal",
+    "maturely",
+    "maturing",
+    "meagerly",
+    "mealworm",
+    "measured",
+    "mechanic",
+    "menhaden",
+    "menially",
+    "mentally",
+    "merciful",
+    "meteoric",
+    "midnight",
+    "mightily",
+    "minutely",
+    "moccasin",
+    "modestly",
+    "modishly",
+    "mongoose",
+    "monkfish",
+    "moonfish",
+    "morbidly",
+    "morosely",
+    "mortally",
+    "mosquito",
+    "motherly",
+    "mountain",
+    "movingly",
+    "mulishly",
+    "multiply",
+    "murrelet",
+    "muscular",
+    "mushroom",
+    "mutually",
+    "narrowly",
+    "national",
+    "negative",
+    "nightjar",
+    "normally",
+    "nurtured",
+    "nuthatch",
+    "obliging",
+    "obtusely",
+    "odiously",
+    "opaquely",
+    "ordinary",
+    "oriented",
+    "original",
+    "ornately",
+    "outgoing",
+    "ovenbird",
+    "owlishly",
+    "oxpecker",
+    "palpably",
+    "pangolin",
+    "papillon",
+    "parakeet",
+    "parental",
+    "patchily",
+    "patently",
+    "paternal",
+    "peaceful",
+    "peerless",
+    "pheasant",
+    "physical",
+    "pickerel",
+    "pilchard",
+    "pinniped",
+    "pinscher",
+    "pipefish",
+    "pitiably",
+    "placidly",
+    "platypus",
+    "pleasant",
+    "pleasing",
+    "pliantly",
+    "poignant",
+    "polished",
+    "politely",
+    "polliwog",
+    "porpoise",
+    "position",
+    "positive",
+    "possible",
+    "possibly",
+    "potently",
+    "powerful",
+    "practice",
+    "precious",
+    "prepared",
+    "prettily",
+    "princely",
+    "priority",
+    "prissily",
+    "probable",
+    "probably",
+    "profound",
+    "prolific",
+    "promoted",
+    "promptly",
+    "properly",
+    "property",
+    "protozoa",
+    "provably",
+    "publicly",
+    "punctual",
+    "purchase",
+    "purified",
+    "quaintly",
+    "queasily",
+    "question",
+    "racially",
+    "radially",
+    "raggedly",
+    "rakishly",
+    "randomly",
+    "rational",
+    "recently",
+    "redshank",
+    "redstart",
+    "reedbuck",
+    "reindeer",
+    "relative",
+    "relaxing",
+    "relevant",
+    "reliable",
+    "reliably",
+    "relieved",
+    "relished",
+    "remember",
+    "remotely",
+    "renewing",
+    "renowned",
+    "resemble",
+    "resolute",
+    "resolved",
+    "resource",
+    "response",
+    "reverent",
+    "rewarded",
+    "rightful",
+    "ringtail",
+    "ritually",
+    "robustly",
+    "rockfish",
+    "rockling",
+    "romantic",
+    "ruefully",
+    "ruggedly",
+    "sacredly",
+    "sailfish",
+    "sandfish",
+    "savagely",
+    "scantily",
+    "scarcely",
+    "scissors",
+    "scorpion",
+    "screamer",
+    "seahorse",
+    "seasnail",
+    "seasoned",
+    "secondly",
+    "secretly",
+    "securely",
+    "security",
+    "sedately",
+    "selected",
+    "sensible",
+    "sensibly",
+    "sensuous",
+    "sentence",
+    "serenely",
+    "serially",
+    "settling",
+    "severely",
+    "shabbily",
+    "sheepdog",
+    "shelduck",
+    "shepherd",
+    "shiftily",
+    "shoddily",
+    "shoebill",
+    "shoulder",
+    "shoveler",
+    "shrewdly",
+    "signally",
+    "silently",
+    "silkworm",
+    "sinfully",
+    "singular",
+    "sisterly",
+    "sleepily",
+    "slightly",
+    "sloppily",
+    "slovenly",
+    "smashing",
+    "smoothly",
+    "sneakily",
+    "sociable",
+    "sociably",
+    "socially",
+    "solemnly",
+    "solution",
+    "soothing",
+    "sordidly",
+    "spacious",
+    "spanking",
+    "sparsely",
+    "speedily",
+    "spirally",
+    "spirited",
+    "splendid",
+    "sporting",
+    "spotless",
+    "springer",
+    "squarely",
+    "squeaker",
+    "squirrel",
+    "stallion",
+    "stalwart",
+    "starfish",
+    "starling",
+    "steadily",
+    "steenbok",
+    "sterling",
+    "stingily",
+    "stingray",
+    "stinkbug",
+    "stirring",
+    "stockily",
+    "stolidly",
+    "stormily",
+    "strategy",
+    "strictly",
+    "striking",
+    "striving",
+    "strongly",
+    "struggle",
+    "studious",
+    "stuffily",
+    "stunning",
+    "stupidly",
+    "sturdily",
+    "sturgeon",
+    "succinct",
+    "suddenly",
+    "suitable",
+    "suitably",
+    "sullenly",
+    "superbly",
+    "superior",
+    "supinely",
+    "surfbird",
+    "suricate",
+    "surprise",
+    "surround",
+    "sweeping",
+    "swiftlet",
+    "talented",
+    "tamandua",
+    "tangible",
+    "tangibly",
+    "tasteful",
+    "teaching",
+    "tenderly",
+    "terrapin",
+    "terribly",
+    "terrific",
+    "thankful",
+    "thorough",
+    "thrasher",
+    "thrilled",
+    "thriving",
+    "tilefish",
+    "timeless",
+    "tireless",
+    "titmouse",
+    "toadfish",
+    "together",
+    "tolerant",
+    "tomorrow",
+    "torpidly",
+    "tortoise",
+    "touchily",
+    "touching",
+    "tragopan",
+    "tranquil",
+    "transfer",
+    "treefrog",
+    "trembler",
+    "trustful",
+    "trusting",
+    "truthful",
+    "turgidly",
+    "ultimate",
+    "umbrella",
+    "unbiased",
+    "unbroken",
+    "uncommon",
+    "uneasily",
+    "unerring",
+    "unevenly",
+    "unfairly",
+    "ungainly",
+    "uniquely",
+    "universe",
+    "unjustly",
+    "unkindly",
+    "unlikely",
+    "unseemly",
+    "untidily",
+    "untimely",
+    "untiring",
+    "unwisely",
+    "uplifted",
+    "upwardly",
+    "urbanely",
+    "urgently",
+    "usefully",
+    "vacantly",
+    "valuable",
+    "variably",
+    "vaulting",
+    "vehement",
+    "verbally",
+    "verified",
+    "vigilant",
+    "vigorous",
+    "virtuous",
+    "visually",
+    "vizcacha",
+    "volcanic",
+    "vulgarly",
+    "wallaroo",
+    "wantonly",
+    "warmouth",
+    "welcomed",
+    "werewolf",
+    "westerly",
+    "wheatear",
+    "wheezily",
+    "whimbrel",
+    "whistler",
+    "whitefly",
+    "wickedly",
+    "wildfowl",
+    "woefully",
+    "wolffish",
+    "wondrous",
+    "woodcock",
+    "woodenly",
+    "workable",
+    "worthily",
+    "youthful"
+];
+
+
+
+/*
+ * TESTS
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    fn day(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn daily_word_is_the_same_all_day() {
+        let challenge_date: Date = day(2026, Month::March, 14);
+        assert_eq!(get_daily_word(challenge_date), get_daily_word(challenge_date));
+        assert_eq!(get_daily_word(challenge_date), get_daily_word(day(2026, Month::March, 14)));
+    }
+
+    #[test]
+    fn neighbouring_days_get_different_words() {
+        let mut challenge_date: Date = day(2026, Month::January, 1);
+        let mut previous_word: String = get_daily_word(challenge_date);
+
+        for _ in 0..365 {
+            challenge_date = challenge_date.next_day().unwrap();
+            let daily_word: String = get_daily_word(challenge_date);
+            assert_ne!(daily_word, previous_word, "{}", challenge_date);
+            previous_word = daily_word;
+        }
+    }
+
+    #[test]
+    fn daily_word_is_a_real_five_letter_word() {
+        let daily_word: String = get_daily_word(day(2026, Month::October, 18));
+        assert_eq!(daily_word.chars().count(), 5);
+        assert_eq!(daily_word, utils::all_caps(&daily_word));
+        assert!(crate::words_all::is_real_word(&daily_word, &SupportedLangs::English));
+    }
+}
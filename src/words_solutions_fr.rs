@@ -0,0 +1,1874 @@
+/* 
+ * 
+ * 
+ * 
+ * 
+ * sssssssssssssssssssssssssssssssssssssssssssssssssssssss
+ * sssssssssssssssssssssssssssssssssssssssssssssssssssssss
+ * sssssssssssssss                         sssssssssssssss
+ * sssssssssssssss  FRENCH SOLUTION WORDS  sssssssssssssss
+ * sssssssssssssss                         sssssssssssssss
+ * sssssssssssssssssssssssssssssssssssssssssssssssssssssss
+ * sssssssssssssssssssssssssssssssssssssssssssssssssssssss
+ * 
+ * 
+ * 
+ * Solutions for French games, one list per word length.
+ * Only base forms: singular nouns, masculine adjectives and infinitives.
+ * Each is a subset of the matching list in words_all_fr.
+ * See words_solutions for picking one.
+ * 
+ * 
+ * 
+*/
+
+
+pub static FOUR_LETTER_SOLUTIONS: [&str; 219] = [
+    "abri",
+    "acte",
+    "aide",
+    "aigu",
+    "aile",
+    "aise",
+    "amer",
+    "amie",
+    "ange",
+    "arme",
+    "aube",
+    "auto",
+    "avis",
+    "azur",
+    "baie",
+    "bain",
+    "banc",
+    "base",
+    "beau",
+    "bebe",
+    "bete",
+    "bien",
+    "bise",
+    "bleu",
+    "bloc",
+    "bois",
+    "bond",
+    "bord",
+    "boue",
+    "bout",
+    "boxe",
+    "bras",
+    "bref",
+    "brin",
+    "brun",
+    "cafe",
+    "cage",
+    "camp",
+    "case",
+    "cave",
+    "cerf",
+    "chat",
+    "chef",
+    "cher",
+    "chic",
+    "chou",
+    "ciel",
+    "clou",
+    "code",
+    "coin",
+    "cote",
+    "coup",
+    "cour",
+    "cuir",
+    "cure",
+    "dame",
+    "date",
+    "defi",
+    "dent",
+    "dieu",
+    "dore",
+    "doux",
+    "drap",
+    "dune",
+    "egal",
+    "elan",
+    "epee",
+    "etat",
+    "exil",
+    "face",
+    "faim",
+    "faux",
+    "fete",
+    "feve",
+    "fier",
+    "file",
+    "film",
+    "fils",
+    "fois",
+    "fond",
+    "fort",
+    "four",
+    "gant",
+    "gare",
+    "gout",
+    "gras",
+    "gris",
+    "gros",
+    "haie",
+    "haut",
+    "idee",
+    "joie",
+    "joli",
+    "jour",
+    "juge",
+    "jupe",
+    "laid",
+    "lait",
+    "lent",
+    "lien",
+    "lieu",
+    "lime",
+    "lion",
+    "long",
+    "loup",
+    "lune",
+    "luxe",
+    "main",
+    "mare",
+    "mari",
+    "mars",
+    "menu",
+    "mere",
+    "midi",
+    "miel",
+    "mine",
+    "mode",
+    "mois",
+    "mort",
+    "muet",
+    "nage",
+    "naif",
+    "nerf",
+    "neuf",
+    "noce",
+    "noel",
+    "noir",
+    "note",
+    "nuit",
+    "oeil",
+    "oeuf",
+    "oser",
+    "ours",
+    "page",
+    "pain",
+    "paix",
+    "pale",
+    "papa",
+    "parc",
+    "part",
+    "pate",
+    "pave",
+    "pays",
+    "peau",
+    "peur",
+    "pied",
+    "pile",
+    "pire",
+    "plan",
+    "plat",
+    "pneu",
+    "poil",
+    "pois",
+    "pole",
+    "poli",
+    "pont",
+    "porc",
+    "port",
+    "prix",
+    "puce",
+    "pull",
+    "quai",
+    "race",
+    "rage",
+    "rang",
+    "rare",
+    "ravi",
+    "reel",
+    "reve",
+    "rire",
+    "rive",
+    "robe",
+    "role",
+    "rond",
+    "rose",
+    "roue",
+    "roux",
+    "rude",
+    "ruse",
+    "sage",
+    "sain",
+    "sale",
+    "sang",
+    "saut",
+    "scie",
+    "seau",
+    "sens",
+    "seul",
+    "site",
+    "soie",
+    "soif",
+    "soir",
+    "sort",
+    "taxi",
+    "tele",
+    "tete",
+    "toit",
+    "tour",
+    "tout",
+    "toux",
+    "trou",
+    "truc",
+    "tube",
+    "tuer",
+    "type",
+    "user",
+    "veau",
+    "velo",
+    "vent",
+    "vers",
+    "vert",
+    "vide",
+    "voeu",
+    "voie",
+    "voix",
+    "vote",
+    "vrai",
+    "zero",
+    "zone"
+];
+
+
+pub static FIVE_LETTER_SOLUTIONS: [&str; 409] = [
+    "acces",
+    "achat",
+    "acier",
+    "actif",
+    "adieu",
+    "agent",
+    "agile",
+    "aider",
+    "aigle",
+    "aimer",
+    "album",
+    "allee",
+    "amant",
+    "amour",
+    "ample",
+    "angle",
+    "anime",
+    "annee",
+    "appel",
+    "appui",
+    "arbre",
+    "arche",
+    "armee",
+    "arret",
+    "asile",
+    "atout",
+    "avion",
+    "avril",
+    "bague",
+    "balai",
+    "balle",
+    "bande",
+    "barbe",
+    "barre",
+    "baton",
+    "bazar",
+    "biche",
+    "bidon",
+    "bijou",
+    "bille",
+    "bisou",
+    "blanc",
+    "blond",
+    "boeuf",
+    "boite",
+    "bombe",
+    "bosse",
+    "botte",
+    "boule",
+    "brise",
+    "bruit",
+    "brume",
+    "bulle",
+    "cadre",
+    "calme",
+    "canal",
+    "canne",
+    "canot",
+    "capot",
+    "carre",
+    "carte",
+    "cause",
+    "chair",
+    "champ",
+    "chant",
+    "chaud",
+    "chene",
+    "chien",
+    "choix",
+    "chose",
+    "clair",
+    "clown",
+    "coeur",
+    "colis",
+    "comte",
+    "conte",
+    "copie",
+    "corde",
+    "corps",
+    "coton",
+    "coude",
+    "coupe",
+    "cours",
+    "court",
+    "crabe",
+    "craie",
+    "creme",
+    "crepe",
+    "creux",
+    "crier",
+    "crime",
+    "crise",
+    "croix",
+    "cruel",
+    "cygne",
+    "danse",
+    "debut",
+    "degre",
+    "delai",
+    "desir",
+    "dette",
+    "digne",
+    "dinde",
+    "diner",
+    "divin",
+    "doigt",
+    "dorer",
+    "doute",
+    "droit",
+    "drole",
+    "duree",
+    "durer",
+    "ecole",
+    "ecran",
+    "effet",
+    "eleve",
+    "email",
+    "encre",
+    "ennui",
+    "envie",
+    "epais",
+    "epice",
+    "epine",
+    "essai",
+    "etage",
+    "etang",
+    "etude",
+    "evier",
+    "exact",
+    "fable",
+    "faute",
+    "femme",
+    "ferme",
+    "feter",
+    "fiche",
+    "filet",
+    "fille",
+    "final",
+    "fixer",
+    "fleur",
+    "flute",
+    "foire",
+    "folie",
+    "force",
+    "foret",
+    "forme",
+    "fosse",
+    "foule",
+    "frais",
+    "franc",
+    "frere",
+    "froid",
+    "front",
+    "fruit",
+    "fumee",
+    "fumer",
+    "fusee",
+    "fusil",
+    "futur",
+    "garde",
+    "gater",
+    "gazon",
+    "gener",
+    "genie",
+    "genou",
+    "genre",
+    "geste",
+    "gilet",
+    "glace",
+    "gland",
+    "gomme",
+    "gorge",
+    "grain",
+    "grand",
+    "grave",
+    "guepe",
+    "guide",
+    "habit",
+    "hache",
+    "herbe",
+    "heros",
+    "heure",
+    "hibou",
+    "hiver",
+    "homme",
+    "honte",
+    "hotel",
+    "huile",
+    "hutte",
+    "idiot",
+    "igloo",
+    "image",
+    "impot",
+    "jambe",
+    "jaune",
+    "jeudi",
+    "jeune",
+    "jouer",
+    "jouet",
+    "jurer",
+    "juste",
+    "kayak",
+    "laine",
+    "lampe",
+    "lapin",
+    "large",
+    "larme",
+    "laver",
+    "lecon",
+    "leger",
+    "lever",
+    "levre",
+    "libre",
+    "ligne",
+    "linge",
+    "livre",
+    "louer",
+    "loupe",
+    "lourd",
+    "loyal",
+    "lundi",
+    "lutin",
+    "lycee",
+    "macon",
+    "maire",
+    "malin",
+    "maman",
+    "mardi",
+    "marin",
+    "match",
+    "matin",
+    "melon",
+    "mener",
+    "merci",
+    "merle",
+    "metal",
+    "metre",
+    "mieux",
+    "mille",
+    "mince",
+    "momie",
+    "monde",
+    "moyen",
+    "musee",
+    "nager",
+    "nappe",
+    "natal",
+    "neige",
+    "neveu",
+    "niece",
+    "noble",
+    "noeud",
+    "noter",
+    "nuage",
+    "objet",
+    "ocean",
+    "odeur",
+    "offre",
+    "olive",
+    "ombre",
+    "oncle",
+    "ongle",
+    "opera",
+    "orage",
+    "ordre",
+    "orgue",
+    "otage",
+    "outil",
+    "panne",
+    "passe",
+    "patte",
+    "pause",
+    "payer",
+    "peche",
+    "peine",
+    "pelle",
+    "pente",
+    "perle",
+    "peser",
+    "petit",
+    "phare",
+    "photo",
+    "piano",
+    "piece",
+    "piege",
+    "pique",
+    "piste",
+    "place",
+    "plage",
+    "plein",
+    "plier",
+    "pluie",
+    "plume",
+    "poche",
+    "poele",
+    "poeme",
+    "poete",
+    "poids",
+    "poing",
+    "point",
+    "poire",
+    "pomme",
+    "pompe",
+    "porte",
+    "poser",
+    "poste",
+    "poule",
+    "prier",
+    "prune",
+    "puits",
+    "puree",
+    "quart",
+    "queue",
+    "radio",
+    "radis",
+    "rater",
+    "rayon",
+    "recit",
+    "regle",
+    "reine",
+    "repas",
+    "repos",
+    "reste",
+    "rever",
+    "revue",
+    "rhume",
+    "riche",
+    "robot",
+    "roche",
+    "roman",
+    "ronde",
+    "rouge",
+    "route",
+    "ruban",
+    "sable",
+    "sabot",
+    "saint",
+    "salle",
+    "salon",
+    "sante",
+    "sapin",
+    "sauce",
+    "saule",
+    "savon",
+    "scene",
+    "seuil",
+    "siege",
+    "signe",
+    "singe",
+    "sirop",
+    "soeur",
+    "somme",
+    "songe",
+    "souci",
+    "soupe",
+    "sourd",
+    "sport",
+    "stade",
+    "style",
+    "stylo",
+    "sucer",
+    "sucre",
+    "sueur",
+    "suite",
+    "sujet",
+    "table",
+    "tache",
+    "tante",
+    "tapis",
+    "tarte",
+    "tasse",
+    "taupe",
+    "temps",
+    "tente",
+    "terre",
+    "texte",
+    "tiede",
+    "tigre",
+    "tirer",
+    "tissu",
+    "titre",
+    "toile",
+    "tombe",
+    "tordu",
+    "total",
+    "trace",
+    "train",
+    "trait",
+    "tribu",
+    "tronc",
+    "tuile",
+    "tuyau",
+    "usage",
+    "usine",
+    "utile",
+    "vache",
+    "vague",
+    "vaste",
+    "veine",
+    "verre",
+    "veste",
+    "vieux",
+    "ville",
+    "vitre",
+    "voile",
+    "voler",
+    "voter",
+    "wagon",
+    "zebre"
+];
+
+
+pub static SIX_LETTER_SOLUTIONS: [&str; 461] = [
+    "absent",
+    "accent",
+    "accord",
+    "acteur",
+    "action",
+    "adorer",
+    "adroit",
+    "adulte",
+    "agence",
+    "agneau",
+    "agrafe",
+    "aimant",
+    "alcool",
+    "allure",
+    "alpage",
+    "amande",
+    "amende",
+    "amener",
+    "amitie",
+    "amuser",
+    "ananas",
+    "ancien",
+    "animal",
+    "anneau",
+    "argent",
+    "argile",
+    "aspect",
+    "auteur",
+    "avance",
+    "avenir",
+    "avenue",
+    "avocat",
+    "avoine",
+    "bagage",
+    "baiser",
+    "balcon",
+    "ballon",
+    "banane",
+    "banque",
+    "barque",
+    "bassin",
+    "bateau",
+    "bavard",
+    "beaute",
+    "berger",
+    "besoin",
+    "beurre",
+    "billet",
+    "blague",
+    "bleuet",
+    "blouse",
+    "bonbon",
+    "bonnet",
+    "bouche",
+    "boucle",
+    "bougie",
+    "bourse",
+    "bouton",
+    "brebis",
+    "brique",
+    "brosse",
+    "bruler",
+    "budget",
+    "buffet",
+    "buffle",
+    "bureau",
+    "cabane",
+    "cabine",
+    "cacher",
+    "cadeau",
+    "cahier",
+    "caisse",
+    "calcul",
+    "calmer",
+    "camera",
+    "camion",
+    "canape",
+    "canard",
+    "carafe",
+    "carnet",
+    "carton",
+    "casque",
+    "casser",
+    "castor",
+    "causer",
+    "celeri",
+    "cendre",
+    "centre",
+    "cercle",
+    "cerise",
+    "chaine",
+    "chaise",
+    "chance",
+    "change",
+    "charge",
+    "charme",
+    "chasse",
+    "chaton",
+    "chemin",
+    "cheque",
+    "cheval",
+    "cheveu",
+    "chevre",
+    "chimie",
+    "chuter",
+    "cierge",
+    "cigale",
+    "cinema",
+    "cirque",
+    "ciseau",
+    "citron",
+    "clarte",
+    "classe",
+    "client",
+    "climat",
+    "cloche",
+    "cochon",
+    "coffre",
+    "cogner",
+    "colere",
+    "coller",
+    "combat",
+    "commun",
+    "compte",
+    "copain",
+    "couche",
+    "couler",
+    "couper",
+    "course",
+    "couter",
+    "crayon",
+    "croute",
+    "cuisse",
+    "danger",
+    "danser",
+    "defaut",
+    "depart",
+    "desert",
+    "dessin",
+    "destin",
+    "detail",
+    "devoir",
+    "diable",
+    "dictee",
+    "direct",
+    "disque",
+    "donner",
+    "douane",
+    "douche",
+    "douter",
+    "dragon",
+    "eclair",
+    "ecorce",
+    "ecurie",
+    "effort",
+    "eglise",
+    "elever",
+    "empire",
+    "emploi",
+    "encore",
+    "enfant",
+    "ennemi",
+    "enorme",
+    "entier",
+    "entree",
+    "entrer",
+    "epaule",
+    "epeler",
+    "eponge",
+    "epoque",
+    "equipe",
+    "erreur",
+    "espace",
+    "espece",
+    "espoir",
+    "esprit",
+    "etoile",
+    "etroit",
+    "eviter",
+    "examen",
+    "excuse",
+    "expose",
+    "facade",
+    "facher",
+    "facile",
+    "faible",
+    "farine",
+    "faucon",
+    "faveur",
+    "favori",
+    "fermer",
+    "feroce",
+    "fidele",
+    "fievre",
+    "figure",
+    "flacon",
+    "flamme",
+    "flaque",
+    "fleche",
+    "fleuve",
+    "flocon",
+    "former",
+    "foudre",
+    "fourmi",
+    "fraise",
+    "fumier",
+    "fusain",
+    "gagner",
+    "garage",
+    "garcon",
+    "garder",
+    "gateau",
+    "gentil",
+    "girafe",
+    "glacon",
+    "gloire",
+    "gourde",
+    "gouter",
+    "goutte",
+    "gradin",
+    "graine",
+    "grange",
+    "grappe",
+    "griffe",
+    "grille",
+    "grotte",
+    "groupe",
+    "guerre",
+    "habile",
+    "hangar",
+    "hasard",
+    "homard",
+    "huitre",
+    "humain",
+    "humeur",
+    "humide",
+    "humour",
+    "hurler",
+    "indice",
+    "invite",
+    "ivoire",
+    "jaloux",
+    "jambon",
+    "jardin",
+    "joueur",
+    "joyeux",
+    "jument",
+    "lagune",
+    "lancer",
+    "langue",
+    "lavabo",
+    "legume",
+    "lettre",
+    "lezard",
+    "lievre",
+    "limace",
+    "livrer",
+    "loisir",
+    "madame",
+    "maigre",
+    "mairie",
+    "maison",
+    "maitre",
+    "malade",
+    "manche",
+    "manege",
+    "manger",
+    "marbre",
+    "marche",
+    "marque",
+    "marron",
+    "masque",
+    "membre",
+    "menace",
+    "menton",
+    "mesure",
+    "metier",
+    "meuble",
+    "milieu",
+    "minute",
+    "miroir",
+    "modele",
+    "moitie",
+    "mollet",
+    "moment",
+    "monter",
+    "montre",
+    "morale",
+    "moteur",
+    "mouche",
+    "moulin",
+    "mousse",
+    "mouton",
+    "muguet",
+    "muscle",
+    "narine",
+    "nature",
+    "navire",
+    "niveau",
+    "nombre",
+    "nuance",
+    "numero",
+    "obscur",
+    "oeuvre",
+    "oignon",
+    "oiseau",
+    "orange",
+    "orteil",
+    "otarie",
+    "paille",
+    "palais",
+    "panier",
+    "papier",
+    "paquet",
+    "pareil",
+    "parent",
+    "parfum",
+    "parler",
+    "parole",
+    "partie",
+    "passer",
+    "patron",
+    "pauvre",
+    "paysan",
+    "pecher",
+    "peigne",
+    "pensee",
+    "penser",
+    "pensif",
+    "petale",
+    "peuple",
+    "phrase",
+    "pierre",
+    "pigeon",
+    "pilote",
+    "piquer",
+    "pirate",
+    "placer",
+    "plaine",
+    "plante",
+    "poesie",
+    "pointe",
+    "poivre",
+    "police",
+    "porter",
+    "potage",
+    "poteau",
+    "poulet",
+    "poupee",
+    "poutre",
+    "presse",
+    "preter",
+    "preuve",
+    "prince",
+    "prison",
+    "projet",
+    "propre",
+    "public",
+    "racine",
+    "raisin",
+    "raison",
+    "rameau",
+    "ranger",
+    "rapide",
+    "reflet",
+    "refuge",
+    "regard",
+    "renard",
+    "requin",
+    "reseau",
+    "rester",
+    "retard",
+    "retour",
+    "reveil",
+    "rideau",
+    "rigolo",
+    "rivage",
+    "rocher",
+    "roseau",
+    "rouler",
+    "rythme",
+    "safran",
+    "saison",
+    "salade",
+    "saluer",
+    "samedi",
+    "saumon",
+    "sauter",
+    "sauver",
+    "savant",
+    "secret",
+    "serrer",
+    "siecle",
+    "signer",
+    "simple",
+    "sirene",
+    "soiree",
+    "soldat",
+    "soleil",
+    "sombre",
+    "sommet",
+    "sonner",
+    "sortie",
+    "souple",
+    "source",
+    "souris",
+    "statue",
+    "subtil",
+    "taille",
+    "talent",
+    "tarder",
+    "tardif",
+    "temoin",
+    "tendre",
+    "tennis",
+    "ticket",
+    "timbre",
+    "timide",
+    "tiroir",
+    "tomate",
+    "tomber",
+    "tortue",
+    "toucan",
+    "touche",
+    "tracer",
+    "trajet",
+    "tresor",
+    "tricot",
+    "triste",
+    "troupe",
+    "truite",
+    "tulipe",
+    "tunnel",
+    "unique",
+    "urgent",
+    "valeur",
+    "valise",
+    "vallee",
+    "valser",
+    "vapeur",
+    "veille",
+    "ventre",
+    "verger",
+    "verite",
+    "vernis",
+    "verser",
+    "viande",
+    "vilain",
+    "violet",
+    "violon",
+    "vipere",
+    "virage",
+    "visage",
+    "visite",
+    "vivant",
+    "voisin",
+    "volant",
+    "volcan",
+    "voleur",
+    "voyage",
+    "yaourt"
+];
+
+
+pub static SEVEN_LETTER_SOLUTIONS: [&str; 434] = [
+    "abeille",
+    "abricot",
+    "absence",
+    "accueil",
+    "admirer",
+    "adresse",
+    "affaire",
+    "affiche",
+    "aimable",
+    "ajouter",
+    "aliment",
+    "allumer",
+    "amateur",
+    "ampoule",
+    "amusant",
+    "ancetre",
+    "anglais",
+    "animaux",
+    "annonce",
+    "antenne",
+    "anxieux",
+    "appetit",
+    "appuyer",
+    "arbuste",
+    "ardoise",
+    "armoire",
+    "arreter",
+    "arrivee",
+    "arriver",
+    "arroser",
+    "article",
+    "artiste",
+    "atelier",
+    "attente",
+    "auberge",
+    "autobus",
+    "autocar",
+    "automne",
+    "avancer",
+    "bagarre",
+    "baisser",
+    "balance",
+    "balayer",
+    "baleine",
+    "barrage",
+    "bercail",
+    "bibelot",
+    "biberon",
+    "billard",
+    "biscuit",
+    "bizarre",
+    "blesser",
+    "blouson",
+    "boisson",
+    "bonheur",
+    "boucher",
+    "bouleau",
+    "bouquet",
+    "branche",
+    "briller",
+    "brioche",
+    "brosser",
+    "brusque",
+    "buisson",
+    "cadenas",
+    "caillou",
+    "canevas",
+    "cantine",
+    "capable",
+    "caprice",
+    "capuche",
+    "caramel",
+    "carotte",
+    "carreau",
+    "cascade",
+    "caverne",
+    "cerceau",
+    "cerveau",
+    "chagrin",
+    "chaleur",
+    "chambre",
+    "chameau",
+    "changer",
+    "chanson",
+    "chanter",
+    "chapeau",
+    "charbon",
+    "charger",
+    "chariot",
+    "chasser",
+    "chateau",
+    "chemise",
+    "chiffon",
+    "chiffre",
+    "chorale",
+    "citoyen",
+    "classer",
+    "clavier",
+    "clocher",
+    "cocotte",
+    "college",
+    "collier",
+    "colline",
+    "colombe",
+    "colonne",
+    "comedie",
+    "comique",
+    "complet",
+    "compter",
+    "concert",
+    "confier",
+    "conseil",
+    "console",
+    "content",
+    "contrat",
+    "corbeau",
+    "correct",
+    "costume",
+    "coucher",
+    "couleur",
+    "couloir",
+    "courage",
+    "courant",
+    "courber",
+    "couteau",
+    "coutume",
+    "crainte",
+    "crapaud",
+    "cravate",
+    "creatif",
+    "creuser",
+    "cristal",
+    "cuisine",
+    "culture",
+    "curieux",
+    "danseur",
+    "dauphin",
+    "decider",
+    "decorer",
+    "degager",
+    "delicat",
+    "demande",
+    "deposer",
+    "dernier",
+    "desirer",
+    "dessert",
+    "deviner",
+    "diamant",
+    "diplome",
+    "discret",
+    "dispute",
+    "docteur",
+    "domaine",
+    "dommage",
+    "dossier",
+    "douleur",
+    "drapeau",
+    "echange",
+    "echarpe",
+    "echelle",
+    "eclipse",
+    "ecouter",
+    "edifice",
+    "elegant",
+    "emmener",
+    "emotion",
+    "encadre",
+    "endormi",
+    "endroit",
+    "energie",
+    "enfance",
+    "engrais",
+    "enlever",
+    "enquete",
+    "envoler",
+    "episode",
+    "esperer",
+    "essuyer",
+    "estomac",
+    "etagere",
+    "etonner",
+    "etrange",
+    "etudier",
+    "evasion",
+    "exemple",
+    "exister",
+    "facteur",
+    "famille",
+    "fatigue",
+    "fenetre",
+    "fenouil",
+    "feuille",
+    "ficelle",
+    "flamant",
+    "flotter",
+    "fortune",
+    "fragile",
+    "frapper",
+    "frisson",
+    "fromage",
+    "furieux",
+    "galerie",
+    "galette",
+    "gardien",
+    "gazelle",
+    "glacial",
+    "glisser",
+    "gobelet",
+    "goeland",
+    "gorille",
+    "gouffre",
+    "gratuit",
+    "grenade",
+    "grenier",
+    "grimace",
+    "grimper",
+    "gronder",
+    "guitare",
+    "gymnase",
+    "habiter",
+    "hamster",
+    "haricot",
+    "hauteur",
+    "hesiter",
+    "heureux",
+    "honnete",
+    "honneur",
+    "hopital",
+    "horizon",
+    "horloge",
+    "hygiene",
+    "ignorer",
+    "immense",
+    "inquiet",
+    "insecte",
+    "instant",
+    "inutile",
+    "inviter",
+    "javelot",
+    "journal",
+    "journee",
+    "jumelle",
+    "justice",
+    "kiosque",
+    "laisser",
+    "legende",
+    "liberte",
+    "liquide",
+    "louange",
+    "lucarne",
+    "lumiere",
+    "lunette",
+    "machine",
+    "magasin",
+    "maillot",
+    "mainate",
+    "maladie",
+    "manchot",
+    "maniere",
+    "manquer",
+    "manteau",
+    "marcher",
+    "mariage",
+    "marmite",
+    "marquer",
+    "matelas",
+    "matelot",
+    "mauvais",
+    "mechant",
+    "medecin",
+    "melodie",
+    "memoire",
+    "meriter",
+    "mesange",
+    "message",
+    "mesurer",
+    "meunier",
+    "microbe",
+    "miracle",
+    "mission",
+    "moderne",
+    "moineau",
+    "monnaie",
+    "montrer",
+    "morceau",
+    "mouette",
+    "musique",
+    "mystere",
+    "naturel",
+    "navette",
+    "nerveux",
+    "nombril",
+    "notaire",
+    "nouveau",
+    "opinion",
+    "oreille",
+    "oublier",
+    "ouragan",
+    "ouvrier",
+    "palmier",
+    "panneau",
+    "parapet",
+    "paresse",
+    "parfait",
+    "passage",
+    "patient",
+    "patiner",
+    "paysage",
+    "pedaler",
+    "peigner",
+    "peintre",
+    "pelican",
+    "pelouse",
+    "peniche",
+    "petrole",
+    "peureux",
+    "piloter",
+    "pinceau",
+    "pirogue",
+    "piscine",
+    "placard",
+    "plafond",
+    "plaisir",
+    "planche",
+    "planete",
+    "planter",
+    "plateau",
+    "pleurer",
+    "plonger",
+    "plumage",
+    "poignee",
+    "poignet",
+    "poireau",
+    "poisson",
+    "pompier",
+    "portail",
+    "potager",
+    "pousser",
+    "poussin",
+    "pouvoir",
+    "prairie",
+    "premier",
+    "present",
+    "profond",
+    "prouver",
+    "prudent",
+    "qualite",
+    "quitter",
+    "ramener",
+    "rapport",
+    "recette",
+    "recolte",
+    "renfort",
+    "rentrer",
+    "reparer",
+    "repeter",
+    "reponse",
+    "reposer",
+    "requete",
+    "reunion",
+    "rigoler",
+    "riviere",
+    "sacoche",
+    "sagesse",
+    "salaire",
+    "sandale",
+    "sauvage",
+    "science",
+    "scooter",
+    "semaine",
+    "sembler",
+    "semelle",
+    "sentier",
+    "separer",
+    "serieux",
+    "serpent",
+    "serrure",
+    "service",
+    "siffler",
+    "sifflet",
+    "silence",
+    "sincere",
+    "soigner",
+    "sommeil",
+    "sorcier",
+    "souffle",
+    "soulier",
+    "sourcil",
+    "sourire",
+    "spatule",
+    "stupide",
+    "superbe",
+    "surface",
+    "systeme",
+    "tableau",
+    "tambour",
+    "tartine",
+    "taureau",
+    "tempete",
+    "terrain",
+    "theatre",
+    "tilleul",
+    "tonneau",
+    "torchon",
+    "tornade",
+    "toucher",
+    "tourner",
+    "tournoi",
+    "tousser",
+    "tramway",
+    "tranche",
+    "trapeze",
+    "travail",
+    "travaux",
+    "tricher",
+    "tromper",
+    "trophee",
+    "trotter",
+    "trouver",
+    "univers",
+    "vampire",
+    "vanille",
+    "vautour",
+    "vedette",
+    "vendeur",
+    "verdure",
+    "village",
+    "visiter",
+    "vitesse",
+    "vitrine",
+    "voiture",
+    "volonte",
+    "voyager",
+    "voyelle"
+];
+
+
+pub static EIGHT_LETTER_SOLUTIONS: [&str; 308] = [
+    "accepter",
+    "accident",
+    "accroche",
+    "activite",
+    "addition",
+    "aeroport",
+    "agreable",
+    "aiguille",
+    "ailleurs",
+    "allonger",
+    "alphabet",
+    "ambiance",
+    "amoureux",
+    "annoncer",
+    "appareil",
+    "applique",
+    "apporter",
+    "apprenti",
+    "aquarium",
+    "araignee",
+    "arracher",
+    "arrosoir",
+    "assiette",
+    "assister",
+    "attacher",
+    "attentif",
+    "attitude",
+    "attraper",
+    "audience",
+    "autorite",
+    "autruche",
+    "aventure",
+    "aviateur",
+    "baguette",
+    "banniere",
+    "barriere",
+    "bataille",
+    "bavarder",
+    "bergerie",
+    "bienfait",
+    "biologie",
+    "blaireau",
+    "blessure",
+    "boussole",
+    "boutique",
+    "bricoler",
+    "brillant",
+    "brouette",
+    "bulletin",
+    "camarade",
+    "campagne",
+    "caravane",
+    "carriere",
+    "cartable",
+    "ceinture",
+    "champion",
+    "chandail",
+    "chanteur",
+    "chantier",
+    "chapitre",
+    "charmant",
+    "chasseur",
+    "chaudron",
+    "cheminee",
+    "chenille",
+    "chercher",
+    "chocolat",
+    "collegue",
+    "colorier",
+    "commerce",
+    "comparer",
+    "comptoir",
+    "concours",
+    "consoler",
+    "coquille",
+    "corriger",
+    "coupable",
+    "couronne",
+    "courrier",
+    "craintif",
+    "crevette",
+    "critique",
+    "cuillere",
+    "cuisiner",
+    "cyclisme",
+    "declarer",
+    "decoller",
+    "decouper",
+    "dejeuner",
+    "delivrer",
+    "demander",
+    "demarche",
+    "dentiste",
+    "depasser",
+    "deplacer",
+    "descente",
+    "dessiner",
+    "detacher",
+    "diapason",
+    "dimanche",
+    "discours",
+    "discuter",
+    "distance",
+    "domicile",
+    "dompteur",
+    "douzaine",
+    "eclairer",
+    "economie",
+    "ecriture",
+    "ecrivain",
+    "ecureuil",
+    "efficace",
+    "effrayer",
+    "elegance",
+    "elephant",
+    "emballer",
+    "embarras",
+    "emission",
+    "emouvant",
+    "empecher",
+    "emporter",
+    "enfermer",
+    "ennuyeux",
+    "entourer",
+    "entracte",
+    "entrepot",
+    "epicerie",
+    "equateur",
+    "escalier",
+    "escargot",
+    "etonnant",
+    "etudiant",
+    "eventail",
+    "examiner",
+    "exercice",
+    "exploser",
+    "fabrique",
+    "familier",
+    "fauteuil",
+    "festival",
+    "figurine",
+    "fontaine",
+    "forgeron",
+    "fouiller",
+    "fourneau",
+    "fourrure",
+    "fraction",
+    "fragment",
+    "francais",
+    "fromager",
+    "garderie",
+    "gendarme",
+    "genereux",
+    "glorieux",
+    "gourmand",
+    "gracieux",
+    "grillage",
+    "habiller",
+    "habitude",
+    "harmonie",
+    "herisson",
+    "heritage",
+    "histoire",
+    "horrible",
+    "imaginer",
+    "incendie",
+    "innocent",
+    "instinct",
+    "inventer",
+    "jacinthe",
+    "jardiner",
+    "lanterne",
+    "limonade",
+    "logement",
+    "lointain",
+    "lumineux",
+    "magicien",
+    "magnolia",
+    "marchand",
+    "marmotte",
+    "martinet",
+    "medaille",
+    "meilleur",
+    "melanger",
+    "mensonge",
+    "mercredi",
+    "minuteur",
+    "moelleux",
+    "monsieur",
+    "montagne",
+    "monument",
+    "mosaique",
+    "mouchoir",
+    "muraille",
+    "murmurer",
+    "nageoire",
+    "naufrage",
+    "noisette",
+    "nombreux",
+    "nouvelle",
+    "observer",
+    "obstacle",
+    "officier",
+    "offrande",
+    "oiseleur",
+    "omelette",
+    "oreiller",
+    "orphelin",
+    "paisible",
+    "panorama",
+    "pantalon",
+    "papillon",
+    "paravent",
+    "parcours",
+    "partager",
+    "passager",
+    "passoire",
+    "pasteque",
+    "patience",
+    "paupiere",
+    "personne",
+    "physique",
+    "pingouin",
+    "pistache",
+    "plongeur",
+    "poitrine",
+    "portiere",
+    "portrait",
+    "poubelle",
+    "pratique",
+    "precieux",
+    "preparer",
+    "pression",
+    "principe",
+    "probleme",
+    "prochain",
+    "profiter",
+    "promesse",
+    "proteger",
+    "prunelle",
+    "puissant",
+    "punition",
+    "pyramide",
+    "quantite",
+    "quartier",
+    "question",
+    "raconter",
+    "ramasser",
+    "raquette",
+    "rassurer",
+    "reclamer",
+    "recolter",
+    "recopier",
+    "regarder",
+    "regiment",
+    "regulier",
+    "relation",
+    "remorque",
+    "repasser",
+    "reserver",
+    "resister",
+    "respirer",
+    "resultat",
+    "revanche",
+    "richesse",
+    "ridicule",
+    "roulotte",
+    "rubrique",
+    "ruisseau",
+    "saladier",
+    "sanglier",
+    "saucisse",
+    "scarabee",
+    "sorciere",
+    "soucoupe",
+    "souffler",
+    "souvenir",
+    "surprise",
+    "suspense",
+    "tabouret",
+    "taquiner",
+    "terminer",
+    "terrasse",
+    "terrible",
+    "tirelire",
+    "toboggan",
+    "tonnerre",
+    "tracteur",
+    "traineau",
+    "trembler",
+    "tremplin",
+    "tribunal",
+    "tricycle",
+    "triomphe",
+    "trottoir",
+    "troupeau",
+    "uniforme",
+    "vacances",
+    "vaisseau",
+    "vendredi",
+    "verifier",
+    "vetement",
+    "victoire",
+    "vignoble",
+    "vinaigre",
+    "violette",
+    "volaille",
+    "voyageur"
+];
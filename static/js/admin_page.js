@@ -24,6 +24,96 @@ const show_msg_box = () => {
 }
 
 
+// ADMIN ACTIONS
+
+const get_hashed_game_id = () =>
+    document.getElementById("hashed_game_id").value
+
+/**
+ * Every admin action works the same way:
+ * POST it, show what happened, then reload to see the new state.
+ */
+const post_admin_action = async (route, input) => {
+    const result = await utils.post_json(route, input)
+    const message = !!result.error ? result.error : result.data.message
+
+    msgs.push(message)
+    show_msg_box()
+
+    setTimeout(() => {
+        location.reload()
+    }, 2500)
+}
+
+const cancel_game = async () => {
+    if (!confirm("Cancel this game?")) { return }
+
+    await post_admin_action("/admin/cancel_game", {
+        "hashed_game_id": get_hashed_game_id()
+    })
+}
+
+const finish_game = async () => {
+    const winner_username = document.getElementById("winner_select").value
+    if (!confirm("Finish this game?")) { return }
+
+    await post_admin_action("/admin/finish_game", {
+        "hashed_game_id": get_hashed_game_id(),
+        "winner_username": winner_username === "" ? null : winner_username
+    })
+}
+
+const remove_player = async (username) => {
+    if (!confirm("Remove " + username + " from this game?")) { return }
+
+    await post_admin_action("/admin/remove_player", {
+        "hashed_game_id": get_hashed_game_id(),
+        "username": username
+    })
+}
+
+const ban_user = async () => {
+    const username = document.getElementById("username").value
+    const days = document.getElementById("ban_days_input").value
+    if (!confirm("Ban " + username + "?")) { return }
+
+    await post_admin_action("/admin/ban_user", {
+        "username": username,
+        "ban_scope": document.getElementById("ban_scope_select").value,
+        "reason": document.getElementById("ban_reason_input").value,
+        "days": days === "" ? null : Number(days)
+    })
+}
+
+const lift_ban = async (ban_id) => {
+    if (!confirm("Lift this ban?")) { return }
+
+    await post_admin_action("/admin/lift_ban", { "ban_id": Number(ban_id) })
+}
+
+
 // Add event listeners
+// The game and user pages share this script, so only wire up the buttons that are there
 
-document.addEventListener('DOMContentLoaded', () => hide_msg_box())
+const add_click_listener = (element_id, callback) => {
+    const element = document.getElementById(element_id)
+    if (!!element) {
+        element.addEventListener('click', () => callback())
+    }
+}
+
+document.addEventListener('DOMContentLoaded', () => {
+    hide_msg_box()
+
+    add_click_listener("cancel_game_btn", cancel_game)
+    add_click_listener("finish_game_btn", finish_game)
+    add_click_listener("ban_user_btn", ban_user)
+
+    for (let remove_btn of document.getElementsByClassName("remove_player_btn")) {
+        remove_btn.addEventListener('click', () => remove_player(remove_btn.dataset.username))
+    }
+
+    for (let lift_btn of document.getElementsByClassName("lift_ban_btn")) {
+        lift_btn.addEventListener('click', () => lift_ban(lift_btn.dataset.banId))
+    }
+})
@@ -0,0 +1,26 @@
+$(document).foundation()
+import * as io from './io.js'
+
+
+const show_msg = msg => {
+    const msg_box = document.getElementById("msg_box")
+    msg_box.innerHTML = "<p>" + msg + "</p>"
+    msg_box.classList.remove('hidden')
+}
+
+const play_daily = async () => {
+    const game_data = await io.new_daily_game()
+
+    if (!game_data.hashed_game_id) {
+        show_msg(game_data.error)
+        return
+    }
+
+    // Redirect user to the game
+    window.location.href = "/game/" + game_data.hashed_game_id
+}
+
+const daily_btn = document.getElementById("daily_btn")
+if (!!daily_btn) {
+    daily_btn.addEventListener('click', (e) => play_daily())
+}
@@ -13,7 +13,9 @@ const create_new_game = async () => {
     msgs.push("NEW GAME CREATING")
 
     const invite_only = document.getElementById("invite_only_check").checked
-    const game_data = await io.new_game(invite_only)
+    const word_length = parseInt(document.getElementById("word_length_select").value)
+    const game_lang = document.getElementById("game_lang_select").value
+    const game_data = await io.new_game(invite_only, word_length, game_lang)
     const hashed_game_id = game_data.hashed_game_id
 
     if (!hashed_game_id || hashed_game_id < 1) {
@@ -123,13 +125,12 @@ document.addEventListener('DOMContentLoaded', () => {
     hide_element(document.getElementById("rules_div"))
     hide_msg_box()
     invites_list = document.getElementById("invitations")
-    refresh_data()
 
     const verify_btn = document.getElementById("verify_btn")
     !!verify_btn && verify_btn.addEventListener("click", () => req_email_verify())
 
-    // Check every 4 seconds for new users or updated game_status
-    setInterval(refresh_data, 4001)
+    // The server tells us when our invitations change (any event means refresh)
+    io.open_live_socket("/ws/dashboard", () => refresh_data(), refresh_data)
 })
 
 document.getElementById('new_game_button').addEventListener(
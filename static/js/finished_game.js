@@ -0,0 +1,63 @@
+$(document).foundation()
+import * as io from './io.js'
+
+
+let replay = null
+let replay_step = 0
+
+/**
+ * Show the game as it was after the given number of turns.
+ * Each row is one turn: who played, and the word colored by its score.
+ */
+const show_replay_step = step => {
+    replay_step = Math.max(0, Math.min(step, replay.turns.length))
+
+    document.getElementById("replay_step_label").innerHTML =
+        "TURN " + replay_step + " / " + replay.turns.length
+
+    document.getElementById("replay_tbody").innerHTML =
+        replay.turns
+            .slice(0, replay_step)
+            .reduce((html, turn) => html + get_turn_row(turn), "")
+}
+
+const get_turn_row = turn =>
+    "<tr>" +
+    "<td>" + turn.turn_number + "</td>" +
+    "<td>" + turn.username + "</td>" +
+    "<td>" + get_turn_letters(turn) + "</td>" +
+    "</tr>"
+
+// Timed-out turns have no word, so show an empty tile for each letter
+const get_turn_letters = turn => {
+    const letters = turn.timed_out ?
+        Array(turn.score.length).fill("&nbsp;") :
+        turn.word.split("")
+
+    return letters.reduce((html, letter, index) =>
+        html + "<div class='kb_letter letter_" + turn.score[index] + "'>" + letter + "</div>"
+    , "") + (turn.timed_out ? " (TIMED OUT)" : "")
+}
+
+
+document.addEventListener('DOMContentLoaded', async () => {
+    const game_id = document.getElementById("game_id").value
+    const replay_response = await io.get_replay(game_id)
+
+    if (!replay_response.replay) {
+        console.log(replay_response.error)
+        return
+    }
+
+    replay = replay_response.replay
+    show_replay_step(replay.turns.length)
+
+    document.getElementById("replay_first_btn")
+        .addEventListener('click', (e) => show_replay_step(0))
+    document.getElementById("replay_prev_btn")
+        .addEventListener('click', (e) => show_replay_step(replay_step - 1))
+    document.getElementById("replay_next_btn")
+        .addEventListener('click', (e) => show_replay_step(replay_step + 1))
+    document.getElementById("replay_last_btn")
+        .addEventListener('click', (e) => show_replay_step(replay.turns.length))
+})
@@ -66,8 +66,8 @@ const set_sizes = () => {
 
     if (panel_width < 500) {
 
-        // resize title
-        let new_tile_font_size = Math.round(panel_width / 8.7).toString() + "px"
+        // resize title (longer words need smaller tiles)
+        let new_tile_font_size = Math.round(panel_width / (word_length * 1.74)).toString() + "px"
         board.style.fontSize = new_tile_font_size
 
         // resize headline
@@ -78,7 +78,7 @@ const set_sizes = () => {
         board_panel.style.marginTop = "0px";
 
     } else {
-        board.style.fontSize = "57px"
+        board.style.fontSize = Math.round(285 / word_length).toString() + "px"
         headline.style.fontSize = "101px"
 
         // re-institute margins on board panel
@@ -118,64 +118,24 @@ let user_id = null
 let username = null
 let number_of_players = 0
 
-// This is how we set the colors for each letter
+// Board dimensions come from the game (set by the template)
+const word_length = parseInt(document.getElementById("word_length").value)
+const max_turns = parseInt(document.getElementById("max_turns").value)
+
+/**
+ * This is how we set the colors for each letter.
+ * One word per guess, one tile per letter.
+ * The tile elements (ids like "2-4") are generated by the template.
+ */
 const guess_map = {
-    words: [
-        // guess 1
-        {
-            tiles: [
-                new Tile(document.getElementById("1-1"), LetterState.CURRENT),
-                new Tile(document.getElementById("1-2"), LetterState.CURRENT),
-                new Tile(document.getElementById("1-3"), LetterState.CURRENT),
-                new Tile(document.getElementById("1-4"), LetterState.CURRENT),
-                new Tile(document.getElementById("1-5"), LetterState.CURRENT),
-            ]
-        },
-
-        // guess 2
-        {
-            tiles: [
-                new Tile(document.getElementById("2-1"), LetterState.CURRENT),
-                new Tile(document.getElementById("2-2"), LetterState.CURRENT),
-                new Tile(document.getElementById("2-3"), LetterState.CURRENT),
-                new Tile(document.getElementById("2-4"), LetterState.CURRENT),
-                new Tile(document.getElementById("2-5"), LetterState.CURRENT),
-            ]
-        },
-
-        // guess 3
-        {
-            tiles: [
-                new Tile(document.getElementById("3-1"), LetterState.CURRENT),
-                new Tile(document.getElementById("3-2"), LetterState.CURRENT),
-                new Tile(document.getElementById("3-3"), LetterState.CURRENT),
-                new Tile(document.getElementById("3-4"), LetterState.CURRENT),
-                new Tile(document.getElementById("3-5"), LetterState.CURRENT),
-            ]
-        },
-
-        // guess 4
-        {
-            tiles: [
-                new Tile(document.getElementById("4-1"), LetterState.CURRENT),
-                new Tile(document.getElementById("4-2"), LetterState.CURRENT),
-                new Tile(document.getElementById("4-3"), LetterState.CURRENT),
-                new Tile(document.getElementById("4-4"), LetterState.CURRENT),
-                new Tile(document.getElementById("4-5"), LetterState.CURRENT),
-            ]
-        },
-
-        // guess 5
-        {
-            tiles: [
-                new Tile(document.getElementById("5-1"), LetterState.CURRENT),
-                new Tile(document.getElementById("5-2"), LetterState.CURRENT),
-                new Tile(document.getElementById("5-3"), LetterState.CURRENT),
-                new Tile(document.getElementById("5-4"), LetterState.CURRENT),
-                new Tile(document.getElementById("5-5"), LetterState.CURRENT),
-            ]
-        },
-    ]
+    words: Array.from({ length: max_turns }, (_, word_num) => ({
+        tiles: Array.from({ length: word_length }, (_, letter_num) =>
+            new Tile(
+                document.getElementById((word_num + 1) + "-" + (letter_num + 1)),
+                LetterState.CURRENT
+            )
+        )
+    }))
 }
 
 let word_index = 0
@@ -224,20 +184,9 @@ const check_guess = async () => {
         console.log("ERROR")
         new_message(letter_states_obj.error)
         return
-    } else if (letter_states_obj.fake_word) {
-        new_message("NOT IN WORD LIST")
-        letter_index = 0
-        set_current_tile(current_word.tiles[letter_index])
-        current_tile.element.focus()
-        return
-    } else if (letter_states_obj.max_guesses) {
-        new_message("NO MORE GUESSES")
-        letter_index = 0
-        set_current_tile(current_word.tiles[letter_index])
-        current_tile.element.focus()
-        return
-    } else if (letter_states_obj.wrong_turn) {
-        new_message("NOT YOUR TURN")
+    } else if (letter_states_obj.rejected) {
+        // fake word, wrong length, hard mode, not your turn...
+        new_message(letter_states_obj.message)
         letter_index = 0
         set_current_tile(current_word.tiles[letter_index])
         current_tile.element.focus()
@@ -278,7 +227,7 @@ const check_guess = async () => {
     letter_index = 0
     word_index ++
 
-    if (word_index < 5) {        
+    if (word_index < max_turns) {
         current_word = guess_map.words[word_index]
         set_current_tile(current_word.tiles[letter_index])
         remove_tabindexes() // remove old (all) tabindexes
@@ -364,7 +313,7 @@ const key_pressed = event => {
     current_tile.set_letter(key)
     current_tile.element.classList.add(LetterState.CURRENT)
 
-    letter_index < 4 && letter_index++
+    letter_index < word_length - 1 && letter_index++
     set_current_tile(current_word.tiles[letter_index])
     current_tile.element.focus()
 }
@@ -396,7 +345,7 @@ const move_left = (event = null) => {
 
 
 const move_right = (event = null) => {
-    if (letter_index < 4) {
+    if (letter_index < word_length - 1) {
         letter_index++
         set_current_tile(current_word.tiles[letter_index])
         current_tile.element.focus()
@@ -580,7 +529,7 @@ const settle_old_scores = async () => {
 
     create_keyboard_element()
 
-    if (word_index > 3) {
+    if (word_index >= max_turns - 1) {
         // If all guesses are full, remove all interactiveness
         current_word = null
         set_current_tile(null)
@@ -703,6 +652,32 @@ const refresh_players = async () => {
     }
 }
 
+/**
+ * Handle an event pushed from the server.
+ * @param {object} live_event 
+ */
+const handle_live_event = live_event => {
+    switch (live_event.event) {
+        case "turn_changed":
+            turn_timeout = new Date(live_event.turn_timeout)
+            refresh_players()
+            break
+        case "new_score":
+            // our own scores are already on the board (unless we timed out)
+            live_event.user_id != user_id && refresh_players()
+            break
+        case "game_over":
+            end_game(live_event.winner_id == user_id, "finished")
+            break
+        case "game_cancelled":
+            end_game(false, "cancelled")
+            break
+        default:
+            // player_left, resync, etc.
+            refresh_players()
+    }
+}
+
 const build_player_li = username => "<li " +
     "class='player_label'" +
     ">&nbsp;" + username + "</li>"
@@ -730,7 +705,7 @@ const OPPO_SCORE_DATA = {
         "future": "transparent"
     },
     border_size: 5,
-    rects_per_line: 5
+    rects_per_line: word_length
 }
 
 /**
@@ -745,7 +720,7 @@ const show_oppo_scores = (players) => {
     // For each oppo_panel we set their scores in the squares
 
     let rect_width = null // fill on first loop, then read from filled variable
-    const max_words = 5
+    const max_words = max_turns
 
     players.map(player => {
         const label_id = player.username + "_label"
@@ -761,11 +736,17 @@ const show_oppo_scores = (players) => {
         context.clearRect(0, 0, oppo_canvas.width, oppo_canvas.height)
         
         // draw the scores
+        // (squares must fit both across and down, since word length varies)
         rect_width = (rect_width != null) ?
             rect_width :
-            (oppo_canvas.width -
-                ((OPPO_SCORE_DATA.rects_per_line + 1) * OPPO_SCORE_DATA.border_size)) /
-                OPPO_SCORE_DATA.rects_per_line
+            Math.min(
+                (oppo_canvas.width -
+                    ((OPPO_SCORE_DATA.rects_per_line + 1) * OPPO_SCORE_DATA.border_size)) /
+                    OPPO_SCORE_DATA.rects_per_line,
+                (oppo_canvas.height -
+                    ((max_words + 1) * OPPO_SCORE_DATA.border_size)) /
+                    max_words
+            )
         
         for (let i=0; i<word_scores.length; i++) {
             const letter_scores = word_scores[i].score
@@ -986,15 +967,15 @@ document.addEventListener('DOMContentLoaded', async () => {
 
 
     if (number_of_players > 1) {
-        // Check every 1.5 seconds for new users or updated game_status
-        setInterval(refresh_players, 1500)
         setInterval(increment_turn_countdown, 1000)
     } else {
         // if single-player game, the countdown is null
         timer_element.innerHTML = "--"
-        // Check every 5 seconds for new users or updated game_status
-        setInterval(refresh_players, 5000)
     }
+
+    // The server pushes turn changes, scores, and game-over.
+    // Refresh everything when the socket (re)connects, in case we missed something.
+    io.open_live_socket("/ws/game/" + hashed_game_id(), handle_live_event, refresh_players)
     show_scores()
 
     const cranktable = document.getElementById("cranktable")
@@ -40,10 +40,12 @@ import * as utils from './utils.js'
  * Backend creates an empty new game and returns id.
  * @returns 
  */
-export const new_game = async (invite_only) => {
+export const new_game = async (invite_only, word_length, game_lang) => {
     const route = "/new_game"
     const input = {
-        "invite_only": invite_only
+        "invite_only": invite_only,
+        "word_length": word_length,
+        "game_lang": game_lang
     }
 
     const return_obj = {
@@ -51,31 +53,41 @@ export const new_game = async (invite_only) => {
         error: null
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        return_obj.error = result.error
+    } else if (!!result.data.hashed_game_id) {
+        return_obj.hashed_game_id = result.data.hashed_game_id
+    } else {
+        console.log("no game id")
+        return_obj.error = "NO GAME ID"
+    }
 
-            throw new Error("Unable to create new game, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        if (data.hashed_game_id !== undefined && !!data.hashed_game_id) {
-            return_obj.hashed_game_id = data.hashed_game_id
-        } else if (!!data.error) {
-            return_obj.error = data.error
-        } else {
-            console.log("no game id")
-            return_obj.error = "NO GAME ID"
-        }     
-    }).catch(error =>
-        console.log('Error: ', error)
-    )
+    return return_obj
+}
+
+
+/**
+ * Start (or go back to) the user's attempt at today's daily challenge.
+ * 
+ * @returns json object with hashed_game_id or error
+ */
+export const new_daily_game = async () => {
+    const route = "/new_daily_game"
+
+    const return_obj = {
+        hashed_game_id: 0,
+        error: null
+    }
+
+    const result = await utils.post_json(route, {})
+    if (!!result.error) {
+        return_obj.error = result.error
+    } else if (!!result.data.hashed_game_id) {
+        return_obj.hashed_game_id = result.data.hashed_game_id
+    } else {
+        return_obj.error = "NO GAME ID"
+    }
 
     return return_obj
 }
@@ -98,29 +110,80 @@ export const start_game = async hashed_game_id => {
         error: null
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT START GAME")
+        response_obj.error = result.error
+    } else {
+        response_obj.success = result.data.success
+    }
+
+    return response_obj
+}
 
-            throw new Error("Unable to start game, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        if (data.success) {
-            response_obj.success = true
-        } else {
-            console.log("DID NOT START GAME")
-            response_obj.error = !!data.error ? data.error : "DID NOT START GAME"
-        }        
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
+
+/**
+ * When the owner changes the game settings in the pre-game lobby.
+ * 
+ * @param {int} hashed_game_id 
+ * @param {object} settings (max_guesses, turn_seconds, max_players, hard_mode)
+ * @returns json object
+ */
+export const update_settings = async (hashed_game_id, settings) => {
+    const route = "/game_in/update_settings"
+    const input = {
+        "hashed_game_id": String(hashed_game_id),
+        "max_guesses": settings.max_guesses,
+        "turn_seconds": settings.turn_seconds,
+        "max_players": settings.max_players,
+        "hard_mode": settings.hard_mode
+    }
+
+    const response_obj = {
+        success: false,
+        error: null
+    }
+
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT UPDATE SETTINGS")
+        response_obj.error = result.error
+    } else {
+        response_obj.success = result.data.success
+    }
+
+    return response_obj
+}
+
+
+
+/**
+ * Owner adds a computer player to the pre-game.
+ * 
+ * @param {string} hashed_game_id 
+ * @param {string} bot_level (easy, medium or hard)
+ * @returns json object with success, username and error
+ */
+export const add_bot = async (hashed_game_id, bot_level) => {
+    const route = "/game_in/add_bot"
+    const input = {
+        "hashed_game_id": String(hashed_game_id),
+        "bot_level": bot_level
+    }
+
+    const response_obj = {
+        success: false,
+        username: null,
+        error: null
+    }
+
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        response_obj.error = result.error
+    } else {
+        response_obj.success = result.data.success
+        response_obj.username = result.data.username
+    }
 
     return response_obj
 }
@@ -145,34 +208,53 @@ export const get_guess_scores = async hashed_game_id => {
         error: null
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT GET GUESS DATA")
+        response_obj.error = result.error
+    } else {
+        response_obj.scores = result.data.scores
+    }
 
-            throw new Error("Unable to get guesses, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-
-        if (!!data.scores) {
-            response_obj.scores = data.scores
-        } else {
-            console.log("DID NOT GET GUESS DATA")
-            response_obj.error = !!data.error ? data.error : "DID NOT GET GUESS DATA"
-        }        
-    }).catch(error =>
-        console.log('Error: ', error)
-    )
+    return response_obj
+}
+
+/**
+ * Get every turn of a finished game, in order, with the words revealed.
+ * 
+ * @param {string} hashed_game_id 
+ * @returns json object with replay (or error)
+ */
+export const get_replay = async hashed_game_id => {
+    const route = "/game_in/get_replay"
+    const input = {
+        "hashed_game_id": String(hashed_game_id)
+    }
+
+    const response_obj = {
+        replay: null,
+        error: null
+    }
+
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        response_obj.error = result.error
+    } else {
+        response_obj.replay = result.data
+    }
 
     return response_obj
 }
 
+// Error codes for a guess that was refused but didn't use up the turn
+const GUESS_REJECTIONS = [
+    "fake_word",
+    "max_guesses",
+    "wrong_turn",
+    "wrong_length",
+    "hard_mode_violation"
+]
+
 /**
  * Check a particular guess (word) and get a result for that word
  * @param {*} guess_word 
@@ -188,48 +270,36 @@ export const check_guess_io = async (guess_word, hashed_game_id) => {
 
     const response_obj = {
         letter_states: [],
-        fake_word: false,
-        max_guesses: false,
-        wrong_turn: false,
+        rejected: false,
+        rejection_code: null,
+        message: null,
         game_over: false,
         is_winner: false,
         next_turn_id: null,
         error: null
     }
 
-    await utils.fetch_json_post(check_guess_route, guess_obj)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to check word, or error on server.")
+    const result = await utils.post_json(check_guess_route, guess_obj)
+
+    if (GUESS_REJECTIONS.includes(result.error_code)) {
+        // Guess was not accepted. Player may try again.
+        response_obj.rejected = true
+        response_obj.rejection_code = result.error_code
+        response_obj.message = result.error
+    } else if (!!result.error) {
+        response_obj.error = result.error
+    } else {
+        const guess_result = result.data
+        if (guess_result.is_winner) {
+            response_obj.is_winner = guess_result.is_winner
+            response_obj.game_over = true
+        } else if (guess_result.game_over) {
+            response_obj.game_over = true
         }
-        return response.json()
-    }).then(guess_result => {
-        if (!!guess_result.fake_word) {
-            response_obj.fake_word = true
-        } else if (!!guess_result.max_guesses) {
-            response_obj.max_guesses = true
-        } else if (!!guess_result.wrong_turn) {
-            response_obj.wrong_turn = true
-        } else {
-            if (guess_result.is_winner) {
-                response_obj.is_winner = guess_result.is_winner
-                response_obj.game_over = true
-            } else if (guess_result.game_over) {
-                response_obj.game_over = true
-            }
-            
-            response_obj.next_turn_id = guess_result.next_turn_id
-            response_obj.letter_states = guess_result.score
-        }        
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
+
+        response_obj.next_turn_id = guess_result.next_turn_id
+        response_obj.letter_states = guess_result.score
+    }
 
     return response_obj
 }
@@ -254,33 +324,17 @@ export const refresh_players = async hashed_game_id => {
         turn_timeout: false,
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to refresh game, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        //console.log("data: " + JSON.stringify(data))
-        if (!!data.current_turn_id && !!data.players && !!data.turn_timeout) {
-            response_obj.players = data.players
-            response_obj.current_turn_id = data.current_turn_id
-            response_obj.turn_timeout = new Date(data.turn_timeout)
-            response_obj.game_status = data.game_status
-        } else {
-            console.log("DID NOT REFRESH PLAYERS DATA")
-            response_obj.error = !!data.error ? data.error : "DID NOT REFRESH PLAYERS DATA"
-        }        
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT REFRESH PLAYERS DATA")
+        response_obj.error = result.error
+    } else {
+        const data = result.data
+        response_obj.players = data.players
+        response_obj.current_turn_id = data.current_turn_id
+        response_obj.turn_timeout = new Date(data.turn_timeout)
+        response_obj.game_status = data.game_status
+    }
 
     return response_obj
 }
@@ -321,30 +375,13 @@ export const join_game = async (hashed_game_id) => {
         error: null
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to join game, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        if (data.success) {
-            response_obj.success = true
-        } else {
-            console.log("DID NOT JOIN GAME")
-            response_obj.error = !!data.error ? data.error :
-                !!data.message ? data.message : "DID NOT JOIN GAME"
-        }        
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT JOIN GAME")
+        response_obj.error = result.error
+    } else {
+        response_obj.success = result.data.success
+    }
 
     return response_obj
 }
@@ -367,29 +404,13 @@ export const leave_game = async (hashed_game_id) => {
         error: null
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to leave game, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        if (data.success) {
-            response_obj.success = true
-        } else {
-            console.log("DID NOT LEAVE GAME")
-            response_obj.error = !!data.error ? data.error : "DID NOT LEAVE GAME"
-        }        
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT LEAVE GAME")
+        response_obj.error = result.error
+    } else {
+        response_obj.success = result.data.success
+    }
 
     return response_obj
 }
@@ -415,31 +436,15 @@ export const invite_player = async (invited_username, hashed_game_id) => {
         error: null
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to invite player, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        if (!!data.message) {
-            response_obj.invite_success = data.invite_success
-            response_obj.message = data.message
-            console.log(response_obj.message)
-        } else {
-            console.log("DID NOT INVITE PLAYER")
-            response_obj.error = !!data.error ? data.error : "DID NOT INVITE PLAYER"
-        }        
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT INVITE PLAYER")
+        response_obj.error = result.error
+        response_obj.message = result.error
+    } else {
+        response_obj.invite_success = result.data.invite_success
+        response_obj.message = result.data.message
+    }
 
     return response_obj
 }
@@ -464,34 +469,20 @@ export const refresh_pregame = async hashed_game_id => {
         invitee_usernames: [],
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to refresh game, or error on server.")
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT REFRESH GAME DATA")
+        response_obj.error = result.error
+    } else {
+        const data = result.data
+        response_obj.players = data.players
+        response_obj.game_status = data.game_status
+        if (!!data.invitee_usernames) {
+            response_obj.invitee_usernames = data.invitee_usernames
         }
-        return response.json()
-    }).then(data => {
-        if (!!data.game_status && !!data.players) {
-            response_obj.players = data.players
-            response_obj.game_status = data.game_status
-            if (!!response_obj.invitee_usernames) {
-                response_obj.invitee_usernames = data.invitee_usernames
-            }
 
-        } else {
-            console.log("DID NOT REFRESH GAME DATA")
-            response_obj.error = !!data.error ? data.error : "DID NOT REFRESH GAME DATA"
-        }        
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
+        response_obj.settings = data.settings
+    }
 
     return response_obj
 }
@@ -511,31 +502,15 @@ export const uninvite_player = async (hashed_game_id, username) => {
         message: null,
     }
 
-    await utils.fetch_json_post(route, input)
-        .then(response => {
-            if(!response.ok) {
-                response.json().then(data => {
-                    console.log("NOT OK")
-                    let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                    msg += (!!data.error) ? data.error : " Error occurred"
-                    response_obj.error = msg
-                })
-
-                throw new Error("Unable to uninvite player, or error on server.")
-            }
-            return response.json()
-        }).then(data => {
-            if (!!data.message) {
-                response_obj.success = !!data.success
-                response_obj.message = data.message
-                console.log(response_obj.message)
-            } else {
-                console.log("DID NOT UNINVITE PLAYER")
-                response_obj.error = !!data.error ? data.error : "DID NOT UNINVITE PLAYER"
-            }        
-        }).catch(error => {
-            console.log('Error: ', error)
-        })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT UNINVITE PLAYER")
+        response_obj.error = result.error
+        response_obj.message = result.error
+    } else {
+        response_obj.success = result.data.success
+        response_obj.message = result.data.message
+    }
 
     return response_obj
 }
@@ -555,31 +530,15 @@ export const boot_player_pregame = async (hashed_game_id, username) => {
         message: null,
     }
 
-    await utils.fetch_json_post(route, input)
-        .then(response => {
-            if(!response.ok) {
-                response.json().then(data => {
-                    console.log("NOT OK")
-                    let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                    msg += (!!data.error) ? data.error : " Error occurred"
-                    response_obj.error = msg
-                })
-
-                throw new Error("Unable to boot player, or error on server.")
-            }
-            return response.json()
-        }).then(data => {
-            if (!!data.message) {
-                response_obj.success = !!data.success
-                response_obj.message = data.message
-                console.log(response_obj.message)
-            } else {
-                console.log("DID NOT BOOT PLAYER")
-                response_obj.error = !!data.error ? data.error : "DID NOT BOOT PLAYER"
-            }        
-        }).catch(error => {
-            console.log('Error: ', error)
-        })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT BOOT PLAYER")
+        response_obj.error = result.error
+        response_obj.message = result.error
+    } else {
+        response_obj.success = result.data.success
+        response_obj.message = result.data.message
+    }
 
     return response_obj
 }
@@ -604,29 +563,13 @@ export const cancel_game = async hashed_game_id => {
         error: null
     }
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to start game, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        if (data.success) {
-            response_obj.success = data.success
-        } else {
-            console.log("DID NOT CANCEL GAME")
-            response_obj.error = !!data.error ? data.error : "DID NOT CANCEL GAME"
-        }        
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
+    const result = await utils.post_json(route, input)
+    if (!!result.error) {
+        console.log("DID NOT CANCEL GAME")
+        response_obj.error = result.error
+    } else {
+        response_obj.success = result.data.success
+    }
 
     return response_obj
 }
@@ -647,22 +590,16 @@ export const cancel_game = async hashed_game_id => {
 export const refresh_dashboard = async () => {
     const route = "/game_in/refresh_dashboard"
 
-    let response = await fetch(route, {
-        method: 'POST',
-        headers: { 'Content-Type': 'application/json; charset=utf-8' }
-    })
-
-    let data = await response.json()
-
     let return_obj = {
         invited_games: [],
         error: null
     }
 
-    if (data.invited_games !== undefined) {
-        return_obj.invited_games = data.invited_games
-    } else if (!!data.error) {
-        return_obj.error = data.error
+    const result = await utils.post_json(route, {})
+    if (!!result.error) {
+        return_obj.error = result.error
+    } else {
+        return_obj.invited_games = result.data.invited_games
     }
 
     return return_obj
@@ -694,8 +631,8 @@ export const req_email_verify = async () => {
         }
 
         if (!response.ok) {
-            if (data.error) {
-                message += data.error;
+            if (data.error && data.error.message) {
+                message += data.error.message;
             }
             if (data.message) {
                 message += " " + data.message;
@@ -709,4 +646,48 @@ export const req_email_verify = async () => {
     })
 
     return return_message
-}
\ No newline at end of file
+}
+
+
+/**
+ * Open a WebSocket for live updates and keep it open.
+ * The server pushes JSON events (tagged with an "event" field),
+ * and each one is handed to on_event.
+ * 
+ * If the connection drops we reconnect, waiting a little longer each time.
+ * on_open is called on every (re)connect, so the page can fetch fresh data
+ * to cover anything it missed while disconnected.
+ * 
+ * @param {String} route ie: "/ws/game/abc123" or "/ws/dashboard"
+ * @param {function} on_event called with each parsed event object
+ * @param {function} on_open called whenever the socket connects
+ */
+export const open_live_socket = (route, on_event, on_open) => {
+    const protocol = window.location.protocol == "https:" ? "wss://" : "ws://"
+    const url = protocol + window.location.host + route
+    let retry_ms = 1000
+
+    const connect = () => {
+        const socket = new WebSocket(url)
+
+        socket.addEventListener("open", () => {
+            retry_ms = 1000
+            on_open()
+        })
+
+        socket.addEventListener("message", message => {
+            try {
+                on_event(JSON.parse(message.data))
+            } catch (e) {
+                console.log("Bad live event: " + message.data)
+            }
+        })
+
+        socket.addEventListener("close", () => {
+            setTimeout(connect, retry_ms)
+            retry_ms = Math.min(retry_ms * 2, 30000)
+        })
+    }
+
+    connect()
+}
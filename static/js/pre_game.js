@@ -84,6 +84,7 @@ const refresh_data = async () => {
         }
 
         number_of_players = refresh_response.players.length
+        set_settings_labels(refresh_response.settings)
         await set_players_list(refresh_response.players)
         await set_players_event_listeners(game_id, refresh_response.players)
         await set_pending_invites(refresh_response.invitee_usernames)   
@@ -102,15 +103,18 @@ const set_players_list = async players_list => {
     document.getElementById("players_ul").innerHTML =
         players_list.reduce((html, player_item) => 
             !!player_item.username ? 
-                html + get_player_item_li(player_item.username) :
+                html + get_player_item_li(player_item) :
                 html
         , "")
 }
 
-const get_player_item_li = username =>
-    "<li><a href='/user/" + username + "'>" +
-    username + "</a>" +
-    get_boot_btn(username) +
+// Bots (negative ids) have no user page to link to
+const get_player_item_li = player_item =>
+    "<li>" +
+    (player_item.user_id < 0 ?
+        player_item.username :
+        "<a href='/user/" + player_item.username + "'>" + player_item.username + "</a>") +
+    get_boot_btn(player_item.username) +
     "</li>"
 
 
@@ -172,6 +176,57 @@ const set_invitee_event_listeners = async (game_id, invitee_usernames) => {
 
 
 
+/**
+ * Non-owners see the settings as text.
+ * (The owner's inputs are left alone so we don't clobber their typing.)
+ * @param {object} settings 
+ */
+const set_settings_labels = settings => {
+    const max_guesses_label = document.getElementById("max_guesses_label")
+    if (!settings || !max_guesses_label) {
+        return
+    }
+
+    max_guesses_label.innerHTML = settings.max_guesses
+    document.getElementById("turn_seconds_label").innerHTML = settings.turn_seconds
+    document.getElementById("max_players_label").innerHTML = settings.max_players
+    document.getElementById("hard_mode_label").innerHTML = settings.hard_mode ? "ON" : "OFF"
+}
+
+/**
+ * When the owner presses the button to save the game settings
+ */
+const update_settings = async () => {
+    const hash_game_id = document.getElementById("game_id").value
+    const settings = {
+        max_guesses: parseInt(document.getElementById("max_guesses_input").value),
+        turn_seconds: parseInt(document.getElementById("turn_seconds_input").value),
+        max_players: parseInt(document.getElementById("max_players_input").value),
+        hard_mode: document.getElementById("hard_mode_check").checked
+    }
+
+    const update_response = await io.update_settings(hash_game_id, settings)
+    msgs.push(update_response.success ? "Settings saved" : update_response.error)
+    show_msg_box()
+
+    msgs = []
+}
+
+/**
+ * When the owner presses the button to add a bot player
+ */
+const add_bot = async () => {
+    const hash_game_id = document.getElementById("game_id").value
+    const bot_level = document.getElementById("bot_level_select").value
+    const add_bot_response = await io.add_bot(hash_game_id, bot_level)
+    msgs.push(add_bot_response.success ?
+        add_bot_response.username + " joined the game" :
+        add_bot_response.error)
+    show_msg_box()
+
+    msgs = []
+}
+
 const get_uninvite_id = username => "uninvite_" + username
 const get_boot_id = username => "boot_" + username
 
@@ -233,6 +288,8 @@ document.addEventListener('DOMContentLoaded', () => {
     const invite_button = document.getElementById('invite_submit')
     const leave_btn = document.getElementById('leave_btn')
     const invite_input = document.getElementById('invite_input')
+    const settings_button = document.getElementById('settings_submit')
+    const add_bot_button = document.getElementById('add_bot_submit')
 
     // Add event listeners
     join_btn && join_btn.addEventListener('click', (e) => join_game())
@@ -241,6 +298,8 @@ document.addEventListener('DOMContentLoaded', () => {
     cancel_button && cancel_button.addEventListener('click', (e) => cancel_game())
     invite_button && invite_button.addEventListener('click', (e) => invite_player())
     leave_btn && leave_btn.addEventListener('click', (e) => leave_game())
+    settings_button && settings_button.addEventListener('click', (e) => update_settings())
+    add_bot_button && add_bot_button.addEventListener('click', (e) => add_bot())
     invite_input && invite_input.addEventListener("keydown", (event) => {
         const key = event.key.toString().toUpperCase()
         if (key == "ENTER") {
@@ -249,12 +308,30 @@ document.addEventListener('DOMContentLoaded', () => {
         }
     });
 
-    refresh_data()
-
-    // Check every 3 seconds for new users or updated game_status
-    setInterval(refresh_data, 3000);
+    // The server tells us when players join/leave or the game starts.
+    // Fetch everything when the socket (re)connects, then on each event.
+    const game_id = document.getElementById("game_id").value
+    io.open_live_socket("/ws/game/" + game_id, handle_live_event, refresh_data)
 })
 
+/**
+ * Something happened in this game.
+ * If the game started or was cancelled the page must change,
+ * otherwise just refresh the lists.
+ * @param {object} live_event 
+ */
+const handle_live_event = live_event => {
+    if (
+        live_event.event == "game_started" ||
+        live_event.event == "game_cancelled"
+    ) {
+        window.location.reload()
+        return
+    }
+
+    refresh_data()
+}
+
 window.join_game = join_game
 window.start_game = start_game
 window.cancel_game = cancel_game
@@ -0,0 +1,20 @@
+/**
+ * Copy the shareable result text (on the finished game and public result pages)
+ */
+const copy_share_text = async () => {
+    const share_text = document.getElementById("share_text").value
+    const copy_btn = document.getElementById("copy_share_btn")
+
+    try {
+        await navigator.clipboard.writeText(share_text)
+        copy_btn.innerHTML = "COPIED"
+    } catch (error) {
+        console.log('Error: ', error)
+        document.getElementById("share_text").select()
+    }
+}
+
+document.addEventListener('DOMContentLoaded', () => {
+    const copy_btn = document.getElementById("copy_share_btn")
+    copy_btn && copy_btn.addEventListener('click', (e) => copy_share_text())
+})
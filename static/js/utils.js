@@ -37,6 +37,49 @@ export const fetch_json_post = async (route, json_obj) => {
 }
 
 
+/**
+ * POST a JSON object to one of our JSON routes and read the reply.
+ * Failures come back with an error status and
+ * { "error": { "code", "message" } } (the message is already translated).
+ * @param {String} route 
+ * @param {JSON object} json_obj 
+ * @returns { data, error, error_code, error_body }: data on success, the rest on failure
+ */
+export const post_json = async (route, json_obj) => {
+    const result = {
+        data: null,
+        error: null,
+        error_code: null,
+        error_body: null
+    }
+
+    try {
+        const response = await fetch_json_post(route, json_obj)
+        const body = await response.json().catch(() => ({}))
+
+        if (response.ok) {
+            result.data = body
+        } else if (!!body.error && !!body.error.code) {
+            result.error = body.error.message
+            result.error_code = body.error.code
+            result.error_body = body.error
+
+            // The banned page explains the suspension and when it ends
+            if (body.error.code === "banned") {
+                window.location.href = "/banned"
+            }
+        } else {
+            result.error = response.status + " Error occurred"
+        }
+    } catch (error) {
+        console.log('Error: ', error)
+        result.error = "Error occurred"
+    }
+
+    return result
+}
+
+
 /* make sure that a string is a legit JSON string which can be parsed. */ 
 const is_valid_json_string = (json_string) => {
     try {
@@ -19,32 +19,10 @@ const block_user = async () => {
         "username": String(username_to_block)
     }
 
-    const response_obj = {
-        success: false,
-        message: null
-    }
+    const result = await utils.post_json(route, input)
+    const message = !!result.error ? result.error : result.data.message
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to block user, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        response_obj.message = data.message
-        response_obj.success = data.success      
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
-
-    msgs.push(response_obj.message)
+    msgs.push(message)
     show_msg_box()
 
     setTimeout(() => {
@@ -64,32 +42,10 @@ const unblock_user = async () => {
         "username": String(username_to_unblock)
     }
 
-    const response_obj = {
-        success: false,
-        message: null
-    }
+    const result = await utils.post_json(route, input)
+    const message = !!result.error ? result.error : result.data.message
 
-    await utils.fetch_json_post(route, input)
-    .then(response => {
-        if(!response.ok) {
-            response.json().then(data => {
-                console.log("NOT OK")
-                let msg = (!!data.code) ? (data.code.toString() + " ") : ""
-                msg += (!!data.error) ? data.error : " Error occurred"
-                response_obj.error = msg
-            })
-
-            throw new Error("Unable to unblock user, or error on server.")
-        }
-        return response.json()
-    }).then(data => {
-        response_obj.message = data.message
-        response_obj.success = data.success      
-    }).catch(error => {
-        console.log('Error: ', error)
-    })
-
-    msgs.push(response_obj.message)
+    msgs.push(message)
     show_msg_box()
 
     setTimeout(() => {